@@ -6,7 +6,7 @@ use chainflip_api::{
 	self, clean_foreign_chain_address,
 	primitives::{
 		AccountRole, Affiliates, Asset, BasisPoints, BlockNumber, CcmChannelMetadata, ChannelId,
-		ChannelRefundParameters,
+		ChannelRefundParameters, DcaParameters,
 	},
 	settings::StateChain,
 	AccountId32, BrokerApi, OperatorApi, StateChainApi, WithdrawFeesDetail,
@@ -63,6 +63,7 @@ pub trait Rpc {
 		boost_fee: Option<BasisPoints>,
 		affiliate_fees: Option<Affiliates<AccountId32>>,
 		refund_parameters: Option<ChannelRefundParameters>,
+		dca_parameters: Option<DcaParameters>,
 	) -> RpcResult<BrokerSwapDepositAddress>;
 
 	#[method(name = "withdraw_fees", aliases = ["broker_withdrawFees"])]
@@ -80,11 +81,16 @@ pub struct RpcServerImpl {
 impl RpcServerImpl {
 	pub async fn new(
 		scope: &Scope<'_, anyhow::Error>,
-		BrokerOptions { ws_endpoint, signing_key_file, .. }: BrokerOptions,
+		BrokerOptions { ws_endpoint, signing_key_file, signer_socket, scoped_key_of, .. }: BrokerOptions,
 	) -> Result<Self, anyhow::Error> {
+		let state_chain_settings = StateChain { ws_endpoint, signing_key_file, signer_socket };
 		Ok(Self {
-			api: StateChainApi::connect(scope, StateChain { ws_endpoint, signing_key_file })
-				.await?,
+			api: match scoped_key_of {
+				Some(account_id) =>
+					StateChainApi::connect_with_scoped_key(scope, state_chain_settings, account_id)
+						.await?,
+				None => StateChainApi::connect(scope, state_chain_settings).await?,
+			},
 		})
 	}
 }
@@ -110,6 +116,7 @@ impl RpcServer for RpcServerImpl {
 		boost_fee: Option<BasisPoints>,
 		affiliate_fees: Option<Affiliates<AccountId32>>,
 		refund_parameters: Option<ChannelRefundParameters>,
+		dca_parameters: Option<DcaParameters>,
 	) -> RpcResult<BrokerSwapDepositAddress> {
 		Ok(self
 			.api
@@ -123,6 +130,7 @@ impl RpcServer for RpcServerImpl {
 				boost_fee,
 				affiliate_fees.unwrap_or_default(),
 				refund_parameters,
+				dca_parameters,
 			)
 			.await
 			.map(BrokerSwapDepositAddress::from)?)
@@ -168,6 +176,16 @@ pub struct BrokerOptions {
 		help = "A path to a file that contains the broker's secret key for signing extrinsics."
 	)]
 	pub signing_key_file: PathBuf,
+	#[clap(
+		long = "state_chain.signer_socket",
+		help = "A path to the unix socket of a remote signer to sign extrinsics with, instead of the signing key file."
+	)]
+	pub signer_socket: Option<PathBuf>,
+	#[clap(
+		long = "state_chain.scoped_key_of",
+		help = "The broker account to act on behalf of, if the signing key is one of its scoped keys."
+	)]
+	pub scoped_key_of: Option<AccountId32>,
 }
 
 #[tokio::main]
@@ -14,10 +14,18 @@ use api::{
 use cf_chains::eth::Address as EthereumAddress;
 use chainflip_api as api;
 use chainflip_api::primitives::state_chain_runtime;
+use chainflip_engine::{
+	db::{key_share_backup::KeyShareBackup, PersistentKeyDB},
+	state_chain_observer::client::chain_api::ChainApi,
+};
 use clap::Parser;
 use futures::FutureExt;
 use serde::Serialize;
-use std::{io::Write, path::PathBuf, sync::Arc};
+use std::{
+	io::Write,
+	path::{Path, PathBuf},
+	sync::Arc,
+};
 use utilities::{clean_hex_address, round_f64, task_scope::task_scope};
 mod settings;
 
@@ -48,11 +56,18 @@ async fn run_cli() -> Result<()> {
 			You can also just set all configurations required as command line arguments."#,
 	)?;
 
-	println!(
-		"Connecting to state chain node at: `{}` and using private key located at: `{}`",
-		cli_settings.state_chain.ws_endpoint,
-		cli_settings.state_chain.signing_key_file.display()
-	);
+	match &cli_settings.state_chain.signer_socket {
+		Some(signer_socket) => println!(
+			"Connecting to state chain node at: `{}` and using remote signer at: `{}`",
+			cli_settings.state_chain.ws_endpoint,
+			signer_socket.display()
+		),
+		None => println!(
+			"Connecting to state chain node at: `{}` and using private key located at: `{}`",
+			cli_settings.state_chain.ws_endpoint,
+			cli_settings.state_chain.signing_key_file.display()
+		),
+	}
 
 	task_scope(|scope| {
 		async move {
@@ -74,6 +89,7 @@ async fn run_cli() -> Result<()> {
 								params.boost_fee,
 								Default::default(),
 								None,
+								None,
 							)
 							.await?;
 						println!("Deposit Address: {address}");
@@ -147,6 +163,12 @@ async fn run_cli() -> Result<()> {
 						let tx_hash = api.validator_api().start_bidding().await?;
 						println!("Account started bidding at tx {tx_hash:#x}.");
 					},
+					ValidatorSubcommands::ExportKeyShares { db_path, output } => {
+						export_key_shares(&api, &db_path, &output).await?;
+					},
+					ValidatorSubcommands::ImportKeyShares { db_path, input } => {
+						import_key_shares(&api, &db_path, &input).await?;
+					},
 				},
 				Redeem { amount, eth_address, executor_address } => {
 					request_redemption(api, amount, eth_address, executor_address).await?;
@@ -357,6 +379,90 @@ async fn count_witnesses(
 	Ok(())
 }
 
+/// Environment variable that can be used to supply the key share backup password non-interactively.
+const KEY_SHARE_BACKUP_PASSWORD: &str = "CF_KEY_SHARE_BACKUP_PASSWORD";
+
+fn key_share_backup_password(confirm: bool) -> Result<String> {
+	if let Ok(password) = std::env::var(KEY_SHARE_BACKUP_PASSWORD) {
+		return Ok(password)
+	}
+
+	let password = rpassword::prompt_password("Key share backup password: ")?;
+	if confirm {
+		anyhow::ensure!(!password.is_empty(), "The password must not be empty.");
+		anyhow::ensure!(
+			rpassword::prompt_password("Confirm password: ")? == password,
+			"Passwords do not match."
+		);
+	}
+
+	Ok(password)
+}
+
+/// Entry point for the [settings::ValidatorSubcommands::ExportKeyShares] subcommand.
+async fn export_key_shares(api: &StateChainApi, db_path: &Path, output: &Path) -> Result<()> {
+	anyhow::ensure!(db_path.exists(), "No key database found at {}.", db_path.display());
+	anyhow::ensure!(!output.exists(), "{} already exists.", output.display());
+
+	let backup =
+		KeyShareBackup::from_db(&PersistentKeyDB::open_and_migrate_to_latest(db_path, None)?)?;
+	backup.verify_key_shares()?;
+	backup
+		.verify_against_state_chain(
+			&*api.state_chain_client,
+			api.state_chain_client.latest_finalized_block().hash,
+		)
+		.await?;
+
+	let encrypted = backup.encrypt(&key_share_backup_password(true)?)?;
+	std::fs::OpenOptions::new()
+		.write(true)
+		.create_new(true)
+		.open(output)
+		.and_then(|mut file| file.write_all(&encrypted))
+		.with_context(|| format!("Could not write backup to {}.", output.display()))?;
+
+	println!("Exported {} key shares to {}.", backup.key_count(), output.display());
+
+	Ok(())
+}
+
+/// Entry point for the [settings::ValidatorSubcommands::ImportKeyShares] subcommand.
+async fn import_key_shares(api: &StateChainApi, db_path: &Path, input: &Path) -> Result<()> {
+	anyhow::ensure!(
+		!db_path.exists(),
+		"{} already exists. Key shares can only be imported into a fresh database.",
+		db_path.display()
+	);
+
+	let encrypted = std::fs::read(input)
+		.with_context(|| format!("Could not read backup from {}.", input.display()))?;
+	let backup = KeyShareBackup::decrypt(&encrypted, &key_share_backup_password(false)?)?;
+
+	if let Some(genesis_hash) = backup.genesis_hash {
+		anyhow::ensure!(
+			genesis_hash == api.state_chain_client.genesis_hash(),
+			"The backup was created for a different network (genesis hash {genesis_hash:?})."
+		);
+	}
+	backup.verify_key_shares()?;
+	backup
+		.verify_against_state_chain(
+			&*api.state_chain_client,
+			api.state_chain_client.latest_finalized_block().hash,
+		)
+		.await?;
+
+	backup.import_into_db(&PersistentKeyDB::open_and_migrate_to_latest(
+		db_path,
+		Some(api.state_chain_client.genesis_hash()),
+	)?)?;
+
+	println!("Imported {} key shares into {}.", backup.key_count(), db_path.display());
+
+	Ok(())
+}
+
 fn confirm_submit() -> bool {
 	use std::{io, io::*};
 
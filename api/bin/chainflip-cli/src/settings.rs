@@ -5,10 +5,7 @@ use chainflip_api::{
 pub use chainflip_engine::settings::StateChain;
 use chainflip_engine::{
 	constants::{CONFIG_ROOT, DEFAULT_CONFIG_ROOT},
-	settings::{
-		resolve_settings_path, CfSettings, PathResolutionExpectation, StateChainOptions,
-		DEFAULT_SETTINGS_DIR,
-	},
+	settings::{CfSettings, StateChainOptions, DEFAULT_SETTINGS_DIR},
 };
 use clap::Parser;
 use config::{ConfigBuilder, ConfigError, Source, Value};
@@ -118,6 +115,27 @@ pub enum ValidatorSubcommands {
 	StartBidding,
 	/// Stop bidding, thereby stopping participation in auctions.
 	StopBidding,
+	/// Export all key shares held in the engine's database into a password-encrypted backup
+	/// file. The key shares are verified against the aggregate keys on the State Chain first.
+	/// The password is read from `CF_KEY_SHARE_BACKUP_PASSWORD` or prompted for.
+	ExportKeyShares {
+		/// Path to the engine's key database. The engine must not be running.
+		#[clap(long)]
+		db_path: PathBuf,
+		/// Path of the backup file to create.
+		#[clap(long)]
+		output: PathBuf,
+	},
+	/// Import key shares from a backup created with `export-key-shares` into a fresh database.
+	/// The key shares are verified against the aggregate keys on the State Chain first.
+	ImportKeyShares {
+		/// Path to the new key database.
+		#[clap(long)]
+		db_path: PathBuf,
+		/// Path of the backup file to import.
+		#[clap(long)]
+		input: PathBuf,
+	},
 }
 
 #[derive(Parser, Clone, Debug)]
@@ -246,11 +264,7 @@ impl CfSettings for CLISettings {
 
 	fn validate_settings(&mut self, config_root: &Path) -> Result<(), ConfigError> {
 		self.state_chain.validate_settings()?;
-		self.state_chain.signing_key_file = resolve_settings_path(
-			config_root,
-			&self.state_chain.signing_key_file,
-			Some(PathResolutionExpectation::ExistingFile),
-		)?;
+		self.state_chain.resolve_paths(config_root)?;
 
 		Ok(())
 	}
@@ -329,6 +343,7 @@ mod tests {
 			state_chain_opts: StateChainOptions {
 				state_chain_ws_endpoint: Some("ws://endpoint:1234".to_owned()),
 				state_chain_signing_key_file: Some(PathBuf::from_str("signing_key_file").unwrap()),
+				state_chain_signer_socket: None,
 			},
 
 			cmd: CliCommand::Rotate {}, // Not used in this test
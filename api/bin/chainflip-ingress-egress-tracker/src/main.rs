@@ -1,11 +1,13 @@
-use crate::store::RedisStore;
+use crate::store::{PersistentStore, RedisStore};
 use chainflip_engine::settings::CfSettings;
 use clap::Parser;
 use futures::FutureExt;
 use settings::{DepositTrackerSettings, TrackerOptions};
+use std::net::SocketAddr;
 use store::{Storable, Store};
 use utilities::task_scope;
 
+mod query_server;
 mod settings;
 mod store;
 mod utils;
@@ -20,9 +22,27 @@ async fn start(
 		.try_init()
 		.expect("setting default subscriber failed");
 
-	let client = redis::Client::open(settings.redis_url.clone()).unwrap();
-	let store = RedisStore::new(client.get_multiplexed_tokio_connection().await?);
+	if let Some(persistent_store) = settings.persistent_store.clone() {
+		let store = PersistentStore::open(&persistent_store.db_path)?;
+		query_server::start(
+			scope,
+			SocketAddr::new(persistent_store.query_server_host, persistent_store.query_server_port),
+			store.clone(),
+		)
+		.await?;
+		start_witnessing(scope, settings, store).await
+	} else {
+		let client = redis::Client::open(settings.redis_url.clone()).unwrap();
+		let store = RedisStore::new(client.get_multiplexed_tokio_connection().await?);
+		start_witnessing(scope, settings, store).await
+	}
+}
 
+async fn start_witnessing<S: Store + Clone>(
+	scope: &task_scope::Scope<'_, anyhow::Error>,
+	settings: DepositTrackerSettings,
+	store: S,
+) -> anyhow::Result<()> {
 	let btc_network = witnessing::start(scope, settings.clone(), store.clone())
 		.await?
 		.chainflip_network
@@ -0,0 +1,170 @@
+use crate::store::{Index, PersistentStore, StoredRecord};
+use anyhow::Context;
+use cf_primitives::{ChannelId, ForeignChain};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	server::ServerBuilder,
+};
+use std::net::SocketAddr;
+use tracing::info;
+use utilities::task_scope;
+
+/// The most records returned by a single query. Larger results have to be requested page by page
+/// using the `offset` parameter.
+pub const MAX_PAGE_SIZE: usize = 1000;
+
+/// Identifies a deposit channel in the [Index::Channel] index.
+pub fn channel_index_value(chain: ForeignChain, channel_id: ChannelId) -> String {
+	format!("{chain}:{channel_id}")
+}
+
+/// Hex values are indexed in lower case. Other values, such as base58 addresses, are case
+/// sensitive and are left as they are.
+fn normalize(value: String) -> String {
+	if value.starts_with("0x") || value.starts_with("0X") {
+		value.to_lowercase()
+	} else {
+		value
+	}
+}
+
+/// All queries return records oldest first. They skip the first `offset` records (none by default)
+/// and return at most `limit` records, which defaults to and is capped at [MAX_PAGE_SIZE].
+#[rpc(server, client, namespace = "tracker")]
+pub trait Rpc {
+	/// Every record saved under the given key, e.g. `deposit:Ethereum:0x...`.
+	#[method(name = "history")]
+	async fn history(
+		&self,
+		key: String,
+		offset: Option<usize>,
+		limit: Option<usize>,
+	) -> RpcResult<Vec<StoredRecord>>;
+
+	/// Deposits and mempool transactions sent to the given address.
+	#[method(name = "by_address")]
+	async fn by_address(
+		&self,
+		address: String,
+		offset: Option<usize>,
+		limit: Option<usize>,
+	) -> RpcResult<Vec<StoredRecord>>;
+
+	/// Deposits made to the given deposit channel.
+	#[method(name = "by_channel")]
+	async fn by_channel(
+		&self,
+		chain: ForeignChain,
+		channel_id: ChannelId,
+		offset: Option<usize>,
+		limit: Option<usize>,
+	) -> RpcResult<Vec<StoredRecord>>;
+
+	/// Deposits, broadcasts and mempool transactions with the given transaction hash.
+	#[method(name = "by_tx_hash")]
+	async fn by_tx_hash(
+		&self,
+		tx_hash: String,
+		offset: Option<usize>,
+		limit: Option<usize>,
+	) -> RpcResult<Vec<StoredRecord>>;
+}
+
+pub struct RpcServerImpl {
+	store: PersistentStore,
+}
+
+impl RpcServerImpl {
+	/// Runs the query on the blocking thread pool, so that database reads don't stall the server.
+	async fn query(
+		&self,
+		query: impl FnOnce(&PersistentStore) -> anyhow::Result<Vec<StoredRecord>> + Send + 'static,
+	) -> RpcResult<Vec<StoredRecord>> {
+		let store = self.store.clone();
+		Ok(tokio::task::spawn_blocking(move || query(&store))
+			.await
+			.context("Query task failed")??)
+	}
+
+	async fn search(
+		&self,
+		index: Index,
+		value: String,
+		offset: Option<usize>,
+		limit: Option<usize>,
+	) -> RpcResult<Vec<StoredRecord>> {
+		let (offset, limit) = page(offset, limit);
+		self.query(move |store| store.search(index, &value, offset, limit)).await
+	}
+}
+
+fn page(offset: Option<usize>, limit: Option<usize>) -> (usize, usize) {
+	(offset.unwrap_or_default(), limit.map_or(MAX_PAGE_SIZE, |limit| limit.min(MAX_PAGE_SIZE)))
+}
+
+#[async_trait]
+impl RpcServer for RpcServerImpl {
+	async fn history(
+		&self,
+		key: String,
+		offset: Option<usize>,
+		limit: Option<usize>,
+	) -> RpcResult<Vec<StoredRecord>> {
+		let (offset, limit) = page(offset, limit);
+		self.query(move |store| store.history(&key, offset, limit)).await
+	}
+
+	async fn by_address(
+		&self,
+		address: String,
+		offset: Option<usize>,
+		limit: Option<usize>,
+	) -> RpcResult<Vec<StoredRecord>> {
+		self.search(Index::Address, normalize(address), offset, limit).await
+	}
+
+	async fn by_channel(
+		&self,
+		chain: ForeignChain,
+		channel_id: ChannelId,
+		offset: Option<usize>,
+		limit: Option<usize>,
+	) -> RpcResult<Vec<StoredRecord>> {
+		self.search(Index::Channel, channel_index_value(chain, channel_id), offset, limit)
+			.await
+	}
+
+	async fn by_tx_hash(
+		&self,
+		tx_hash: String,
+		offset: Option<usize>,
+		limit: Option<usize>,
+	) -> RpcResult<Vec<StoredRecord>> {
+		// Transaction hashes are always hex, but Bitcoin ones are commonly given without a prefix.
+		let tx_hash = tx_hash.to_lowercase();
+		let tx_hash = if tx_hash.starts_with("0x") { tx_hash } else { format!("0x{tx_hash}") };
+
+		self.search(Index::TxHash, tx_hash, offset, limit).await
+	}
+}
+
+/// Serves queries over the history kept in the [PersistentStore], over both HTTP and websockets.
+pub async fn start(
+	scope: &task_scope::Scope<'_, anyhow::Error>,
+	address: SocketAddr,
+	store: PersistentStore,
+) -> anyhow::Result<()> {
+	let server = ServerBuilder::default().build(address).await?;
+	let server_addr = server.local_addr()?;
+	let server = server.start(RpcServerImpl { store }.into_rpc())?;
+
+	info!("Query server is listening on {server_addr}.");
+
+	scope.spawn(async move {
+		server.stopped().await;
+		Ok(())
+	});
+
+	Ok(())
+}
@@ -4,7 +4,12 @@ use chainflip_engine::settings::{
 use clap::Parser;
 use config::{Config, ConfigBuilder, ConfigError, Environment, Map, Source, Value};
 use serde::Deserialize;
-use std::{collections::HashMap, env};
+use std::{
+	collections::HashMap,
+	env,
+	net::{IpAddr, Ipv4Addr},
+	path::PathBuf,
+};
 
 #[derive(Parser, Debug, Clone, Default)]
 #[clap(version = env!("SUBSTRATE_CLI_IMPL_VERSION"), version_short = 'v')]
@@ -27,6 +32,12 @@ pub struct TrackerOptions {
 	btc_password: Option<String>,
 	#[clap(long = "redis_url")]
 	redis_url: Option<String>,
+	#[clap(long = "persistent_store.db_path")]
+	persistent_store_db_path: Option<String>,
+	#[clap(long = "persistent_store.query_server_host")]
+	persistent_store_query_server_host: Option<String>,
+	#[clap(long = "persistent_store.query_server_port")]
+	persistent_store_query_server_port: Option<u16>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -36,6 +47,26 @@ pub struct DepositTrackerSettings {
 	pub state_chain_ws_endpoint: String,
 	pub btc: HttpBasicAuthEndpoint,
 	pub redis_url: String,
+	/// If set, witnesses are kept indefinitely in an embedded database instead of Redis.
+	pub persistent_store: Option<PersistentStoreSettings>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct PersistentStoreSettings {
+	pub db_path: PathBuf,
+	/// The query server only accepts local connections unless this is set to a public address.
+	#[serde(default = "default_query_server_host")]
+	pub query_server_host: IpAddr,
+	#[serde(default = "default_query_server_port")]
+	pub query_server_port: u16,
+}
+
+fn default_query_server_host() -> IpAddr {
+	IpAddr::V4(Ipv4Addr::LOCALHOST)
+}
+
+fn default_query_server_port() -> u16 {
+	13337
 }
 
 impl CfSettings for DepositTrackerSettings {
@@ -99,6 +130,21 @@ impl Source for TrackerOptions {
 		insert_command_line_option(&mut map, "btc.basic_auth_user", &self.btc_username);
 		insert_command_line_option(&mut map, "btc.basic_auth_password", &self.btc_password);
 		insert_command_line_option(&mut map, "redis_url", &self.redis_url);
+		insert_command_line_option(
+			&mut map,
+			"persistent_store.db_path",
+			&self.persistent_store_db_path,
+		);
+		insert_command_line_option(
+			&mut map,
+			"persistent_store.query_server_host",
+			&self.persistent_store_query_server_host,
+		);
+		insert_command_line_option(
+			&mut map,
+			"persistent_store.query_server_port",
+			&self.persistent_store_query_server_port,
+		);
 
 		Ok(map)
 	}
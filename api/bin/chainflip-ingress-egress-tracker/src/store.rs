@@ -1,8 +1,12 @@
 use async_trait::async_trait;
 use redis::{aio::MultiplexedConnection, AsyncCommands};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
+mod persistent;
+
+pub use persistent::{PersistentStore, StoredRecord};
+
 #[async_trait]
 pub trait Store: Sync + Send + 'static {
 	type Output: Sync + Send + 'static;
@@ -55,4 +59,29 @@ pub trait Storable: Serialize + Sized + Sync + 'static {
 	fn get_expiry_duration(&self) -> Duration {
 		Self::DEFAULT_EXPIRY_DURATION
 	}
+
+	/// Values, other than the key, under which the item can be looked up. Only stores that keep
+	/// history, such as the [PersistentStore], make use of these.
+	fn get_indexes(&self) -> Vec<(Index, String)> {
+		Vec::new()
+	}
+}
+
+/// Fields by which stored items can be searched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Index {
+	Address,
+	Channel,
+	TxHash,
+}
+
+impl Index {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Index::Address => "address",
+			Index::Channel => "channel",
+			Index::TxHash => "tx_hash",
+		}
+	}
 }
@@ -0,0 +1,359 @@
+use super::{Index, Storable, Store};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use rocksdb::{
+	ColumnFamily, ColumnFamilyDescriptor, Direction, IteratorMode, Options, WriteBatch, DB,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+	path::Path,
+	sync::{Arc, Mutex},
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Column family names
+// Every saved item, keyed by its storable key followed by its sequence number.
+const RECORDS_COLUMN: &str = "records";
+// Keys of the form `index\0value\0record_key`, with empty values.
+const INDEXES_COLUMN: &str = "indexes";
+// This column is for various metadata.
+const METADATA_COLUMN: &str = "metadata";
+
+const NEXT_SEQUENCE_NUMBER_KEY: &[u8] = b"next_sequence_number";
+
+/// Separates the parts of composite keys. Storable keys and index values never contain it.
+const SEPARATOR: u8 = 0;
+
+const SEQUENCE_NUMBER_SIZE: usize = std::mem::size_of::<u64>();
+
+/// An item as it was saved at a given time.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoredRecord {
+	pub key: String,
+	/// Unix timestamp, in seconds, at which the item was saved.
+	pub saved_at: u64,
+	pub value: serde_json::Value,
+}
+
+/// A [Store] backed by an embedded RocksDB database. Unlike the [super::RedisStore], nothing
+/// expires: every saved item is kept as part of the history of its key, and can be searched for
+/// using the [Index]es it provides.
+#[derive(Clone)]
+pub struct PersistentStore {
+	db: Arc<DB>,
+	/// Held for the duration of each write, so that sequence numbers are never reused.
+	next_sequence_number: Arc<Mutex<u64>>,
+}
+
+impl PersistentStore {
+	pub fn open(db_path: &Path) -> Result<Self> {
+		let column_families = [RECORDS_COLUMN, INDEXES_COLUMN, METADATA_COLUMN]
+			.map(|name| ColumnFamilyDescriptor::new(name, Options::default()));
+
+		let open_options = {
+			let mut options = Options::default();
+			options.create_missing_column_families(true);
+			options.create_if_missing(true);
+			options
+		};
+
+		let db = DB::open_cf_descriptors(&open_options, db_path, column_families)
+			.with_context(|| format!("Failed to open database at: {}", db_path.display()))?;
+
+		let next_sequence_number = db
+			.get_cf(get_column_handle(&db, METADATA_COLUMN), NEXT_SEQUENCE_NUMBER_KEY)?
+			.map(|bytes| {
+				bytes
+					.try_into()
+					.map(u64::from_be_bytes)
+					.map_err(|_| anyhow::anyhow!("Invalid sequence number in database"))
+			})
+			.transpose()?
+			.unwrap_or_default();
+
+		Ok(Self {
+			db: Arc::new(db),
+			next_sequence_number: Arc::new(Mutex::new(next_sequence_number)),
+		})
+	}
+
+	/// The records saved under the given key, oldest first, skipping the first `offset` of them
+	/// and returning at most `limit`.
+	pub fn history(&self, key: &str, offset: usize, limit: usize) -> Result<Vec<StoredRecord>> {
+		self.records_for_prefix(&key_prefix(key)).skip(offset).take(limit).collect()
+	}
+
+	/// The records with the given index value, oldest first, skipping the first `offset` of them
+	/// and returning at most `limit`.
+	pub fn search(
+		&self,
+		index: Index,
+		value: &str,
+		offset: usize,
+		limit: usize,
+	) -> Result<Vec<StoredRecord>> {
+		let prefix = index_prefix(index, value);
+
+		// Index entries are ordered by record key rather than by age, so they are sorted before
+		// the page is selected. Only the records on the page are read.
+		let mut record_keys = prefix_iterator(&self.db, INDEXES_COLUMN, &prefix)
+			.map(|entry| {
+				let (index_key, _) = entry?;
+				let record_key = index_key[prefix.len()..].to_vec();
+				Ok((sequence_number(&record_key)?, record_key))
+			})
+			.collect::<Result<Vec<(u64, Vec<u8>)>>>()?;
+
+		record_keys.sort_by_key(|(sequence_number, _)| *sequence_number);
+
+		record_keys
+			.into_iter()
+			.skip(offset)
+			.take(limit)
+			.map(|(_, record_key)| {
+				let record = self
+					.db
+					.get_cf(get_column_handle(&self.db, RECORDS_COLUMN), record_key)?
+					.context("Index refers to a missing record")?;
+
+				Ok(serde_json::from_slice(&record)?)
+			})
+			.collect()
+	}
+
+	fn latest(&self, key: &str) -> Result<Option<StoredRecord>> {
+		let prefix = key_prefix(key);
+		let upper_bound = [&prefix[..], &[u8::MAX; SEQUENCE_NUMBER_SIZE]].concat();
+
+		self.db
+			.iterator_cf(
+				get_column_handle(&self.db, RECORDS_COLUMN),
+				IteratorMode::From(&upper_bound, Direction::Reverse),
+			)
+			.next()
+			.transpose()?
+			.filter(|(record_key, _)| record_key.starts_with(&prefix))
+			.map(|(_, record)| serde_json::from_slice(&record).map_err(Into::into))
+			.transpose()
+	}
+
+	fn append<S: Storable>(&self, storable: &S, value: serde_json::Value) -> Result<()> {
+		let key = storable.get_key();
+		let record = StoredRecord {
+			key: key.clone(),
+			saved_at: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+			value,
+		};
+
+		let mut next_sequence_number =
+			self.next_sequence_number.lock().expect("Lock should not be poisoned");
+		let record_key = [&key_prefix(&key)[..], &next_sequence_number.to_be_bytes()].concat();
+
+		let mut batch = WriteBatch::default();
+		batch.put_cf(
+			get_column_handle(&self.db, RECORDS_COLUMN),
+			&record_key,
+			serde_json::to_vec(&record)?,
+		);
+		for (index, index_value) in storable.get_indexes() {
+			batch.put_cf(
+				get_column_handle(&self.db, INDEXES_COLUMN),
+				[&index_prefix(index, &index_value)[..], &record_key].concat(),
+				[],
+			);
+		}
+		batch.put_cf(
+			get_column_handle(&self.db, METADATA_COLUMN),
+			NEXT_SEQUENCE_NUMBER_KEY,
+			(*next_sequence_number + 1).to_be_bytes(),
+		);
+		self.db.write(batch).context("Failed to write record to database.")?;
+
+		*next_sequence_number += 1;
+
+		Ok(())
+	}
+
+	fn records_for_prefix<'a>(
+		&'a self,
+		prefix: &'a [u8],
+	) -> impl Iterator<Item = Result<StoredRecord>> + 'a {
+		prefix_iterator(&self.db, RECORDS_COLUMN, prefix).map(|entry| {
+			let (_, record) = entry?;
+			Ok(serde_json::from_slice(&record)?)
+		})
+	}
+}
+
+#[async_trait]
+impl Store for PersistentStore {
+	type Output = ();
+
+	async fn save_to_array<S: Storable>(&mut self, storable: &S) -> Result<()> {
+		self.append(storable, serde_json::to_value(storable)?)
+	}
+
+	/// Singletons are overwritten in the [super::RedisStore], so only changes to their value are
+	/// recorded here.
+	async fn save_singleton<S: Storable>(&mut self, storable: &S) -> Result<()> {
+		let value = serde_json::to_value(storable)?;
+
+		if self.latest(&storable.get_key())?.map_or(true, |latest| latest.value != value) {
+			self.append(storable, value)?;
+		}
+
+		Ok(())
+	}
+}
+
+fn key_prefix(key: &str) -> Vec<u8> {
+	[key.as_bytes(), &[SEPARATOR]].concat()
+}
+
+fn index_prefix(index: Index, value: &str) -> Vec<u8> {
+	[index.as_str().as_bytes(), &[SEPARATOR], value.as_bytes(), &[SEPARATOR]].concat()
+}
+
+fn sequence_number(record_key: &[u8]) -> Result<u64> {
+	record_key
+		.len()
+		.checked_sub(SEQUENCE_NUMBER_SIZE)
+		.and_then(|start| record_key[start..].try_into().ok())
+		.map(u64::from_be_bytes)
+		.context("Invalid record key")
+}
+
+fn prefix_iterator<'a>(
+	db: &'a DB,
+	column_name: &str,
+	prefix: &'a [u8],
+) -> impl Iterator<Item = Result<(Box<[u8]>, Box<[u8]>)>> + 'a {
+	db.iterator_cf(
+		get_column_handle(db, column_name),
+		IteratorMode::From(prefix, Direction::Forward),
+	)
+	.map(|entry| entry.map_err(Into::into))
+	.take_while(move |entry| entry.as_ref().map_or(true, |(key, _)| key.starts_with(prefix)))
+}
+
+fn get_column_handle<'a>(db: &'a DB, column_name: &str) -> &'a ColumnFamily {
+	db.cf_handle(column_name)
+		.unwrap_or_else(|| panic!("Should get column family handle for {column_name}"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Serialize)]
+	struct TestItem {
+		#[serde(skip_serializing)]
+		address: String,
+		tx_hash: String,
+		confirmations: u32,
+	}
+
+	impl Storable for TestItem {
+		fn get_key(&self) -> String {
+			format!("test:{}", self.address)
+		}
+
+		fn get_indexes(&self) -> Vec<(Index, String)> {
+			vec![(Index::Address, self.address.clone()), (Index::TxHash, self.tx_hash.clone())]
+		}
+	}
+
+	fn item(address: &str, tx_hash: &str, confirmations: u32) -> TestItem {
+		TestItem { address: address.to_string(), tx_hash: tx_hash.to_string(), confirmations }
+	}
+
+	fn values(records: Vec<StoredRecord>) -> Vec<serde_json::Value> {
+		records.into_iter().map(|record| record.value).collect()
+	}
+
+	#[tokio::test]
+	async fn keeps_history_and_indexes() {
+		let db_dir = tempfile::tempdir().unwrap();
+		let mut store = PersistentStore::open(db_dir.path()).unwrap();
+
+		store.save_to_array(&item("a", "0x01", 0)).await.unwrap();
+		store.save_to_array(&item("ab", "0x02", 0)).await.unwrap();
+		store.save_to_array(&item("a", "0x03", 0)).await.unwrap();
+
+		assert_eq!(
+			values(store.history("test:a", 0, usize::MAX).unwrap()),
+			vec![
+				serde_json::json!({ "tx_hash": "0x01", "confirmations": 0 }),
+				serde_json::json!({ "tx_hash": "0x03", "confirmations": 0 }),
+			]
+		);
+		assert_eq!(store.search(Index::Address, "a", 0, usize::MAX).unwrap().len(), 2);
+		assert_eq!(store.search(Index::Address, "ab", 0, usize::MAX).unwrap().len(), 1);
+		assert_eq!(
+			values(store.search(Index::TxHash, "0x03", 0, usize::MAX).unwrap()),
+			vec![serde_json::json!({ "tx_hash": "0x03", "confirmations": 0 })]
+		);
+		assert!(store.search(Index::Channel, "a", 0, usize::MAX).unwrap().is_empty());
+	}
+
+	#[tokio::test]
+	async fn singletons_only_record_changes() {
+		let db_dir = tempfile::tempdir().unwrap();
+		let mut store = PersistentStore::open(db_dir.path()).unwrap();
+
+		store.save_singleton(&item("a", "0x01", 0)).await.unwrap();
+		store.save_singleton(&item("a", "0x01", 0)).await.unwrap();
+		store.save_singleton(&item("a", "0x01", 1)).await.unwrap();
+
+		assert_eq!(
+			values(store.history("test:a", 0, usize::MAX).unwrap()),
+			vec![
+				serde_json::json!({ "tx_hash": "0x01", "confirmations": 0 }),
+				serde_json::json!({ "tx_hash": "0x01", "confirmations": 1 }),
+			]
+		);
+	}
+
+	#[tokio::test]
+	async fn results_are_paged() {
+		let db_dir = tempfile::tempdir().unwrap();
+		let mut store = PersistentStore::open(db_dir.path()).unwrap();
+
+		// Saved under different keys, so that the index entries are not in the order they were
+		// saved in.
+		store.save_to_array(&item("b", "0x01", 0)).await.unwrap();
+		store.save_to_array(&item("a", "0x01", 1)).await.unwrap();
+		store.save_to_array(&item("b", "0x01", 2)).await.unwrap();
+
+		assert_eq!(
+			values(store.search(Index::TxHash, "0x01", 1, 1).unwrap()),
+			vec![serde_json::json!({ "tx_hash": "0x01", "confirmations": 1 })]
+		);
+		assert_eq!(
+			values(store.history("test:b", 1, 5).unwrap()),
+			vec![serde_json::json!({ "tx_hash": "0x01", "confirmations": 2 })]
+		);
+		assert!(store.search(Index::TxHash, "0x01", 3, 5).unwrap().is_empty());
+	}
+
+	#[tokio::test]
+	async fn sequence_numbers_survive_reopening() {
+		let db_dir = tempfile::tempdir().unwrap();
+
+		{
+			let mut store = PersistentStore::open(db_dir.path()).unwrap();
+			store.save_to_array(&item("a", "0x01", 0)).await.unwrap();
+		}
+
+		let mut store = PersistentStore::open(db_dir.path()).unwrap();
+		store.save_to_array(&item("a", "0x02", 0)).await.unwrap();
+
+		assert_eq!(
+			values(store.search(Index::Address, "a", 0, usize::MAX).unwrap()),
+			vec![
+				serde_json::json!({ "tx_hash": "0x01", "confirmations": 0 }),
+				serde_json::json!({ "tx_hash": "0x02", "confirmations": 0 }),
+			]
+		);
+	}
+}
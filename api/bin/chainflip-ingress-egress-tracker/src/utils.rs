@@ -1,5 +1,8 @@
-use cf_chains::instances::{ChainInstanceAlias, ChainInstanceFor};
-use cf_primitives::BroadcastId;
+use cf_chains::{
+	instances::{ChainInstanceAlias, ChainInstanceFor},
+	Chain,
+};
+use cf_primitives::{BroadcastId, ChannelId};
 use chainflip_engine::state_chain_observer::client::{
 	chain_api::ChainApi, storage_api::StorageApi, STATE_CHAIN_CONNECTION,
 };
@@ -34,3 +37,23 @@ where
 
 	id
 }
+
+pub async fn get_channel_id<C, StateChainClient>(
+	state_chain_client: &StateChainClient,
+	deposit_address: &C::ChainAccount,
+) -> Option<ChannelId>
+where
+	state_chain_runtime::Runtime:
+		pallet_cf_ingress_egress::Config<ChainInstanceFor<C>, TargetChain = C>,
+	C: Chain + ChainInstanceAlias,
+	StateChainClient: StorageApi + ChainApi + 'static + Send + Sync,
+{
+	state_chain_client
+		.storage_map_entry::<pallet_cf_ingress_egress::DepositChannelLookup<
+			state_chain_runtime::Runtime,
+			ChainInstanceFor<C>,
+		>>(state_chain_client.latest_unfinalized_block().hash, deposit_address)
+		.await
+		.expect(STATE_CHAIN_CONNECTION)
+		.map(|details| details.deposit_channel.channel_id)
+}
@@ -5,11 +5,12 @@ mod eth;
 pub mod state_chain;
 
 use self::state_chain::handle_call;
-use crate::{settings::DepositTrackerSettings, store::RedisStore};
+use crate::{settings::DepositTrackerSettings, store::Store};
 use anyhow::anyhow;
 use cf_chains::dot::PolkadotHash;
 use cf_primitives::{chains::assets::eth::Asset, NetworkEnvironment};
 use chainflip_engine::{
+	health::HealthReporter,
 	state_chain_observer::{
 		self,
 		client::{
@@ -107,10 +108,10 @@ async fn get_env_parameters(state_chain_client: &StateChainClient<()>) -> Enviro
 	}
 }
 
-pub(super) async fn start(
+pub(super) async fn start<S: Store + Clone>(
 	scope: &task_scope::Scope<'_, anyhow::Error>,
 	settings: DepositTrackerSettings,
-	store: RedisStore,
+	store: S,
 ) -> anyhow::Result<EnvironmentParameters> {
 	let (state_chain_stream, unfinalized_chain_stream, state_chain_client) = {
 		state_chain_observer::client::StateChainClient::connect_without_account(
@@ -126,6 +127,9 @@ pub(super) async fn start(
 	let epoch_source =
 		EpochSource::builder(scope, state_chain_stream.clone(), state_chain_client.clone()).await;
 
+	// The tracker doesn't serve health reports, so nothing reads what the witnessers report.
+	let health_reporter = HealthReporter::default();
+
 	let witness_call = {
 		let state_chain_client = state_chain_client.clone();
 		move |call: state_chain_runtime::RuntimeCall, _epoch_index| {
@@ -149,6 +153,7 @@ pub(super) async fn start(
 		env_params.clone(),
 		epoch_source.clone(),
 		witness_call.clone(),
+		health_reporter.clone(),
 	)
 	.await?;
 
@@ -160,6 +165,7 @@ pub(super) async fn start(
 		state_chain_client.clone(),
 		unfinalized_chain_stream.clone(),
 		epoch_source.clone(),
+		health_reporter.clone(),
 	)
 	.await?;
 
@@ -171,6 +177,7 @@ pub(super) async fn start(
 		state_chain_client.clone(),
 		unfinalized_chain_stream,
 		epoch_source,
+		health_reporter,
 	)
 	.await?;
 
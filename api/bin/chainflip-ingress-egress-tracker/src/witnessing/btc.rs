@@ -3,6 +3,7 @@ use std::sync::Arc;
 use cf_primitives::EpochIndex;
 use chainflip_engine::{
 	btc::retry_rpc::{BtcRetryRpcApi, BtcRetryRpcClient},
+	health::HealthReporter,
 	settings::NodeContainer,
 	state_chain_observer::client::{
 		stream_api::{StreamApi, UNFINALIZED},
@@ -27,6 +28,7 @@ pub(super) async fn start<ProcessCall, ProcessingFut>(
 	state_chain_client: Arc<StateChainClient<()>>,
 	state_chain_stream: impl StreamApi<UNFINALIZED> + Clone,
 	epoch_source: EpochSourceBuilder<'_, '_, StateChainClient<()>, (), ()>,
+	health_reporter: HealthReporter,
 ) -> anyhow::Result<()>
 where
 	ProcessCall: Fn(state_chain_runtime::RuntimeCall, EpochIndex) -> ProcessingFut
@@ -38,8 +40,9 @@ where
 {
 	let btc_client = BtcRetryRpcClient::new(
 		scope,
-		NodeContainer { primary: settings.btc, backup: None },
+		NodeContainer::primary_only(settings.btc),
 		env_params.chainflip_network.into(),
+		health_reporter.clone(),
 	)
 	.await?;
 
@@ -64,7 +67,7 @@ where
 		.egress_items(scope, state_chain_stream, state_chain_client)
 		.await
 		.then(move |epoch, header| process_egress(epoch, header, witness_call.clone()))
-		.logging("witnessing")
+		.logging("witnessing", health_reporter)
 		.spawn(scope);
 
 	Ok(())
@@ -1,4 +1,4 @@
-use crate::{Storable, Store};
+use crate::{store::Index, Storable, Store};
 use bitcoin::{BlockHash, Network, ScriptBuf, Transaction, Txid};
 use cf_chains::btc::BitcoinNetwork;
 use cf_primitives::ForeignChain;
@@ -44,6 +44,13 @@ impl Storable for QueryResult {
 	fn get_expiry_duration(&self) -> Duration {
 		Duration::from_secs(60)
 	}
+
+	fn get_indexes(&self) -> Vec<(Index, String)> {
+		vec![
+			(Index::Address, self.destination.clone()),
+			(Index::TxHash, format!("0x{}", self.tx_hash)),
+		]
+	}
 }
 
 fn script_to_address(script: &ScriptBuf, btc_network: Network) -> Option<String> {
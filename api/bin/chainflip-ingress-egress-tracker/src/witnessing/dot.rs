@@ -3,6 +3,7 @@ use std::sync::Arc;
 use cf_primitives::EpochIndex;
 use chainflip_engine::{
 	dot::retry_rpc::DotRetryRpcClient,
+	health::HealthReporter,
 	settings::NodeContainer,
 	state_chain_observer::client::{
 		storage_api::StorageApi,
@@ -29,6 +30,7 @@ pub(super) async fn start<ProcessCall, ProcessingFut>(
 	state_chain_client: Arc<StateChainClient<()>>,
 	state_chain_stream: impl StreamApi<UNFINALIZED> + Clone,
 	epoch_source: EpochSourceBuilder<'_, '_, StateChainClient<()>, (), ()>,
+	health_reporter: HealthReporter,
 ) -> anyhow::Result<()>
 where
 	ProcessCall: Fn(state_chain_runtime::RuntimeCall, EpochIndex) -> ProcessingFut
@@ -40,8 +42,9 @@ where
 {
 	let dot_client = DotRetryRpcClient::new(
 		scope,
-		NodeContainer { primary: settings.dot, backup: None },
+		NodeContainer::primary_only(settings.dot),
 		env_params.dot_genesis_hash,
+		health_reporter.clone(),
 	)?;
 
 	let epoch_source = epoch_source
@@ -76,7 +79,7 @@ where
 		.then(move |epoch, header| {
 			process_egress(epoch, header, witness_call.clone(), dot_client.clone())
 		})
-		.logging("witnessing")
+		.logging("witnessing", health_reporter)
 		.spawn(scope);
 
 	Ok(())
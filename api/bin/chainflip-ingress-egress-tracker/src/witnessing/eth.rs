@@ -6,6 +6,7 @@ use utilities::task_scope;
 
 use chainflip_engine::{
 	evm::{retry_rpc::EvmRetryRpcClient, rpc::EvmRpcClient},
+	health::HealthReporter,
 	settings::NodeContainer,
 	state_chain_observer::client::{
 		chain_api::ChainApi,
@@ -34,6 +35,7 @@ pub(super) async fn start<ProcessCall, ProcessingFut>(
 	env_params: EnvironmentParameters,
 	epoch_source: EpochSourceBuilder<'_, '_, StateChainClient<()>, (), ()>,
 	witness_call: ProcessCall,
+	health_reporter: HealthReporter,
 ) -> anyhow::Result<()>
 where
 	ProcessCall: Fn(state_chain_runtime::RuntimeCall, cf_primitives::EpochIndex) -> ProcessingFut
@@ -44,7 +46,7 @@ where
 	ProcessingFut: futures::Future<Output = ()> + Send + 'static,
 {
 	let eth_client = {
-		let nodes = NodeContainer { primary: settings.eth.clone(), backup: None };
+		let nodes = NodeContainer::primary_only(settings.eth.clone());
 
 		EvmRetryRpcClient::<EvmRpcClient>::new(
 			scope,
@@ -54,6 +56,7 @@ where
 			"eth_subscribe",
 			"Ethereum",
 			Ethereum::WITNESS_PERIOD,
+			health_reporter.clone(),
 		)?
 	};
 
@@ -76,7 +79,7 @@ where
 			env_params.usdc_contract_address,
 		)
 		.await?
-		.logging("witnessing USDCDeposits")
+		.logging("witnessing USDCDeposits", health_reporter.clone())
 		.spawn(scope);
 
 	eth_source_deposit_addresses
@@ -88,7 +91,7 @@ where
 			env_params.flip_contract_address,
 		)
 		.await?
-		.logging("witnessing FlipDeposits")
+		.logging("witnessing FlipDeposits", health_reporter.clone())
 		.spawn(scope);
 
 	eth_source_deposit_addresses
@@ -100,7 +103,7 @@ where
 			env_params.usdt_contract_address,
 		)
 		.await?
-		.logging("witnessing USDTDeposits")
+		.logging("witnessing USDTDeposits", health_reporter.clone())
 		.spawn(scope);
 
 	eth_source_deposit_addresses
@@ -113,7 +116,7 @@ where
 			env_params.eth_vault_address,
 		)
 		.await
-		.logging("witnessing EthereumDeposits")
+		.logging("witnessing EthereumDeposits", health_reporter.clone())
 		.spawn(scope);
 
 	eth_source
@@ -126,7 +129,7 @@ where
 			cf_primitives::ForeignChain::Ethereum,
 			env_params.supported_erc20_tokens.clone(),
 		)
-		.logging("witnessing Vault")
+		.logging("witnessing Vault", health_reporter.clone())
 		.spawn(scope);
 
 	let key_manager_address = state_chain_client
@@ -139,7 +142,7 @@ where
 	eth_source
 		.clone()
 		.key_manager_witnessing(witness_call.clone(), eth_client.clone(), key_manager_address)
-		.logging("witnessing KeyManager")
+		.logging("witnessing KeyManager", health_reporter)
 		.spawn(scope);
 
 	Ok(())
@@ -1,14 +1,16 @@
 use crate::{
-	store::{Storable, Store},
-	utils::{get_broadcast_id, hex_encode_bytes},
+	query_server::channel_index_value,
+	store::{Index, Storable, Store},
+	utils::{get_broadcast_id, get_channel_id, hex_encode_bytes},
 };
 use cf_chains::{
 	address::ToHumanreadableAddress,
 	dot::PolkadotTransactionId,
 	evm::{SchnorrVerificationComponents, H256},
-	AnyChain, Arbitrum, Bitcoin, Chain, Ethereum, Polkadot,
+	instances::{ChainInstanceAlias, ChainInstanceFor},
+	AnyChain, Arbitrum, Assethub, Bitcoin, Chain, Ethereum, Polkadot,
 };
-use cf_primitives::{BroadcastId, ForeignChain, NetworkEnvironment};
+use cf_primitives::{BroadcastId, ChannelId, ForeignChain, NetworkEnvironment};
 use chainflip_engine::state_chain_observer::client::{
 	chain_api::ChainApi, storage_api::StorageApi,
 };
@@ -20,6 +22,12 @@ use utilities::{rpc::NumberOrHex, ArrayCollect};
 #[derive(Debug)]
 struct BitcoinHash(pub H256);
 
+impl BitcoinHash {
+	fn to_hex(&self) -> String {
+		hex_encode_bytes(&self.0.to_fixed_bytes().into_iter().rev().collect::<Vec<_>>())
+	}
+}
+
 impl Serialize for BitcoinHash {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
@@ -47,6 +55,7 @@ enum TransactionRef {
 	Ethereum { hash: H256 },
 	Polkadot { transaction_id: PolkadotTransactionId },
 	Arbitrum { hash: H256 },
+	Assethub { transaction_id: PolkadotTransactionId },
 }
 
 #[derive(Serialize)]
@@ -56,6 +65,7 @@ enum TransactionId {
 	Ethereum { signature: SchnorrVerificationComponents },
 	Polkadot { signature: DotSignature },
 	Arbitrum { signature: SchnorrVerificationComponents },
+	Assethub { signature: DotSignature },
 }
 
 #[derive(Serialize)]
@@ -65,6 +75,10 @@ enum WitnessInformation {
 		deposit_chain_block_height: <AnyChain as Chain>::ChainBlockNumber,
 		#[serde(skip_serializing)]
 		deposit_address: String,
+		#[serde(skip_serializing)]
+		channel_id: Option<ChannelId>,
+		#[serde(skip_serializing)]
+		tx_hashes: Vec<String>,
 		amount: NumberOrHex,
 		asset: cf_chains::assets::any::Asset,
 	},
@@ -85,6 +99,7 @@ impl WitnessInformation {
 				TransactionId::Ethereum { .. } => ForeignChain::Ethereum,
 				TransactionId::Polkadot { .. } => ForeignChain::Polkadot,
 				TransactionId::Arbitrum { .. } => ForeignChain::Arbitrum,
+				TransactionId::Assethub { .. } => ForeignChain::Assethub,
 			},
 		}
 	}
@@ -110,15 +125,49 @@ impl Storable for WitnessInformation {
 			_ => Self::DEFAULT_EXPIRY_DURATION,
 		}
 	}
+
+	fn get_indexes(&self) -> Vec<(Index, String)> {
+		let chain = self.to_foreign_chain();
+
+		match self {
+			Self::Deposit { deposit_address, channel_id, tx_hashes, .. } =>
+				std::iter::once((Index::Address, deposit_address.clone()))
+					.chain(
+						channel_id.map(|channel_id| {
+							(Index::Channel, channel_index_value(chain, channel_id))
+						}),
+					)
+					.chain(tx_hashes.iter().map(|tx_hash| (Index::TxHash, tx_hash.clone())))
+					.collect(),
+			Self::Broadcast { tx_ref, .. } => match tx_ref {
+				TransactionRef::Bitcoin { hash } => vec![(Index::TxHash, hash.to_hex())],
+				TransactionRef::Ethereum { hash } | TransactionRef::Arbitrum { hash } =>
+					vec![(Index::TxHash, hex_encode_bytes(hash.as_bytes()))],
+				TransactionRef::Polkadot { .. } | TransactionRef::Assethub { .. } => vec![],
+			},
+		}
+	}
 }
 
-type DepositInfo<T> = (DepositWitness<T>, <T as Chain>::ChainBlockNumber, NetworkEnvironment);
+type DepositInfo<T> =
+	(DepositWitness<T>, <T as Chain>::ChainBlockNumber, Option<ChannelId>, NetworkEnvironment);
+
+fn evm_tx_hashes(deposit_details: cf_chains::evm::DepositDetails) -> Vec<String> {
+	deposit_details
+		.tx_hashes
+		.unwrap_or_default()
+		.iter()
+		.map(|tx_hash| hex_encode_bytes(tx_hash.as_bytes()))
+		.collect()
+}
 
 impl From<DepositInfo<Ethereum>> for WitnessInformation {
-	fn from((value, height, _): DepositInfo<Ethereum>) -> Self {
+	fn from((value, height, channel_id, _): DepositInfo<Ethereum>) -> Self {
 		Self::Deposit {
 			deposit_chain_block_height: height,
 			deposit_address: hex_encode_bytes(value.deposit_address.as_bytes()),
+			channel_id,
+			tx_hashes: evm_tx_hashes(value.deposit_details),
 			amount: value.amount.into(),
 			asset: value.asset.into(),
 		}
@@ -126,10 +175,12 @@ impl From<DepositInfo<Ethereum>> for WitnessInformation {
 }
 
 impl From<DepositInfo<Bitcoin>> for WitnessInformation {
-	fn from((value, height, network): DepositInfo<Bitcoin>) -> Self {
+	fn from((value, height, channel_id, network): DepositInfo<Bitcoin>) -> Self {
 		Self::Deposit {
 			deposit_chain_block_height: height,
 			deposit_address: value.deposit_address.to_humanreadable(network),
+			channel_id,
+			tx_hashes: vec![BitcoinHash(value.deposit_details.tx_id).to_hex()],
 			amount: value.amount.into(),
 			asset: value.asset.into(),
 		}
@@ -137,10 +188,13 @@ impl From<DepositInfo<Bitcoin>> for WitnessInformation {
 }
 
 impl From<DepositInfo<Polkadot>> for WitnessInformation {
-	fn from((value, height, _): DepositInfo<Polkadot>) -> Self {
+	fn from((value, height, channel_id, _): DepositInfo<Polkadot>) -> Self {
 		Self::Deposit {
 			deposit_chain_block_height: height as u64,
 			deposit_address: hex_encode_bytes(value.deposit_address.aliased_ref()),
+			channel_id,
+			// Polkadot deposits are identified by their extrinsic index rather than a hash.
+			tx_hashes: vec![],
 			amount: value.amount.into(),
 			asset: value.asset.into(),
 		}
@@ -148,29 +202,59 @@ impl From<DepositInfo<Polkadot>> for WitnessInformation {
 }
 
 impl From<DepositInfo<Arbitrum>> for WitnessInformation {
-	fn from((value, height, _): DepositInfo<Arbitrum>) -> Self {
+	fn from((value, height, channel_id, _): DepositInfo<Arbitrum>) -> Self {
 		Self::Deposit {
 			deposit_chain_block_height: height,
 			deposit_address: hex_encode_bytes(value.deposit_address.as_bytes()),
+			channel_id,
+			tx_hashes: evm_tx_hashes(value.deposit_details),
 			amount: value.amount.into(),
 			asset: value.asset.into(),
 		}
 	}
 }
 
-async fn save_deposit_witnesses<S: Store, C: Chain>(
+impl From<DepositInfo<Assethub>> for WitnessInformation {
+	fn from((value, height, channel_id, _): DepositInfo<Assethub>) -> Self {
+		Self::Deposit {
+			deposit_chain_block_height: height as u64,
+			deposit_address: hex_encode_bytes(value.deposit_address.aliased_ref()),
+			channel_id,
+			// Asset Hub deposits are identified by their extrinsic index rather than a hash.
+			tx_hashes: vec![],
+			amount: value.amount.into(),
+			asset: value.asset.into(),
+		}
+	}
+}
+
+async fn save_deposit_witnesses<S, C, StateChainClient>(
 	store: &mut S,
 	deposit_witnesses: Vec<DepositWitness<C>>,
 	block_height: <C as Chain>::ChainBlockNumber,
 	chainflip_network: NetworkEnvironment,
+	state_chain_client: &StateChainClient,
 ) -> anyhow::Result<()>
 where
-	WitnessInformation:
-		From<(DepositWitness<C>, <C as Chain>::ChainBlockNumber, NetworkEnvironment)>,
+	S: Store,
+	C: Chain + ChainInstanceAlias,
+	StateChainClient: StorageApi + ChainApi + 'static + Send + Sync,
+	state_chain_runtime::Runtime:
+		pallet_cf_ingress_egress::Config<ChainInstanceFor<C>, TargetChain = C>,
+	WitnessInformation: From<DepositInfo<C>>,
 {
 	for witness in deposit_witnesses {
+		let channel_id =
+			get_channel_id::<C, StateChainClient>(state_chain_client, &witness.deposit_address)
+				.await;
+
 		store
-			.save_to_array(&WitnessInformation::from((witness, block_height, chainflip_network)))
+			.save_to_array(&WitnessInformation::from((
+				witness,
+				block_height,
+				channel_id,
+				chainflip_network,
+			)))
 			.await?;
 	}
 
@@ -196,37 +280,66 @@ where
 			deposit_witnesses,
 			block_height,
 		}) =>
-			save_deposit_witnesses(store, deposit_witnesses, block_height, chainflip_network)
-				.await?,
+			save_deposit_witnesses(
+				store,
+				deposit_witnesses,
+				block_height,
+				chainflip_network,
+				state_chain_client,
+			)
+			.await?,
 		BitcoinIngressEgress(IngressEgressCall::process_deposits {
 			deposit_witnesses,
 			block_height,
 		}) =>
-			save_deposit_witnesses(store, deposit_witnesses, block_height, chainflip_network)
-				.await?,
+			save_deposit_witnesses(
+				store,
+				deposit_witnesses,
+				block_height,
+				chainflip_network,
+				state_chain_client,
+			)
+			.await?,
 		PolkadotIngressEgress(IngressEgressCall::process_deposits {
 			deposit_witnesses,
 			block_height,
 		}) =>
-			for witness in deposit_witnesses as Vec<DepositWitness<Polkadot>> {
-				store
-					.save_to_array(&WitnessInformation::from((
-						witness,
-						block_height,
-						chainflip_network,
-					)))
-					.await?;
-			},
+			save_deposit_witnesses(
+				store,
+				deposit_witnesses,
+				block_height,
+				chainflip_network,
+				state_chain_client,
+			)
+			.await?,
 		ArbitrumIngressEgress(IngressEgressCall::process_deposits {
 			deposit_witnesses,
 			block_height,
 		}) =>
-			save_deposit_witnesses(store, deposit_witnesses, block_height, chainflip_network)
-				.await?,
+			save_deposit_witnesses(
+				store,
+				deposit_witnesses,
+				block_height,
+				chainflip_network,
+				state_chain_client,
+			)
+			.await?,
 		SolanaIngressEgress(IngressEgressCall::process_deposits {
 			deposit_witnesses: _,
 			block_height: _,
 		}) => todo!(),
+		AssethubIngressEgress(IngressEgressCall::process_deposits {
+			deposit_witnesses,
+			block_height,
+		}) =>
+			save_deposit_witnesses(
+				store,
+				deposit_witnesses,
+				block_height,
+				chainflip_network,
+				state_chain_client,
+			)
+			.await?,
 		EthereumBroadcaster(BroadcastCall::transaction_succeeded {
 			tx_out_id,
 			transaction_ref,
@@ -310,12 +423,34 @@ where
 			transaction_ref: _,
 			..
 		}) => todo!(),
+		AssethubBroadcaster(BroadcastCall::transaction_succeeded {
+			tx_out_id,
+			transaction_ref,
+			..
+		}) => {
+			let broadcast_id =
+				get_broadcast_id::<Assethub, StateChainClient>(state_chain_client, &tx_out_id)
+					.await;
+
+			if let Some(broadcast_id) = broadcast_id {
+				store
+					.save_singleton(&WitnessInformation::Broadcast {
+						broadcast_id,
+						tx_out_id: TransactionId::Assethub {
+							signature: DotSignature(*tx_out_id.aliased_ref()),
+						},
+						tx_ref: TransactionRef::Assethub { transaction_id: transaction_ref },
+					})
+					.await?;
+			}
+		},
 
 		EthereumIngressEgress(_) |
 		BitcoinIngressEgress(_) |
 		PolkadotIngressEgress(_) |
 		ArbitrumIngressEgress(_) |
 		SolanaIngressEgress(_) |
+		AssethubIngressEgress(_) |
 		System(_) |
 		Timestamp(_) |
 		Environment(_) |
@@ -335,11 +470,13 @@ where
 		PolkadotChainTracking(_) |
 		ArbitrumChainTracking(_) |
 		SolanaChainTracking(_) |
+		AssethubChainTracking(_) |
 		EthereumVault(_) |
 		PolkadotVault(_) |
 		BitcoinVault(_) |
 		ArbitrumVault(_) |
 		SolanaVault(_) |
+		AssethubVault(_) |
 		EvmThresholdSigner(_) |
 		PolkadotThresholdSigner(_) |
 		BitcoinThresholdSigner(_) |
@@ -349,6 +486,7 @@ where
 		BitcoinBroadcaster(_) |
 		ArbitrumBroadcaster(_) |
 		SolanaBroadcaster(_) |
+		AssethubBroadcaster(_) |
 		Swapping(_) |
 		LiquidityProvider(_) |
 		LiquidityPools(_) => {},
@@ -508,6 +646,19 @@ mod tests {
 		client
 	}
 
+	fn expect_no_channel<C: Chain + ChainInstanceAlias>(client: &mut MockStateChainClient)
+	where
+		state_chain_runtime::Runtime:
+			pallet_cf_ingress_egress::Config<ChainInstanceFor<C>, TargetChain = C>,
+	{
+		client
+			.expect_storage_map_entry::<pallet_cf_ingress_egress::DepositChannelLookup<
+				state_chain_runtime::Runtime,
+				ChainInstanceFor<C>,
+			>>()
+			.returning(|_, _| Ok(None));
+	}
+
 	fn parse_eth_address(address: &'static str) -> (H160, &'static str) {
 		let mut eth_address_bytes = [0; 20];
 
@@ -529,7 +680,14 @@ mod tests {
 		let (eth_address2, eth_address_str2) =
 			parse_eth_address("0xa56A6be23b6Cf39D9448FF6e897C29c41c8fbDFF");
 
-		let client = MockStateChainClient::new();
+		let mut client = MockStateChainClient::new();
+		client.expect_latest_unfinalized_block().returning(|| BlockInfo {
+			parent_hash: state_chain_runtime::Hash::default(),
+			hash: state_chain_runtime::Hash::default(),
+			number: 1,
+		});
+		expect_no_channel::<Ethereum>(&mut client);
+		expect_no_channel::<Polkadot>(&mut client);
 		let mut store = MockStore::default();
 		handle_call(
 			state_chain_runtime::RuntimeCall::EthereumIngressEgress(
@@ -663,6 +821,60 @@ mod tests {
 		insta::assert_snapshot!(store.storage.get("broadcast:Ethereum:1").unwrap());
 	}
 
+	#[tokio::test]
+	async fn deposits_are_indexed_by_address_channel_and_tx_hash() {
+		let (eth_address, eth_address_str) =
+			parse_eth_address("0x541f563237A309B3A61E33BDf07a8930Bdba8D99");
+		let tx_hash = H256::repeat_byte(1);
+
+		let mut client = MockStateChainClient::new();
+		client.expect_latest_unfinalized_block().returning(|| BlockInfo {
+			parent_hash: state_chain_runtime::Hash::default(),
+			hash: state_chain_runtime::Hash::default(),
+			number: 1,
+		});
+		client
+			.expect_storage_map_entry::<pallet_cf_ingress_egress::DepositChannelLookup<
+				state_chain_runtime::Runtime,
+				ChainInstanceFor<Ethereum>,
+			>>()
+			.return_once(move |_, _| {
+				Ok(Some(pallet_cf_ingress_egress::DepositChannelDetails {
+					deposit_channel: cf_chains::DepositChannel {
+						channel_id: 7,
+						address: eth_address,
+						asset: cf_chains::assets::eth::Asset::Eth,
+						state: Default::default(),
+					},
+					opened_at: 1,
+					expires_at: 100,
+					action: pallet_cf_ingress_egress::ChannelAction::LiquidityProvision {
+						lp_account: Default::default(),
+					},
+					boost_fee: 0,
+					boost_status: pallet_cf_ingress_egress::BoostStatus::NotBoosted,
+				}))
+			});
+
+		let witness = DepositWitness::<Ethereum> {
+			deposit_address: eth_address,
+			amount: 100u128,
+			asset: cf_chains::assets::eth::Asset::Eth,
+			deposit_details: cf_chains::evm::DepositDetails { tx_hashes: Some(vec![tx_hash]) },
+		};
+		let channel_id = get_channel_id::<Ethereum, _>(&client, &witness.deposit_address).await;
+
+		assert_eq!(
+			WitnessInformation::from((witness, 1, channel_id, NetworkEnvironment::Testnet))
+				.get_indexes(),
+			vec![
+				(Index::Address, eth_address_str.to_lowercase()),
+				(Index::Channel, "Ethereum:7".to_string()),
+				(Index::TxHash, format!("0x{}", hex::encode(tx_hash))),
+			]
+		);
+	}
+
 	#[test]
 	fn serialization_works_as_expected() {
 		let h = BitcoinHash(
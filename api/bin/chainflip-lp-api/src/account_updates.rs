@@ -0,0 +1,681 @@
+//! Updates pushed to subscribers that concern only the API's own account: free balance changes,
+//! the lifecycle of the account's orders, and deposits and withdrawals credited to or debited
+//! from the account. All of them are derived from finalized blocks.
+
+use super::*;
+use cf_primitives::{AssetAmount, BroadcastId};
+use chainflip_api::primitives::state_chain_runtime::{Runtime, RuntimeEvent};
+use frame_system::{EventRecord, Phase};
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
+pub struct FreeBalances {
+	pub free_balances: AssetMap<U256>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct OrderUpdates {
+	pub updates: Vec<OrderUpdate>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+	Placed,
+	Updated,
+	PartiallyFilled,
+	FullyFilled,
+	Cancelled,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderUpdate {
+	LimitOrder {
+		base_asset: Asset,
+		quote_asset: Asset,
+		side: Side,
+		id: U256,
+		tick: Tick,
+		status: OrderStatus,
+		remaining: U256,
+	},
+	/// Range orders are never filled as such, so their status is only ever `placed`, `updated`
+	/// or `cancelled`. Fees earned by them are reported by `subscribe_order_fills`.
+	RangeOrder {
+		base_asset: Asset,
+		quote_asset: Asset,
+		id: U256,
+		range: Range<Tick>,
+		status: OrderStatus,
+		liquidity: U256,
+	},
+	/// A scheduled or conditional limit order update was dispatched. The changes it made to the
+	/// order are reported as separate updates.
+	ScheduledUpdateExecuted {
+		id: U256,
+	},
+	ScheduledUpdateFailed {
+		id: U256,
+	},
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct AccountTransfers {
+	pub transfers: Vec<AccountTransfer>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountTransfer {
+	DepositCredited {
+		asset: Asset,
+		amount: U256,
+	},
+	WithdrawalScheduled {
+		egress_id: EgressId,
+		asset: Asset,
+		amount: U256,
+		destination_address: String,
+		fee: U256,
+	},
+	/// The transaction egressing the withdrawal was successfully broadcast.
+	WithdrawalCompleted {
+		egress_id: EgressId,
+		asset: Asset,
+		amount: U256,
+		broadcast_id: BroadcastId,
+	},
+	TransferredIn {
+		from: AccountId,
+		asset: Asset,
+		amount: U256,
+	},
+	TransferredOut {
+		to: AccountId,
+		asset: Asset,
+		amount: U256,
+	},
+}
+
+/// Sends the update to the subscriber. Returns false once the subscription should end, either
+/// because the subscriber went away or because of an error, in which case the sink is closed with
+/// it.
+pub fn forward_update<Data: serde::Serialize>(
+	sink: &mut SubscriptionSink,
+	update: Result<BlockUpdate<Data>, jsonrpsee::core::Error>,
+) -> bool {
+	match update.and_then(|update| sink.send(&update).map_err(jsonrpsee::core::Error::ParseError)) {
+		Ok(true) => true,
+		Ok(false) => false,
+		Err(error) => {
+			sink.close(error);
+			false
+		},
+	}
+}
+
+pub async fn order_updates<StateChainClient>(
+	state_chain_client: Arc<StateChainClient>,
+	account_id: &AccountId,
+	block: BlockInfo,
+) -> Result<BlockUpdate<OrderUpdates>, jsonrpsee::core::Error>
+where
+	StateChainClient: StorageApi,
+{
+	let (fills, events) = try_join!(
+		order_fills(state_chain_client.clone(), block),
+		state_chain_client.storage_value::<frame_system::Events<Runtime>>(block.hash),
+	)?;
+
+	let updates = events
+		.iter()
+		.filter_map(|event_record| match &event_record.event {
+			RuntimeEvent::LiquidityPools(pallet_cf_pools::Event::LimitOrderUpdated {
+				lp,
+				base_asset,
+				quote_asset,
+				side,
+				id,
+				tick,
+				sell_amount_change,
+				sell_amount_total,
+				..
+			}) if lp == account_id => Some(OrderUpdate::LimitOrder {
+				base_asset: *base_asset,
+				quote_asset: *quote_asset,
+				side: *side,
+				id: (*id).into(),
+				tick: *tick,
+				status: order_status(sell_amount_change.clone(), *sell_amount_total),
+				remaining: (*sell_amount_total).into(),
+			}),
+			RuntimeEvent::LiquidityPools(pallet_cf_pools::Event::RangeOrderUpdated {
+				lp,
+				base_asset,
+				quote_asset,
+				id,
+				tick_range,
+				size_change,
+				liquidity_total,
+				..
+			}) if lp == account_id => Some(OrderUpdate::RangeOrder {
+				base_asset: *base_asset,
+				quote_asset: *quote_asset,
+				id: (*id).into(),
+				range: tick_range.clone(),
+				status: order_status(
+					size_change.clone().map(|change| change.map(|change| change.liquidity)),
+					*liquidity_total,
+				),
+				liquidity: (*liquidity_total).into(),
+			}),
+			RuntimeEvent::LiquidityPools(
+				pallet_cf_pools::Event::ScheduledLimitOrderUpdateDispatchSuccess { lp, order_id },
+			) if lp == account_id => Some(OrderUpdate::ScheduledUpdateExecuted { id: (*order_id).into() }),
+			RuntimeEvent::LiquidityPools(
+				pallet_cf_pools::Event::ScheduledLimitOrderUpdateDispatchFailure {
+					lp,
+					order_id,
+					..
+				},
+			) if lp == account_id => Some(OrderUpdate::ScheduledUpdateFailed { id: (*order_id).into() }),
+			_ => None,
+		})
+		.chain(fills.data.fills.into_iter().filter_map(|fill| match fill {
+			OrderFilled::LimitOrder {
+				lp,
+				base_asset,
+				quote_asset,
+				side,
+				id,
+				tick,
+				remaining,
+				..
+			} if &lp == account_id => Some(OrderUpdate::LimitOrder {
+				base_asset,
+				quote_asset,
+				side,
+				id,
+				tick,
+				status: if remaining.is_zero() {
+					OrderStatus::FullyFilled
+				} else {
+					OrderStatus::PartiallyFilled
+				},
+				remaining,
+			}),
+			_ => None,
+		}))
+		.collect();
+
+	Ok(BlockUpdate {
+		block_hash: block.hash,
+		block_number: block.number,
+		data: OrderUpdates { updates },
+	})
+}
+
+/// An order whose size was increased from nothing was placed, and one whose size is now zero was
+/// cancelled. Any other change to an order, including collecting its fees, is an update.
+fn order_status<Amount: Copy + PartialEq + Default>(
+	change: Option<IncreaseOrDecrease<Amount>>,
+	total: Amount,
+) -> OrderStatus {
+	match change {
+		_ if total == Default::default() => OrderStatus::Cancelled,
+		Some(IncreaseOrDecrease::Increase(amount)) if amount == total => OrderStatus::Placed,
+		_ => OrderStatus::Updated,
+	}
+}
+
+/// A withdrawal of the account that has been scheduled but not yet broadcast successfully.
+struct PendingWithdrawal {
+	asset: Asset,
+	amount: AssetAmount,
+	broadcast_id: Option<BroadcastId>,
+}
+
+/// Keeps track of the account's withdrawals, keyed by their egress id, until they have been
+/// broadcast.
+#[derive(Default)]
+pub struct WithdrawalTracker {
+	withdrawals: HashMap<EgressId, PendingWithdrawal>,
+}
+
+impl WithdrawalTracker {
+	pub async fn account_transfers<StateChainClient>(
+		&mut self,
+		state_chain_client: Arc<StateChainClient>,
+		account_id: &AccountId,
+		block: BlockInfo,
+	) -> Result<BlockUpdate<AccountTransfers>, jsonrpsee::core::Error>
+	where
+		StateChainClient: StorageApi,
+	{
+		let events = state_chain_client
+			.storage_value::<frame_system::Events<Runtime>>(block.hash)
+			.await?;
+
+		Ok(BlockUpdate {
+			block_hash: block.hash,
+			block_number: block.number,
+			data: AccountTransfers { transfers: self.process_events(account_id, &events) },
+		})
+	}
+
+	fn process_events(
+		&mut self,
+		account_id: &AccountId,
+		events: &[Box<EventRecord<RuntimeEvent, Hash>>],
+	) -> Vec<AccountTransfer> {
+		// Withdrawal events don't name the account, but the pallet emits them directly after
+		// debiting the withdrawn amount, so only a withdrawal immediately following one of the
+		// account's debits belongs to it.
+		let mut account_debit = None::<(&Phase, Asset)>;
+		let mut transfers = Vec::new();
+
+		for event_record in events {
+			let EventRecord { phase, event, .. } = &**event_record;
+			let preceding_debit = account_debit.take();
+			match event {
+				RuntimeEvent::LiquidityProvider(pallet_cf_lp::Event::AccountDebited {
+					account_id: debited_account,
+					asset,
+					..
+				}) if debited_account == account_id => account_debit = Some((phase, *asset)),
+				RuntimeEvent::LiquidityProvider(
+					pallet_cf_lp::Event::LiquidityDepositCredited {
+						account_id: credited_account,
+						asset,
+						amount_credited,
+					},
+				) if credited_account == account_id => transfers.push(AccountTransfer::DepositCredited {
+					asset: *asset,
+					amount: (*amount_credited).into(),
+				}),
+				RuntimeEvent::LiquidityProvider(
+					pallet_cf_lp::Event::WithdrawalEgressScheduled {
+						egress_id,
+						asset,
+						amount,
+						destination_address,
+						fee,
+					},
+				) if preceding_debit == Some((phase, *asset)) => {
+					self.withdrawals.insert(
+						*egress_id,
+						PendingWithdrawal { asset: *asset, amount: *amount, broadcast_id: None },
+					);
+					transfers.push(AccountTransfer::WithdrawalScheduled {
+						egress_id: *egress_id,
+						asset: *asset,
+						amount: (*amount).into(),
+						destination_address: destination_address.to_string(),
+						fee: (*fee).into(),
+					});
+				},
+				RuntimeEvent::LiquidityProvider(pallet_cf_lp::Event::AssetTransferred {
+					from,
+					to,
+					asset,
+					amount,
+				}) =>
+					if to == account_id {
+						transfers.push(AccountTransfer::TransferredIn {
+							from: from.clone(),
+							asset: *asset,
+							amount: (*amount).into(),
+						});
+					} else if from == account_id {
+						transfers.push(AccountTransfer::TransferredOut {
+							to: to.clone(),
+							asset: *asset,
+							amount: (*amount).into(),
+						});
+					},
+				_ =>
+					if let Some((broadcast_id, egress_ids)) = broadcast_requested(event) {
+						for egress_id in egress_ids {
+							if let Some(withdrawal) = self.withdrawals.get_mut(&egress_id) {
+								withdrawal.broadcast_id = Some(broadcast_id);
+							}
+						}
+					} else if let Some((chain, broadcast_id)) = broadcast_succeeded(event) {
+						let mut completed = self
+							.withdrawals
+							.iter()
+							.filter(|((egress_chain, _), withdrawal)| {
+								*egress_chain == chain &&
+									withdrawal.broadcast_id == Some(broadcast_id)
+							})
+							.map(|(egress_id, _)| *egress_id)
+							.collect::<Vec<_>>();
+						completed.sort();
+						for egress_id in completed {
+							if let Some(PendingWithdrawal { asset, amount, .. }) =
+								self.withdrawals.remove(&egress_id)
+							{
+								transfers.push(AccountTransfer::WithdrawalCompleted {
+									egress_id,
+									asset,
+									amount: amount.into(),
+									broadcast_id,
+								});
+							}
+						}
+					},
+			}
+		}
+
+		transfers
+	}
+}
+
+fn broadcast_requested(event: &RuntimeEvent) -> Option<(BroadcastId, Vec<EgressId>)> {
+	macro_rules! broadcast_requested {
+		( $( $pallet: ident ),+ ) => {
+			match event {
+				$(
+					RuntimeEvent::$pallet(pallet_cf_ingress_egress::Event::BatchBroadcastRequested {
+						broadcast_id,
+						egress_ids,
+					}) => Some((*broadcast_id, egress_ids.clone())),
+					RuntimeEvent::$pallet(pallet_cf_ingress_egress::Event::CcmBroadcastRequested {
+						broadcast_id,
+						egress_id,
+					}) => Some((*broadcast_id, vec![*egress_id])),
+				)+
+				_ => None,
+			}
+		};
+	}
+
+	broadcast_requested!(
+		EthereumIngressEgress,
+		PolkadotIngressEgress,
+		BitcoinIngressEgress,
+		ArbitrumIngressEgress,
+		SolanaIngressEgress,
+		AssethubIngressEgress
+	)
+}
+
+fn broadcast_succeeded(event: &RuntimeEvent) -> Option<(ForeignChain, BroadcastId)> {
+	macro_rules! broadcast_succeeded {
+		( $( ($chain: ident, $pallet: ident) ),+ ) => {
+			match event {
+				$(
+					RuntimeEvent::$pallet(pallet_cf_broadcast::Event::BroadcastSuccess {
+						broadcast_id,
+						..
+					}) => Some((ForeignChain::$chain, *broadcast_id)),
+				)+
+				_ => None,
+			}
+		};
+	}
+
+	broadcast_succeeded!(
+		(Ethereum, EthereumBroadcaster),
+		(Polkadot, PolkadotBroadcaster),
+		(Bitcoin, BitcoinBroadcaster),
+		(Arbitrum, ArbitrumBroadcaster),
+		(Solana, SolanaBroadcaster),
+		(Assethub, AssethubBroadcaster)
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chainflip_api::primitives::EncodedAddress;
+	use std::collections::BTreeSet;
+
+	const LP: AccountId = AccountId::new([1; 32]);
+	const OTHER_LP: AccountId = AccountId::new([2; 32]);
+
+	fn record(extrinsic_index: u32, event: RuntimeEvent) -> Box<EventRecord<RuntimeEvent, Hash>> {
+		Box::new(EventRecord {
+			phase: Phase::ApplyExtrinsic(extrinsic_index),
+			event,
+			topics: vec![],
+		})
+	}
+
+	fn withdrawal(
+		extrinsic_index: u32,
+		account_id: AccountId,
+		egress_id: EgressId,
+		amount: AssetAmount,
+	) -> [Box<EventRecord<RuntimeEvent, Hash>>; 2] {
+		[
+			record(
+				extrinsic_index,
+				RuntimeEvent::LiquidityProvider(pallet_cf_lp::Event::AccountDebited {
+					account_id,
+					asset: Asset::Btc,
+					amount_debited: amount,
+				}),
+			),
+			record(
+				extrinsic_index,
+				RuntimeEvent::LiquidityProvider(pallet_cf_lp::Event::WithdrawalEgressScheduled {
+					egress_id,
+					asset: Asset::Btc,
+					amount,
+					destination_address: EncodedAddress::Btc(b"bcrt1qxyz".to_vec()),
+					fee: 0,
+				}),
+			),
+		]
+	}
+
+	fn batch_broadcast_requested(
+		broadcast_id: BroadcastId,
+		egress_ids: Vec<EgressId>,
+	) -> Box<EventRecord<RuntimeEvent, Hash>> {
+		record(
+			0,
+			RuntimeEvent::BitcoinIngressEgress(
+				pallet_cf_ingress_egress::Event::BatchBroadcastRequested {
+					broadcast_id,
+					egress_ids,
+				},
+			),
+		)
+	}
+
+	fn broadcast_success(broadcast_id: BroadcastId) -> Box<EventRecord<RuntimeEvent, Hash>> {
+		record(
+			0,
+			RuntimeEvent::BitcoinBroadcaster(pallet_cf_broadcast::Event::BroadcastSuccess {
+				broadcast_id,
+				transaction_out_id: Default::default(),
+				transaction_ref: Default::default(),
+			}),
+		)
+	}
+
+	fn scheduled_egress_ids(transfers: &[AccountTransfer]) -> Vec<EgressId> {
+		transfers
+			.iter()
+			.filter_map(|transfer| match transfer {
+				AccountTransfer::WithdrawalScheduled { egress_id, .. } => Some(*egress_id),
+				_ => None,
+			})
+			.collect()
+	}
+
+	fn completed_egress_ids(transfers: &[AccountTransfer]) -> Vec<(EgressId, BroadcastId)> {
+		transfers
+			.iter()
+			.filter_map(|transfer| match transfer {
+				AccountTransfer::WithdrawalCompleted { egress_id, broadcast_id, .. } =>
+					Some((*egress_id, *broadcast_id)),
+				_ => None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn deposits_and_transfers_of_the_account_are_reported() {
+		let transfers = WithdrawalTracker::default().process_events(
+			&LP,
+			&[
+				record(
+					0,
+					RuntimeEvent::LiquidityProvider(
+						pallet_cf_lp::Event::LiquidityDepositCredited {
+							account_id: LP,
+							asset: Asset::Eth,
+							amount_credited: 100,
+						},
+					),
+				),
+				record(
+					0,
+					RuntimeEvent::LiquidityProvider(
+						pallet_cf_lp::Event::LiquidityDepositCredited {
+							account_id: OTHER_LP,
+							asset: Asset::Eth,
+							amount_credited: 200,
+						},
+					),
+				),
+				record(
+					1,
+					RuntimeEvent::LiquidityProvider(pallet_cf_lp::Event::AssetTransferred {
+						from: OTHER_LP,
+						to: LP,
+						asset: Asset::Flip,
+						amount: 300,
+					}),
+				),
+				record(
+					2,
+					RuntimeEvent::LiquidityProvider(pallet_cf_lp::Event::AssetTransferred {
+						from: LP,
+						to: OTHER_LP,
+						asset: Asset::Usdc,
+						amount: 400,
+					}),
+				),
+			],
+		);
+
+		assert!(matches!(
+			transfers.as_slice(),
+			[
+				AccountTransfer::DepositCredited { asset: Asset::Eth, amount: credited },
+				AccountTransfer::TransferredIn { from, asset: Asset::Flip, amount: transferred_in },
+				AccountTransfer::TransferredOut { to, asset: Asset::Usdc, amount: transferred_out },
+			] if *credited == U256::from(100) &&
+				*from == OTHER_LP &&
+				*transferred_in == U256::from(300) &&
+				*to == OTHER_LP &&
+				*transferred_out == U256::from(400)
+		));
+	}
+
+	#[test]
+	fn only_withdrawals_following_a_debit_of_the_account_are_tracked() {
+		let mut tracker = WithdrawalTracker::default();
+
+		// Withdrawals of both accounts in the same block and, in the case of the batch, in the
+		// same extrinsic.
+		let events = [
+			withdrawal(0, OTHER_LP, (ForeignChain::Bitcoin, 1), 100),
+			withdrawal(1, LP, (ForeignChain::Bitcoin, 2), 200),
+			withdrawal(2, LP, (ForeignChain::Bitcoin, 3), 300),
+			withdrawal(2, OTHER_LP, (ForeignChain::Bitcoin, 4), 400),
+			withdrawal(2, LP, (ForeignChain::Bitcoin, 5), 500),
+		]
+		.into_iter()
+		.flatten()
+		.collect::<Vec<_>>();
+
+		assert_eq!(
+			scheduled_egress_ids(&tracker.process_events(&LP, &events)),
+			vec![
+				(ForeignChain::Bitcoin, 2),
+				(ForeignChain::Bitcoin, 3),
+				(ForeignChain::Bitcoin, 5)
+			]
+		);
+		assert_eq!(
+			tracker.withdrawals.keys().copied().collect::<BTreeSet<_>>(),
+			BTreeSet::from([
+				(ForeignChain::Bitcoin, 2),
+				(ForeignChain::Bitcoin, 3),
+				(ForeignChain::Bitcoin, 5)
+			])
+		);
+	}
+
+	#[test]
+	fn withdrawals_complete_when_their_own_broadcast_succeeds() {
+		let mut tracker = WithdrawalTracker::default();
+
+		let events = [
+			withdrawal(0, LP, (ForeignChain::Bitcoin, 1), 100),
+			withdrawal(1, LP, (ForeignChain::Bitcoin, 2), 200),
+			withdrawal(2, OTHER_LP, (ForeignChain::Bitcoin, 3), 300),
+		]
+		.into_iter()
+		.flatten()
+		.collect::<Vec<_>>();
+		assert_eq!(scheduled_egress_ids(&tracker.process_events(&LP, &events)).len(), 2);
+
+		// The withdrawals are egressed in separate broadcasts, alongside other accounts' egresses.
+		let transfers = tracker.process_events(
+			&LP,
+			&[
+				batch_broadcast_requested(
+					7,
+					vec![(ForeignChain::Bitcoin, 1), (ForeignChain::Bitcoin, 3)],
+				),
+				batch_broadcast_requested(8, vec![(ForeignChain::Bitcoin, 2)]),
+			],
+		);
+		assert!(transfers.is_empty());
+
+		assert_eq!(
+			completed_egress_ids(&tracker.process_events(&LP, &[broadcast_success(8)])),
+			vec![((ForeignChain::Bitcoin, 2), 8)]
+		);
+		assert_eq!(
+			tracker.withdrawals.keys().copied().collect::<Vec<_>>(),
+			vec![(ForeignChain::Bitcoin, 1)]
+		);
+
+		assert_eq!(
+			completed_egress_ids(&tracker.process_events(&LP, &[broadcast_success(7)])),
+			vec![((ForeignChain::Bitcoin, 1), 7)]
+		);
+		assert!(tracker.withdrawals.is_empty());
+
+		// A broadcast that succeeds again doesn't complete anything.
+		assert!(tracker.process_events(&LP, &[broadcast_success(7)]).is_empty());
+	}
+
+	#[test]
+	fn order_status_is_derived_from_the_change() {
+		assert_eq!(
+			order_status(Some(IncreaseOrDecrease::Increase(100u128)), 100),
+			OrderStatus::Placed
+		);
+		assert_eq!(
+			order_status(Some(IncreaseOrDecrease::Increase(50u128)), 100),
+			OrderStatus::Updated
+		);
+		assert_eq!(
+			order_status(Some(IncreaseOrDecrease::Decrease(50u128)), 50),
+			OrderStatus::Updated
+		);
+		assert_eq!(order_status(None, 100u128), OrderStatus::Updated);
+		assert_eq!(
+			order_status(Some(IncreaseOrDecrease::Decrease(100u128)), 0),
+			OrderStatus::Cancelled
+		);
+	}
+}
@@ -8,8 +8,8 @@ use cf_utilities::{
 use chainflip_api::{
 	self,
 	lp::{
-		types::{LimitOrder, RangeOrder},
-		ApiWaitForResult, LpApi, PoolPairsMap, Side, Tick,
+		types::{BatchOrder, BatchOrderResult, LimitOrder, RangeOrder},
+		ApiWaitForResult, LpApi, PoolPairsMap, Side, Tick, TriggerCondition,
 	},
 	primitives::{
 		chains::{assets::any::AssetMap, Bitcoin, Ethereum, Polkadot},
@@ -30,7 +30,7 @@ use jsonrpsee::{
 	SubscriptionSink,
 };
 use pallet_cf_pools::{AssetPair, IncreaseOrDecrease, OrderId, RangeOrderSize};
-use rpc_types::{OpenSwapChannels, OrderIdJson, RangeOrderSizeJson};
+use rpc_types::{BatchOrderJson, OpenSwapChannels, OrderIdJson, RangeOrderSizeJson};
 use sp_core::{H256, U256};
 use std::{
 	collections::{HashMap, HashSet},
@@ -40,6 +40,9 @@ use std::{
 };
 use tracing::log;
 
+mod account_updates;
+use account_updates::{AccountTransfers, FreeBalances, OrderUpdates, WithdrawalTracker};
+
 /// Contains RPC interface types that differ from internal types.
 pub mod rpc_types {
 	use super::*;
@@ -86,6 +89,100 @@ pub mod rpc_types {
 		}
 	}
 
+	#[derive(Clone, Debug, Serialize, Deserialize)]
+	#[serde(rename_all = "snake_case")]
+	pub enum BatchOrderJson {
+		UpdateLimitOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			side: Side,
+			id: OrderIdJson,
+			tick: Option<Tick>,
+			amount_change: IncreaseOrDecrease<NumberOrHex>,
+		},
+		SetLimitOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			side: Side,
+			id: OrderIdJson,
+			tick: Option<Tick>,
+			sell_amount: NumberOrHex,
+		},
+		UpdateRangeOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			id: OrderIdJson,
+			tick_range: Option<Range<Tick>>,
+			size_change: IncreaseOrDecrease<RangeOrderSizeJson>,
+		},
+		SetRangeOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			id: OrderIdJson,
+			tick_range: Option<Range<Tick>>,
+			size: RangeOrderSizeJson,
+		},
+	}
+	impl TryFrom<BatchOrderJson> for BatchOrder {
+		type Error = anyhow::Error;
+
+		fn try_from(value: BatchOrderJson) -> Result<Self, Self::Error> {
+			Ok(match value {
+				BatchOrderJson::UpdateLimitOrder {
+					base_asset,
+					quote_asset,
+					side,
+					id,
+					tick,
+					amount_change,
+				} => BatchOrder::UpdateLimitOrder {
+					base_asset,
+					quote_asset,
+					side,
+					id: id.try_into()?,
+					option_tick: tick,
+					amount_change: amount_change.try_map(try_parse_number_or_hex)?,
+				},
+				BatchOrderJson::SetLimitOrder {
+					base_asset,
+					quote_asset,
+					side,
+					id,
+					tick,
+					sell_amount,
+				} => BatchOrder::SetLimitOrder {
+					base_asset,
+					quote_asset,
+					side,
+					id: id.try_into()?,
+					option_tick: tick,
+					sell_amount: try_parse_number_or_hex(sell_amount)?,
+				},
+				BatchOrderJson::UpdateRangeOrder {
+					base_asset,
+					quote_asset,
+					id,
+					tick_range,
+					size_change,
+				} => BatchOrder::UpdateRangeOrder {
+					base_asset,
+					quote_asset,
+					id: id.try_into()?,
+					option_tick_range: tick_range,
+					size_change: size_change.try_map(|size| size.try_into())?,
+				},
+				BatchOrderJson::SetRangeOrder { base_asset, quote_asset, id, tick_range, size } =>
+					BatchOrder::SetRangeOrder {
+						base_asset,
+						quote_asset,
+						id: id.try_into()?,
+						option_tick_range: tick_range,
+						size: size.try_into()?,
+					},
+			})
+		}
+	}
+
 	#[derive(Serialize, Deserialize, Clone)]
 	pub struct OpenSwapChannels {
 		pub ethereum: Vec<SwapChannelInfo<Ethereum>>,
@@ -179,6 +276,34 @@ pub trait Rpc {
 		wait_for: Option<WaitFor>,
 	) -> RpcResult<ApiWaitForResult<Vec<LimitOrder>>>;
 
+	#[method(name = "batch_orders")]
+	async fn batch_orders(
+		&self,
+		orders: Vec<BatchOrderJson>,
+		wait_for: Option<WaitFor>,
+	) -> RpcResult<ApiWaitForResult<Vec<BatchOrderResult>>>;
+
+	#[method(name = "set_conditional_limit_order")]
+	async fn set_conditional_limit_order(
+		&self,
+		base_asset: Asset,
+		quote_asset: Asset,
+		side: Side,
+		id: OrderIdJson,
+		tick: Option<Tick>,
+		sell_amount: NumberOrHex,
+		trigger: TriggerCondition,
+		wait_for: Option<WaitFor>,
+	) -> RpcResult<ApiWaitForResult<Vec<LimitOrder>>>;
+
+	#[method(name = "cancel_conditional_limit_orders")]
+	async fn cancel_conditional_limit_orders(
+		&self,
+		base_asset: Asset,
+		quote_asset: Asset,
+		id: OrderIdJson,
+	) -> RpcResult<Hash>;
+
 	#[method(name = "free_balances", aliases = ["lp_asset_balances"])]
 	async fn free_balances(&self) -> RpcResult<AssetMap<U256>>;
 
@@ -198,6 +323,20 @@ pub trait Rpc {
 
 	#[method(name = "order_fills")]
 	async fn order_fills(&self, at: Option<Hash>) -> RpcResult<BlockUpdate<OrderFills>>;
+
+	/// Sends the account's free balances whenever they change, starting with the balances at
+	/// the latest finalized block.
+	#[subscription(name = "subscribe_free_balances", item = BlockUpdate<FreeBalances>)]
+	fn subscribe_free_balances(&self);
+
+	/// Sends the changes to the account's orders in every finalized block that has any.
+	#[subscription(name = "subscribe_order_updates", item = BlockUpdate<OrderUpdates>)]
+	fn subscribe_order_updates(&self);
+
+	/// Sends the deposits, withdrawals and transfers of the account in every finalized block that
+	/// has any.
+	#[subscription(name = "subscribe_account_transfers", item = BlockUpdate<AccountTransfers>)]
+	fn subscribe_account_transfers(&self);
 }
 
 pub struct RpcServerImpl {
@@ -207,11 +346,16 @@ pub struct RpcServerImpl {
 impl RpcServerImpl {
 	pub async fn new(
 		scope: &Scope<'_, anyhow::Error>,
-		LPOptions { ws_endpoint, signing_key_file, .. }: LPOptions,
+		LPOptions { ws_endpoint, signing_key_file, signer_socket, scoped_key_of, .. }: LPOptions,
 	) -> Result<Self, anyhow::Error> {
+		let state_chain_settings = StateChain { ws_endpoint, signing_key_file, signer_socket };
 		Ok(Self {
-			api: StateChainApi::connect(scope, StateChain { ws_endpoint, signing_key_file })
-				.await?,
+			api: match scoped_key_of {
+				Some(account_id) =>
+					StateChainApi::connect_with_scoped_key(scope, state_chain_settings, account_id)
+						.await?,
+				None => StateChainApi::connect(scope, state_chain_settings).await?,
+			},
 		})
 	}
 }
@@ -427,6 +571,61 @@ impl RpcServer for RpcServerImpl {
 			.await?)
 	}
 
+	async fn batch_orders(
+		&self,
+		orders: Vec<BatchOrderJson>,
+		wait_for: Option<WaitFor>,
+	) -> RpcResult<ApiWaitForResult<Vec<BatchOrderResult>>> {
+		Ok(self
+			.api
+			.lp_api()
+			.batch_orders(
+				orders.into_iter().map(TryInto::try_into).collect::<Result<Vec<_>, _>>()?,
+				wait_for.unwrap_or_default(),
+			)
+			.await?)
+	}
+
+	async fn set_conditional_limit_order(
+		&self,
+		base_asset: Asset,
+		quote_asset: Asset,
+		side: Side,
+		id: OrderIdJson,
+		tick: Option<Tick>,
+		sell_amount: NumberOrHex,
+		trigger: TriggerCondition,
+		wait_for: Option<WaitFor>,
+	) -> RpcResult<ApiWaitForResult<Vec<LimitOrder>>> {
+		Ok(self
+			.api
+			.lp_api()
+			.set_conditional_limit_order(
+				base_asset,
+				quote_asset,
+				side,
+				id.try_into()?,
+				tick,
+				try_parse_number_or_hex(sell_amount)?,
+				trigger,
+				wait_for.unwrap_or_default(),
+			)
+			.await?)
+	}
+
+	async fn cancel_conditional_limit_orders(
+		&self,
+		base_asset: Asset,
+		quote_asset: Asset,
+		id: OrderIdJson,
+	) -> RpcResult<Hash> {
+		Ok(self
+			.api
+			.lp_api()
+			.cancel_conditional_limit_orders(base_asset, quote_asset, id.try_into()?)
+			.await?)
+	}
+
 	/// Returns the tx hash that the account role was set
 	async fn register_account(&self) -> RpcResult<Hash> {
 		Ok(self
@@ -472,17 +671,10 @@ impl RpcServer for RpcServerImpl {
 		tokio::spawn(async move {
 			let mut finalized_block_stream = state_chain_client.finalized_block_stream().await;
 			while let Some(block) = finalized_block_stream.next().await {
-				if let Err(option_error) = order_fills(state_chain_client.clone(), block)
-					.await
-					.map_err(Some)
-					.and_then(|order_fills| match sink.send(&order_fills) {
-						Ok(true) => Ok(()),
-						Ok(false) => Err(None),
-						Err(error) => Err(Some(jsonrpsee::core::Error::ParseError(error))),
-					}) {
-					if let Some(error) = option_error {
-						sink.close(error);
-					}
+				if !account_updates::forward_update(
+					&mut sink,
+					order_fills(state_chain_client.clone(), block).await,
+				) {
 					break
 				}
 			}
@@ -502,6 +694,91 @@ impl RpcServer for RpcServerImpl {
 
 		Ok(order_fills(state_chain_client.clone(), block).await?)
 	}
+
+	fn subscribe_free_balances(&self, mut sink: SubscriptionSink) -> SubscriptionResult {
+		sink.accept()?;
+		let state_chain_client = self.api.state_chain_client.clone();
+		tokio::spawn(async move {
+			let account_id = state_chain_client.account_id();
+			let mut previous_balances = None;
+			let mut finalized_block_stream = state_chain_client.finalized_block_stream().await;
+			while let Some(block) = finalized_block_stream.next().await {
+				let balances = match state_chain_client
+					.base_rpc_client
+					.raw_rpc_client
+					.cf_free_balances(account_id.clone(), Some(block.hash))
+					.await
+				{
+					Ok(free_balances) => FreeBalances { free_balances },
+					Err(error) => {
+						sink.close(error);
+						break
+					},
+				};
+				if previous_balances.as_ref() == Some(&balances) {
+					continue
+				}
+				previous_balances = Some(balances.clone());
+				if !account_updates::forward_update(
+					&mut sink,
+					Ok(BlockUpdate {
+						block_hash: block.hash,
+						block_number: block.number,
+						data: balances,
+					}),
+				) {
+					break
+				}
+			}
+		});
+
+		Ok(())
+	}
+
+	fn subscribe_order_updates(&self, mut sink: SubscriptionSink) -> SubscriptionResult {
+		sink.accept()?;
+		let state_chain_client = self.api.state_chain_client.clone();
+		tokio::spawn(async move {
+			let account_id = state_chain_client.account_id();
+			let mut finalized_block_stream = state_chain_client.finalized_block_stream().await;
+			while let Some(block) = finalized_block_stream.next().await {
+				let order_updates =
+					account_updates::order_updates(state_chain_client.clone(), &account_id, block)
+						.await;
+				if matches!(&order_updates, Ok(update) if update.data.updates.is_empty()) {
+					continue
+				}
+				if !account_updates::forward_update(&mut sink, order_updates) {
+					break
+				}
+			}
+		});
+
+		Ok(())
+	}
+
+	fn subscribe_account_transfers(&self, mut sink: SubscriptionSink) -> SubscriptionResult {
+		sink.accept()?;
+		let state_chain_client = self.api.state_chain_client.clone();
+		tokio::spawn(async move {
+			let account_id = state_chain_client.account_id();
+			let mut withdrawal_tracker = WithdrawalTracker::default();
+			let mut finalized_block_stream = state_chain_client.finalized_block_stream().await;
+			while let Some(block) = finalized_block_stream.next().await {
+				let account_transfers = withdrawal_tracker
+					.account_transfers(state_chain_client.clone(), &account_id, block)
+					.await;
+				if matches!(&account_transfers, Ok(update) if update.data.transfers.is_empty()) {
+					continue
+				}
+				if !account_updates::forward_update(&mut sink, account_transfers) {
+					break
+				}
+			}
+		});
+
+		Ok(())
+	}
 }
 
 async fn order_fills<StateChainClient>(
@@ -695,6 +972,16 @@ pub struct LPOptions {
 		help = "A path to a file that contains the LP's secret key for signing extrinsics."
 	)]
 	pub signing_key_file: PathBuf,
+	#[clap(
+		long = "state_chain.signer_socket",
+		help = "A path to the unix socket of a remote signer to sign extrinsics with, instead of the signing key file."
+	)]
+	pub signer_socket: Option<PathBuf>,
+	#[clap(
+		long = "state_chain.scoped_key_of",
+		help = "The liquidity provider account to act on behalf of, if the signing key is one of its scoped keys."
+	)]
+	pub scoped_key_of: Option<AccountId32>,
 }
 
 #[tokio::main]
@@ -707,7 +994,7 @@ async fn main() -> anyhow::Result<()> {
 		.expect("setting default subscriber failed");
 
 	assert!(
-		opts.signing_key_file.exists(),
+		opts.signer_socket.is_some() || opts.signing_key_file.exists(),
 		"No signing_key_file found at {}",
 		opts.signing_key_file.to_string_lossy()
 	);
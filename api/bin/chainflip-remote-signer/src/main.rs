@@ -0,0 +1,36 @@
+use cf_utilities::task_scope::task_scope;
+use chainflip_api::signer::{read_signing_key_file, remote::run_signer};
+use clap::Parser;
+use futures::FutureExt;
+use std::path::PathBuf;
+
+#[derive(Parser, Debug, Clone, Default)]
+#[clap(version = env!("CARGO_PKG_VERSION"))]
+pub struct RemoteSignerOptions {
+	#[clap(
+		long = "socket_path",
+		default_value = "/run/chainflip/signer.sock",
+		help = "The path of the unix socket on which to listen for signing requests."
+	)]
+	pub socket_path: PathBuf,
+	#[clap(
+		long = "signing_key_file",
+		default_value = "/etc/chainflip/keys/signing_key_file",
+		help = "A path to a file that contains the secret key to sign extrinsics with."
+	)]
+	pub signing_key_file: PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+	let opts = RemoteSignerOptions::parse();
+	tracing_subscriber::FmtSubscriber::builder()
+		.with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+		.try_init()
+		.expect("setting default subscriber failed");
+
+	let pair = read_signing_key_file(&opts.signing_key_file)?;
+
+	task_scope(|scope| async move { run_signer(scope, &opts.socket_path, pair).await }.boxed())
+		.await
+}
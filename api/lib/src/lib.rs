@@ -4,7 +4,7 @@ use anyhow::{anyhow, bail, Context, Result};
 use async_trait::async_trait;
 use cf_chains::{
 	address::EncodedAddress, dot::PolkadotAccountId, evm::to_evm_address, sol::SolAddress,
-	AnyChain, CcmChannelMetadata, ChannelRefundParameters, ForeignChain,
+	AnyChain, CcmChannelMetadata, ChannelRefundParameters, DcaParameters, ForeignChain,
 };
 pub use cf_primitives::{AccountRole, Affiliates, Asset, BasisPoints, ChannelId, SemVer};
 use futures::FutureExt;
@@ -25,7 +25,7 @@ pub mod primitives {
 	pub type RedemptionAmount = pallet_cf_funding::RedemptionAmount<FlipBalance>;
 	pub use cf_chains::{
 		address::{EncodedAddress, ForeignChainAddress},
-		CcmChannelMetadata, CcmDepositMetadata, ChannelRefundParameters,
+		CcmChannelMetadata, CcmDepositMetadata, ChannelRefundParameters, DcaParameters,
 	};
 }
 pub use cf_chains::eth::Address as EthereumAddress;
@@ -34,7 +34,9 @@ pub use chainflip_engine::{
 	state_chain_observer::client::{
 		base_rpc_api::{BaseRpcApi, RawRpcApi},
 		chain_api::ChainApi,
-		extrinsic_api::signed::{SignedExtrinsicApi, UntilFinalized, WaitFor, WaitForResult},
+		extrinsic_api::signed::{
+			signer, SignedExtrinsicApi, UntilFinalized, WaitFor, WaitForResult,
+		},
 		storage_api::StorageApi,
 		BlockInfo,
 	},
@@ -116,7 +118,7 @@ impl StateChainApi {
 		let (.., state_chain_client) = StateChainClient::connect_with_account(
 			scope,
 			&state_chain_settings.ws_endpoint,
-			&state_chain_settings.signing_key_file,
+			state_chain_settings.signing_key_source(),
 			AccountRole::Unregistered,
 			false,
 			false,
@@ -127,6 +129,25 @@ impl StateChainApi {
 		Ok(Self { state_chain_client })
 	}
 
+	/// Connects using a scoped key of `account_id`, submitting all extrinsics on its behalf. The
+	/// signing key file must contain the scoped key rather than the account's own key.
+	pub async fn connect_with_scoped_key<'a>(
+		scope: &Scope<'a, anyhow::Error>,
+		state_chain_settings: settings::StateChain,
+		account_id: AccountId32,
+	) -> Result<Self, anyhow::Error> {
+		let (.., state_chain_client) = StateChainClient::connect_with_scoped_key(
+			scope,
+			&state_chain_settings.ws_endpoint,
+			state_chain_settings.signing_key_source(),
+			account_id,
+			AccountRole::Unregistered,
+		)
+		.await?;
+
+		Ok(Self { state_chain_client })
+	}
+
 	pub fn operator_api(&self) -> Arc<impl OperatorApi> {
 		self.state_chain_client.clone()
 	}
@@ -338,29 +359,36 @@ pub trait BrokerApi: SignedExtrinsicApi + Sized + Send + Sync + 'static {
 		boost_fee: Option<BasisPoints>,
 		affiliate_fees: Affiliates<AccountId32>,
 		refund_parameters: Option<ChannelRefundParameters>,
+		dca_parameters: Option<DcaParameters>,
 	) -> Result<SwapDepositAddress> {
 		let (_tx_hash, events, header, ..) = self
-			.submit_signed_extrinsic_with_dry_run(if affiliate_fees.is_empty() {
-				pallet_cf_swapping::Call::request_swap_deposit_address {
-					source_asset,
-					destination_asset,
-					destination_address,
-					broker_commission,
-					channel_metadata,
-					boost_fee: boost_fee.unwrap_or_default(),
-				}
-			} else {
-				pallet_cf_swapping::Call::request_swap_deposit_address_with_affiliates {
-					source_asset,
-					destination_asset,
-					destination_address,
-					broker_commission,
-					channel_metadata,
-					boost_fee: boost_fee.unwrap_or_default(),
-					affiliate_fees,
-					refund_parameters,
-				}
-			})
+			.submit_signed_extrinsic_with_dry_run(
+				if affiliate_fees.is_empty() &&
+					refund_parameters.is_none() &&
+					dca_parameters.is_none()
+				{
+					pallet_cf_swapping::Call::request_swap_deposit_address {
+						source_asset,
+						destination_asset,
+						destination_address,
+						broker_commission,
+						channel_metadata,
+						boost_fee: boost_fee.unwrap_or_default(),
+					}
+				} else {
+					pallet_cf_swapping::Call::request_swap_deposit_address_with_affiliates {
+						source_asset,
+						destination_asset,
+						destination_address,
+						broker_commission,
+						channel_metadata,
+						boost_fee: boost_fee.unwrap_or_default(),
+						affiliate_fees,
+						refund_parameters,
+						dca_parameters,
+					}
+				},
+			)
 			.await?
 			.until_in_block()
 			.await?;
@@ -468,6 +496,8 @@ pub fn clean_foreign_chain_address(chain: ForeignChain, address: &str) -> Result
 		ForeignChain::Bitcoin => EncodedAddress::Btc(address.as_bytes().to_vec()),
 		ForeignChain::Arbitrum => EncodedAddress::Arb(clean_hex_address(address)?),
 		ForeignChain::Solana => EncodedAddress::Sol(SolAddress::from_str(address)?.into()),
+		ForeignChain::Assethub =>
+			EncodedAddress::Hub(PolkadotAccountId::from_str(address).map(|id| *id.aliased_ref())?),
 	})
 }
 
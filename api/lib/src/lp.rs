@@ -11,6 +11,7 @@ use chainflip_engine::state_chain_observer::client::{
 	extrinsic_api::signed::{SignedExtrinsicApi, UntilInBlock, WaitFor, WaitForResult},
 	StateChainClient,
 };
+pub use pallet_cf_pools::TriggerCondition;
 use pallet_cf_pools::{IncreaseOrDecrease, OrderId, RangeOrderSize};
 use serde::{Deserialize, Serialize};
 use sp_core::{H256, U256};
@@ -74,6 +75,153 @@ pub mod types {
 		pub bought_amount: U256,
 		pub sell_amount_change: Option<IncreaseOrDecrease<U256>>,
 	}
+
+	/// A limit or range order change applied as part of a batch.
+	#[derive(Serialize, Deserialize, Clone)]
+	#[serde(rename_all = "snake_case")]
+	pub enum BatchOrder {
+		UpdateLimitOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			side: Side,
+			id: OrderId,
+			option_tick: Option<Tick>,
+			amount_change: IncreaseOrDecrease<AssetAmount>,
+		},
+		SetLimitOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			side: Side,
+			id: OrderId,
+			option_tick: Option<Tick>,
+			sell_amount: AssetAmount,
+		},
+		UpdateRangeOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			id: OrderId,
+			option_tick_range: Option<Range<Tick>>,
+			size_change: IncreaseOrDecrease<RangeOrderSize>,
+		},
+		SetRangeOrder {
+			base_asset: Asset,
+			quote_asset: Asset,
+			id: OrderId,
+			option_tick_range: Option<Range<Tick>>,
+			size: RangeOrderSize,
+		},
+	}
+
+	impl From<BatchOrder> for pallet_cf_pools::Call<state_chain_runtime::Runtime> {
+		fn from(order: BatchOrder) -> Self {
+			match order {
+				BatchOrder::UpdateLimitOrder {
+					base_asset,
+					quote_asset,
+					side,
+					id,
+					option_tick,
+					amount_change,
+				} => pallet_cf_pools::Call::update_limit_order {
+					base_asset,
+					quote_asset,
+					side,
+					id,
+					option_tick,
+					amount_change,
+				},
+				BatchOrder::SetLimitOrder {
+					base_asset,
+					quote_asset,
+					side,
+					id,
+					option_tick,
+					sell_amount,
+				} => pallet_cf_pools::Call::set_limit_order {
+					base_asset,
+					quote_asset,
+					side,
+					id,
+					option_tick,
+					sell_amount,
+				},
+				BatchOrder::UpdateRangeOrder {
+					base_asset,
+					quote_asset,
+					id,
+					option_tick_range,
+					size_change,
+				} => pallet_cf_pools::Call::update_range_order {
+					base_asset,
+					quote_asset,
+					id,
+					option_tick_range,
+					size_change,
+				},
+				BatchOrder::SetRangeOrder {
+					base_asset,
+					quote_asset,
+					id,
+					option_tick_range,
+					size,
+				} => pallet_cf_pools::Call::set_range_order {
+					base_asset,
+					quote_asset,
+					id,
+					option_tick_range,
+					size,
+				},
+			}
+		}
+	}
+
+	/// The changes a batched order update made to its order.
+	#[derive(Serialize, Deserialize, Clone)]
+	#[serde(rename_all = "snake_case")]
+	pub enum BatchOrderResult {
+		LimitOrder(Vec<LimitOrder>),
+		RangeOrder(Vec<RangeOrder>),
+	}
+}
+
+/// Splits the events of a batch of order updates into the changes made to each order, in the
+/// order of the batch.
+fn collect_batch_order_returns(
+	orders: Vec<types::BatchOrder>,
+	events: Vec<state_chain_runtime::RuntimeEvent>,
+) -> Vec<types::BatchOrderResult> {
+	let limit_orders = collect_limit_order_returns(events.clone());
+	let range_orders = collect_range_order_returns(events);
+
+	orders
+		.into_iter()
+		.map(|order| match order {
+			types::BatchOrder::UpdateLimitOrder { base_asset, quote_asset, side, id, .. } |
+			types::BatchOrder::SetLimitOrder { base_asset, quote_asset, side, id, .. } =>
+				types::BatchOrderResult::LimitOrder(
+					limit_orders
+						.iter()
+						.filter(|order| {
+							(order.base_asset, order.quote_asset, order.side, order.id) ==
+								(base_asset, quote_asset, side, id.into())
+						})
+						.cloned()
+						.collect(),
+				),
+			types::BatchOrder::UpdateRangeOrder { base_asset, quote_asset, id, .. } |
+			types::BatchOrder::SetRangeOrder { base_asset, quote_asset, id, .. } =>
+				types::BatchOrderResult::RangeOrder(
+					range_orders
+						.iter()
+						.filter(|order| {
+							(order.base_asset, order.quote_asset, order.id) ==
+								(base_asset, quote_asset, id.into())
+						})
+						.cloned()
+						.collect(),
+				),
+		})
+		.collect()
 }
 
 fn collect_range_order_returns(
@@ -380,6 +528,84 @@ pub trait LpApi: SignedExtrinsicApi + Sized + Send + Sync + 'static {
 		.await
 	}
 
+	async fn set_conditional_limit_order(
+		&self,
+		base_asset: Asset,
+		quote_asset: Asset,
+		side: Side,
+		id: OrderId,
+		option_tick: Option<Tick>,
+		sell_amount: AssetAmount,
+		trigger: TriggerCondition,
+		wait_for: WaitFor,
+	) -> Result<ApiWaitForResult<Vec<types::LimitOrder>>> {
+		Ok(into_api_wait_for_result(
+			self.submit_signed_extrinsic_wait_for(
+				pallet_cf_pools::Call::schedule_conditional_limit_order_update {
+					call: Box::new(pallet_cf_pools::Call::set_limit_order {
+						base_asset,
+						quote_asset,
+						side,
+						id,
+						option_tick,
+						sell_amount,
+					}),
+					trigger,
+				},
+				wait_for,
+			)
+			.await?,
+			collect_limit_order_returns,
+		))
+	}
+
+	async fn cancel_conditional_limit_orders(
+		&self,
+		base_asset: Asset,
+		quote_asset: Asset,
+		id: OrderId,
+	) -> Result<H256> {
+		self.simple_submission_with_dry_run(
+			pallet_cf_pools::Call::cancel_conditional_limit_order_updates {
+				base_asset,
+				quote_asset,
+				id,
+			},
+		)
+		.await
+	}
+
+	/// Atomically applies all the order changes in a single extrinsic.
+	async fn batch_orders(
+		&self,
+		orders: Vec<types::BatchOrder>,
+		wait_for: WaitFor,
+	) -> Result<ApiWaitForResult<Vec<types::BatchOrderResult>>> {
+		if orders.len() > pallet_cf_pools::MAX_BATCHED_ORDER_UPDATES as usize {
+			bail!(
+				"Too many orders in batch, the maximum is {}",
+				pallet_cf_pools::MAX_BATCHED_ORDER_UPDATES
+			);
+		}
+
+		Ok(into_api_wait_for_result(
+			self.submit_signed_extrinsic_wait_for(
+				pallet_cf_pools::Call::batch_order_updates {
+					calls: orders
+						.iter()
+						.cloned()
+						.map(Into::into)
+						.collect::<Vec<_>>()
+						.try_into()
+						.expect("checked the number of orders above"),
+				},
+				wait_for,
+			)
+			.await?,
+			|events| collect_batch_order_returns(orders, events),
+		))
+	}
+
 	async fn scheduled_or_immediate(
 		&self,
 		call: pallet_cf_pools::Call<state_chain_runtime::Runtime>,
@@ -42,7 +42,7 @@ impl QueryApi {
 		let (.., state_chain_client) = StateChainClient::connect_with_account(
 			scope,
 			&state_chain_settings.ws_endpoint,
-			&state_chain_settings.signing_key_file,
+			state_chain_settings.signing_key_source(),
 			AccountRole::Unregistered,
 			false,
 			false,
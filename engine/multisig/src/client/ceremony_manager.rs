@@ -3,9 +3,9 @@ mod tests;
 
 use anyhow::{anyhow, bail, Context, Result};
 use futures::FutureExt;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::{
-	collections::{BTreeSet, HashMap},
+	collections::{BTreeSet, HashMap, VecDeque},
 	fmt::{Debug, Display},
 	marker::PhantomData,
 	sync::Arc,
@@ -19,16 +19,19 @@ use crate::{
 		ceremony_id_string,
 		common::{KeygenFailureReason, SigningFailureReason},
 		signing::PayloadAndKey,
-		CeremonyRequestDetails,
+		CeremonyRequestDetails, KeygenRequestDetails, SigningRequestDetails,
 	},
-	crypto::{CryptoScheme, Rng},
+	crypto::{CryptoScheme, CryptoTag, Rng},
 	p2p::{OutgoingMultisigStageMessages, VersionedCeremonyMessage},
 	ChainSigning,
 };
 use cf_primitives::{AuthorityCount, CeremonyId};
 use state_chain_runtime::AccountId;
 use utilities::{
-	metrics::{AUTHORIZED_CEREMONIES, CEREMONY_BAD_MSG, UNAUTHORIZED_CEREMONIES},
+	metrics::{
+		AUTHORIZED_CEREMONIES, CEREMONY_BAD_MSG, QUEUED_CEREMONIES, REJECTED_CEREMONIES,
+		UNAUTHORIZED_CEREMONIES,
+	},
 	task_scope::{task_scope, Scope, ScopedJoinHandle},
 };
 
@@ -61,6 +64,45 @@ pub type CeremonyResultReceiver<Ceremony> = oneshot::Receiver<CeremonyOutcome<Ce
 const KEYGEN_LABEL: &str = "keygen";
 const SIGNING_LABEL: &str = "signing";
 
+/// Limits on the number of ceremonies of a single crypto scheme that a node takes on at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct SchedulingLimits {
+	/// The maximum number of authorised ceremonies (keygen and signing) running at the same time.
+	pub max_concurrent_ceremonies: usize,
+	/// The maximum number of signing requests waiting for a free slot. Requests arriving while the
+	/// queue is full are rejected with [SigningFailureReason::CeremonyQueueFull].
+	pub max_queued_signing_requests: usize,
+}
+
+impl Default for SchedulingLimits {
+	fn default() -> Self {
+		Self { max_concurrent_ceremonies: 32, max_queued_signing_requests: 128 }
+	}
+}
+
+/// The [SchedulingLimits] of each crypto scheme. Chains that share a crypto scheme (eg. Ethereum
+/// and Arbitrum) share a ceremony manager, and therefore its limits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct SchedulingLimitsPerScheme {
+	pub evm: SchedulingLimits,
+	pub polkadot: SchedulingLimits,
+	pub bitcoin: SchedulingLimits,
+	pub ed25519: SchedulingLimits,
+}
+
+impl SchedulingLimitsPerScheme {
+	pub fn for_scheme<C: CryptoScheme>(&self) -> SchedulingLimits {
+		match C::CRYPTO_TAG {
+			CryptoTag::Evm => self.evm,
+			CryptoTag::Polkadot => self.polkadot,
+			CryptoTag::Bitcoin => self.bitcoin,
+			CryptoTag::Ed25519 => self.ed25519,
+		}
+	}
+}
+
 /// Ceremony trait combines type parameters that are often used together
 pub trait CeremonyTrait: 'static {
 	const CEREMONY_TYPE: &'static str;
@@ -121,6 +163,9 @@ pub struct CeremonyManager<Chain: ChainSigning> {
 	signing_states: CeremonyStates<SigningCeremony<Chain::CryptoScheme>>,
 	keygen_states: CeremonyStates<KeygenCeremony<Chain::CryptoScheme>>,
 	latest_ceremony_id: CeremonyId,
+	scheduling_limits: SchedulingLimits,
+	// Signing requests waiting for a free slot, in the order they were received
+	queued_signing_requests: VecDeque<(CeremonyId, SigningRequestDetails<Chain::CryptoScheme>)>,
 }
 
 // A CeremonyStage for either keygen or signing
@@ -344,9 +389,16 @@ impl<Chain: ChainSigning> CeremonyManager<Chain> {
 			signing_states: CeremonyStates::new(),
 			keygen_states: CeremonyStates::new(),
 			latest_ceremony_id,
+			scheduling_limits: SchedulingLimits::default(),
+			queued_signing_requests: VecDeque::new(),
 		}
 	}
 
+	pub fn with_scheduling_limits(mut self, scheduling_limits: SchedulingLimits) -> Self {
+		self.scheduling_limits = scheduling_limits;
+		self
+	}
+
 	async fn on_request(
 		&mut self,
 		request: CeremonyRequest<Chain::CryptoScheme>,
@@ -357,49 +409,37 @@ impl<Chain: ChainSigning> CeremonyManager<Chain> {
 
 		match request.details {
 			Some(CeremonyRequestDetails::Keygen(details)) => {
-				if let Some(resharing_context) = details.resharing_context {
-					self.on_key_handover_request(
-						request.ceremony_id,
-						details.participants,
-						details.rng,
-						details.result_sender,
-						resharing_context,
-						scope,
-					)
-				} else {
-					self.on_keygen_request(
-						request.ceremony_id,
-						details.participants,
-						details.rng,
-						details.result_sender,
-						scope,
-					)
-				}
-				UNAUTHORIZED_CEREMONIES.set(
-					&[Chain::NAME, KEYGEN_LABEL],
-					self.keygen_states.count_unauthorised_ceremonies(),
-				);
-				AUTHORIZED_CEREMONIES.set(
-					&[Chain::NAME, KEYGEN_LABEL],
-					self.keygen_states.count_authorised_ceremonies(),
-				);
+				// Keygen and key handover ceremonies are never queued, as delaying them risks
+				// failing the rotation. They do take up slots, so they are prioritised over
+				// signing.
+				self.start_keygen_ceremony(request.ceremony_id, details, scope);
 			},
 			Some(CeremonyRequestDetails::Sign(details)) => {
-				self.on_request_to_sign(
-					request.ceremony_id,
-					details.participants,
-					details.signing_info,
-					details.rng,
-					details.result_sender,
-					scope,
-				);
-				UNAUTHORIZED_CEREMONIES.set(
-					&[Chain::NAME, SIGNING_LABEL],
-					self.signing_states.count_unauthorised_ceremonies(),
-				);
-				AUTHORIZED_CEREMONIES.set(
-					&[Chain::NAME, SIGNING_LABEL],
-					self.signing_states.count_authorised_ceremonies(),
+				if self.queued_signing_requests.is_empty() && self.has_free_ceremony_slot() {
+					self.start_signing_ceremony(request.ceremony_id, details, scope);
+				} else if self.queued_signing_requests.len() <
+					self.scheduling_limits.max_queued_signing_requests
+				{
+					debug!(
+						"Queueing signing ceremony {}: all ceremony slots are taken",
+						ceremony_id_string::<Chain>(request.ceremony_id)
+					);
+					self.queued_signing_requests.push_back((request.ceremony_id, details));
+					// The other participants may have already started the ceremony. Make sure
+					// there is an unauthorised ceremony to hold on to their messages until ours
+					// starts, as they would otherwise be dropped for having an old ceremony id.
+					self.signing_states
+						.get_state_or_create_unauthorized::<Chain>(request.ceremony_id, scope);
+					UNAUTHORIZED_CEREMONIES.set(
+						&[Chain::NAME, SIGNING_LABEL],
+						self.signing_states.count_unauthorised_ceremonies(),
+					);
+				} else {
+					self.reject_signing_request(request.ceremony_id, details);
+				}
+				QUEUED_CEREMONIES.set(
+					&[<Chain::CryptoScheme as CryptoScheme>::NAME, SIGNING_LABEL],
+					self.queued_signing_requests.len(),
 				);
 			},
 			None => {
@@ -453,10 +493,12 @@ impl<Chain: ChainSigning> CeremonyManager<Chain> {
 						Some((id, outcome)) = self.signing_states.outcome_receiver.recv() => {
 							self.signing_states.finalize_authorised_ceremony(id, outcome);
 							AUTHORIZED_CEREMONIES.set(&[Chain::NAME, SIGNING_LABEL], self.signing_states.count_authorised_ceremonies());
+							self.start_queued_signing_ceremonies(scope);
 						}
 						Some((id, outcome)) = self.keygen_states.outcome_receiver.recv() => {
 							self.keygen_states.finalize_authorised_ceremony(id, outcome);
 							AUTHORIZED_CEREMONIES.set(&[Chain::NAME, KEYGEN_LABEL], self.keygen_states.count_authorised_ceremonies());
+							self.start_queued_signing_ceremonies(scope);
 						}
 					}
 				}
@@ -467,6 +509,99 @@ impl<Chain: ChainSigning> CeremonyManager<Chain> {
 		.await
 	}
 
+	fn start_keygen_ceremony(
+		&mut self,
+		ceremony_id: CeremonyId,
+		details: KeygenRequestDetails<Chain::CryptoScheme>,
+		scope: &Scope<'_, anyhow::Error>,
+	) {
+		if let Some(resharing_context) = details.resharing_context {
+			self.on_key_handover_request(
+				ceremony_id,
+				details.participants,
+				details.rng,
+				details.result_sender,
+				resharing_context,
+				scope,
+			)
+		} else {
+			self.on_keygen_request(
+				ceremony_id,
+				details.participants,
+				details.rng,
+				details.result_sender,
+				scope,
+			)
+		}
+		UNAUTHORIZED_CEREMONIES
+			.set(&[Chain::NAME, KEYGEN_LABEL], self.keygen_states.count_unauthorised_ceremonies());
+		AUTHORIZED_CEREMONIES
+			.set(&[Chain::NAME, KEYGEN_LABEL], self.keygen_states.count_authorised_ceremonies());
+	}
+
+	fn start_signing_ceremony(
+		&mut self,
+		ceremony_id: CeremonyId,
+		details: SigningRequestDetails<Chain::CryptoScheme>,
+		scope: &Scope<'_, anyhow::Error>,
+	) {
+		self.on_request_to_sign(
+			ceremony_id,
+			details.participants,
+			details.signing_info,
+			details.rng,
+			details.result_sender,
+			scope,
+		);
+		UNAUTHORIZED_CEREMONIES.set(
+			&[Chain::NAME, SIGNING_LABEL],
+			self.signing_states.count_unauthorised_ceremonies(),
+		);
+		AUTHORIZED_CEREMONIES
+			.set(&[Chain::NAME, SIGNING_LABEL], self.signing_states.count_authorised_ceremonies());
+	}
+
+	/// Start queued signing ceremonies, oldest first, for as long as there are free slots.
+	fn start_queued_signing_ceremonies(&mut self, scope: &Scope<'_, anyhow::Error>) {
+		while self.has_free_ceremony_slot() {
+			match self.queued_signing_requests.pop_front() {
+				Some((ceremony_id, details)) =>
+					self.start_signing_ceremony(ceremony_id, details, scope),
+				None => break,
+			}
+		}
+		QUEUED_CEREMONIES.set(
+			&[<Chain::CryptoScheme as CryptoScheme>::NAME, SIGNING_LABEL],
+			self.queued_signing_requests.len(),
+		);
+	}
+
+	/// Immediately fail a signing request that can't be queued, so that the State Chain observer
+	/// knows we are not taking part, rather than letting the ceremony time out.
+	fn reject_signing_request(
+		&mut self,
+		ceremony_id: CeremonyId,
+		details: SigningRequestDetails<Chain::CryptoScheme>,
+	) {
+		REJECTED_CEREMONIES.inc(&[<Chain::CryptoScheme as CryptoScheme>::NAME, SIGNING_LABEL]);
+
+		let _res = details.result_sender.send(CeremonyOutcome::<
+			SigningCeremony<Chain::CryptoScheme>,
+		>::Err((
+			BTreeSet::new(),
+			SigningFailureReason::CeremonyQueueFull,
+		)));
+
+		// Remove a possible unauthorised ceremony
+		self.signing_states.cleanup_unauthorised_ceremony(&ceremony_id);
+	}
+
+	fn has_free_ceremony_slot(&self) -> bool {
+		self.signing_states.count_authorised_ceremonies() +
+			self.keygen_states.count_authorised_ceremonies() <
+			self.scheduling_limits.max_concurrent_ceremonies
+	}
+
 	fn on_key_handover_request(
 		&mut self,
 		ceremony_id: CeremonyId,
@@ -1,10 +1,15 @@
-use std::{collections::BTreeSet, pin::Pin, time::Duration};
+use std::{
+	collections::{BTreeSet, HashMap},
+	pin::Pin,
+	time::Duration,
+};
 
 use crate::{
 	client::{
 		self,
 		ceremony_manager::{
-			CeremonyHandle, CeremonyManager, CeremonyRequestState, SigningCeremony,
+			CeremonyHandle, CeremonyManager, CeremonyRequestState, SchedulingLimits,
+			SigningCeremony,
 		},
 		ceremony_runner::CeremonyRunner,
 		common::{BroadcastFailureReason, SigningFailureReason, SigningStageName},
@@ -13,6 +18,7 @@ use crate::{
 			ACCOUNT_IDS, CEREMONY_TIMEOUT_DURATION, DEFAULT_KEYGEN_SEED, DEFAULT_SIGNING_SEED,
 			INITIAL_LATEST_CEREMONY_ID,
 		},
+		keygen::generate_key_data,
 		CeremonyRequest, CeremonyRequestDetails, KeygenRequestDetails, MultisigData,
 		SigningRequestDetails,
 	},
@@ -108,6 +114,76 @@ fn send_signing_request<C: CryptoScheme>(
 	result_receiver
 }
 
+/// Sends a signing request to the ceremony manager of `account_id`, using that account's share of
+/// a test key, so that a ceremony between ceremony managers can complete.
+fn send_signing_request_with_own_key_share<C: CryptoScheme>(
+	ceremony_request_sender: &tokio::sync::mpsc::UnboundedSender<CeremonyRequest<C>>,
+	account_id: &AccountId,
+	participants: BTreeSet<AccountId32>,
+	ceremony_id: CeremonyId,
+) -> tokio::sync::oneshot::Receiver<
+	Result<Vec<C::Signature>, (BTreeSet<AccountId32>, SigningFailureReason)>,
+> {
+	let (result_sender, result_receiver) = oneshot::channel();
+
+	let (_, mut key_shares) =
+		generate_key_data::<C>(participants.clone(), &mut Rng::from_seed(DEFAULT_KEYGEN_SEED));
+	let request = CeremonyRequest {
+		ceremony_id,
+		details: Some(CeremonyRequestDetails::Sign(SigningRequestDetails::<C> {
+			participants,
+			signing_info: vec![(
+				key_shares.remove(account_id).expect("should be a participant"),
+				C::signing_payload_for_test(),
+			)],
+			rng: Rng::from_seed(account_id.clone().into()),
+			result_sender,
+		})),
+	};
+
+	let _result = ceremony_request_sender.send(request);
+
+	result_receiver
+}
+
+/// Forwards the outgoing p2p messages of each ceremony manager to the ceremony managers they are
+/// addressed to.
+fn route_p2p_messages(
+	nodes: Vec<(
+		AccountId,
+		mpsc::UnboundedSender<(AccountId32, VersionedCeremonyMessage)>,
+		mpsc::UnboundedReceiver<OutgoingMultisigStageMessages>,
+	)>,
+) {
+	let incoming_p2p_senders: HashMap<_, _> = nodes
+		.iter()
+		.map(|(account_id, incoming_p2p_sender, _)| {
+			(account_id.clone(), incoming_p2p_sender.clone())
+		})
+		.collect();
+
+	for (account_id, _, mut outgoing_p2p_receiver) in nodes {
+		let incoming_p2p_senders = incoming_p2p_senders.clone();
+		tokio::spawn(async move {
+			while let Some(messages) = outgoing_p2p_receiver.recv().await {
+				let messages = match messages {
+					OutgoingMultisigStageMessages::Broadcast(receiver_ids, payload) => receiver_ids
+						.into_iter()
+						.map(|receiver_id| (receiver_id, payload.clone()))
+						.collect(),
+					OutgoingMultisigStageMessages::Private(messages) => messages,
+				};
+				for (receiver_id, payload) in messages {
+					let _result = incoming_p2p_senders[&receiver_id].send((
+						account_id.clone(),
+						VersionedCeremonyMessage { version: CURRENT_PROTOCOL_VERSION, payload },
+					));
+				}
+			}
+		});
+	}
+}
+
 fn spawn_ceremony_manager<Chain: ChainSigning>(
 	our_account_id: AccountId,
 	latest_ceremony_id: CeremonyId,
@@ -115,12 +191,29 @@ fn spawn_ceremony_manager<Chain: ChainSigning>(
 	mpsc::UnboundedSender<CeremonyRequest<Chain::CryptoScheme>>,
 	mpsc::UnboundedSender<(AccountId32, VersionedCeremonyMessage)>,
 	mpsc::UnboundedReceiver<OutgoingMultisigStageMessages>,
+) {
+	spawn_ceremony_manager_with_limits::<Chain>(
+		our_account_id,
+		latest_ceremony_id,
+		SchedulingLimits::default(),
+	)
+}
+
+fn spawn_ceremony_manager_with_limits<Chain: ChainSigning>(
+	our_account_id: AccountId,
+	latest_ceremony_id: CeremonyId,
+	scheduling_limits: SchedulingLimits,
+) -> (
+	mpsc::UnboundedSender<CeremonyRequest<Chain::CryptoScheme>>,
+	mpsc::UnboundedSender<(AccountId32, VersionedCeremonyMessage)>,
+	mpsc::UnboundedReceiver<OutgoingMultisigStageMessages>,
 ) {
 	let (ceremony_request_sender, ceremony_request_receiver) = mpsc::unbounded_channel();
 	let (incoming_p2p_sender, incoming_p2p_receiver) = mpsc::unbounded_channel();
 	let (outgoing_p2p_sender, outgoing_p2p_receiver) = mpsc::unbounded_channel();
 	let ceremony_manager =
-		CeremonyManager::<Chain>::new(our_account_id, outgoing_p2p_sender, latest_ceremony_id);
+		CeremonyManager::<Chain>::new(our_account_id, outgoing_p2p_sender, latest_ceremony_id)
+			.with_scheduling_limits(scheduling_limits);
 	tokio::spawn(ceremony_manager.run(ceremony_request_receiver, incoming_p2p_receiver));
 
 	(ceremony_request_sender, incoming_p2p_sender, outgoing_p2p_receiver)
@@ -320,6 +413,132 @@ async fn should_send_outcome_of_authorised_ceremony() {
 	);
 }
 
+#[tokio::test(start_paused = true)]
+async fn should_queue_signing_requests_and_reject_them_once_the_queue_is_full() {
+	let our_account_id = ACCOUNT_IDS[0].clone();
+	let all_account_ids = BTreeSet::from_iter(ACCOUNT_IDS.iter().cloned());
+
+	let (ceremony_request_sender, incoming_p2p_sender, outgoing_p2p_receiver) =
+		spawn_ceremony_manager_with_limits::<EthSigning>(
+			our_account_id.clone(),
+			INITIAL_LATEST_CEREMONY_ID,
+			SchedulingLimits { max_concurrent_ceremonies: 1, max_queued_signing_requests: 1 },
+		);
+
+	let mut running_receiver = send_signing_request(
+		&ceremony_request_sender,
+		all_account_ids.clone(),
+		INITIAL_LATEST_CEREMONY_ID + 1,
+	);
+	let queued_receiver = send_signing_request_with_own_key_share(
+		&ceremony_request_sender,
+		&our_account_id,
+		all_account_ids.clone(),
+		INITIAL_LATEST_CEREMONY_ID + 2,
+	);
+	let mut rejected_receiver = send_signing_request(
+		&ceremony_request_sender,
+		all_account_ids.clone(),
+		INITIAL_LATEST_CEREMONY_ID + 3,
+	);
+
+	// Small delay to let the ceremony manager process the requests
+	tokio::time::sleep(Duration::from_millis(50)).await;
+
+	// The request that doesn't fit in the queue is rejected straight away
+	assert_eq!(
+		rejected_receiver.try_recv().unwrap(),
+		Err((BTreeSet::default(), SigningFailureReason::CeremonyQueueFull))
+	);
+	assert!(running_receiver.try_recv().is_err());
+
+	// The other participants only take part in the queued ceremony, so the running one times out.
+	// They start the queued ceremony shortly before that happens, so their first stage messages
+	// reach us while the ceremony is still queued.
+	let mut peers = vec![(our_account_id.clone(), incoming_p2p_sender, outgoing_p2p_receiver)];
+	let mut peer_request_senders = vec![];
+	for account_id in ACCOUNT_IDS.iter().skip(1) {
+		let (peer_request_sender, peer_incoming_p2p_sender, peer_outgoing_p2p_receiver) =
+			spawn_ceremony_manager::<EthSigning>(
+				account_id.clone(),
+				INITIAL_LATEST_CEREMONY_ID + 1,
+			);
+		peers.push((account_id.clone(), peer_incoming_p2p_sender, peer_outgoing_p2p_receiver));
+		peer_request_senders.push((account_id.clone(), peer_request_sender));
+	}
+	route_p2p_messages(peers);
+
+	tokio::time::sleep(CEREMONY_TIMEOUT_DURATION - Duration::from_secs(10)).await;
+	for (account_id, peer_request_sender) in &peer_request_senders {
+		let _peer_result_receiver = send_signing_request_with_own_key_share(
+			peer_request_sender,
+			account_id,
+			all_account_ids.clone(),
+			INITIAL_LATEST_CEREMONY_ID + 2,
+		);
+	}
+
+	// The queued ceremony only starts once the running one has finished
+	tokio::time::sleep(Duration::from_secs(10)).await;
+	assert_eq!(
+		running_receiver.try_recv().unwrap(),
+		Err((
+			BTreeSet::default(),
+			SigningFailureReason::BroadcastFailure(
+				BroadcastFailureReason::InsufficientVerificationMessages,
+				SigningStageName::VerifyCommitmentsBroadcast2,
+			),
+		))
+	);
+
+	// The messages received while queued were kept, so the queued ceremony completes
+	assert!(tokio::time::timeout(CEREMONY_TIMEOUT_DURATION, queued_receiver)
+		.await
+		.expect("queued ceremony should complete")
+		.unwrap()
+		.is_ok());
+}
+
+#[tokio::test]
+async fn should_not_queue_keygen_requests() {
+	let our_account_id = ACCOUNT_IDS[0].clone();
+	let (ceremony_request_sender, _incoming_p2p_sender, mut outgoing_p2p_receiver) =
+		spawn_ceremony_manager_with_limits::<EthSigning>(
+			our_account_id.clone(),
+			INITIAL_LATEST_CEREMONY_ID,
+			SchedulingLimits { max_concurrent_ceremonies: 1, max_queued_signing_requests: 1 },
+		);
+
+	// Take up the only slot with a signing ceremony
+	let _signing_result_receiver = send_signing_request(
+		&ceremony_request_sender,
+		BTreeSet::from_iter(ACCOUNT_IDS.iter().cloned()),
+		INITIAL_LATEST_CEREMONY_ID + 1,
+	);
+
+	let (result_sender, _keygen_result_receiver) = oneshot::channel();
+	let _result = ceremony_request_sender.send(CeremonyRequest {
+		ceremony_id: INITIAL_LATEST_CEREMONY_ID + 2,
+		details: Some(CeremonyRequestDetails::Keygen(KeygenRequestDetails {
+			rng: Rng::from_seed(DEFAULT_KEYGEN_SEED),
+			participants: BTreeSet::from_iter(ACCOUNT_IDS.iter().cloned()),
+			result_sender,
+			resharing_context: None,
+		})),
+	});
+
+	// Small delay to let the ceremonies start
+	tokio::time::sleep(Duration::from_millis(50)).await;
+
+	// Both ceremonies have broadcast their first stage
+	for _ in 0..2 {
+		assert!(matches!(
+			outgoing_p2p_receiver.try_recv().unwrap(),
+			OutgoingMultisigStageMessages::Broadcast(..)
+		));
+	}
+}
+
 #[tokio::test]
 async fn should_cleanup_unauthorised_ceremony_if_not_participating() {
 	task_scope(|scope| {
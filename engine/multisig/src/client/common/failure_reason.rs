@@ -40,6 +40,8 @@ pub enum SigningFailureReason {
 	DeserializationError,
 	#[error("Developer Error: {0}")]
 	DeveloperError(String),
+	#[error("Ceremony Queue Full")]
+	CeremonyQueueFull,
 }
 
 #[derive(Error, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -105,7 +107,8 @@ impl CeremonyFailureReason for SigningFailureReason {
 			SigningFailureReason::DeveloperError(_) |
 			SigningFailureReason::InvalidParticipants |
 			SigningFailureReason::NotEnoughSigners |
-			SigningFailureReason::UnknownKey => {
+			SigningFailureReason::UnknownKey |
+			SigningFailureReason::CeremonyQueueFull => {
 				warn!(tag = REQUEST_TO_SIGN_IGNORED, "{REQUEST_TO_SIGN_IGNORED_PREFIX}: {self}",);
 			},
 		}
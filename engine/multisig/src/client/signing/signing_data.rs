@@ -169,7 +169,7 @@ impl<P: ECPoint> PreProcessStageDataCheck<SigningStageName> for SigningData<P> {
 	fn is_initial_stage_data_size_valid<Chain: ChainSigning>(&self) -> bool {
 		match self {
 			SigningData::CommStage1(message) => match Chain::CHAIN_TAG {
-				ChainTag::Ethereum | ChainTag::Polkadot | ChainTag::Ed25519 =>
+				ChainTag::Ethereum | ChainTag::Polkadot | ChainTag::Solana =>
 					message.payload.len() <= max_signing_commitments_size(1),
 				ChainTag::Bitcoin =>
 				// At this stage we may not know the number of payloads, so we use a maximum
@@ -1,13 +1,19 @@
+use anyhow::Result;
+
 use super::{
 	curve25519::edwards::Point, CanonicalEncoding, ChainSigning, ChainTag, CryptoScheme, CryptoTag,
-	ECPoint,
+	ECPoint, SignatureToThresholdSignature,
 };
-use cf_chains::Chain;
+use cf_chains::{Chain, ChainCrypto, Solana};
 use ed25519_consensus::VerificationKeyBytes;
 use serde::{Deserialize, Serialize};
 
+/// The maximum size of a serialized Solana transaction, which bounds the size of the message we
+/// sign.
+const MAX_SOLANA_TRANSACTION_SIZE: usize = 1232;
+
 #[derive(Clone, Debug, PartialEq)]
-pub struct Ed25519Signing {}
+pub struct SolSigning {}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Signature {
@@ -24,6 +30,17 @@ impl Signature {
 	}
 }
 
+impl SignatureToThresholdSignature<<Solana as Chain>::ChainCrypto> for Vec<Signature> {
+	fn to_threshold_signature(
+		&self,
+	) -> <<Solana as Chain>::ChainCrypto as ChainCrypto>::ThresholdSignature {
+		self.iter()
+			.map(|s| s.to_bytes().into())
+			.next()
+			.expect("Exactly one signature for Solana")
+	}
+}
+
 impl CanonicalEncoding for VerificationKeyBytes {
 	fn encode_key(&self) -> Vec<u8> {
 		self.to_bytes().to_vec()
@@ -44,20 +61,25 @@ impl AsRef<[u8]> for SigningPayload {
 		self.0.as_ref()
 	}
 }
+
+impl SigningPayload {
+	pub fn new(payload: Vec<u8>) -> Result<Self> {
+		if payload.is_empty() || payload.len() > MAX_SOLANA_TRANSACTION_SIZE {
+			anyhow::bail!("Invalid payload size");
+		}
+		Ok(SigningPayload(payload))
+	}
+}
 #[derive(Clone, Debug, PartialEq)]
 pub struct Ed25519CryptoScheme;
 
-impl ChainSigning for Ed25519Signing {
+impl ChainSigning for SolSigning {
 	type CryptoScheme = Ed25519CryptoScheme;
-	// This scheme isn't implemented on the state chain.
-	type ChainCrypto = <cf_chains::none::NoneChain as Chain>::ChainCrypto;
+	type ChainCrypto = <Solana as Chain>::ChainCrypto;
 
-	const NAME: &'static str = "Ed25519";
+	const NAME: &'static str = "Solana";
 
-	// TODO: Technically the same "scheme" can be used by
-	// multiple chains, so we might want to decouple
-	// "scheme" from "chain".
-	const CHAIN_TAG: ChainTag = ChainTag::Ed25519;
+	const CHAIN_TAG: ChainTag = ChainTag::Solana;
 }
 
 impl CryptoScheme for Ed25519CryptoScheme {
@@ -16,6 +16,10 @@ impl KeyId {
 	pub fn new<Key: CanonicalEncoding>(epoch_index: EpochIndex, key: Key) -> Self {
 		KeyId { epoch_index, public_key_bytes: key.encode_key() }
 	}
+
+	pub fn epoch_index(&self) -> EpochIndex {
+		self.epoch_index
+	}
 }
 
 impl CanonicalEncoding for cf_chains::dot::PolkadotPublicKey {
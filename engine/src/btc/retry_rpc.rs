@@ -2,6 +2,7 @@ use bitcoin::{BlockHash, Txid};
 use utilities::task_scope::Scope;
 
 use crate::{
+	health::HealthReporter,
 	retrier::{Attempt, RequestLog, RetrierClient},
 	settings::{HttpBasicAuthEndpoint, NodeContainer},
 	witness::common::chain_source::{ChainClient, Header},
@@ -23,27 +24,38 @@ const MAX_CONCURRENT_SUBMISSIONS: u32 = 100;
 
 const MAX_BROADCAST_RETRIES: Attempt = 2;
 
+/// A block as compared between endpoints when requesting with a quorum. The `confirmations` and
+/// `next_block_hash` fields depend on each endpoint's view of the tip, so honest endpoints can
+/// disagree on them; only the block's hash and transactions are compared.
+struct QuorumBlock(VerboseBlock);
+
+impl PartialEq for QuorumBlock {
+	fn eq(&self, other: &Self) -> bool {
+		self.0.header.hash == other.0.header.hash && self.0.txdata == other.0.txdata
+	}
+}
+
 impl BtcRetryRpcClient {
 	pub async fn new(
 		scope: &Scope<'_, anyhow::Error>,
 		nodes: NodeContainer<HttpBasicAuthEndpoint>,
 		expected_btc_network: BitcoinNetwork,
+		health_reporter: HealthReporter,
 	) -> Result<Self> {
-		let rpc_client = BtcRpcClient::new(nodes.primary, Some(expected_btc_network))?;
-
-		let backup_rpc_client = nodes
-			.backup
-			.map(|backup_endpoint| BtcRpcClient::new(backup_endpoint, Some(expected_btc_network)))
-			.transpose()?;
+		let rpc_clients = nodes
+			.endpoints()
+			.map(|endpoint| BtcRpcClient::new(endpoint.clone(), Some(expected_btc_network)))
+			.collect::<Result<Vec<_>>>()?;
 
 		Ok(Self {
 			retry_client: RetrierClient::new(
 				scope,
 				"btc_rpc",
-				rpc_client,
-				backup_rpc_client,
+				rpc_clients,
+				nodes.quorum,
 				BITCOIN_RPC_TIMEOUT,
 				MAX_CONCURRENT_SUBMISSIONS,
+				health_reporter,
 			),
 		})
 	}
@@ -68,19 +80,20 @@ pub trait BtcRetryRpcApi: Clone {
 impl BtcRetryRpcApi for BtcRetryRpcClient {
 	async fn block(&self, block_hash: BlockHash) -> VerboseBlock {
 		self.retry_client
-			.request(
+			.request_with_quorum(
 				RequestLog::new("block".to_string(), Some(format!("{block_hash}"))),
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
-					Box::pin(async move { client.block(block_hash).await })
+					Box::pin(async move { client.block(block_hash).await.map(QuorumBlock) })
 				}),
 			)
 			.await
+			.0
 	}
 
 	async fn block_hash(&self, block_number: cf_chains::btc::BlockNumber) -> BlockHash {
 		self.retry_client
-			.request(
+			.request_with_quorum(
 				RequestLog::new("block_hash".to_string(), Some(format!("{block_number}"))),
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
@@ -185,6 +198,79 @@ impl ChainClient for BtcRetryRpcClient {
 	}
 }
 
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::btc::rpc::Difficulty;
+	use bitcoin::{block::Version, hash_types::TxMerkleNode, hashes::Hash};
+	use futures::FutureExt;
+	use utilities::task_scope::task_scope;
+
+	fn block(confirmations: i32, next_block_hash: Option<BlockHash>) -> VerboseBlock {
+		VerboseBlock {
+			header: BlockHeader {
+				hash: BlockHash::from_byte_array([1; 32]),
+				confirmations,
+				height: 2000,
+				version: Version::from_consensus(0),
+				version_hex: Default::default(),
+				merkle_root: TxMerkleNode::from_byte_array([0; 32]),
+				time: 0,
+				median_time: Default::default(),
+				nonce: 0,
+				bits: Default::default(),
+				difficulty: Difficulty::Number(1.0),
+				chainwork: Default::default(),
+				n_tx: Default::default(),
+				previous_block_hash: Some(BlockHash::from_byte_array([0; 32])),
+				next_block_hash,
+				strippedsize: None,
+				size: None,
+				weight: None,
+			},
+			txdata: vec![],
+		}
+	}
+
+	#[tokio::test]
+	async fn quorum_is_reached_on_blocks_seen_from_different_tips() {
+		task_scope(|scope| {
+			async move {
+				// Each endpoint's client is the block that endpoint returns.
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					vec![
+						futures::future::ready(block(1, None)),
+						futures::future::ready(block(2, Some(BlockHash::from_byte_array([2; 32])))),
+					],
+					2,
+					Duration::from_millis(100),
+					100,
+					HealthReporter::default(),
+				);
+
+				let quorum_block = tokio::time::timeout(
+					Duration::from_secs(5),
+					retrier_client.request_with_quorum(
+						RequestLog::new("block".to_string(), None),
+						Box::pin(|client| Box::pin(async move { Ok(QuorumBlock(client)) })),
+					),
+				)
+				.await
+				.expect("endpoints should agree on the same block")
+				.0;
+				assert_eq!(quorum_block.header.hash, BlockHash::from_byte_array([1; 32]));
+
+				Ok(())
+			}
+			.boxed()
+		})
+		.await
+		.unwrap();
+	}
+}
+
 #[cfg(test)]
 pub mod mocks {
 
@@ -222,6 +222,18 @@ pub struct VerboseTxIn {
 	pub outpoint: VerboseOutPoint,
 	pub txinwitness: Option<Vec<String>>,
 	pub sequence: Sequence,
+	/// The output spent by this input. Only included by `getblock` with verbosity 3.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub prevout: Option<VerbosePrevOut>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct VerbosePrevOut {
+	#[serde(with = "bitcoin::amount::serde::as_btc")]
+	pub value: Amount,
+	#[serde(rename = "scriptPubKey")]
+	#[serde(deserialize_with = "deserialize_scriptpubkey")]
+	pub script_pubkey: ScriptBuf,
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
@@ -355,7 +367,7 @@ pub trait BtcRpcApi {
 impl BtcRpcApi for BtcRpcClient {
 	async fn block(&self, block_hash: BlockHash) -> anyhow::Result<VerboseBlock> {
 		Ok(self
-			.call_rpc("getblock", ReqParams::Batch(vec![json!([json!(block_hash), json!(2)])]))
+			.call_rpc("getblock", ReqParams::Batch(vec![json!([json!(block_hash), json!(3)])]))
 			.await?
 			.into_iter()
 			.next()
@@ -511,6 +523,20 @@ mod tests {
 		let _result: VerboseBlock = serde_path_to_error::deserialize(jd).unwrap();
 	}
 
+	#[test]
+	fn test_tx_in_with_prevout() {
+		// An input from a `getblock` RPC call with verbosity 3
+		let tx_in_data: &str = r#"{"txid":"773171b0c840f1dc69c247db64efc553dc2ce0c18558cd9ecef8e7bf81a81f2d","vout":0,"scriptSig":{"asm":"","hex":""},"txinwitness":[],"prevout":{"generated":false,"height":112,"value":50.00000000,"scriptPubKey":{"asm":"0 a66802f0279cc06c04abe451733d0644b7cd1aa3","desc":"addr(bcrt1q5e5q9up8nnqxcp9tu3ghx0gxgjmu6x4rkhnr04)#qqa77u3g","hex":"0014a66802f0279cc06c04abe451733d0644b7cd1aa3","address":"bcrt1q5e5q9up8nnqxcp9tu3ghx0gxgjmu6x4rkhnr04","type":"witness_v0_keyhash"}},"sequence":4294967293}"#;
+		let tx_in: VerboseTxIn = serde_json::from_str(tx_in_data).unwrap();
+
+		let prevout = tx_in.prevout.unwrap();
+		assert_eq!(prevout.value, Amount::from_sat(5_000_000_000));
+		assert_eq!(
+			prevout.script_pubkey,
+			ScriptBuf::from(hex::decode("0014a66802f0279cc06c04abe451733d0644b7cd1aa3").unwrap())
+		);
+	}
+
 	#[tokio::test]
 	#[ignore = "requires local node, useful for manual testing"]
 	async fn test_btc_async() {
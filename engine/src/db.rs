@@ -1,3 +1,4 @@
+pub mod key_share_backup;
 pub mod persistent;
 use std::{collections::HashMap, sync::Arc};
 
@@ -0,0 +1,350 @@
+//! Password-encrypted backups of the key shares held in a [PersistentKeyDB].
+//!
+//! A backup file is laid out as follows:
+//!
+//! | magic (8 bytes) | version (2 bytes, big endian) | salt (16 bytes) | nonce (12 bytes) | ciphertext |
+//!
+//! The encryption key is derived from the password using scrypt and the plaintext (the bincode
+//! encoded [KeyShareBackup]) is encrypted with ChaCha20-Poly1305. The header is authenticated
+//! as associated data, so tampering with any part of the file causes decryption to fail.
+
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use cf_chains::{
+	instances::{ChainCryptoInstanceAlias, CryptoInstanceFor},
+	ChainCrypto,
+};
+use cf_primitives::EpochIndex;
+use chacha20poly1305::{
+	aead::{Aead, KeyInit, Payload},
+	ChaCha20Poly1305, Key, Nonce,
+};
+use multisig::{
+	bitcoin::BtcSigning, client::KeygenResultInfo, ed25519::SolSigning, eth::EthSigning,
+	polkadot::PolkadotSigning, ChainSigning, KeyId,
+};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+use zeroize::Zeroizing;
+
+use super::PersistentKeyDB;
+use crate::state_chain_observer::client::storage_api::StorageApi;
+
+const MAGIC: &[u8; 8] = b"CFKEYBAK";
+
+/// The version of the backup file format produced by this build. This *must* be bumped on any
+/// change to the file layout, the key derivation parameters or the format of [KeyShareBackup].
+pub const KEY_SHARE_BACKUP_VERSION: u16 = 1;
+
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const HEADER_LENGTH: usize = MAGIC.len() + 2 + SALT_LENGTH + NONCE_LENGTH;
+
+/// Scrypt parameters used by version 1 of the file format (N = 2^15, r = 8, p = 1).
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+
+type KeyShares<C> = Vec<(KeyId, KeygenResultInfo<<C as ChainSigning>::CryptoScheme>)>;
+
+/// All of the key shares held by a validator, for every chain.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct KeyShareBackup {
+	pub genesis_hash: Option<state_chain_runtime::Hash>,
+	pub ethereum: KeyShares<EthSigning>,
+	pub polkadot: KeyShares<PolkadotSigning>,
+	pub bitcoin: KeyShares<BtcSigning>,
+	pub solana: KeyShares<SolSigning>,
+}
+
+impl KeyShareBackup {
+	/// Collects all key shares from the db.
+	pub fn from_db(db: &PersistentKeyDB) -> Result<Self> {
+		fn load<C: ChainSigning>(db: &PersistentKeyDB) -> KeyShares<C> {
+			let mut keys = db.load_keys::<C>().into_iter().collect::<Vec<_>>();
+			keys.sort_by_key(|(key_id, _)| key_id.epoch_index());
+			keys
+		}
+
+		Ok(Self {
+			genesis_hash: db.get_genesis_hash()?,
+			ethereum: load::<EthSigning>(db),
+			polkadot: load::<PolkadotSigning>(db),
+			bitcoin: load::<BtcSigning>(db),
+			solana: load::<SolSigning>(db),
+		})
+	}
+
+	/// Writes all key shares into the db. The db must not contain any key shares already, so
+	/// that an import can never overwrite or mix with existing keys.
+	pub fn import_into_db(&self, db: &PersistentKeyDB) -> Result<()> {
+		fn store<C: ChainSigning>(db: &PersistentKeyDB, keys: &KeyShares<C>) -> Result<()> {
+			ensure!(
+				db.load_keys::<C>().is_empty(),
+				"The database already contains {} key shares. Key shares can only be imported into a fresh database.",
+				C::NAME
+			);
+			for (key_id, keygen_result_info) in keys {
+				db.update_key::<C>(key_id, keygen_result_info);
+			}
+			Ok(())
+		}
+
+		match (db.get_genesis_hash()?, self.genesis_hash) {
+			(Some(db_genesis_hash), Some(backup_genesis_hash)) => ensure!(
+				db_genesis_hash == backup_genesis_hash,
+				"The backup was created for a network with genesis hash {backup_genesis_hash:?}, but the database is for genesis hash {db_genesis_hash:?}"
+			),
+			(None, Some(backup_genesis_hash)) => db.put_genesis_hash(backup_genesis_hash)?,
+			(_, None) => {},
+		}
+
+		store::<EthSigning>(db, &self.ethereum)?;
+		store::<PolkadotSigning>(db, &self.polkadot)?;
+		store::<BtcSigning>(db, &self.bitcoin)?;
+		store::<SolSigning>(db, &self.solana)?;
+
+		info!("Imported {} key shares into the database", self.key_count());
+
+		Ok(())
+	}
+
+	pub fn key_count(&self) -> usize {
+		self.ethereum.len() + self.polkadot.len() + self.bitcoin.len() + self.solana.len()
+	}
+
+	/// Checks that every key share is consistent with the key id it is stored under, i.e. that
+	/// the aggregate key of the share is the key the id refers to.
+	pub fn verify_key_shares(&self) -> Result<()> {
+		fn verify<C: ChainSigning>(keys: &KeyShares<C>) -> Result<()> {
+			for (key_id, keygen_result_info) in keys {
+				ensure!(
+					KeyId::new(key_id.epoch_index(), keygen_result_info.key.get_agg_public_key()) ==
+						*key_id,
+					"{} key share does not match its key id {key_id}",
+					C::NAME
+				);
+			}
+			Ok(())
+		}
+
+		verify::<EthSigning>(&self.ethereum)?;
+		verify::<PolkadotSigning>(&self.polkadot)?;
+		verify::<BtcSigning>(&self.bitcoin)?;
+		verify::<SolSigning>(&self.solana)?;
+
+		Ok(())
+	}
+
+	/// Checks that the key ids match the aggregate keys recorded on the State Chain for their
+	/// epochs. Keys for epochs that are no longer stored on chain cannot be checked and are
+	/// skipped with a warning.
+	pub async fn verify_against_state_chain<StateChainClient: StorageApi + Send + Sync>(
+		&self,
+		state_chain_client: &StateChainClient,
+		block_hash: state_chain_runtime::Hash,
+	) -> Result<()> {
+		verify_against_on_chain_keys::<EthSigning, _>(
+			state_chain_client,
+			block_hash,
+			&self.ethereum,
+			KeyId::new,
+		)
+		.await?;
+		verify_against_on_chain_keys::<PolkadotSigning, _>(
+			state_chain_client,
+			block_hash,
+			&self.polkadot,
+			KeyId::new,
+		)
+		.await?;
+		verify_against_on_chain_keys::<BtcSigning, _>(
+			state_chain_client,
+			block_hash,
+			&self.bitcoin,
+			|epoch_index, agg_key| KeyId::new(epoch_index, agg_key.current),
+		)
+		.await?;
+		verify_against_on_chain_keys::<SolSigning, _>(
+			state_chain_client,
+			block_hash,
+			&self.solana,
+			KeyId::new,
+		)
+		.await?;
+
+		Ok(())
+	}
+
+	/// Serializes and encrypts the backup with the given password.
+	pub fn encrypt(&self, password: &str) -> Result<Vec<u8>> {
+		let mut salt = [0u8; SALT_LENGTH];
+		let mut nonce = [0u8; NONCE_LENGTH];
+		rand::thread_rng().fill_bytes(&mut salt);
+		rand::thread_rng().fill_bytes(&mut nonce);
+
+		let header =
+			[&MAGIC[..], &KEY_SHARE_BACKUP_VERSION.to_be_bytes()[..], &salt[..], &nonce[..]]
+				.concat();
+
+		let plaintext = Zeroizing::new(
+			bincode::serialize(self).expect("Serialization is not expected to fail"),
+		);
+
+		let ciphertext = cipher(password, &salt)?
+			.encrypt(Nonce::from_slice(&nonce), Payload { msg: &plaintext, aad: &header })
+			.map_err(|_| anyhow!("Failed to encrypt key share backup"))?;
+
+		Ok([header, ciphertext].concat())
+	}
+
+	/// Decrypts and deserializes a backup produced by [Self::encrypt].
+	pub fn decrypt(bytes: &[u8], password: &str) -> Result<Self> {
+		ensure!(
+			bytes.len() > HEADER_LENGTH && bytes.starts_with(MAGIC),
+			"Not a Chainflip key share backup file"
+		);
+		let (header, ciphertext) = bytes.split_at(HEADER_LENGTH);
+
+		let version = u16::from_be_bytes(
+			header[MAGIC.len()..MAGIC.len() + 2].try_into().expect("Checked length above"),
+		);
+		if version != KEY_SHARE_BACKUP_VERSION {
+			bail!(
+				"Unsupported key share backup version {version}, expected {KEY_SHARE_BACKUP_VERSION}"
+			);
+		}
+		let salt = &header[MAGIC.len() + 2..MAGIC.len() + 2 + SALT_LENGTH];
+		let nonce = &header[MAGIC.len() + 2 + SALT_LENGTH..];
+
+		let plaintext = Zeroizing::new(
+			cipher(password, salt)?
+				.decrypt(Nonce::from_slice(nonce), Payload { msg: ciphertext, aad: header })
+				.map_err(|_| {
+					anyhow!("Failed to decrypt key share backup: wrong password or corrupted file")
+				})?,
+		);
+
+		bincode::deserialize(&plaintext).context("Failed to deserialize key share backup")
+	}
+}
+
+fn cipher(password: &str, salt: &[u8]) -> Result<ChaCha20Poly1305> {
+	let mut key = Zeroizing::new([0u8; 32]);
+	scrypt::scrypt(
+		password.as_bytes(),
+		salt,
+		&scrypt::Params::new(SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P)
+			.expect("Scrypt parameters are valid"),
+		&mut key[..],
+	)
+	.map_err(|e| anyhow!("Failed to derive encryption key: {e}"))?;
+
+	Ok(ChaCha20Poly1305::new(Key::from_slice(&key[..])))
+}
+
+async fn verify_against_on_chain_keys<C, StateChainClient>(
+	state_chain_client: &StateChainClient,
+	block_hash: state_chain_runtime::Hash,
+	keys: &KeyShares<C>,
+	on_chain_key_id: impl Fn(EpochIndex, <C::ChainCrypto as ChainCrypto>::AggKey) -> KeyId,
+) -> Result<()>
+where
+	C: ChainSigning,
+	C::ChainCrypto: ChainCryptoInstanceAlias,
+	StateChainClient: StorageApi + Send + Sync,
+	state_chain_runtime::Runtime: pallet_cf_threshold_signature::Config<
+		CryptoInstanceFor<C::ChainCrypto>,
+		TargetChainCrypto = C::ChainCrypto,
+	>,
+{
+	for (key_id, _) in keys {
+		match state_chain_client
+			.storage_map_entry::<pallet_cf_threshold_signature::Keys<
+				state_chain_runtime::Runtime,
+				CryptoInstanceFor<C::ChainCrypto>,
+			>>(block_hash, &key_id.epoch_index())
+			.await?
+		{
+			Some(agg_key) => ensure!(
+				on_chain_key_id(key_id.epoch_index(), agg_key) == *key_id,
+				"{} key share {key_id} does not match the aggregate key on the State Chain",
+				C::NAME
+			),
+			None => warn!(
+				"No {} aggregate key on the State Chain for epoch {}, skipping verification of {key_id}",
+				C::NAME,
+				key_id.epoch_index()
+			),
+		}
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use cf_primitives::AccountId;
+	use multisig::{client::keygen, eth::EvmCryptoScheme, Rng};
+	use rand::SeedableRng;
+	use std::collections::BTreeSet;
+
+	fn backup_with_eth_key() -> KeyShareBackup {
+		let (public_key, key_data) = keygen::generate_key_data::<EvmCryptoScheme>(
+			BTreeSet::from([AccountId::new([1; 32])]),
+			&mut Rng::from_seed([4; 32]),
+		);
+
+		KeyShareBackup {
+			genesis_hash: Some(state_chain_runtime::Hash::repeat_byte(0xcf)),
+			ethereum: vec![(KeyId::new(3, public_key), key_data.into_values().next().unwrap())],
+			polkadot: vec![],
+			bitcoin: vec![],
+			solana: vec![],
+		}
+	}
+
+	#[test]
+	fn backup_can_be_decrypted_with_correct_password_only() {
+		let backup = backup_with_eth_key();
+		let encrypted = backup.encrypt("correct horse").unwrap();
+
+		assert_eq!(KeyShareBackup::decrypt(&encrypted, "correct horse").unwrap(), backup);
+		assert!(KeyShareBackup::decrypt(&encrypted, "battery staple").is_err());
+
+		// Tampering with the header or the ciphertext is detected.
+		for index in [MAGIC.len() + 2, encrypted.len() - 1] {
+			let mut tampered = encrypted.clone();
+			tampered[index] ^= 1;
+			assert!(KeyShareBackup::decrypt(&tampered, "correct horse").is_err());
+		}
+	}
+
+	#[test]
+	fn key_shares_round_trip_through_a_fresh_db() {
+		let backup = backup_with_eth_key();
+		backup.verify_key_shares().unwrap();
+
+		let (_dir, db_path) = utilities::testing::new_temp_directory_with_nonexistent_file();
+		let db = PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap();
+		backup.import_into_db(&db).unwrap();
+
+		assert_eq!(KeyShareBackup::from_db(&db).unwrap(), backup);
+
+		// Importing again would mix the backup with existing keys.
+		assert!(backup.import_into_db(&db).is_err());
+	}
+
+	#[test]
+	fn key_share_must_match_its_key_id() {
+		let mut backup = backup_with_eth_key();
+		let (other_public_key, _) = keygen::generate_key_data::<EvmCryptoScheme>(
+			BTreeSet::from([AccountId::new([1; 32])]),
+			&mut Rng::from_seed([5; 32]),
+		);
+		backup.ethereum[0].0 = KeyId::new(3, other_public_key);
+
+		assert!(backup.verify_key_shares().is_err());
+	}
+}
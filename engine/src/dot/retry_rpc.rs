@@ -1,5 +1,5 @@
 use crate::{
-	common::option_inner,
+	health::HealthReporter,
 	retrier::{Attempt, RetryLimitReturn},
 	settings::{NodeContainer, WsHttpEndpoints},
 	witness::common::chain_source::{ChainClient, Header},
@@ -46,8 +46,9 @@ impl DotRetryRpcClient {
 		scope: &Scope<'_, anyhow::Error>,
 		nodes: NodeContainer<WsHttpEndpoints>,
 		expected_genesis_hash: PolkadotHash,
+		health_reporter: HealthReporter,
 	) -> Result<Self> {
-		Self::new_inner(scope, nodes, Some(expected_genesis_hash))
+		Self::new_inner(scope, nodes, Some(expected_genesis_hash), health_reporter)
 	}
 
 	fn new_inner(
@@ -55,40 +56,59 @@ impl DotRetryRpcClient {
 		nodes: NodeContainer<WsHttpEndpoints>,
 		// The genesis hash is optional to facilitate testing
 		expected_genesis_hash: Option<PolkadotHash>,
+		health_reporter: HealthReporter,
 	) -> Result<Self> {
-		let f_create_clients = |endpoints: WsHttpEndpoints| {
+		let f_create_clients = |endpoints: &WsHttpEndpoints| {
 			Result::<_, anyhow::Error>::Ok((
-				DotHttpRpcClient::new(endpoints.http_endpoint, expected_genesis_hash)?,
-				DotSubClient::new(endpoints.ws_endpoint, expected_genesis_hash),
+				DotHttpRpcClient::new(endpoints.http_endpoint.clone(), expected_genesis_hash)?,
+				futures::future::ready(DotSubClient::new(
+					endpoints.ws_endpoint.clone(),
+					expected_genesis_hash,
+				)),
 			))
 		};
 
-		let (rpc_client, sub_client) = f_create_clients(nodes.primary)?;
-
-		let (backup_rpc_client, backup_sub_client) =
-			option_inner(nodes.backup.map(f_create_clients).transpose()?);
+		let (rpc_clients, sub_clients): (Vec<_>, Vec<_>) = nodes
+			.endpoints()
+			.map(f_create_clients)
+			.collect::<Result<Vec<_>>>()?
+			.into_iter()
+			.unzip();
 
 		Ok(DotRetryRpcClient {
 			rpc_retry_client: RetrierClient::new(
 				scope,
 				"dot_rpc",
-				rpc_client,
-				backup_rpc_client,
+				rpc_clients,
+				nodes.quorum,
 				POLKADOT_RPC_TIMEOUT,
 				MAX_CONCURRENT_SUBMISSIONS,
+				health_reporter.clone(),
 			),
+			// Headers are checked against the block hash quorum when witnessing, so the
+			// subscriptions don't need a quorum.
 			sub_retry_client: RetrierClient::new(
 				scope,
 				"dot_subscribe",
-				futures::future::ready(sub_client),
-				backup_sub_client.map(futures::future::ready),
+				sub_clients,
+				1,
 				POLKADOT_RPC_TIMEOUT,
 				MAX_CONCURRENT_SUBMISSIONS,
+				health_reporter,
 			),
 		})
 	}
 }
 
+/// Events compared by their encoded bytes, so that endpoints can be checked against the quorum.
+struct QuorumEvents(Option<Events<PolkadotConfig>>);
+
+impl PartialEq for QuorumEvents {
+	fn eq(&self, other: &Self) -> bool {
+		self.0.as_ref().map(Events::bytes) == other.0.as_ref().map(Events::bytes)
+	}
+}
+
 #[async_trait::async_trait]
 pub trait DotRetryRpcApi: Clone {
 	async fn block_hash(&self, block_number: PolkadotBlockNumber) -> Option<PolkadotHash>;
@@ -114,7 +134,7 @@ pub trait DotRetryRpcApi: Clone {
 impl DotRetryRpcApi for DotRetryRpcClient {
 	async fn block_hash(&self, block_number: PolkadotBlockNumber) -> Option<PolkadotHash> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				RequestLog::new("block_hash".to_string(), Some(format!("{block_number}"))),
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
@@ -126,7 +146,7 @@ impl DotRetryRpcApi for DotRetryRpcClient {
 
 	async fn extrinsics(&self, block_hash: PolkadotHash) -> Vec<Bytes> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				RequestLog::new("extrinsics".to_string(), Some(format!("{block_hash:?}"))),
 				Box::pin(move |client| {
 					#[allow(clippy::redundant_async_block)]
@@ -146,16 +166,20 @@ impl DotRetryRpcApi for DotRetryRpcClient {
 		parent_hash: PolkadotHash,
 		retry_limit: R,
 	) -> R::ReturnType<Option<Events<PolkadotConfig>>> {
-		self.rpc_retry_client
-			.request_with_limit(
-				RequestLog::new("events".to_string(), Some(format!("{block_hash:?}"))),
-				Box::pin(move |client: DotHttpRpcClient| {
-					#[allow(clippy::redundant_async_block)]
-					Box::pin(async move { client.events(block_hash, parent_hash).await })
-				}),
-				retry_limit,
-			)
-			.await
+		R::map_return_type(
+			self.rpc_retry_client
+				.request_with_quorum_and_limit(
+					RequestLog::new("events".to_string(), Some(format!("{block_hash:?}"))),
+					Box::pin(move |client: DotHttpRpcClient| {
+						Box::pin(async move {
+							client.events(block_hash, parent_hash).await.map(QuorumEvents)
+						})
+					}),
+					retry_limit,
+				)
+				.await,
+			|QuorumEvents(events)| events,
+		)
 	}
 
 	async fn runtime_version(&self, block_hash: Option<H256>) -> RuntimeVersion {
@@ -332,14 +356,12 @@ mod tests {
 			async move {
 				let dot_retry_rpc_client = DotRetryRpcClient::new_inner(
 					scope,
-					NodeContainer {
-						primary: WsHttpEndpoints {
-							http_endpoint: "http://127.0.0.1:9945".into(),
-							ws_endpoint: "ws://127.0.0.1:9945".into(),
-						},
-						backup: None,
-					},
+					NodeContainer::primary_only(WsHttpEndpoints {
+						http_endpoint: "http://127.0.0.1:9945".into(),
+						ws_endpoint: "ws://127.0.0.1:9945".into(),
+					}),
 					None,
+					Default::default(),
 				)
 				.unwrap();
 
@@ -11,6 +11,7 @@ use utilities::task_scope::Scope;
 
 use crate::{
 	evm::rpc::{EvmRpcApi, EvmSigningRpcApi},
+	health::HealthReporter,
 	retrier::{Attempt, RequestLog, RetrierClient},
 	settings::{NodeContainer, WsHttpEndpoints},
 	witness::common::chain_source::{ChainClient, Header},
@@ -44,39 +45,44 @@ impl<Rpc: EvmRpcApi> EvmRetryRpcClient<Rpc> {
 		scope: &Scope<'_, anyhow::Error>,
 		nodes: NodeContainer<WsHttpEndpoints>,
 		expected_chain_id: U256,
-		rpc_client: ClientFut,
-		backup_rpc_client: Option<ClientFut>,
+		rpc_clients: Vec<ClientFut>,
 		evm_rpc_client_name: &'static str,
 		evm_subscription_client_name: &'static str,
 		chain_name: &'static str,
 		witness_period: u64,
+		health_reporter: HealthReporter,
 	) -> Self {
-		let sub_client = ReconnectSubscriptionClient::new(
-			nodes.primary.ws_endpoint,
-			expected_chain_id,
-			chain_name,
-		);
-
-		let backup_sub_client = nodes.backup.as_ref().map(|ep| {
-			ReconnectSubscriptionClient::new(ep.ws_endpoint.clone(), expected_chain_id, chain_name)
-		});
+		let sub_clients = nodes
+			.endpoints()
+			.map(|ep| {
+				futures::future::ready(ReconnectSubscriptionClient::new(
+					ep.ws_endpoint.clone(),
+					expected_chain_id,
+					chain_name,
+				))
+			})
+			.collect();
 
 		Self {
 			rpc_retry_client: RetrierClient::new(
 				scope,
 				evm_rpc_client_name,
-				rpc_client,
-				backup_rpc_client,
+				rpc_clients,
+				nodes.quorum,
 				ETHERS_RPC_TIMEOUT,
 				MAX_CONCURRENT_SUBMISSIONS,
+				health_reporter.clone(),
 			),
+			// Block headers are checked when the logs of the block are requested, so the
+			// subscription doesn't need a quorum.
 			sub_retry_client: RetrierClient::new(
 				scope,
 				evm_subscription_client_name,
-				futures::future::ready(sub_client),
-				backup_sub_client.map(futures::future::ready),
+				sub_clients,
+				1,
 				ETHERS_RPC_TIMEOUT,
 				MAX_CONCURRENT_SUBMISSIONS,
+				health_reporter,
 			),
 			chain_name,
 			witness_period,
@@ -93,31 +99,25 @@ impl EvmRetryRpcClient<EvmRpcClient> {
 		evm_subscription_client_name: &'static str,
 		chain_name: &'static str,
 		witness_period: u64,
+		health_reporter: HealthReporter,
 	) -> Result<Self> {
-		let rpc_client = EvmRpcClient::new(
-			nodes.primary.http_endpoint.clone(),
-			expected_chain_id.as_u64(),
-			chain_name,
-		)?;
-
-		let backup_rpc_client = nodes
-			.backup
-			.as_ref()
+		let rpc_clients = nodes
+			.endpoints()
 			.map(|ep| {
 				EvmRpcClient::new(ep.http_endpoint.clone(), expected_chain_id.as_u64(), chain_name)
 			})
-			.transpose()?;
+			.collect::<Result<Vec<_>>>()?;
 
 		Ok(Self::from_inner_clients(
 			scope,
 			nodes,
 			expected_chain_id,
-			rpc_client,
-			backup_rpc_client,
+			rpc_clients,
 			evm_rpc_client_name,
 			evm_subscription_client_name,
 			chain_name,
 			witness_period,
+			health_reporter,
 		))
 	}
 }
@@ -132,17 +132,10 @@ impl EvmRetryRpcClient<EvmRpcSigningClient> {
 		evm_subscription_client_name: &'static str,
 		chain_name: &'static str,
 		witness_period: u64,
+		health_reporter: HealthReporter,
 	) -> Result<Self> {
-		let rpc_client = EvmRpcSigningClient::new(
-			private_key_file.clone(),
-			nodes.primary.http_endpoint.clone(),
-			expected_chain_id.as_u64(),
-			chain_name,
-		)?;
-
-		let backup_rpc_client = nodes
-			.backup
-			.as_ref()
+		let rpc_clients = nodes
+			.endpoints()
 			.map(|ep| {
 				EvmRpcSigningClient::new(
 					private_key_file.clone(),
@@ -151,18 +144,18 @@ impl EvmRetryRpcClient<EvmRpcSigningClient> {
 					chain_name,
 				)
 			})
-			.transpose()?;
+			.collect::<Result<Vec<_>>>()?;
 
 		Ok(Self::from_inner_clients(
 			scope,
 			nodes,
 			expected_chain_id,
-			rpc_client,
-			backup_rpc_client,
+			rpc_clients,
 			evm_rpc_client_name,
 			evm_subscription_client_name,
 			chain_name,
 			witness_period,
+			health_reporter,
 		))
 	}
 }
@@ -212,7 +205,7 @@ impl<Rpc: EvmRpcApi> EvmRetryRpcApi for EvmRetryRpcClient<Rpc> {
 	) -> Vec<Log> {
 		assert!(!range.is_empty());
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				RequestLog::new(
 					"get_logs_range".to_string(),
 					Some(format!("{range:?}, {contract_address:?}")),
@@ -238,7 +231,7 @@ impl<Rpc: EvmRpcApi> EvmRetryRpcApi for EvmRetryRpcClient<Rpc> {
 
 	async fn get_logs(&self, block_hash: H256, contract_address: H160) -> Vec<Log> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				RequestLog::new(
 					"get_logs".to_string(),
 					Some(format!("{block_hash:?}, {contract_address:?}")),
@@ -567,6 +560,7 @@ mod tests {
 					"eth_subscribe",
 					"Ethereum",
 					Ethereum::WITNESS_PERIOD,
+					Default::default(),
 				)
 				.unwrap();
 
@@ -35,7 +35,7 @@ impl<Rpc: EvmRpcApi + AddressCheckerRpcApi> AddressCheckerRetryRpcApi for EvmRet
 		addresses: Vec<H160>,
 	) -> Vec<AddressState> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				RequestLog::new(
 					"address_states".to_string(),
 					Some(format!("{block_hash:?}, {contract_address:?}")),
@@ -58,7 +58,7 @@ impl<Rpc: EvmRpcApi + AddressCheckerRpcApi> AddressCheckerRetryRpcApi for EvmRet
 		addresses: Vec<H160>,
 	) -> Vec<U256> {
 		self.rpc_retry_client
-			.request(
+			.request_with_quorum(
 				RequestLog::new(
 					"balances".to_string(),
 					Some(format!("{block_hash:?}, {contract_address:?}")),
@@ -1,10 +1,22 @@
 //! Health monitor for the CFE
 //! allowing external services to query, ensuring it's online
-//! Returns a HTTP 200 response to any request on {hostname}:{port}/health
+//! Returns a JSON report of the health of each subsystem on {hostname}:{port}/health and
+//! {hostname}:{port}/ready. The subsystems report their progress and status through the
+//! [HealthReporter] shared with the health server.
 //! Method returns a Sender, allowing graceful termination of the infinite loop
+//!
+//! - `/health` returns HTTP 503 if a subsystem has stalled, so the engine should be restarted.
+//! - `/ready` additionally returns HTTP 503 until the engine has completed initialising and while
+//!   any of its external dependencies (RPC endpoints, p2p peers, the database) are unavailable.
 
-use std::{net::IpAddr, sync::Arc};
+use std::{
+	collections::BTreeMap,
+	net::IpAddr,
+	sync::{atomic::AtomicBool, Arc, Mutex, Weak},
+	time::{Duration, Instant},
+};
 
+use serde::Serialize;
 use tracing::info;
 use utilities::task_scope;
 use warp::Filter;
@@ -14,27 +26,316 @@ use crate::settings;
 const INITIALISING: &str = "INITIALISING";
 const RUNNING: &str = "RUNNING";
 
+/// A witnesser has stalled if it hasn't processed a block for this long, even though the head of
+/// the chain it is witnessing has progressed in that time.
+const WITNESSER_STALL_TIMEOUT: Duration = Duration::from_secs(20 * 60);
+
+/// The State Chain observer has stalled if it hasn't processed a block for this long.
+const STATE_CHAIN_OBSERVER_STALL_TIMEOUT: Duration = Duration::from_secs(2 * 60);
+
+struct Progress {
+	block_number: u64,
+	updated_at: Instant,
+}
+
+impl Progress {
+	fn now(block_number: u64) -> Self {
+		Self { block_number, updated_at: Instant::now() }
+	}
+}
+
+#[derive(Default)]
+struct ChainProgress {
+	head: Option<Progress>,
+	// The last block processed by each witnesser of the chain.
+	witnessers: BTreeMap<&'static str, Progress>,
+}
+
+#[derive(Clone, Copy, Default, Serialize, PartialEq, Eq, Debug)]
+struct EndpointStatus {
+	connected: bool,
+	penalty: u32,
+}
+
+#[derive(Default)]
+struct HealthReports {
+	chains: BTreeMap<&'static str, ChainProgress>,
+	state_chain_observer: Option<Progress>,
+	p2p_registered_peers: usize,
+	p2p_reconnecting_peers: usize,
+	// The status of each endpoint of each rpc client, by client name.
+	rpc_clients: BTreeMap<&'static str, BTreeMap<usize, EndpointStatus>>,
+	// The database is open for as long as any subsystem holds on to it.
+	db: Option<Weak<dyn Send + Sync>>,
+}
+
+/// Collects the progress and status reports of the subsystems. Clones share the same reports,
+/// which are served by the health server the reporter was passed to.
+#[derive(Clone, Default)]
+pub struct HealthReporter {
+	reports: Arc<Mutex<HealthReports>>,
+}
+
+impl HealthReporter {
+	fn update_reports(&self, f: impl FnOnce(&mut HealthReports)) {
+		f(&mut self.reports.lock().unwrap())
+	}
+
+	/// Reports the latest block of the chain, as seen by chain tracking.
+	pub fn report_chain_head(&self, chain: &'static str, block_number: u64) {
+		self.update_reports(|reports| {
+			reports.chains.entry(chain).or_default().head = Some(Progress::now(block_number));
+		});
+	}
+
+	/// Reports the latest block processed by one of the witnessers of the chain.
+	pub fn report_witnessed_block(
+		&self,
+		chain: &'static str,
+		witnesser: &'static str,
+		block_number: u64,
+	) {
+		self.update_reports(|reports| {
+			reports
+				.chains
+				.entry(chain)
+				.or_default()
+				.witnessers
+				.insert(witnesser, Progress::now(block_number));
+		});
+	}
+
+	pub fn report_state_chain_block(&self, block_number: state_chain_runtime::BlockNumber) {
+		self.update_reports(|reports| {
+			reports.state_chain_observer = Some(Progress::now(block_number.into()));
+		});
+	}
+
+	/// Reports the number of registered peers we maintain connections with.
+	pub fn report_p2p_registered_peers(&self, count: usize) {
+		self.update_reports(|reports| reports.p2p_registered_peers = count);
+	}
+
+	/// Reports the number of peers we have lost the connection to and are waiting to reconnect
+	/// to.
+	pub fn report_p2p_reconnecting_peers(&self, count: usize) {
+		self.update_reports(|reports| reports.p2p_reconnecting_peers = count);
+	}
+
+	/// Reports whether the endpoint of the rpc client is connected, i.e. whether its last request
+	/// succeeded.
+	pub fn report_rpc_endpoint_connected(
+		&self,
+		client: &'static str,
+		endpoint: usize,
+		connected: bool,
+	) {
+		self.update_reports(|reports| {
+			reports
+				.rpc_clients
+				.entry(client)
+				.or_default()
+				.entry(endpoint)
+				.or_default()
+				.connected = connected;
+		});
+	}
+
+	pub fn report_rpc_endpoint_penalty(&self, client: &'static str, endpoint: usize, penalty: u32) {
+		self.update_reports(|reports| {
+			reports
+				.rpc_clients
+				.entry(client)
+				.or_default()
+				.entry(endpoint)
+				.or_default()
+				.penalty = penalty;
+		});
+	}
+
+	/// Reports the database as open until it is closed, i.e. until the last reference to it is
+	/// dropped.
+	pub fn report_db_open<Db: Send + Sync + 'static>(&self, db: &Arc<Db>) {
+		let db: Weak<dyn Send + Sync> = Arc::downgrade(db);
+		self.update_reports(|reports| reports.db = Some(db));
+	}
+}
+
+#[derive(Serialize, PartialEq, Eq, Debug)]
+struct WitnesserHealth {
+	chain: &'static str,
+	witnesser: &'static str,
+	last_processed_block: u64,
+	chain_head: Option<u64>,
+	lag: Option<u64>,
+	healthy: bool,
+}
+
+#[derive(Serialize, PartialEq, Eq, Debug)]
+struct StateChainObserverHealth {
+	last_processed_block: Option<u64>,
+	seconds_since_last_block: Option<u64>,
+	healthy: bool,
+}
+
+#[derive(Serialize, PartialEq, Eq, Debug)]
+struct P2PHealth {
+	registered_peers: usize,
+	reconnecting_peers: usize,
+	ready: bool,
+}
+
+#[derive(Serialize, PartialEq, Eq, Debug)]
+struct RpcClientHealth {
+	client: &'static str,
+	endpoints: BTreeMap<usize, EndpointStatus>,
+	ready: bool,
+}
+
+#[derive(Serialize, PartialEq, Eq, Debug)]
+struct DbHealth {
+	open: bool,
+}
+
+#[derive(Serialize, PartialEq, Eq, Debug)]
+struct HealthReport {
+	status: &'static str,
+	healthy: bool,
+	ready: bool,
+	witnessers: Vec<WitnesserHealth>,
+	state_chain_observer: StateChainObserverHealth,
+	p2p: P2PHealth,
+	rpc_clients: Vec<RpcClientHealth>,
+	db: DbHealth,
+}
+
+impl HealthReport {
+	fn new(reports: &HealthReports, has_completed_initialising: bool, now: Instant) -> Self {
+		let stalled = |progress: &Progress, timeout: Duration| {
+			now.saturating_duration_since(progress.updated_at) > timeout
+		};
+
+		let witnessers = reports
+			.chains
+			.iter()
+			.flat_map(|(chain, chain_progress)| {
+				chain_progress.witnessers.iter().map(|(witnesser, progress)| {
+					let chain_head = chain_progress.head.as_ref();
+					WitnesserHealth {
+						chain: *chain,
+						witnesser: *witnesser,
+						last_processed_block: progress.block_number,
+						chain_head: chain_head.map(|head| head.block_number),
+						lag: chain_head
+							.map(|head| head.block_number.saturating_sub(progress.block_number)),
+						// If the chain head isn't progressing either, the witnesser has nothing to
+						// do, e.g. because we are not an authority.
+						healthy: !(stalled(progress, WITNESSER_STALL_TIMEOUT) &&
+							chain_head.is_some_and(|head| {
+								!stalled(head, WITNESSER_STALL_TIMEOUT) &&
+									head.block_number > progress.block_number
+							})),
+					}
+				})
+			})
+			.collect::<Vec<_>>();
+
+		let state_chain_observer = StateChainObserverHealth {
+			last_processed_block: reports
+				.state_chain_observer
+				.as_ref()
+				.map(|progress| progress.block_number),
+			seconds_since_last_block: reports
+				.state_chain_observer
+				.as_ref()
+				.map(|progress| now.saturating_duration_since(progress.updated_at).as_secs()),
+			// Until the first block is processed, the engine is still initialising.
+			healthy: reports
+				.state_chain_observer
+				.as_ref()
+				.map_or(true, |progress| !stalled(progress, STATE_CHAIN_OBSERVER_STALL_TIMEOUT)),
+		};
+
+		let p2p = P2PHealth {
+			registered_peers: reports.p2p_registered_peers,
+			reconnecting_peers: reports.p2p_reconnecting_peers,
+			ready: reports.p2p_registered_peers == 0 ||
+				reports.p2p_reconnecting_peers < reports.p2p_registered_peers,
+		};
+
+		let rpc_clients = reports
+			.rpc_clients
+			.iter()
+			.map(|(client, endpoints)| RpcClientHealth {
+				client: *client,
+				endpoints: endpoints.clone(),
+				ready: endpoints.values().any(|status| status.connected),
+			})
+			.collect::<Vec<_>>();
+
+		let db = DbHealth { open: reports.db.as_ref().is_some_and(|db| db.strong_count() > 0) };
+
+		let healthy =
+			witnessers.iter().all(|witnesser| witnesser.healthy) && state_chain_observer.healthy;
+		let ready = healthy &&
+			has_completed_initialising &&
+			state_chain_observer.last_processed_block.is_some() &&
+			p2p.ready && rpc_clients.iter().all(|client| client.ready) &&
+			db.open;
+
+		Self {
+			status: if has_completed_initialising { RUNNING } else { INITIALISING },
+			healthy,
+			ready,
+			witnessers,
+			state_chain_observer,
+			p2p,
+			rpc_clients,
+			db,
+		}
+	}
+}
+
+fn reply(
+	health_reporter: &HealthReporter,
+	has_completed_initialising: &AtomicBool,
+	is_ok: impl FnOnce(&HealthReport) -> bool,
+) -> warp::reply::WithStatus<warp::reply::Json> {
+	let report = HealthReport::new(
+		&health_reporter.reports.lock().unwrap(),
+		has_completed_initialising.load(std::sync::atomic::Ordering::Relaxed),
+		Instant::now(),
+	);
+	let status = if is_ok(&report) {
+		warp::http::StatusCode::OK
+	} else {
+		warp::http::StatusCode::SERVICE_UNAVAILABLE
+	};
+	warp::reply::with_status(warp::reply::json(&report), status)
+}
+
 #[tracing::instrument(name = "health-check", skip_all)]
 pub async fn start<'a, 'env>(
 	scope: &'a task_scope::Scope<'env, anyhow::Error>,
 	health_check_settings: &'a settings::HealthCheck,
-	has_completed_initialising: Arc<std::sync::atomic::AtomicBool>,
+	has_completed_initialising: Arc<AtomicBool>,
+	health_reporter: HealthReporter,
 ) -> Result<(), anyhow::Error> {
 	info!("Starting");
 
-	const PATH: &str = "health";
-
-	let future =
-		warp::serve(warp::any().and(warp::path(PATH)).and(warp::path::end()).map(move || {
-			warp::reply::with_status(
-				if has_completed_initialising.load(std::sync::atomic::Ordering::Relaxed) {
-					RUNNING
-				} else {
-					INITIALISING
-				},
-				warp::http::StatusCode::OK,
-			)
-		}))
+	const HEALTH_PATH: &str = "health";
+	const READY_PATH: &str = "ready";
+
+	let health = warp::path(HEALTH_PATH).and(warp::path::end()).map({
+		let health_reporter = health_reporter.clone();
+		let has_completed_initialising = has_completed_initialising.clone();
+		move || reply(&health_reporter, &has_completed_initialising, |report| report.healthy)
+	});
+	let ready = warp::path(READY_PATH)
+		.and(warp::path::end())
+		.map(move || reply(&health_reporter, &has_completed_initialising, |report| report.ready));
+
+	let future = warp::serve(warp::any().and(health.or(ready).unify()))
 		.bind((health_check_settings.hostname.parse::<IpAddr>()?, health_check_settings.port));
 
 	scope.spawn_weak(async move {
@@ -60,35 +361,47 @@ mod tests {
 
 		task_scope::task_scope(|scope| {
 			async {
-				let has_completed_initialising =
-					Arc::new(std::sync::atomic::AtomicBool::new(false));
-				start(scope, &health_check, has_completed_initialising.clone()).await.unwrap();
-
-				let request_test = |path: &'static str,
-				                    expected_status: reqwest::StatusCode,
-				                    expected_text: &'static str| {
-					let health_check = health_check.clone();
-
-					async move {
-						let resp = reqwest::get(&format!(
-							"http://{}:{}/{}",
-							&health_check.hostname, &health_check.port, path
-						))
-						.await
-						.unwrap();
-
-						assert_eq!(expected_status, resp.status());
-						assert_eq!(resp.text().await.unwrap(), expected_text);
-					}
-				};
+				let has_completed_initialising = Arc::new(AtomicBool::new(false));
+				start(
+					scope,
+					&health_check,
+					has_completed_initialising.clone(),
+					HealthReporter::default(),
+				)
+				.await
+				.unwrap();
+
+				let request_test =
+					|path: &'static str,
+					 expected_status: reqwest::StatusCode,
+					 expected_engine_status: Option<&'static str>| {
+						let health_check = health_check.clone();
+
+						async move {
+							let resp = reqwest::get(&format!(
+								"http://{}:{}/{}",
+								&health_check.hostname, &health_check.port, path
+							))
+							.await
+							.unwrap();
+
+							assert_eq!(expected_status, resp.status());
+							if let Some(expected_engine_status) = expected_engine_status {
+								let report = resp.json::<serde_json::Value>().await.unwrap();
+								assert_eq!(report["status"], expected_engine_status);
+							}
+						}
+					};
 
 				// starts with `has_completed_initialising` set to false
-				request_test("health", reqwest::StatusCode::OK, INITIALISING).await;
-				request_test("invalid", reqwest::StatusCode::NOT_FOUND, "").await;
+				request_test("health", reqwest::StatusCode::OK, Some(INITIALISING)).await;
+				request_test("ready", reqwest::StatusCode::SERVICE_UNAVAILABLE, Some(INITIALISING))
+					.await;
+				request_test("invalid", reqwest::StatusCode::NOT_FOUND, None).await;
 
 				has_completed_initialising.store(true, std::sync::atomic::Ordering::Relaxed);
 
-				request_test("health", reqwest::StatusCode::OK, RUNNING).await;
+				request_test("health", reqwest::StatusCode::OK, Some(RUNNING)).await;
 
 				Ok(())
 			}
@@ -97,4 +410,117 @@ mod tests {
 		.await
 		.unwrap();
 	}
+
+	#[test]
+	fn health_report_reflects_subsystem_progress() {
+		let start = Instant::now();
+		let later = start + WITNESSER_STALL_TIMEOUT + Duration::from_secs(1);
+
+		let mut reports = HealthReports::default();
+		reports.state_chain_observer = Some(Progress { block_number: 100, updated_at: later });
+		reports.p2p_registered_peers = 3;
+		reports.p2p_reconnecting_peers = 1;
+		reports.rpc_clients.insert(
+			"btc_rpc",
+			BTreeMap::from([
+				(0, EndpointStatus { connected: true, penalty: 0 }),
+				(1, EndpointStatus { connected: false, penalty: 0 }),
+			]),
+		);
+		let db = Arc::new(());
+		reports.db = Some(Arc::downgrade(&db) as Weak<dyn Send + Sync>);
+		reports.chains.insert(
+			"Bitcoin",
+			ChainProgress {
+				head: Some(Progress { block_number: 12, updated_at: start }),
+				witnessers: BTreeMap::from([(
+					"witnessing",
+					Progress { block_number: 10, updated_at: start },
+				)]),
+			},
+		);
+
+		// Neither the chain head nor the witnesser have progressed, which isn't a stall.
+		let report = HealthReport::new(&reports, true, later);
+		assert_eq!(
+			report.witnessers,
+			vec![WitnesserHealth {
+				chain: "Bitcoin",
+				witnesser: "witnessing",
+				last_processed_block: 10,
+				chain_head: Some(12),
+				lag: Some(2),
+				healthy: true,
+			}]
+		);
+		assert!(report.healthy);
+		assert!(report.ready);
+
+		// The chain head progressed, but the witnesser didn't.
+		reports.chains.get_mut("Bitcoin").unwrap().head =
+			Some(Progress { block_number: 15, updated_at: later });
+		let report = HealthReport::new(&reports, true, later);
+		assert!(!report.witnessers[0].healthy);
+		assert!(!report.healthy);
+		assert!(!report.ready);
+		reports
+			.chains
+			.get_mut("Bitcoin")
+			.unwrap()
+			.witnessers
+			.insert("witnessing", Progress { block_number: 13, updated_at: later });
+		assert!(HealthReport::new(&reports, true, later).healthy);
+
+		// The State Chain observer stalled.
+		let report =
+			HealthReport::new(&reports, true, later + STATE_CHAIN_OBSERVER_STALL_TIMEOUT * 2);
+		assert!(!report.state_chain_observer.healthy);
+		assert!(!report.healthy);
+
+		// External dependencies only affect readiness.
+		reports.p2p_reconnecting_peers = 3;
+		let report = HealthReport::new(&reports, true, later);
+		assert!(report.healthy);
+		assert!(!report.ready);
+		reports.p2p_reconnecting_peers = 0;
+
+		reports.rpc_clients.get_mut("btc_rpc").unwrap().get_mut(&0).unwrap().connected = false;
+		let report = HealthReport::new(&reports, true, later);
+		assert!(report.healthy);
+		assert!(!report.ready);
+		reports.rpc_clients.get_mut("btc_rpc").unwrap().get_mut(&1).unwrap().connected = true;
+
+		assert!(HealthReport::new(&reports, true, later).ready);
+		assert!(!HealthReport::new(&reports, false, later).ready);
+
+		// The database was closed.
+		drop(db);
+		let report = HealthReport::new(&reports, true, later);
+		assert!(!report.db.open);
+		assert!(report.healthy);
+		assert!(!report.ready);
+	}
+
+	#[test]
+	fn reporters_share_the_reports() {
+		let reporter = HealthReporter::default();
+		reporter.clone().report_rpc_endpoint_connected("btc_rpc", 0, true);
+		reporter.report_rpc_endpoint_penalty("btc_rpc", 0, 2);
+
+		let report = HealthReport::new(&reporter.reports.lock().unwrap(), true, Instant::now());
+		assert_eq!(
+			report.rpc_clients,
+			vec![RpcClientHealth {
+				client: "btc_rpc",
+				endpoints: BTreeMap::from([(0, EndpointStatus { connected: true, penalty: 2 })]),
+				ready: true,
+			}]
+		);
+
+		// Endpoints that fail a request are no longer reported as connected.
+		reporter.report_rpc_endpoint_connected("btc_rpc", 0, false);
+		let report = HealthReport::new(&reporter.reports.lock().unwrap(), true, Instant::now());
+		assert!(!report.rpc_clients[0].ready);
+		assert!(!report.ready);
+	}
 }
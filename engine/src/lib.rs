@@ -21,9 +21,12 @@ pub mod witness;
 pub mod btc;
 pub mod dot;
 pub mod evm;
+pub mod sol;
 
 use crate::state_chain_observer::client::CreateStateChainClientError;
-use ::multisig::{bitcoin::BtcSigning, eth::EthSigning, polkadot::PolkadotSigning};
+use ::multisig::{
+	bitcoin::BtcSigning, ed25519::SolSigning, eth::EthSigning, polkadot::PolkadotSigning,
+};
 use cf_primitives::CfeCompatibility;
 use state_chain_observer::client::{
 	chain_api::ChainApi, extrinsic_api::signed::SignedExtrinsicApi, storage_api::StorageApi,
@@ -35,7 +38,9 @@ use self::{
 	db::{KeyStore, PersistentKeyDB},
 	dot::retry_rpc::DotRetryRpcClient,
 	evm::{retry_rpc::EvmRetryRpcClient, rpc::EvmRpcSigningClient},
+	health::HealthReporter,
 	settings::{CommandLineOptions, Settings, DEFAULT_SETTINGS_DIR},
+	sol::retry_rpc::SolRetryRpcClient,
 };
 use anyhow::Context;
 use cf_chains::{dot::PolkadotHash, Chain};
@@ -118,12 +123,13 @@ async fn run_main(
 	task_scope(|scope| {
 		async move {
 			let has_completed_initialising = Arc::new(AtomicBool::new(false));
+			let health_reporter = HealthReporter::default();
 
 			let (state_chain_stream, unfinalised_state_chain_stream, state_chain_client) =
 				state_chain_observer::client::StateChainClient::connect_with_account(
 					scope,
 					&settings.state_chain.ws_endpoint,
-					&settings.state_chain.signing_key_file,
+					settings.state_chain.signing_key_source(),
 					AccountRole::Validator,
 					true,
 					true,
@@ -136,8 +142,13 @@ async fn run_main(
 			tokio::time::sleep(Duration::from_secs(4)).await;
 
 			if let Some(health_check_settings) = &settings.health_check {
-				health::start(scope, health_check_settings, has_completed_initialising.clone())
-					.await?;
+				health::start(
+					scope,
+					health_check_settings,
+					has_completed_initialising.clone(),
+					health_reporter.clone(),
+				)
+				.await?;
 			}
 
 			if let Some(prometheus_settings) = &settings.prometheus {
@@ -151,6 +162,7 @@ async fn run_main(
 				)
 				.context("Failed to open database")?,
 			);
+			health_reporter.report_db_open(&db);
 
 			let (
 				eth_outgoing_sender,
@@ -159,6 +171,8 @@ async fn run_main(
 				dot_incoming_receiver,
 				btc_outgoing_sender,
 				btc_incoming_receiver,
+				sol_outgoing_sender,
+				sol_incoming_receiver,
 				p2p_ready_receiver,
 				p2p_fut,
 			) = p2p::start(
@@ -166,6 +180,7 @@ async fn run_main(
 				state_chain_stream.clone(),
 				settings.node_p2p.clone(),
 				state_chain_stream.cache().hash,
+				health_reporter.clone(),
 			)
 			.await
 			.context("Failed to start p2p")?;
@@ -187,6 +202,7 @@ async fn run_main(
 					eth_incoming_receiver,
 					eth_outgoing_sender,
 					ceremony_id_counters.ethereum,
+					&settings.signing.scheduling_limits,
 				);
 
 			scope.spawn(eth_multisig_client_backend_future);
@@ -198,6 +214,7 @@ async fn run_main(
 					dot_incoming_receiver,
 					dot_outgoing_sender,
 					ceremony_id_counters.polkadot,
+					&settings.signing.scheduling_limits,
 				);
 
 			scope.spawn(dot_multisig_client_backend_future);
@@ -209,10 +226,23 @@ async fn run_main(
 					btc_incoming_receiver,
 					btc_outgoing_sender,
 					ceremony_id_counters.bitcoin,
+					&settings.signing.scheduling_limits,
 				);
 
 			scope.spawn(btc_multisig_client_backend_future);
 
+			let (sol_multisig_client, sol_multisig_client_backend_future) =
+				multisig::start_client::<SolSigning>(
+					state_chain_client.account_id(),
+					KeyStore::new(db.clone()),
+					sol_incoming_receiver,
+					sol_outgoing_sender,
+					ceremony_id_counters.solana,
+					&settings.signing.scheduling_limits,
+				);
+
+			scope.spawn(sol_multisig_client_backend_future);
+
 			// Create all the clients
 			let eth_client = {
 				let expected_eth_chain_id = web3::types::U256::from(
@@ -232,6 +262,7 @@ async fn run_main(
 					"eth_subscribe",
 					"Ethereum",
 					cf_chains::Ethereum::WITNESS_PERIOD,
+					health_reporter.clone(),
 				)?
 			};
 			let arb_client = {
@@ -252,6 +283,7 @@ async fn run_main(
 					"arb_subscribe",
 					"Arbitrum",
 					cf_chains::Arbitrum::WITNESS_PERIOD,
+					health_reporter.clone(),
 				)?
 			};
 
@@ -264,7 +296,13 @@ async fn run_main(
 						.await
 						.expect(STATE_CHAIN_CONNECTION),
 				);
-				BtcRetryRpcClient::new(scope, settings.btc.nodes, expected_btc_network).await?
+				BtcRetryRpcClient::new(
+					scope,
+					settings.btc.nodes,
+					expected_btc_network,
+					health_reporter.clone(),
+				)
+				.await?
 			};
 			let dot_client = {
 				let expected_dot_genesis_hash = PolkadotHash::from(
@@ -275,7 +313,42 @@ async fn run_main(
 						.await
 						.expect(STATE_CHAIN_CONNECTION),
 				);
-				DotRetryRpcClient::new(scope, settings.dot.nodes, expected_dot_genesis_hash)?
+				DotRetryRpcClient::new(
+					scope,
+					settings.dot.nodes,
+					expected_dot_genesis_hash,
+					health_reporter.clone(),
+				)?
+			};
+			let hub_client = {
+				let expected_hub_genesis_hash = PolkadotHash::from(
+					state_chain_client
+						.storage_value::<pallet_cf_environment::AssethubGenesisHash<state_chain_runtime::Runtime>>(
+							state_chain_client.latest_finalized_block().hash,
+						)
+						.await
+						.expect(STATE_CHAIN_CONNECTION),
+				);
+				DotRetryRpcClient::new(
+					scope,
+					settings.hub.nodes,
+					expected_hub_genesis_hash,
+					health_reporter.clone(),
+				)?
+			};
+			let sol_client = {
+				let expected_sol_genesis_hash = state_chain_client
+					.storage_value::<pallet_cf_environment::SolanaGenesisHash<state_chain_runtime::Runtime>>(
+						state_chain_client.latest_finalized_block().hash,
+					)
+					.await
+					.expect(STATE_CHAIN_CONNECTION);
+				SolRetryRpcClient::new(
+					scope,
+					settings.sol.nodes,
+					expected_sol_genesis_hash,
+					health_reporter.clone(),
+				)?
 			};
 
 			witness::start::start(
@@ -284,10 +357,13 @@ async fn run_main(
 				arb_client.clone(),
 				btc_client.clone(),
 				dot_client.clone(),
+				hub_client.clone(),
+				sol_client.clone(),
 				state_chain_client.clone(),
 				state_chain_stream.clone(),
 				unfinalised_state_chain_stream.clone(),
 				db.clone(),
+				health_reporter.clone(),
 			)
 			.await?;
 
@@ -297,10 +373,14 @@ async fn run_main(
 				eth_client,
 				arb_client,
 				dot_client,
+				hub_client,
 				btc_client,
+				sol_client,
 				eth_multisig_client,
 				dot_multisig_client,
 				btc_multisig_client,
+				sol_multisig_client,
+				health_reporter,
 			));
 
 			p2p_ready_receiver.await.unwrap();
@@ -9,6 +9,7 @@ use std::{
 };
 
 use crate::{
+	health::HealthReporter,
 	p2p::core::ed25519_secret_key_to_x25519_secret_key,
 	settings::P2P as P2PSettings,
 	state_chain_observer::client::{
@@ -26,7 +27,9 @@ pub use self::{
 	muxer::{ProtocolVersion, VersionedCeremonyMessage, CURRENT_PROTOCOL_VERSION},
 };
 use anyhow::Context;
-use cf_chains::{btc::BitcoinCrypto, dot::PolkadotCrypto, evm::EvmCrypto, ChainCrypto};
+use cf_chains::{
+	btc::BitcoinCrypto, dot::PolkadotCrypto, evm::EvmCrypto, sol::SolanaCrypto, ChainCrypto,
+};
 use cf_primitives::AccountId;
 use futures::{Future, FutureExt, StreamExt};
 use multisig::p2p::OutgoingMultisigStageMessages;
@@ -92,6 +95,7 @@ pub async fn start<StateChainClient, BlockStream: StreamApi<FINALIZED>>(
 	sc_block_stream: BlockStream,
 	settings: P2PSettings,
 	initial_block_hash: H256,
+	health_reporter: HealthReporter,
 ) -> anyhow::Result<(
 	MultisigMessageSender<EvmCrypto>,
 	MultisigMessageReceiver<EvmCrypto>,
@@ -99,6 +103,8 @@ pub async fn start<StateChainClient, BlockStream: StreamApi<FINALIZED>>(
 	MultisigMessageReceiver<PolkadotCrypto>,
 	MultisigMessageSender<BitcoinCrypto>,
 	MultisigMessageReceiver<BitcoinCrypto>,
+	MultisigMessageSender<SolanaCrypto>,
+	MultisigMessageReceiver<SolanaCrypto>,
 	oneshot::Receiver<()>,
 	impl Future<Output = anyhow::Result<()>>,
 )>
@@ -147,6 +153,8 @@ where
 		dot_incoming_receiver,
 		btc_outgoing_sender,
 		btc_incoming_receiver,
+		sol_outgoing_sender,
+		sol_incoming_receiver,
 		muxer_future,
 	) = P2PMuxer::start(incoming_message_receiver, outgoing_message_sender);
 
@@ -175,6 +183,7 @@ where
 						incoming_message_sender,
 						outgoing_message_receiver,
 						peer_update_receiver,
+						health_reporter,
 					)
 					.await?;
 
@@ -209,6 +218,8 @@ where
 		dot_incoming_receiver,
 		btc_outgoing_sender,
 		btc_incoming_receiver,
+		sol_outgoing_sender,
+		sol_incoming_receiver,
 		p2p_ready_receiver,
 		fut,
 	))
@@ -30,7 +30,10 @@ use utilities::{
 };
 use x25519_dalek::StaticSecret;
 
-use crate::p2p::{pk_to_string, OutgoingMultisigStageMessages};
+use crate::{
+	health::HealthReporter,
+	p2p::{pk_to_string, OutgoingMultisigStageMessages},
+};
 use monitor::MonitorEvent;
 
 use socket::{ConnectedOutgoingSocket, OutgoingSocket, RECONNECT_INTERVAL, RECONNECT_INTERVAL_MAX};
@@ -131,11 +134,12 @@ pub fn ed25519_public_key_to_x25519_public_key(
 struct ReconnectContext {
 	reconnect_delays: BTreeMap<AccountId, std::time::Duration>,
 	reconnect_sender: UnboundedSender<AccountId>,
+	health_reporter: HealthReporter,
 }
 
 impl ReconnectContext {
-	fn new(reconnect_sender: UnboundedSender<AccountId>) -> Self {
-		ReconnectContext { reconnect_delays: BTreeMap::new(), reconnect_sender }
+	fn new(reconnect_sender: UnboundedSender<AccountId>, health_reporter: HealthReporter) -> Self {
+		ReconnectContext { reconnect_delays: BTreeMap::new(), reconnect_sender, health_reporter }
 	}
 
 	fn get_delay_for(&mut self, account_id: &AccountId) -> std::time::Duration {
@@ -160,6 +164,7 @@ impl ReconnectContext {
 
 		tracing::debug!("Will reconnect to {} in {:?}", account_id, delay);
 		P2P_RECONNECT_PEERS.set(self.reconnect_delays.len());
+		self.health_reporter.report_p2p_reconnecting_peers(self.reconnect_delays.len());
 		tokio::spawn({
 			let sender = self.reconnect_sender.clone();
 			async move {
@@ -179,6 +184,7 @@ impl ReconnectContext {
 			tracing::debug!("Reconnection delay for {} is reset", account_id);
 		}
 		P2P_RECONNECT_PEERS.set(self.reconnect_delays.len());
+		self.health_reporter.report_p2p_reconnecting_peers(self.reconnect_delays.len());
 	}
 }
 
@@ -207,11 +213,16 @@ struct ConnectionStateInfo {
 struct ActiveConnectionWrapper {
 	metric: &'static P2P_ACTIVE_CONNECTIONS,
 	map: BTreeMap<AccountId, ConnectionStateInfo>,
+	health_reporter: HealthReporter,
 }
 
 impl ActiveConnectionWrapper {
-	fn new() -> ActiveConnectionWrapper {
-		ActiveConnectionWrapper { metric: &P2P_ACTIVE_CONNECTIONS, map: Default::default() }
+	fn new(health_reporter: HealthReporter) -> ActiveConnectionWrapper {
+		ActiveConnectionWrapper {
+			metric: &P2P_ACTIVE_CONNECTIONS,
+			map: Default::default(),
+			health_reporter,
+		}
 	}
 	fn get(&self, account_id: &AccountId) -> Option<&ConnectionStateInfo> {
 		self.map.get(account_id)
@@ -226,11 +237,13 @@ impl ActiveConnectionWrapper {
 	) -> Option<ConnectionStateInfo> {
 		let result = self.map.insert(key, value);
 		self.metric.set(self.map.len());
+		self.health_reporter.report_p2p_registered_peers(self.map.len());
 		result
 	}
 	fn remove(&mut self, key: &AccountId) -> Option<ConnectionStateInfo> {
 		let result = self.map.remove(key);
 		self.metric.set(self.map.len());
+		self.health_reporter.report_p2p_registered_peers(self.map.len());
 		result
 	}
 }
@@ -279,6 +292,7 @@ pub(super) async fn start(
 	incoming_message_sender: UnboundedSender<(AccountId, Vec<u8>)>,
 	outgoing_message_receiver: UnboundedReceiver<OutgoingMultisigStageMessages>,
 	peer_update_receiver: UnboundedReceiver<PeerUpdate>,
+	health_reporter: HealthReporter,
 ) -> anyhow::Result<()> {
 	debug!("Our derived x25519 pubkey: {}", pk_to_string(&p2p_key.encryption_key.public_key));
 
@@ -298,9 +312,9 @@ pub(super) async fn start(
 		key: p2p_key.encryption_key,
 		monitor_handle,
 		authenticator,
-		active_connections: ActiveConnectionWrapper::new(),
+		active_connections: ActiveConnectionWrapper::new(health_reporter.clone()),
 		x25519_to_account_id: Default::default(),
-		reconnect_context: ReconnectContext::new(reconnect_sender),
+		reconnect_context: ReconnectContext::new(reconnect_sender, health_reporter),
 		incoming_message_sender,
 		our_account_id,
 		stop_thread: Arc::new(AtomicBool::new(false)),
@@ -58,6 +58,7 @@ fn spawn_node(
 			incoming_message_sender,
 			outgoing_message_receiver,
 			peer_update_receiver,
+			Default::default(),
 		)
 		.instrument(info_span!("node", idx = idx))
 	});
@@ -5,15 +5,19 @@
 //! On each request it applies a timeout, such that requests cannot hang.
 //! It applies exponential backoff and jitter to the requests if they fail, and will retry them
 //! until they succeed.
+//! Any number of endpoints can be used. Requests that are critical to witnessing can require a
+//! quorum of endpoints to agree on the response, in which case endpoints that disagree are
+//! penalised, such that they are deprioritised for future requests.
 
 use std::{
 	any::Any,
 	collections::{BTreeMap, VecDeque},
 	pin::Pin,
+	sync::{Arc, Mutex},
 	time::Duration,
 };
 
-use crate::common::Signal;
+use crate::{common::Signal, health::HealthReporter};
 use anyhow::Result;
 use core::cmp::min;
 use futures::Future;
@@ -22,7 +26,10 @@ use rand::Rng;
 use std::fmt;
 use tokio::sync::{mpsc, oneshot};
 use utilities::{
-	metrics::{RPC_RETRIER_REQUESTS, RPC_RETRIER_TOTAL_REQUESTS},
+	metrics::{
+		RPC_ENDPOINT_PENALTY, RPC_QUORUM_DISAGREEMENTS, RPC_RETRIER_REQUESTS,
+		RPC_RETRIER_TOTAL_REQUESTS,
+	},
 	task_scope::Scope,
 	UnendingStream,
 };
@@ -75,37 +82,19 @@ impl fmt::Display for RequestLog {
 	}
 }
 
-#[derive(PartialEq, Eq, Debug, Clone)]
-pub enum PrimaryOrSecondary {
-	Primary,
-	Secondary,
-}
-
-impl std::ops::Not for PrimaryOrSecondary {
-	type Output = Self;
-
-	fn not(self) -> Self::Output {
-		match self {
-			PrimaryOrSecondary::Primary => PrimaryOrSecondary::Secondary,
-			PrimaryOrSecondary::Secondary => PrimaryOrSecondary::Primary,
-		}
-	}
-}
+// The position of an endpoint in the list of endpoints the retrier was created with. The primary
+// endpoint is first.
+type EndpointIndex = usize;
 
-type SubmissionFutureOutput = (
-	RequestId,
-	RequestLog,
-	RetryLimit,
-	PrimaryOrSecondary,
-	Result<BoxAny, (anyhow::Error, Attempt)>,
-);
+type SubmissionFutureOutput =
+	(RequestId, RequestLog, RetryLimit, EndpointIndex, Result<BoxAny, (anyhow::Error, Attempt)>);
 type SubmissionFuture = Pin<Box<dyn Future<Output = SubmissionFutureOutput> + Send + 'static>>;
 type SubmissionFutures = FuturesUnordered<SubmissionFuture>;
 
 type RetryDelays = FuturesUnordered<
 	Pin<
 		Box<
-			dyn Future<Output = (RequestId, RequestLog, Attempt, RetryLimit, PrimaryOrSecondary)>
+			dyn Future<Output = (RequestId, RequestLog, Attempt, RetryLimit, EndpointIndex)>
 				+ Send
 				+ 'static,
 		>,
@@ -124,6 +113,12 @@ type RequestSent<Client> =
 pub struct RetrierClient<Client> {
 	// The channel to send requests to the client.
 	request_sender: mpsc::Sender<RequestSent<Client>>,
+	name: &'static str,
+	client_selector: ClientSelector<Client>,
+	// The number of endpoints that must agree on the response to a quorum request.
+	quorum: usize,
+	initial_request_timeout: Duration,
+	health_reporter: HealthReporter,
 }
 
 #[derive(Default)]
@@ -206,7 +201,7 @@ fn submission_future<Client: Clone + Send + Sync + 'static>(
 	request_id: RequestId,
 	initial_request_timeout: Duration,
 	attempt: Attempt,
-	primary_or_secondary: PrimaryOrSecondary,
+	endpoint: EndpointIndex,
 ) -> SubmissionFuture {
 	let submission_fut = submission_fn(client);
 	// Apply exponential backoff to the request.
@@ -215,7 +210,7 @@ fn submission_future<Client: Clone + Send + Sync + 'static>(
 			request_id,
 			request_log.clone(),
 			retry_limit,
-			primary_or_secondary,
+			endpoint,
 			match tokio::time::timeout(
 				max_sleep_duration(initial_request_timeout, attempt),
 				submission_fut,
@@ -231,13 +226,13 @@ fn submission_future<Client: Clone + Send + Sync + 'static>(
 	})
 }
 
-// Pass in two clients, a primary and an optional secondary.
-// We can then select the client requested if it's ready, otherwise we return the client that's
-// ready first.
+// Holds a client for each endpoint. We can then select the least penalised client if it's ready,
+// otherwise we return the client that's ready first.
 #[derive(Clone)]
-struct ClientSelector<Client: Clone + Send + Sync + 'static> {
-	primary_signal: Signal<(Client, PrimaryOrSecondary)>,
-	secondary_signal: Option<Signal<(Client, PrimaryOrSecondary)>>,
+struct ClientSelector<Client> {
+	signals: Vec<Signal<(Client, EndpointIndex)>>,
+	// The penalty of each endpoint, increased each time it disagrees with a quorum of endpoints.
+	penalties: Arc<Mutex<Vec<u32>>>,
 }
 
 impl<Client: Send + Sync + Clone + 'static> ClientSelector<Client> {
@@ -245,58 +240,122 @@ impl<Client: Send + Sync + Clone + 'static> ClientSelector<Client> {
 	/// client.
 	pub fn new<ClientFut: Future<Output = Client> + Send + 'static>(
 		scope: &Scope<'_, anyhow::Error>,
-		primary_fut: ClientFut,
-		secondary_fut: Option<ClientFut>,
+		name: &'static str,
+		client_futs: Vec<ClientFut>,
+		health_reporter: &HealthReporter,
 	) -> Self {
-		let (primary_signaller, primary_signal) = Signal::new();
-
-		scope.spawn_weak(async move {
-			let client = primary_fut.await;
-			primary_signaller.signal((client, PrimaryOrSecondary::Primary));
-			Ok(())
-		});
+		assert!(!client_futs.is_empty(), "At least one client is required");
+
+		let signals = client_futs
+			.into_iter()
+			.enumerate()
+			.map(|(endpoint, client_fut)| {
+				let (signaller, signal) = Signal::new();
+				health_reporter.report_rpc_endpoint_connected(name, endpoint, false);
+				let health_reporter = health_reporter.clone();
+				scope.spawn_weak(async move {
+					let client = client_fut.await;
+					health_reporter.report_rpc_endpoint_connected(name, endpoint, true);
+					signaller.signal((client, endpoint));
+					Ok(())
+				});
+				signal
+			})
+			.collect::<Vec<_>>();
 
-		let secondary_signal = if let Some(secondary_fut) = secondary_fut {
-			let (secondary_signaller, secondary_signal) = Signal::new();
+		Self { penalties: Arc::new(Mutex::new(vec![0; signals.len()])), signals }
+	}
 
-			scope.spawn_weak(async move {
-				let client = secondary_fut.await;
-				secondary_signaller.signal((client, PrimaryOrSecondary::Secondary));
-				Ok(())
-			});
-			Some(secondary_signal)
-		} else {
-			None
-		};
+	pub fn endpoint_count(&self) -> usize {
+		self.signals.len()
+	}
 
-		Self { primary_signal, secondary_signal }
+	// Orders the endpoints from least to most penalised. If an endpoint was just tried, it is
+	// moved to the back and the endpoints after it are preferred, so that retries rotate through
+	// the endpoints.
+	fn preference_order(&self, last_tried: Option<EndpointIndex>) -> Vec<EndpointIndex> {
+		let penalties = self.penalties.lock().unwrap();
+		let endpoint_count = self.signals.len();
+		let mut order = (0..endpoint_count).collect::<Vec<_>>();
+		order.sort_by_key(|&endpoint| match last_tried {
+			Some(last_tried) => (
+				endpoint == last_tried,
+				penalties[endpoint],
+				(endpoint + endpoint_count - last_tried - 1) % endpoint_count,
+			),
+			None => (false, penalties[endpoint], endpoint),
+		});
+		order
 	}
 
-	// Returns a client, and the type of client selected.
+	// Returns a client, and the endpoint of the client selected.
 	pub async fn select_client(
 		&self,
-		primary_or_secondary: PrimaryOrSecondary,
-	) -> (Client, PrimaryOrSecondary) {
+		last_tried: Option<EndpointIndex>,
+	) -> (Client, EndpointIndex) {
+		// The signals are polled in order of preference, so the most preferred of the ready
+		// clients is returned. If none are ready we have to wait for the first one to be.
 		futures::future::select_all(
-			utilities::conditional::conditional(
-				&self.secondary_signal,
-				|secondary_signal| {
-					// If we have two clients, then we should bias the requested one, but if it's
-					// not ready, request from the other one.
-					match primary_or_secondary {
-						PrimaryOrSecondary::Secondary => [secondary_signal, &self.primary_signal],
-						PrimaryOrSecondary::Primary => [&self.primary_signal, secondary_signal],
-					}
-					.into_iter()
-				},
-				// If we only have a primary, we have to wait for it to be ready.
-				|()| [&self.primary_signal].into_iter(),
-			)
-			.map(|signal| Box::pin(signal.clone().wait())),
+			self.preference_order(last_tried)
+				.into_iter()
+				.map(|endpoint| Box::pin(self.signals[endpoint].clone().wait())),
 		)
 		.await
 		.0
 	}
+
+	// Waits until at least `count` clients are ready, and then returns all the ready clients.
+	pub async fn ready_clients(&self, count: usize) -> Vec<(Client, EndpointIndex)> {
+		let mut signals = self.signals.clone();
+		loop {
+			let ready_clients = signals
+				.iter_mut()
+				.filter_map(|signal| signal.get().cloned())
+				.collect::<Vec<_>>();
+			if ready_clients.len() >= count || ready_clients.len() == signals.len() {
+				break ready_clients
+			}
+			futures::future::select_all(
+				signals
+					.iter()
+					.filter(|signal| matches!(signal, Signal::Pending(_)))
+					.map(|signal| Box::pin(signal.clone().wait())),
+			)
+			.await;
+		}
+	}
+
+	// Returns the new penalty of the endpoint.
+	pub fn penalise(&self, endpoint: EndpointIndex) -> u32 {
+		let mut penalties = self.penalties.lock().unwrap();
+		penalties[endpoint] = penalties[endpoint].saturating_add(1);
+		penalties[endpoint]
+	}
+
+	// Endpoints recover from penalties by agreeing with the quorum, so that an endpoint that was
+	// only lagging temporarily is not deprioritised forever. Returns the new penalty of the
+	// endpoint.
+	pub fn reward(&self, endpoint: EndpointIndex) -> u32 {
+		let mut penalties = self.penalties.lock().unwrap();
+		penalties[endpoint] = penalties[endpoint].saturating_sub(1);
+		penalties[endpoint]
+	}
+}
+
+// Groups equal responses, returning each distinct response with the endpoints that returned it,
+// ordered from the most to the least common response.
+fn group_responses<T: PartialEq>(
+	responses: impl IntoIterator<Item = (EndpointIndex, T)>,
+) -> Vec<(T, Vec<EndpointIndex>)> {
+	let mut groups: Vec<(T, Vec<EndpointIndex>)> = Vec::new();
+	for (endpoint, response) in responses {
+		match groups.iter_mut().find(|(grouped_response, _)| *grouped_response == response) {
+			Some((_, endpoints)) => endpoints.push(endpoint),
+			None => groups.push((response, vec![endpoint])),
+		}
+	}
+	groups.sort_by_key(|(_, endpoints)| std::cmp::Reverse(endpoints.len()));
+	groups
 }
 
 #[async_trait::async_trait]
@@ -309,6 +368,11 @@ pub trait RetryLimitReturn: Send + 'static {
 		inner: Result<BoxAny, tokio::sync::oneshot::error::RecvError>,
 		log_message: String,
 	) -> Self::ReturnType<T>;
+
+	fn map_return_type<T, U>(
+		return_value: Self::ReturnType<T>,
+		f: impl FnOnce(T) -> U,
+	) -> Self::ReturnType<U>;
 }
 
 pub struct NoRetryLimit;
@@ -327,6 +391,13 @@ impl RetryLimitReturn for NoRetryLimit {
 		let result: BoxAny = inner.unwrap();
 		*result.downcast::<T>().expect("We know we cast the T into an any, and it is a T that we are receiving. Hitting this is a programmer error.")
 	}
+
+	fn map_return_type<T, U>(
+		return_value: Self::ReturnType<T>,
+		f: impl FnOnce(T) -> U,
+	) -> Self::ReturnType<U> {
+		f(return_value)
+	}
 }
 
 pub struct SetRetryLimit {}
@@ -345,6 +416,13 @@ impl RetryLimitReturn for u32 {
 		let result: BoxAny = inner.map_err(|_| anyhow::anyhow!("{log_message}"))?;
 		Ok(*result.downcast::<T>().expect("We know we cast the T into an any, and it is a T that we are receiving. Hitting this is a programmer error."))
 	}
+
+	fn map_return_type<T, U>(
+		return_value: Self::ReturnType<T>,
+		f: impl FnOnce(T) -> U,
+	) -> Self::ReturnType<U> {
+		return_value.map(f)
+	}
 }
 
 /// Requests submitted to this client will be retried until success.
@@ -358,10 +436,14 @@ where
 		scope: &Scope<'_, anyhow::Error>,
 		// The name of the retrier that appears in the logs.
 		name: &'static str,
-		primary_client_fut: ClientFut,
-		secondary_client_fut: Option<ClientFut>,
+		// The clients of each endpoint, starting with the primary endpoint.
+		client_futs: Vec<ClientFut>,
+		// The number of endpoints that must agree on the response to a quorum request.
+		quorum: u32,
 		initial_request_timeout: Duration,
 		maximum_concurrent_submissions: u32,
+		// Endpoints are reported as connected while their requests succeed.
+		health_reporter: HealthReporter,
 	) -> Self {
 		let (request_sender, mut request_receiver) = mpsc::channel::<RequestSent<Client>>(1);
 
@@ -373,21 +455,30 @@ where
 		let mut submission_holder = SubmissionHolder::new(maximum_concurrent_submissions);
 
 		let client_selector: ClientSelector<Client> =
-			ClientSelector::new(scope, primary_client_fut, secondary_client_fut);
+			ClientSelector::new(scope, name, client_futs, &health_reporter);
+		let retrier_client = Self {
+			request_sender,
+			name,
+			client_selector: client_selector.clone(),
+			quorum: (quorum as usize).clamp(1, client_selector.endpoint_count()),
+			initial_request_timeout,
+			health_reporter: health_reporter.clone(),
+		};
 
 		scope.spawn(async move {
 			utilities::loop_select! {
 				if let Some((response_sender, request_log, closure, retry_limit)) = request_receiver.recv() => {
 					RPC_RETRIER_REQUESTS.inc(&[name, request_log.rpc_method.as_str()]);
 					let request_id = request_holder.next_request_id();
-					let (client, primary_or_secondary) = client_selector.select_client(PrimaryOrSecondary::Primary).await;
+					let (client, endpoint) = client_selector.select_client(None).await;
 
-					tracing::debug!("Retrier {name}: Received request `{request_log}` assigning request_id `{request_id}` and requesting with `{primary_or_secondary:?}`");
-					submission_holder.push(submission_future(client, request_log, retry_limit, &closure, request_id, initial_request_timeout, 0, primary_or_secondary));
+					tracing::debug!("Retrier {name}: Received request `{request_log}` assigning request_id `{request_id}` and requesting with endpoint `{endpoint}`");
+					submission_holder.push(submission_future(client, request_log, retry_limit, &closure, request_id, initial_request_timeout, 0, endpoint));
 					request_holder.insert(request_id, (response_sender, closure));
 				},
-				let (request_id, request_log, retry_limit, primary_or_secondary, result) = submission_holder.next_or_pending() => {
+				let (request_id, request_log, retry_limit, endpoint, result) = submission_holder.next_or_pending() => {
 					RPC_RETRIER_TOTAL_REQUESTS.inc(&[name, request_log.rpc_method.as_str()]);
+					health_reporter.report_rpc_endpoint_connected(name, endpoint, result.is_ok());
 					match result {
 						Ok(value) => {
 							if let Some((response_sender, _)) = request_holder.remove(&request_id) {
@@ -411,14 +502,14 @@ where
 							retry_delays.push(Box::pin(
 								async move {
 									tokio::time::sleep(sleep_duration).await;
-									// pass in primary or secondary so we know which client to use.
-									(request_id, request_log, attempt, retry_limit, primary_or_secondary)
+									// pass in the endpoint so we know which client to use.
+									(request_id, request_log, attempt, retry_limit, endpoint)
 								}
 							));
 						},
 					}
 				},
-				let (request_id, request_log, attempt, retry_limit, primary_or_secondary) = retry_delays.next_or_pending() => {
+				let (request_id, request_log, attempt, retry_limit, endpoint) = retry_delays.next_or_pending() => {
 					let next_attempt = attempt.saturating_add(1);
 
 					let (response_sender, closure) = request_holder.get(&request_id).expect("We only remove these on success, and if it's in `retry_delays` then it must still be in `request_holder`");
@@ -435,9 +526,9 @@ where
 							_ => {
 								// We want to use a different client than the one we just tried if possible.
 								// This await should always return immediately since we must already have a client if we've already made a request.
-								let (next_client, next_primary_or_secondary) = client_selector.select_client(!primary_or_secondary).await;
-								tracing::trace!("Retrier {name}: Retrying request `{request_log}` with id `{request_id}` and client `{next_primary_or_secondary:?}`, attempt `{next_attempt}`");
-								submission_holder.push(submission_future(next_client, request_log, retry_limit, closure, request_id, initial_request_timeout, next_attempt, next_primary_or_secondary));
+								let (next_client, next_endpoint) = client_selector.select_client(Some(endpoint)).await;
+								tracing::trace!("Retrier {name}: Retrying request `{request_log}` with id `{request_id}` and endpoint `{next_endpoint}`, attempt `{next_attempt}`");
+								submission_holder.push(submission_future(next_client, request_log, retry_limit, closure, request_id, initial_request_timeout, next_attempt, next_endpoint));
 							}
 						}
 					}
@@ -446,7 +537,7 @@ where
 			Ok(())
 		});
 
-		Self { request_sender }
+		retrier_client
 	}
 
 	// Separate function so we can more easily test.
@@ -495,6 +586,128 @@ where
 			format!("Maximum attempt of `{retry_limit:?}` reached for request `{request_log}`."),
 		)
 	}
+
+	/// Requests something from all the ready endpoints, and only returns once a quorum of them
+	/// agree on the response. Endpoints that respond with something else are penalised. If no
+	/// quorum is reached the request is retried until one is. With a quorum of one this is the
+	/// same as `request`.
+	///
+	/// Note these requests are not limited by the maximum number of concurrent submissions.
+	pub async fn request_with_quorum<T: PartialEq + Send + 'static>(
+		&self,
+		request_log: RequestLog,
+		specific_closure: TypedFutureGenerator<T, Client>,
+	) -> T {
+		self.request_with_quorum_and_limit::<T, NoRetryLimit>(
+			request_log,
+			specific_closure,
+			NoRetryLimit,
+		)
+		.await
+	}
+
+	/// Like `request_with_quorum`, but with an explicit retry limit. Returns an error if the retry
+	/// limit is reached without a quorum of endpoints agreeing on the response.
+	pub async fn request_with_quorum_and_limit<
+		T: PartialEq + Send + 'static,
+		R: RetryLimitReturn,
+	>(
+		&self,
+		request_log: RequestLog,
+		specific_closure: TypedFutureGenerator<T, Client>,
+		retry_limit: R,
+	) -> R::ReturnType<T> {
+		if self.quorum <= 1 {
+			return self.request_with_limit(request_log, specific_closure, retry_limit).await
+		}
+
+		let name = self.name;
+		RPC_RETRIER_REQUESTS.inc(&[name, request_log.rpc_method.as_str()]);
+
+		let retry_limit = R::into_retry_limit(retry_limit);
+		// Dropping the sender without sending signals that the retry limit was reached.
+		let (result_sender, result_receiver) = oneshot::channel::<BoxAny>();
+
+		let mut attempt: Attempt = 0;
+		loop {
+			let request_timeout = max_sleep_duration(self.initial_request_timeout, attempt);
+			let responses = futures::future::join_all(
+				self.client_selector.ready_clients(self.quorum).await.into_iter().map(
+					|(client, endpoint)| {
+						RPC_RETRIER_TOTAL_REQUESTS.inc(&[name, request_log.rpc_method.as_str()]);
+						let submission_fut = specific_closure(client);
+						async move {
+							(
+								endpoint,
+								match tokio::time::timeout(request_timeout, submission_fut).await {
+									Ok(result) => result,
+									Err(_) => Err(anyhow::anyhow!("Request timed out")),
+								},
+							)
+						}
+					},
+				),
+			)
+			.await;
+
+			let mut groups = group_responses(responses.into_iter().filter_map(
+				|(endpoint, result)| match result {
+					Ok(response) => {
+						self.health_reporter.report_rpc_endpoint_connected(name, endpoint, true);
+						Some((endpoint, response))
+					},
+					Err(e) => {
+						self.health_reporter.report_rpc_endpoint_connected(name, endpoint, false);
+						tracing::warn!("Retrier {name}: Error for quorum request `{request_log}` from endpoint `{endpoint}`, attempt `{attempt}`: {e}");
+						None
+					},
+				},
+			));
+
+			if groups.first().is_some_and(|(_, endpoints)| endpoints.len() >= self.quorum) {
+				let (response, agreeing_endpoints) = groups.remove(0);
+				for endpoint in agreeing_endpoints {
+					let penalty = self.client_selector.reward(endpoint);
+					RPC_ENDPOINT_PENALTY.set(&[name, &endpoint.to_string()], penalty);
+					self.health_reporter.report_rpc_endpoint_penalty(name, endpoint, penalty);
+				}
+				for endpoint in groups.into_iter().flat_map(|(_, endpoints)| endpoints) {
+					tracing::warn!("Retrier {name}: Endpoint `{endpoint}` disagreed with the quorum for request `{request_log}`");
+					RPC_QUORUM_DISAGREEMENTS.inc(&[
+						name,
+						request_log.rpc_method.as_str(),
+						&endpoint.to_string(),
+					]);
+					let penalty = self.client_selector.penalise(endpoint);
+					RPC_ENDPOINT_PENALTY.set(&[name, &endpoint.to_string()], penalty);
+					self.health_reporter.report_rpc_endpoint_penalty(name, endpoint, penalty);
+				}
+				let _result = result_sender.send(Box::new(response));
+				break
+			}
+
+			let next_attempt = attempt.saturating_add(1);
+			if matches!(retry_limit, RetryLimit::Limit(max_attempts) if next_attempt >= max_attempts)
+			{
+				tracing::error!("Retrier {name}: Quorum of `{}` not reached for request `{request_log}` after the maximum of `{next_attempt}` attempts. Not retrying.", self.quorum);
+				drop(result_sender);
+				break
+			}
+
+			// Apply exponential back off with jitter, as for other requests.
+			let half_max = request_timeout / 2;
+			let sleep_duration =
+				half_max + rand::thread_rng().gen_range(Duration::default()..half_max);
+			tracing::error!("Retrier {name}: Quorum of `{}` not reached for request `{request_log}`, attempt `{attempt}`. Delaying for {sleep_duration:?}", self.quorum);
+			tokio::time::sleep(sleep_duration).await;
+			attempt = next_attempt;
+		}
+
+		R::inner_to_return_type(
+			result_receiver.await,
+			format!("Maximum attempt of `{retry_limit:?}` reached for request `{request_log}`."),
+		)
+	}
 }
 
 #[cfg(test)]
@@ -539,8 +752,15 @@ mod tests {
 			async move {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
 
-				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					vec![async move {}],
+					1,
+					INITIAL_TIMEOUT,
+					100,
+					HealthReporter::default(),
+				);
 
 				const REQUEST_1: u32 = 32;
 				let rx1 = retrier_client
@@ -589,8 +809,15 @@ mod tests {
 				const TIMEOUT: Duration = Duration::from_millis(1000);
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(50);
 
-				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					vec![async move {}],
+					1,
+					INITIAL_TIMEOUT,
+					100,
+					HealthReporter::default(),
+				);
 
 				const REQUEST_1: u32 = 32;
 				let rx1 = retrier_client
@@ -627,8 +854,15 @@ mod tests {
 			async move {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
 
-				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					vec![async move {}],
+					1,
+					INITIAL_TIMEOUT,
+					100,
+					HealthReporter::default(),
+				);
 
 				const REQUEST_1: u32 = 32;
 				assert_eq!(
@@ -666,8 +900,15 @@ mod tests {
 			async move {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
 
-				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					vec![async move {}],
+					1,
+					INITIAL_TIMEOUT,
+					100,
+					HealthReporter::default(),
+				);
 
 				const REQUEST_1: u32 = 32;
 				assert_eq!(
@@ -711,8 +952,15 @@ mod tests {
 
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(1000);
 
-				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 2);
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					vec![async move {}],
+					1,
+					INITIAL_TIMEOUT,
+					2,
+					HealthReporter::default(),
+				);
 
 				// Requests 1 and 2 fill the future buffer.
 				const REQUEST_1: u32 = 32;
@@ -789,8 +1037,15 @@ mod tests {
 			async move {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
 
-				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					vec![async move {}],
+					1,
+					INITIAL_TIMEOUT,
+					100,
+					HealthReporter::default(),
+				);
 
 				retrier_client
 					.request_with_limit(
@@ -824,10 +1079,11 @@ mod tests {
 				let retrier_client = RetrierClient::new(
 					scope,
 					"test",
-					get_client(false),
-					Some(get_client(true)),
+					vec![get_client(false), get_client(true)],
+					1,
 					INITIAL_TIMEOUT,
 					100,
+					HealthReporter::default(),
 				);
 
 				const REQUEST_1: u32 = 32;
@@ -860,6 +1116,102 @@ mod tests {
 		.unwrap();
 	}
 
+	#[test]
+	fn responses_are_grouped_from_most_to_least_common() {
+		assert_eq!(
+			group_responses([(0, 'a'), (1, 'b'), (2, 'b'), (3, 'c'), (4, 'a'), (5, 'b')]),
+			vec![('b', vec![1, 2, 5]), ('a', vec![0, 4]), ('c', vec![3])]
+		);
+		assert_eq!(group_responses::<char>([]), vec![]);
+	}
+
+	#[tokio::test]
+	async fn quorum_request_returns_agreed_response_and_penalises_disagreeing_endpoints() {
+		task_scope(|scope| {
+			async move {
+				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
+
+				// Each endpoint's client is the response that endpoint gives.
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					vec![
+						futures::future::ready(1u32),
+						futures::future::ready(2u32),
+						futures::future::ready(1u32),
+					],
+					2,
+					INITIAL_TIMEOUT,
+					100,
+					HealthReporter::default(),
+				);
+				retrier_client.client_selector.ready_clients(3).await;
+
+				let respond_with_client: TypedFutureGenerator<u32, u32> =
+					Box::pin(|client| Box::pin(async move { Ok(client) }));
+				assert_eq!(
+					retrier_client
+						.request_with_quorum(
+							RequestLog::new("request".to_string(), None),
+							respond_with_client,
+						)
+						.await,
+					1
+				);
+
+				// The disagreeing endpoint is now tried last.
+				assert_eq!(retrier_client.client_selector.preference_order(None), vec![0, 2, 1]);
+				assert_eq!(retrier_client.client_selector.preference_order(Some(0)), vec![2, 1, 0]);
+
+				Ok(())
+			}
+			.boxed()
+		})
+		.await
+		.unwrap();
+	}
+
+	#[tokio::test]
+	async fn quorum_request_with_limit_fails_if_no_quorum_is_reached() {
+		task_scope(|scope| {
+			async move {
+				const INITIAL_TIMEOUT: Duration = Duration::from_millis(10);
+
+				// Every endpoint gives a different response, so a quorum is never reached.
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					vec![
+						futures::future::ready(1u32),
+						futures::future::ready(2u32),
+						futures::future::ready(3u32),
+					],
+					2,
+					INITIAL_TIMEOUT,
+					100,
+					HealthReporter::default(),
+				);
+				retrier_client.client_selector.ready_clients(3).await;
+
+				let respond_with_client: TypedFutureGenerator<u32, u32> =
+					Box::pin(|client| Box::pin(async move { Ok(client) }));
+				assert!(retrier_client
+					.request_with_quorum_and_limit(
+						RequestLog::new("request".to_string(), None),
+						respond_with_client,
+						2,
+					)
+					.await
+					.is_err());
+
+				Ok(())
+			}
+			.boxed()
+		})
+		.await
+		.unwrap();
+	}
+
 	#[tokio::test]
 	#[ignore = "Test runs forever. Useful for manually testing the failing requests will never return (because they are retried until success)."]
 	async fn request_always_fails() {
@@ -867,8 +1219,15 @@ mod tests {
 			async move {
 				const INITIAL_TIMEOUT: Duration = Duration::from_millis(100);
 
-				let retrier_client =
-					RetrierClient::new(scope, "test", async move {}, None, INITIAL_TIMEOUT, 100);
+				let retrier_client = RetrierClient::new(
+					scope,
+					"test",
+					vec![async move {}],
+					1,
+					INITIAL_TIMEOUT,
+					100,
+					HealthReporter::default(),
+				);
 
 				retrier_client
 					.request(
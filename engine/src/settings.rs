@@ -16,11 +16,15 @@ use sp_runtime::DeserializeOwned;
 use url::Url;
 
 use clap::Parser;
+use multisig::client::ceremony_manager::SchedulingLimitsPerScheme;
 use utilities::{
 	logging::LoggingSettings, metrics::Prometheus, redact_endpoint_secret::SecretUrl, Port,
 };
 
-use crate::constants::{CONFIG_ROOT, DEFAULT_CONFIG_ROOT};
+use crate::{
+	constants::{CONFIG_ROOT, DEFAULT_CONFIG_ROOT},
+	state_chain_observer::client::extrinsic_api::signed::signer::SigningKeySource,
+};
 
 pub const DEFAULT_SETTINGS_DIR: &str = "config";
 
@@ -38,6 +42,9 @@ pub struct StateChain {
 	pub ws_endpoint: String,
 	#[serde(deserialize_with = "deser_path")]
 	pub signing_key_file: PathBuf,
+	/// The socket of a remote signer to use instead of the `signing_key_file`.
+	#[serde(default)]
+	pub signer_socket: Option<PathBuf>,
 }
 
 impl StateChain {
@@ -46,6 +53,27 @@ impl StateChain {
 			.map_err(|e| ConfigError::Message(e.to_string()))?;
 		Ok(())
 	}
+
+	pub fn signing_key_source(&self) -> SigningKeySource {
+		match &self.signer_socket {
+			Some(signer_socket) => SigningKeySource::Remote(signer_socket.clone()),
+			None => SigningKeySource::File(self.signing_key_file.clone()),
+		}
+	}
+
+	/// Resolves the signing key file, and the signer socket if there is one, relative to the config
+	/// root. The signing key file is only required to exist if there is no remote signer.
+	pub fn resolve_paths(&mut self, config_root: &Path) -> Result<(), ConfigError> {
+		self.signing_key_file = resolve_settings_path(
+			config_root,
+			&self.signing_key_file,
+			self.signer_socket.is_none().then_some(PathResolutionExpectation::ExistingFile),
+		)?;
+		if let Some(signer_socket) = &self.signer_socket {
+			self.signer_socket = Some(resolve_settings_path(config_root, signer_socket, None)?);
+		}
+		Ok(())
+	}
 }
 
 #[derive(Debug, Deserialize, Clone, Default, PartialEq, Eq)]
@@ -69,19 +97,59 @@ impl ValidateSettings for WsHttpEndpoints {
 	}
 }
 
-#[derive(Debug, Deserialize, Clone, Default, PartialEq, Eq)]
+#[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
 pub struct NodeContainer<NodeConfig> {
 	#[serde(rename = "rpc")]
 	pub primary: NodeConfig,
 	#[serde(rename = "backup_rpc")]
 	pub backup: Option<NodeConfig>,
+	/// Endpoints used in addition to the primary and backup endpoints.
+	#[serde(rename = "additional_rpcs", default)]
+	pub additional: Vec<NodeConfig>,
+	/// The number of endpoints that must agree on the result of reads that are critical to
+	/// witnessing.
+	#[serde(rename = "rpc_quorum", default = "default_rpc_quorum")]
+	pub quorum: u32,
+}
+
+fn default_rpc_quorum() -> u32 {
+	1
+}
+
+impl<NodeConfig: Default> Default for NodeContainer<NodeConfig> {
+	fn default() -> Self {
+		Self {
+			primary: Default::default(),
+			backup: None,
+			additional: Vec::new(),
+			quorum: default_rpc_quorum(),
+		}
+	}
+}
+
+impl<NodeConfig> NodeContainer<NodeConfig> {
+	/// A container with only a primary endpoint.
+	pub fn primary_only(primary: NodeConfig) -> Self {
+		Self { primary, backup: None, additional: Vec::new(), quorum: default_rpc_quorum() }
+	}
+
+	/// All the endpoints, starting with the primary and then the backup endpoint.
+	pub fn endpoints(&self) -> impl Iterator<Item = &NodeConfig> {
+		std::iter::once(&self.primary).chain(&self.backup).chain(&self.additional)
+	}
 }
 
 impl<NodeConfig: ValidateSettings> NodeContainer<NodeConfig> {
 	pub fn validate(&self) -> Result<(), ConfigError> {
-		self.primary.validate()?;
-		if let Some(backup) = &self.backup {
-			backup.validate()?;
+		for endpoint in self.endpoints() {
+			endpoint.validate()?;
+		}
+		let endpoint_count = self.endpoints().count();
+		if self.quorum == 0 || self.quorum as usize > endpoint_count {
+			return Err(ConfigError::Message(format!(
+				"The rpc quorum must be between 1 and the number of endpoints ({endpoint_count}), but is {}",
+				self.quorum
+			)))
 		}
 		Ok(())
 	}
@@ -121,9 +189,8 @@ impl Dot {
 					))
 				})
 		};
-		validate_dot_endpoints(&self.nodes.primary)?;
-		if let Some(backup) = &self.nodes.backup {
-			validate_dot_endpoints(backup)?;
+		for endpoints in self.nodes.endpoints() {
+			validate_dot_endpoints(endpoints)?;
 		}
 		Ok(())
 	}
@@ -140,6 +207,18 @@ fn validate_port_exists(url: &SecretUrl) -> Result<()> {
 	Ok(())
 }
 
+#[derive(Debug, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct Sol {
+	#[serde(flatten)]
+	pub nodes: NodeContainer<WsHttpEndpoints>,
+}
+
+impl Sol {
+	pub fn validate_settings(&self) -> Result<(), ConfigError> {
+		self.nodes.validate()
+	}
+}
+
 #[derive(Debug, Deserialize, Clone, Default, PartialEq, Eq)]
 pub struct HttpBasicAuthEndpoint {
 	pub http_endpoint: SecretUrl,
@@ -178,6 +257,8 @@ pub struct HealthCheck {
 pub struct Signing {
 	#[serde(deserialize_with = "deser_path")]
 	pub db_file: PathBuf,
+	#[serde(default)]
+	pub scheduling_limits: SchedulingLimitsPerScheme,
 }
 
 #[derive(Debug, Deserialize, Clone, PartialEq, Eq)]
@@ -189,6 +270,10 @@ pub struct Settings {
 	pub dot: Dot,
 	pub btc: Btc,
 	pub arb: Evm,
+	pub sol: Sol,
+	/// Polkadot Asset Hub nodes. Asset Hub is a Substrate chain, so it uses the same settings as
+	/// Polkadot.
+	pub hub: Dot,
 
 	pub health_check: Option<HealthCheck>,
 	pub prometheus: Option<Prometheus>,
@@ -202,6 +287,8 @@ pub struct StateChainOptions {
 	pub state_chain_ws_endpoint: Option<String>,
 	#[clap(long = "state_chain.signing_key_file")]
 	pub state_chain_signing_key_file: Option<PathBuf>,
+	#[clap(long = "state_chain.signer_socket")]
+	pub state_chain_signer_socket: Option<PathBuf>,
 }
 
 #[derive(Parser, Debug, Clone, Default)]
@@ -266,6 +353,32 @@ pub struct ArbOptions {
 	pub arb_private_key_file: Option<PathBuf>,
 }
 
+#[derive(Parser, Debug, Clone, Default)]
+pub struct SolOptions {
+	#[clap(long = "sol.rpc.ws_endpoint")]
+	pub sol_ws_endpoint: Option<String>,
+	#[clap(long = "sol.rpc.http_endpoint")]
+	pub sol_http_endpoint: Option<String>,
+
+	#[clap(long = "sol.backup_rpc.ws_endpoint")]
+	pub sol_backup_ws_endpoint: Option<String>,
+	#[clap(long = "sol.backup_rpc.http_endpoint")]
+	pub sol_backup_http_endpoint: Option<String>,
+}
+
+#[derive(Parser, Debug, Clone, Default)]
+pub struct HubOptions {
+	#[clap(long = "hub.rpc.ws_endpoint")]
+	pub hub_ws_endpoint: Option<String>,
+	#[clap(long = "hub.rpc.http_endpoint")]
+	pub hub_http_endpoint: Option<String>,
+
+	#[clap(long = "hub.backup_rpc.ws_endpoint")]
+	pub hub_backup_ws_endpoint: Option<String>,
+	#[clap(long = "hub.backup_rpc.http_endpoint")]
+	pub hub_backup_http_endpoint: Option<String>,
+}
+
 #[derive(Parser, Debug, Clone, Default)]
 pub struct P2POptions {
 	#[clap(long = "p2p.node_key_file", parse(from_os_str))]
@@ -303,6 +416,12 @@ pub struct CommandLineOptions {
 	#[clap(flatten)]
 	pub arb_opts: ArbOptions,
 
+	#[clap(flatten)]
+	pub sol_opts: SolOptions,
+
+	#[clap(flatten)]
+	pub hub_opts: HubOptions,
+
 	// Health Check Settings
 	#[clap(long = "health_check.hostname")]
 	pub health_check_hostname: Option<String>,
@@ -340,6 +459,8 @@ impl Default for CommandLineOptions {
 			dot_opts: DotOptions::default(),
 			btc_opts: BtcOptions::default(),
 			arb_opts: ArbOptions::default(),
+			sol_opts: SolOptions::default(),
+			hub_opts: HubOptions::default(),
 			health_check_hostname: None,
 			health_check_port: None,
 			prometheus_hostname: None,
@@ -357,6 +478,7 @@ const NODE_P2P_ALLOW_LOCAL_IP: &str = "node_p2p.allow_local_ip";
 
 const STATE_CHAIN_WS_ENDPOINT: &str = "state_chain.ws_endpoint";
 const STATE_CHAIN_SIGNING_KEY_FILE: &str = "state_chain.signing_key_file";
+const STATE_CHAIN_SIGNER_SOCKET: &str = "state_chain.signer_socket";
 
 const ETH_PRIVATE_KEY_FILE: &str = "eth.private_key_file";
 const ARB_PRIVATE_KEY_FILE: &str = "arb.private_key_file";
@@ -524,15 +646,15 @@ impl CfSettings for Settings {
 
 		self.arb.validate_settings()?;
 
+		self.sol.validate_settings()?;
+
+		self.hub.validate_settings()?;
+
 		self.state_chain.validate_settings()?;
 
 		is_valid_db_path(&self.signing.db_file).map_err(|e| ConfigError::Message(e.to_string()))?;
 
-		self.state_chain.signing_key_file = resolve_settings_path(
-			config_root,
-			&self.state_chain.signing_key_file,
-			Some(PathResolutionExpectation::ExistingFile),
-		)?;
+		self.state_chain.resolve_paths(config_root)?;
 		self.eth.private_key_file = resolve_settings_path(
 			config_root,
 			&self.eth.private_key_file,
@@ -621,6 +743,9 @@ impl Source for CommandLineOptions {
 
 		self.arb_opts.insert_all(&mut map);
 
+		self.sol_opts.insert_all(&mut map);
+		self.hub_opts.insert_all(&mut map);
+
 		insert_command_line_option(&mut map, "health_check.hostname", &self.health_check_hostname);
 		insert_command_line_option(&mut map, "health_check.port", &self.health_check_port);
 
@@ -678,6 +803,11 @@ impl StateChainOptions {
 			STATE_CHAIN_SIGNING_KEY_FILE,
 			&self.state_chain_signing_key_file,
 		);
+		insert_command_line_option_path(
+			map,
+			STATE_CHAIN_SIGNER_SOCKET,
+			&self.state_chain_signer_socket,
+		);
 	}
 }
 
@@ -771,6 +901,34 @@ impl ArbOptions {
 	}
 }
 
+impl SolOptions {
+	pub fn insert_all(&self, map: &mut HashMap<String, Value>) {
+		insert_command_line_option(map, "sol.rpc.ws_endpoint", &self.sol_ws_endpoint);
+		insert_command_line_option(map, "sol.rpc.http_endpoint", &self.sol_http_endpoint);
+
+		insert_command_line_option(map, "sol.backup_rpc.ws_endpoint", &self.sol_backup_ws_endpoint);
+		insert_command_line_option(
+			map,
+			"sol.backup_rpc.http_endpoint",
+			&self.sol_backup_http_endpoint,
+		);
+	}
+}
+
+impl HubOptions {
+	pub fn insert_all(&self, map: &mut HashMap<String, Value>) {
+		insert_command_line_option(map, "hub.rpc.ws_endpoint", &self.hub_ws_endpoint);
+		insert_command_line_option(map, "hub.rpc.http_endpoint", &self.hub_http_endpoint);
+
+		insert_command_line_option(map, "hub.backup_rpc.ws_endpoint", &self.hub_backup_ws_endpoint);
+		insert_command_line_option(
+			map,
+			"hub.backup_rpc.http_endpoint",
+			&self.hub_backup_http_endpoint,
+		);
+	}
+}
+
 impl Settings {
 	/// New settings loaded from "$base_config_path/config/Settings.toml",
 	/// environment and `CommandLineOptions`
@@ -838,10 +996,13 @@ pub mod tests {
 		BTC_BACKUP_HTTP_ENDPOINT, BTC_BACKUP_RPC_PASSWORD, BTC_BACKUP_RPC_USER, BTC_HTTP_ENDPOINT,
 		BTC_RPC_PASSWORD, BTC_RPC_USER, DOT_BACKUP_HTTP_ENDPOINT, DOT_BACKUP_WS_ENDPOINT,
 		DOT_HTTP_ENDPOINT, DOT_WS_ENDPOINT, ETH_BACKUP_HTTP_ENDPOINT, ETH_BACKUP_WS_ENDPOINT,
-		ETH_HTTP_ENDPOINT, ETH_WS_ENDPOINT, NODE_P2P_IP_ADDRESS,
+		ETH_HTTP_ENDPOINT, ETH_WS_ENDPOINT, HUB_BACKUP_HTTP_ENDPOINT, HUB_BACKUP_WS_ENDPOINT,
+		HUB_HTTP_ENDPOINT, HUB_WS_ENDPOINT, NODE_P2P_IP_ADDRESS, SOL_BACKUP_HTTP_ENDPOINT,
+		SOL_BACKUP_WS_ENDPOINT, SOL_HTTP_ENDPOINT, SOL_WS_ENDPOINT,
 	};
 
 	use super::*;
+	use multisig::client::ceremony_manager::SchedulingLimits;
 
 	macro_rules! implement_test_environment {
 		($($const_name:ident => $const_value:expr),*) => {
@@ -892,7 +1053,19 @@ pub mod tests {
 		ARB_HTTP_ENDPOINT => "http://localhost:8547",
 		ARB_WS_ENDPOINT => "ws://localhost:8548",
 		ARB_BACKUP_HTTP_ENDPOINT => "http://second.localhost:8547",
-		ARB_BACKUP_WS_ENDPOINT => "ws://second.localhost:8548"
+		ARB_BACKUP_WS_ENDPOINT => "ws://second.localhost:8548",
+
+		SOL_HTTP_ENDPOINT => "http://localhost:8899",
+		SOL_WS_ENDPOINT => "ws://localhost:8900",
+		SOL_BACKUP_HTTP_ENDPOINT => "http://second.localhost:8899",
+		SOL_BACKUP_WS_ENDPOINT => "ws://second.localhost:8900",
+
+		HUB_WS_ENDPOINT => "wss://my_fake_assethub_rpc:443/<secret_key>",
+		HUB_HTTP_ENDPOINT => "https://my_fake_assethub_rpc:443/<secret_key>",
+		HUB_BACKUP_WS_ENDPOINT =>
+		"wss://second.my_fake_assethub_rpc:443/<secret_key>",
+		HUB_BACKUP_HTTP_ENDPOINT =>
+		"https://second.my_fake_assethub_rpc:443/<secret_key>"
 	}
 
 	// We do them like this so they run sequentially, which is necessary so the environment doesn't
@@ -928,10 +1101,23 @@ pub mod tests {
 			settings.dot.nodes.backup.unwrap().ws_endpoint.as_ref(),
 			"wss://second.my_fake_polkadot_rpc:443/<secret_key>"
 		);
+		assert_eq!(
+			settings.hub.nodes.primary.ws_endpoint.as_ref(),
+			"wss://my_fake_assethub_rpc:443/<secret_key>"
+		);
+		assert_eq!(
+			settings.hub.nodes.backup.unwrap().ws_endpoint.as_ref(),
+			"wss://second.my_fake_assethub_rpc:443/<secret_key>"
+		);
 		assert_eq!(
 			settings.arb.nodes.backup.unwrap().http_endpoint.as_ref(),
 			"http://second.localhost:8547"
 		);
+		assert_eq!(settings.sol.nodes.primary.http_endpoint.as_ref(), "http://localhost:8899");
+		assert_eq!(
+			settings.sol.nodes.backup.unwrap().http_endpoint.as_ref(),
+			"http://second.localhost:8899"
+		);
 	}
 
 	fn test_init_config_with_testing_config() {
@@ -944,6 +1130,18 @@ pub mod tests {
 				.canonicalize()
 				.unwrap()
 		);
+
+		// Limits that aren't set fall back to the defaults, per crypto scheme and per field.
+		assert_eq!(
+			test_settings.signing.scheduling_limits,
+			SchedulingLimitsPerScheme {
+				evm: SchedulingLimits {
+					max_concurrent_ceremonies: 16,
+					..SchedulingLimits::default()
+				},
+				..SchedulingLimitsPerScheme::default()
+			}
+		);
 	}
 
 	fn test_base_config_path_command_line_option() {
@@ -994,6 +1192,7 @@ pub mod tests {
 				state_chain_signing_key_file: Some(
 					PathBuf::from_str("keys/signing_key_file_2").unwrap(),
 				),
+				state_chain_signer_socket: Some(PathBuf::from_str("signer.sock").unwrap()),
 			},
 			eth_opts: EthOptions {
 				eth_ws_endpoint: Some("ws://endpoint:4321".to_owned()),
@@ -1025,6 +1224,19 @@ pub mod tests {
 				arb_backup_http_endpoint: Some("http://second_endpoint:4321".to_owned()),
 				arb_private_key_file: Some(PathBuf::from_str("keys/eth_private_key_2").unwrap()),
 			},
+			sol_opts: SolOptions {
+				sol_ws_endpoint: Some("ws://endpoint:4321".to_owned()),
+				sol_http_endpoint: Some("http://endpoint:4321".to_owned()),
+				sol_backup_ws_endpoint: Some("ws://second_endpoint:4321".to_owned()),
+				sol_backup_http_endpoint: Some("http://second_endpoint:4321".to_owned()),
+			},
+			hub_opts: HubOptions {
+				hub_ws_endpoint: Some("ws://endpoint:4321".to_owned()),
+				hub_http_endpoint: Some("http://endpoint:4321".to_owned()),
+
+				hub_backup_ws_endpoint: Some("ws://second.endpoint:4321".to_owned()),
+				hub_backup_http_endpoint: Some("http://second.endpoint:4321".to_owned()),
+			},
 			health_check_hostname: Some("health_check_hostname".to_owned()),
 			health_check_port: Some(1337),
 			prometheus_hostname: Some(("prometheus_hostname").to_owned()),
@@ -1050,6 +1262,7 @@ pub mod tests {
 			settings.state_chain.ws_endpoint
 		);
 		assert!(settings.state_chain.signing_key_file.ends_with("signing_key_file_2"));
+		assert!(settings.state_chain.signer_socket.as_ref().unwrap().ends_with("signer.sock"));
 
 		assert_eq!(
 			opts.eth_opts.eth_ws_endpoint.unwrap(),
@@ -1143,6 +1356,44 @@ pub mod tests {
 			settings.arb.nodes.primary.http_endpoint.as_ref()
 		);
 
+		assert_eq!(
+			opts.sol_opts.sol_ws_endpoint.unwrap(),
+			settings.sol.nodes.primary.ws_endpoint.as_ref()
+		);
+		assert_eq!(
+			opts.sol_opts.sol_http_endpoint.unwrap(),
+			settings.sol.nodes.primary.http_endpoint.as_ref()
+		);
+
+		let sol_backup_node = settings.sol.nodes.backup.unwrap();
+		assert_eq!(
+			opts.sol_opts.sol_backup_ws_endpoint.unwrap(),
+			sol_backup_node.ws_endpoint.as_ref()
+		);
+		assert_eq!(
+			opts.sol_opts.sol_backup_http_endpoint.unwrap(),
+			sol_backup_node.http_endpoint.as_ref()
+		);
+
+		assert_eq!(
+			opts.hub_opts.hub_ws_endpoint.unwrap(),
+			settings.hub.nodes.primary.ws_endpoint.as_ref()
+		);
+		assert_eq!(
+			opts.hub_opts.hub_http_endpoint.unwrap(),
+			settings.hub.nodes.primary.http_endpoint.as_ref()
+		);
+
+		let hub_backup_node = settings.hub.nodes.backup.unwrap();
+		assert_eq!(
+			opts.hub_opts.hub_backup_ws_endpoint.unwrap(),
+			hub_backup_node.ws_endpoint.as_ref()
+		);
+		assert_eq!(
+			opts.hub_opts.hub_backup_http_endpoint.unwrap(),
+			hub_backup_node.http_endpoint.as_ref()
+		);
+
 		assert_eq!(
 			opts.health_check_hostname.unwrap(),
 			settings.health_check.as_ref().unwrap().hostname
@@ -1224,6 +1475,8 @@ pub mod tests {
 					ws_endpoint: "ws://valid.endpoint_with_port:1234".into(),
 					http_endpoint: "http://valid.endpoint_with_port:6969".into(),
 				}),
+				additional: vec![],
+				quorum: 1,
 			},
 		};
 		assert_ok!(valid_settings.validate_settings());
@@ -1239,6 +1492,38 @@ pub mod tests {
 			http_endpoint: "http://invalid.no_port_in_url/secret_key".into(),
 		});
 		assert!(invalid_backup_settings.validate_settings().is_err());
+
+		let mut invalid_additional_settings = valid_settings.clone();
+		invalid_additional_settings.nodes.additional = vec![WsHttpEndpoints {
+			ws_endpoint: "ws://invalid.no_port_in_url/secret_key".into(),
+			http_endpoint: "http://valid.endpoint_with_port:443".into(),
+		}];
+		assert!(invalid_additional_settings.validate_settings().is_err());
+	}
+
+	#[test]
+	fn test_rpc_quorum_validation() {
+		let endpoints = |port: u16| WsHttpEndpoints {
+			ws_endpoint: format!("ws://localhost:{port}").into(),
+			http_endpoint: format!("http://localhost:{port}").into(),
+		};
+		let mut nodes = NodeContainer {
+			primary: endpoints(1),
+			backup: Some(endpoints(2)),
+			additional: vec![endpoints(3)],
+			quorum: 2,
+		};
+		assert_ok!(nodes.validate());
+		assert_eq!(nodes.endpoints().count(), 3);
+
+		nodes.quorum = 3;
+		assert_ok!(nodes.validate());
+
+		nodes.quorum = 4;
+		assert!(nodes.validate().is_err());
+
+		nodes.quorum = 0;
+		assert!(nodes.validate().is_err());
 	}
 
 	#[test]
@@ -0,0 +1,2 @@
+pub mod retry_rpc;
+pub mod rpc;
@@ -0,0 +1,295 @@
+use std::ops::RangeInclusive;
+
+use cf_chains::{
+	sol::{SolAddress, SolHash, SolSignature},
+	Chain, Solana,
+};
+use core::time::Duration;
+use utilities::task_scope::Scope;
+
+use crate::{
+	health::HealthReporter,
+	retrier::{Attempt, RequestLog, RetrierClient},
+	settings::{NodeContainer, WsHttpEndpoints},
+	witness::common::chain_source::{ChainClient, Header},
+};
+
+use anyhow::{anyhow, Result};
+
+use super::rpc::{
+	ConfirmedTransaction, SignatureInfo, SignatureStatus, SlotNumber, SolRpcApi, SolRpcClient,
+	MAX_SIGNATURES_PER_REQUEST,
+};
+
+#[derive(Clone)]
+pub struct SolRetryRpcClient {
+	retry_client: RetrierClient<SolRpcClient>,
+}
+
+const SOLANA_RPC_TIMEOUT: Duration = Duration::from_millis(4 * 1000);
+const MAX_CONCURRENT_SUBMISSIONS: u32 = 100;
+
+const MAX_BROADCAST_RETRIES: Attempt = 2;
+
+/// How often we poll for the finalized slot while waiting for a witness range to be finalized.
+const FINALIZED_SLOT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+impl SolRetryRpcClient {
+	pub fn new(
+		scope: &Scope<'_, anyhow::Error>,
+		nodes: NodeContainer<WsHttpEndpoints>,
+		expected_genesis_hash: Option<SolHash>,
+		health_reporter: HealthReporter,
+	) -> Result<Self> {
+		let rpc_clients = nodes
+			.endpoints()
+			.map(|endpoint| {
+				SolRpcClient::new(endpoint.http_endpoint.clone(), expected_genesis_hash)
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		Ok(Self {
+			retry_client: RetrierClient::new(
+				scope,
+				"sol_rpc",
+				rpc_clients,
+				nodes.quorum,
+				SOLANA_RPC_TIMEOUT,
+				MAX_CONCURRENT_SUBMISSIONS,
+				health_reporter,
+			),
+		})
+	}
+}
+
+#[async_trait::async_trait]
+pub trait SolRetryRpcApi: Clone {
+	async fn finalized_slot(&self) -> SlotNumber;
+
+	/// The median of the recent prioritization fees paid by transactions writing to the given
+	/// accounts, in micro-lamports per compute unit.
+	async fn prioritization_fee(&self, addresses: Vec<SolAddress>) -> u64;
+
+	/// All the finalized signatures involving `address` that were included in one of the slots of
+	/// `slots`, ordered from oldest to newest.
+	async fn signatures_for_address(
+		&self,
+		address: SolAddress,
+		slots: RangeInclusive<SlotNumber>,
+	) -> Vec<SignatureInfo>;
+
+	async fn transaction(&self, signature: SolSignature) -> ConfirmedTransaction;
+
+	async fn signature_statuses(
+		&self,
+		signatures: Vec<SolSignature>,
+	) -> Vec<Option<SignatureStatus>>;
+
+	async fn send_transaction(&self, transaction_bytes: Vec<u8>) -> anyhow::Result<SolSignature>;
+
+	/// The current durable nonce of `nonce_account`, or `None` if the account doesn't exist.
+	async fn durable_nonce(&self, nonce_account: SolAddress) -> Option<SolHash>;
+}
+
+#[async_trait::async_trait]
+impl SolRetryRpcApi for SolRetryRpcClient {
+	async fn finalized_slot(&self) -> SlotNumber {
+		self.retry_client
+			.request(
+				RequestLog::new("finalized_slot".to_string(), None),
+				Box::pin(move |client| {
+					#[allow(clippy::redundant_async_block)]
+					Box::pin(async move { client.get_finalized_slot().await })
+				}),
+			)
+			.await
+	}
+
+	async fn prioritization_fee(&self, addresses: Vec<SolAddress>) -> u64 {
+		self.retry_client
+			.request(
+				RequestLog::new("prioritization_fee".to_string(), Some(format!("{addresses:?}"))),
+				Box::pin(move |client| {
+					let addresses = addresses.clone();
+					#[allow(clippy::redundant_async_block)]
+					Box::pin(async move {
+						let mut fees = client
+							.get_recent_prioritization_fees(addresses)
+							.await?
+							.into_iter()
+							.map(|fee| fee.prioritization_fee)
+							.collect::<Vec<_>>();
+						fees.sort_unstable();
+						Ok(fees.get(fees.len() / 2).copied().unwrap_or_default())
+					})
+				}),
+			)
+			.await
+	}
+
+	async fn signatures_for_address(
+		&self,
+		address: SolAddress,
+		slots: RangeInclusive<SlotNumber>,
+	) -> Vec<SignatureInfo> {
+		self.retry_client
+			.request(
+				RequestLog::new(
+					"signatures_for_address".to_string(),
+					Some(format!("{address}, {slots:?}")),
+				),
+				Box::pin(move |client| {
+					let slots = slots.clone();
+					#[allow(clippy::redundant_async_block)]
+					Box::pin(async move {
+						// Signatures are returned newest first, so we page backwards until we pass
+						// the start of the range.
+						let mut signatures = Vec::new();
+						let mut before = None;
+						loop {
+							let page = client.get_signatures_for_address(address, before).await?;
+							let is_last_page = page.len() < MAX_SIGNATURES_PER_REQUEST;
+							before = page.last().map(|info| info.signature);
+							let passed_range_start =
+								page.last().map_or(true, |info| info.slot < *slots.start());
+
+							signatures
+								.extend(page.into_iter().filter(|info| slots.contains(&info.slot)));
+
+							if is_last_page || passed_range_start {
+								break
+							}
+						}
+						signatures.reverse();
+						Ok(signatures)
+					})
+				}),
+			)
+			.await
+	}
+
+	async fn transaction(&self, signature: SolSignature) -> ConfirmedTransaction {
+		self.retry_client
+			.request_with_quorum(
+				RequestLog::new("transaction".to_string(), Some(format!("{signature}"))),
+				Box::pin(move |client| {
+					#[allow(clippy::redundant_async_block)]
+					Box::pin(async move {
+						client
+							.get_transaction(signature)
+							.await?
+							.ok_or_else(|| anyhow!("Transaction {signature} not found"))
+					})
+				}),
+			)
+			.await
+	}
+
+	async fn signature_statuses(
+		&self,
+		signatures: Vec<SolSignature>,
+	) -> Vec<Option<SignatureStatus>> {
+		self.retry_client
+			.request(
+				RequestLog::new("signature_statuses".to_string(), Some(format!("{signatures:?}"))),
+				Box::pin(move |client| {
+					let signatures = signatures.clone();
+					#[allow(clippy::redundant_async_block)]
+					Box::pin(async move { client.get_signature_statuses(signatures).await })
+				}),
+			)
+			.await
+	}
+
+	async fn send_transaction(&self, transaction_bytes: Vec<u8>) -> anyhow::Result<SolSignature> {
+		self.retry_client
+			.request_with_limit(
+				RequestLog::new(
+					"send_transaction".to_string(),
+					Some(format!("{transaction_bytes:?}")),
+				),
+				Box::pin(move |client| {
+					let transaction_bytes = transaction_bytes.clone();
+					#[allow(clippy::redundant_async_block)]
+					Box::pin(async move { client.send_transaction(transaction_bytes).await })
+				}),
+				MAX_BROADCAST_RETRIES,
+			)
+			.await
+	}
+
+	async fn durable_nonce(&self, nonce_account: SolAddress) -> Option<SolHash> {
+		self.retry_client
+			.request(
+				RequestLog::new("durable_nonce".to_string(), Some(format!("{nonce_account}"))),
+				Box::pin(move |client| {
+					#[allow(clippy::redundant_async_block)]
+					Box::pin(async move { client.get_durable_nonce(nonce_account).await })
+				}),
+			)
+			.await
+	}
+}
+
+#[async_trait::async_trait]
+impl ChainClient for SolRetryRpcClient {
+	type Index = <Solana as Chain>::ChainBlockNumber;
+	type Hash = ();
+	type Data = ();
+
+	/// Waits until the whole witness range starting at `index` has been finalized. Solana slots
+	/// don't form a hash-linked chain we can follow (slots may be skipped), so the header carries
+	/// no hash.
+	async fn header_at_index(
+		&self,
+		index: Self::Index,
+	) -> Header<Self::Index, Self::Hash, Self::Data> {
+		assert!(Solana::is_block_witness_root(index));
+		let witness_range_end = *Solana::block_witness_range(index).end();
+
+		while self.finalized_slot().await < witness_range_end {
+			tokio::time::sleep(FINALIZED_SLOT_POLL_INTERVAL).await;
+		}
+
+		Header { index, hash: (), parent_hash: Some(()), data: () }
+	}
+}
+
+#[cfg(test)]
+pub mod mocks {
+
+	use super::*;
+	use mockall::mock;
+
+	mock! {
+		pub SolRetryRpcClient {}
+
+		impl Clone for SolRetryRpcClient {
+			fn clone(&self) -> Self;
+		}
+
+		#[async_trait::async_trait]
+		impl SolRetryRpcApi for SolRetryRpcClient {
+			async fn finalized_slot(&self) -> SlotNumber;
+
+			async fn prioritization_fee(&self, addresses: Vec<SolAddress>) -> u64;
+
+			async fn signatures_for_address(
+				&self,
+				address: SolAddress,
+				slots: RangeInclusive<SlotNumber>,
+			) -> Vec<SignatureInfo>;
+
+			async fn transaction(&self, signature: SolSignature) -> ConfirmedTransaction;
+
+			async fn signature_statuses(
+				&self,
+				signatures: Vec<SolSignature>,
+			) -> Vec<Option<SignatureStatus>>;
+
+			async fn send_transaction(&self, transaction_bytes: Vec<u8>) -> anyhow::Result<SolSignature>;
+
+			async fn durable_nonce(&self, nonce_account: SolAddress) -> Option<SolHash>;
+		}
+	}
+}
@@ -0,0 +1,589 @@
+use base64::Engine;
+use cf_chains::{
+	sol::{SolAddress, SolHash, SolSignature},
+	Chain, Solana,
+};
+use futures_core::Future;
+use reqwest::Client;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+use thiserror::Error;
+use tracing::{error, info};
+use utilities::{make_periodic_tick, redact_endpoint_secret::SecretUrl};
+
+use crate::constants::RPC_RETRY_CONNECTION_INTERVAL;
+
+use anyhow::{anyhow, bail, Result};
+
+pub type SlotNumber = <Solana as Chain>::ChainBlockNumber;
+
+/// The maximum number of signatures the node will return from a single `getSignaturesForAddress`
+/// request.
+pub const MAX_SIGNATURES_PER_REQUEST: usize = 1000;
+
+/// The length of the data of a durable nonce account.
+const NONCE_ACCOUNT_LENGTH: usize = 80;
+
+/// All queries are made at the `finalized` commitment level, so that we never witness anything that
+/// could be rolled back.
+const FINALIZED: &str = "finalized";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RpcError {
+	pub code: i64,
+	pub message: String,
+	pub data: Option<serde_json::Value>,
+}
+
+#[derive(Error, Debug)]
+pub enum Error {
+	#[error("Transport error: {0}")]
+	Transport(reqwest::Error),
+	#[error("JSON decode error: {0}")]
+	Json(serde_json::Error),
+	#[error("RPC error response: {0:?}")]
+	Rpc(RpcError),
+}
+
+/// An entry returned by `getSignaturesForAddress`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureInfo {
+	pub signature: SolSignature,
+	pub slot: SlotNumber,
+	pub err: Option<serde_json::Value>,
+}
+
+/// An entry returned by `getSignatureStatuses`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureStatus {
+	pub slot: SlotNumber,
+	pub err: Option<serde_json::Value>,
+	pub confirmation_status: Option<String>,
+}
+
+impl SignatureStatus {
+	pub fn is_finalized(&self) -> bool {
+		self.confirmation_status.as_deref() == Some(FINALIZED)
+	}
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrioritizationFee {
+	pub slot: SlotNumber,
+	pub prioritization_fee: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LoadedAddresses {
+	pub writable: Vec<SolAddress>,
+	pub readonly: Vec<SolAddress>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMeta {
+	pub err: Option<serde_json::Value>,
+	pub fee: u64,
+	pub pre_balances: Vec<u64>,
+	pub post_balances: Vec<u64>,
+	#[serde(default)]
+	pub loaded_addresses: Option<LoadedAddresses>,
+	#[serde(default)]
+	pub pre_token_balances: Option<Vec<TokenBalance>>,
+	#[serde(default)]
+	pub post_token_balances: Option<Vec<TokenBalance>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UiTokenAmount {
+	/// The raw token amount, as a decimal string.
+	pub amount: String,
+}
+
+/// The SPL token balance of one of the accounts referenced by a transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenBalance {
+	pub account_index: usize,
+	pub mint: SolAddress,
+	pub ui_token_amount: UiTokenAmount,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionMessage {
+	pub account_keys: Vec<SolAddress>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct UiTransaction {
+	pub signatures: Vec<SolSignature>,
+	pub message: TransactionMessage,
+}
+
+/// A transaction as returned by `getTransaction` with `json` encoding.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ConfirmedTransaction {
+	pub slot: SlotNumber,
+	pub meta: Option<TransactionMeta>,
+	pub transaction: UiTransaction,
+}
+
+impl ConfirmedTransaction {
+	/// All the accounts referenced by the transaction, in the order used to index the balances in
+	/// the transaction meta. This includes accounts loaded from address lookup tables.
+	pub fn account_keys(&self) -> Vec<SolAddress> {
+		let mut account_keys = self.transaction.message.account_keys.clone();
+		if let Some(loaded_addresses) =
+			self.meta.as_ref().and_then(|meta| meta.loaded_addresses.as_ref())
+		{
+			account_keys.extend(loaded_addresses.writable.iter().copied());
+			account_keys.extend(loaded_addresses.readonly.iter().copied());
+		}
+		account_keys
+	}
+
+	/// The change in lamports of the given account caused by this transaction. Returns `None` if
+	/// the transaction failed or doesn't reference the account.
+	pub fn balance_change(&self, address: &SolAddress) -> Option<i128> {
+		let meta = self.meta.as_ref().filter(|meta| meta.err.is_none())?;
+		let index = self.account_keys().iter().position(|key| key == address)?;
+		Some(
+			i128::from(*meta.post_balances.get(index)?) -
+				i128::from(*meta.pre_balances.get(index)?),
+		)
+	}
+
+	/// The change in the balance of the given token account for the token `mint` caused by this
+	/// transaction. Returns `None` if the transaction failed or doesn't touch the token account.
+	pub fn token_balance_change(&self, address: &SolAddress, mint: &SolAddress) -> Option<i128> {
+		let meta = self.meta.as_ref().filter(|meta| meta.err.is_none())?;
+		let index = self.account_keys().iter().position(|key| key == address)?;
+		let token_balance = |balances: &Option<Vec<TokenBalance>>| -> Option<i128> {
+			balances
+				.as_ref()?
+				.iter()
+				.find(|balance| balance.account_index == index && balance.mint == *mint)
+				.and_then(|balance| balance.ui_token_amount.amount.parse::<u64>().ok())
+				.map(i128::from)
+		};
+		match (token_balance(&meta.pre_token_balances), token_balance(&meta.post_token_balances)) {
+			(None, None) => None,
+			// Token accounts that are created or closed within the transaction only have one entry.
+			(pre, post) => Some(post.unwrap_or_default() - pre.unwrap_or_default()),
+		}
+	}
+}
+
+#[derive(Clone)]
+pub struct SolRpcClient {
+	// Internally the Client is Arc'd
+	client: Client,
+	endpoint: SecretUrl,
+}
+
+impl SolRpcClient {
+	pub fn new(
+		endpoint: SecretUrl,
+		expected_genesis_hash: Option<SolHash>,
+	) -> Result<impl Future<Output = Self>> {
+		let client = Client::builder().build()?;
+
+		Ok(async move {
+			let sol_client = Self { client, endpoint };
+			let mut poll_interval = make_periodic_tick(RPC_RETRY_CONNECTION_INTERVAL, true);
+			loop {
+				poll_interval.tick().await;
+				match sol_client.get_genesis_hash().await {
+					Ok(genesis_hash) => match expected_genesis_hash {
+						Some(expected_genesis_hash) if expected_genesis_hash != genesis_hash => {
+							error!(
+								"Connected to Solana node at {} but the genesis hash {genesis_hash} does not match the expected genesis hash {expected_genesis_hash}. \
+								Please check your CFE configuration file...",
+								sol_client.endpoint
+							)
+						},
+						_ => {
+							info!(
+								"Connected to Solana node at {} with genesis hash {genesis_hash}",
+								sol_client.endpoint
+							);
+							break
+						},
+					},
+					Err(e) => error!(
+						"Failure connecting to Solana node at {} with error: {e}. \
+						Please check your CFE configuration file. Retrying in {:?}...",
+						sol_client.endpoint,
+						poll_interval.period()
+					),
+				}
+			}
+			sol_client
+		})
+	}
+
+	async fn call_rpc_raw(
+		&self,
+		method: &str,
+		params: serde_json::Value,
+	) -> Result<serde_json::Value, Error> {
+		let response = self
+			.client
+			.post(self.endpoint.as_ref())
+			.json(&json!({
+				"jsonrpc": "2.0",
+				"id": 0,
+				"method": method,
+				"params": params,
+			}))
+			.send()
+			.await
+			.map_err(Error::Transport)?
+			.json::<serde_json::Value>()
+			.await
+			.map_err(Error::Transport)?;
+
+		let error = &response["error"];
+		if !error.is_null() {
+			Err(Error::Rpc(serde_json::from_value(error.clone()).map_err(Error::Json)?))
+		} else {
+			Ok(response["result"].to_owned())
+		}
+	}
+
+	async fn call_rpc<T: DeserializeOwned>(
+		&self,
+		method: &str,
+		params: serde_json::Value,
+	) -> Result<T> {
+		serde_json::from_value(self.call_rpc_raw(method, params).await?)
+			.map_err(|e| anyhow!("Error deserializing {method} response: {e}"))
+	}
+}
+
+/// Extracts the durable nonce from the data of a nonce account.
+fn durable_nonce_from_account_data(data: &[u8]) -> Result<SolHash> {
+	// The data is the bincode-serialized nonce state: a u32 version, a u32 state (1 once
+	// initialized), the 32 byte authority, the 32 byte durable nonce and the u64 fee.
+	if data.len() != NONCE_ACCOUNT_LENGTH {
+		bail!("Unexpected nonce account length {}", data.len())
+	}
+	if data[4..8] != 1u32.to_le_bytes() {
+		bail!("Nonce account is not initialized")
+	}
+	Ok(SolHash(data[40..72].try_into().expect("Length is checked above")))
+}
+
+/// Extracts the first signature of a bincode-serialized transaction, which is the transaction id.
+fn transaction_signature(transaction_bytes: &[u8]) -> Result<SolSignature> {
+	// The signatures are prefixed by a compact-u16 length. Any length below 0x80 is encoded as a
+	// single byte.
+	match transaction_bytes.first() {
+		Some(&num_signatures) if num_signatures > 0 && num_signatures < 0x80 =>
+			Ok(<[u8; 64]>::try_from(
+				transaction_bytes
+					.get(1..65)
+					.ok_or_else(|| anyhow!("Transaction too short to contain a signature"))?,
+			)?
+			.into()),
+		_ => bail!("Transaction has an unexpected number of signatures"),
+	}
+}
+
+#[async_trait::async_trait]
+pub trait SolRpcApi {
+	async fn get_genesis_hash(&self) -> Result<SolHash>;
+
+	async fn get_finalized_slot(&self) -> Result<SlotNumber>;
+
+	async fn get_recent_prioritization_fees(
+		&self,
+		addresses: Vec<SolAddress>,
+	) -> Result<Vec<PrioritizationFee>>;
+
+	async fn get_signatures_for_address(
+		&self,
+		address: SolAddress,
+		before: Option<SolSignature>,
+	) -> Result<Vec<SignatureInfo>>;
+
+	async fn get_transaction(
+		&self,
+		signature: SolSignature,
+	) -> Result<Option<ConfirmedTransaction>>;
+
+	async fn get_signature_statuses(
+		&self,
+		signatures: Vec<SolSignature>,
+	) -> Result<Vec<Option<SignatureStatus>>>;
+
+	async fn send_transaction(&self, transaction_bytes: Vec<u8>) -> Result<SolSignature>;
+
+	/// The current durable nonce of `nonce_account`, if the account exists.
+	async fn get_durable_nonce(&self, nonce_account: SolAddress) -> Result<Option<SolHash>>;
+}
+
+#[async_trait::async_trait]
+impl SolRpcApi for SolRpcClient {
+	async fn get_genesis_hash(&self) -> Result<SolHash> {
+		self.call_rpc("getGenesisHash", json!([])).await
+	}
+
+	async fn get_finalized_slot(&self) -> Result<SlotNumber> {
+		self.call_rpc("getSlot", json!([{ "commitment": FINALIZED }])).await
+	}
+
+	async fn get_recent_prioritization_fees(
+		&self,
+		addresses: Vec<SolAddress>,
+	) -> Result<Vec<PrioritizationFee>> {
+		self.call_rpc("getRecentPrioritizationFees", json!([addresses])).await
+	}
+
+	async fn get_signatures_for_address(
+		&self,
+		address: SolAddress,
+		before: Option<SolSignature>,
+	) -> Result<Vec<SignatureInfo>> {
+		let mut config = json!({
+			"commitment": FINALIZED,
+			"limit": MAX_SIGNATURES_PER_REQUEST,
+		});
+		if let Some(before) = before {
+			config["before"] = json!(before);
+		}
+		self.call_rpc("getSignaturesForAddress", json!([address, config])).await
+	}
+
+	async fn get_transaction(
+		&self,
+		signature: SolSignature,
+	) -> Result<Option<ConfirmedTransaction>> {
+		self.call_rpc(
+			"getTransaction",
+			json!([signature, {
+				"commitment": FINALIZED,
+				"encoding": "json",
+				"maxSupportedTransactionVersion": 0,
+			}]),
+		)
+		.await
+	}
+
+	async fn get_signature_statuses(
+		&self,
+		signatures: Vec<SolSignature>,
+	) -> Result<Vec<Option<SignatureStatus>>> {
+		#[derive(Deserialize)]
+		struct Response {
+			value: Vec<Option<SignatureStatus>>,
+		}
+
+		let response: Response = self
+			.call_rpc(
+				"getSignatureStatuses",
+				json!([signatures, { "searchTransactionHistory": true }]),
+			)
+			.await?;
+
+		if response.value.len() != signatures.len() {
+			bail!("Incorrect number of statuses returned by getSignatureStatuses");
+		}
+
+		Ok(response.value)
+	}
+
+	async fn send_transaction(&self, transaction_bytes: Vec<u8>) -> Result<SolSignature> {
+		let derived_signature = transaction_signature(&transaction_bytes)?;
+
+		match self
+			.call_rpc_raw(
+				"sendTransaction",
+				json!([base64::engine::general_purpose::STANDARD.encode(&transaction_bytes), {
+					"encoding": "base64",
+					"preflightCommitment": FINALIZED,
+				}]),
+			)
+			.await
+		{
+			Ok(signature) => {
+				let signature = serde_json::from_value::<SolSignature>(signature)
+					.map_err(|e| anyhow!("Error deserializing response: {e:?}"))?;
+				assert_eq!(signature, derived_signature);
+				Ok(signature)
+			},
+			Err(Error::Rpc(e)) if e.message.contains("already been processed") => {
+				tracing::info!(
+					"Transaction already processed with signature: {:?}",
+					derived_signature
+				);
+				Ok(derived_signature)
+			},
+			Err(e) => Err(anyhow!("Error sending transaction: {e}")),
+		}
+	}
+
+	async fn get_durable_nonce(&self, nonce_account: SolAddress) -> Result<Option<SolHash>> {
+		#[derive(Deserialize)]
+		struct AccountInfo {
+			// The encoded data, followed by its encoding
+			data: (String, String),
+		}
+		#[derive(Deserialize)]
+		struct Response {
+			value: Option<AccountInfo>,
+		}
+
+		let response: Response = self
+			.call_rpc(
+				"getAccountInfo",
+				json!([nonce_account, { "commitment": FINALIZED, "encoding": "base64" }]),
+			)
+			.await?;
+
+		response
+			.value
+			.map(|account| {
+				durable_nonce_from_account_data(
+					&base64::engine::general_purpose::STANDARD.decode(account.data.0)?,
+				)
+			})
+			.transpose()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn balance_change_includes_loaded_addresses() {
+		let deposit_address = SolAddress([3u8; 32]);
+		let transaction: ConfirmedTransaction = serde_json::from_value(json!({
+			"slot": 100,
+			"meta": {
+				"err": null,
+				"fee": 5000,
+				"preBalances": [1_000_000, 0, 500],
+				"postBalances": [894_500, 1, 100_500],
+				"loadedAddresses": {
+					"writable": [deposit_address],
+					"readonly": [],
+				},
+			},
+			"transaction": {
+				"signatures": [SolSignature([1u8; 64])],
+				"message": {
+					"accountKeys": [SolAddress([1u8; 32]), SolAddress([2u8; 32])],
+				},
+			},
+		}))
+		.unwrap();
+
+		assert_eq!(transaction.balance_change(&deposit_address), Some(100_000));
+		assert_eq!(transaction.balance_change(&SolAddress([1u8; 32])), Some(-105_500));
+		assert_eq!(transaction.balance_change(&SolAddress([4u8; 32])), None);
+	}
+
+	#[test]
+	fn token_balance_change_of_new_token_account() {
+		let owner = SolAddress([2u8; 32]);
+		let token_account = SolAddress([3u8; 32]);
+		let mint = SolAddress([9u8; 32]);
+		let transaction: ConfirmedTransaction = serde_json::from_value(json!({
+			"slot": 100,
+			"meta": {
+				"err": null,
+				"fee": 5000,
+				"preBalances": [1_000_000, 0, 0],
+				"postBalances": [995_000, 0, 0],
+				"preTokenBalances": [
+					{ "accountIndex": 1, "mint": mint, "uiTokenAmount": { "amount": "2500000" } },
+				],
+				"postTokenBalances": [
+					{ "accountIndex": 1, "mint": mint, "uiTokenAmount": { "amount": "1500000" } },
+					{ "accountIndex": 2, "mint": mint, "uiTokenAmount": { "amount": "1000000" } },
+				],
+			},
+			"transaction": {
+				"signatures": [SolSignature([1u8; 64])],
+				"message": {
+					"accountKeys": [SolAddress([1u8; 32]), owner, token_account],
+				},
+			},
+		}))
+		.unwrap();
+
+		assert_eq!(transaction.token_balance_change(&token_account, &mint), Some(1_000_000));
+		assert_eq!(transaction.token_balance_change(&owner, &mint), Some(-1_000_000));
+		assert_eq!(transaction.token_balance_change(&token_account, &SolAddress([8u8; 32])), None);
+		assert_eq!(transaction.token_balance_change(&SolAddress([1u8; 32]), &mint), None);
+	}
+
+	#[test]
+	fn failed_transactions_have_no_balance_change() {
+		let transaction: ConfirmedTransaction = serde_json::from_value(json!({
+			"slot": 100,
+			"meta": {
+				"err": { "InstructionError": [0, "InvalidAccountData"] },
+				"fee": 5000,
+				"preBalances": [1_000_000, 0],
+				"postBalances": [995_000, 0],
+			},
+			"transaction": {
+				"signatures": [SolSignature([1u8; 64])],
+				"message": {
+					"accountKeys": [SolAddress([1u8; 32]), SolAddress([2u8; 32])],
+				},
+			},
+		}))
+		.unwrap();
+
+		assert_eq!(transaction.balance_change(&SolAddress([1u8; 32])), None);
+	}
+
+	#[test]
+	fn extracts_signature_from_serialized_transaction() {
+		let mut transaction_bytes = vec![1u8];
+		transaction_bytes.extend([7u8; 64]);
+		transaction_bytes.extend([0u8; 100]);
+
+		assert_eq!(transaction_signature(&transaction_bytes).unwrap(), SolSignature([7u8; 64]));
+		assert!(transaction_signature(&[0u8; 65]).is_err());
+		assert!(transaction_signature(&[1u8; 10]).is_err());
+	}
+
+	#[test]
+	fn extracts_durable_nonce_from_nonce_account_data() {
+		let mut data = vec![];
+		data.extend(1u32.to_le_bytes());
+		data.extend(1u32.to_le_bytes());
+		data.extend([2u8; 32]);
+		data.extend([3u8; 32]);
+		data.extend(5000u64.to_le_bytes());
+
+		assert_eq!(durable_nonce_from_account_data(&data).unwrap(), SolHash([3u8; 32]));
+		assert!(durable_nonce_from_account_data(&data[..72]).is_err());
+
+		// Uninitialized
+		data[4..8].copy_from_slice(&0u32.to_le_bytes());
+		assert!(durable_nonce_from_account_data(&data).is_err());
+	}
+
+	#[tokio::test]
+	#[ignore = "requires local node, useful for manual testing"]
+	async fn test_sol_async() {
+		let client = SolRpcClient::new("http://localhost:8899".into(), None).unwrap().await;
+
+		let slot = client.get_finalized_slot().await.unwrap();
+		println!("finalized slot: {slot}");
+
+		let fees = client.get_recent_prioritization_fees(vec![]).await.unwrap();
+		println!("prioritization fees: {fees:?}");
+	}
+}
@@ -8,7 +8,7 @@ pub mod subxt_state_chain_config;
 
 use async_trait::async_trait;
 
-use anyhow::{anyhow, bail, Context, Result};
+use anyhow::{bail, Context, Result};
 use cf_primitives::{AccountRole, SemVer};
 use futures::{StreamExt, TryStreamExt};
 
@@ -16,7 +16,7 @@ use cf_primitives::CfeCompatibility;
 use futures_core::future::BoxFuture;
 use futures_util::FutureExt;
 use jsonrpsee::core::RpcResult;
-use sp_core::{Pair, H256};
+use sp_core::H256;
 use state_chain_runtime::AccountId;
 use std::{pin::Pin, sync::Arc, time::Duration};
 use subxt::{backend::rpc::RpcClient, config::DefaultExtrinsicParamsBuilder};
@@ -26,7 +26,7 @@ use tracing::{info, warn};
 
 use utilities::{
 	cached_stream::{CachedStream, MakeCachedStream},
-	loop_select, make_periodic_tick, read_clean_and_decode_hex_str_file, spmc,
+	loop_select, make_periodic_tick, spmc,
 	task_scope::{Scope, UnwrapOrCancel},
 	try_cached_stream::{MakeTryCachedStream, TryCachedStream},
 };
@@ -42,7 +42,10 @@ use self::{
 	base_rpc_api::BaseRpcClient,
 	chain_api::ChainApi,
 	extrinsic_api::{
-		signed::{signer, SignedExtrinsicApi, WaitFor, WaitForResult},
+		signed::{
+			signer::{self, SigningKeySource},
+			SignedExtrinsicApi, WaitFor, WaitForResult,
+		},
 		unsigned,
 	},
 	storage_api::{BlockCompatibility, StorageApi},
@@ -122,7 +125,7 @@ impl StateChainClient<extrinsic_api::signed::SignedExtrinsicClient> {
 	pub async fn connect_with_account<'a>(
 		scope: &Scope<'a, anyhow::Error>,
 		ws_endpoint: &str,
-		signing_key_file: &std::path::Path,
+		signing_key: SigningKeySource,
 		required_role: AccountRole,
 		wait_for_required_role: bool,
 		submit_cfe_version: bool,
@@ -132,7 +135,7 @@ impl StateChainClient<extrinsic_api::signed::SignedExtrinsicClient> {
 		Self::new_with_account(
 			scope,
 			DefaultRpcClient::connect(ws_endpoint).await?.into(),
-			signing_key_file,
+			signing_key,
 			required_role,
 			wait_for_required_role,
 			submit_cfe_version,
@@ -140,6 +143,32 @@ impl StateChainClient<extrinsic_api::signed::SignedExtrinsicClient> {
 		)
 		.await
 	}
+
+	/// Connects using a scoped key of `account_id`. All extrinsics are submitted on behalf of
+	/// `account_id`, which must have the required role.
+	pub async fn connect_with_scoped_key<'a>(
+		scope: &Scope<'a, anyhow::Error>,
+		ws_endpoint: &str,
+		signing_key: SigningKeySource,
+		account_id: AccountId,
+		required_role: AccountRole,
+	) -> Result<(impl StreamApi<FINALIZED> + Clone, impl StreamApi<UNFINALIZED> + Clone, Arc<Self>)>
+	{
+		Self::new(
+			scope,
+			DefaultRpcClient::connect(ws_endpoint).await?.into(),
+			SignedExtrinsicClientBuilder {
+				nonce_and_signer: None,
+				signing_key,
+				scoped_key_of: Some(account_id),
+				required_role,
+				wait_for_required_role: false,
+				submit_cfe_version: false,
+			},
+			None,
+		)
+		.await
+	}
 }
 
 impl StateChainClient<()> {
@@ -158,7 +187,7 @@ impl<BaseRpcClient: base_rpc_api::BaseRpcApi + Send + Sync + 'static>
 	pub async fn new_with_account<'a>(
 		scope: &Scope<'a, anyhow::Error>,
 		base_rpc_client: Arc<BaseRpcClient>,
-		signing_key_file: &std::path::Path,
+		signing_key: SigningKeySource,
 		required_role: AccountRole,
 		wait_for_required_role: bool,
 		submit_cfe_version: bool,
@@ -170,9 +199,8 @@ impl<BaseRpcClient: base_rpc_api::BaseRpcApi + Send + Sync + 'static>
 			base_rpc_client,
 			SignedExtrinsicClientBuilder {
 				nonce_and_signer: None,
-				signing_key_file: signing_key_file.to_owned(), /* I have to take a clone here
-				                                                * because of a compiler issue it
-				                                                * seems */
+				signing_key,
+				scoped_key_of: None,
 				required_role,
 				wait_for_required_role,
 				submit_cfe_version,
@@ -656,9 +684,10 @@ impl SignedExtrinsicClientBuilderTrait for () {
 }
 
 struct SignedExtrinsicClientBuilder {
-	nonce_and_signer:
-		Option<(state_chain_runtime::Nonce, signer::PairSigner<sp_core::sr25519::Pair>)>,
-	signing_key_file: std::path::PathBuf,
+	nonce_and_signer: Option<(state_chain_runtime::Nonce, signer::PairSigner)>,
+	signing_key: SigningKeySource,
+	/// The account the signing key is a scoped key of, if any.
+	scoped_key_of: Option<AccountId>,
 	required_role: AccountRole,
 	wait_for_required_role: bool,
 	submit_cfe_version: bool,
@@ -688,15 +717,27 @@ impl SignedExtrinsicClientBuilderTrait for SignedExtrinsicClientBuilder {
 			"This function should be run exactly once successfully before build is called"
 		);
 
-		let pair = sp_core::sr25519::Pair::from_seed(&read_clean_and_decode_hex_str_file(
-			&self.signing_key_file,
-			"Signing Key",
-			|str| {
-				<[u8; 32]>::try_from(hex::decode(str)?)
-					.map_err(|e| anyhow!("Failed to decode signing key: Wrong length. {e:?}"))
+		let signer = self.signing_key.signer().await?;
+		let signer = match &self.scoped_key_of {
+			Some(account_id) => {
+				let block_hash = finalized_block_stream.cache().hash;
+				if base_rpc_client
+					.storage_map_entry::<pallet_cf_account_roles::ScopedKeyAccount<state_chain_runtime::Runtime>>(
+						block_hash,
+						&signer.account_id,
+					)
+					.await?
+					.as_ref() != Some(account_id)
+				{
+					bail!("The signing key {} is not registered as a scoped key of Chainflip account {account_id}", signer.account_id);
+				}
+				signer.with_scoped_key_of(account_id.clone())
 			},
-		)?);
-		let signer = signer::PairSigner::<sp_core::sr25519::Pair>::new(pair.clone());
+			None => signer,
+		};
+		// The account the role is checked for. When using a scoped key, this is the account the
+		// key acts on behalf of.
+		let account_id = signer.submitting_account_id();
 
 		let account_nonce = {
 			loop {
@@ -705,7 +746,7 @@ impl SignedExtrinsicClientBuilderTrait for SignedExtrinsicClientBuilder {
 				match base_rpc_client
 					.storage_map_entry::<pallet_cf_account_roles::AccountRoles<state_chain_runtime::Runtime>>(
 						block_hash,
-						&signer.account_id,
+						&account_id,
 					)
 					.await?
 				{
@@ -715,15 +756,15 @@ impl SignedExtrinsicClientBuilderTrait for SignedExtrinsicClientBuilder {
 						{
 							break
 						} else if self.wait_for_required_role && role == AccountRole::Unregistered {
-							warn!("Your Chainflip account {} does not have an assigned account role. WAITING for the account role to be set to '{:?}' at block: {block_hash}", signer.account_id, self.required_role);
+							warn!("Your Chainflip account {} does not have an assigned account role. WAITING for the account role to be set to '{:?}' at block: {block_hash}", account_id, self.required_role);
 						} else {
-							bail!("Your Chainflip account {} has the wrong account role '{role:?}'. The '{:?}' account role is required", signer.account_id, self.required_role);
+							bail!("Your Chainflip account {} has the wrong account role '{role:?}'. The '{:?}' account role is required", account_id, self.required_role);
 						},
 					None =>
 						if self.wait_for_required_role {
-							warn!("Your Chainflip account {} is not funded. Note, it may take some time for your funds to be detected. WAITING for your account to be funded at block: {block_hash}", signer.account_id);
+							warn!("Your Chainflip account {} is not funded. Note, it may take some time for your funds to be detected. WAITING for your account to be funded at block: {block_hash}", account_id);
 						} else {
-							bail!("Your Chainflip account {} is not funded", signer.account_id);
+							bail!("Your Chainflip account {} is not funded", account_id);
 						},
 				}
 
@@ -743,7 +784,6 @@ impl SignedExtrinsicClientBuilderTrait for SignedExtrinsicClientBuilder {
 
 		if self.submit_cfe_version {
 			use crate::state_chain_observer::client::subxt_state_chain_config::StateChainConfig;
-			use subxt::tx::Signer;
 
 			let rpc_client = RpcClient::new(SubxtInterface(base_rpc_client.clone()));
 
@@ -751,24 +791,7 @@ impl SignedExtrinsicClientBuilderTrait for SignedExtrinsicClientBuilder {
 				rpc_client.clone(),
 			)
 			.await?;
-			let subxt_signer = {
-				struct SubxtSignerInterface<T>(subxt::utils::AccountId32, T);
-				impl subxt::tx::Signer<StateChainConfig> for SubxtSignerInterface<sp_core::sr25519::Pair> {
-					fn account_id(&self) -> <StateChainConfig as subxt::Config>::AccountId {
-						self.0.clone()
-					}
-
-					fn address(&self) -> <StateChainConfig as subxt::Config>::Address {
-						subxt::utils::MultiAddress::Id(self.0.clone())
-					}
-
-					fn sign(&self, bytes: &[u8]) -> <StateChainConfig as subxt::Config>::Signature {
-						use sp_core::Pair;
-						state_chain_runtime::Signature::Sr25519(self.1.sign(bytes))
-					}
-				}
-				SubxtSignerInterface(subxt::utils::AccountId32(*signer.account_id.as_ref()), pair)
-			};
+			let account_id = subxt::utils::AccountId32(*signer.account_id.as_ref());
 
 			let recorded_version = <SemVer as codec::Decode>::decode(
 				&mut subxt_client
@@ -778,7 +801,7 @@ impl SignedExtrinsicClientBuilderTrait for SignedExtrinsicClientBuilder {
 					.fetch_or_default(&subxt::storage::dynamic(
 						"Validator",
 						"NodeCFEVersion",
-						vec![subxt_signer.account_id()],
+						vec![account_id.clone()],
 					))
 					.await?
 					.encoded(),
@@ -802,41 +825,46 @@ impl SignedExtrinsicClientBuilderTrait for SignedExtrinsicClientBuilder {
 				// so we use a timeout to ensure we can recover:
 				tokio::time::timeout(CFE_VERSION_SUBMIT_TIMEOUT, async {
 					let current_nonce = rpc_client
-						.request::<u32>(
-							"system_accountNextIndex",
-							subxt::rpc_params![&subxt_signer.account_id()],
-						)
+						.request::<u32>("system_accountNextIndex", subxt::rpc_params![&account_id])
 						.await?;
 
-					subxt_client
-						.tx()
-						.create_signed_with_nonce(
-							&subxt::dynamic::tx(
-								"Validator",
-								"cfe_version",
-								vec![(
-									"new_version",
-									vec![
-										("major", CFE_VERSION.major),
-										("minor", CFE_VERSION.minor),
-										("patch", CFE_VERSION.patch),
-									],
-								)],
-							),
-							&subxt_signer,
-							current_nonce.into(),
-							DefaultExtrinsicParamsBuilder::new()
-								.mortal_unchecked(
-									block_number.into(),
-									block_hash,
-									SIGNED_EXTRINSIC_LIFETIME.into(),
-								)
-								.build(),
-						)?
-						.submit_and_watch()
-						.await?
-						.wait_for_finalized()
-						.await
+					// Sign separately rather than with a subxt signer, so that signing failures are
+					// reported as errors.
+					let partial_extrinsic = subxt_client.tx().create_partial_signed_with_nonce(
+						&subxt::dynamic::tx(
+							"Validator",
+							"cfe_version",
+							vec![(
+								"new_version",
+								vec![
+									("major", CFE_VERSION.major),
+									("minor", CFE_VERSION.minor),
+									("patch", CFE_VERSION.patch),
+								],
+							)],
+						),
+						current_nonce.into(),
+						DefaultExtrinsicParamsBuilder::new()
+							.mortal_unchecked(
+								block_number.into(),
+								block_hash,
+								SIGNED_EXTRINSIC_LIFETIME.into(),
+							)
+							.build(),
+					)?;
+					let signature = signer.sign(&partial_extrinsic.signer_payload()).await?;
+
+					Ok::<_, anyhow::Error>(
+						partial_extrinsic
+							.sign_with_address_and_signature(
+								&subxt::utils::MultiAddress::Id(account_id.clone()),
+								&signature,
+							)
+							.submit_and_watch()
+							.await?
+							.wait_for_finalized()
+							.await?,
+					)
 				})
 				.await
 				.map_err(|_| anyhow::anyhow!("Timed out trying to submit CFE version"))??;
@@ -176,7 +176,7 @@ impl SignedExtrinsicClient {
 		scope: &Scope<'a, anyhow::Error>,
 		base_rpc_client: Arc<BaseRpcClient>,
 		account_nonce: Nonce,
-		signer: signer::PairSigner<sp_core::sr25519::Pair>,
+		signer: signer::PairSigner,
 		genesis_hash: H256,
 		state_chain_stream: &mut BlockStream,
 	) -> Result<Self> {
@@ -186,7 +186,7 @@ impl SignedExtrinsicClient {
 		let (dry_run_sender, mut dry_run_receiver) = mpsc::channel(REQUEST_BUFFER);
 
 		Ok(Self {
-			account_id: signer.account_id.clone(),
+			account_id: signer.submitting_account_id(),
 			request_sender,
 			dry_run_sender,
 			_task_handle: scope.spawn_with_handle({
@@ -1,5 +1,7 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
 use codec::Encode;
-use sp_core::Pair;
+use sp_core::{sr25519, Pair};
 use sp_runtime::{
 	generic::Era,
 	traits::{IdentifyAccount, Verify},
@@ -7,28 +9,110 @@ use sp_runtime::{
 };
 use sp_version::RuntimeVersion;
 use state_chain_runtime::{AccountId, Signature};
+use std::{
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+use utilities::read_clean_and_decode_hex_str_file;
+
+pub mod remote;
+
+/// Produces the sr25519 signatures for State Chain extrinsics.
+#[async_trait]
+pub trait ExtrinsicSigner: Send + Sync {
+	fn public(&self) -> sr25519::Public;
+
+	/// Signs the (already hashed, if required) extrinsic payload.
+	async fn sign(&self, payload: &[u8]) -> Result<sr25519::Signature>;
+}
+
+#[async_trait]
+impl ExtrinsicSigner for sr25519::Pair {
+	fn public(&self) -> sr25519::Public {
+		Pair::public(self)
+	}
 
-/// A wrapper around a substrate [`Pair`] that can be used for signing.
-#[derive(Clone, Debug)]
-pub struct PairSigner<P: Pair> {
+	async fn sign(&self, payload: &[u8]) -> Result<sr25519::Signature> {
+		Ok(Pair::sign(self, payload))
+	}
+}
+
+/// Where the key used to sign State Chain extrinsics is held.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SigningKeySource {
+	/// A hex encoded sr25519 secret key, stored in a local file.
+	File(PathBuf),
+	/// A [remote] signer, listening on a local unix socket.
+	Remote(PathBuf),
+}
+
+impl SigningKeySource {
+	/// Loads the key file, or connects to the remote signer.
+	pub async fn signer(&self) -> Result<PairSigner> {
+		Ok(match self {
+			SigningKeySource::File(signing_key_file) =>
+				PairSigner::new(read_signing_key_file(signing_key_file)?),
+			SigningKeySource::Remote(socket_path) =>
+				PairSigner::new(remote::RemoteSigner::connect(socket_path).await?),
+		})
+	}
+}
+
+/// Reads a hex encoded sr25519 secret key from a file.
+pub fn read_signing_key_file(signing_key_file: &Path) -> Result<sr25519::Pair> {
+	Ok(sr25519::Pair::from_seed(&read_clean_and_decode_hex_str_file(
+		signing_key_file,
+		"Signing Key",
+		|str| {
+			<[u8; 32]>::try_from(hex::decode(str)?)
+				.map_err(|e| anyhow!("Failed to decode signing key: Wrong length. {e:?}"))
+		},
+	)?))
+}
+
+/// A wrapper around an [`ExtrinsicSigner`], such as a substrate [`Pair`], that can be used for
+/// signing extrinsics.
+#[derive(Clone)]
+pub struct PairSigner {
 	pub account_id: AccountId,
-	signer: P,
+	/// If set, the signer is a scoped key and all calls are submitted on behalf of this account.
+	pub scoped_key_of: Option<AccountId>,
+	signer: Arc<dyn ExtrinsicSigner>,
 }
 
-impl<P> PairSigner<P>
-where
-	Signature: From<P::Signature>,
-	<Signature as Verify>::Signer: From<P::Public> + IdentifyAccount<AccountId = AccountId>,
-	P: Pair,
-{
-	/// Creates a new [`Signer`] from a [`Pair`].
-	pub fn new(signer: P) -> Self {
+impl std::fmt::Debug for PairSigner {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("PairSigner")
+			.field("account_id", &self.account_id)
+			.field("scoped_key_of", &self.scoped_key_of)
+			.finish_non_exhaustive()
+	}
+}
+
+impl PairSigner {
+	/// Creates a new [`PairSigner`] from an [`ExtrinsicSigner`].
+	pub fn new(signer: impl ExtrinsicSigner + 'static) -> Self {
 		let account_id = <Signature as Verify>::Signer::from(signer.public()).into_account();
-		Self { account_id, signer }
+		Self { account_id, scoped_key_of: None, signer: Arc::new(signer) }
+	}
+
+	/// Signs an arbitrary payload with the underlying key.
+	pub async fn sign(&self, payload: &[u8]) -> Result<Signature> {
+		self.signer.sign(payload).await.map(Into::into)
+	}
+
+	/// Use the signer as a scoped key of `account_id`, submitting all calls on its behalf.
+	pub fn with_scoped_key_of(self, account_id: AccountId) -> Self {
+		Self { scoped_key_of: Some(account_id), ..self }
+	}
+
+	/// The account on whose behalf calls are submitted.
+	pub fn submitting_account_id(&self) -> AccountId {
+		self.scoped_key_of.clone().unwrap_or_else(|| self.account_id.clone())
 	}
 
 	/// Returns a signed extrinsic that matches the provided call
-	pub fn new_signed_extrinsic(
+	pub async fn new_signed_extrinsic(
 		&self,
 		call: state_chain_runtime::RuntimeCall,
 		runtime_version: &RuntimeVersion,
@@ -37,12 +121,21 @@ where
 		current_block_number: state_chain_runtime::BlockNumber,
 		lifetime: state_chain_runtime::BlockNumber,
 		nonce: state_chain_runtime::Nonce,
-	) -> (
+	) -> Result<(
 		state_chain_runtime::UncheckedExtrinsic,
 		std::ops::RangeTo<state_chain_runtime::BlockNumber>,
-	) {
+	)> {
 		assert!(lifetime <= state_chain_runtime::BlockHashCount::get());
 
+		let call = if self.scoped_key_of.is_some() {
+			pallet_cf_account_roles::Call::<state_chain_runtime::Runtime>::call_as_account {
+				call: Box::new(call),
+			}
+			.into()
+		} else {
+			call
+		};
+
 		let era = Era::mortal(lifetime as u64, current_block_number as u64);
 
 		let lifetime = ..era.death(current_block_number as u64) as state_chain_runtime::BlockNumber;
@@ -75,9 +168,10 @@ where
 			extra.clone(),
 			additional_signed,
 		);
-		let signature = signed_payload.using_encoded(|bytes| self.signer.sign(bytes).into());
+		// The payload is hashed first if it is longer than 256 bytes.
+		let signature = self.sign(&signed_payload.using_encoded(|bytes| bytes.to_vec())).await?;
 
-		(
+		Ok((
 			state_chain_runtime::UncheckedExtrinsic::new_signed(
 				call,
 				MultiAddress::Id(self.account_id.clone()),
@@ -85,6 +179,6 @@ where
 				extra,
 			),
 			lifetime,
-		)
+		))
 	}
 }
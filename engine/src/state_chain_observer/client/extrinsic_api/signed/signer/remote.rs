@@ -0,0 +1,258 @@
+//! Delegates signing of State Chain extrinsics to an external process, so that the signing key
+//! doesn't need to be held by the process submitting the extrinsics.
+//!
+//! The signer listens on a local unix socket. Each message is a single line of JSON: the client
+//! writes a [SignerRequest] and the signer replies with a [SignerResponse]. For example:
+//!
+//! ```text
+//! > {"method":"public_key"}
+//! < {"public_key":"0x8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48"}
+//! > {"method":"sign","payload":"0x0a00..."}
+//! < {"signature":"0x6a8b..."}
+//! ```
+//!
+//! Payloads are signed as-is with sr25519, using the substrate signing context. Payloads longer
+//! than 256 bytes have already been hashed by the client, as required by the State Chain.
+//!
+//! Any process that implements this protocol, for example a wrapper around an HSM or a KMS, can be
+//! used as a signer. [run_signer] is a reference implementation that signs with a local key.
+
+use super::ExtrinsicSigner;
+use anyhow::{anyhow, bail, Context, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sp_core::{sr25519, Bytes, Pair};
+use std::{
+	os::unix::fs::{FileTypeExt, PermissionsExt},
+	path::{Path, PathBuf},
+	time::Duration,
+};
+use tokio::{
+	io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+	net::{UnixListener, UnixStream},
+};
+use tracing::{info, warn};
+use utilities::task_scope::Scope;
+
+/// How long to wait for the signer to respond. Signers backed by hardware might need some time.
+const SIGNER_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum SignerRequest {
+	/// Returns the public key of the signing key.
+	PublicKey,
+	/// Signs the payload.
+	Sign { payload: Bytes },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SignerResponse {
+	PublicKey(Bytes),
+	Signature(Bytes),
+	Error(String),
+}
+
+/// Signs by sending requests to an external signer over a unix socket.
+///
+/// A new connection is opened for every request, so the signer can be restarted without
+/// restarting the client.
+pub struct RemoteSigner {
+	socket_path: PathBuf,
+	public: sr25519::Public,
+}
+
+impl RemoteSigner {
+	/// Connects to the signer listening at `socket_path` and fetches its public key.
+	pub async fn connect(socket_path: &Path) -> Result<Self> {
+		let public = match request(socket_path, &SignerRequest::PublicKey).await? {
+			SignerResponse::PublicKey(public) => sr25519::Public::try_from(&public[..])
+				.map_err(|_| anyhow!("The remote signer returned an invalid public key"))?,
+			response => bail!("Unexpected response from the remote signer: {response:?}"),
+		};
+		info!("Using remote signer at {} for public key {public}", socket_path.display());
+		Ok(Self { socket_path: socket_path.to_owned(), public })
+	}
+}
+
+#[async_trait]
+impl ExtrinsicSigner for RemoteSigner {
+	fn public(&self) -> sr25519::Public {
+		self.public
+	}
+
+	async fn sign(&self, payload: &[u8]) -> Result<sr25519::Signature> {
+		match request(&self.socket_path, &SignerRequest::Sign { payload: payload.to_vec().into() })
+			.await?
+		{
+			SignerResponse::Signature(signature) => {
+				let signature = sr25519::Signature::try_from(&signature[..])
+					.map_err(|_| anyhow!("The remote signer returned an invalid signature"))?;
+				// Catch a misconfigured signer here rather than having the extrinsic rejected.
+				if !sr25519::Pair::verify(&signature, payload, &self.public) {
+					bail!("The remote signer signed with a key other than {}", self.public);
+				}
+				Ok(signature)
+			},
+			SignerResponse::Error(error) => bail!("The remote signer failed to sign: {error}"),
+			response => bail!("Unexpected response from the remote signer: {response:?}"),
+		}
+	}
+}
+
+async fn request(socket_path: &Path, request: &SignerRequest) -> Result<SignerResponse> {
+	tokio::time::timeout(SIGNER_TIMEOUT, async {
+		let mut stream = UnixStream::connect(socket_path).await.with_context(|| {
+			format!("Failed to connect to the remote signer at {}", socket_path.display())
+		})?;
+
+		let mut message = serde_json::to_vec(request)?;
+		message.push(b'\n');
+		stream.write_all(&message).await?;
+
+		let mut response = String::new();
+		BufReader::new(stream)
+			.read_line(&mut response)
+			.await
+			.context("Failed to read the response of the remote signer")?;
+		serde_json::from_str(&response)
+			.context("Failed to decode the response of the remote signer")
+	})
+	.await
+	.map_err(|_| anyhow!("The remote signer did not respond within {SIGNER_TIMEOUT:?}"))?
+}
+
+/// Reference implementation of a remote signer: listens on `socket_path` and signs every request
+/// with `pair`. Runs until an error occurs while accepting connections.
+///
+/// The socket is only accessible to the user running the signer, since anyone who can connect to
+/// it can sign with the key.
+pub async fn run_signer(
+	scope: &Scope<'_, anyhow::Error>,
+	socket_path: &Path,
+	pair: sr25519::Pair,
+) -> Result<()> {
+	remove_stale_socket(socket_path).await?;
+	let listener = UnixListener::bind(socket_path).with_context(|| {
+		format!("Failed to listen for signing requests at {}", socket_path.display())
+	})?;
+	std::fs::set_permissions(socket_path, std::fs::Permissions::from_mode(0o600))
+		.with_context(|| format!("Failed to restrict access to {}", socket_path.display()))?;
+	info!("Listening for signing requests for {} at {}", pair.public(), socket_path.display());
+
+	loop {
+		let (stream, _) = listener.accept().await?;
+		let pair = pair.clone();
+		scope.spawn(async move {
+			// A misbehaving client shouldn't stop the signer.
+			if let Err(error) = handle_connection(stream, &pair).await {
+				warn!("Error while handling signing requests: {error:#}");
+			}
+			Ok(())
+		});
+	}
+}
+
+/// Removes the socket left behind by a signer that didn't shut down cleanly. Fails if another
+/// signer is still listening on it, or if the path isn't a socket.
+async fn remove_stale_socket(socket_path: &Path) -> Result<()> {
+	match std::fs::symlink_metadata(socket_path) {
+		Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+		Err(error) =>
+			Err(error).with_context(|| format!("Failed to read {}", socket_path.display())),
+		Ok(metadata) => {
+			if !metadata.file_type().is_socket() {
+				bail!("{} already exists and is not a socket", socket_path.display());
+			}
+			if UnixStream::connect(socket_path).await.is_ok() {
+				bail!("Another signer is already listening at {}", socket_path.display());
+			}
+			warn!("Removing the stale socket at {}", socket_path.display());
+			std::fs::remove_file(socket_path)
+				.with_context(|| format!("Failed to remove {}", socket_path.display()))
+		},
+	}
+}
+
+async fn handle_connection(stream: UnixStream, pair: &sr25519::Pair) -> Result<()> {
+	let (reader, mut writer) = stream.into_split();
+	let mut lines = BufReader::new(reader).lines();
+
+	while let Some(line) = lines.next_line().await? {
+		let response = match serde_json::from_str(&line) {
+			Ok(SignerRequest::PublicKey) =>
+				SignerResponse::PublicKey(pair.public().0.to_vec().into()),
+			Ok(SignerRequest::Sign { payload }) =>
+				SignerResponse::Signature(pair.sign(&payload).0.to_vec().into()),
+			Err(error) => SignerResponse::Error(format!("Invalid request: {error}")),
+		};
+		let mut message = serde_json::to_vec(&response)?;
+		message.push(b'\n');
+		writer.write_all(&message).await?;
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::FutureExt;
+	use utilities::task_scope::task_scope;
+
+	#[test]
+	fn request_encoding() {
+		assert_eq!(
+			serde_json::to_string(&SignerRequest::PublicKey).unwrap(),
+			r#"{"method":"public_key"}"#
+		);
+		assert_eq!(
+			serde_json::to_string(&SignerRequest::Sign { payload: vec![1, 2, 3].into() }).unwrap(),
+			r#"{"method":"sign","payload":"0x010203"}"#
+		);
+		assert_eq!(
+			serde_json::to_string(&SignerResponse::Signature(vec![0xab].into())).unwrap(),
+			r#"{"signature":"0xab"}"#
+		);
+	}
+
+	#[tokio::test]
+	async fn remote_signer_signs_with_the_signer_key() {
+		let directory = tempfile::tempdir().unwrap();
+		let socket_path = directory.path().join("signer.sock");
+		let pair = sr25519::Pair::generate().0;
+
+		// A socket left behind by a signer that didn't shut down cleanly.
+		drop(std::os::unix::net::UnixListener::bind(&socket_path).unwrap());
+
+		task_scope(|scope| {
+			async move {
+				let client = async {
+					// Wait for the signer to start listening.
+					while UnixStream::connect(&socket_path).await.is_err() {
+						tokio::time::sleep(Duration::from_millis(10)).await;
+					}
+					assert_eq!(
+						std::fs::metadata(&socket_path)?.permissions().mode() & 0o777,
+						0o600
+					);
+					let signer = RemoteSigner::connect(&socket_path).await?;
+					assert_eq!(ExtrinsicSigner::public(&signer), pair.public());
+
+					let signature = signer.sign(b"payload").await?;
+					assert!(sr25519::Pair::verify(&signature, b"payload", &pair.public()));
+					Ok::<_, anyhow::Error>(())
+				};
+
+				tokio::select! {
+					result = run_signer(scope, &socket_path, pair.clone()) => result,
+					result = client => result,
+				}
+			}
+			.boxed()
+		})
+		.await
+		.unwrap();
+	}
+}
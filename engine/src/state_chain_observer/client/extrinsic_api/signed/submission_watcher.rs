@@ -57,6 +57,8 @@ pub enum DryRunError {
 	InvalidTransaction(#[from] TransactionValidityError),
 	#[error("The transaction failed: {0}")]
 	Dispatch(#[from] DispatchError),
+	#[error("Unable to sign the transaction: {0:#}")]
+	Signing(anyhow::Error),
 }
 
 pub type ExtrinsicDetails =
@@ -89,6 +91,8 @@ pub struct Request {
 	next_submission_id: SubmissionID,
 	pending_submissions: BTreeMap<SubmissionID, Nonce>,
 	strictly_one_submission: bool,
+	// Sent the hash of the first submission, for requests with strictly one submission.
+	hash_sender: Option<oneshot::Sender<H256>>,
 	resubmit_window: std::ops::RangeToInclusive<cf_primitives::BlockNumber>,
 	call: state_chain_runtime::RuntimeCall,
 	until_in_block_sender: Option<oneshot::Sender<InBlockResult>>,
@@ -120,7 +124,7 @@ pub struct SubmissionWatcher<
 	#[allow(clippy::type_complexity)]
 	submission_status_futures:
 		FutureMap<(RequestID, SubmissionID), task_scope::ScopedJoinHandle<Option<(H256, H256)>>>,
-	signer: signer::PairSigner<sp_core::sr25519::Pair>,
+	signer: signer::PairSigner,
 	finalized_nonce: Nonce,
 	finalized_block_hash: state_chain_runtime::Hash,
 	finalized_block_number: BlockNumber,
@@ -142,6 +146,7 @@ pub struct SubmissionWatcher<
 
 pub enum SubmissionLogicError {
 	NonceTooLow,
+	Signing(anyhow::Error),
 }
 
 impl<'a, 'env, BaseRpcClient: base_rpc_api::BaseRpcApi + Send + Sync + 'static>
@@ -149,7 +154,7 @@ impl<'a, 'env, BaseRpcClient: base_rpc_api::BaseRpcApi + Send + Sync + 'static>
 {
 	pub fn new(
 		scope: &'a Scope<'env, anyhow::Error>,
-		signer: signer::PairSigner<sp_core::sr25519::Pair>,
+		signer: signer::PairSigner,
 		finalized_nonce: Nonce,
 		finalized_block_hash: state_chain_runtime::Hash,
 		finalized_block_number: BlockNumber,
@@ -189,15 +194,22 @@ impl<'a, 'env, BaseRpcClient: base_rpc_api::BaseRpcApi + Send + Sync + 'static>
 		nonce: Nonce,
 	) -> Result<Result<H256, SubmissionLogicError>, anyhow::Error> {
 		loop {
-			let (signed_extrinsic, lifetime) = self.signer.new_signed_extrinsic(
-				request.call.clone(),
-				&self.runtime_version,
-				self.genesis_hash,
-				self.finalized_block_hash,
-				self.finalized_block_number,
-				self.extrinsic_lifetime,
-				nonce,
-			);
+			let (signed_extrinsic, lifetime) = match self
+				.signer
+				.new_signed_extrinsic(
+					request.call.clone(),
+					&self.runtime_version,
+					self.genesis_hash,
+					self.finalized_block_hash,
+					self.finalized_block_number,
+					self.extrinsic_lifetime,
+					nonce,
+				)
+				.await
+			{
+				Ok(signed_extrinsic) => signed_extrinsic,
+				Err(error) => break Ok(Err(SubmissionLogicError::Signing(error))),
+			};
 			assert!(lifetime.contains(&(self.finalized_block_number + 1)));
 
 			let tx_hash: H256 = {
@@ -232,6 +244,9 @@ impl<'a, 'env, BaseRpcClient: base_rpc_api::BaseRpcApi + Send + Sync + 'static>
 					);
 					info!(target: "state_chain_client", request_id = request.id, submission_id = request.next_submission_id, "Submission succeeded");
 					request.next_submission_id += 1;
+					if let Some(hash_sender) = request.hash_sender.take() {
+						let _result = hash_sender.send(tx_hash);
+					}
 					break Ok(Ok(tx_hash))
 				},
 				Err(rpc_err) => {
@@ -278,15 +293,22 @@ impl<'a, 'env, BaseRpcClient: base_rpc_api::BaseRpcApi + Send + Sync + 'static>
 		}
 	}
 
-	async fn submit_extrinsic(&mut self, request: &mut Request) -> Result<H256, anyhow::Error> {
-		Ok(loop {
+	async fn submit_extrinsic(&mut self, request: &mut Request) -> Result<(), anyhow::Error> {
+		loop {
 			let nonce =
 				self.base_rpc_client.next_account_nonce(self.signer.account_id.clone()).await?;
 			match self.submit_extrinsic_at_nonce(request, nonce).await? {
-				Ok(tx_hash) => break tx_hash,
+				Ok(_tx_hash) => break Ok(()),
 				Err(SubmissionLogicError::NonceTooLow) => {},
+				// The signer may only be unavailable for a while (eg. a remote signer that is
+				// restarting), so this only affects this request. As it has no pending submission,
+				// it is retried on the next finalized block.
+				Err(SubmissionLogicError::Signing(error)) => {
+					warn!(target: "state_chain_client", request_id = request.id, "Failed to sign the extrinsic: {error:#}");
+					break Ok(())
+				},
 			}
-		})
+		}
 	}
 
 	pub async fn dry_run_extrinsic(
@@ -305,15 +327,19 @@ impl<'a, 'env, BaseRpcClient: base_rpc_api::BaseRpcApi + Send + Sync + 'static>
 			self.base_rpc_client.runtime_version(Some(hash)),
 		)?;
 
-		let (signed_extrinsic, _) = self.signer.new_signed_extrinsic(
-			call.clone(),
-			&runtime_version,
-			self.genesis_hash,
-			self.finalized_block_hash,
-			self.finalized_block_number,
-			self.extrinsic_lifetime,
-			account_info.nonce,
-		);
+		let (signed_extrinsic, _) = self
+			.signer
+			.new_signed_extrinsic(
+				call.clone(),
+				&runtime_version,
+				self.genesis_hash,
+				self.finalized_block_hash,
+				self.finalized_block_number,
+				self.extrinsic_lifetime,
+				account_info.nonce,
+			)
+			.await
+			.map_err(DryRunError::Signing)?;
 
 		let dry_run_result: ApplyExtrinsicResult = Decode::decode(
 			&mut &*self
@@ -348,6 +374,10 @@ impl<'a, 'env, BaseRpcClient: base_rpc_api::BaseRpcApi + Send + Sync + 'static>
 						strategy,
 						RequestStrategy::StrictlyOneSubmission(_)
 					),
+					hash_sender: match strategy {
+						RequestStrategy::StrictlyOneSubmission(hash_sender) => Some(hash_sender),
+						RequestStrategy::AllowMultipleSubmissions => None,
+					},
 					resubmit_window: ..=(self.finalized_block_number + 1 + REQUEST_LIFETIME),
 					call,
 					until_in_block_sender: Some(until_in_block_sender),
@@ -355,11 +385,8 @@ impl<'a, 'env, BaseRpcClient: base_rpc_api::BaseRpcApi + Send + Sync + 'static>
 				},
 			)
 			.unwrap();
-		let tx_hash: H256 = self.submit_extrinsic(request).await?;
+		self.submit_extrinsic(request).await?;
 		info!(target: "state_chain_client", request_id = request.id, "New request: {:?}", request.call);
-		if let RequestStrategy::StrictlyOneSubmission(hash_sender) = strategy {
-			let _result = hash_sender.send(tx_hash);
-		};
 		Ok(())
 	}
 
@@ -627,11 +654,13 @@ impl<'a, 'env, BaseRpcClient: base_rpc_api::BaseRpcApi + Send + Sync + 'static>
 			});
 
 			// Remove any requests that have all their submission have expired and whose
-			// resubmission window has past.
+			// resubmission window has past. Requests that are still waiting for their only
+			// submission are kept until their resubmission window has past, as the caller is
+			// waiting for its hash.
 			for (_request_id, request) in requests.extract_if(|_request_id, request| {
 				request.pending_submissions.is_empty() &&
 					(!request.resubmit_window.contains(&(block.header.number + 1)) ||
-						request.strictly_one_submission)
+						(request.strictly_one_submission && request.hash_sender.is_none()))
 			}) {
 				info!(target: "state_chain_client", request_id = request.id, "Request has timed out.");
 				if let Some(until_in_block_sender) = request.until_in_block_sender {
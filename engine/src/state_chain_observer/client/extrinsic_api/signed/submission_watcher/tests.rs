@@ -1,6 +1,7 @@
 use cf_chains::{dot, ChainState};
 use futures_util::FutureExt;
 use jsonrpsee::core::client::{Subscription, SubscriptionKind};
+use sp_core::{crypto::AccountId32, storage::StorageData};
 use utilities::task_scope::task_scope;
 
 use crate::{
@@ -60,7 +61,136 @@ async fn should_update_version_on_bad_proof() {
 				))
 			});
 
-			let _watcher = new_watcher_and_submit_test_extrinsic(scope, mock_rpc_api).await;
+			let (_watcher, _request, result) = new_watcher_and_submit_test_extrinsic(
+				scope,
+				signer::PairSigner::new(<sp_core::sr25519::Pair as sp_core::Pair>::generate().0),
+				mock_rpc_api,
+			)
+			.await;
+			assert!(result.is_ok());
+
+			Ok(())
+		}
+		.boxed()
+	})
+	.await
+	.unwrap();
+}
+
+struct UnavailableSigner;
+
+#[async_trait::async_trait]
+impl signer::ExtrinsicSigner for UnavailableSigner {
+	fn public(&self) -> sp_core::sr25519::Public {
+		sp_core::sr25519::Public::from_raw([0; 32])
+	}
+
+	async fn sign(&self, _payload: &[u8]) -> Result<sp_core::sr25519::Signature> {
+		Err(anyhow!("The signer is unavailable"))
+	}
+}
+
+/// If signing fails, the request should be kept for a later retry rather than failing the watcher.
+#[tokio::test]
+async fn signing_failure_is_retried_later() {
+	task_scope(|scope| {
+		async {
+			let mut mock_rpc_api = MockBaseRpcApi::new();
+
+			mock_rpc_api.expect_next_account_nonce().return_once(move |_| Ok(1));
+			mock_rpc_api.expect_submit_and_watch_extrinsic().never();
+
+			let (_watcher, request, result) = new_watcher_and_submit_test_extrinsic(
+				scope,
+				signer::PairSigner::new(UnavailableSigner),
+				mock_rpc_api,
+			)
+			.await;
+			assert!(result.is_ok());
+			assert!(request.pending_submissions.is_empty());
+			assert_eq!(request.next_submission_id, 0);
+
+			Ok(())
+		}
+		.boxed()
+	})
+	.await
+	.unwrap();
+}
+
+/// A request whose only submission could never be signed should time out once its resubmission
+/// window has past, rather than being retried forever.
+#[tokio::test]
+async fn unsigned_request_times_out_after_its_resubmit_window() {
+	task_scope(|scope| {
+		async {
+			let account_key = frame_system::Account::<state_chain_runtime::Runtime>::hashed_key_for(
+				AccountId32::new([0; 32]),
+			);
+
+			let mut mock_rpc_api = MockBaseRpcApi::new();
+			mock_rpc_api.expect_next_account_nonce().return_once(move |_| Ok(1));
+			mock_rpc_api.expect_submit_and_watch_extrinsic().never();
+			mock_rpc_api.expect_block().return_once(|_| {
+				Ok(Some(state_chain_runtime::SignedBlock {
+					block: state_chain_runtime::Block {
+						header: state_chain_runtime::Header::new(
+							1,
+							Default::default(),
+							Default::default(),
+							Default::default(),
+							Default::default(),
+						),
+						extrinsics: vec![],
+					},
+					justifications: None,
+				}))
+			});
+			mock_rpc_api.expect_storage().returning(move |_, storage_key| {
+				Ok((storage_key.0 == account_key).then(|| {
+					StorageData(
+						frame_system::AccountInfo::<
+							Nonce,
+							<state_chain_runtime::Runtime as frame_system::Config>::AccountData,
+						> {
+							nonce: INITIAL_NONCE,
+							..Default::default()
+						}
+						.encode(),
+					)
+				}))
+			});
+
+			let (mut watcher, mut request, result) = new_watcher_and_submit_test_extrinsic(
+				scope,
+				signer::PairSigner::new(UnavailableSigner),
+				mock_rpc_api,
+			)
+			.await;
+			assert!(result.is_ok());
+
+			let (hash_sender, hash_receiver) = oneshot::channel();
+			let (until_in_block_sender, until_in_block_receiver) = oneshot::channel();
+			let (until_finalized_sender, until_finalized_receiver) = oneshot::channel();
+			request.strictly_one_submission = true;
+			request.hash_sender = Some(hash_sender);
+			request.until_in_block_sender = Some(until_in_block_sender);
+			request.until_finalized_sender = until_finalized_sender;
+			let mut requests = BTreeMap::from([(request.id, request)]);
+
+			// The resubmission window ends at block 1.
+			watcher.on_block_finalized(&mut requests, H256::default()).await.unwrap();
+
+			assert!(requests.is_empty());
+			assert!(hash_receiver.await.is_err());
+			assert!(matches!(
+				until_in_block_receiver.await,
+				Ok(Err(ExtrinsicError::Other(InBlockError::NotInBlock)))
+			));
+			assert!(matches!(
+				until_finalized_receiver.await,
+				Ok(Err(ExtrinsicError::Other(FinalizationError::NotFinalized)))
+			));
 
 			Ok(())
 		}
@@ -73,11 +203,12 @@ async fn should_update_version_on_bad_proof() {
 /// Create a new watcher and submit a dummy extrinsic.
 async fn new_watcher_and_submit_test_extrinsic<'a, 'env>(
 	scope: &'a Scope<'env, anyhow::Error>,
+	signer: signer::PairSigner,
 	mock_rpc_api: MockBaseRpcApi,
-) -> SubmissionWatcher<'a, 'env, MockBaseRpcApi> {
+) -> (SubmissionWatcher<'a, 'env, MockBaseRpcApi>, Request, Result<()>) {
 	let (mut watcher, _requests) = SubmissionWatcher::new(
 		scope,
-		signer::PairSigner::new(sp_core::Pair::generate().0),
+		signer,
 		INITIAL_NONCE,
 		H256::default(),
 		0,
@@ -108,13 +239,14 @@ async fn new_watcher_and_submit_test_extrinsic<'a, 'env>(
 		next_submission_id: 0,
 		pending_submissions: Default::default(),
 		strictly_one_submission: false,
+		hash_sender: None,
 		resubmit_window: ..=1,
 		call,
 		until_in_block_sender: Some(oneshot::channel().0),
 		until_finalized_sender: oneshot::channel().0,
 	};
 
-	let _result = watcher.submit_extrinsic(&mut request).await;
+	let result = watcher.submit_extrinsic(&mut request).await;
 
-	watcher
+	(watcher, request, result)
 }
@@ -14,7 +14,7 @@ type CfeEvent = pallet_cf_cfe_interface::CfeEvent<Runtime>;
 
 use sp_runtime::AccountId32;
 use state_chain_runtime::{
-	AccountId, BitcoinInstance, EvmInstance, PolkadotInstance, Runtime, RuntimeCall,
+	AccountId, BitcoinInstance, EvmInstance, PolkadotInstance, Runtime, RuntimeCall, SolanaInstance,
 };
 use std::{
 	collections::BTreeSet,
@@ -30,6 +30,8 @@ use crate::{
 	btc::retry_rpc::BtcRetryRpcApi,
 	dot::retry_rpc::DotRetryRpcApi,
 	evm::retry_rpc::EvmRetrySigningRpcApi,
+	health::HealthReporter,
+	sol::retry_rpc::SolRetryRpcApi,
 	state_chain_observer::client::{
 		extrinsic_api::{
 			signed::{SignedExtrinsicApi, UntilFinalized},
@@ -40,9 +42,12 @@ use crate::{
 	},
 };
 use multisig::{
-	bitcoin::BtcCryptoScheme, client::MultisigClientApi, eth::EvmCryptoScheme,
-	polkadot::PolkadotCryptoScheme, ChainSigning, CryptoScheme, KeyId,
-	SignatureToThresholdSignature,
+	bitcoin::BtcCryptoScheme,
+	client::{MultisigClientApi, SigningFailureReason},
+	ed25519::Ed25519CryptoScheme,
+	eth::EvmCryptoScheme,
+	polkadot::PolkadotCryptoScheme,
+	ChainSigning, CryptoScheme, KeyId, SignatureToThresholdSignature,
 };
 use utilities::task_scope::{task_scope, Scope};
 
@@ -157,7 +162,8 @@ async fn handle_signing_request<'a, StateChainClient, MultisigClient, C, I>(
 		<Runtime as pallet_cf_threshold_signature::Config<I>>::TargetChainCrypto,
 	>,
 {
-	if signers.contains(&state_chain_client.account_id()) {
+	let our_account_id = state_chain_client.account_id();
+	if signers.contains(&our_account_id) {
 		// We initiate signing outside of the spawn to avoid requesting ceremonies out of order
 		let signing_result_future =
 			multisig_client.initiate_signing(ceremony_id, signers, signing_info);
@@ -175,6 +181,20 @@ async fn handle_signing_request<'a, StateChainClient, MultisigClient, C, I>(
 						})
 						.await;
 				},
+				Err((_, SigningFailureReason::CeremonyQueueFull)) => {
+					// We declined to take part, so we are the one to blame. Reporting this straight
+					// away lets the ceremony be retried without waiting for it to time out.
+					warn!("Declined signing ceremony {ceremony_id}: too many ceremonies queued");
+					state_chain_client
+						.finalize_signed_extrinsic(pallet_cf_threshold_signature::Call::<
+							Runtime,
+							I,
+						>::report_signature_failed {
+							ceremony_id,
+							offenders: BTreeSet::from([our_account_id]),
+						})
+						.await;
+				},
 				Err((bad_account_ids, _reason)) => {
 					state_chain_client
 						.finalize_signed_extrinsic(pallet_cf_threshold_signature::Call::<
@@ -222,28 +242,36 @@ pub async fn start<
 	EvmRpc,
 	DotRpc,
 	BtcRpc,
+	SolRpc,
 	EthMultisigClient,
 	PolkadotMultisigClient,
 	BitcoinMultisigClient,
+	SolanaMultisigClient,
 >(
 	state_chain_client: Arc<StateChainClient>,
 	sc_block_stream: BlockStream,
 	eth_rpc: EvmRpc,
 	arb_rpc: EvmRpc,
 	dot_rpc: DotRpc,
+	hub_rpc: DotRpc,
 	btc_rpc: BtcRpc,
+	sol_rpc: SolRpc,
 	eth_multisig_client: EthMultisigClient,
 	dot_multisig_client: PolkadotMultisigClient,
 	btc_multisig_client: BitcoinMultisigClient,
+	sol_multisig_client: SolanaMultisigClient,
+	health_reporter: HealthReporter,
 ) -> Result<(), anyhow::Error>
 where
 	BlockStream: StreamApi<FINALIZED>,
 	EvmRpc: EvmRetrySigningRpcApi + Send + Sync + 'static,
 	DotRpc: DotRetryRpcApi + Send + Sync + 'static,
 	BtcRpc: BtcRetryRpcApi + Send + Sync + 'static,
+	SolRpc: SolRetryRpcApi + Send + Sync + 'static,
 	EthMultisigClient: MultisigClientApi<EvmCryptoScheme> + Send + Sync + 'static,
 	PolkadotMultisigClient: MultisigClientApi<PolkadotCryptoScheme> + Send + Sync + 'static,
 	BitcoinMultisigClient: MultisigClientApi<BtcCryptoScheme> + Send + Sync + 'static,
+	SolanaMultisigClient: MultisigClientApi<Ed25519CryptoScheme> + Send + Sync + 'static,
 	StateChainClient:
 		StorageApi + ChainApi + UnsignedExtrinsicApi + SignedExtrinsicApi + 'static + Send + Sync,
 {
@@ -480,6 +508,29 @@ where
                                             });
                                         }
                                     }
+                                    CfeEvent::HubTxBroadcastRequest(TxBroadcastRequest::<Runtime, _> { broadcast_id, nominee, payload }) => {
+                                        if nominee == account_id {
+                                            let hub_rpc = hub_rpc.clone();
+                                            let state_chain_client = state_chain_client.clone();
+                                            scope.spawn(async move {
+                                                match hub_rpc.submit_raw_encoded_extrinsic(payload.encoded_extrinsic).await {
+                                                    Ok(tx_hash) => info!("Assethub TransactionBroadcastRequest {broadcast_id:?} success: tx_hash: {tx_hash:#x}"),
+                                                    Err(error) => {
+                                                        error!("Error on Assethub TransactionBroadcastRequest {broadcast_id:?}: {error:?}");
+                                                        state_chain_client.finalize_signed_extrinsic(
+                                                            RuntimeCall::AssethubBroadcaster(
+                                                                pallet_cf_broadcast::Call::transaction_failed {
+                                                                    broadcast_id,
+                                                                },
+                                                            ),
+                                                        )
+                                                        .await;
+                                                    }
+                                                }
+                                                Ok(())
+                                            });
+                                        }
+                                    }
                                     CfeEvent::EthTxBroadcastRequest(TxBroadcastRequest::<Runtime, _> { broadcast_id, nominee, payload }) => {
                                         if nominee == account_id {
                                             let eth_rpc = eth_rpc.clone();
@@ -537,17 +588,56 @@ where
                                         // p2p registration is handled in the p2p module.
                                         // Matching here to log the event due to the match_event macro.
                                     }
-                                    CfeEvent::SolThresholdSignatureRequest(unsupported) => {
-                                        tracing::warn!("sol-threshold-signature-request: {:?}", unsupported);
-                                        todo!()
+                                    CfeEvent::SolThresholdSignatureRequest(req) => {
+                                        match multisig::ed25519::SigningPayload::new(req.payload.0) {
+                                            Ok(payload) => {
+                                                handle_signing_request::<_, _, _, SolanaInstance>(
+                                                    scope,
+                                                    &sol_multisig_client,
+                                                    state_chain_client.clone(),
+                                                    req.ceremony_id,
+                                                    req.signatories,
+                                                    vec![(KeyId::new(req.epoch_index, req.key), payload)],
+                                                ).await;
+                                            }
+                                            Err(error) => {
+                                                error!(ceremony_id = req.ceremony_id, "Ignoring Solana signing request: {error}");
+                                                sol_multisig_client.update_latest_ceremony_id(req.ceremony_id);
+                                            }
+                                        }
                                     }
-                                    CfeEvent::SolKeygenRequest(unsupported) => {
-                                        tracing::warn!("sol-keygen-request: {:?}", unsupported);
-                                        todo!()
+                                    CfeEvent::SolKeygenRequest(req) => {
+                                        handle_keygen_request::<_, _, _, SolanaInstance>(
+                                            scope,
+                                            &sol_multisig_client,
+                                            state_chain_client.clone(),
+                                            req.ceremony_id,
+                                            req.epoch_index,
+                                            req.participants,
+                                        ).await;
                                     }
-                                    CfeEvent::SolTxBroadcastRequest(unsupported) => {
-                                        tracing::warn!("sol-tx-broadcast-request: {:?}", unsupported);
-                                        todo!()
+                                    CfeEvent::SolTxBroadcastRequest(TxBroadcastRequest::<Runtime, _> { broadcast_id, nominee, payload }) => {
+                                        if nominee == account_id {
+                                            let sol_rpc = sol_rpc.clone();
+                                            let state_chain_client = state_chain_client.clone();
+                                            scope.spawn(async move {
+                                                match sol_rpc.send_transaction(payload.serialized_transaction).await {
+                                                    Ok(signature) => info!("Solana TransactionBroadcastRequest {broadcast_id:?} success: signature: {signature}"),
+                                                    Err(error) => {
+                                                        error!("Error on Solana TransactionBroadcastRequest {broadcast_id:?}: {error:?}");
+                                                        state_chain_client.finalize_signed_extrinsic(
+                                                            RuntimeCall::SolanaBroadcaster(
+                                                                pallet_cf_broadcast::Call::transaction_failed {
+                                                                    broadcast_id,
+                                                                },
+                                                            ),
+                                                        )
+                                                        .await;
+                                                    }
+                                                }
+                                                Ok(())
+                                            });
+                                        }
                                     }
                                 }}
                             }
@@ -572,6 +662,8 @@ where
 
                         last_heartbeat_submitted_at = current_block.number;
                     }
+
+                    health_reporter.report_state_chain_block(current_block.number);
                 }
                 None => {
                     error!("Exiting as State Chain block stream ended");
@@ -587,6 +679,7 @@ pub struct CeremonyIdCounters {
 	pub ethereum: CeremonyId,
 	pub polkadot: CeremonyId,
 	pub bitcoin: CeremonyId,
+	pub solana: CeremonyId,
 }
 
 /// Get the ceremony id counters for each chain at the **start** of the given block without
@@ -658,5 +751,20 @@ where
 				.await
 				.context("Failed to get Bitcoin CeremonyIdCounter from SC")?
 		},
+		solana: if let Some(ceremony_id) = events.iter().find_map(|event| match event {
+			CfeEvent::SolThresholdSignatureRequest(req) => Some(req.ceremony_id),
+			CfeEvent::SolKeygenRequest(req) => Some(req.ceremony_id),
+			_ => None,
+		}) {
+			ceremony_id.saturating_sub(1)
+		} else {
+			state_chain_client
+				.storage_value::<pallet_cf_threshold_signature::CeremonyIdCounter<
+					state_chain_runtime::Runtime,
+					state_chain_runtime::SolanaInstance,
+				>>(block_hash)
+				.await
+				.context("Failed to get Solana CeremonyIdCounter from SC")?
+		},
 	})
 }
@@ -2,12 +2,14 @@ use cf_chains::{
 	btc::BitcoinCrypto,
 	dot::{PolkadotCrypto, PolkadotPublicKey},
 	evm::EvmCrypto,
+	sol::{SolAddress, SolanaCrypto},
 	ChainCrypto,
 };
 use multisig::{
-	bitcoin::BtcSigning, eth::EthSigning, polkadot::PolkadotSigning, ChainSigning, CryptoScheme,
+	bitcoin::BtcSigning, ed25519::SolSigning, eth::EthSigning, polkadot::PolkadotSigning,
+	ChainSigning, CryptoScheme,
 };
-use state_chain_runtime::{BitcoinInstance, EvmInstance, PolkadotInstance};
+use state_chain_runtime::{BitcoinInstance, EvmInstance, PolkadotInstance, SolanaInstance};
 
 /// Compatibility layer for converting between public keys generated using the [CryptoScheme] types
 /// and the on-chain representation as defined by [ChainCrypto].
@@ -40,3 +42,11 @@ impl CryptoCompat<PolkadotSigning, PolkadotCrypto> for PolkadotInstance {
 		PolkadotPublicKey::from_aliased(pubkey.to_bytes())
 	}
 }
+
+impl CryptoCompat<SolSigning, SolanaCrypto> for SolanaInstance {
+	fn pubkey_to_aggkey(
+		pubkey: <<SolSigning as ChainSigning>::CryptoScheme as CryptoScheme>::PublicKey,
+	) -> <SolanaCrypto as ChainCrypto>::AggKey {
+		SolAddress(pubkey.to_bytes())
+	}
+}
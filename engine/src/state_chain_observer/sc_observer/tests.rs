@@ -4,6 +4,7 @@ use crate::{
 	btc::retry_rpc::mocks::MockBtcRetryRpcClient,
 	dot::retry_rpc::mocks::MockDotHttpRpcClient,
 	evm::retry_rpc::mocks::MockEvmRetryRpcClient,
+	sol::retry_rpc::mocks::MockSolRetryRpcClient,
 	state_chain_observer::{
 		client::{
 			extrinsic_api,
@@ -54,10 +55,14 @@ async fn start_sc_observer<
 		eth_rpc,
 		MockEvmRetryRpcClient::new(),
 		MockDotHttpRpcClient::new(),
+		MockDotHttpRpcClient::new(),
 		MockBtcRetryRpcClient::new(),
+		MockSolRetryRpcClient::new(),
+		MockMultisigClientApi::new(),
 		MockMultisigClientApi::new(),
 		MockMultisigClientApi::new(),
 		MockMultisigClientApi::new(),
+		Default::default(),
 	)
 	.await
 	.unwrap_err();
@@ -264,6 +269,60 @@ async fn should_handle_signing_request_eth() {
 	should_handle_signing_request::<EvmCryptoScheme, EvmInstance>().await;
 }
 
+#[tokio::test]
+async fn should_blame_ourselves_for_signing_requests_rejected_by_a_full_queue() {
+	let key_id = KeyId::new(1, [0u8; 32]);
+	let payload = EvmCryptoScheme::signing_payload_for_test();
+	let our_account_id = AccountId32::new([0; 32]);
+	let ceremony_id = 1;
+	type ThresholdSignatureCall = pallet_cf_threshold_signature::Call<Runtime, EvmInstance>;
+
+	let mut state_chain_client = MockStateChainClient::new();
+	let mut multisig_client = MockMultisigClientApi::<EvmCryptoScheme>::new();
+
+	state_chain_client
+		.expect_account_id()
+		.once()
+		.return_const(our_account_id.clone());
+	multisig_client.expect_initiate_signing().once().return_once(|_, _, _| {
+		futures::future::ready(Err((BTreeSet::new(), SigningFailureReason::CeremonyQueueFull)))
+			.boxed()
+	});
+	state_chain_client
+		.expect_finalize_signed_extrinsic::<ThresholdSignatureCall>()
+		.with(eq(ThresholdSignatureCall::report_signature_failed {
+			ceremony_id,
+			offenders: BTreeSet::from([our_account_id.clone()]),
+		}))
+		.once()
+		.return_once(|_| {
+			(
+				extrinsic_api::signed::MockUntilInBlock::new(),
+				extrinsic_api::signed::MockUntilFinalized::new(),
+			)
+		});
+
+	let state_chain_client = Arc::new(state_chain_client);
+	task_scope(|scope| {
+		async {
+			sc_observer::handle_signing_request::<_, _, EvmCryptoScheme, EvmInstance>(
+				scope,
+				&multisig_client,
+				state_chain_client.clone(),
+				ceremony_id,
+				BTreeSet::from_iter([our_account_id]),
+				vec![(key_id, payload)],
+			)
+			.await;
+
+			Ok(())
+		}
+		.boxed()
+	})
+	.await
+	.unwrap();
+}
+
 mod dot_signing {
 
 	use multisig::polkadot::PolkadotCryptoScheme;
@@ -378,6 +437,17 @@ mod dot_keygen {
 	}
 }
 
+mod sol_keygen {
+	use multisig::ed25519::SolSigning;
+	use state_chain_runtime::SolanaInstance;
+
+	use super::*;
+	#[tokio::test]
+	async fn should_handle_keygen_request_sol() {
+		should_handle_keygen_request::<SolSigning, SolanaInstance>().await;
+	}
+}
+
 #[tokio::test]
 async fn should_handle_key_handover_request()
 where
@@ -533,6 +603,7 @@ async fn test_get_ceremony_id_counters_with_events() {
 	const ETH_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK: CeremonyId = 10;
 	const DOT_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK: CeremonyId = 20;
 	const BTC_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK: CeremonyId = 30;
+	const SOL_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK: CeremonyId = 40;
 	let block_hash = H256::default();
 
 	let test_block_streams = vec![
@@ -574,6 +645,16 @@ async fn test_get_ceremony_id_counters_with_events() {
 				signatories: Default::default(),
 				payload: Default::default(),
 			}),
+			CfeEvent::<Runtime>::SolThresholdSignatureRequest(ThresholdSignatureRequest::<
+				Runtime,
+				_,
+			> {
+				ceremony_id: SOL_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK + 1,
+				epoch_index: 1,
+				key: Default::default(),
+				signatories: Default::default(),
+				payload: cf_chains::sol::SolMessageBytes(vec![]),
+			}),
 		],
 		// Test 2: 1 keygen request for each chain
 		vec![
@@ -592,10 +673,20 @@ async fn test_get_ceremony_id_counters_with_events() {
 				epoch_index: 1,
 				participants: Default::default(),
 			}),
+			CfeEvent::<Runtime>::SolKeygenRequest(KeygenRequest::<Runtime> {
+				ceremony_id: SOL_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK + 1,
+				epoch_index: 1,
+				participants: Default::default(),
+			}),
 		],
 		// Test 3: 1 key handover request for BTC (and keygen requests for the other chains to
 		// avoid test complexity)
 		vec![
+			CfeEvent::<Runtime>::SolKeygenRequest(KeygenRequest::<Runtime> {
+				ceremony_id: SOL_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK + 1,
+				epoch_index: 1,
+				participants: Default::default(),
+			}),
 			CfeEvent::<Runtime>::EvmKeygenRequest(KeygenRequest::<Runtime> {
 				ceremony_id: ETH_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK + 1,
 				epoch_index: 1,
@@ -636,6 +727,7 @@ async fn test_get_ceremony_id_counters_with_events() {
 		assert_eq!(ceremony_id_counters.ethereum, ETH_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK);
 		assert_eq!(ceremony_id_counters.polkadot, DOT_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK);
 		assert_eq!(ceremony_id_counters.bitcoin, BTC_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK);
+		assert_eq!(ceremony_id_counters.solana, SOL_CEREMONY_ID_COUNTER_BEFORE_INITIAL_BLOCK);
 	}
 }
 
@@ -644,6 +736,7 @@ async fn test_get_ceremony_id_counters_without_events() {
 	const ETH_CEREMONY_ID_COUNTER: CeremonyId = 10;
 	const DOT_CEREMONY_ID_COUNTER: CeremonyId = 20;
 	const BTC_CEREMONY_ID_COUNTER: CeremonyId = 30;
+	const SOL_CEREMONY_ID_COUNTER: CeremonyId = 40;
 	let block_hash = H256::default();
 	let mut state_chain_client = MockStateChainClient::new();
 
@@ -673,6 +766,14 @@ async fn test_get_ceremony_id_counters_without_events() {
 		.with(eq(block_hash))
 		.once()
 		.return_once(|_| Ok(BTC_CEREMONY_ID_COUNTER));
+	state_chain_client
+		.expect_storage_value::<pallet_cf_threshold_signature::CeremonyIdCounter<
+			state_chain_runtime::Runtime,
+			state_chain_runtime::SolanaInstance,
+		>>()
+		.with(eq(block_hash))
+		.once()
+		.return_once(|_| Ok(SOL_CEREMONY_ID_COUNTER));
 
 	// No events in the stream that would change the ceremony id counters
 	state_chain_client
@@ -699,6 +800,7 @@ async fn test_get_ceremony_id_counters_without_events() {
 	assert_eq!(ceremony_id_counters.ethereum, ETH_CEREMONY_ID_COUNTER);
 	assert_eq!(ceremony_id_counters.polkadot, DOT_CEREMONY_ID_COUNTER);
 	assert_eq!(ceremony_id_counters.bitcoin, BTC_CEREMONY_ID_COUNTER);
+	assert_eq!(ceremony_id_counters.solana, SOL_CEREMONY_ID_COUNTER);
 }
 
 #[tokio::test]
@@ -712,7 +814,7 @@ async fn run_the_sc_observer() {
 				crate::state_chain_observer::client::StateChainClient::connect_with_account(
 					scope,
 					&settings.state_chain.ws_endpoint,
-					&settings.state_chain.signing_key_file,
+					settings.state_chain.signing_key_source(),
 					AccountRole::Unregistered,
 					false,
 					false,
@@ -727,10 +829,14 @@ async fn run_the_sc_observer() {
 				MockEvmRetryRpcClient::new(),
 				MockEvmRetryRpcClient::new(),
 				MockDotHttpRpcClient::new(),
+				MockDotHttpRpcClient::new(),
 				MockBtcRetryRpcClient::new(),
+				MockSolRetryRpcClient::new(),
 				MockMultisigClientApi::new(),
 				MockMultisigClientApi::new(),
 				MockMultisigClientApi::new(),
+				MockMultisigClientApi::new(),
+				Default::default(),
 			)
 			.await
 			.unwrap_err();
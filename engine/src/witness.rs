@@ -4,4 +4,6 @@ pub mod common;
 pub mod dot;
 pub mod eth;
 pub mod evm;
+pub mod hub;
+pub mod sol;
 pub mod start;
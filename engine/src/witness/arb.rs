@@ -11,6 +11,7 @@ use utilities::task_scope::Scope;
 use crate::{
 	db::PersistentKeyDB,
 	evm::{retry_rpc::EvmRetryRpcClient, rpc::EvmRpcSigningClient},
+	health::HealthReporter,
 	state_chain_observer::client::{
 		chain_api::ChainApi,
 		extrinsic_api::signed::SignedExtrinsicApi,
@@ -38,6 +39,7 @@ pub async fn start<StateChainClient, StateChainStream, ProcessCall, ProcessingFu
 	state_chain_stream: StateChainStream,
 	epoch_source: EpochSourceBuilder<'_, '_, StateChainClient, (), ()>,
 	db: Arc<PersistentKeyDB>,
+	health_reporter: HealthReporter,
 ) -> Result<()>
 where
 	StateChainClient: StorageApi + ChainApi + SignedExtrinsicApi + 'static + Send + Sync,
@@ -95,7 +97,7 @@ where
 	arb_source
 		.clone()
 		.chunk_by_time(epoch_source.clone(), scope)
-		.chain_tracking(state_chain_client.clone(), arb_client.clone())
+		.chain_tracking(state_chain_client.clone(), arb_client.clone(), health_reporter.clone())
 		.logging("chain tracking")
 		.spawn(scope);
 
@@ -128,7 +130,7 @@ where
 		.clone()
 		.key_manager_witnessing(process_call.clone(), arb_client.clone(), key_manager_address)
 		.continuous("ArbitrumKeyManager".to_string(), db.clone())
-		.logging("KeyManager")
+		.logging("KeyManager", health_reporter.clone())
 		.spawn(scope);
 
 	arb_safe_vault_source_deposit_addresses
@@ -141,7 +143,7 @@ where
 		)
 		.await?
 		.continuous("ArbitrumUSDCDeposits".to_string(), db.clone())
-		.logging("USDCDeposits")
+		.logging("USDCDeposits", health_reporter.clone())
 		.spawn(scope);
 
 	arb_safe_vault_source_deposit_addresses
@@ -155,7 +157,7 @@ where
 		)
 		.await
 		.continuous("ArbitrumDeposits".to_string(), db.clone())
-		.logging("Deposits")
+		.logging("Deposits", health_reporter.clone())
 		.spawn(scope);
 
 	arb_safe_vault_source
@@ -168,7 +170,7 @@ where
 			supported_arb_erc20_assets,
 		)
 		.continuous("ArbitrumVault".to_string(), db)
-		.logging("Vault")
+		.logging("Vault", health_reporter)
 		.spawn(scope);
 
 	Ok(())
@@ -184,7 +186,7 @@ mod tests {
 
 	use crate::{
 		settings::{NodeContainer, WsHttpEndpoints},
-		state_chain_observer,
+		state_chain_observer::{self, client::extrinsic_api::signed::signer::SigningKeySource},
 		witness::common::epoch_source::EpochSource,
 	};
 
@@ -213,7 +215,7 @@ mod tests {
 					state_chain_observer::client::StateChainClient::connect_with_account(
 						scope,
 						"ws://localhost:9944",
-						PathBuf::from("/Users/kylezs/Documents/cf-repos/chainflip-backend/localnet/init/keys/bashful/signing_key_file").as_path(),
+						SigningKeySource::File(PathBuf::from("/Users/kylezs/Documents/cf-repos/chainflip-backend/localnet/init/keys/bashful/signing_key_file")),
 						AccountRole::Validator,
 						false,
 						false,
@@ -246,12 +248,13 @@ mod tests {
 					EvmRetryRpcClient::<EvmRpcSigningClient>::new(
 						scope,
 						PathBuf::from("/Users/kylezs/Documents/cf-repos/chainflip-backend/localnet/init/keys/bashful/eth_private_key_file"),
-						NodeContainer { primary: WsHttpEndpoints { ws_endpoint: "ws://localhost:8548".into(), http_endpoint: "http://localhost:8547".into()}, backup: None },
+						NodeContainer::primary_only(WsHttpEndpoints { ws_endpoint: "ws://localhost:8548".into(), http_endpoint: "http://localhost:8547".into()}),
 						expected_arb_chain_id,
 						"arb_rpc",
 						"arb_subscribe",
 						"Arbitrum",
 						Arbitrum::WITNESS_PERIOD,
+						Default::default(),
 					).unwrap()
 				};
 
@@ -259,7 +262,7 @@ mod tests {
 				let db = Arc::new(PersistentKeyDB::open_and_migrate_to_latest(&db_path, None).unwrap());
 
 
-				start(scope, arb_client, witness_call, state_chain_client, state_chain_stream, epoch_source, db).await.unwrap();
+				start(scope, arb_client, witness_call, state_chain_client, state_chain_stream, epoch_source, db, Default::default()).await.unwrap();
 
 				Ok(())
 			}
@@ -8,6 +8,7 @@ use crate::{
 		rpc::VerboseTransaction,
 	},
 	db::PersistentKeyDB,
+	health::HealthReporter,
 	state_chain_observer::client::{
 		extrinsic_api::signed::SignedExtrinsicApi,
 		storage_api::StorageApi,
@@ -81,6 +82,7 @@ pub async fn start<
 	unfinalised_state_chain_stream: impl StreamApi<UNFINALIZED>,
 	epoch_source: EpochSourceBuilder<'_, '_, StateChainClient, (), ()>,
 	db: Arc<PersistentKeyDB>,
+	health_reporter: HealthReporter,
 ) -> Result<()>
 where
 	StateChainClient: StorageApi + SignedExtrinsicApi + 'static + Send + Sync,
@@ -103,7 +105,7 @@ where
 	btc_source
 		.clone()
 		.chunk_by_time(epoch_source.clone(), scope)
-		.chain_tracking(state_chain_client.clone(), btc_client.clone())
+		.chain_tracking(state_chain_client.clone(), btc_client.clone(), health_reporter.clone())
 		.logging("chain tracking")
 		.spawn(scope);
 
@@ -129,7 +131,7 @@ where
 		.deposit_addresses(scope, unfinalised_state_chain_stream, state_chain_client.clone())
 		.await
 		.btc_deposits(prewitness_call)
-		.logging("pre-witnessing")
+		.logging("pre-witnessing", health_reporter.clone())
 		.spawn(scope);
 
 	let btc_safety_margin = match state_chain_client
@@ -165,6 +167,7 @@ where
 		.deposit_addresses(scope, state_chain_stream.clone(), state_chain_client.clone())
 		.await
 		.btc_deposits(process_call.clone())
+		.btc_vault_swaps(process_call.clone())
 		.egress_items(scope, state_chain_stream, state_chain_client.clone())
 		.await
 		.then({
@@ -172,7 +175,7 @@ where
 			move |epoch, header| process_egress(epoch, header, process_call.clone())
 		})
 		.continuous("Bitcoin".to_string(), db)
-		.logging("witnessing")
+		.logging("witnessing", health_reporter)
 		.spawn(scope);
 
 	Ok(())
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use cf_primitives::EpochIndex;
 use futures_core::Future;
 use itertools::Itertools;
-use pallet_cf_ingress_egress::{DepositChannelDetails, DepositWitness};
+use pallet_cf_ingress_egress::{DepositChannelDetails, DepositWitness, VaultDepositWitness};
 use secp256k1::hashes::Hash as secp256k1Hash;
 use state_chain_runtime::BitcoinInstance;
 
@@ -17,10 +17,13 @@ use crate::{
 		RuntimeHasChain,
 	},
 };
-use bitcoin::BlockHash;
+use bitcoin::{script::Instruction, BlockHash, Script};
 use cf_chains::{
 	assets::btc,
-	btc::{ScriptPubkey, UtxoId},
+	btc::{
+		deposit_address::DepositAddress, vault_swap_encoding::decode_vault_swap_payload,
+		ScriptPubkey, UtxoId, CHANGE_ADDRESS_SALT,
+	},
 	Bitcoin,
 };
 
@@ -79,6 +82,182 @@ impl<Inner: ChunkedByVault> ChunkedByVaultBuilder<Inner> {
 			}
 		})
 	}
+
+	/// Witnesses deposits made directly into the vault that carry swap parameters in an OP_RETURN
+	/// output. During a rotation, deposits into the previous vault are witnessed as well. Only used
+	/// for full witnessing, since vault swaps are not boosted.
+	pub fn btc_vault_swaps<ProcessCall, ProcessingFut>(
+		self,
+		process_call: ProcessCall,
+	) -> ChunkedByVaultBuilder<
+		impl ChunkedByVault<
+			Index = u64,
+			Hash = BlockHash,
+			Data = Vec<VerboseTransaction>,
+			Chain = Bitcoin,
+		>,
+	>
+	where
+		Inner: ChunkedByVault<
+			Index = u64,
+			Hash = BlockHash,
+			Data = Vec<VerboseTransaction>,
+			Chain = Bitcoin,
+		>,
+		ProcessCall: Fn(state_chain_runtime::RuntimeCall, EpochIndex) -> ProcessingFut
+			+ Send
+			+ Sync
+			+ Clone
+			+ 'static,
+		ProcessingFut: Future<Output = ()> + Send + 'static,
+	{
+		self.then(move |epoch, header| {
+			let process_call = process_call.clone();
+			async move {
+				let txs = header.data;
+
+				let vault_keys = core::iter::once(epoch.info.0.current)
+					.chain(epoch.info.0.previous)
+					.collect::<Vec<_>>();
+
+				for deposit in vault_deposit_witnesses(&txs, &vault_keys) {
+					process_call(
+						pallet_cf_ingress_egress::Call::<_, BitcoinInstance>::vault_swap_request {
+							block_height: header.index,
+							deposit: Box::new(deposit),
+						}
+						.into(),
+						epoch.index,
+					)
+					.await;
+				}
+				txs
+			}
+		})
+	}
+}
+
+fn vault_deposit_witnesses(
+	txs: &[VerboseTransaction],
+	vault_keys: &[[u8; 32]],
+) -> Vec<VaultDepositWitness<state_chain_runtime::Runtime, BitcoinInstance>> {
+	let vault_scripts = vault_keys
+		.iter()
+		.map(|pubkey_x| DepositAddress::new(*pubkey_x, CHANGE_ADDRESS_SALT).script_pubkey())
+		.collect::<Vec<_>>();
+
+	txs.iter()
+		.filter_map(|tx| {
+			// Our own broadcasts pay their change into the vault and may carry a CCM message in an
+			// OP_RETURN output. They must not be mistaken for vault swaps.
+			if spends_vault_utxos(tx, vault_keys, &vault_scripts) {
+				return None
+			}
+
+			// As for deposit channels, only the largest output to the vault is taken as the
+			// deposit.
+			let (vout, deposit_output, vault_script) = Iterator::zip(0.., &tx.vout)
+				.filter(|(_vout, tx_out)| tx_out.value.to_sat() > 0)
+				.filter_map(|(vout, tx_out)| {
+					vault_scripts
+						.iter()
+						.find(|script| tx_out.script_pubkey.as_bytes() == script.bytes())
+						.map(|script| (vout, tx_out, script))
+				})
+				.max_by_key(|(_vout, tx_out, _script)| tx_out.value)?;
+
+			// Transfers into the vault without an OP_RETURN output are not vault swaps.
+			let payload =
+				tx.vout.iter().find_map(|tx_out| op_return_data(&tx_out.script_pubkey))?;
+
+			let Some(refund_address) = tx
+				.vin
+				.first()
+				.and_then(|tx_in| tx_in.prevout.as_ref())
+				.and_then(|prevout| script_pubkey_from_script(&prevout.script_pubkey))
+			else {
+				tracing::warn!(
+					"Ignoring vault swap in tx {} because its refund address can't be determined.",
+					tx.txid
+				);
+				return None
+			};
+
+			Some(VaultDepositWitness {
+				deposit_address: vault_script.clone(),
+				asset: btc::Asset::Btc,
+				amount: deposit_output.value.to_sat(),
+				deposit_details: UtxoId { tx_id: tx.txid.to_byte_array().into(), vout },
+				refund_address,
+				tx_hash: tx.txid.to_byte_array(),
+				swap_parameters: decode_vault_swap_payload(payload),
+			})
+		})
+		.collect()
+}
+
+/// Whether the transaction spends utxos held by the vault, either from the vault address itself or
+/// from one of its deposit channels. Only the validators can produce such transactions.
+fn spends_vault_utxos(
+	tx: &VerboseTransaction,
+	vault_keys: &[[u8; 32]],
+	vault_scripts: &[ScriptPubkey],
+) -> bool {
+	tx.vin.iter().any(|tx_in| {
+		// Vault utxos are spent via the taproot key path.
+		let spends_vault_address = tx_in.prevout.as_ref().is_some_and(|prevout| {
+			vault_scripts
+				.iter()
+				.any(|script| prevout.script_pubkey.as_bytes() == script.bytes())
+		});
+		// Deposit channel utxos are spent via a script path whose unlock script ends with
+		// `<pubkey_x> OP_CHECKSIG`.
+		let spends_deposit_channel = tx_in
+			.txinwitness
+			.iter()
+			.flatten()
+			.filter_map(|item| hex::decode(item).ok())
+			.any(|item| {
+				vault_keys
+					.iter()
+					.any(|pubkey_x| item.ends_with(&[&[0x20], &pubkey_x[..], &[0xac]].concat()))
+			});
+		spends_vault_address || spends_deposit_channel
+	})
+}
+
+/// Returns the data pushed by an OP_RETURN output script, if it is one.
+fn op_return_data(script: &Script) -> Option<&[u8]> {
+	if !script.is_op_return() {
+		return None
+	}
+	match script.instructions().nth(1) {
+		Some(Ok(Instruction::PushBytes(data))) => Some(data.as_bytes()),
+		_ => None,
+	}
+}
+
+/// Converts a standard output script into the corresponding `ScriptPubkey`.
+fn script_pubkey_from_script(script: &Script) -> Option<ScriptPubkey> {
+	let bytes = script.as_bytes();
+	if script.is_p2pkh() {
+		Some(ScriptPubkey::P2PKH(bytes[3..23].try_into().ok()?))
+	} else if script.is_p2sh() {
+		Some(ScriptPubkey::P2SH(bytes[2..22].try_into().ok()?))
+	} else if script.is_v0_p2wpkh() {
+		Some(ScriptPubkey::P2WPKH(bytes[2..22].try_into().ok()?))
+	} else if script.is_v0_p2wsh() {
+		Some(ScriptPubkey::P2WSH(bytes[2..34].try_into().ok()?))
+	} else if script.is_v1_p2tr() {
+		Some(ScriptPubkey::Taproot(bytes[2..34].try_into().ok()?))
+	} else if script.is_witness_program() {
+		Some(ScriptPubkey::OtherSegwit {
+			version: script.witness_version()?.to_num(),
+			program: bytes[2..].to_vec().try_into().ok()?,
+		})
+	} else {
+		None
+	}
 }
 
 fn deposit_witnesses(
@@ -131,17 +310,23 @@ fn script_addresses(
 #[cfg(test)]
 pub mod tests {
 
-	use crate::btc::rpc::VerboseTxOut;
+	use crate::btc::rpc::{VerboseOutPoint, VerbosePrevOut, VerboseTxIn, VerboseTxOut};
 
 	use super::*;
 	use bitcoin::{
 		absolute::{Height, LockTime},
-		Amount, ScriptBuf, Txid,
+		Amount, ScriptBuf, Sequence, Txid,
 	};
 	use cf_chains::{
-		btc::{deposit_address::DepositAddress, ScriptPubkey},
+		address::EncodedAddress,
+		btc::{
+			deposit_address::DepositAddress,
+			vault_swap_encoding::{encode_vault_swap_payload, VaultSwapParameters},
+			ScriptPubkey,
+		},
 		DepositChannel,
 	};
+	use cf_primitives::Asset;
 	use pallet_cf_ingress_egress::{BoostStatus, ChannelAction};
 	use rand::{seq::SliceRandom, Rng, SeedableRng};
 	use sp_runtime::AccountId32;
@@ -387,4 +572,172 @@ pub mod tests {
 		assert_eq!(deposit_witnesses[0].amount, UTXO_WITNESSED_1);
 		assert_eq!(deposit_witnesses[1].amount, UTXO_WITNESSED_2);
 	}
+
+	// OP_RETURN OP_PUSHDATA1 <payload>
+	fn op_return(payload: Vec<u8>) -> Vec<u8> {
+		[vec![0x6a, 0x4c, payload.len() as u8], payload].concat()
+	}
+
+	fn with_input(
+		mut tx: VerboseTransaction,
+		prevout_script: &ScriptPubkey,
+		txinwitness: Option<Vec<String>>,
+	) -> VerboseTransaction {
+		tx.vin = vec![VerboseTxIn {
+			outpoint: VerboseOutPoint::Txid { txid: Txid::all_zeros(), vout: 0 },
+			txinwitness,
+			sequence: Sequence::MAX,
+			prevout: Some(VerbosePrevOut {
+				value: Amount::from_sat(10_000),
+				script_pubkey: ScriptBuf::from(prevout_script.bytes()),
+			}),
+		}];
+		tx
+	}
+
+	fn vault_swap_parameters() -> VaultSwapParameters<AccountId32> {
+		VaultSwapParameters {
+			output_asset: Asset::Eth,
+			output_address: EncodedAddress::Eth([2; 20]),
+			min_output_amount: 1_000,
+			broker_id: AccountId32::new([3; 32]),
+			broker_commission: 10,
+		}
+	}
+
+	#[test]
+	fn vault_swap_witnesses() {
+		let vault_script = DepositAddress::new([1; 32], CHANGE_ADDRESS_SALT).script_pubkey();
+		let refund_script = ScriptPubkey::P2WPKH([7; 20]);
+		let parameters = vault_swap_parameters();
+		let with_refund_input = |tx| with_input(tx, &refund_script, None);
+
+		let txs = vec![
+			with_refund_input(fake_transaction(
+				fake_verbose_vouts(vec![
+					(5_000, vault_script.bytes()),
+					(0, op_return(encode_vault_swap_payload(&parameters).unwrap())),
+				]),
+				None,
+			)),
+			// Malformed payload.
+			with_refund_input(fake_transaction(
+				fake_verbose_vouts(vec![(0, op_return(vec![0xff])), (6_000, vault_script.bytes())]),
+				None,
+			)),
+			// A transfer into the vault without a payload is not a vault swap.
+			with_refund_input(fake_transaction(
+				fake_verbose_vouts(vec![(7_000, vault_script.bytes())]),
+				None,
+			)),
+			// A payload without a transfer into the vault is not a vault swap.
+			with_refund_input(fake_transaction(
+				fake_verbose_vouts(vec![
+					(8_000, refund_script.bytes()),
+					(0, op_return(encode_vault_swap_payload(&parameters).unwrap())),
+				]),
+				None,
+			)),
+			// Without a known input, the deposit can't be refunded.
+			fake_transaction(
+				fake_verbose_vouts(vec![
+					(9_000, vault_script.bytes()),
+					(0, op_return(encode_vault_swap_payload(&parameters).unwrap())),
+				]),
+				None,
+			),
+		];
+
+		let witnesses = vault_deposit_witnesses(&txs, &[[1; 32]]);
+		assert_eq!(witnesses.len(), 2);
+
+		assert_eq!(witnesses[0].amount, 5_000);
+		assert_eq!(witnesses[0].deposit_details.vout, 0);
+		assert_eq!(witnesses[0].refund_address, refund_script);
+		assert_eq!(witnesses[0].tx_hash, txs[0].txid.to_byte_array());
+		assert_eq!(witnesses[0].swap_parameters, Some(parameters));
+
+		assert_eq!(witnesses[1].amount, 6_000);
+		assert_eq!(witnesses[1].deposit_details.vout, 1);
+		assert_eq!(witnesses[1].swap_parameters, None);
+	}
+
+	#[test]
+	fn vault_swaps_into_the_previous_vault_are_witnessed() {
+		let previous_vault_script =
+			DepositAddress::new([1; 32], CHANGE_ADDRESS_SALT).script_pubkey();
+		let refund_script = ScriptPubkey::P2WPKH([7; 20]);
+
+		let txs = vec![with_input(
+			fake_transaction(
+				fake_verbose_vouts(vec![
+					(5_000, previous_vault_script.bytes()),
+					(0, op_return(encode_vault_swap_payload(&vault_swap_parameters()).unwrap())),
+				]),
+				None,
+			),
+			&refund_script,
+			None,
+		)];
+
+		assert!(vault_deposit_witnesses(&txs, &[[2; 32]]).is_empty());
+
+		let witnesses = vault_deposit_witnesses(&txs, &[[2; 32], [1; 32]]);
+		assert_eq!(witnesses.len(), 1);
+		assert_eq!(witnesses[0].deposit_address, previous_vault_script);
+	}
+
+	#[test]
+	fn ccm_egress_is_not_a_vault_swap() {
+		const VAULT_KEY: [u8; 32] = [1; 32];
+		let vault_script = DepositAddress::new(VAULT_KEY, CHANGE_ADDRESS_SALT).script_pubkey();
+		let destination_script = ScriptPubkey::P2WPKH([7; 20]);
+		// A CCM message that happens to decode as vault swap parameters.
+		let message = encode_vault_swap_payload(&vault_swap_parameters()).unwrap();
+		let ccm_egress_outputs = || {
+			fake_verbose_vouts(vec![
+				(5_000, destination_script.bytes()),
+				(0, op_return(message.clone())),
+				(20_000, vault_script.bytes()),
+			])
+		};
+
+		let deposit_channel = DepositAddress::new(VAULT_KEY, 5);
+		let deposit_channel_witness = vec![
+			hex::encode([0xaa; 64]),
+			hex::encode(&deposit_channel.script_path.as_ref().unwrap().unlock_script),
+			hex::encode([0xc0; 33]),
+		];
+
+		let txs = vec![
+			// Spends a change utxo from the vault.
+			with_input(fake_transaction(ccm_egress_outputs(), None), &vault_script, None),
+			// Spends a utxo from a deposit channel.
+			with_input(
+				fake_transaction(ccm_egress_outputs(), None),
+				&deposit_channel.script_pubkey(),
+				Some(deposit_channel_witness),
+			),
+		];
+
+		assert!(vault_deposit_witnesses(&txs, &[VAULT_KEY]).is_empty());
+	}
+
+	#[test]
+	fn script_pubkey_from_standard_scripts() {
+		for script_pubkey in [
+			ScriptPubkey::P2PKH([1; 20]),
+			ScriptPubkey::P2SH([2; 20]),
+			ScriptPubkey::P2WPKH([3; 20]),
+			ScriptPubkey::P2WSH([4; 32]),
+			ScriptPubkey::Taproot([5; 32]),
+			ScriptPubkey::OtherSegwit { version: 2, program: vec![6; 40].try_into().unwrap() },
+		] {
+			assert_eq!(
+				script_pubkey_from_script(&ScriptBuf::from(script_pubkey.bytes())),
+				Some(script_pubkey)
+			);
+		}
+		assert_eq!(script_pubkey_from_script(&ScriptBuf::from(vec![0x6a, 0x01, 0xaa])), None);
+	}
 }
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use cf_chains::{instances::ChainInstanceFor, ChainState};
 
 use crate::{
+	health::HealthReporter,
 	state_chain_observer::client::extrinsic_api::signed::SignedExtrinsicApi,
 	witness::common::{chain_source::Header, RuntimeCallHasChain, RuntimeHasChain},
 };
@@ -24,6 +25,7 @@ impl<Inner: ChunkedByTime> ChunkedByTimeBuilder<Inner> {
 		self,
 		state_chain_client: Arc<StateChainClient>,
 		tracked_data_client: TrackedDataClient,
+		health_reporter: HealthReporter,
 	) -> ChunkedByTimeBuilder<impl ChunkedByTime>
 	where
 		Inner: ChunkedByTime,
@@ -36,6 +38,7 @@ impl<Inner: ChunkedByTime> ChunkedByTimeBuilder<Inner> {
 		self.latest_then(move |epoch, header| {
 			let state_chain_client = state_chain_client.clone();
 			let tracked_data_client = tracked_data_client.clone();
+			let health_reporter = health_reporter.clone();
 			async move {
 				let call: Box<state_chain_runtime::RuntimeCall> = Box::new(
 					pallet_cf_chain_tracking::Call::<
@@ -56,6 +59,7 @@ impl<Inner: ChunkedByTime> ChunkedByTimeBuilder<Inner> {
 					})
 					.await;
 				CHAIN_TRACKING.set(&[Inner::Chain::NAME], Into::<u64>::into(header.index));
+				health_reporter.report_chain_head(Inner::Chain::NAME, header.index.into());
 				Ok::<_, anyhow::Error>(header.data)
 			}
 		})
@@ -1,23 +1,39 @@
+use crate::health::HealthReporter;
+
 use super::{
 	chunked_by_time::{builder::ChunkedByTimeBuilder, ChunkedByTime},
 	chunked_by_vault::{builder::ChunkedByVaultBuilder, ChunkedByVault},
 };
 
 impl<Inner: ChunkedByVault> ChunkedByVaultBuilder<Inner> {
-	pub fn logging(self, log_prefix: &'static str) -> ChunkedByVaultBuilder<impl ChunkedByVault>
+	/// Logs each processed block and reports it as the progress of the witnesser named by
+	/// `log_prefix`.
+	pub fn logging(
+		self,
+		log_prefix: &'static str,
+		health_reporter: HealthReporter,
+	) -> ChunkedByVaultBuilder<impl ChunkedByVault>
 	where
 		Inner: ChunkedByVault,
 	{
-		self.then(move |epoch, header| async move {
-			tracing::info!(
-				"{} | {} processed: epoch index: {:?}, block index {:?}, hash {:?}",
-				<Inner::Chain as cf_chains::Chain>::NAME,
-				log_prefix,
-				epoch.index,
-				header.index,
-				header.hash
-			);
-			Ok::<_, anyhow::Error>(header.data)
+		self.then(move |epoch, header| {
+			let health_reporter = health_reporter.clone();
+			async move {
+				tracing::info!(
+					"{} | {} processed: epoch index: {:?}, block index {:?}, hash {:?}",
+					<Inner::Chain as cf_chains::Chain>::NAME,
+					log_prefix,
+					epoch.index,
+					header.index,
+					header.hash
+				);
+				health_reporter.report_witnessed_block(
+					<Inner::Chain as cf_chains::Chain>::NAME,
+					log_prefix,
+					header.index.into(),
+				);
+				Ok::<_, anyhow::Error>(header.data)
+			}
 		})
 	}
 }
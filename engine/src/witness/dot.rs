@@ -25,6 +25,7 @@ use utilities::task_scope::Scope;
 use crate::{
 	db::PersistentKeyDB,
 	dot::retry_rpc::{DotRetryRpcApi, DotRetryRpcClient},
+	health::HealthReporter,
 	state_chain_observer::client::{
 		extrinsic_api::signed::SignedExtrinsicApi,
 		storage_api::StorageApi,
@@ -187,6 +188,7 @@ pub async fn start<StateChainClient, ProcessCall, ProcessingFut>(
 	state_chain_stream: impl StreamApi<FINALIZED> + Clone,
 	epoch_source: EpochSourceBuilder<'_, '_, StateChainClient, (), ()>,
 	db: Arc<PersistentKeyDB>,
+	health_reporter: HealthReporter,
 ) -> Result<()>
 where
 	StateChainClient: StorageApi + SignedExtrinsicApi + 'static + Send + Sync,
@@ -205,7 +207,7 @@ where
 	unfinalised_source
 		.clone()
 		.chunk_by_time(epoch_source.clone(), scope)
-		.chain_tracking(state_chain_client.clone(), dot_client.clone())
+		.chain_tracking(state_chain_client.clone(), dot_client.clone(), health_reporter.clone())
 		.logging("chain tracking")
 		.spawn(scope);
 
@@ -250,7 +252,7 @@ where
 			}
 		})
 		.continuous("Polkadot".to_string(), db)
-		.logging("witnessing")
+		.logging("witnessing", health_reporter)
 		.spawn(scope);
 
 	Ok(())
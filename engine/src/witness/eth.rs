@@ -12,6 +12,7 @@ use utilities::task_scope::Scope;
 use crate::{
 	db::PersistentKeyDB,
 	evm::{retry_rpc::EvmRetryRpcClient, rpc::EvmRpcSigningClient},
+	health::HealthReporter,
 	state_chain_observer::client::{
 		chain_api::ChainApi,
 		extrinsic_api::signed::SignedExtrinsicApi,
@@ -37,6 +38,7 @@ pub async fn start<StateChainClient, StateChainStream, ProcessCall, ProcessingFu
 	state_chain_stream: StateChainStream,
 	epoch_source: EpochSourceBuilder<'_, '_, StateChainClient, (), ()>,
 	db: Arc<PersistentKeyDB>,
+	health_reporter: HealthReporter,
 ) -> Result<()>
 where
 	StateChainClient: StorageApi + ChainApi + SignedExtrinsicApi + 'static + Send + Sync,
@@ -103,7 +105,7 @@ where
 	eth_source
 		.clone()
 		.chunk_by_time(epoch_source.clone(), scope)
-		.chain_tracking(state_chain_client.clone(), eth_client.clone())
+		.chain_tracking(state_chain_client.clone(), eth_client.clone(), health_reporter.clone())
 		.logging("chain tracking")
 		.spawn(scope);
 
@@ -136,7 +138,7 @@ where
 		.clone()
 		.key_manager_witnessing(process_call.clone(), eth_client.clone(), key_manager_address)
 		.continuous("KeyManager".to_string(), db.clone())
-		.logging("KeyManager")
+		.logging("KeyManager", health_reporter.clone())
 		.spawn(scope);
 
 	eth_safe_vault_source
@@ -147,7 +149,7 @@ where
 			state_chain_gateway_address,
 		)
 		.continuous("StateChainGateway".to_string(), db.clone())
-		.logging("StateChainGateway")
+		.logging("StateChainGateway", health_reporter.clone())
 		.spawn(scope);
 
 	eth_safe_vault_source_deposit_addresses
@@ -160,7 +162,7 @@ where
 		)
 		.await?
 		.continuous("USDCDeposits".to_string(), db.clone())
-		.logging("USDCDeposits")
+		.logging("USDCDeposits", health_reporter.clone())
 		.spawn(scope);
 
 	eth_safe_vault_source_deposit_addresses
@@ -173,7 +175,7 @@ where
 		)
 		.await?
 		.continuous("FlipDeposits".to_string(), db.clone())
-		.logging("FlipDeposits")
+		.logging("FlipDeposits", health_reporter.clone())
 		.spawn(scope);
 
 	eth_safe_vault_source_deposit_addresses
@@ -186,7 +188,7 @@ where
 		)
 		.await?
 		.continuous("USDTDeposits".to_string(), db.clone())
-		.logging("USDTDeposits")
+		.logging("USDTDeposits", health_reporter.clone())
 		.spawn(scope);
 
 	eth_safe_vault_source_deposit_addresses
@@ -200,7 +202,7 @@ where
 		)
 		.await
 		.continuous("EthereumDeposits".to_string(), db.clone())
-		.logging("EthereumDeposits")
+		.logging("EthereumDeposits", health_reporter.clone())
 		.spawn(scope);
 
 	eth_safe_vault_source
@@ -213,7 +215,7 @@ where
 			supported_erc20_tokens,
 		)
 		.continuous("Vault".to_string(), db)
-		.logging("Vault")
+		.logging("Vault", health_reporter)
 		.spawn(scope);
 
 	Ok(())
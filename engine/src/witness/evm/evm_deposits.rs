@@ -404,6 +404,7 @@ mod tests {
 					"eth_subscribe",
 					"Ethereum",
 					Ethereum::WITNESS_PERIOD,
+					Default::default(),
 				)
 				.unwrap();
 
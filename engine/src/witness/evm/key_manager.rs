@@ -189,7 +189,9 @@ mod tests {
 	use crate::{
 		evm::{retry_rpc::EvmRetryRpcClient, rpc::EvmRpcClient},
 		settings::{NodeContainer, WsHttpEndpoints},
-		state_chain_observer::client::StateChainClient,
+		state_chain_observer::client::{
+			extrinsic_api::signed::signer::SigningKeySource, StateChainClient,
+		},
 		witness::common::{chain_source::extension::ChainSourceExt, epoch_source::EpochSource},
 	};
 
@@ -200,18 +202,16 @@ mod tests {
 			async {
 				let retry_client = EvmRetryRpcClient::<EvmRpcClient>::new(
 					scope,
-					NodeContainer {
-						primary: WsHttpEndpoints {
-							ws_endpoint: "ws://localhost:8546".into(),
-							http_endpoint: "http://localhost:8545".into(),
-						},
-						backup: None,
-					},
+					NodeContainer::primary_only(WsHttpEndpoints {
+						ws_endpoint: "ws://localhost:8546".into(),
+						http_endpoint: "http://localhost:8545".into(),
+					}),
 					U256::from(1337u64),
 					"eth_rpc",
 					"eth_subscribe",
 					"Ethereum",
 					Ethereum::WITNESS_PERIOD,
+					Default::default(),
 				)
 				.unwrap();
 
@@ -219,7 +219,9 @@ mod tests {
 					StateChainClient::connect_with_account(
 						scope,
 						"ws://localhost:9944",
-						PathBuf::from_str("/some/sc/key/bashful-key").unwrap().as_path(),
+						SigningKeySource::File(
+							PathBuf::from_str("/some/sc/key/bashful-key").unwrap(),
+						),
 						AccountRole::Unregistered,
 						false,
 						false,
@@ -0,0 +1,221 @@
+use cf_primitives::{EpochIndex, PolkadotBlockNumber};
+use futures_core::Future;
+use pallet_cf_ingress_egress::{DepositChannelDetails, DepositWitness};
+use state_chain_runtime::AssethubInstance;
+
+use super::super::common::chunked_chain_source::chunked_by_vault::{
+	builder::ChunkedByVaultBuilder, ChunkedByVault,
+};
+use crate::witness::{
+	common::{
+		chunked_chain_source::chunked_by_vault::deposit_addresses::Addresses, RuntimeCallHasChain,
+		RuntimeHasChain,
+	},
+	hub::EventWrapper,
+};
+use cf_chains::{
+	assets::hub::Asset,
+	dot::{PolkadotAccountId, PolkadotHash},
+	hub::{assets_pallet_id, USDC_ASSET_ID, USDT_ASSET_ID},
+	Assethub,
+};
+use subxt::events::Phase;
+
+impl<Inner: ChunkedByVault> ChunkedByVaultBuilder<Inner> {
+	pub fn hub_deposits<ProcessCall, ProcessingFut>(
+		self,
+		process_call: ProcessCall,
+	) -> ChunkedByVaultBuilder<
+		impl ChunkedByVault<
+			Index = PolkadotBlockNumber,
+			Hash = PolkadotHash,
+			Data = Vec<(Phase, EventWrapper)>,
+			Chain = Assethub,
+			ExtraInfo = PolkadotAccountId,
+			ExtraHistoricInfo = (),
+		>,
+	>
+	where
+		Inner: ChunkedByVault<
+			Index = PolkadotBlockNumber,
+			Hash = PolkadotHash,
+			Data = (Vec<(Phase, EventWrapper)>, Addresses<Inner>),
+			Chain = Assethub,
+			ExtraInfo = PolkadotAccountId,
+			ExtraHistoricInfo = (),
+		>,
+		ProcessCall: Fn(state_chain_runtime::RuntimeCall, EpochIndex) -> ProcessingFut
+			+ Send
+			+ Sync
+			+ Clone
+			+ 'static,
+		ProcessingFut: Future<Output = ()> + Send + 'static,
+		state_chain_runtime::Runtime: RuntimeHasChain<Inner::Chain>,
+		state_chain_runtime::RuntimeCall:
+			RuntimeCallHasChain<state_chain_runtime::Runtime, Inner::Chain>,
+	{
+		self.then(move |epoch, header| {
+			let process_call = process_call.clone();
+			async move {
+				let (events, addresses_and_details) = header.data;
+
+				let addresses = address_and_details_to_addresses(addresses_and_details);
+
+				let deposit_witnesses = deposit_witnesses(addresses, &events);
+
+				if !deposit_witnesses.is_empty() {
+					process_call(
+						pallet_cf_ingress_egress::Call::<_, AssethubInstance>::process_deposits {
+							deposit_witnesses,
+							block_height: header.index,
+						}
+						.into(),
+						epoch.index,
+					)
+					.await
+				}
+
+				events
+			}
+		})
+	}
+}
+
+fn address_and_details_to_addresses(
+	address_and_details: Vec<DepositChannelDetails<state_chain_runtime::Runtime, AssethubInstance>>,
+) -> Vec<(PolkadotAccountId, Asset)> {
+	address_and_details
+		.into_iter()
+		.map(|deposit_channel_details| {
+			(
+				deposit_channel_details.deposit_channel.address,
+				deposit_channel_details.deposit_channel.asset,
+			)
+		})
+		.collect()
+}
+
+/// Returns the deposit witnesses for transfers of a channel's asset to that channel's address.
+/// Native DOT arrives through the Balances pallet, USDT and USDC through the Assets pallet.
+fn deposit_witnesses(
+	monitored_addresses: Vec<(PolkadotAccountId, Asset)>,
+	events: &Vec<(Phase, EventWrapper)>,
+) -> Vec<DepositWitness<Assethub>> {
+	let mut deposit_witnesses = vec![];
+	for (phase, wrapped_event) in events {
+		if let Phase::ApplyExtrinsic(extrinsic_index) = phase {
+			let (to, asset, amount) = match wrapped_event {
+				EventWrapper::Transfer { to, amount, from: _ } => (to, Asset::HubDot, *amount),
+				EventWrapper::AssetsTransferred { asset_id, to, amount, from: _ } => {
+					let asset = match *asset_id {
+						USDT_ASSET_ID => Asset::HubUsdt,
+						USDC_ASSET_ID => Asset::HubUsdc,
+						_ => continue,
+					};
+					debug_assert_eq!(assets_pallet_id(asset), Some(*asset_id));
+					(to, asset, *amount)
+				},
+				_ => continue,
+			};
+			let deposit_address = PolkadotAccountId::from_aliased(to.0);
+			if monitored_addresses.contains(&(deposit_address, asset)) {
+				deposit_witnesses.push(DepositWitness {
+					deposit_address,
+					asset,
+					amount,
+					deposit_details: *extrinsic_index,
+				});
+			}
+		}
+	}
+	deposit_witnesses
+}
+
+#[cfg(test)]
+mod test {
+	use cf_chains::dot::PolkadotBalance;
+
+	use crate::witness::hub::test::phase_and_events;
+
+	use super::*;
+
+	fn mock_transfer(
+		from: &PolkadotAccountId,
+		to: &PolkadotAccountId,
+		amount: PolkadotBalance,
+	) -> EventWrapper {
+		EventWrapper::Transfer {
+			from: from.aliased_ref().to_owned().into(),
+			to: to.aliased_ref().to_owned().into(),
+			amount,
+		}
+	}
+
+	fn mock_assets_transfer(
+		asset_id: u32,
+		from: &PolkadotAccountId,
+		to: &PolkadotAccountId,
+		amount: PolkadotBalance,
+	) -> EventWrapper {
+		EventWrapper::AssetsTransferred {
+			asset_id,
+			from: from.aliased_ref().to_owned().into(),
+			to: to.aliased_ref().to_owned().into(),
+			amount,
+		}
+	}
+
+	#[test]
+	fn witness_deposits_of_the_channel_asset() {
+		let sender = PolkadotAccountId::from_aliased([7; 32]);
+		let dot_address = PolkadotAccountId::from_aliased([1; 32]);
+		let usdt_address = PolkadotAccountId::from_aliased([2; 32]);
+		let usdc_address = PolkadotAccountId::from_aliased([3; 32]);
+
+		let block_event_details = phase_and_events(vec![
+			(1, mock_transfer(&sender, &dot_address, 10_000)),
+			(2, mock_assets_transfer(USDT_ASSET_ID, &sender, &usdt_address, 20_000)),
+			(3, mock_assets_transfer(USDC_ASSET_ID, &sender, &usdc_address, 30_000)),
+			// Wrong asset for the channel.
+			(4, mock_assets_transfer(USDC_ASSET_ID, &sender, &dot_address, 40_000)),
+			(5, mock_transfer(&sender, &usdt_address, 50_000)),
+			// Unsupported asset.
+			(6, mock_assets_transfer(42, &sender, &usdc_address, 60_000)),
+			// Not one of our addresses.
+			(7, mock_assets_transfer(USDT_ASSET_ID, &sender, &sender, 70_000)),
+		]);
+
+		let deposit_witnesses = deposit_witnesses(
+			vec![
+				(dot_address, Asset::HubDot),
+				(usdt_address, Asset::HubUsdt),
+				(usdc_address, Asset::HubUsdc),
+			],
+			&block_event_details,
+		);
+
+		assert_eq!(
+			deposit_witnesses,
+			vec![
+				DepositWitness {
+					deposit_address: dot_address,
+					asset: Asset::HubDot,
+					amount: 10_000,
+					deposit_details: 1,
+				},
+				DepositWitness {
+					deposit_address: usdt_address,
+					asset: Asset::HubUsdt,
+					amount: 20_000,
+					deposit_details: 2,
+				},
+				DepositWitness {
+					deposit_address: usdc_address,
+					asset: Asset::HubUsdc,
+					amount: 30_000,
+					deposit_details: 3,
+				},
+			]
+		);
+	}
+}
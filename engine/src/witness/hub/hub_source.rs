@@ -0,0 +1,107 @@
+use cf_chains::dot::PolkadotHash;
+use cf_primitives::PolkadotBlockNumber;
+use subxt::{events::Events, PolkadotConfig};
+
+use crate::{
+	dot::retry_rpc::{DotRetryRpcApi, DotRetrySubscribeApi},
+	witness::{
+		common::{
+			chain_source::{BoxChainStream, ChainClient, ChainSource},
+			ExternalChainSource,
+		},
+		dot::{DotFinalisedSource, DotUnfinalisedSource},
+	},
+};
+
+// Asset Hub is a Substrate chain with the same header and event types as the relay chain, so we
+// reuse the Polkadot sources, only changing the chain they are witnessing for.
+
+#[derive(Clone)]
+pub struct HubUnfinalisedSource<C>(DotUnfinalisedSource<C>);
+
+impl<C> HubUnfinalisedSource<C> {
+	pub fn new(client: C) -> Self {
+		Self(DotUnfinalisedSource::new(client))
+	}
+}
+
+#[async_trait::async_trait]
+impl<C> ChainSource for HubUnfinalisedSource<C>
+where
+	C: ChainClient<Index = PolkadotBlockNumber, Hash = PolkadotHash, Data = Events<PolkadotConfig>>
+		+ DotRetryRpcApi
+		+ DotRetrySubscribeApi
+		+ Clone
+		+ 'static,
+{
+	type Index = <C as ChainClient>::Index;
+	type Hash = <C as ChainClient>::Hash;
+	type Data = <C as ChainClient>::Data;
+	type Client = C;
+
+	async fn stream_and_client(
+		&self,
+	) -> (BoxChainStream<'_, Self::Index, Self::Hash, Self::Data>, Self::Client) {
+		self.0.stream_and_client().await
+	}
+}
+
+impl<
+		C: ChainClient<
+				Index = PolkadotBlockNumber,
+				Hash = PolkadotHash,
+				Data = Events<PolkadotConfig>,
+			> + DotRetryRpcApi
+			+ DotRetrySubscribeApi
+			+ Clone
+			+ 'static,
+	> ExternalChainSource for HubUnfinalisedSource<C>
+{
+	type Chain = cf_chains::Assethub;
+}
+
+pub struct HubFinalisedSource<C>(DotFinalisedSource<C>);
+
+impl<C> HubFinalisedSource<C> {
+	pub fn new(client: C) -> Self {
+		Self(DotFinalisedSource::new(client))
+	}
+}
+
+#[async_trait::async_trait]
+impl<
+		C: ChainClient<
+				Index = PolkadotBlockNumber,
+				Hash = PolkadotHash,
+				Data = Events<PolkadotConfig>,
+			> + DotRetryRpcApi
+			+ DotRetrySubscribeApi
+			+ Clone
+			+ 'static,
+	> ChainSource for HubFinalisedSource<C>
+{
+	type Index = <C as ChainClient>::Index;
+	type Hash = <C as ChainClient>::Hash;
+	type Data = <C as ChainClient>::Data;
+	type Client = C;
+
+	async fn stream_and_client(
+		&self,
+	) -> (BoxChainStream<'_, Self::Index, Self::Hash, Self::Data>, Self::Client) {
+		self.0.stream_and_client().await
+	}
+}
+
+impl<
+		C: ChainClient<
+				Index = PolkadotBlockNumber,
+				Hash = PolkadotHash,
+				Data = Events<PolkadotConfig>,
+			> + DotRetryRpcApi
+			+ DotRetrySubscribeApi
+			+ Clone
+			+ 'static,
+	> ExternalChainSource for HubFinalisedSource<C>
+{
+	type Chain = cf_chains::Assethub;
+}
@@ -0,0 +1,214 @@
+mod chain_tracking;
+mod deposits;
+mod durable_nonce;
+pub mod source;
+
+use crate::{
+	db::PersistentKeyDB,
+	health::HealthReporter,
+	sol::{
+		retry_rpc::{SolRetryRpcApi, SolRetryRpcClient},
+		rpc::SlotNumber,
+	},
+	state_chain_observer::client::{
+		chain_api::ChainApi,
+		extrinsic_api::signed::SignedExtrinsicApi,
+		storage_api::StorageApi,
+		stream_api::{StreamApi, FINALIZED},
+	},
+};
+use cf_chains::{sol::SolSignature, Chain, Solana};
+use cf_primitives::EpochIndex;
+use futures_core::Future;
+use source::SolSource;
+use std::sync::Arc;
+use utilities::task_scope::Scope;
+
+use super::common::{
+	chain_source::{extension::ChainSourceExt, Header},
+	epoch_source::{EpochSourceBuilder, Vault},
+};
+
+use anyhow::{Context, Result};
+
+pub async fn process_egress<ProcessCall, ProcessingFut, ExtraInfo, ExtraHistoricInfo>(
+	epoch: Vault<Solana, ExtraInfo, ExtraHistoricInfo>,
+	header: Header<SlotNumber, (), ((), Vec<(SolSignature, SlotNumber)>)>,
+	sol_client: impl SolRetryRpcApi,
+	process_call: ProcessCall,
+) where
+	ProcessCall: Fn(state_chain_runtime::RuntimeCall, EpochIndex) -> ProcessingFut
+		+ Send
+		+ Sync
+		+ Clone
+		+ 'static,
+	ProcessingFut: Future<Output = ()> + Send + 'static,
+{
+	let ((), monitored_signatures) = header.data;
+
+	if monitored_signatures.is_empty() {
+		return
+	}
+
+	let monitored_signatures = monitored_signatures
+		.into_iter()
+		.map(|(signature, _)| signature)
+		.collect::<Vec<_>>();
+
+	for signature in success_witnesses(
+		Solana::block_witness_range(header.index),
+		monitored_signatures.clone(),
+		sol_client.signature_statuses(monitored_signatures).await,
+	) {
+		let transaction = sol_client.transaction(signature).await;
+
+		process_call(
+			state_chain_runtime::RuntimeCall::SolanaBroadcaster(
+				pallet_cf_broadcast::Call::transaction_succeeded {
+					tx_out_id: signature,
+					signer_id: epoch.info.0,
+					tx_fee: transaction.meta.map(|meta| meta.fee).unwrap_or_default().into(),
+					tx_metadata: (),
+					transaction_ref: signature,
+				},
+			),
+			epoch.index,
+		)
+		.await;
+	}
+}
+
+pub async fn start<StateChainClient, StateChainStream, ProcessCall, ProcessingFut>(
+	scope: &Scope<'_, anyhow::Error>,
+	sol_client: SolRetryRpcClient,
+	process_call: ProcessCall,
+	state_chain_client: Arc<StateChainClient>,
+	state_chain_stream: StateChainStream,
+	epoch_source: EpochSourceBuilder<'_, '_, StateChainClient, (), ()>,
+	db: Arc<PersistentKeyDB>,
+	health_reporter: HealthReporter,
+) -> Result<()>
+where
+	StateChainClient: StorageApi + ChainApi + SignedExtrinsicApi + 'static + Send + Sync,
+	StateChainStream: StreamApi<FINALIZED> + Clone,
+	ProcessCall: Fn(state_chain_runtime::RuntimeCall, EpochIndex) -> ProcessingFut
+		+ Send
+		+ Sync
+		+ Clone
+		+ 'static,
+	ProcessingFut: Future<Output = ()> + Send + 'static,
+{
+	let sol_source = SolSource::new(sol_client.clone()).strictly_monotonic().shared(scope);
+
+	sol_source
+		.clone()
+		.chunk_by_time(epoch_source.clone(), scope)
+		.chain_tracking(state_chain_client.clone(), sol_client.clone(), health_reporter.clone())
+		.logging("chain tracking")
+		.spawn(scope);
+
+	sol_source
+		.clone()
+		.chunk_by_time(epoch_source.clone(), scope)
+		.latest_then({
+			let sol_client = sol_client.clone();
+			let state_chain_client = state_chain_client.clone();
+			let process_call = process_call.clone();
+			move |epoch, _header| {
+				durable_nonce::witness_durable_nonce(
+					epoch.index,
+					sol_client.clone(),
+					state_chain_client.clone(),
+					process_call.clone(),
+				)
+			}
+		})
+		.logging("durable nonce witnessing")
+		.spawn(scope);
+
+	let usdc_mint = state_chain_client
+		.storage_map_entry::<pallet_cf_environment::SolanaSupportedAssets<state_chain_runtime::Runtime>>(
+			state_chain_client.latest_finalized_block().hash,
+			&cf_primitives::chains::assets::sol::Asset::SolUsdc,
+		)
+		.await
+		.context("Failed to fetch Solana supported assets")?;
+	if usdc_mint.is_none() {
+		tracing::warn!("The Solana USDC mint address is not set, USDC deposits won't be witnessed");
+	}
+
+	let vaults = epoch_source.vaults::<Solana>().await;
+
+	// The source only yields witness ranges that have been finalized, so there is no need to wait
+	// for a safety margin.
+	sol_source
+		.chunk_by_vault(vaults, scope)
+		.deposit_addresses(scope, state_chain_stream.clone(), state_chain_client.clone())
+		.await
+		.sol_deposits(sol_client.clone(), usdc_mint, process_call.clone())
+		.egress_items(scope, state_chain_stream, state_chain_client.clone())
+		.await
+		.then({
+			let process_call = process_call.clone();
+			move |epoch, header| {
+				process_egress(epoch, header, sol_client.clone(), process_call.clone())
+			}
+		})
+		.continuous("Solana".to_string(), db)
+		.logging("witnessing", health_reporter)
+		.spawn(scope);
+
+	Ok(())
+}
+
+/// The monitored signatures of transactions that were successfully executed within `slots`.
+fn success_witnesses(
+	slots: std::ops::RangeInclusive<SlotNumber>,
+	monitored_signatures: Vec<SolSignature>,
+	statuses: Vec<Option<crate::sol::rpc::SignatureStatus>>,
+) -> Vec<SolSignature> {
+	monitored_signatures
+		.into_iter()
+		.zip(statuses)
+		.filter_map(|(signature, status)| {
+			status
+				.filter(|status| {
+					status.is_finalized() && status.err.is_none() && slots.contains(&status.slot)
+				})
+				.map(|_| signature)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sol::rpc::SignatureStatus;
+
+	fn status(slot: SlotNumber, err: Option<serde_json::Value>) -> Option<SignatureStatus> {
+		Some(SignatureStatus { slot, err, confirmation_status: Some("finalized".to_string()) })
+	}
+
+	#[test]
+	fn witnesses_signatures_finalized_within_range() {
+		let signatures = (1..=5u8).map(|i| SolSignature([i; 64])).collect::<Vec<_>>();
+
+		assert_eq!(
+			success_witnesses(
+				15..=29,
+				signatures.clone(),
+				vec![
+					status(20, None),
+					// Not yet seen.
+					None,
+					// Executed before the range.
+					status(14, None),
+					// Failed.
+					status(21, Some(serde_json::json!("Failed"))),
+					status(29, None),
+				],
+			),
+			vec![signatures[0], signatures[4]]
+		);
+	}
+}
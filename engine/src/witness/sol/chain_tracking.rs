@@ -0,0 +1,16 @@
+use crate::sol::retry_rpc::SolRetryRpcApi;
+use cf_chains::sol::SolTrackedData;
+
+use super::super::common::{
+	chain_source::Header, chunked_chain_source::chunked_by_time::chain_tracking::GetTrackedData,
+};
+
+#[async_trait::async_trait]
+impl<T: SolRetryRpcApi + Send + Sync + Clone> GetTrackedData<cf_chains::Solana, (), ()> for T {
+	async fn get_tracked_data(
+		&self,
+		_header: &Header<<cf_chains::Solana as cf_chains::Chain>::ChainBlockNumber, (), ()>,
+	) -> Result<<cf_chains::Solana as cf_chains::Chain>::TrackedData, anyhow::Error> {
+		Ok(SolTrackedData { priority_fee: self.prioritization_fee(vec![]).await.into() })
+	}
+}
@@ -0,0 +1,307 @@
+use std::ops::RangeInclusive;
+
+use cf_primitives::EpochIndex;
+use futures_core::Future;
+use pallet_cf_ingress_egress::{DepositChannelDetails, DepositWitness};
+use state_chain_runtime::SolanaInstance;
+
+use super::super::common::chunked_chain_source::chunked_by_vault::{
+	builder::ChunkedByVaultBuilder, ChunkedByVault,
+};
+use crate::{
+	sol::{retry_rpc::SolRetryRpcApi, rpc::SlotNumber},
+	witness::common::{
+		chunked_chain_source::chunked_by_vault::deposit_addresses::Addresses, RuntimeCallHasChain,
+		RuntimeHasChain,
+	},
+};
+use cf_chains::{assets, sol::SolAddress, Chain, Solana};
+
+impl<Inner: ChunkedByVault> ChunkedByVaultBuilder<Inner> {
+	pub fn sol_deposits<SolRetryRpcClient, ProcessCall, ProcessingFut>(
+		self,
+		sol_client: SolRetryRpcClient,
+		usdc_mint: Option<SolAddress>,
+		process_call: ProcessCall,
+	) -> ChunkedByVaultBuilder<
+		impl ChunkedByVault<Index = SlotNumber, Hash = (), Data = (), Chain = Solana>,
+	>
+	where
+		Inner: ChunkedByVault<
+			Index = SlotNumber,
+			Hash = (),
+			Data = ((), Addresses<Inner>),
+			Chain = Solana,
+		>,
+		SolRetryRpcClient: SolRetryRpcApi + Send + Sync + 'static,
+		ProcessCall: Fn(state_chain_runtime::RuntimeCall, EpochIndex) -> ProcessingFut
+			+ Send
+			+ Sync
+			+ Clone
+			+ 'static,
+		ProcessingFut: Future<Output = ()> + Send + 'static,
+		state_chain_runtime::Runtime: RuntimeHasChain<Inner::Chain>,
+		state_chain_runtime::RuntimeCall:
+			RuntimeCallHasChain<state_chain_runtime::Runtime, Inner::Chain>,
+	{
+		self.then(move |epoch, header| {
+			let sol_client = sol_client.clone();
+			let process_call = process_call.clone();
+			async move {
+				let ((), deposit_channels) = header.data;
+
+				let deposit_witnesses = deposit_witnesses(
+					&sol_client,
+					Solana::block_witness_range(header.index),
+					deposit_channels,
+					usdc_mint,
+				)
+				.await;
+
+				// Submit all deposit witnesses for the witness range.
+				if !deposit_witnesses.is_empty() {
+					process_call(
+						pallet_cf_ingress_egress::Call::<_, SolanaInstance>::process_deposits {
+							deposit_witnesses,
+							block_height: header.index,
+						}
+						.into(),
+						epoch.index,
+					)
+					.await;
+				}
+			}
+		})
+	}
+}
+
+/// Every successful transaction in `slots` that increased the balance of a deposit channel
+/// address is witnessed as a deposit of the increase. For SPL token channels, the deposit address
+/// is a token account and its token balance is used instead of its lamports balance. Token
+/// channels are skipped if the token's mint address isn't known.
+async fn deposit_witnesses<SolRetryRpcClient: SolRetryRpcApi>(
+	sol_client: &SolRetryRpcClient,
+	slots: RangeInclusive<SlotNumber>,
+	deposit_channels: Vec<DepositChannelDetails<state_chain_runtime::Runtime, SolanaInstance>>,
+	usdc_mint: Option<SolAddress>,
+) -> Vec<DepositWitness<Solana>> {
+	let mut deposit_witnesses = Vec::new();
+
+	for details in deposit_channels {
+		let deposit_address = details.deposit_channel.address;
+
+		let token_mint = match details.deposit_channel.asset {
+			assets::sol::Asset::Sol => None,
+			assets::sol::Asset::SolUsdc => match usdc_mint {
+				Some(usdc_mint) => Some(usdc_mint),
+				None => {
+					tracing::warn!(
+						"Not witnessing deposit channel {deposit_address}: the Solana USDC mint address is not set"
+					);
+					continue
+				},
+			},
+		};
+
+		for signature_info in sol_client
+			.signatures_for_address(deposit_address, slots.clone())
+			.await
+			.into_iter()
+			.filter(|signature_info| signature_info.err.is_none())
+		{
+			let transaction = sol_client.transaction(signature_info.signature).await;
+
+			let balance_change = match token_mint {
+				None => transaction.balance_change(&deposit_address),
+				Some(mint) => transaction.token_balance_change(&deposit_address, &mint),
+			};
+
+			if let Some(amount) = balance_change.filter(|balance_change| *balance_change > 0) {
+				deposit_witnesses.push(DepositWitness {
+					deposit_address,
+					asset: details.deposit_channel.asset,
+					amount: amount as <Solana as Chain>::ChainAmount,
+					deposit_details: (),
+				});
+			}
+		}
+	}
+
+	deposit_witnesses
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::sol::{
+		retry_rpc::mocks::MockSolRetryRpcClient,
+		rpc::{ConfirmedTransaction, SignatureInfo},
+	};
+	use cf_chains::{sol::SolSignature, DepositChannel};
+	use mockall::predicate::eq;
+	use pallet_cf_ingress_egress::{BoostStatus, ChannelAction};
+	use serde_json::json;
+	use sp_runtime::AccountId32;
+
+	const USDC_MINT: SolAddress = SolAddress([9u8; 32]);
+
+	fn fake_details(
+		address: SolAddress,
+		asset: assets::sol::Asset,
+	) -> DepositChannelDetails<state_chain_runtime::Runtime, SolanaInstance> {
+		DepositChannelDetails::<_, SolanaInstance> {
+			opened_at: 1,
+			expires_at: 100,
+			deposit_channel: DepositChannel { channel_id: 1, address, asset, state: () },
+			action: ChannelAction::<AccountId32>::LiquidityProvision {
+				lp_account: AccountId32::new([0xab; 32]),
+			},
+			boost_fee: 0,
+			boost_status: BoostStatus::NotBoosted,
+		}
+	}
+
+	fn transfer_to(
+		deposit_address: SolAddress,
+		signature: SolSignature,
+		amount: u64,
+	) -> ConfirmedTransaction {
+		serde_json::from_value(json!({
+			"slot": 20,
+			"meta": {
+				"err": null,
+				"fee": 5000,
+				"preBalances": [1_000_000_000, 0],
+				"postBalances": [1_000_000_000 - amount - 5000, amount],
+			},
+			"transaction": {
+				"signatures": [signature],
+				"message": {
+					"accountKeys": [SolAddress([1u8; 32]), deposit_address],
+				},
+			},
+		}))
+		.unwrap()
+	}
+
+	#[tokio::test]
+	async fn witnesses_successful_transfers_to_deposit_addresses() {
+		const AMOUNT: u64 = 100_000;
+		let deposit_address = SolAddress([2u8; 32]);
+		let successful = SolSignature([1u8; 64]);
+		let failed = SolSignature([2u8; 64]);
+
+		let mut sol_client = MockSolRetryRpcClient::new();
+		sol_client
+			.expect_signatures_for_address()
+			.with(eq(deposit_address), eq(15..=29))
+			.once()
+			.return_once(move |_, _| {
+				vec![
+					SignatureInfo { signature: failed, slot: 16, err: Some(json!("Failed")) },
+					SignatureInfo { signature: successful, slot: 20, err: None },
+				]
+			});
+		sol_client
+			.expect_transaction()
+			.with(eq(successful))
+			.once()
+			.return_once(move |signature| transfer_to(deposit_address, signature, AMOUNT));
+
+		let deposit_witnesses = deposit_witnesses(
+			&sol_client,
+			15..=29,
+			vec![fake_details(deposit_address, assets::sol::Asset::Sol)],
+			Some(USDC_MINT),
+		)
+		.await;
+
+		assert_eq!(
+			deposit_witnesses,
+			vec![DepositWitness {
+				deposit_address,
+				asset: assets::sol::Asset::Sol,
+				amount: AMOUNT.into(),
+				deposit_details: (),
+			}]
+		);
+	}
+
+	#[tokio::test]
+	async fn witnesses_token_transfers_to_usdc_deposit_addresses() {
+		const AMOUNT: u64 = 2_500_000;
+		let deposit_address = SolAddress([2u8; 32]);
+		let signature = SolSignature([1u8; 64]);
+
+		let mut sol_client = MockSolRetryRpcClient::new();
+		sol_client
+			.expect_signatures_for_address()
+			.with(eq(deposit_address), eq(15..=29))
+			.once()
+			.return_once(move |_, _| vec![SignatureInfo { signature, slot: 20, err: None }]);
+		sol_client
+			.expect_transaction()
+			.with(eq(signature))
+			.once()
+			.return_once(move |signature| {
+				serde_json::from_value(json!({
+					"slot": 20,
+					"meta": {
+						"err": null,
+						"fee": 5000,
+						"preBalances": [1_000_000_000, 2_039_280, 2_039_280],
+						"postBalances": [1_000_000_000 - 5000, 2_039_280, 2_039_280],
+						"preTokenBalances": [
+							{ "accountIndex": 1, "mint": USDC_MINT, "uiTokenAmount": { "amount": "10000000" } },
+						],
+						"postTokenBalances": [
+							{ "accountIndex": 1, "mint": USDC_MINT, "uiTokenAmount": { "amount": "7500000" } },
+							{ "accountIndex": 2, "mint": USDC_MINT, "uiTokenAmount": { "amount": "2500000" } },
+						],
+					},
+					"transaction": {
+						"signatures": [signature],
+						"message": {
+							"accountKeys": [SolAddress([1u8; 32]), SolAddress([3u8; 32]), deposit_address],
+						},
+					},
+				}))
+				.unwrap()
+			});
+
+		let deposit_witnesses = deposit_witnesses(
+			&sol_client,
+			15..=29,
+			vec![fake_details(deposit_address, assets::sol::Asset::SolUsdc)],
+			Some(USDC_MINT),
+		)
+		.await;
+
+		assert_eq!(
+			deposit_witnesses,
+			vec![DepositWitness {
+				deposit_address,
+				asset: assets::sol::Asset::SolUsdc,
+				amount: AMOUNT.into(),
+				deposit_details: (),
+			}]
+		);
+	}
+
+	#[tokio::test]
+	async fn skips_usdc_deposit_addresses_without_a_mint() {
+		let mut sol_client = MockSolRetryRpcClient::new();
+		sol_client.expect_signatures_for_address().never();
+		sol_client.expect_transaction().never();
+
+		let deposit_witnesses = deposit_witnesses(
+			&sol_client,
+			15..=29,
+			vec![fake_details(SolAddress([2u8; 32]), assets::sol::Asset::SolUsdc)],
+			None,
+		)
+		.await;
+
+		assert!(deposit_witnesses.is_empty());
+	}
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use cf_primitives::EpochIndex;
+use futures_core::Future;
+
+use crate::{
+	sol::retry_rpc::SolRetryRpcApi,
+	state_chain_observer::client::{
+		chain_api::ChainApi, storage_api::StorageApi, STATE_CHAIN_CONNECTION,
+	},
+};
+
+/// Witnesses the nonce of the durable nonce account whenever it differs from the one recorded on
+/// the State Chain. Each of our transactions advances the nonce, and the next transaction can only
+/// be built once the new nonce has been recorded. The nonce keeps being witnessed until it is
+/// recorded, so that all the witnesses eventually agree on it.
+pub async fn witness_durable_nonce<StateChainClient, ProcessCall, ProcessingFut>(
+	epoch_index: EpochIndex,
+	sol_client: impl SolRetryRpcApi,
+	state_chain_client: Arc<StateChainClient>,
+	process_call: ProcessCall,
+) where
+	StateChainClient: StorageApi + ChainApi + Send + Sync,
+	ProcessCall: Fn(state_chain_runtime::RuntimeCall, EpochIndex) -> ProcessingFut,
+	ProcessingFut: Future<Output = ()> + Send,
+{
+	let Some((nonce_account, recorded_nonce)) = state_chain_client
+		.storage_value::<pallet_cf_environment::SolanaDurableNonce<state_chain_runtime::Runtime>>(
+			state_chain_client.latest_finalized_block().hash,
+		)
+		.await
+		.expect(STATE_CHAIN_CONNECTION)
+	else {
+		return
+	};
+
+	match sol_client.durable_nonce(nonce_account).await {
+		Some(durable_nonce) if durable_nonce != recorded_nonce =>
+			process_call(
+				pallet_cf_environment::Call::witness_sol_durable_nonce {
+					nonce_account,
+					durable_nonce,
+				}
+				.into(),
+				epoch_index,
+			)
+			.await,
+		Some(_) => {},
+		None => tracing::warn!("The Solana durable nonce account {nonce_account} does not exist"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		sol::retry_rpc::mocks::MockSolRetryRpcClient,
+		state_chain_observer::client::{mocks::MockStateChainClient, BlockInfo},
+	};
+	use cf_chains::sol::{SolAddress, SolHash};
+	use std::sync::Mutex;
+
+	type SolanaDurableNonce =
+		pallet_cf_environment::SolanaDurableNonce<state_chain_runtime::Runtime>;
+
+	const NONCE_ACCOUNT: SolAddress = SolAddress([1u8; 32]);
+	const RECORDED_NONCE: SolHash = SolHash([2u8; 32]);
+
+	async fn witnessed_calls(
+		current_nonce: Option<SolHash>,
+	) -> Vec<state_chain_runtime::RuntimeCall> {
+		let mut state_chain_client = MockStateChainClient::new();
+		state_chain_client.expect_latest_finalized_block().return_const(BlockInfo {
+			parent_hash: Default::default(),
+			hash: Default::default(),
+			number: 1,
+		});
+		state_chain_client
+			.expect_storage_value::<SolanaDurableNonce>()
+			.return_once(|_| Ok(Some((NONCE_ACCOUNT, RECORDED_NONCE))));
+
+		let mut sol_client = MockSolRetryRpcClient::new();
+		sol_client.expect_durable_nonce().return_once(move |_| current_nonce);
+
+		let calls = Mutex::new(vec![]);
+		witness_durable_nonce(1, sol_client, Arc::new(state_chain_client), |call, _| {
+			calls.lock().unwrap().push(call);
+			async {}
+		})
+		.await;
+		calls.into_inner().unwrap()
+	}
+
+	#[tokio::test]
+	async fn witnesses_advanced_nonce() {
+		assert_eq!(
+			witnessed_calls(Some(SolHash([3u8; 32]))).await,
+			vec![pallet_cf_environment::Call::witness_sol_durable_nonce {
+				nonce_account: NONCE_ACCOUNT,
+				durable_nonce: SolHash([3u8; 32]),
+			}
+			.into()]
+		);
+	}
+
+	#[tokio::test]
+	async fn does_not_witness_recorded_nonce() {
+		assert_eq!(witnessed_calls(Some(RECORDED_NONCE)).await, vec![]);
+		assert_eq!(witnessed_calls(None).await, vec![]);
+	}
+}
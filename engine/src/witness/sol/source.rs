@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use cf_chains::{Chain, Solana};
+use futures_util::stream;
+use utilities::make_periodic_tick;
+
+use crate::{
+	sol::{retry_rpc::SolRetryRpcApi, rpc::SlotNumber},
+	witness::common::{
+		chain_source::{BoxChainStream, ChainClient, ChainSource, Header},
+		ExternalChainSource,
+	},
+};
+
+/// Yields the witness root of the most recent witness range whose slots have all been finalized.
+#[derive(Clone)]
+pub struct SolSource<C> {
+	client: C,
+}
+
+impl<C> SolSource<C> {
+	pub fn new(client: C) -> Self {
+		Self { client }
+	}
+}
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[async_trait::async_trait]
+impl<C> ChainSource for SolSource<C>
+where
+	C: SolRetryRpcApi + ChainClient<Index = SlotNumber, Hash = (), Data = ()>,
+{
+	type Index = <C as ChainClient>::Index;
+	type Hash = <C as ChainClient>::Hash;
+	type Data = <C as ChainClient>::Data;
+	type Client = C;
+
+	async fn stream_and_client(
+		&self,
+	) -> (BoxChainStream<'_, Self::Index, Self::Hash, Self::Data>, Self::Client) {
+		(
+			Box::pin(stream::unfold(
+				(self.client.clone(), None, make_periodic_tick(POLL_INTERVAL, true)),
+				|(client, last_witness_root_yielded, mut tick)| async move {
+					loop {
+						tick.tick().await;
+
+						let finalized_slot = client.finalized_slot().await;
+						if let Some(witness_root) =
+							Solana::checked_block_witness_previous(finalized_slot.saturating_add(1))
+						{
+							if last_witness_root_yielded != Some(witness_root) {
+								return Some((
+									Header {
+										index: witness_root,
+										hash: (),
+										parent_hash: Some(()),
+										data: (),
+									},
+									(client, Some(witness_root), tick),
+								))
+							}
+						}
+					}
+				},
+			)),
+			self.client.clone(),
+		)
+	}
+}
+
+impl<C> ExternalChainSource for SolSource<C>
+where
+	C: SolRetryRpcApi + ChainClient<Index = SlotNumber, Hash = (), Data = ()> + Clone,
+{
+	type Chain = Solana;
+}
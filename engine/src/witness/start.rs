@@ -7,6 +7,8 @@ use crate::{
 	db::PersistentKeyDB,
 	dot::retry_rpc::DotRetryRpcClient,
 	evm::{retry_rpc::EvmRetryRpcClient, rpc::EvmRpcSigningClient},
+	health::HealthReporter,
+	sol::retry_rpc::SolRetryRpcClient,
 	state_chain_observer::client::{
 		extrinsic_api::signed::SignedExtrinsicApi,
 		storage_api::StorageApi,
@@ -31,10 +33,13 @@ pub async fn start<StateChainClient>(
 	arb_client: EvmRetryRpcClient<EvmRpcSigningClient>,
 	btc_client: BtcRetryRpcClient,
 	dot_client: DotRetryRpcClient,
+	hub_client: DotRetryRpcClient,
+	sol_client: SolRetryRpcClient,
 	state_chain_client: Arc<StateChainClient>,
 	state_chain_stream: impl StreamApi<FINALIZED> + Clone,
 	unfinalised_state_chain_stream: impl StreamApi<UNFINALIZED> + Clone,
 	db: Arc<PersistentKeyDB>,
+	health_reporter: HealthReporter,
 ) -> Result<()>
 where
 	StateChainClient: StorageApi + ChainApi + SignedExtrinsicApi + 'static + Send + Sync,
@@ -88,6 +93,7 @@ where
 		state_chain_stream.clone(),
 		epoch_source.clone(),
 		db.clone(),
+		health_reporter.clone(),
 	);
 
 	let start_btc = super::btc::start(
@@ -100,6 +106,7 @@ where
 		unfinalised_state_chain_stream.clone(),
 		epoch_source.clone(),
 		db.clone(),
+		health_reporter.clone(),
 	);
 
 	let start_dot = super::dot::start(
@@ -110,19 +117,43 @@ where
 		state_chain_stream.clone(),
 		epoch_source.clone(),
 		db.clone(),
+		health_reporter.clone(),
+	);
+
+	let start_hub = super::hub::start(
+		scope,
+		hub_client,
+		witness_call.clone(),
+		state_chain_client.clone(),
+		state_chain_stream.clone(),
+		epoch_source.clone(),
+		db.clone(),
+		health_reporter.clone(),
 	);
 
 	let start_arb = super::arb::start(
 		scope,
 		arb_client,
+		witness_call.clone(),
+		state_chain_client.clone(),
+		state_chain_stream.clone(),
+		epoch_source.clone(),
+		db.clone(),
+		health_reporter.clone(),
+	);
+
+	let start_sol = super::sol::start(
+		scope,
+		sol_client,
 		witness_call,
 		state_chain_client.clone(),
 		state_chain_stream.clone(),
 		epoch_source.clone(),
 		db.clone(),
+		health_reporter,
 	);
 
-	futures::future::try_join4(start_eth, start_btc, start_dot, start_arb).await?;
+	futures::try_join!(start_eth, start_btc, start_dot, start_hub, start_arb, start_sol)?;
 
 	Ok(())
 }
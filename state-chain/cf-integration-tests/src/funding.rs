@@ -7,13 +7,18 @@ use crate::{
 
 use super::{genesis, network, *};
 use cf_primitives::{AccountRole, GENESIS_EPOCH};
-use cf_traits::{offence_reporting::OffenceReporter, AccountInfo, Bid, EpochInfo};
+use cf_traits::{
+	offence_reporting::OffenceReporter, AccountInfo, Bid, DelegationApi, EpochInfo, Slashing,
+};
 use mock_runtime::MIN_FUNDING;
 use pallet_cf_funding::pallet::Error;
 use pallet_cf_validator::{Backups, CurrentRotationPhase};
-use sp_runtime::{FixedPointNumber, FixedU64};
+use sp_runtime::{FixedPointNumber, FixedU64, Percent};
 use state_chain_runtime::{
-	chainflip::{backup_node_rewards::calculate_backup_rewards, calculate_account_apy, Offence},
+	chainflip::{
+		backup_node_rewards::calculate_backup_rewards, calculate_account_apy,
+		DelegatedStakeSlasher, Offence,
+	},
 	RuntimeEvent,
 };
 
@@ -327,3 +332,62 @@ fn backup_rewards_event_gets_emitted_on_heartbeat_interval() {
 			);
 		});
 }
+
+#[test]
+fn operator_backed_by_delegated_funds_is_slashed() {
+	super::genesis::with_test_defaults().build().execute_with(|| {
+		let (mut testnet, _) = network::Network::create(0, &Validator::current_authorities());
+		let operator = AccountId::from(ALICE);
+		let delegator = AccountId::from([0xde; 32]);
+
+		// The operator's bid is mostly backed by delegated funds, and the other authorities'
+		// bids are high enough that the bond is well above the operator's own funds.
+		const DELEGATED: FlipBalance = GENESIS_BALANCE * 9;
+		testnet.state_chain_gateway_contract.fund_account(
+			delegator.clone(),
+			DELEGATED,
+			GENESIS_EPOCH,
+		);
+		for node in [BOB, CHARLIE] {
+			testnet.state_chain_gateway_contract.fund_account(
+				AccountId::from(node),
+				DELEGATED + GENESIS_BALANCE,
+				GENESIS_EPOCH,
+			);
+		}
+		testnet.move_forward_blocks(1);
+
+		assert_ok!(Funding::set_operator_commission(
+			RuntimeOrigin::signed(operator.clone()),
+			Percent::from_percent(10)
+		));
+		assert_ok!(Funding::delegate(
+			RuntimeOrigin::signed(delegator.clone()),
+			operator.clone(),
+			DELEGATED
+		));
+
+		testnet.move_to_the_next_epoch();
+		assert!(Validator::current_authorities().contains(&operator));
+		// Bonding the full bid would leave the operator unslashable.
+		assert!(Validator::bond() > Flip::balance(&operator) * 5);
+
+		// Only the operator's own funds are bonded.
+		assert_eq!(Flip::bond(&operator), Validator::bond() - DELEGATED);
+
+		let operator_balance = Flip::balance(&operator);
+		let delegator_balance = Flip::balance(&delegator);
+		const BLOCKS_OFFLINE: BlockNumber = 100;
+		let slash = DelegatedStakeSlasher::calculate_slash_amount(&operator, BLOCKS_OFFLINE);
+		let shares = <Funding as DelegationApi>::slash_shares(&operator, slash);
+		assert_eq!(shares.len(), 2);
+		let (_, operator_share) = shares[0];
+		let (_, delegator_share) = shares[1];
+		assert!(operator_share > 0);
+
+		DelegatedStakeSlasher::slash(&operator, BLOCKS_OFFLINE);
+
+		assert_eq!(Flip::balance(&operator), operator_balance - operator_share);
+		assert_eq!(Flip::balance(&delegator), delegator_balance - delegator_share);
+	});
+}
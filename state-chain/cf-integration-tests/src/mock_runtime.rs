@@ -8,9 +8,10 @@ use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_runtime::{Percent, Permill};
 use state_chain_runtime::{
 	chainflip::Offence, constants::common::*, opaque::SessionKeys, test_runner::*, AccountId,
-	AccountRolesConfig, ArbitrumChainTrackingConfig, ArbitrumVaultConfig, EmissionsConfig,
-	EthereumVaultConfig, EvmThresholdSignerConfig, FlipConfig, FundingConfig, GovernanceConfig,
-	ReputationConfig, SessionConfig, SolanaChainTrackingConfig, SolanaVaultConfig, ValidatorConfig,
+	AccountRolesConfig, ArbitrumChainTrackingConfig, ArbitrumVaultConfig,
+	AssethubChainTrackingConfig, AssethubVaultConfig, EmissionsConfig, EthereumVaultConfig,
+	EvmThresholdSignerConfig, FlipConfig, FundingConfig, GovernanceConfig, ReputationConfig,
+	SessionConfig, SolanaChainTrackingConfig, SolanaVaultConfig, ValidatorConfig,
 };
 
 use cf_chains::{
@@ -19,7 +20,7 @@ use cf_chains::{
 	dot::{PolkadotTrackedData, RuntimeVersion},
 	eth::EthereumTrackedData,
 	sol::SolTrackedData,
-	Arbitrum, Bitcoin, ChainState, Ethereum, Polkadot, Solana,
+	Arbitrum, Assethub, Bitcoin, ChainState, Ethereum, Polkadot, Solana,
 };
 use state_chain_runtime::{
 	BitcoinChainTrackingConfig, EthereumChainTrackingConfig, PolkadotChainTrackingConfig,
@@ -205,6 +206,10 @@ impl ExtBuilder {
 				chain_initialized: false,
 			},
 			solana_vault: SolanaVaultConfig { deployment_block: None, chain_initialized: false },
+			assethub_vault: AssethubVaultConfig {
+				deployment_block: None,
+				chain_initialized: false,
+			},
 			emissions: EmissionsConfig {
 				current_authority_emission_inflation: CURRENT_AUTHORITY_EMISSION_INFLATION_PERBILL,
 				backup_node_emission_inflation: BACKUP_NODE_EMISSION_INFLATION_PERBILL,
@@ -255,6 +260,18 @@ impl ExtBuilder {
 					},
 				},
 			},
+			assethub_chain_tracking: AssethubChainTrackingConfig {
+				init_chain_state: ChainState::<Assethub> {
+					block_height: 0,
+					tracked_data: PolkadotTrackedData {
+						median_tip: 0,
+						runtime_version: RuntimeVersion {
+							spec_version: 17,
+							transaction_version: 17,
+						},
+					},
+				},
+			},
 			solana_chain_tracking: SolanaChainTrackingConfig {
 				init_chain_state: ChainState::<Solana> {
 					block_height: 0,
@@ -282,6 +299,7 @@ impl ExtBuilder {
 			ethereum_ingress_egress: Default::default(),
 			arbitrum_ingress_egress: Default::default(),
 			solana_ingress_egress: Default::default(),
+			assethub_ingress_egress: Default::default(),
 		})
 	}
 }
@@ -20,9 +20,9 @@ use pallet_cf_funding::{MinimumFunding, RedemptionAmount};
 use sp_consensus_aura::SlotDuration;
 use sp_std::collections::btree_set::BTreeSet;
 use state_chain_runtime::{
-	AccountRoles, AllPalletsWithSystem, ArbitrumInstance, BitcoinInstance, Funding,
-	LiquidityProvider, PalletExecutionOrder, PolkadotInstance, Runtime, RuntimeCall, RuntimeEvent,
-	RuntimeOrigin, Validator, Weight,
+	AccountRoles, AllPalletsWithSystem, ArbitrumInstance, AssethubInstance, BitcoinInstance,
+	Funding, LiquidityProvider, PalletExecutionOrder, PolkadotInstance, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeOrigin, Validator, Weight,
 };
 use std::{
 	cell::RefCell,
@@ -269,6 +269,18 @@ impl Engine {
 							pallet_cf_governance::RawOrigin::GovernanceApproval.into()
 						);
 					}
+					RuntimeEvent::AssethubVault(pallet_cf_vaults::Event::<_, AssethubInstance>::AwaitingGovernanceActivation { .. }) => {
+						queue_dispatch_extrinsic(
+							RuntimeCall::Environment(pallet_cf_environment::Call::witness_assethub_vault_creation {
+								hub_pure_proxy_vault_key: Default::default(),
+								tx_id: TxId {
+									block_number: 1,
+									extrinsic_index: 0,
+								},
+							}),
+							pallet_cf_governance::RawOrigin::GovernanceApproval.into()
+						);
+					}
 					RuntimeEvent::BitcoinVault(pallet_cf_vaults::Event::<_, BitcoinInstance>::AwaitingGovernanceActivation { new_public_key }) => {
 						queue_dispatch_extrinsic(
 							RuntimeCall::Environment(pallet_cf_environment::Call::witness_current_bitcoin_block_number_for_key {
@@ -47,6 +47,7 @@ use state_chain_runtime::{
 		address_derivation::AddressDerivation, ChainAddressConverter, EthTransactionBuilder,
 		EvmEnvironment,
 	},
+	runtime_apis::{runtime_decl_for_custom_runtime_api::CustomRuntimeApiV1, CcmDepositCheck},
 	EthereumBroadcaster, EthereumChainTracking, EthereumIngressEgress, EthereumInstance,
 	LiquidityPools, LiquidityProvider, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, Swapping,
 	System, Timestamp, Validator, Weight, Witnesser,
@@ -269,6 +270,7 @@ fn basic_pool_setup_provision_and_swap() {
 			0u16,
 			Default::default(),
 			None,
+			None,
 		));
 
 		let deposit_address = <AddressDerivation as AddressDerivationApi<Ethereum>>::generate_address(
@@ -379,6 +381,7 @@ fn can_process_ccm_via_swap_deposit_address() {
 			0u16,
 			Default::default(),
 			None,
+			None,
 		));
 
 		// Deposit funds for the ccm.
@@ -792,6 +795,126 @@ fn ethereum_ccm_can_calculate_gas_limits() {
 	});
 }
 
+#[test]
+fn ccm_deposit_check_caps_the_gas_budget_at_the_max_gas_limit() {
+	super::genesis::with_test_defaults().build().execute_with(|| {
+		witness_call(RuntimeCall::EthereumChainTracking(
+			pallet_cf_chain_tracking::Call::update_chain_state {
+				new_chain_state: ChainState::<Ethereum> {
+					block_height: 1,
+					tracked_data: EthereumTrackedData {
+						base_fee: 1_000_000u128,
+						priority_fee: 500_000u128,
+					},
+				},
+			},
+		));
+
+		let usable_gas_budget = |gas_budget: AssetAmount| {
+			let Ok(CcmDepositCheck::Accepted {
+				gas_budget: accepted_gas_budget,
+				estimated_destination_gas_budget,
+				..
+			}) = Runtime::cf_ccm_deposit_check(
+				Asset::Eth,
+				Asset::Eth,
+				10u128.pow(18),
+				CcmChannelMetadata {
+					message: vec![0u8, 1u8, 2u8, 3u8].try_into().unwrap(),
+					gas_budget,
+					cf_parameters: Default::default(),
+				},
+			)
+			else {
+				panic!("The CCM deposit should be accepted");
+			};
+			assert_eq!(accepted_gas_budget, gas_budget);
+			estimated_destination_gas_budget
+		};
+
+		// Each unit of gas costs 1 * 1_000_000 + 500_000 = 1_500_000, so the maximum gas limit of
+		// 10_000_000 costs 15_000_000_000_000.
+		assert_eq!(usable_gas_budget(1_000_000_000_000), 1_000_000_000_000);
+		assert_eq!(usable_gas_budget(15_000_000_000_000), 15_000_000_000_000);
+		assert_eq!(usable_gas_budget(20_000_000_000_000), 15_000_000_000_000);
+	});
+}
+
+#[test]
+fn ccm_is_delivered_once_its_broadcast_is_witnessed() {
+	const EPOCH_BLOCKS: u32 = 1000;
+	const MAX_AUTHORITIES: AuthorityCount = 10;
+	super::genesis::with_test_defaults()
+		.blocks_per_epoch(EPOCH_BLOCKS)
+		.max_authorities(MAX_AUTHORITIES)
+		.build()
+		.execute_with(|| {
+			let (mut testnet, _genesis, _backup_nodes) =
+				fund_authorities_and_join_auction(MAX_AUTHORITIES);
+			testnet.move_to_the_next_epoch();
+
+			let witness_broadcast_success = |broadcast_id| {
+				witness_call(RuntimeCall::EthereumBroadcaster(
+					pallet_cf_broadcast::Call::transaction_succeeded {
+						tx_out_id: AwaitingBroadcast::<Runtime, Instance1>::get(broadcast_id)
+							.unwrap()
+							.transaction_out_id,
+						signer_id: Default::default(),
+						tx_fee: TransactionFee {
+							effective_gas_price: Default::default(),
+							gas_used: Default::default(),
+						},
+						tx_metadata: Default::default(),
+						transaction_ref: Default::default(),
+					},
+				));
+			};
+
+			// Broadcast id 1 is the rotation transaction, which is a barrier broadcast.
+			witness_broadcast_success(1);
+			setup_pool_and_accounts(vec![Asset::Eth, Asset::Flip], OrderType::LimitOrder);
+
+			witness_call(RuntimeCall::Swapping(pallet_cf_swapping::Call::ccm_deposit {
+				source_asset: Asset::Flip,
+				deposit_amount: 10_000,
+				destination_asset: Asset::Usdc,
+				destination_address: EncodedAddress::Eth([0x02; 20]),
+				deposit_metadata: CcmDepositMetadata {
+					source_chain: ForeignChain::Ethereum,
+					source_address: Some(ForeignChainAddress::Eth([0xcf; 20].into())),
+					channel_metadata: CcmChannelMetadata {
+						message: vec![0u8, 1u8, 2u8, 3u8, 4u8].try_into().unwrap(),
+						gas_budget: 1_000,
+						cf_parameters: Default::default(),
+					},
+				},
+				tx_hash: Default::default(),
+			}));
+
+			// Process the swap -> egress -> threshold sign -> broadcast
+			testnet.move_forward_blocks(3);
+			let broadcast_id = BroadcastIdCounter::<Runtime, Instance1>::get();
+			assert!(RequestSuccessCallbacks::<Runtime, Instance1>::get(broadcast_id).is_some());
+
+			// The success callback is dispatched with the witnessed origin.
+			witness_broadcast_success(broadcast_id);
+			assert_events_match!(
+				Runtime,
+				RuntimeEvent::EthereumBroadcaster(
+					pallet_cf_broadcast::Event::BroadcastCallbackExecuted {
+						broadcast_id: id,
+						result: Ok(()),
+					},
+				) if id == broadcast_id => (),
+				RuntimeEvent::EthereumIngressEgress(
+					pallet_cf_ingress_egress::Event::CcmDelivered {
+						egress_id: (ForeignChain::Ethereum, _),
+					},
+				) => ()
+			);
+		});
+}
+
 #[test]
 fn can_resign_failed_ccm() {
 	const EPOCH_BLOCKS: u32 = 1000;
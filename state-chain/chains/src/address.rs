@@ -8,7 +8,7 @@ use crate::{
 	Chain,
 };
 use cf_primitives::{
-	chains::{Arbitrum, Bitcoin, Ethereum, Polkadot, Solana},
+	chains::{Arbitrum, Assethub, Bitcoin, Ethereum, Polkadot, Solana},
 	ChannelId, ForeignChain, NetworkEnvironment,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
@@ -25,6 +25,8 @@ pub enum AddressDerivationError {
 	MissingBitcoinVault,
 	BitcoinChannelIdTooLarge,
 	SolanaDerivationError(sol::AddressDerivationError),
+	MissingSolanaTokenMint,
+	MissingAssethubVault,
 }
 
 impl From<sol::AddressDerivationError> for AddressDerivationError {
@@ -67,6 +69,7 @@ pub enum ForeignChainAddress {
 	Btc(ScriptPubkey),
 	Arb(EvmAddress),
 	Sol(SolAddress),
+	Hub(PolkadotAccountId),
 }
 
 impl ForeignChainAddress {
@@ -77,6 +80,7 @@ impl ForeignChainAddress {
 			ForeignChainAddress::Btc(_) => ForeignChain::Bitcoin,
 			ForeignChainAddress::Arb(_) => ForeignChain::Arbitrum,
 			ForeignChainAddress::Sol(_) => ForeignChain::Solana,
+			ForeignChainAddress::Hub(_) => ForeignChain::Assethub,
 		}
 	}
 }
@@ -88,6 +92,7 @@ pub enum EncodedAddress {
 	Btc(Vec<u8>),
 	Arb([u8; 20]),
 	Sol([u8; crate::sol::consts::SOLANA_ADDRESS_SIZE]),
+	Hub([u8; 32]),
 }
 
 pub trait AddressConverter: Sized {
@@ -103,7 +108,8 @@ impl core::fmt::Display for EncodedAddress {
 		match self {
 			EncodedAddress::Eth(addr) | EncodedAddress::Arb(addr) =>
 				write!(f, "0x{}", hex::encode(&addr[..])),
-			EncodedAddress::Dot(addr) => write!(f, "0x{}", hex::encode(&addr[..])),
+			EncodedAddress::Dot(addr) | EncodedAddress::Hub(addr) =>
+				write!(f, "0x{}", hex::encode(&addr[..])),
 			EncodedAddress::Btc(addr) => write!(
 				f,
 				"{}",
@@ -136,7 +142,7 @@ impl TryFrom<ForeignChainAddress> for PolkadotAccountId {
 
 	fn try_from(address: ForeignChainAddress) -> Result<Self, Self::Error> {
 		match address {
-			ForeignChainAddress::Dot(addr) => Ok(addr),
+			ForeignChainAddress::Dot(addr) | ForeignChainAddress::Hub(addr) => Ok(addr),
 			_ => Err(AddressError::InvalidAddress),
 		}
 	}
@@ -175,6 +181,12 @@ impl IntoForeignChainAddress<Polkadot> for PolkadotAccountId {
 	}
 }
 
+impl IntoForeignChainAddress<Assethub> for PolkadotAccountId {
+	fn into_foreign_chain_address(address: PolkadotAccountId) -> ForeignChainAddress {
+		ForeignChainAddress::Hub(address)
+	}
+}
+
 impl IntoForeignChainAddress<Bitcoin> for ScriptPubkey {
 	fn into_foreign_chain_address(address: ScriptPubkey) -> ForeignChainAddress {
 		ForeignChainAddress::Btc(address)
@@ -218,6 +230,14 @@ impl EncodedAddress {
 			ForeignChain::Solana => Ok(EncodedAddress::Sol(
 				bytes.try_into().map_err(|_| "Invalid Solana address length")?,
 			)),
+			ForeignChain::Assethub => {
+				if bytes.len() != 32 {
+					return Err("Invalid Assethub address length")
+				}
+				let mut address = [0u8; 32];
+				address.copy_from_slice(&bytes);
+				Ok(EncodedAddress::Hub(address))
+			},
 		}
 	}
 }
@@ -234,6 +254,7 @@ pub fn to_encoded_address<GetNetwork: FnOnce() -> NetworkEnvironment>(
 		),
 		ForeignChainAddress::Arb(address) => EncodedAddress::Arb(address.0),
 		ForeignChainAddress::Sol(address) => EncodedAddress::Sol(address.into()),
+		ForeignChainAddress::Hub(address) => EncodedAddress::Hub(*address.aliased_ref()),
 	}
 }
 
@@ -255,6 +276,8 @@ pub fn try_from_encoded_address<GetNetwork: FnOnce() -> NetworkEnvironment>(
 			.map_err(|_| ())?,
 		)),
 		EncodedAddress::Sol(address_bytes) => Ok(ForeignChainAddress::Sol(address_bytes.into())),
+		EncodedAddress::Hub(address_bytes) =>
+			Ok(ForeignChainAddress::Hub(PolkadotAccountId::from_aliased(address_bytes))),
 	}
 }
 
@@ -311,6 +334,7 @@ pub enum ForeignChainAddressHumanreadable {
 	Btc(<ScriptPubkey as ToHumanreadableAddress>::Humanreadable),
 	Arb(<EvmAddress as ToHumanreadableAddress>::Humanreadable),
 	Sol(<SolAddress as ToHumanreadableAddress>::Humanreadable),
+	Hub(<PolkadotAccountId as ToHumanreadableAddress>::Humanreadable),
 }
 
 #[cfg(feature = "std")]
@@ -340,6 +364,8 @@ impl ToHumanreadableAddress for ForeignChainAddress {
 				ForeignChainAddressHumanreadable::Arb(address.to_humanreadable(network_environment)),
 			ForeignChainAddress::Sol(address) =>
 				ForeignChainAddressHumanreadable::Sol(address.to_humanreadable(network_environment)),
+			ForeignChainAddress::Hub(address) =>
+				ForeignChainAddressHumanreadable::Hub(address.to_humanreadable(network_environment)),
 		}
 	}
 }
@@ -87,6 +87,10 @@ pub mod fees {
 	pub const GAS_COST_PER_FETCH: u128 = 30_000;
 	pub const GAS_COST_PER_TRANSFER_NATIVE: u128 = 20_000;
 	pub const GAS_COST_PER_TRANSFER_TOKEN: u128 = 40_000;
+	/// Gas used by the Vault's `executexSwapAndCall` on top of the receiver's gas.
+	pub const CCM_BASE_GAS: u128 = 100_000;
+	/// Calldata cost of each (non-zero) byte of the CCM message.
+	pub const CCM_GAS_PER_MESSAGE_BYTE: u128 = 16;
 }
 
 impl FeeEstimationApi<Arbitrum> for ArbitrumTrackedData {
@@ -123,6 +127,20 @@ impl FeeEstimationApi<Arbitrum> for ArbitrumTrackedData {
 		self.base_fee
 			.saturating_mul(self.gas_limit_multiplier.saturating_mul_int(gas_cost_per_transfer))
 	}
+
+	fn estimate_ccm_gas_budget(
+		&self,
+		gas_limit: u128,
+		message_length: u32,
+	) -> Option<<Arbitrum as Chain>::ChainAmount> {
+		use crate::arb::fees::*;
+
+		let gas = CCM_BASE_GAS
+			.saturating_add(CCM_GAS_PER_MESSAGE_BYTE.saturating_mul(message_length.into()))
+			.saturating_add(gas_limit);
+
+		Some(self.base_fee.saturating_mul(self.gas_limit_multiplier.saturating_mul_int(gas)))
+	}
 }
 
 impl From<&DepositChannel<Arbitrum>> for EvmFetchId {
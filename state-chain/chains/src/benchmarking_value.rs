@@ -1,5 +1,5 @@
 #[cfg(feature = "runtime-benchmarks")]
-use cf_primitives::chains::assets::sol;
+use cf_primitives::chains::assets::{hub, sol};
 #[cfg(feature = "runtime-benchmarks")]
 use cf_primitives::{
 	chains::assets::{btc, dot, eth},
@@ -98,6 +98,13 @@ impl BenchmarkValue for sol::Asset {
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkValue for hub::Asset {
+	fn benchmark_value() -> Self {
+		hub::Asset::HubDot
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl BenchmarkValue for ForeignChainAddress {
 	fn benchmark_value() -> Self {
@@ -2,6 +2,7 @@ pub mod api;
 pub mod benchmarking;
 pub mod deposit_address;
 pub mod utxo_selection;
+pub mod vault_swap_encoding;
 
 extern crate alloc;
 use self::deposit_address::DepositAddress;
@@ -44,6 +45,10 @@ pub const MAX_BITCOIN_SCRIPT_LENGTH: u32 = 128;
 // limit
 pub const BITCOIN_DUST_LIMIT: u64 = 600;
 
+// The maximum amount of data that can be carried by an OP_RETURN output according to the standard
+// relay policy.
+pub const MAX_OP_RETURN_DATA_BYTES: u32 = 80;
+
 pub type BlockNumber = u64;
 
 #[derive(Encode, Decode, TypeInfo, Clone, RuntimeDebug, PartialEq, Eq, Copy)]
@@ -145,6 +150,25 @@ impl FeeEstimationApi<Bitcoin> for BitcoinTrackedData {
 			.saturating_add(self.btc_fee_info.fee_per_output_utxo())
 			.saturating_add(self.btc_fee_info.fee_per_output_utxo())
 	}
+
+	// A CCM is a regular egress with an additional OP_RETURN output carrying the message. There is
+	// no receiver to pay gas for, so the gas limit is ignored.
+	fn estimate_ccm_gas_budget(
+		&self,
+		_gas_limit: u128,
+		message_length: u32,
+	) -> Option<<Bitcoin as Chain>::ChainAmount> {
+		// Amount (8 bytes), script length (1 byte), OP_RETURN and the push opcodes (3 bytes).
+		const OP_RETURN_OUTPUT_OVERHEAD_IN_BYTES: BtcAmount = 12;
+
+		Some(
+			self.estimate_egress_fee(assets::btc::Asset::Btc).saturating_add(
+				self.btc_fee_info.sats_per_kilobyte().saturating_mul(
+					OP_RETURN_OUTPUT_OVERHEAD_IN_BYTES + message_length as BtcAmount,
+				) / BYTES_PER_BTC_KILOBYTE,
+			),
+		)
+	}
 }
 
 /// A record of the Bitcoin transaction fee.
@@ -533,7 +557,13 @@ pub enum ScriptPubkey {
 	P2WPKH([u8; 20]),
 	P2WSH([u8; 32]),
 	Taproot([u8; 32]),
-	OtherSegwit { version: u8, program: BoundedVec<u8, ConstU32<MAX_SEGWIT_PROGRAM_BYTES>> },
+	OtherSegwit {
+		version: u8,
+		program: BoundedVec<u8, ConstU32<MAX_SEGWIT_PROGRAM_BYTES>>,
+	},
+	/// An unspendable output that carries arbitrary data. Not an address, only used for outputs
+	/// of transactions we build ourselves.
+	OpReturn(BoundedVec<u8, ConstU32<MAX_OP_RETURN_DATA_BYTES>>),
 }
 
 impl SerializeBtc for ScriptPubkey {
@@ -577,6 +607,10 @@ impl ScriptPubkey {
 				BitcoinOp::PushVersion { version: *version },
 				BitcoinOp::PushBytes { bytes: program.clone() },
 			]),
+			ScriptPubkey::OpReturn(data) => BitcoinScript::new(&[
+				BitcoinOp::Return,
+				BitcoinOp::PushData { bytes: data.clone() },
+			]),
 		}
 	}
 
@@ -586,6 +620,7 @@ impl ScriptPubkey {
 
 	pub fn to_address(&self, network: &BitcoinNetwork) -> String {
 		let (data, maybe_bech, version) = match self {
+			ScriptPubkey::OpReturn(_) => return String::from("OP_RETURN"),
 			ScriptPubkey::P2PKH(data) => (&data[..], None, network.p2pkh_address_version()),
 			ScriptPubkey::P2SH(data) => (&data[..], None, network.p2sh_address_version()),
 			ScriptPubkey::P2WPKH(data) => (&data[..], Some(Variant::Bech32), SEGWIT_VERSION_ZERO),
@@ -703,15 +738,24 @@ fn extend_with_inputs_outputs(bytes: &mut Vec<u8>, inputs: &[Utxo], outputs: &[B
 	outputs.btc_encode_to(bytes);
 }
 
+fn unsigned_transaction_bytes(inputs: &[Utxo], outputs: &[BitcoinOutput]) -> Vec<u8> {
+	const SEGWIT_MARKER: u8 = 0u8;
+	const SEGWIT_FLAG: u8 = 1u8;
+
+	let mut transaction_bytes = Vec::default();
+	transaction_bytes.extend(VERSION);
+	transaction_bytes.push(SEGWIT_MARKER);
+	transaction_bytes.push(SEGWIT_FLAG);
+	extend_with_inputs_outputs(&mut transaction_bytes, inputs, outputs);
+	transaction_bytes
+}
+
 impl BitcoinTransaction {
 	pub fn create_new_unsigned(
 		agg_key: &AggKey,
 		inputs: Vec<Utxo>,
 		outputs: Vec<BitcoinOutput>,
 	) -> Self {
-		const SEGWIT_MARKER: u8 = 0u8;
-		const SEGWIT_FLAG: u8 = 1u8;
-
 		let old_utxo_input_indices = (0..)
 			.zip(&inputs)
 			.filter_map(|(i, Utxo { deposit_address, .. })| {
@@ -727,14 +771,49 @@ impl BitcoinTransaction {
 			})
 			.collect::<VecDeque<_>>();
 
-		let mut transaction_bytes = Vec::default();
-		transaction_bytes.extend(VERSION);
-		transaction_bytes.push(SEGWIT_MARKER);
-		transaction_bytes.push(SEGWIT_FLAG);
-		extend_with_inputs_outputs(&mut transaction_bytes, &inputs, &outputs);
+		let transaction_bytes = unsigned_transaction_bytes(&inputs, &outputs);
 		Self { inputs, outputs, signatures: vec![], transaction_bytes, old_utxo_input_indices }
 	}
 
+	/// Creates an unsigned transaction that spends the same inputs as this one, with the given
+	/// outputs instead. Since both transactions spend the same inputs, at most one of them can be
+	/// included in a block.
+	pub fn with_outputs(&self, outputs: Vec<BitcoinOutput>) -> Self {
+		Self {
+			inputs: self.inputs.clone(),
+			transaction_bytes: unsigned_transaction_bytes(&self.inputs, &outputs),
+			outputs,
+			signatures: vec![],
+			old_utxo_input_indices: self.old_utxo_input_indices.clone(),
+		}
+	}
+
+	pub fn inputs(&self) -> &[Utxo] {
+		&self.inputs
+	}
+
+	/// The fee paid by this transaction: the value of the inputs that is not spent by the outputs.
+	pub fn fee(&self) -> BtcAmount {
+		self.inputs
+			.iter()
+			.map(|input| input.amount)
+			.sum::<BtcAmount>()
+			.saturating_sub(self.outputs.iter().map(|output| output.amount).sum())
+	}
+
+	/// The fee that this transaction should pay, given the fee rate in `fee_info`. This is
+	/// estimated in the same way as when selecting the inputs for a new transaction.
+	pub fn estimated_fee(&self, fee_info: &BitcoinFeeInfo) -> BtcAmount {
+		self.inputs
+			.iter()
+			.map(|input| fee_info.fee_for_utxo(input))
+			.sum::<BtcAmount>()
+			.saturating_add(
+				fee_info.fee_per_output_utxo().saturating_mul(self.outputs.len() as BtcAmount),
+			)
+			.saturating_add(fee_info.min_fee_required_per_tx())
+	}
+
 	pub fn add_signatures(&mut self, signatures: Vec<Signature>) {
 		debug_assert_eq!(signatures.len(), self.inputs.len());
 		self.signatures = signatures;
@@ -936,6 +1015,8 @@ pub enum BitcoinOp {
 	PushArray20 { bytes: [u8; 20] },
 	PushArray32 { bytes: [u8; 32] },
 	PushVersion { version: u8 },
+	Return,
+	PushData { bytes: BoundedVec<u8, ConstU32<MAX_OP_RETURN_DATA_BYTES>> },
 }
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, RuntimeDebug, PartialEq, Eq)]
@@ -989,25 +1070,8 @@ impl SerializeBtc for BitcoinOp {
 					buf.extend(value.to_le_bytes().into_iter().take(num_bytes));
 				},
 			},
-			BitcoinOp::PushBytes { bytes } => {
-				let num_bytes = bytes.len() as u32;
-				match num_bytes {
-					0..=0x4b => buf.push(num_bytes as u8),
-					0x4c..=0xff => {
-						buf.push(0x4c);
-						buf.push(num_bytes as u8);
-					},
-					0x100..=0xffff => {
-						buf.push(0x4d);
-						buf.extend(num_bytes.to_le_bytes().into_iter().take(2));
-					},
-					0x10000..=0xffffffff => {
-						buf.push(0x4e);
-						buf.extend(num_bytes.to_le_bytes().into_iter().take(4));
-					},
-				}
-				buf.extend(bytes);
-			},
+			BitcoinOp::PushBytes { bytes } => push_bytes(bytes, buf),
+			BitcoinOp::PushData { bytes } => push_bytes(bytes, buf),
 			BitcoinOp::Drop => buf.push(0x75),
 			BitcoinOp::CheckSig => buf.push(0xac),
 			BitcoinOp::Dup => buf.push(0x76),
@@ -1028,6 +1092,7 @@ impl SerializeBtc for BitcoinOp {
 				} else {
 					buf.push(0x50 + *version);
 				},
+			BitcoinOp::Return => buf.push(0x6a),
 		}
 	}
 
@@ -1041,16 +1106,8 @@ impl SerializeBtc for BitcoinOp {
 					1 + num_bytes
 				},
 			},
-			BitcoinOp::PushBytes { bytes } => {
-				let num_bytes = bytes.len();
-				num_bytes +
-					match num_bytes {
-						0..=0x4b => 1,
-						0x4c..=0xff => 2,
-						0x100..=0xffff => 3,
-						_ => 5,
-					}
-			},
+			BitcoinOp::PushBytes { bytes } => push_bytes_size(bytes),
+			BitcoinOp::PushData { bytes } => push_bytes_size(bytes),
 			BitcoinOp::Drop |
 			BitcoinOp::CheckSig |
 			BitcoinOp::Dup |
@@ -1060,10 +1117,42 @@ impl SerializeBtc for BitcoinOp {
 			BitcoinOp::PushArray20 { .. } => 21,
 			BitcoinOp::PushArray32 { .. } => 33,
 			BitcoinOp::PushVersion { .. } => 1,
+			BitcoinOp::Return => 1,
 		}
 	}
 }
 
+fn push_bytes(bytes: &[u8], buf: &mut Vec<u8>) {
+	let num_bytes = bytes.len() as u32;
+	match num_bytes {
+		0..=0x4b => buf.push(num_bytes as u8),
+		0x4c..=0xff => {
+			buf.push(0x4c);
+			buf.push(num_bytes as u8);
+		},
+		0x100..=0xffff => {
+			buf.push(0x4d);
+			buf.extend(num_bytes.to_le_bytes().into_iter().take(2));
+		},
+		0x10000..=0xffffffff => {
+			buf.push(0x4e);
+			buf.extend(num_bytes.to_le_bytes().into_iter().take(4));
+		},
+	}
+	buf.extend(bytes);
+}
+
+fn push_bytes_size(bytes: &[u8]) -> usize {
+	let num_bytes = bytes.len();
+	num_bytes +
+		match num_bytes {
+			0..=0x4b => 1,
+			0x4c..=0xff => 2,
+			0x100..=0xffff => 3,
+			_ => 5,
+		}
+}
+
 pub struct BitcoinRetryPolicy;
 impl RetryPolicy for BitcoinRetryPolicy {
 	type BlockNumber = u32;
@@ -1314,6 +1403,49 @@ mod test {
 		assert_eq!(tx.finalize(), hex_literal::hex!("020000000001014C94E48A870B85F41228D33CF25213DFCC8DD796E7211ED6B1F9A014809DBBB50100000000FDFFFFFF0100E1F5050000000022512042E4F4C78A1D8F936AD7FC2C2F028F9BB1538CFC9A509B985031457C367815C003400000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000025017B752078C79A2B436DA5575A03CDE40197775C656FFF9F0F59FC1466E09C20A81A9CDBAC21C0EEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE00000000"));
 	}
 
+	#[test]
+	fn batch_transfer_replacement_pays_higher_fee_from_change() {
+		let change_utxo_key = [0xcf; 32];
+		let original = create_test_unsigned_transaction(PreviousOrCurrent::Current);
+		let mut outputs = original.outputs.clone();
+		outputs[0].amount = 50_000_000;
+		outputs.push(BitcoinOutput {
+			amount: 50_000_000,
+			script_pubkey: DepositAddress::new(change_utxo_key, CHANGE_ADDRESS_SALT)
+				.script_pubkey(),
+		});
+		let batch_transfer = api::batch_transfer::BatchTransfer {
+			bitcoin_transaction: original.with_outputs(outputs),
+			change_utxo_key,
+		};
+		assert_eq!(batch_transfer.bitcoin_transaction.fee(), 10_000);
+
+		// The fee already paid is enough.
+		assert!(batch_transfer.replace_by_fee(&BitcoinFeeInfo::new(10_000)).is_none());
+		// The change output can't cover the fee.
+		assert!(batch_transfer.replace_by_fee(&BitcoinFeeInfo::new(300_000_000_000)).is_none());
+
+		let fee_info = BitcoinFeeInfo::new(100_000);
+		let replacement = batch_transfer.replace_by_fee(&fee_info).unwrap();
+		assert_eq!(
+			replacement.bitcoin_transaction.fee(),
+			batch_transfer.bitcoin_transaction.estimated_fee(&fee_info)
+		);
+		assert_eq!(
+			replacement.bitcoin_transaction.outputs[0],
+			batch_transfer.bitcoin_transaction.outputs[0]
+		);
+		assert_eq!(
+			replacement.bitcoin_transaction.get_signing_payloads().len(),
+			batch_transfer.bitcoin_transaction.get_signing_payloads().len()
+		);
+		assert_ne!(
+			replacement.bitcoin_transaction.txid(),
+			batch_transfer.bitcoin_transaction.txid()
+		);
+		assert_eq!(replacement.change_utxo_id().unwrap().vout, 1);
+	}
+
 	#[test]
 	fn test_payloads() {
 		test_payload(PreviousOrCurrent::Previous);
@@ -1357,6 +1489,15 @@ mod test {
 		}
 	}
 
+	#[test]
+	fn test_op_return_script() {
+		let data = vec![0xcf; 80];
+		let script_pubkey = ScriptPubkey::OpReturn(data.clone().try_into().unwrap());
+
+		assert_eq!(script_pubkey.bytes(), [vec![0x6a, 0x4c, 80], data].concat());
+		assert_eq!(script_pubkey.size(), 1 + 83);
+	}
+
 	#[test]
 	fn test_varint() {
 		let test_data = [
@@ -2,7 +2,7 @@ pub mod batch_transfer;
 
 use super::{
 	deposit_address::DepositAddress, AggKey, Bitcoin, BitcoinCrypto, BitcoinOutput, BtcAmount,
-	Utxo, BITCOIN_DUST_LIMIT, CHANGE_ADDRESS_SALT,
+	ScriptPubkey, Utxo, BITCOIN_DUST_LIMIT, CHANGE_ADDRESS_SALT,
 };
 use crate::*;
 use frame_support::{CloneNoBound, DebugNoBound, EqNoBound, Never, PartialEqNoBound};
@@ -125,16 +125,61 @@ impl<E> From<batch_transfer::BatchTransfer> for BitcoinApi<E> {
 	}
 }
 
-// TODO: Implement transfer / transfer and call for Bitcoin.
-impl<E: ReplayProtectionProvider<Bitcoin>> ExecutexSwapAndCall<Bitcoin> for BitcoinApi<E> {
+// Bitcoin has no way of calling the receiver, so the message is delivered alongside the transfer in
+// an OP_RETURN output. The gas budget only pays for the transaction fee.
+impl<E> ExecutexSwapAndCall<Bitcoin> for BitcoinApi<E>
+where
+	E: ChainEnvironment<UtxoSelectionType, SelectedUtxosAndChangeAmount>
+		+ ChainEnvironment<(), AggKey>,
+{
 	fn new_unsigned(
-		_transfer_param: TransferAssetParams<Bitcoin>,
+		transfer_param: TransferAssetParams<Bitcoin>,
 		_source_chain: ForeignChain,
 		_source_address: Option<ForeignChainAddress>,
 		_gas_budget: <Bitcoin as Chain>::ChainAmount,
-		_message: Vec<u8>,
+		message: Vec<u8>,
 	) -> Result<Self, DispatchError> {
-		Err(DispatchError::Other("Bitcoin's ExecutexSwapAndCall is not supported."))
+		let message_output = BitcoinOutput {
+			amount: 0,
+			script_pubkey: ScriptPubkey::OpReturn(
+				message
+					.try_into()
+					.map_err(|_| DispatchError::Other("CCM message is too long for Bitcoin."))?,
+			),
+		};
+		if transfer_param.amount < BITCOIN_DUST_LIMIT {
+			return Err(DispatchError::Other("CCM amount is below the Bitcoin dust limit."))
+		}
+
+		let agg_key @ AggKey { current, .. } = <E as ChainEnvironment<(), AggKey>>::lookup(())
+			.ok_or(DispatchError::Other("Bitcoin AggKey not set."))?;
+		let bitcoin_change_script =
+			DepositAddress::new(current, CHANGE_ADDRESS_SALT).script_pubkey();
+
+		let (selected_input_utxos, change_amount) = E::lookup(UtxoSelectionType::Some {
+			output_amount: transfer_param.amount,
+			number_of_outputs: 3, // transfer, message and change outputs
+		})
+		.ok_or(DispatchError::Other("Bitcoin UTXO selection failed."))?;
+
+		// The change output must come last.
+		let mut btc_outputs = vec![
+			BitcoinOutput { amount: transfer_param.amount, script_pubkey: transfer_param.to },
+			message_output,
+		];
+		if change_amount >= BITCOIN_DUST_LIMIT {
+			btc_outputs.push(BitcoinOutput {
+				amount: change_amount,
+				script_pubkey: bitcoin_change_script,
+			});
+		}
+
+		Ok(Self::BatchTransfer(batch_transfer::BatchTransfer::new_unsigned(
+			&agg_key,
+			agg_key.current,
+			selected_input_utxos,
+			btc_outputs,
+		)))
 	}
 }
 
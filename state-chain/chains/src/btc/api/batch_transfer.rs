@@ -2,7 +2,11 @@ use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_std::vec::Vec;
 
-use crate::btc::{AggKey, BitcoinCrypto, BitcoinOutput, BitcoinTransaction, Utxo};
+use crate::btc::{
+	deposit_address::DepositAddress, AggKey, BitcoinCrypto, BitcoinFeeInfo, BitcoinOutput,
+	BitcoinTransaction, Utxo, UtxoId, BITCOIN_DUST_LIMIT, BYTES_PER_BTC_KILOBYTE,
+	CHANGE_ADDRESS_SALT,
+};
 
 use crate::{ApiCall, ChainCrypto};
 
@@ -32,6 +36,49 @@ impl BatchTransfer {
 			change_utxo_key,
 		}
 	}
+
+	/// The id of the change output, which is always the last output of the transaction.
+	pub fn change_utxo_id(&self) -> Option<UtxoId> {
+		let vout = self.bitcoin_transaction.outputs.len().checked_sub(1)?;
+		let change_script =
+			DepositAddress::new(self.change_utxo_key, CHANGE_ADDRESS_SALT).script_pubkey();
+
+		(self.bitcoin_transaction.outputs[vout].script_pubkey == change_script)
+			.then(|| UtxoId { tx_id: self.bitcoin_transaction.txid(), vout: vout as u32 })
+	}
+
+	/// Builds an unsigned replacement of this transaction that spends the same inputs and pays the
+	/// fee required at the rate given in `fee_info`. The additional fee is taken out of the change
+	/// output, so the transfers themselves are unaffected.
+	///
+	/// Returns `None` if the fee required hasn't risen above the fee already paid, or if the change
+	/// output can't cover the difference.
+	pub fn replace_by_fee(&self, fee_info: &BitcoinFeeInfo) -> Option<Self> {
+		let fee_paid = self.bitcoin_transaction.fee();
+		let fee_required = self.bitcoin_transaction.estimated_fee(fee_info);
+		if fee_required <= fee_paid {
+			return None
+		}
+		// Nodes only relay a replacement if it pays for its own size at the minimum relay fee on
+		// top of the fee paid by the transaction it replaces.
+		let additional_fee = (fee_required - fee_paid).max(
+			self.bitcoin_transaction
+				.estimated_fee(&BitcoinFeeInfo::new(BYTES_PER_BTC_KILOBYTE)),
+		);
+
+		self.change_utxo_id()?;
+		let mut outputs = self.bitcoin_transaction.outputs.clone();
+		let change_output = outputs.last_mut().expect("Change output exists");
+		change_output.amount = change_output
+			.amount
+			.checked_sub(additional_fee)
+			.filter(|amount| *amount >= BITCOIN_DUST_LIMIT)?;
+
+		Some(Self {
+			bitcoin_transaction: self.bitcoin_transaction.with_outputs(outputs),
+			change_utxo_key: self.change_utxo_key,
+		})
+	}
 }
 
 impl ApiCall<BitcoinCrypto> for BatchTransfer {
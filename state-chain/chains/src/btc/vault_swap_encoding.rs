@@ -0,0 +1,109 @@
+use crate::{address::EncodedAddress, btc::MAX_OP_RETURN_DATA_BYTES};
+use cf_primitives::{Asset, AssetAmount, BasisPoints};
+use codec::{Decode, DecodeAll, Encode};
+use frame_support::sp_runtime::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// The version of the vault swap payload encoding. It is the first byte of every payload, so that
+/// the encoding can be changed without ambiguity.
+pub const CURRENT_VERSION: u8 = 0;
+
+/// The parameters of a swap made by depositing BTC directly into the vault, without opening a
+/// deposit channel. They are carried in an OP_RETURN output of the deposit transaction.
+#[derive(Encode, Decode, TypeInfo, Clone, RuntimeDebug, PartialEq, Eq)]
+pub struct VaultSwapParameters<AccountId> {
+	pub output_asset: Asset,
+	pub output_address: EncodedAddress,
+	/// The swap is refunded if its output would be less than this.
+	#[codec(compact)]
+	pub min_output_amount: AssetAmount,
+	pub broker_id: AccountId,
+	pub broker_commission: BasisPoints,
+}
+
+#[derive(Clone, RuntimeDebug, PartialEq, Eq)]
+pub enum VaultSwapEncodingError {
+	/// The encoded parameters don't fit into a standard OP_RETURN output. This happens for long
+	/// output addresses combined with very large minimum output amounts.
+	PayloadTooLong,
+}
+
+/// Encodes the parameters as the data of an OP_RETURN output. Fails if the payload would exceed
+/// [MAX_OP_RETURN_DATA_BYTES], since nodes with the default relay policy don't relay such
+/// transactions.
+pub fn encode_vault_swap_payload<AccountId: Encode>(
+	parameters: &VaultSwapParameters<AccountId>,
+) -> Result<Vec<u8>, VaultSwapEncodingError> {
+	let mut payload = sp_std::vec![CURRENT_VERSION];
+	parameters.encode_to(&mut payload);
+	if payload.len() > MAX_OP_RETURN_DATA_BYTES as usize {
+		return Err(VaultSwapEncodingError::PayloadTooLong)
+	}
+	Ok(payload)
+}
+
+/// Decodes the data of an OP_RETURN output. Returns `None` if the payload has an unknown version or
+/// isn't a valid encoding of the parameters.
+pub fn decode_vault_swap_payload<AccountId: Decode>(
+	payload: &[u8],
+) -> Option<VaultSwapParameters<AccountId>> {
+	match payload.split_first() {
+		Some((&CURRENT_VERSION, mut parameters)) =>
+			VaultSwapParameters::decode_all(&mut parameters).ok(),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parameters() -> VaultSwapParameters<[u8; 32]> {
+		VaultSwapParameters {
+			output_asset: Asset::Eth,
+			output_address: EncodedAddress::Eth([0xaa; 20]),
+			min_output_amount: 1_000_000_000_000_000,
+			broker_id: [0xbb; 32],
+			broker_commission: 50,
+		}
+	}
+
+	#[test]
+	fn payload_encoding_round_trip() {
+		let payload = encode_vault_swap_payload(&parameters()).unwrap();
+
+		assert_eq!(payload[0], CURRENT_VERSION);
+		assert_eq!(decode_vault_swap_payload(&payload), Some(parameters()));
+	}
+
+	#[test]
+	fn payloads_must_fit_into_an_op_return_output() {
+		let parameters =
+			VaultSwapParameters { output_address: EncodedAddress::Sol([0xaa; 32]), ..parameters() };
+		assert!(encode_vault_swap_payload(&parameters).is_ok());
+		assert_eq!(
+			encode_vault_swap_payload(&VaultSwapParameters {
+				min_output_amount: AssetAmount::MAX,
+				..parameters
+			}),
+			Err(VaultSwapEncodingError::PayloadTooLong)
+		);
+	}
+
+	#[test]
+	fn malformed_payloads_are_rejected() {
+		let payload = encode_vault_swap_payload(&parameters()).unwrap();
+
+		assert_eq!(decode_vault_swap_payload::<[u8; 32]>(&[]), None);
+		assert_eq!(decode_vault_swap_payload::<[u8; 32]>(&payload[..payload.len() - 1]), None);
+		assert_eq!(
+			decode_vault_swap_payload::<[u8; 32]>(&[payload.clone(), vec![0]].concat()),
+			None
+		);
+
+		let mut unknown_version = payload;
+		unknown_version[0] = CURRENT_VERSION + 1;
+		assert_eq!(decode_vault_swap_payload::<[u8; 32]>(&unknown_version), None);
+	}
+}
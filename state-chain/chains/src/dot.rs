@@ -307,6 +307,22 @@ impl FeeEstimationApi<Polkadot> for PolkadotTrackedData {
 
 		self.median_tip + transfer::EXTRINSIC_FEE
 	}
+
+	// A CCM is a transfer batched with a remark carrying the message. There is no receiver to pay
+	// gas for, so the gas limit is ignored.
+	fn estimate_ccm_gas_budget(
+		&self,
+		_gas_limit: u128,
+		message_length: u32,
+	) -> Option<<Polkadot as Chain>::ChainAmount> {
+		use fee_constants::transfer::*;
+
+		Some(
+			self.median_tip +
+				transfer::EXTRINSIC_FEE +
+				LENGTH_FEE.saturating_mul(message_length.into()),
+		)
+	}
 }
 
 #[derive(
@@ -501,7 +517,14 @@ pub enum PolkadotRuntimeCall {
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
-pub enum SystemCall {}
+pub enum SystemCall {
+	/// Make some on-chain remark and emit event.
+	#[codec(index = 7u8)]
+	remark_with_event {
+		#[allow(missing_docs)]
+		remark: Vec<u8>,
+	},
+}
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
@@ -1,4 +1,5 @@
 pub mod batch_fetch_and_transfer;
+pub mod execute_x_swap_and_call;
 pub mod rotate_vault_proxy;
 
 use super::{
@@ -115,13 +116,18 @@ where
 	E: PolkadotEnvironment + ReplayProtectionProvider<Polkadot>,
 {
 	fn new_unsigned(
-		_transfer_param: TransferAssetParams<Polkadot>,
+		transfer_param: TransferAssetParams<Polkadot>,
 		_source_chain: ForeignChain,
 		_source_address: Option<ForeignChainAddress>,
 		_gas_budget: <Polkadot as Chain>::ChainAmount,
-		_message: Vec<u8>,
+		message: Vec<u8>,
 	) -> Result<Self, DispatchError> {
-		Err(DispatchError::Other("Not implemented"))
+		Ok(Self::ExecuteXSwapAndCall(execute_x_swap_and_call::extrinsic_builder(
+			E::replay_protection(false),
+			transfer_param,
+			message,
+			E::try_vault_account().ok_or(DispatchError::Other("Vault account not set"))?,
+		)))
 	}
 }
 
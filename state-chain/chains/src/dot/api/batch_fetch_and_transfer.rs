@@ -79,9 +79,21 @@ mod test_batch_fetch {
 		let keypair_proxy = PolkadotPair::from_seed(&RAW_SEED_2);
 
 		let dummy_fetch_params: Vec<FetchAssetParams<Polkadot>> = vec![
-			FetchAssetParams::<Polkadot> { deposit_fetch_id: 1, asset: assets::dot::Asset::Dot },
-			FetchAssetParams::<Polkadot> { deposit_fetch_id: 2, asset: assets::dot::Asset::Dot },
-			FetchAssetParams::<Polkadot> { deposit_fetch_id: 3, asset: assets::dot::Asset::Dot },
+			FetchAssetParams::<Polkadot> {
+				deposit_fetch_id: 1,
+				asset: assets::dot::Asset::Dot,
+				amount: 1_000_000_000,
+			},
+			FetchAssetParams::<Polkadot> {
+				deposit_fetch_id: 2,
+				asset: assets::dot::Asset::Dot,
+				amount: 2_000_000_000,
+			},
+			FetchAssetParams::<Polkadot> {
+				deposit_fetch_id: 3,
+				asset: assets::dot::Asset::Dot,
+				amount: 3_000_000_000,
+			},
 		];
 
 		let dummy_transfer_params: Vec<TransferAssetParams<Polkadot>> = vec![
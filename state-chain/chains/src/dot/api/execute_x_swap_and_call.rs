@@ -0,0 +1,82 @@
+use crate::{
+	dot::{
+		BalancesCall, Polkadot, PolkadotAccountId, PolkadotAccountIdLookup,
+		PolkadotExtrinsicBuilder, PolkadotProxyType, PolkadotReplayProtection, PolkadotRuntimeCall,
+		ProxyCall, SystemCall, UtilityCall,
+	},
+	TransferAssetParams,
+};
+use sp_std::{boxed::Box, vec, vec::Vec};
+
+/// Polkadot has no way of calling the receiver, so the message is delivered as a
+/// `system.remark_with_event` that is batched atomically with the transfer.
+pub fn extrinsic_builder(
+	replay_protection: PolkadotReplayProtection,
+	transfer_param: TransferAssetParams<Polkadot>,
+	message: Vec<u8>,
+	vault_account: PolkadotAccountId,
+) -> PolkadotExtrinsicBuilder {
+	PolkadotExtrinsicBuilder::new(
+		replay_protection,
+		PolkadotRuntimeCall::Proxy(ProxyCall::proxy {
+			real: PolkadotAccountIdLookup::from(vault_account),
+			force_proxy_type: Some(PolkadotProxyType::Any),
+			call: Box::new(PolkadotRuntimeCall::Utility(UtilityCall::batch_all {
+				calls: vec![
+					PolkadotRuntimeCall::Balances(BalancesCall::transfer_allow_death {
+						dest: PolkadotAccountIdLookup::from(transfer_param.to),
+						value: transfer_param.amount,
+					}),
+					PolkadotRuntimeCall::System(SystemCall::remark_with_event { remark: message }),
+				],
+			})),
+		}),
+	)
+}
+
+#[cfg(test)]
+mod test_execute_x_swap_and_call {
+
+	use super::*;
+	use crate::dot::{PolkadotPair, NONCE_1, RAW_SEED_1, RAW_SEED_2, TEST_RUNTIME_VERSION};
+	use cf_primitives::chains::assets;
+	use codec::Encode;
+
+	#[test]
+	fn remark_encoding() {
+		// System pallet index, call index and the SCALE-encoded remark.
+		assert_eq!(
+			PolkadotRuntimeCall::System(SystemCall::remark_with_event { remark: vec![0xcf, 0x01] })
+				.encode(),
+			vec![0u8, 7u8, 8u8, 0xcf, 0x01]
+		);
+	}
+
+	#[test]
+	fn create_test_api_call() {
+		let keypair_vault = PolkadotPair::from_seed(&RAW_SEED_1);
+		let keypair_proxy = PolkadotPair::from_seed(&RAW_SEED_2);
+
+		let mut builder = super::extrinsic_builder(
+			PolkadotReplayProtection {
+				nonce: NONCE_1,
+				signer: keypair_proxy.public_key(),
+				genesis_hash: Default::default(),
+			},
+			TransferAssetParams::<Polkadot> {
+				to: PolkadotAccountId::from_aliased([7u8; 32]),
+				amount: 4,
+				asset: assets::dot::Asset::Dot,
+			},
+			b"hello".to_vec(),
+			keypair_vault.public_key(),
+		);
+
+		let payload = builder.get_signature_payload(
+			TEST_RUNTIME_VERSION.spec_version,
+			TEST_RUNTIME_VERSION.transaction_version,
+		);
+		builder.insert_signature(keypair_proxy.sign(&payload));
+		assert!(builder.is_signed());
+	}
+}
@@ -17,7 +17,10 @@ pub use ethabi::{
 	Address, Hash as TxHash, Token, Uint, Word,
 };
 use evm::api::EvmReplayProtection;
-use frame_support::sp_runtime::{traits::Zero, FixedPointNumber, FixedU64, RuntimeDebug};
+use frame_support::sp_runtime::{
+	traits::{One, Zero},
+	FixedPointNumber, FixedU64, RuntimeDebug,
+};
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_std::{cmp::min, convert::TryInto, str};
@@ -84,6 +87,10 @@ impl EthereumTrackedData {
 
 pub mod fees {
 	pub const BASE_COST_PER_BATCH: u128 = 50_000;
+	/// Gas used by the Vault's `executexSwapAndCall` on top of the receiver's gas.
+	pub const CCM_BASE_GAS: u128 = 100_000;
+	/// Calldata cost of each (non-zero) byte of the CCM message.
+	pub const CCM_GAS_PER_MESSAGE_BYTE: u128 = 16;
 	pub const GAS_COST_PER_FETCH: u128 = 30_000;
 	pub const GAS_COST_PER_TRANSFER_NATIVE: u128 = 20_000;
 	pub const GAS_COST_PER_TRANSFER_TOKEN: u128 = 40_000;
@@ -123,6 +130,23 @@ impl FeeEstimationApi<Ethereum> for EthereumTrackedData {
 
 		(self.base_fee + self.priority_fee).saturating_mul(gas_cost_per_transfer)
 	}
+
+	fn estimate_ccm_gas_budget(
+		&self,
+		gas_limit: u128,
+		message_length: u32,
+	) -> Option<<Ethereum as Chain>::ChainAmount> {
+		use crate::eth::fees::*;
+
+		// The gas limit of a CCM is derived from its budget at the unmultiplied fee per gas.
+		Some(
+			self.max_fee_per_gas(One::one()).saturating_mul(
+				CCM_BASE_GAS
+					.saturating_add(CCM_GAS_PER_MESSAGE_BYTE.saturating_mul(message_length.into()))
+					.saturating_add(gas_limit),
+			),
+		)
+	}
 }
 
 impl Default for EthereumTrackedData {
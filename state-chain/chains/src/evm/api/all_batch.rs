@@ -207,14 +207,17 @@ mod test_all_batch {
 				FetchAssetParams {
 					deposit_fetch_id: EvmFetchId::Fetch(eth::Address::from_low_u64_be(CHANNEL_ID)),
 					asset: assets::eth::Asset::Usdc,
+					amount: 1_000_000,
 				},
 				FetchAssetParams {
 					deposit_fetch_id: EvmFetchId::DeployAndFetch(CHANNEL_ID),
 					asset: assets::eth::Asset::Eth,
+					amount: 1_000_000_000_000_000_000,
 				},
 				FetchAssetParams {
 					deposit_fetch_id: EvmFetchId::NotRequired,
 					asset: assets::eth::Asset::Eth,
+					amount: 1_000_000_000_000_000_000,
 				},
 			],
 			vec![],
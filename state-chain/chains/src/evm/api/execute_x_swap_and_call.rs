@@ -52,6 +52,8 @@ impl ExecutexSwapAndCall {
 				(ForeignChain::Arbitrum as u32, source_address.0.to_vec()),
 			Some(ForeignChainAddress::Sol(source_address)) =>
 				(ForeignChain::Solana as u32, source_address.0.to_vec()),
+			Some(ForeignChainAddress::Hub(source_address)) =>
+				(ForeignChain::Assethub as u32, source_address.aliased_ref().to_vec()),
 		}
 	}
 }
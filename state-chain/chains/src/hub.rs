@@ -0,0 +1,535 @@
+//! Types and functions that are common to Polkadot Asset Hub.
+//!
+//! Asset Hub is a Polkadot system parachain, so it shares the Polkadot crypto and account types.
+//! The vault is a separate proxy account on Asset Hub, controlled by the same Polkadot aggregate
+//! key.
+pub mod api;
+
+pub mod benchmarking;
+
+use crate::{
+	dot::{
+		BalancesCall, EncodedPolkadotPayload, PolkadotAccountId, PolkadotAccountIdLookup,
+		PolkadotBalance, PolkadotChannelId, PolkadotChannelState, PolkadotCheckMortality,
+		PolkadotCheckNonce, PolkadotCrypto, PolkadotExtrinsicIndex, PolkadotHash,
+		PolkadotReplayProtection, PolkadotSignature, PolkadotSpecVersion, PolkadotTrackedData,
+		PolkadotTransactionData, PolkadotTransactionId, PolkadotTransactionVersion,
+		ResetProxyAccountNonce, SystemCall,
+	},
+	*,
+};
+pub use cf_primitives::chains::Assethub;
+use cf_primitives::PolkadotBlockNumber;
+use codec::{Decode, Encode};
+use core::str::FromStr;
+use frame_support::sp_runtime::{
+	generic::{Era, SignedPayload, UncheckedExtrinsic},
+	traits::{DispatchInfoOf, SignedExtension},
+	transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+	MultiAddress, MultiSignature,
+};
+use scale_info::TypeInfo;
+use sp_core::{sr25519, H256};
+
+/// The id of USDT in the Asset Hub `assets` pallet.
+pub const USDT_ASSET_ID: AssethubAssetId = 1984;
+/// The id of USDC in the Asset Hub `assets` pallet.
+pub const USDC_ASSET_ID: AssethubAssetId = 1337;
+
+pub type AssethubAssetId = u32;
+
+impl Chain for Assethub {
+	const NAME: &'static str = "Assethub";
+	const GAS_ASSET: Self::ChainAsset = assets::hub::Asset::HubDot;
+	const WITNESS_PERIOD: Self::ChainBlockNumber = 1;
+
+	type ChainCrypto = PolkadotCrypto;
+	type ChainBlockNumber = PolkadotBlockNumber;
+	type ChainAmount = PolkadotBalance;
+	type TrackedData = PolkadotTrackedData;
+	type ChainAccount = PolkadotAccountId;
+	type TransactionFee = Self::ChainAmount;
+	type ChainAsset = assets::hub::Asset;
+	type DepositFetchId = PolkadotChannelId;
+	type DepositChannelState = PolkadotChannelState;
+	type DepositDetails = PolkadotExtrinsicIndex;
+	type Transaction = PolkadotTransactionData;
+	type TransactionMetadata = ();
+	type ReplayProtectionParams = ResetProxyAccountNonce;
+	type ReplayProtection = PolkadotReplayProtection;
+	type TransactionRef = PolkadotTransactionId;
+}
+
+/// The `assets` pallet id of the given asset, or `None` for the native asset.
+pub fn assets_pallet_id(asset: assets::hub::Asset) -> Option<AssethubAssetId> {
+	match asset {
+		assets::hub::Asset::HubDot => None,
+		assets::hub::Asset::HubUsdt => Some(USDT_ASSET_ID),
+		assets::hub::Asset::HubUsdc => Some(USDC_ASSET_ID),
+	}
+}
+
+/// Asset Hub fees are roughly a tenth of those on the relay chain.
+///
+/// Fee constants here already include the Multiplier.
+mod fee_constants {
+	pub const MICRO_DOT: u128 = 10_000;
+	pub const MILLI_DOT: u128 = 1_000 * MICRO_DOT;
+
+	/// Taken from the Asset Hub runtime.
+	pub const BASE_FEE: u128 = MILLI_DOT / 10;
+	/// Taken from the Asset Hub runtime.
+	pub const LENGTH_FEE: u128 = MILLI_DOT / 100;
+
+	pub mod fetch {
+		pub use super::*;
+
+		/// Estimated from the Asset Hub runtime.
+		pub const ADJUSTED_WEIGHT_FEE_NATIVE: u128 = 33 * MICRO_DOT;
+		/// Fetching an asset also touches the asset and account storage of the `assets` pallet.
+		pub const ADJUSTED_WEIGHT_FEE_ASSET: u128 = 60 * MICRO_DOT;
+		/// The length in bytes of an extrinsic that encodes a single fetch operation.
+		pub const EXTRINSIC_LENGTH: u128 = 190;
+	}
+
+	pub mod transfer {
+		pub use super::*;
+
+		/// Estimated from the Asset Hub runtime.
+		pub const ADJUSTED_WEIGHT_FEE_NATIVE: u128 = 25 * MICRO_DOT;
+		/// Transferring an asset also touches the asset and account storage of the `assets`
+		/// pallet.
+		pub const ADJUSTED_WEIGHT_FEE_ASSET: u128 = 50 * MICRO_DOT;
+		/// The length in bytes of an extrinsic that encodes a single transfer operation.
+		pub const EXTRINSIC_LENGTH: u128 = 190;
+	}
+}
+
+impl FeeEstimationApi<Assethub> for PolkadotTrackedData {
+	fn estimate_ingress_fee(
+		&self,
+		asset: <Assethub as Chain>::ChainAsset,
+	) -> <Assethub as Chain>::ChainAmount {
+		use fee_constants::fetch::*;
+
+		let weight_fee = match asset {
+			assets::hub::Asset::HubDot => ADJUSTED_WEIGHT_FEE_NATIVE,
+			assets::hub::Asset::HubUsdt | assets::hub::Asset::HubUsdc => ADJUSTED_WEIGHT_FEE_ASSET,
+		};
+
+		self.median_tip + BASE_FEE + LENGTH_FEE * EXTRINSIC_LENGTH + weight_fee
+	}
+
+	fn estimate_egress_fee(
+		&self,
+		asset: <Assethub as Chain>::ChainAsset,
+	) -> <Assethub as Chain>::ChainAmount {
+		use fee_constants::transfer::*;
+
+		let weight_fee = match asset {
+			assets::hub::Asset::HubDot => ADJUSTED_WEIGHT_FEE_NATIVE,
+			assets::hub::Asset::HubUsdt | assets::hub::Asset::HubUsdc => ADJUSTED_WEIGHT_FEE_ASSET,
+		};
+
+		self.median_tip + BASE_FEE + LENGTH_FEE * EXTRINSIC_LENGTH + weight_fee
+	}
+}
+
+impl FeeRefundCalculator<Assethub> for PolkadotTransactionData {
+	fn return_fee_refund(
+		&self,
+		fee_paid: <Assethub as Chain>::TransactionFee,
+	) -> <Assethub as Chain>::ChainAmount {
+		fee_paid
+	}
+}
+
+#[derive(Debug, Clone, Encode, Decode, TypeInfo)]
+pub struct AssethubUncheckedExtrinsic(
+	UncheckedExtrinsic<
+		MultiAddress<PolkadotAccountId, ()>,
+		AssethubRuntimeCall,
+		MultiSignature,
+		AssethubSignedExtra,
+	>,
+);
+impl AssethubUncheckedExtrinsic {
+	pub fn new_signed(
+		function: AssethubRuntimeCall,
+		signed: PolkadotAccountId,
+		signature: PolkadotSignature,
+		extra: AssethubSignedExtra,
+	) -> Self {
+		Self(UncheckedExtrinsic::new_signed(
+			function,
+			MultiAddress::Id(signed),
+			MultiSignature::Sr25519(sr25519::Signature(*signature.aliased_ref())),
+			extra,
+		))
+	}
+
+	pub fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+		Ok(Self(UncheckedExtrinsic::decode(input)?))
+	}
+
+	pub fn signature(&self) -> Option<PolkadotSignature> {
+		self.0.signature.as_ref().and_then(|signature| {
+			if let MultiSignature::Sr25519(signature) = &signature.1 {
+				Some(PolkadotSignature::from_aliased(signature.0))
+			} else {
+				None
+			}
+		})
+	}
+}
+
+/// The payload being signed in transactions.
+pub type AssethubPayload = SignedPayload<AssethubRuntimeCall, AssethubSignedExtra>;
+
+/// The builder for creating and signing Asset Hub extrinsics, and creating signature payload
+#[derive(Debug, Encode, Decode, TypeInfo, Eq, PartialEq, Clone)]
+pub struct AssethubExtrinsicBuilder {
+	extrinsic_call: AssethubRuntimeCall,
+	replay_protection: PolkadotReplayProtection,
+	signature: Option<PolkadotSignature>,
+}
+
+impl AssethubExtrinsicBuilder {
+	pub fn new(
+		replay_protection: PolkadotReplayProtection,
+		extrinsic_call: AssethubRuntimeCall,
+	) -> Self {
+		Self { extrinsic_call, replay_protection, signature: None }
+	}
+
+	pub fn signature(&self) -> Option<PolkadotSignature> {
+		self.signature.clone()
+	}
+
+	fn extra(&self) -> AssethubSignedExtra {
+		const TIP: PolkadotBalance = 0;
+		AssethubSignedExtra((
+			(),
+			(),
+			(),
+			(),
+			PolkadotCheckMortality(Era::Immortal),
+			PolkadotCheckNonce(self.replay_protection.nonce),
+			(),
+			AssethubChargeAssetTxPayment { tip: TIP, asset_id: None },
+			AssethubCheckMetadataHash::disabled(),
+		))
+	}
+
+	pub fn get_signature_payload(
+		&self,
+		spec_version: u32,
+		transaction_version: u32,
+	) -> <<Assethub as Chain>::ChainCrypto as ChainCrypto>::Payload {
+		EncodedPolkadotPayload(
+			AssethubPayload::from_raw(
+				self.extrinsic_call.clone(),
+				self.extra(),
+				(
+					(),
+					spec_version,
+					transaction_version,
+					self.replay_protection.genesis_hash,
+					self.replay_protection.genesis_hash,
+					(),
+					(),
+					(),
+					None,
+				),
+			)
+			.encode(),
+		)
+	}
+
+	pub fn insert_signature(&mut self, signature: PolkadotSignature) {
+		self.signature.replace(signature);
+	}
+
+	pub fn get_signed_unchecked_extrinsic(&self) -> Option<AssethubUncheckedExtrinsic> {
+		self.signature.as_ref().map(|signature| {
+			AssethubUncheckedExtrinsic::new_signed(
+				self.extrinsic_call.clone(),
+				self.replay_protection.signer,
+				signature.clone(),
+				self.extra(),
+			)
+		})
+	}
+
+	pub fn is_signed(&self) -> bool {
+		self.signature.is_some()
+	}
+}
+
+// The call type that is expected by the Polkadot Asset Hub runtime.
+#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+pub enum AssethubRuntimeCall {
+	#[codec(index = 0u8)]
+	System(SystemCall),
+	#[codec(index = 10u8)]
+	Balances(BalancesCall),
+	#[codec(index = 40u8)]
+	Utility(AssethubUtilityCall),
+	#[codec(index = 42u8)]
+	Proxy(AssethubProxyCall),
+	#[codec(index = 50u8)]
+	Assets(AssetsCall),
+}
+
+// FROM: https://github.com/polkadot-fellows/runtimes/blob/main/system-parachains/asset-hubs/asset-hub-polkadot/src/lib.rs
+#[allow(clippy::unnecessary_cast)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum AssethubProxyType {
+	Any = 0,
+	NonTransfer = 1,
+	CancelProxy = 2,
+	Assets = 3,
+	AssetOwner = 4,
+	AssetManager = 5,
+	Collator = 6,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+pub enum AssethubUtilityCall {
+	/// Send a call through an indexed pseudonym of the sender.
+	#[codec(index = 1u8)]
+	as_derivative {
+		#[allow(missing_docs)]
+		index: u16,
+		#[allow(missing_docs)]
+		call: Box<AssethubRuntimeCall>,
+	},
+	/// Send a batch of dispatch calls and atomically execute them.
+	#[codec(index = 2u8)]
+	batch_all {
+		#[allow(missing_docs)]
+		calls: Vec<AssethubRuntimeCall>,
+	},
+	/// Send a batch of dispatch calls. Unlike `batch`, it allows errors and won't interrupt.
+	#[codec(index = 4u8)]
+	force_batch {
+		#[allow(missing_docs)]
+		calls: Vec<AssethubRuntimeCall>,
+	},
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+pub enum AssethubProxyCall {
+	/// Dispatch the given `call` from an account that the sender is authorised for through
+	/// `add_proxy`.
+	#[codec(index = 0u8)]
+	proxy {
+		#[allow(missing_docs)]
+		real: PolkadotAccountIdLookup,
+		#[allow(missing_docs)]
+		force_proxy_type: Option<AssethubProxyType>,
+		#[allow(missing_docs)]
+		call: Box<AssethubRuntimeCall>,
+	},
+	/// Register a proxy account for the sender that is able to make calls on its behalf.
+	#[codec(index = 1u8)]
+	add_proxy {
+		#[allow(missing_docs)]
+		delegate: PolkadotAccountIdLookup,
+		#[allow(missing_docs)]
+		proxy_type: AssethubProxyType,
+		#[allow(missing_docs)]
+		delay: PolkadotBlockNumber,
+	},
+	/// Unregister a proxy account for the sender.
+	#[codec(index = 2u8)]
+	remove_proxy {
+		#[allow(missing_docs)]
+		delegate: PolkadotAccountIdLookup,
+		#[allow(missing_docs)]
+		proxy_type: AssethubProxyType,
+		#[allow(missing_docs)]
+		delay: PolkadotBlockNumber,
+	},
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+pub enum AssetsCall {
+	/// Move some assets from the sender account to another.
+	#[codec(index = 8u8)]
+	transfer {
+		#[allow(missing_docs)]
+		#[codec(compact)]
+		id: AssethubAssetId,
+		#[allow(missing_docs)]
+		target: PolkadotAccountIdLookup,
+		#[allow(missing_docs)]
+		#[codec(compact)]
+		amount: PolkadotBalance,
+	},
+	/// Transfer the entire transferable balance of an asset from the caller account.
+	#[codec(index = 32u8)]
+	transfer_all {
+		#[allow(missing_docs)]
+		#[codec(compact)]
+		id: AssethubAssetId,
+		#[allow(missing_docs)]
+		dest: PolkadotAccountIdLookup,
+		#[allow(missing_docs)]
+		keep_alive: bool,
+	},
+}
+
+/// The `ChargeAssetTxPayment` extension. We always pay fees in DOT, so the fee asset is never set.
+#[derive(Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+pub struct AssethubChargeAssetTxPayment {
+	#[codec(compact)]
+	tip: PolkadotBalance,
+	asset_id: Option<()>,
+}
+
+/// The `CheckMetadataHash` extension, which we never enable.
+#[derive(Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+pub struct AssethubCheckMetadataHash {
+	mode: u8,
+}
+
+impl AssethubCheckMetadataHash {
+	pub const fn disabled() -> Self {
+		Self { mode: 0 }
+	}
+}
+
+#[derive(Debug, Encode, Decode, Copy, Clone, Eq, PartialEq, TypeInfo)]
+pub struct AssethubSignedExtra(
+	pub  (
+		(),
+		(),
+		(),
+		(),
+		PolkadotCheckMortality,
+		PolkadotCheckNonce,
+		(),
+		AssethubChargeAssetTxPayment,
+		AssethubCheckMetadataHash,
+	),
+);
+
+impl SignedExtension for AssethubSignedExtra {
+	type AccountId = PolkadotAccountId;
+	type Call = ();
+	type AdditionalSigned = (
+		(),
+		PolkadotSpecVersion,
+		PolkadotTransactionVersion,
+		PolkadotHash,
+		PolkadotHash,
+		(),
+		(),
+		(),
+		Option<[u8; 32]>,
+	);
+	type Pre = ();
+	const IDENTIFIER: &'static str = "AssethubSignedExtra";
+
+	// This is a dummy implementation of additional_signed required by SignedPayload. This is never
+	// actually used since the extrinsic builder that constructs the payload uses its own
+	// additional_signed and constructs payload from raw.
+	fn additional_signed(
+		&self,
+	) -> sp_std::result::Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok((
+			(),
+			1_002_000,
+			15,
+			H256::from_str("68d56f15f85d3136970ec16946040bc1752654e906147f7e43e9d539d7c3de2f")
+				.unwrap(),
+			H256::from_str("68d56f15f85d3136970ec16946040bc1752654e906147f7e43e9d539d7c3de2f")
+				.unwrap(),
+			(),
+			(),
+			(),
+			None,
+		))
+	}
+
+	fn pre_dispatch(
+		self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		Ok(<ValidTransaction as Default>::default())
+	}
+}
+
+#[cfg(test)]
+mod test_assethub_extrinsics {
+	use super::*;
+	use crate::dot::{PolkadotPair, RAW_SEED_1, RAW_SEED_2, TEST_RUNTIME_VERSION};
+
+	#[test]
+	fn signed_extrinsic_decodes() {
+		let keypair = PolkadotPair::from_seed(&RAW_SEED_1);
+		let mut builder = AssethubExtrinsicBuilder::new(
+			PolkadotReplayProtection {
+				nonce: 3,
+				signer: keypair.public_key(),
+				genesis_hash: Default::default(),
+			},
+			AssethubRuntimeCall::Assets(AssetsCall::transfer {
+				id: USDT_ASSET_ID,
+				target: PolkadotAccountIdLookup::from(
+					PolkadotPair::from_seed(&RAW_SEED_2).public_key(),
+				),
+				amount: 1_000_000,
+			}),
+		);
+		builder.insert_signature(keypair.sign(&builder.get_signature_payload(
+			TEST_RUNTIME_VERSION.spec_version,
+			TEST_RUNTIME_VERSION.transaction_version,
+		)));
+
+		let encoded = builder.get_signed_unchecked_extrinsic().unwrap().encode();
+		assert_eq!(
+			AssethubUncheckedExtrinsic::decode(&mut &encoded[..]).unwrap().encode(),
+			encoded
+		);
+	}
+
+	#[test]
+	fn asset_fees_are_higher_than_native_fees() {
+		let tracked_data = PolkadotTrackedData {
+			median_tip: Default::default(),
+			runtime_version: TEST_RUNTIME_VERSION,
+		};
+
+		for estimate in [
+			<PolkadotTrackedData as FeeEstimationApi<Assethub>>::estimate_ingress_fee,
+			<PolkadotTrackedData as FeeEstimationApi<Assethub>>::estimate_egress_fee,
+		] {
+			assert!(
+				estimate(&tracked_data, assets::hub::Asset::HubUsdt) >
+					estimate(&tracked_data, assets::hub::Asset::HubDot)
+			);
+			assert_eq!(
+				estimate(&tracked_data, assets::hub::Asset::HubUsdt),
+				estimate(&tracked_data, assets::hub::Asset::HubUsdc)
+			);
+		}
+	}
+}
@@ -0,0 +1,188 @@
+pub mod batch_fetch_and_transfer;
+pub mod rotate_vault_proxy;
+
+use super::{Assethub, AssethubExtrinsicBuilder};
+use crate::{
+	dot::{
+		api::VaultAccount, PolkadotAccountId, PolkadotCrypto, PolkadotPublicKey, RuntimeVersion,
+	},
+	*,
+};
+use frame_support::{traits::Get, CloneNoBound, DebugNoBound, EqNoBound, Never, PartialEqNoBound};
+use sp_std::marker::PhantomData;
+
+/// Chainflip api calls available on Polkadot Asset Hub.
+#[derive(CloneNoBound, DebugNoBound, PartialEqNoBound, EqNoBound, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(Environment))]
+pub enum AssethubApi<Environment: 'static> {
+	BatchFetchAndTransfer(AssethubExtrinsicBuilder),
+	RotateVaultProxy(AssethubExtrinsicBuilder),
+	ChangeGovKey(AssethubExtrinsicBuilder),
+	ExecuteXSwapAndCall(AssethubExtrinsicBuilder),
+	#[doc(hidden)]
+	#[codec(skip)]
+	_Phantom(PhantomData<Environment>, Never),
+}
+
+pub trait AssethubEnvironment {
+	fn try_vault_account() -> Option<PolkadotAccountId>;
+	fn vault_account() -> PolkadotAccountId {
+		Self::try_vault_account().expect("Vault account must be set")
+	}
+
+	fn runtime_version() -> RuntimeVersion;
+}
+
+impl<T: ChainEnvironment<VaultAccount, PolkadotAccountId> + Get<RuntimeVersion>> AssethubEnvironment
+	for T
+{
+	fn try_vault_account() -> Option<PolkadotAccountId> {
+		Self::lookup(VaultAccount)
+	}
+
+	fn runtime_version() -> RuntimeVersion {
+		Self::get()
+	}
+}
+
+impl<E> ConsolidateCall<Assethub> for AssethubApi<E>
+where
+	E: AssethubEnvironment + ReplayProtectionProvider<Assethub>,
+{
+	fn consolidate_utxos() -> Result<Self, ConsolidationError> {
+		Err(ConsolidationError::NotRequired)
+	}
+}
+
+impl<E> AllBatch<Assethub> for AssethubApi<E>
+where
+	E: AssethubEnvironment + ReplayProtectionProvider<Assethub>,
+{
+	fn new_unsigned(
+		fetch_params: Vec<FetchAssetParams<Assethub>>,
+		transfer_params: Vec<TransferAssetParams<Assethub>>,
+	) -> Result<Self, AllBatchError> {
+		Ok(Self::BatchFetchAndTransfer(batch_fetch_and_transfer::extrinsic_builder(
+			E::replay_protection(false),
+			fetch_params,
+			transfer_params,
+			E::try_vault_account().ok_or(AllBatchError::VaultAccountNotSet)?,
+		)))
+	}
+}
+
+impl<E> SetGovKeyWithAggKey<PolkadotCrypto> for AssethubApi<E>
+where
+	E: AssethubEnvironment + ReplayProtectionProvider<Assethub>,
+{
+	fn new_unsigned(
+		maybe_old_key: Option<PolkadotPublicKey>,
+		new_key: PolkadotPublicKey,
+	) -> Result<Self, ()> {
+		let vault = E::try_vault_account().ok_or(())?;
+
+		Ok(Self::ChangeGovKey(rotate_vault_proxy::extrinsic_builder(
+			E::replay_protection(false),
+			maybe_old_key,
+			new_key,
+			vault,
+		)))
+	}
+}
+
+impl<E> SetAggKeyWithAggKey<PolkadotCrypto> for AssethubApi<E>
+where
+	E: AssethubEnvironment + ReplayProtectionProvider<Assethub>,
+{
+	fn new_unsigned(
+		maybe_old_key: Option<PolkadotPublicKey>,
+		new_key: PolkadotPublicKey,
+	) -> Result<Option<Self>, SetAggKeyWithAggKeyError> {
+		let vault = E::try_vault_account().ok_or(SetAggKeyWithAggKeyError::Failed)?;
+
+		Ok(Some(Self::RotateVaultProxy(rotate_vault_proxy::extrinsic_builder(
+			// we reset the proxy account nonce on a rotation tx
+			E::replay_protection(true),
+			maybe_old_key,
+			new_key,
+			vault,
+		))))
+	}
+}
+
+impl<E> ExecutexSwapAndCall<Assethub> for AssethubApi<E>
+where
+	E: AssethubEnvironment + ReplayProtectionProvider<Assethub>,
+{
+	fn new_unsigned(
+		_transfer_param: TransferAssetParams<Assethub>,
+		_source_chain: ForeignChain,
+		_source_address: Option<ForeignChainAddress>,
+		_gas_budget: <Assethub as Chain>::ChainAmount,
+		_message: Vec<u8>,
+	) -> Result<Self, DispatchError> {
+		Err(DispatchError::Other("Not implemented"))
+	}
+}
+
+impl<E> TransferFallback<Assethub> for AssethubApi<E>
+where
+	E: AssethubEnvironment + ReplayProtectionProvider<Assethub>,
+{
+	fn new_unsigned(_transfer_param: TransferAssetParams<Assethub>) -> Result<Self, DispatchError> {
+		Err(DispatchError::Other("TransferFallback is not supported for the Assethub chain."))
+	}
+}
+
+macro_rules! map_over_api_variants {
+	( $self:expr, $var:pat_param, $var_method:expr $(,)* ) => {
+		match $self {
+			AssethubApi::BatchFetchAndTransfer($var) => $var_method,
+			AssethubApi::RotateVaultProxy($var) => $var_method,
+			AssethubApi::ChangeGovKey($var) => $var_method,
+			AssethubApi::ExecuteXSwapAndCall($var) => $var_method,
+			AssethubApi::_Phantom(..) => unreachable!(),
+		}
+	};
+}
+
+impl<E: AssethubEnvironment> ApiCall<PolkadotCrypto> for AssethubApi<E> {
+	fn threshold_signature_payload(&self) -> <PolkadotCrypto as ChainCrypto>::Payload {
+		let RuntimeVersion { spec_version, transaction_version, .. } = E::runtime_version();
+		map_over_api_variants!(
+			self,
+			call,
+			call.get_signature_payload(spec_version, transaction_version)
+		)
+	}
+
+	fn signed(
+		mut self,
+		threshold_signature: &<PolkadotCrypto as ChainCrypto>::ThresholdSignature,
+	) -> Self {
+		map_over_api_variants!(
+			self,
+			ref mut call,
+			call.insert_signature(threshold_signature.clone())
+		);
+		self
+	}
+
+	fn chain_encoded(&self) -> Vec<u8> {
+		map_over_api_variants!(
+			self,
+			call,
+			call.get_signed_unchecked_extrinsic()
+				.expect("Must be called after `signed`")
+				.encode()
+		)
+	}
+
+	fn is_signed(&self) -> bool {
+		map_over_api_variants!(self, call, call.is_signed())
+	}
+
+	fn transaction_out_id(&self) -> <PolkadotCrypto as ChainCrypto>::TransactionOutId {
+		map_over_api_variants!(self, call, call.signature().unwrap())
+	}
+}
@@ -0,0 +1,180 @@
+use crate::{
+	dot::{BalancesCall, PolkadotAccountId, PolkadotAccountIdLookup, PolkadotReplayProtection},
+	hub::{
+		assets_pallet_id, Assethub, AssethubExtrinsicBuilder, AssethubProxyCall, AssethubProxyType,
+		AssethubRuntimeCall, AssethubUtilityCall, AssetsCall,
+	},
+	FetchAssetParams, TransferAssetParams,
+};
+use cf_primitives::{chains::assets, ChannelId};
+use cf_utilities::SliceToArray;
+use sp_std::{boxed::Box, vec::Vec};
+
+pub fn extrinsic_builder(
+	replay_protection: PolkadotReplayProtection,
+	fetch_params: Vec<FetchAssetParams<Assethub>>,
+	transfer_params: Vec<TransferAssetParams<Assethub>>,
+	vault_account: PolkadotAccountId,
+) -> AssethubExtrinsicBuilder {
+	AssethubExtrinsicBuilder::new(
+		replay_protection,
+		AssethubRuntimeCall::Proxy(AssethubProxyCall::proxy {
+			real: PolkadotAccountIdLookup::from(vault_account),
+			force_proxy_type: Some(AssethubProxyType::Any),
+			call: Box::new(AssethubRuntimeCall::Utility(AssethubUtilityCall::force_batch {
+				calls: [
+					fetch_params
+						.into_iter()
+						.map(|fetch_param| {
+							utility_fetch(
+								fetch_param.deposit_fetch_id,
+								fetch_param.asset,
+								vault_account,
+							)
+						})
+						.collect::<Vec<AssethubRuntimeCall>>(),
+					transfer_params
+						.into_iter()
+						.map(|transfer_param| {
+							transfer(transfer_param.asset, transfer_param.to, transfer_param.amount)
+						})
+						.collect::<Vec<AssethubRuntimeCall>>(),
+				]
+				.concat(),
+			})),
+		}),
+	)
+}
+
+fn transfer(asset: assets::hub::Asset, to: PolkadotAccountId, amount: u128) -> AssethubRuntimeCall {
+	match assets_pallet_id(asset) {
+		None => AssethubRuntimeCall::Balances(BalancesCall::transfer_allow_death {
+			dest: PolkadotAccountIdLookup::from(to),
+			value: amount,
+		}),
+		Some(id) => AssethubRuntimeCall::Assets(AssetsCall::transfer {
+			id,
+			target: PolkadotAccountIdLookup::from(to),
+			amount,
+		}),
+	}
+}
+
+fn utility_fetch(
+	channel_id: ChannelId,
+	asset: assets::hub::Asset,
+	vault_account: PolkadotAccountId,
+) -> AssethubRuntimeCall {
+	let layers = channel_id
+		.to_be_bytes()
+		.chunks(2)
+		.map(|chunk| u16::from_be_bytes(chunk.as_array::<2>()))
+		.skip_while(|layer| *layer == 0u16)
+		.collect::<Vec<u16>>();
+
+	let sweep = match assets_pallet_id(asset) {
+		None => AssethubRuntimeCall::Balances(BalancesCall::transfer_all {
+			dest: PolkadotAccountIdLookup::from(vault_account),
+			keep_alive: false,
+		}),
+		Some(id) => AssethubRuntimeCall::Assets(AssetsCall::transfer_all {
+			id,
+			dest: PolkadotAccountIdLookup::from(vault_account),
+			keep_alive: false,
+		}),
+	};
+
+	layers.into_iter().fold(sweep, |call, index| {
+		AssethubRuntimeCall::Utility(AssethubUtilityCall::as_derivative {
+			index,
+			call: Box::new(call),
+		})
+	})
+}
+
+#[cfg(test)]
+mod test_batch_fetch {
+
+	use super::*;
+	use crate::{
+		dot::{PolkadotPair, NONCE_1, RAW_SEED_1, RAW_SEED_2, TEST_RUNTIME_VERSION},
+		hub::{USDC_ASSET_ID, USDT_ASSET_ID},
+	};
+
+	#[test]
+	fn create_test_api_call() {
+		let keypair_vault = PolkadotPair::from_seed(&RAW_SEED_1);
+		let account_id_vault = keypair_vault.public_key();
+
+		let keypair_proxy = PolkadotPair::from_seed(&RAW_SEED_2);
+
+		let mut builder = super::extrinsic_builder(
+			PolkadotReplayProtection {
+				nonce: NONCE_1,
+				signer: keypair_proxy.public_key(),
+				genesis_hash: Default::default(),
+			},
+			vec![
+				FetchAssetParams::<Assethub> {
+					deposit_fetch_id: 1,
+					asset: assets::hub::Asset::HubDot,
+					amount: 1_000_000_000,
+				},
+				FetchAssetParams::<Assethub> {
+					deposit_fetch_id: 2,
+					asset: assets::hub::Asset::HubUsdt,
+					amount: 1_000_000,
+				},
+			],
+			vec![TransferAssetParams::<Assethub> {
+				to: PolkadotAccountId::from_aliased([7u8; 32]),
+				amount: 4,
+				asset: assets::hub::Asset::HubUsdc,
+			}],
+			account_id_vault,
+		);
+
+		let AssethubRuntimeCall::Proxy(AssethubProxyCall::proxy { call, .. }) =
+			builder.extrinsic_call.clone()
+		else {
+			panic!("Expected a proxy call");
+		};
+		let AssethubRuntimeCall::Utility(AssethubUtilityCall::force_batch { calls }) = *call else {
+			panic!("Expected a batch call");
+		};
+		assert_eq!(
+			calls,
+			vec![
+				AssethubRuntimeCall::Utility(AssethubUtilityCall::as_derivative {
+					index: 1,
+					call: Box::new(AssethubRuntimeCall::Balances(BalancesCall::transfer_all {
+						dest: PolkadotAccountIdLookup::from(account_id_vault),
+						keep_alive: false,
+					})),
+				}),
+				AssethubRuntimeCall::Utility(AssethubUtilityCall::as_derivative {
+					index: 2,
+					call: Box::new(AssethubRuntimeCall::Assets(AssetsCall::transfer_all {
+						id: USDT_ASSET_ID,
+						dest: PolkadotAccountIdLookup::from(account_id_vault),
+						keep_alive: false,
+					})),
+				}),
+				AssethubRuntimeCall::Assets(AssetsCall::transfer {
+					id: USDC_ASSET_ID,
+					target: PolkadotAccountIdLookup::from(PolkadotAccountId::from_aliased(
+						[7u8; 32]
+					)),
+					amount: 4,
+				}),
+			]
+		);
+
+		let payload = builder.get_signature_payload(
+			TEST_RUNTIME_VERSION.spec_version,
+			TEST_RUNTIME_VERSION.transaction_version,
+		);
+		builder.insert_signature(keypair_proxy.sign(&payload));
+		assert!(builder.is_signed());
+	}
+}
@@ -0,0 +1,51 @@
+use sp_std::{boxed::Box, vec};
+
+use crate::{
+	dot::{BalancesCall, PolkadotAccountId, PolkadotAccountIdLookup, PolkadotReplayProtection},
+	hub::{
+		AssethubExtrinsicBuilder, AssethubProxyCall, AssethubProxyType, AssethubRuntimeCall,
+		AssethubUtilityCall,
+	},
+};
+
+pub fn extrinsic_builder(
+	replay_protection: PolkadotReplayProtection,
+	maybe_old_proxy: Option<PolkadotAccountId>,
+	new_proxy: PolkadotAccountId,
+	vault_account: PolkadotAccountId,
+) -> AssethubExtrinsicBuilder {
+	AssethubExtrinsicBuilder::new(
+		replay_protection,
+		AssethubRuntimeCall::Utility(AssethubUtilityCall::batch_all {
+			calls: vec![
+				AssethubRuntimeCall::Proxy(AssethubProxyCall::proxy {
+					real: PolkadotAccountIdLookup::from(vault_account),
+					force_proxy_type: Some(AssethubProxyType::Any),
+					call: Box::new(AssethubRuntimeCall::Utility(AssethubUtilityCall::batch_all {
+						calls: [
+							Some(AssethubRuntimeCall::Proxy(AssethubProxyCall::add_proxy {
+								delegate: new_proxy.into(),
+								proxy_type: AssethubProxyType::Any,
+								delay: 0,
+							})),
+							maybe_old_proxy.map(|old_proxy| {
+								AssethubRuntimeCall::Proxy(AssethubProxyCall::remove_proxy {
+									delegate: old_proxy.into(),
+									proxy_type: AssethubProxyType::Any,
+									delay: 0,
+								})
+							}),
+						]
+						.into_iter()
+						.flatten()
+						.collect(),
+					})),
+				}),
+				AssethubRuntimeCall::Balances(BalancesCall::transfer_all {
+					dest: new_proxy.into(),
+					keep_alive: false,
+				}),
+			],
+		}),
+	)
+}
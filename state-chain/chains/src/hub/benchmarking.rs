@@ -0,0 +1,22 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::{
+	benchmarking_value::BenchmarkValue,
+	dot::PolkadotReplayProtection,
+	hub::api::{rotate_vault_proxy, AssethubApi},
+};
+
+impl<E> BenchmarkValue for AssethubApi<E> {
+	fn benchmark_value() -> Self {
+		AssethubApi::RotateVaultProxy(rotate_vault_proxy::extrinsic_builder(
+			PolkadotReplayProtection {
+				genesis_hash: Default::default(),
+				signer: BenchmarkValue::benchmark_value(),
+				nonce: Default::default(),
+			},
+			Some(Default::default()),
+			Default::default(),
+			Default::default(),
+		))
+	}
+}
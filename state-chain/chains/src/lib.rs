@@ -43,6 +43,7 @@ pub mod btc;
 pub mod dot;
 pub mod eth;
 pub mod evm;
+pub mod hub;
 pub mod none;
 pub mod sol;
 
@@ -356,6 +357,18 @@ where
 	fn calculate_gas_limit(_call: &Call) -> Option<U256> {
 		Default::default()
 	}
+
+	/// Builds an unsigned replacement for a call that has not been included after
+	/// `blocks_pending` target chain blocks, paying a higher fee. The replacement must spend the
+	/// same inputs as the original, so that at most one of them can ever be included.
+	///
+	/// Returns `None` if the call should not be replaced, which is always the case for chains that
+	/// don't support fee bumping. This must not have side effects: they belong in
+	/// `OnBroadcastReady::on_broadcast_replaced`, which is called once the replacement is committed
+	/// to.
+	fn replace_by_fee(_call: &Call, _blocks_pending: C::ChainBlockNumber) -> Option<Call> {
+		None
+	}
 }
 
 pub trait TransactionMetadata<C: Chain> {
@@ -377,6 +390,9 @@ impl<C: Chain> TransactionMetadata<C> for () {
 pub struct FetchAssetParams<C: Chain> {
 	pub deposit_fetch_id: <C as Chain>::DepositFetchId,
 	pub asset: <C as Chain>::ChainAsset,
+	/// The amount deposited into the channel. Only used by chains that can't fetch the channel's
+	/// whole balance, such as SPL tokens on Solana.
+	pub amount: <C as Chain>::ChainAmount,
 }
 
 /// Contains all the parameters required for transferring an asset on an external chain.
@@ -604,6 +620,17 @@ pub trait FeeEstimationApi<C: Chain> {
 	fn estimate_ingress_fee(&self, asset: C::ChainAsset) -> C::ChainAmount;
 
 	fn estimate_egress_fee(&self, asset: C::ChainAsset) -> C::ChainAmount;
+
+	/// Estimates the gas budget, in the chain's gas asset, that lets the receiver of a CCM with a
+	/// message of `message_length` bytes use up to `gas_limit` gas. Returns `None` if the chain
+	/// does not support CCM.
+	fn estimate_ccm_gas_budget(
+		&self,
+		_gas_limit: u128,
+		_message_length: u32,
+	) -> Option<C::ChainAmount> {
+		None
+	}
 }
 
 impl<C: Chain> FeeEstimationApi<C> for () {
@@ -651,3 +678,15 @@ pub struct ChannelRefundParameters {
 	pub refund_address: ForeignChainAddress,
 	pub min_price: Price,
 }
+
+/// Parameters for splitting a swap into a number of smaller swaps (chunks) that are executed at
+/// regular intervals (dollar-cost averaging).
+#[derive(
+	Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
+pub struct DcaParameters {
+	/// The number of chunks the swap input is split into.
+	pub number_of_chunks: u32,
+	/// The number of blocks between the execution of consecutive chunks.
+	pub chunk_interval: u32,
+}
@@ -369,6 +369,7 @@ impl<C: ChainCrypto + 'static> ApiCall<C> for MockApiCall<C> {
 
 thread_local! {
 	pub static REQUIRES_REFRESH: std::cell::RefCell<bool> = const { RefCell::new(false) };
+	pub static REPLACEMENT_CALL: std::cell::RefCell<Option<Vec<u8>>> = const { RefCell::new(None) };
 }
 
 pub struct MockTransactionBuilder<C, Call>(PhantomData<(C, Call)>);
@@ -379,6 +380,13 @@ impl<C, Call> MockTransactionBuilder<C, Call> {
 	}
 }
 
+impl<C, Call: Encode> MockTransactionBuilder<C, Call> {
+	/// The next call to `replace_by_fee` returns the given replacement.
+	pub fn set_replacement(replacement: Call) {
+		REPLACEMENT_CALL.with(|call| *call.borrow_mut() = Some(replacement.encode()))
+	}
+}
+
 impl<C: Chain<Transaction = MockTransaction>, Call: ApiCall<C::ChainCrypto>>
 	TransactionBuilder<C, Call> for MockTransactionBuilder<C, Call>
 {
@@ -396,4 +404,10 @@ impl<C: Chain<Transaction = MockTransaction>, Call: ApiCall<C::ChainCrypto>>
 	) -> bool {
 		REQUIRES_REFRESH.with(|is_valid| *is_valid.borrow())
 	}
+
+	fn replace_by_fee(_call: &Call, _blocks_pending: C::ChainBlockNumber) -> Option<Call> {
+		REPLACEMENT_CALL.with(|call| call.borrow_mut().take()).map(|encoded| {
+			Call::decode(&mut &encoded[..]).expect("Replacement was encoded from a valid call")
+		})
+	}
 }
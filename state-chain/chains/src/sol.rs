@@ -1,8 +1,9 @@
 pub use cf_primitives::chains::Solana;
 use cf_primitives::{AssetAmount, ChannelId};
 
-use sp_core::ConstBool;
-use sp_std::vec;
+use sp_core::{ed25519, ConstBool};
+use sp_runtime::traits::Verify;
+use sp_std::{vec, vec::Vec};
 
 use sol_prim::SlotNumber;
 
@@ -29,6 +30,26 @@ pub use sol_prim::{
 	Address as SolAddress, Digest as SolHash, Signature as SolSignature,
 };
 
+/// Derives the associated token account of `owner` for the SPL token identified by `mint`.
+pub fn derive_associated_token_account(
+	owner: SolAddress,
+	mint: SolAddress,
+) -> Result<(SolAddress, u8), AddressDerivationError> {
+	use core::str::FromStr;
+	use sol_tx_building_blocks::{ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID};
+
+	DerivedAddressBuilder::from_address(
+		SolAddress::from_str(ASSOCIATED_TOKEN_PROGRAM_ID)
+			.expect("Associated token program id is a valid address"),
+	)?
+	.chain_seed(owner)?
+	.chain_seed(
+		SolAddress::from_str(TOKEN_PROGRAM_ID).expect("Token program id is a valid address"),
+	)?
+	.chain_seed(mint)?
+	.finish()
+}
+
 impl Chain for Solana {
 	const NAME: &'static str = "Solana";
 	const GAS_ASSET: Self::ChainAsset = assets::sol::Asset::Sol;
@@ -48,7 +69,7 @@ impl Chain for Solana {
 	type TransactionMetadata = (); //todo
 	type ReplayProtectionParams = (); //todo
 	type ReplayProtection = (); //todo
-	type TransactionRef = SolHash;
+	type TransactionRef = SolSignature;
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -59,7 +80,7 @@ impl ChainCrypto for SolanaCrypto {
 	type KeyHandoverIsRequired = ConstBool<false>;
 
 	type AggKey = SolAddress;
-	type Payload = (); //todo
+	type Payload = SolMessageBytes;
 	type ThresholdSignature = SolSignature;
 	type TransactionInId = SolHash;
 	type TransactionOutId = Self::ThresholdSignature;
@@ -67,32 +88,40 @@ impl ChainCrypto for SolanaCrypto {
 	type GovKey = SolAddress;
 
 	fn verify_threshold_signature(
-		_agg_key: &Self::AggKey,
-		_payload: &Self::Payload,
-		_signature: &Self::ThresholdSignature,
+		agg_key: &Self::AggKey,
+		payload: &Self::Payload,
+		signature: &Self::ThresholdSignature,
 	) -> bool {
-		todo!()
+		ed25519::Signature::from_raw(signature.0)
+			.verify(&payload.0[..], &ed25519::Public::from_raw(agg_key.0))
 	}
 
-	fn agg_key_to_payload(_agg_key: Self::AggKey, _for_handover: bool) -> Self::Payload {
-		todo!()
+	fn agg_key_to_payload(agg_key: Self::AggKey, _for_handover: bool) -> Self::Payload {
+		SolMessageBytes(agg_key.0.to_vec())
 	}
 
 	fn handover_key_matches(_current_key: &Self::AggKey, _new_key: &Self::AggKey) -> bool {
-		todo!()
+		// Solana does not require key handover.
+		true
 	}
 
 	fn key_handover_is_required() -> bool {
-		todo!()
+		false
 	}
 
 	fn maybe_broadcast_barriers_on_rotation(
-		_rotation_broadcast_id: cf_primitives::BroadcastId,
+		rotation_broadcast_id: cf_primitives::BroadcastId,
 	) -> vec::Vec<cf_primitives::BroadcastId> {
-		todo!()
+		// Transactions signed by the old key become invalid once the vault has been rotated, so we
+		// need to wait for the rotation transaction before broadcasting anything else.
+		vec![rotation_broadcast_id]
 	}
 }
 
+/// The serialized Solana message that is signed by the aggregate key.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct SolMessageBytes(pub Vec<u8>);
+
 pub const LAMPORTS_PER_SIGNATURE: <Solana as Chain>::ChainAmount = 5000;
 
 // This is to be used both for ingress/egress estimation and for setting the compute units
@@ -101,10 +130,10 @@ mod compute_units_costs {
 	pub const BASE_COMPUTE_UNITS_PER_TX: u128 = 450;
 	pub const COMPUTE_UNITS_PER_FETCH_NATIVE: u128 = 7_500;
 	pub const COMPUTE_UNITS_PER_TRANSFER_NATIVE: u128 = 300;
-	#[allow(dead_code)]
 	pub const COMPUTE_UNITS_PER_FETCH_TOKEN: u128 = 31_000;
-	#[allow(dead_code)]
 	pub const COMPUTE_UNITS_PER_TRANSFER_TOKEN: u128 = 41_200;
+	pub const COMPUTE_UNITS_PER_CCM_TRANSFER: u128 = 45_000;
+	pub const COMPUTE_UNITS_PER_ROTATION: u128 = 300_000;
 }
 
 #[derive(
@@ -134,8 +163,7 @@ impl FeeEstimationApi<Solana> for SolTrackedData {
 		let compute_units_per_transfer = BASE_COMPUTE_UNITS_PER_TX +
 			match asset {
 				assets::sol::Asset::Sol => COMPUTE_UNITS_PER_TRANSFER_NATIVE,
-				// TODO: To add when USDC is supported
-				// assets::sol::Asset::SolUsdc => COMPUTE_UNITS_PER_TRANSFER_TOKEN,
+				assets::sol::Asset::SolUsdc => COMPUTE_UNITS_PER_TRANSFER_TOKEN,
 			};
 
 		LAMPORTS_PER_SIGNATURE + (self.priority_fee).saturating_mul(compute_units_per_transfer)
@@ -149,28 +177,33 @@ impl FeeEstimationApi<Solana> for SolTrackedData {
 		let compute_units_per_transfer = BASE_COMPUTE_UNITS_PER_TX +
 			match asset {
 				assets::sol::Asset::Sol => COMPUTE_UNITS_PER_FETCH_NATIVE,
-				// TODO: To add when USDC is supported
-				// assets::sol::Asset::SolUsdc => COMPUTE_UNITS_PER_FETCH_TOKEN,
+				assets::sol::Asset::SolUsdc => COMPUTE_UNITS_PER_FETCH_TOKEN,
 			};
 
 		LAMPORTS_PER_SIGNATURE + (self.priority_fee).saturating_mul(compute_units_per_transfer)
 	}
+
+	fn estimate_ccm_gas_budget(
+		&self,
+		gas_limit: u128,
+		_message_length: u32,
+	) -> Option<<Solana as crate::Chain>::ChainAmount> {
+		use compute_units_costs::*;
+
+		// Solana fees don't depend on the message length, only on the compute units used.
+		let compute_units = BASE_COMPUTE_UNITS_PER_TX
+			.saturating_add(COMPUTE_UNITS_PER_CCM_TRANSFER)
+			.saturating_add(gas_limit);
+
+		Some(LAMPORTS_PER_SIGNATURE + self.priority_fee.saturating_mul(compute_units))
+	}
 }
 
-#[derive(
-	Debug,
-	Clone,
-	Copy,
-	PartialEq,
-	Eq,
-	TypeInfo,
-	Encode,
-	Decode,
-	MaxEncodedLen,
-	Serialize,
-	Deserialize,
-)]
-pub struct SolTransaction {}
+#[derive(Debug, Clone, PartialEq, Eq, TypeInfo, Encode, Decode, Serialize, Deserialize)]
+pub struct SolTransaction {
+	/// The bincode-serialized, signed transaction, ready to be submitted to a Solana node.
+	pub serialized_transaction: Vec<u8>,
+}
 
 impl FeeRefundCalculator<Solana> for SolTransaction {
 	fn return_fee_refund(
@@ -209,3 +242,22 @@ impl address::ToHumanreadableAddress for SolAddress {
 		self.to_string()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use core::str::FromStr;
+	use sol_tx_building_blocks::{
+		MINT_PUB_KEY, TOKEN_VAULT_ASSOCIATED_TOKEN_ACCOUNT, TOKEN_VAULT_PDA_ACCOUNT,
+	};
+
+	#[test]
+	fn can_derive_associated_token_account() {
+		let (ata, _bump) = derive_associated_token_account(
+			SolAddress::from_str(TOKEN_VAULT_PDA_ACCOUNT).unwrap(),
+			SolAddress::from_str(MINT_PUB_KEY).unwrap(),
+		)
+		.unwrap();
+		assert_eq!(ata, SolAddress::from_str(TOKEN_VAULT_ASSOCIATED_TOKEN_ACCOUNT).unwrap());
+	}
+}
@@ -1,117 +1,474 @@
-use core::marker::PhantomData;
+use core::{marker::PhantomData, str::FromStr};
 
 use codec::{Decode, Encode};
 use frame_support::{
-	sp_runtime::DispatchError, CloneNoBound, DebugNoBound, EqNoBound, PartialEqNoBound,
+	sp_runtime::DispatchError, CloneNoBound, DebugNoBound, EqNoBound, Never, PartialEqNoBound,
 };
 use scale_info::TypeInfo;
-use sp_std::vec;
+use sp_std::{vec, vec::Vec};
 
 use crate::{
-	AllBatch, AllBatchError, ApiCall, Chain, ChainCrypto, ConsolidateCall, ConsolidationError,
-	ExecutexSwapAndCall, FetchAssetParams, ForeignChainAddress, SetAggKeyWithAggKey,
-	TransferAssetParams, TransferFallback,
+	assets::sol::Asset as SolAsset, AllBatch, AllBatchError, ApiCall, Chain, ChainCrypto,
+	ConsolidateCall, ConsolidationError, ExecutexSwapAndCall, FetchAssetParams,
+	ForeignChainAddress, SetAggKeyWithAggKey, SetAggKeyWithAggKeyError, TransferAssetParams,
+	TransferFallback,
 };
 
-use super::{Solana, SolanaCrypto};
+use super::{
+	bpf_loader_instructions::set_upgrade_authority,
+	compute_budget::ComputeBudgetInstruction,
+	compute_units_costs::*,
+	consts::SOLANA_SIGNATURE_SIZE,
+	derive_associated_token_account,
+	program_instructions::{ProgramInstruction, SystemProgramInstruction, VaultProgram},
+	sol_tx_building_blocks::{
+		AccountMeta, Hash, Instruction, Message, Pubkey, MAX_TRANSACTION_LENGTH, NONCE_ACCOUNTS,
+		SYSTEM_PROGRAM_ID, SYS_VAR_INSTRUCTIONS, TOKEN_PROGRAM_ID, TOKEN_VAULT_PDA_ACCOUNT,
+		UPGRADE_MANAGER_PROGRAM_DATA_ACCOUNT, VAULT_PROGRAM_DATA_ACCOUNT,
+	},
+	token_instructions::AssociatedTokenAccountInstruction,
+	DerivedAddressBuilder, SolAddress, SolHash, SolMessageBytes, SolSignature, Solana,
+	SolanaCrypto,
+};
+
+/// The number of decimals of the SPL tokens supported on Solana.
+const SOL_USDC_DECIMALS: u8 = 6;
+
+/// The maximum compute unit limit Solana accepts for a transaction.
+const MAX_COMPUTE_UNIT_LIMIT: u128 = 1_400_000;
+
+/// The on-chain values needed to build Solana transactions.
+pub trait SolanaEnvironment {
+	/// The address from which deposit channel addresses are derived.
+	fn vault_address() -> SolAddress;
+
+	/// The mint address of an SPL token.
+	fn token_mint(asset: SolAsset) -> Option<SolAddress>;
+
+	/// The current aggregate key, which signs every transaction and pays its fees.
+	fn current_agg_key() -> Option<SolAddress>;
+
+	/// A durable nonce account and its current nonce. Transactions use the nonce in place of a
+	/// recent blockhash, so that they don't expire before they are broadcast.
+	fn durable_nonce() -> Option<(SolAddress, SolHash)>;
+
+	/// The price of a compute unit, in micro-lamports.
+	fn compute_price() -> u64;
+}
+
+/// A serialized Solana message, and its signature once it has been signed.
+///
+/// Chainflip transactions on Solana have a single signer, the aggregate key, which is also the
+/// fee payer.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct SolanaTransactionData {
+	pub serialized_message: Vec<u8>,
+	pub signature: Option<SolSignature>,
+}
+
+impl SolanaTransactionData {
+	fn new(
+		instructions: &[Instruction],
+		agg_key: SolAddress,
+		durable_nonce: SolHash,
+	) -> Result<Self, DispatchError> {
+		let serialized_message = Message::new_with_blockhash(
+			instructions,
+			Some(&Pubkey(agg_key.0)),
+			&Hash(durable_nonce.0),
+		)
+		.serialize();
+		// One byte for the length of the signatures, followed by the single signature.
+		if 1 + SOLANA_SIGNATURE_SIZE + serialized_message.len() > MAX_TRANSACTION_LENGTH {
+			return Err(DispatchError::Other("Solana transaction is too long"))
+		}
+		Ok(Self { serialized_message, signature: None })
+	}
 
+	/// The transaction in the wire format expected by Solana nodes: a compact array of
+	/// signatures, followed by the message.
+	fn serialized_transaction(&self) -> Vec<u8> {
+		let mut serialized_transaction = vec![1u8];
+		serialized_transaction.extend(self.signature.unwrap_or_default().0);
+		serialized_transaction.extend(&self.serialized_message);
+		serialized_transaction
+	}
+}
+
+/// Chainflip api calls available on Solana.
+///
+/// This encoding replaces the one of the stubbed-out calls, whose builders always panicked. No
+/// call with the previous encoding can have been stored, so no storage migration is needed.
 #[derive(CloneNoBound, DebugNoBound, PartialEqNoBound, EqNoBound, Encode, Decode, TypeInfo)]
-#[scale_info(skip_type_params(Env))]
-pub enum SolanaApi<Env> {
-	AllBatch {
-		fetch_params: vec::Vec<FetchAssetParams<Solana>>,
-		transfer_params: vec::Vec<TransferAssetParams<Solana>>,
-	},
-	SetAggKeyWithAggKey {
-		maybe_old_key: Option<<SolanaCrypto as ChainCrypto>::AggKey>,
-		new_key: <SolanaCrypto as ChainCrypto>::AggKey,
-	},
-	TransferFallback {
-		transfer_param: TransferAssetParams<Solana>,
-	},
-	ExecutexSwapAndCall {
-		transfer_param: TransferAssetParams<Solana>,
-		source_chain: cf_primitives::ForeignChain,
-		source_address: Option<ForeignChainAddress>,
-		gas_budget: <Solana as Chain>::ChainAmount,
-		message: vec::Vec<u8>,
-	},
-	ApiCall {
-		threshold_signature: <SolanaCrypto as ChainCrypto>::ThresholdSignature,
-	},
+#[scale_info(skip_type_params(Environment))]
+pub enum SolanaApi<Environment: 'static> {
+	AllBatch(SolanaTransactionData),
+	SetAggKeyWithAggKey(SolanaTransactionData),
+	TransferFallback(SolanaTransactionData),
+	ExecutexSwapAndCall(SolanaTransactionData),
+	#[doc(hidden)]
+	#[codec(skip)]
+	_Phantom(PhantomData<Environment>, Never),
+}
+
+impl<Environment: 'static> SolanaApi<Environment> {
+	fn transaction_data(&self) -> &SolanaTransactionData {
+		match self {
+			SolanaApi::AllBatch(data) |
+			SolanaApi::SetAggKeyWithAggKey(data) |
+			SolanaApi::TransferFallback(data) |
+			SolanaApi::ExecutexSwapAndCall(data) => data,
+			SolanaApi::_Phantom(..) => unreachable!(),
+		}
+	}
 
-	_PhantomData(PhantomData<Env>),
+	fn transaction_data_mut(&mut self) -> &mut SolanaTransactionData {
+		match self {
+			SolanaApi::AllBatch(data) |
+			SolanaApi::SetAggKeyWithAggKey(data) |
+			SolanaApi::TransferFallback(data) |
+			SolanaApi::ExecutexSwapAndCall(data) => data,
+			SolanaApi::_Phantom(..) => unreachable!(),
+		}
+	}
 }
 
-impl<Env: 'static> ApiCall<SolanaCrypto> for SolanaApi<Env> {
+impl<Environment: 'static> ApiCall<SolanaCrypto> for SolanaApi<Environment> {
 	fn threshold_signature_payload(&self) -> <SolanaCrypto as ChainCrypto>::Payload {
-		todo!()
+		SolMessageBytes(self.transaction_data().serialized_message.clone())
 	}
 
 	fn signed(
-		self,
+		mut self,
 		threshold_signature: &<SolanaCrypto as ChainCrypto>::ThresholdSignature,
 	) -> Self {
-		Self::ApiCall { threshold_signature: *threshold_signature }
+		self.transaction_data_mut().signature = Some(*threshold_signature);
+		self
 	}
 
-	fn chain_encoded(&self) -> vec::Vec<u8> {
-		vec![]
+	fn chain_encoded(&self) -> Vec<u8> {
+		self.transaction_data().serialized_transaction()
 	}
 
 	fn is_signed(&self) -> bool {
-		todo!()
+		self.transaction_data().signature.is_some()
 	}
 
 	fn transaction_out_id(&self) -> <SolanaCrypto as ChainCrypto>::TransactionOutId {
-		todo!()
+		self.transaction_data().signature.unwrap_or_default()
+	}
+}
+
+/// The instructions every transaction starts with: advancing the durable nonce, and setting the
+/// compute budget.
+fn transaction_preamble<E: SolanaEnvironment>(
+	agg_key: SolAddress,
+	nonce_account: SolAddress,
+	compute_units: u128,
+) -> Vec<Instruction> {
+	vec![
+		SystemProgramInstruction::advance_nonce_account(
+			&Pubkey(nonce_account.0),
+			&Pubkey(agg_key.0),
+		),
+		ComputeBudgetInstruction::set_compute_unit_price(E::compute_price()),
+		ComputeBudgetInstruction::set_compute_unit_limit(
+			compute_units.min(MAX_COMPUTE_UNIT_LIMIT) as u32
+		),
+	]
+}
+
+fn to_lamports(amount: <Solana as Chain>::ChainAmount) -> Result<u64, DispatchError> {
+	amount.try_into().map_err(|_| DispatchError::Other("Amount exceeds u64::MAX"))
+}
+
+fn pubkey(address: &str) -> Pubkey {
+	Pubkey::from_str(address).expect("Solana program addresses are valid")
+}
+
+/// The instructions transferring `amount` of `asset` from the vault to `to`.
+fn transfer_instructions<E: SolanaEnvironment>(
+	agg_key: SolAddress,
+	TransferAssetParams { asset, amount, to }: &TransferAssetParams<Solana>,
+) -> Result<Vec<Instruction>, DispatchError> {
+	let amount = to_lamports(*amount)?;
+	match asset {
+		SolAsset::Sol =>
+			Ok(vec![SystemProgramInstruction::transfer(&Pubkey(agg_key.0), &Pubkey(to.0), amount)]),
+		SolAsset::SolUsdc => {
+			let mint = E::token_mint(*asset)
+				.ok_or(DispatchError::Other("Solana token mint is not set"))?;
+			let token_vault = SolAddress::from_str(TOKEN_VAULT_PDA_ACCOUNT)
+				.expect("Token vault address is valid");
+			let (token_vault_ata, _bump) = derive_associated_token_account(token_vault, mint)
+				.map_err(|_| DispatchError::Other("Failed to derive token account"))?;
+			let (to_ata, _bump) = derive_associated_token_account(*to, mint)
+				.map_err(|_| DispatchError::Other("Failed to derive token account"))?;
+			Ok(vec![
+				AssociatedTokenAccountInstruction::create_associated_token_account_idempotent_instruction(
+					&Pubkey(agg_key.0),
+					&Pubkey(to.0),
+					&Pubkey(mint.0),
+					&Pubkey(to_ata.0),
+				),
+				VaultProgram::TransferTokens { amount, decimals: SOL_USDC_DECIMALS }
+					.get_instruction(vec![
+						AccountMeta::new_readonly(pubkey(VAULT_PROGRAM_DATA_ACCOUNT), false),
+						AccountMeta::new_readonly(Pubkey(agg_key.0), true),
+						AccountMeta::new_readonly(Pubkey(token_vault.0), false),
+						AccountMeta::new(Pubkey(token_vault_ata.0), false),
+						AccountMeta::new(Pubkey(to_ata.0), false),
+						AccountMeta::new_readonly(Pubkey(mint.0), false),
+						AccountMeta::new_readonly(pubkey(TOKEN_PROGRAM_ID), false),
+						AccountMeta::new_readonly(pubkey(SYSTEM_PROGRAM_ID), false),
+					]),
+			])
+		},
+	}
+}
+
+/// The instruction fetching a deposit from the deposit channel into the vault. Native deposits
+/// are swept from the channel PDA, token deposits from the PDA's associated token account.
+fn fetch_instruction<E: SolanaEnvironment>(
+	agg_key: SolAddress,
+	FetchAssetParams { deposit_fetch_id: channel_id, asset, amount }: &FetchAssetParams<Solana>,
+) -> Result<Instruction, DispatchError> {
+	let seed = channel_id.to_le_bytes();
+	let (deposit_channel, bump) = DerivedAddressBuilder::from_address(E::vault_address())
+		.and_then(|builder| builder.chain_seed(seed))
+		.and_then(|builder| builder.finish())
+		.map_err(|_| DispatchError::Other("Failed to derive deposit channel"))?;
+	match asset {
+		SolAsset::Sol => Ok(VaultProgram::FetchNative { seed: seed.to_vec(), bump }
+			.get_instruction(vec![
+				AccountMeta::new_readonly(pubkey(VAULT_PROGRAM_DATA_ACCOUNT), false),
+				AccountMeta::new(Pubkey(agg_key.0), true),
+				AccountMeta::new(Pubkey(deposit_channel.0), false),
+				AccountMeta::new_readonly(pubkey(SYSTEM_PROGRAM_ID), false),
+			])),
+		SolAsset::SolUsdc => {
+			let mint = E::token_mint(*asset)
+				.ok_or(DispatchError::Other("Solana token mint is not set"))?;
+			let token_vault = SolAddress::from_str(TOKEN_VAULT_PDA_ACCOUNT)
+				.expect("Token vault address is valid");
+			let (deposit_channel_ata, _bump) =
+				derive_associated_token_account(deposit_channel, mint)
+					.map_err(|_| DispatchError::Other("Failed to derive token account"))?;
+			let (token_vault_ata, _bump) = derive_associated_token_account(token_vault, mint)
+				.map_err(|_| DispatchError::Other("Failed to derive token account"))?;
+			Ok(VaultProgram::FetchTokens {
+				seed: seed.to_vec(),
+				bump,
+				amount: to_lamports(*amount)?,
+				decimals: SOL_USDC_DECIMALS,
+			}
+			.get_instruction(vec![
+				AccountMeta::new_readonly(pubkey(VAULT_PROGRAM_DATA_ACCOUNT), false),
+				AccountMeta::new_readonly(Pubkey(agg_key.0), true),
+				AccountMeta::new_readonly(Pubkey(deposit_channel.0), false),
+				AccountMeta::new(Pubkey(deposit_channel_ata.0), false),
+				AccountMeta::new(Pubkey(token_vault_ata.0), false),
+				AccountMeta::new_readonly(Pubkey(mint.0), false),
+				AccountMeta::new_readonly(pubkey(TOKEN_PROGRAM_ID), false),
+				AccountMeta::new_readonly(pubkey(SYSTEM_PROGRAM_ID), false),
+			]))
+		},
+	}
+}
+
+fn fetch_compute_units(asset: SolAsset) -> u128 {
+	match asset {
+		SolAsset::Sol => COMPUTE_UNITS_PER_FETCH_NATIVE,
+		SolAsset::SolUsdc => COMPUTE_UNITS_PER_FETCH_TOKEN,
 	}
 }
 
-impl<Env: 'static> ConsolidateCall<Solana> for SolanaApi<Env> {
+fn transfer_compute_units(asset: SolAsset) -> u128 {
+	match asset {
+		SolAsset::Sol => COMPUTE_UNITS_PER_TRANSFER_NATIVE,
+		SolAsset::SolUsdc => COMPUTE_UNITS_PER_TRANSFER_TOKEN,
+	}
+}
+
+/// Builds a transaction from the instructions, signed by the current aggregate key.
+fn build_transaction<E: SolanaEnvironment>(
+	build_instructions: impl FnOnce(SolAddress) -> Result<Vec<Instruction>, DispatchError>,
+	compute_units: u128,
+) -> Result<SolanaTransactionData, DispatchError> {
+	let agg_key =
+		E::current_agg_key().ok_or(DispatchError::Other("Solana aggregate key is not set"))?;
+	let (nonce_account, nonce) =
+		E::durable_nonce().ok_or(DispatchError::Other("Solana durable nonce is not set"))?;
+
+	let mut instructions = transaction_preamble::<E>(agg_key, nonce_account, compute_units);
+	instructions.extend(build_instructions(agg_key)?);
+	SolanaTransactionData::new(&instructions, agg_key, nonce)
+}
+
+impl<Environment: SolanaEnvironment + 'static> ConsolidateCall<Solana> for SolanaApi<Environment> {
 	fn consolidate_utxos() -> Result<Self, ConsolidationError> {
 		Err(ConsolidationError::NotRequired)
 	}
 }
 
-impl<Env: 'static> SetAggKeyWithAggKey<SolanaCrypto> for SolanaApi<Env> {
+impl<Environment: SolanaEnvironment + 'static> SetAggKeyWithAggKey<SolanaCrypto>
+	for SolanaApi<Environment>
+{
 	fn new_unsigned(
 		maybe_old_key: Option<<SolanaCrypto as ChainCrypto>::AggKey>,
 		new_key: <SolanaCrypto as ChainCrypto>::AggKey,
-	) -> Result<Option<Self>, crate::SetAggKeyWithAggKeyError> {
-		Ok(Some(Self::SetAggKeyWithAggKey { maybe_old_key, new_key }))
+	) -> Result<Option<Self>, SetAggKeyWithAggKeyError> {
+		// The vault is initialised with the first key, so there is nothing to rotate.
+		let Some(old_key) = maybe_old_key else { return Ok(None) };
+		let (nonce_account, nonce) =
+			Environment::durable_nonce().ok_or(SetAggKeyWithAggKeyError::Failed)?;
+
+		let mut instructions = transaction_preamble::<Environment>(
+			old_key,
+			nonce_account,
+			BASE_COMPUTE_UNITS_PER_TX + COMPUTE_UNITS_PER_ROTATION,
+		);
+		let (old, new) = (Pubkey(old_key.0), Pubkey(new_key.0));
+		instructions.extend([
+			VaultProgram::RotateAggKey { skip_transfer_funds: false }.get_instruction(vec![
+				AccountMeta::new(pubkey(VAULT_PROGRAM_DATA_ACCOUNT), false),
+				AccountMeta::new(old, true),
+				AccountMeta::new(new, false),
+				AccountMeta::new_readonly(pubkey(SYSTEM_PROGRAM_ID), false),
+			]),
+			set_upgrade_authority(pubkey(UPGRADE_MANAGER_PROGRAM_DATA_ACCOUNT), &old, Some(&new)),
+		]);
+		// Every nonce account must be authorised to the new key, or it can't be used again.
+		instructions.extend(NONCE_ACCOUNTS.iter().map(|nonce_account| {
+			SystemProgramInstruction::nonce_authorize(&pubkey(nonce_account), &old, &new)
+		}));
+
+		SolanaTransactionData::new(&instructions, old_key, nonce)
+			.map(|data| Some(Self::SetAggKeyWithAggKey(data)))
+			.map_err(|_| SetAggKeyWithAggKeyError::Failed)
 	}
 }
 
-impl<Env: 'static> ExecutexSwapAndCall<Solana> for SolanaApi<Env> {
+impl<Environment: SolanaEnvironment + 'static> ExecutexSwapAndCall<Solana>
+	for SolanaApi<Environment>
+{
 	fn new_unsigned(
 		transfer_param: TransferAssetParams<Solana>,
 		source_chain: cf_primitives::ForeignChain,
 		source_address: Option<ForeignChainAddress>,
 		gas_budget: <Solana as Chain>::ChainAmount,
-		message: vec::Vec<u8>,
+		message: Vec<u8>,
 	) -> Result<Self, DispatchError> {
-		Ok(Self::ExecutexSwapAndCall {
-			transfer_param,
-			source_chain,
-			source_address,
-			gas_budget,
-			message,
-		})
+		let compute_units = BASE_COMPUTE_UNITS_PER_TX
+			.saturating_add(transfer_compute_units(transfer_param.asset))
+			.saturating_add(COMPUTE_UNITS_PER_CCM_TRANSFER)
+			.saturating_add(gas_budget / (Environment::compute_price().max(1) as u128));
+
+		build_transaction::<Environment>(
+			|agg_key| {
+				let mut instructions =
+					transfer_instructions::<Environment>(agg_key, &transfer_param)?;
+				let amount = to_lamports(transfer_param.amount)?;
+				let source_chain = source_chain as u32;
+				let source_address =
+					source_address.map(|address| address.encode()).unwrap_or_default();
+				let receiver = Pubkey(transfer_param.to.0);
+				instructions.push(match transfer_param.asset {
+					SolAsset::Sol => VaultProgram::ExecuteCcmNativeCall {
+						source_chain,
+						source_address,
+						message,
+						amount,
+					}
+					.get_instruction(vec![
+						AccountMeta::new_readonly(pubkey(VAULT_PROGRAM_DATA_ACCOUNT), false),
+						AccountMeta::new_readonly(Pubkey(agg_key.0), true),
+						AccountMeta::new(receiver, false),
+						AccountMeta::new_readonly(receiver, false),
+						AccountMeta::new_readonly(pubkey(SYSTEM_PROGRAM_ID), false),
+						AccountMeta::new_readonly(pubkey(SYS_VAR_INSTRUCTIONS), false),
+					]),
+					SolAsset::SolUsdc => {
+						let mint = Environment::token_mint(transfer_param.asset)
+							.ok_or(DispatchError::Other("Solana token mint is not set"))?;
+						let (receiver_ata, _bump) =
+							derive_associated_token_account(transfer_param.to, mint).map_err(
+								|_| DispatchError::Other("Failed to derive token account"),
+							)?;
+						VaultProgram::ExecuteCcmTokenCall {
+							source_chain,
+							source_address,
+							message,
+							amount,
+						}
+						.get_instruction(vec![
+							AccountMeta::new_readonly(pubkey(VAULT_PROGRAM_DATA_ACCOUNT), false),
+							AccountMeta::new_readonly(Pubkey(agg_key.0), true),
+							AccountMeta::new(Pubkey(receiver_ata.0), false),
+							AccountMeta::new_readonly(receiver, false),
+							AccountMeta::new_readonly(pubkey(TOKEN_PROGRAM_ID), false),
+							AccountMeta::new_readonly(Pubkey(mint.0), false),
+							AccountMeta::new_readonly(pubkey(SYS_VAR_INSTRUCTIONS), false),
+						])
+					},
+				});
+				Ok(instructions)
+			},
+			compute_units,
+		)
+		.map(Self::ExecutexSwapAndCall)
 	}
 }
 
-impl<Env: 'static> AllBatch<Solana> for SolanaApi<Env> {
+impl<Environment: SolanaEnvironment + 'static> AllBatch<Solana> for SolanaApi<Environment> {
 	fn new_unsigned(
-		fetch_params: vec::Vec<FetchAssetParams<Solana>>,
-		transfer_params: vec::Vec<TransferAssetParams<Solana>>,
+		fetch_params: Vec<FetchAssetParams<Solana>>,
+		transfer_params: Vec<TransferAssetParams<Solana>>,
 	) -> Result<Self, AllBatchError> {
-		Ok(Self::AllBatch { fetch_params, transfer_params })
+		if fetch_params.is_empty() && transfer_params.is_empty() {
+			return Err(AllBatchError::NotRequired)
+		}
+		Environment::current_agg_key().ok_or(AllBatchError::AggKeyNotSet)?;
+
+		let compute_units = BASE_COMPUTE_UNITS_PER_TX
+			.saturating_add(
+				fetch_params
+					.iter()
+					.map(|params| fetch_compute_units(params.asset))
+					.sum::<u128>(),
+			)
+			.saturating_add(
+				transfer_params
+					.iter()
+					.map(|params| transfer_compute_units(params.asset))
+					.sum::<u128>(),
+			);
+
+		build_transaction::<Environment>(
+			|agg_key| {
+				let mut instructions = Vec::new();
+				for fetch_param in &fetch_params {
+					instructions.push(fetch_instruction::<Environment>(agg_key, fetch_param)?);
+				}
+				for transfer_param in &transfer_params {
+					instructions
+						.extend(transfer_instructions::<Environment>(agg_key, transfer_param)?);
+				}
+				Ok(instructions)
+			},
+			compute_units,
+		)
+		.map(Self::AllBatch)
+		.map_err(AllBatchError::DispatchError)
 	}
 }
 
-impl<Env: 'static> TransferFallback<Solana> for SolanaApi<Env> {
+impl<Environment: SolanaEnvironment + 'static> TransferFallback<Solana> for SolanaApi<Environment> {
 	fn new_unsigned(transfer_param: TransferAssetParams<Solana>) -> Result<Self, DispatchError> {
-		Ok(Self::TransferFallback { transfer_param })
+		build_transaction::<Environment>(
+			|agg_key| transfer_instructions::<Environment>(agg_key, &transfer_param),
+			BASE_COMPUTE_UNITS_PER_TX + transfer_compute_units(transfer_param.asset),
+		)
+		.map(Self::TransferFallback)
 	}
 }
@@ -1,6 +1,10 @@
 #![cfg(feature = "runtime-benchmarks")]
 
-use super::{api::SolanaApi, SolAddress, SolHash, SolSignature, SolTrackedData, SolTransaction};
+use super::{
+	api::{SolanaApi, SolanaTransactionData},
+	SolAddress, SolHash, SolMessageBytes, SolSignature, SolTrackedData, SolTransaction,
+};
+use sp_std::vec;
 
 use crate::benchmarking_value::{BenchmarkValue, BenchmarkValueExtended};
 
@@ -24,7 +28,7 @@ impl BenchmarkValue for SolTrackedData {
 
 impl BenchmarkValue for SolTransaction {
 	fn benchmark_value() -> Self {
-		SolTransaction {}
+		SolTransaction { serialized_transaction: vec![6u8; 64] }
 	}
 }
 
@@ -34,17 +38,23 @@ impl BenchmarkValue for SolSignature {
 	}
 }
 
+impl BenchmarkValue for SolMessageBytes {
+	fn benchmark_value() -> Self {
+		SolMessageBytes(vec![3u8; 64])
+	}
+}
+
 impl BenchmarkValue for SolHash {
 	fn benchmark_value() -> Self {
 		[5u8; 32].into()
 	}
 }
 
-impl<E> BenchmarkValue for SolanaApi<E> {
+impl<E: 'static> BenchmarkValue for SolanaApi<E> {
 	fn benchmark_value() -> Self {
-		SolanaApi::SetAggKeyWithAggKey {
-			maybe_old_key: Some([7u8; 32].into()),
-			new_key: [8u8; 32].into(),
-		}
+		SolanaApi::SetAggKeyWithAggKey(SolanaTransactionData {
+			serialized_message: vec![7u8; 64],
+			signature: None,
+		})
 	}
 }
@@ -491,7 +491,6 @@ impl Message {
 		}
 	}
 
-	#[cfg(test)]
 	pub fn serialize(&self) -> Vec<u8> {
 		bincode::serde::encode_to_vec(self, bincode::config::legacy()).unwrap()
 	}
@@ -8,11 +8,11 @@ use cf_chains::{
 	address::{ForeignChainAddressHumanreadable, ToHumanreadableAddress},
 	dot::PolkadotAccountId,
 	eth::Address as EthereumAddress,
-	Chain,
+	CcmChannelMetadata, Chain,
 };
 use cf_primitives::{
-	chains::assets::any, AccountRole, Asset, AssetAmount, BlockNumber, BroadcastId, EpochIndex,
-	ForeignChain, NetworkEnvironment, SemVer, SwapId,
+	chains::assets::any, AccountRole, Affiliates, Asset, AssetAmount, BasisPoints, BlockNumber,
+	BroadcastId, EpochIndex, ForeignChain, NetworkEnvironment, SemVer, SwapId,
 };
 use cf_utilities::rpc::NumberOrHex;
 use codec::Encode;
@@ -23,9 +23,9 @@ use jsonrpsee::{
 	types::error::{CallError, SubscriptionEmptyError},
 	SubscriptionSink,
 };
-use pallet_cf_governance::GovCallHash;
+use pallet_cf_governance::{ExecutionMode, GovCallHash, ProposalId, TimeLockedProposal};
 use pallet_cf_pools::{AskBidMap, PoolInfo, PoolLiquidity, PoolPriceV1, UnidirectionalPoolDepth};
-use pallet_cf_swapping::SwapLegInfo;
+use pallet_cf_swapping::{CcmFailReason, DcaInfo, SwapLegInfo};
 use sc_client_api::{BlockchainEvents, HeaderBackend};
 use serde::{Deserialize, Serialize};
 use sp_api::ApiError;
@@ -43,8 +43,9 @@ use state_chain_runtime::{
 		PendingTssCeremonies, RedemptionsInfo,
 	},
 	runtime_apis::{
-		BoostPoolDepth, BoostPoolDetails, BrokerInfo, CustomRuntimeApi, DispatchErrorWithMessage,
-		EventFilter, FailingWitnessValidators, LiquidityProviderInfo, ValidatorInfo,
+		BoostPoolDepth, BoostPoolDetails, BrokerInfo, CcmDepositCheck, CustomRuntimeApi,
+		DispatchErrorWithMessage, EventFilter, FailingWitnessValidators, LiquidityProviderInfo,
+		ValidatorInfo,
 	},
 	NetworkFee,
 };
@@ -165,11 +166,41 @@ pub struct ScheduledSwap {
 	#[serde(skip_serializing_if = "Option::is_none")]
 	pub source_amount: Option<U256>,
 	pub execute_at: BlockNumber,
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub dca_info: Option<ScheduledSwapDcaInfo>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledSwapDcaInfo {
+	pub remaining_chunks: u32,
+	pub chunk_interval: u32,
+	pub remaining_input_amount: U256,
+	pub accumulated_output_amount: U256,
+}
+
+impl From<DcaInfo> for ScheduledSwapDcaInfo {
+	fn from(dca_info: DcaInfo) -> Self {
+		Self {
+			remaining_chunks: dca_info.remaining_chunks,
+			chunk_interval: dca_info.chunk_interval,
+			remaining_input_amount: dca_info.remaining_input_amount.into(),
+			accumulated_output_amount: dca_info.accumulated_output_amount.into(),
+		}
+	}
 }
 
 impl ScheduledSwap {
 	fn new(
-		SwapLegInfo { swap_id, base_asset, quote_asset, side, amount, source_asset, source_amount}: SwapLegInfo,
+		SwapLegInfo {
+			swap_id,
+			base_asset,
+			quote_asset,
+			side,
+			amount,
+			source_asset,
+			source_amount,
+			dca_info,
+		}: SwapLegInfo,
 		execute_at: BlockNumber,
 	) -> Self {
 		ScheduledSwap {
@@ -181,6 +212,7 @@ impl ScheduledSwap {
 			source_asset,
 			source_amount: source_amount.map(Into::into),
 			execute_at,
+			dca_info: dca_info.map(Into::into),
 		}
 	}
 }
@@ -351,6 +383,97 @@ pub struct RpcSwapOutputV2 {
 	pub network_fee: RpcFee,
 	pub ingress_fee: RpcFee,
 	pub egress_fee: RpcFee,
+	// Assets the swap passes through, from the input to the output asset
+	pub route: Vec<Asset>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RpcSwapQuote {
+	// Only present if the deposit would be boosted
+	pub boost_fee: Option<RpcFee>,
+	pub ingress_fee: RpcFee,
+	// Commission of the broker and its affiliates
+	pub broker_fee: RpcFee,
+	// Amount of the source asset that is swapped
+	pub swap_input: U256,
+	// Intermediary amount, if there's any
+	pub intermediary: Option<U256>,
+	pub network_fee: RpcFee,
+	// Amount of the destination asset the swap outputs
+	pub swap_output: U256,
+	pub egress_fee: RpcFee,
+	// Amount expected to be egressed
+	pub output: U256,
+	// Assets the swap passes through, from the input to the output asset
+	pub route: Vec<Asset>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RpcTimeLockedGovernanceProposal {
+	pub proposal_id: ProposalId,
+	// SCALE-encoded call
+	pub call: sp_core::Bytes,
+	pub manual_execution: bool,
+	// Unix time in seconds from which the proposal can be executed
+	pub enactment_time: u64,
+	pub cancellation_approvals: Vec<state_chain_runtime::AccountId>,
+}
+
+impl From<(ProposalId, TimeLockedProposal<state_chain_runtime::AccountId>)>
+	for RpcTimeLockedGovernanceProposal
+{
+	fn from(
+		(proposal_id, proposal): (ProposalId, TimeLockedProposal<state_chain_runtime::AccountId>),
+	) -> Self {
+		Self {
+			proposal_id,
+			call: proposal.call.into(),
+			manual_execution: proposal.execution == ExecutionMode::Manual,
+			enactment_time: proposal.enactment_time,
+			cancellation_approvals: proposal.cancellation_approvals.into_iter().collect(),
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RpcCcmGasBudgetEstimate {
+	// Gas budget in the destination chain's gas asset
+	pub gas_budget: RpcFee,
+	// Amount of the source asset to set aside as gas budget
+	pub source_asset_gas_budget: RpcFee,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum RpcCcmFailReason {
+	UnsupportedForTargetChain,
+	InsufficientDepositAmount,
+	MessageTooLong,
+}
+
+impl From<CcmFailReason> for RpcCcmFailReason {
+	fn from(reason: CcmFailReason) -> Self {
+		match reason {
+			CcmFailReason::UnsupportedForTargetChain => Self::UnsupportedForTargetChain,
+			CcmFailReason::InsufficientDepositAmount => Self::InsufficientDepositAmount,
+			CcmFailReason::MessageTooLong => Self::MessageTooLong,
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RpcCcmDepositCheck {
+	Accepted {
+		// Amount of the source asset that is swapped to the destination asset
+		principal_swap_amount: U256,
+		// Amount of the source asset that is used as gas budget
+		gas_budget: U256,
+		// What the gas budget is expected to be worth in the destination chain's gas asset
+		estimated_destination_gas_budget: RpcFee,
+	},
+	Rejected {
+		reason: RpcCcmFailReason,
+	},
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -414,6 +537,16 @@ pub struct PoolPriceV2 {
 	pub price: pallet_cf_pools::PoolPriceV2,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PoolTwap {
+	pub base_asset: Asset,
+	pub quote_asset: Asset,
+	pub from_block: BlockNumber,
+	pub to_block: BlockNumber,
+	#[serde(flatten)]
+	pub twap: pallet_cf_pools::PoolTwap,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub struct RpcPrewitnessedSwap {
 	pub base_asset: Asset,
@@ -651,6 +784,15 @@ pub trait CustomApi {
 		quote_asset: Asset,
 		at: Option<state_chain_runtime::Hash>,
 	) -> RpcResult<PoolPriceV2>;
+	#[method(name = "pool_twap")]
+	fn cf_pool_twap(
+		&self,
+		base_asset: Asset,
+		quote_asset: Asset,
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<PoolTwap>;
 	#[method(name = "swap_rate")]
 	fn cf_pool_swap_rate(
 		&self,
@@ -668,6 +810,35 @@ pub trait CustomApi {
 		additional_orders: Option<Vec<SwapRateV2AdditionalOrder>>,
 		at: Option<state_chain_runtime::Hash>,
 	) -> RpcResult<RpcSwapOutputV2>;
+	#[method(name = "swap_quote")]
+	fn cf_swap_quote(
+		&self,
+		from_asset: Asset,
+		to_asset: Asset,
+		amount: U256,
+		broker_commission: Option<BasisPoints>,
+		affiliates: Option<Affiliates<state_chain_runtime::AccountId>>,
+		boost_fee: Option<BasisPoints>,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<RpcSwapQuote>;
+	#[method(name = "ccm_gas_budget_estimate")]
+	fn cf_ccm_gas_budget_estimate(
+		&self,
+		source_asset: Asset,
+		destination_chain: ForeignChain,
+		message_length: u32,
+		gas_limit: U256,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<RpcCcmGasBudgetEstimate>;
+	#[method(name = "ccm_deposit_check")]
+	fn cf_ccm_deposit_check(
+		&self,
+		source_asset: Asset,
+		destination_asset: Asset,
+		deposit_amount: U256,
+		channel_metadata: CcmChannelMetadata,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<RpcCcmDepositCheck>;
 	#[method(name = "required_asset_ratio_for_range_order")]
 	fn cf_required_asset_ratio_for_range_order(
 		&self,
@@ -796,6 +967,18 @@ pub trait CustomApi {
 		broadcast_id: BroadcastId,
 	) -> RpcResult<Option<<cf_chains::Arbitrum as Chain>::Transaction>>;
 
+	#[method(name = "aborted_broadcasts")]
+	fn cf_aborted_broadcasts(
+		&self,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<(ForeignChain, BroadcastId)>>;
+
+	#[method(name = "time_locked_governance_proposals")]
+	fn cf_time_locked_governance_proposals(
+		&self,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<RpcTimeLockedGovernanceProposal>>;
+
 	#[method(name = "witness_count")]
 	fn cf_witness_count(
 		&self,
@@ -1185,6 +1368,34 @@ where
 		})
 	}
 
+	fn cf_pool_twap(
+		&self,
+		base_asset: Asset,
+		quote_asset: Asset,
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<PoolTwap> {
+		Ok(PoolTwap {
+			base_asset,
+			quote_asset,
+			from_block,
+			to_block,
+			twap: self
+				.client
+				.runtime_api()
+				.cf_pool_twap(
+					self.unwrap_or_best(at),
+					base_asset,
+					quote_asset,
+					from_block,
+					to_block,
+				)
+				.map_err(to_rpc_error)
+				.and_then(|result| result.map_err(map_dispatch_error))?,
+		})
+	}
+
 	fn cf_pool_swap_rate(
 		&self,
 		from_asset: Asset,
@@ -1248,8 +1459,14 @@ where
 			.map(|simulated_swap_info| RpcSwapOutputV2 {
 				intermediary: simulated_swap_info.intermediary.map(Into::into),
 				output: simulated_swap_info.output.into(),
+				// The network fee is taken in the stable asset unless the route avoids it, in
+				// which case it is taken from the input:
 				network_fee: RpcFee {
-					asset: cf_primitives::STABLE_ASSET,
+					asset: if simulated_swap_info.route.contains(&cf_primitives::STABLE_ASSET) {
+						cf_primitives::STABLE_ASSET
+					} else {
+						from_asset
+					},
 					amount: simulated_swap_info.network_fee.into(),
 				},
 				ingress_fee: RpcFee {
@@ -1260,6 +1477,137 @@ where
 					asset: to_asset,
 					amount: simulated_swap_info.egress_fee.into(),
 				},
+				route: simulated_swap_info.route,
+			})
+	}
+
+	fn cf_swap_quote(
+		&self,
+		from_asset: Asset,
+		to_asset: Asset,
+		amount: U256,
+		broker_commission: Option<BasisPoints>,
+		affiliates: Option<Affiliates<state_chain_runtime::AccountId>>,
+		boost_fee: Option<BasisPoints>,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<RpcSwapQuote> {
+		let total_commission = affiliates
+			.unwrap_or_default()
+			.iter()
+			.try_fold(broker_commission.unwrap_or_default(), |total, affiliate| {
+				total.checked_add(affiliate.bps)
+			})
+			.ok_or_else(|| anyhow::anyhow!("Total broker commission is too large."))?;
+
+		self.client
+			.runtime_api()
+			.cf_swap_quote(
+				self.unwrap_or_best(at),
+				from_asset,
+				to_asset,
+				amount
+					.try_into()
+					.and_then(|amount| {
+						if amount == 0 {
+							Err("Swap input amount cannot be zero.")
+						} else {
+							Ok(amount)
+						}
+					})
+					.map_err(|str| anyhow::anyhow!(str))?,
+				total_commission,
+				boost_fee.unwrap_or_default(),
+			)
+			.map_err(to_rpc_error)
+			.and_then(|result| result.map_err(map_dispatch_error))
+			.map(|quote| RpcSwapQuote {
+				boost_fee: quote
+					.boost_fee
+					.map(|amount| RpcFee { asset: from_asset, amount: amount.into() }),
+				ingress_fee: RpcFee { asset: from_asset, amount: quote.ingress_fee.into() },
+				broker_fee: RpcFee { asset: from_asset, amount: quote.broker_fee.into() },
+				swap_input: quote.swap_input.into(),
+				intermediary: quote.intermediary.map(Into::into),
+				network_fee: RpcFee {
+					asset: if quote.route.contains(&cf_primitives::STABLE_ASSET) {
+						cf_primitives::STABLE_ASSET
+					} else {
+						from_asset
+					},
+					amount: quote.network_fee.into(),
+				},
+				swap_output: quote.swap_output.into(),
+				egress_fee: RpcFee { asset: to_asset, amount: quote.egress_fee.into() },
+				output: quote.output.into(),
+				route: quote.route,
+			})
+	}
+
+	fn cf_ccm_gas_budget_estimate(
+		&self,
+		source_asset: Asset,
+		destination_chain: ForeignChain,
+		message_length: u32,
+		gas_limit: U256,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<RpcCcmGasBudgetEstimate> {
+		self.client
+			.runtime_api()
+			.cf_ccm_gas_budget_estimate(
+				self.unwrap_or_best(at),
+				source_asset,
+				destination_chain,
+				message_length,
+				gas_limit.try_into().map_err(|str| anyhow::anyhow!(str))?,
+			)
+			.map_err(to_rpc_error)
+			.and_then(|result| result.map_err(map_dispatch_error))
+			.map(|estimate| RpcCcmGasBudgetEstimate {
+				gas_budget: RpcFee {
+					asset: estimate.gas_asset,
+					amount: estimate.gas_budget.into(),
+				},
+				source_asset_gas_budget: RpcFee {
+					asset: source_asset,
+					amount: estimate.source_asset_gas_budget.into(),
+				},
+			})
+	}
+
+	fn cf_ccm_deposit_check(
+		&self,
+		source_asset: Asset,
+		destination_asset: Asset,
+		deposit_amount: U256,
+		channel_metadata: CcmChannelMetadata,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<RpcCcmDepositCheck> {
+		self.client
+			.runtime_api()
+			.cf_ccm_deposit_check(
+				self.unwrap_or_best(at),
+				source_asset,
+				destination_asset,
+				deposit_amount.try_into().map_err(|str| anyhow::anyhow!(str))?,
+				channel_metadata,
+			)
+			.map_err(to_rpc_error)
+			.and_then(|result| result.map_err(map_dispatch_error))
+			.map(|check| match check {
+				CcmDepositCheck::Accepted {
+					principal_swap_amount,
+					gas_budget,
+					estimated_destination_gas_budget,
+				} => RpcCcmDepositCheck::Accepted {
+					principal_swap_amount: principal_swap_amount.into(),
+					gas_budget: gas_budget.into(),
+					estimated_destination_gas_budget: RpcFee {
+						asset: ForeignChain::from(destination_asset).gas_asset(),
+						amount: estimated_destination_gas_budget.into(),
+					},
+				},
+				CcmDepositCheck::Rejected(reason) =>
+					RpcCcmDepositCheck::Rejected { reason: reason.into() },
 			})
 	}
 
@@ -1661,6 +2009,27 @@ where
 			.map_err(to_rpc_error)
 	}
 
+	fn cf_aborted_broadcasts(
+		&self,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<(ForeignChain, BroadcastId)>> {
+		self.client
+			.runtime_api()
+			.cf_aborted_broadcasts(self.unwrap_or_best(at))
+			.map_err(to_rpc_error)
+	}
+
+	fn cf_time_locked_governance_proposals(
+		&self,
+		at: Option<state_chain_runtime::Hash>,
+	) -> RpcResult<Vec<RpcTimeLockedGovernanceProposal>> {
+		self.client
+			.runtime_api()
+			.cf_time_locked_governance_proposals(self.unwrap_or_best(at))
+			.map(|proposals| proposals.into_iter().map(Into::into).collect())
+			.map_err(to_rpc_error)
+	}
+
 	fn cf_witness_count(
 		&self,
 		hash: state_chain_runtime::Hash,
@@ -1856,7 +2225,7 @@ mod test {
 	use std::collections::BTreeSet;
 
 	use super::*;
-	use cf_chains::assets::sol;
+	use cf_chains::assets::{hub, sol};
 	use cf_primitives::{
 		chains::assets::{any, arb, btc, dot, eth},
 		FLIPPERINOS_PER_FLIP,
@@ -1894,6 +2263,10 @@ mod test {
 					(Asset::ArbEth, 0),
 					(Asset::ArbUsdc, 0),
 					(Asset::Sol, 0),
+					(Asset::SolUsdc, 0),
+					(Asset::HubDot, 0),
+					(Asset::HubUsdt, 0),
+					(Asset::HubUsdc, 0),
 				]
 			}
 		))
@@ -1919,6 +2292,7 @@ mod test {
 						Some(cf_chains::ForeignChainAddress::Arb(H160::from([2; 20]))),
 					),
 					(ForeignChain::Solana, None),
+					(ForeignChain::Assethub, None),
 				],
 				balances: vec![
 					(Asset::Eth, u128::MAX),
@@ -1930,6 +2304,10 @@ mod test {
 					(Asset::ArbEth, 1),
 					(Asset::ArbUsdc, 2),
 					(Asset::Sol, 3),
+					(Asset::SolUsdc, 4),
+					(Asset::HubDot, 5),
+					(Asset::HubUsdt, 6),
+					(Asset::HubUsdc, 7),
 				],
 				earned_fees: any::AssetMap {
 					eth: eth::AssetMap {
@@ -1941,7 +2319,8 @@ mod test {
 					btc: btc::AssetMap { btc: 0u32.into() },
 					dot: dot::AssetMap { dot: 0u32.into() },
 					arb: arb::AssetMap { eth: 1u32.into(), usdc: 2u32.into() },
-					sol: sol::AssetMap { sol: 2u32.into() },
+					sol: sol::AssetMap { sol: 2u32.into(), usdc: 4u32.into() },
+					hub: hub::AssetMap { dot: 2u32.into(), usdt: 4u32.into(), usdc: 4u32.into() },
 				},
 			},
 			cf_primitives::NetworkEnvironment::Mainnet,
@@ -1989,7 +2368,8 @@ mod test {
 					btc: btc::AssetMap { btc: Some(0u32.into()) },
 					dot: dot::AssetMap { dot: None },
 					arb: arb::AssetMap { eth: None, usdc: Some(0u32.into()) },
-					sol: sol::AssetMap { sol: None },
+					sol: sol::AssetMap { sol: None, usdc: None },
+					hub: hub::AssetMap { dot: None, usdt: None, usdc: None },
 				},
 				network_fee_hundredth_pips: Permill::from_percent(100),
 			},
@@ -2004,7 +2384,8 @@ mod test {
 					btc: btc::AssetMap { btc: 0u32.into() },
 					dot: dot::AssetMap { dot: 0u32.into() },
 					arb: arb::AssetMap { eth: 0u32.into(), usdc: u64::MAX.into() },
-					sol: sol::AssetMap { sol: 0u32.into() },
+					sol: sol::AssetMap { sol: 0u32.into(), usdc: 0u32.into() },
+					hub: hub::AssetMap { dot: 0u32.into(), usdt: 0u32.into(), usdc: 0u32.into() },
 				},
 				ingress_fees: any::AssetMap {
 					eth: eth::AssetMap {
@@ -2016,7 +2397,8 @@ mod test {
 					btc: btc::AssetMap { btc: Some(0u32.into()) },
 					dot: dot::AssetMap { dot: Some((u64::MAX / 2 - 1).into()) },
 					arb: arb::AssetMap { eth: Some(0u32.into()), usdc: None },
-					sol: sol::AssetMap { sol: Some(0u32.into()) },
+					sol: sol::AssetMap { sol: Some(0u32.into()), usdc: None },
+					hub: hub::AssetMap { dot: Some(0u32.into()), usdt: None, usdc: None },
 				},
 				egress_fees: any::AssetMap {
 					eth: eth::AssetMap {
@@ -2028,7 +2410,8 @@ mod test {
 					btc: btc::AssetMap { btc: Some(0u32.into()) },
 					dot: dot::AssetMap { dot: Some((u64::MAX / 2 - 1).into()) },
 					arb: arb::AssetMap { eth: Some(0u32.into()), usdc: None },
-					sol: sol::AssetMap { sol: Some(1u32.into()) },
+					sol: sol::AssetMap { sol: Some(1u32.into()), usdc: None },
+					hub: hub::AssetMap { dot: Some(1u32.into()), usdt: None, usdc: None },
 				},
 				witness_safety_margins: HashMap::from([
 					(ForeignChain::Bitcoin, Some(3u64)),
@@ -2036,6 +2419,7 @@ mod test {
 					(ForeignChain::Polkadot, None),
 					(ForeignChain::Arbitrum, None),
 					(ForeignChain::Solana, None),
+					(ForeignChain::Assethub, None),
 				]),
 				egress_dust_limits: any::AssetMap {
 					eth: eth::AssetMap {
@@ -2047,7 +2431,8 @@ mod test {
 					btc: btc::AssetMap { btc: 0u32.into() },
 					dot: dot::AssetMap { dot: 0u32.into() },
 					arb: arb::AssetMap { eth: 0u32.into(), usdc: u64::MAX.into() },
-					sol: sol::AssetMap { sol: 0u32.into() },
+					sol: sol::AssetMap { sol: 0u32.into(), usdc: 0u32.into() },
+					hub: hub::AssetMap { dot: 0u32.into(), usdt: 0u32.into(), usdc: 0u32.into() },
 				},
 				channel_opening_fees: HashMap::from([
 					(ForeignChain::Bitcoin, 0u32.into()),
@@ -2055,6 +2440,7 @@ mod test {
 					(ForeignChain::Polkadot, 1000u32.into()),
 					(ForeignChain::Arbitrum, 1000u32.into()),
 					(ForeignChain::Solana, 1000u32.into()),
+					(ForeignChain::Assethub, 1000u32.into()),
 				]),
 			},
 			funding: FundingEnvironment {
@@ -2082,7 +2468,12 @@ mod test {
 						btc: btc::AssetMap { btc: Some(pool_info) },
 						dot: dot::AssetMap { dot: Some(pool_info) },
 						arb: arb::AssetMap { eth: Some(pool_info), usdc: Some(pool_info) },
-						sol: sol::AssetMap { sol: Some(pool_info) },
+						sol: sol::AssetMap { sol: Some(pool_info), usdc: Some(pool_info) },
+						hub: hub::AssetMap {
+							dot: Some(pool_info),
+							usdt: Some(pool_info),
+							usdc: Some(pool_info),
+						},
 					},
 				}
 			},
@@ -2167,6 +2558,32 @@ mod test {
 			network_fee: RpcFee { asset: Asset::Usdc, amount: 1_000u128.into() },
 			ingress_fee: RpcFee { asset: Asset::Flip, amount: 500u128.into() },
 			egress_fee: RpcFee { asset: Asset::Eth, amount: 1_000_000u128.into() },
+			route: vec![Asset::Flip, Asset::Usdc, Asset::Eth],
+		})
+		.unwrap());
+	}
+
+	#[test]
+	fn test_ccm_deposit_check_serialization() {
+		insta::assert_snapshot!(serde_json::to_value(RpcCcmDepositCheck::Accepted {
+			principal_swap_amount: 1_000_000u128.into(),
+			gas_budget: 500u128.into(),
+			estimated_destination_gas_budget: RpcFee {
+				asset: Asset::Eth,
+				amount: 1_000u128.into()
+			},
+		})
+		.unwrap());
+	}
+
+	#[test]
+	fn test_time_locked_governance_proposal_serialization() {
+		insta::assert_snapshot!(serde_json::to_value(RpcTimeLockedGovernanceProposal {
+			proposal_id: 1,
+			call: vec![0x1d, 0x01].into(),
+			manual_execution: false,
+			enactment_time: 1_700_000_000,
+			cancellation_approvals: vec![AccountId32::new([1; 32])],
 		})
 		.unwrap());
 	}
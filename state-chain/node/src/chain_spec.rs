@@ -3,8 +3,8 @@ use cf_chains::{
 	assets::btc,
 	btc::BITCOIN_DUST_LIMIT,
 	dot::{PolkadotAccountId, PolkadotHash},
-	sol::{SolAddress, SolTrackedData},
-	Arbitrum, ChainState, Solana,
+	sol::{SolAddress, SolHash, SolTrackedData},
+	Arbitrum, Assethub, ChainState, Solana,
 };
 use cf_primitives::{
 	AccountRole, AuthorityCount, NetworkEnvironment, DEFAULT_MAX_AUTHORITY_SET_CONTRACTION,
@@ -100,7 +100,12 @@ pub struct StateChainEnvironment {
 	dot_genesis_hash: PolkadotHash,
 	dot_vault_account_id: Option<PolkadotAccountId>,
 	dot_runtime_version: RuntimeVersion,
+	hub_genesis_hash: Option<PolkadotHash>,
+	hub_vault_account_id: Option<PolkadotAccountId>,
+	hub_runtime_version: RuntimeVersion,
 	sol_vault_address: SolAddress,
+	sol_usdc_address: SolAddress,
+	sol_genesis_hash: Option<SolHash>,
 }
 
 /// Get the values from the State Chain's environment variables. Else set them via the defaults
@@ -138,6 +143,11 @@ pub fn get_environment_or_defaults(defaults: StateChainEnvironment) -> StateChai
 	from_env_var!(FromStr::from_str, GENESIS_FUNDING, genesis_funding_amount);
 	from_env_var!(FromStr::from_str, MIN_FUNDING, min_funding);
 	from_env_var!(FromStr::from_str, SOL_VAULT_ADDRESS, sol_vault_address);
+	from_env_var!(FromStr::from_str, SOL_USDC_ADDRESS, sol_usdc_address);
+	let sol_genesis_hash = match env::var("SOL_GENESIS_HASH") {
+		Ok(s) => Some(SolHash::from_str(&s).expect("Unable to parse env var SOL_GENESIS_HASH.")),
+		Err(_) => defaults.sol_genesis_hash,
+	};
 
 	let dot_genesis_hash = match env::var("DOT_GENESIS_HASH") {
 		Ok(s) => hex_decode::<32>(&s).unwrap().into(),
@@ -157,6 +167,24 @@ pub fn get_environment_or_defaults(defaults: StateChainEnvironment) -> StateChai
 		Err(_) => defaults.dot_runtime_version.transaction_version,
 	};
 
+	let hub_genesis_hash = match env::var("HUB_GENESIS_HASH") {
+		Ok(s) => Some(hex_decode::<32>(&s).unwrap().into()),
+		Err(_) => defaults.hub_genesis_hash,
+	};
+	let hub_vault_account_id = match env::var("HUB_VAULT_ACCOUNT_ID") {
+		Ok(s) => Some(PolkadotAccountId::from_aliased(hex_decode::<32>(&s).unwrap())),
+		Err(_) => defaults.hub_vault_account_id,
+	};
+
+	let hub_spec_version: u32 = match env::var("HUB_SPEC_VERSION") {
+		Ok(s) => s.parse().unwrap(),
+		Err(_) => defaults.hub_runtime_version.spec_version,
+	};
+	let hub_transaction_version: u32 = match env::var("HUB_TRANSACTION_VERSION") {
+		Ok(s) => s.parse().unwrap(),
+		Err(_) => defaults.hub_runtime_version.transaction_version,
+	};
+
 	StateChainEnvironment {
 		flip_token_address,
 		eth_usdc_address,
@@ -181,7 +209,15 @@ pub fn get_environment_or_defaults(defaults: StateChainEnvironment) -> StateChai
 			spec_version: dot_spec_version,
 			transaction_version: dot_transaction_version,
 		},
+		hub_genesis_hash,
+		hub_vault_account_id,
+		hub_runtime_version: RuntimeVersion {
+			spec_version: hub_spec_version,
+			transaction_version: hub_transaction_version,
+		},
 		sol_vault_address,
+		sol_usdc_address,
+		sol_genesis_hash,
 	}
 }
 
@@ -242,7 +278,12 @@ pub fn inner_cf_development_config(
 		dot_genesis_hash,
 		dot_vault_account_id,
 		dot_runtime_version,
+		hub_genesis_hash,
+		hub_vault_account_id,
+		hub_runtime_version,
 		sol_vault_address,
+		sol_usdc_address,
+		sol_genesis_hash,
 	} = get_environment_or_defaults(testnet::ENV);
 	Ok(ChainSpec::builder(wasm_binary, None)
 		.with_name("CF Develop")
@@ -273,7 +314,12 @@ pub fn inner_cf_development_config(
 				arbitrum_chain_id,
 				polkadot_genesis_hash: dot_genesis_hash,
 				polkadot_vault_account_id: dot_vault_account_id,
+				// Localnets don't run an Asset Hub.
+				assethub_genesis_hash: hub_genesis_hash.unwrap_or_default(),
+				assethub_vault_account_id: hub_vault_account_id,
 				sol_vault_address,
+				sol_usdc_address,
+				sol_genesis_hash,
 				network_environment: NetworkEnvironment::Development,
 				..Default::default()
 			},
@@ -296,6 +342,7 @@ pub fn inner_cf_development_config(
 			devnet::KEYGEN_CEREMONY_TIMEOUT_BLOCKS,
 			devnet::THRESHOLD_SIGNATURE_CEREMONY_TIMEOUT_BLOCKS,
 			dot_runtime_version,
+			hub_runtime_version,
 			// Bitcoin block times on localnets are much faster, so we account for that here.
 			devnet::BITCOIN_EXPIRY_BLOCKS,
 			devnet::ETHEREUM_EXPIRY_BLOCKS,
@@ -347,8 +394,16 @@ macro_rules! network_spec {
 					dot_genesis_hash,
 					dot_vault_account_id,
 					dot_runtime_version,
+					hub_genesis_hash,
+					hub_vault_account_id,
+					hub_runtime_version,
 					sol_vault_address,
+					sol_usdc_address,
+					sol_genesis_hash,
 				} = env_override.unwrap_or(ENV);
+				let hub_genesis_hash = hub_genesis_hash.ok_or_else(|| {
+					format!("HUB_GENESIS_HASH must be set to build the {NETWORK_NAME} chainspec.")
+				})?;
 				let protocol_id = format!(
 					"{}-{}",
 					PROTOCOL_ID,
@@ -408,7 +463,11 @@ macro_rules! network_spec {
 							arbitrum_chain_id,
 							polkadot_genesis_hash: dot_genesis_hash,
 							polkadot_vault_account_id: dot_vault_account_id.clone(),
+							assethub_genesis_hash: hub_genesis_hash,
+							assethub_vault_account_id: hub_vault_account_id.clone(),
 							sol_vault_address,
+							sol_usdc_address,
+							sol_genesis_hash,
 							network_environment: NETWORK_ENVIRONMENT,
 							..Default::default()
 						},
@@ -431,6 +490,7 @@ macro_rules! network_spec {
 						KEYGEN_CEREMONY_TIMEOUT_BLOCKS,
 						THRESHOLD_SIGNATURE_CEREMONY_TIMEOUT_BLOCKS,
 						dot_runtime_version,
+						hub_runtime_version,
 						BITCOIN_EXPIRY_BLOCKS,
 						ETHEREUM_EXPIRY_BLOCKS,
 						ARBITRUM_EXPIRY_BLOCKS,
@@ -482,6 +542,7 @@ fn testnet_genesis(
 	keygen_ceremony_timeout_blocks: BlockNumber,
 	threshold_signature_ceremony_timeout_blocks: BlockNumber,
 	dot_runtime_version: RuntimeVersion,
+	hub_runtime_version: RuntimeVersion,
 	bitcoin_deposit_channel_lifetime: u32,
 	ethereum_deposit_channel_lifetime: u32,
 	arbitrum_deposit_channel_lifetime: u32,
@@ -650,6 +711,12 @@ fn testnet_genesis(
 			chain_initialized: false,
 		},
 
+		// Asset Hub vault is activated with the Polkadot key once the proxy account is witnessed.
+		assethub_vault: state_chain_runtime::AssethubVaultConfig {
+			deployment_block: None,
+			chain_initialized: false,
+		},
+
 		solana_vault: state_chain_runtime::SolanaVaultConfig {
 			deployment_block: None,
 			chain_initialized: false,
@@ -719,6 +786,15 @@ fn testnet_genesis(
 				},
 			},
 		},
+		assethub_chain_tracking: state_chain_runtime::AssethubChainTrackingConfig {
+			init_chain_state: ChainState::<Assethub> {
+				block_height: 0,
+				tracked_data: PolkadotTrackedData {
+					median_tip: 0,
+					runtime_version: hub_runtime_version,
+				},
+			},
+		},
 		solana_chain_tracking: state_chain_runtime::SolanaChainTrackingConfig {
 			init_chain_state: ChainState::<Solana> {
 				block_height: 0,
@@ -745,6 +821,11 @@ fn testnet_genesis(
 			witness_safety_margin: Some(arbitrum_safety_margin),
 			..Default::default()
 		},
+		// Asset Hub shares Polkadot's block time.
+		assethub_ingress_egress: state_chain_runtime::AssethubIngressEgressConfig {
+			deposit_channel_lifetime: polkadot_deposit_channel_lifetime,
+			..Default::default()
+		},
 		solana_ingress_egress: state_chain_runtime::SolanaIngressEgressConfig {
 			deposit_channel_lifetime: solana_deposit_channel_lifetime as u64,
 			witness_safety_margin: Some(solana_safety_margin),
@@ -6,7 +6,10 @@ pub use super::{
 		POLKADOT_EXPIRY_BLOCKS, SOLANA_EXPIRY_BLOCKS,
 	},
 };
-use cf_chains::{dot::RuntimeVersion, sol::SolAddress};
+use cf_chains::{
+	dot::RuntimeVersion,
+	sol::{SolAddress, SolHash},
+};
 use cf_primitives::{AccountId, AccountRole, BlockNumber, FlipBalance, NetworkEnvironment};
 use sc_service::ChainType;
 use sp_core::H256;
@@ -45,7 +48,18 @@ pub const ENV: StateChainEnvironment = StateChainEnvironment {
 	)),
 	dot_vault_account_id: None,
 	dot_runtime_version: RuntimeVersion { spec_version: 10000, transaction_version: 25 },
+	// The Asset Hub of this network runs on its own relay chain, so its genesis hash must be
+	// provided through HUB_GENESIS_HASH when the chainspec is built.
+	hub_genesis_hash: None,
+	hub_vault_account_id: None,
+	hub_runtime_version: RuntimeVersion { spec_version: 1002000, transaction_version: 15 },
 	sol_vault_address: SolAddress([0; 32]), // TODO: fill in the valid Solana address,
+	sol_usdc_address: SolAddress(hex_literal::hex!(
+		"3b442cb3912157f13a933d0134282d032b5ffecd01a2dbf1b7790608df002ea7" // 4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU
+	)),
+	sol_genesis_hash: Some(SolHash(hex_literal::hex!(
+		"ce59db5080fc2c6d3bcf7ca90712d3c2e5e6c28f27f0dfbb9953bdb0894c03ab"
+	))),
 };
 
 pub const BASHFUL_ACCOUNT_ID: &str = "cFLbasoV5juCGacy9LvvwSgkupFiFmwt8RmAuA3xcaY5YmkBe";
@@ -44,7 +44,16 @@ pub const ENV: StateChainEnvironment = StateChainEnvironment {
 	)),
 	dot_vault_account_id: None,
 	dot_runtime_version: RuntimeVersion { spec_version: 10000, transaction_version: 25 },
+	// Localnets don't run an Asset Hub. Networks built from this chainspec must provide its
+	// genesis hash through HUB_GENESIS_HASH.
+	hub_genesis_hash: None,
+	hub_vault_account_id: None,
+	hub_runtime_version: RuntimeVersion { spec_version: 1002000, transaction_version: 15 },
 	sol_vault_address: SolAddress([0; 32]), // TODO: fill in the valid Solana address,
+	sol_usdc_address: SolAddress(hex_literal::hex!(
+		"0fb9ba52b1f09445f1e3a7508d59f0797923acf744fbe2da303fb06da859ee87" // 24PNhTaNtomHhoy3fTRaMhAFCRj4uHqhZEEoWrKDbR5p
+	)),
+	sol_genesis_hash: None,
 };
 
 pub const EPOCH_DURATION_BLOCKS: BlockNumber = 3 * HOURS;
@@ -3,6 +3,21 @@
 use super::*;
 use frame_benchmarking::v2::*;
 
+fn liquidity_provider_with_scoped_key<T: Config>() -> (T::AccountId, T::AccountId) {
+	let caller: T::AccountId = whitelisted_caller();
+	let key: T::AccountId = account("scoped_key", 0, 0);
+	frame_system::Pallet::<T>::inc_providers(&caller);
+	Pallet::<T>::register_as_liquidity_provider(&caller).unwrap();
+	Pallet::<T>::register_scoped_key(
+		RawOrigin::Signed(caller.clone()).into(),
+		key.clone(),
+		BTreeSet::from([ScopedPermission::Orders]),
+		None,
+	)
+	.unwrap();
+	(caller, key)
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -17,4 +32,43 @@ mod benchmarks {
 
 		assert_eq!(VanityNames::<T>::get().get(&caller), Some(&name));
 	}
+
+	#[benchmark]
+	fn register_scoped_key() {
+		let caller: T::AccountId = whitelisted_caller();
+		let key: T::AccountId = account("scoped_key", 0, 0);
+		frame_system::Pallet::<T>::inc_providers(&caller);
+		Pallet::<T>::register_as_liquidity_provider(&caller).unwrap();
+
+		#[extrinsic_call]
+		register_scoped_key(
+			RawOrigin::Signed(caller.clone()),
+			key.clone(),
+			BTreeSet::from([ScopedPermission::Orders, ScopedPermission::DepositChannels]),
+			None,
+		);
+
+		assert!(ScopedKeys::<T>::contains_key(&caller, &key));
+	}
+
+	#[benchmark]
+	fn deregister_scoped_key() {
+		let (caller, key) = liquidity_provider_with_scoped_key::<T>();
+
+		#[extrinsic_call]
+		deregister_scoped_key(RawOrigin::Signed(caller.clone()), key.clone());
+
+		assert!(!ScopedKeys::<T>::contains_key(&caller, &key));
+	}
+
+	// Only measures the scoped key checks: the weight of the wrapped call is added on top.
+	#[benchmark]
+	fn call_as_account() {
+		let (caller, key) = liquidity_provider_with_scoped_key::<T>();
+
+		#[block]
+		{
+			assert_eq!(Pallet::<T>::scoped_key_account(&key), Some(caller));
+		}
+	}
 }
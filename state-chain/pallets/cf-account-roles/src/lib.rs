@@ -11,23 +11,84 @@ pub mod migrations;
 
 use cf_primitives::AccountRole;
 use cf_traits::AccountRoleRegistry;
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
 	error::BadOrigin,
 	pallet_prelude::{DispatchResult, StorageVersion},
+	sp_runtime::{traits::Dispatchable, RuntimeDebug},
 	traits::{EnsureOrigin, HandleLifetime, IsType, OnKilledAccount, OnNewAccount},
 	BoundedVec,
 };
+use scale_info::TypeInfo;
 use sp_core::ConstU32;
 
-use frame_system::{ensure_signed, pallet_prelude::OriginFor, RawOrigin};
+use frame_system::{
+	ensure_signed,
+	pallet_prelude::{BlockNumberFor, OriginFor},
+	RawOrigin,
+};
 pub use pallet::*;
-use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, vec::Vec};
+use sp_std::{
+	boxed::Box,
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	marker::PhantomData,
+	vec::Vec,
+};
 
 pub const PALLET_VERSION: StorageVersion = StorageVersion::new(2);
 pub const MAX_LENGTH_FOR_VANITY_NAME: u32 = 64;
 
 type VanityName = BoundedVec<u8, ConstU32<MAX_LENGTH_FOR_VANITY_NAME>>;
 
+/// The kinds of calls a scoped key can be allowed to submit on behalf of its account.
+///
+/// Changing account roles, redeeming funds and managing scoped keys are never permitted for scoped
+/// keys.
+#[derive(
+	Clone,
+	Copy,
+	RuntimeDebug,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum ScopedPermission {
+	/// Opening, updating and closing orders.
+	Orders,
+	/// Requesting deposit channels.
+	DepositChannels,
+	/// Withdrawing or transferring assets out of the account.
+	Withdrawals,
+	/// Managing account settings such as refund addresses.
+	AccountManagement,
+}
+
+/// A secondary key that can submit a restricted set of calls on behalf of an account.
+#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct ScopedKey<BlockNumber> {
+	pub permissions: BTreeSet<ScopedPermission>,
+	/// The key can no longer be used from this block onwards.
+	pub expires_at: Option<BlockNumber>,
+}
+
+impl<BlockNumber: PartialOrd> ScopedKey<BlockNumber> {
+	pub fn is_expired(&self, current_block: &BlockNumber) -> bool {
+		self.expires_at.as_ref().is_some_and(|expires_at| current_block >= expires_at)
+	}
+}
+
+/// Determines which [ScopedPermission], if any, is required to submit a call with a scoped key.
+pub trait ScopedCallFilter<RuntimeCall> {
+	/// Returns `None` if the call can never be submitted with a scoped key.
+	fn required_permission(call: &RuntimeCall) -> Option<ScopedPermission>;
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -37,6 +98,16 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		type EnsureGovernance: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The overarching call type, used for calls submitted with a scoped key.
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ IsType<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Determines which calls scoped keys are allowed to submit.
+		type ScopedCallFilter: ScopedCallFilter<<Self as Config>::RuntimeCall>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -59,6 +130,21 @@ pub mod pallet {
 	pub type VanityNames<T: Config> =
 		StorageValue<_, BTreeMap<T::AccountId, VanityName>, ValueQuery>;
 
+	/// Scoped keys registered by each account, indexed by account and then by key.
+	#[pallet::storage]
+	pub type ScopedKeys<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::AccountId,
+		Identity,
+		T::AccountId,
+		ScopedKey<BlockNumberFor<T>>,
+	>;
+
+	/// The account on whose behalf each scoped key can act.
+	#[pallet::storage]
+	pub type ScopedKeyAccount<T: Config> = StorageMap<_, Identity, T::AccountId, T::AccountId>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -75,6 +161,23 @@ pub mod pallet {
 			account_id: T::AccountId,
 			name: VanityName,
 		},
+		/// A scoped key has been registered or its permissions have been updated.
+		ScopedKeyRegistered {
+			account_id: T::AccountId,
+			key: T::AccountId,
+			permissions: BTreeSet<ScopedPermission>,
+			expires_at: Option<BlockNumberFor<T>>,
+		},
+		/// A scoped key has been removed.
+		ScopedKeyDeregistered {
+			account_id: T::AccountId,
+			key: T::AccountId,
+		},
+		/// A call has been dispatched on behalf of an account using a scoped key.
+		ScopedCallDispatched {
+			account_id: T::AccountId,
+			key: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -85,6 +188,20 @@ pub mod pallet {
 		AccountRoleAlreadyRegistered,
 		/// Invalid characters in the name.
 		InvalidCharactersInName,
+		/// Only liquidity providers and brokers can register scoped keys.
+		ScopedKeysNotSupported,
+		/// The key is already in use by another account, or is itself a registered account.
+		ScopedKeyUnavailable,
+		/// A scoped key must grant at least one permission.
+		NoPermissionsGranted,
+		/// The expiry block of a scoped key must be in the future.
+		InvalidExpiry,
+		/// The key is not registered as a scoped key.
+		UnknownScopedKey,
+		/// The scoped key has expired.
+		ScopedKeyExpired,
+		/// The scoped key does not have permission to submit this call.
+		CallNotPermitted,
 	}
 
 	#[pallet::genesis_config]
@@ -144,6 +261,167 @@ pub mod pallet {
 			Self::deposit_event(Event::VanityNameSet { account_id, name });
 			Ok(().into())
 		}
+
+		/// Register a scoped key that can submit a restricted set of calls on behalf of the
+		/// calling account, or update the permissions and expiry of an existing one.
+		///
+		/// The dispatch origin of this function must be a liquidity provider or a broker.
+		///
+		/// ## Events
+		///
+		/// - [ScopedKeyRegistered](Event::ScopedKeyRegistered)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::error::BadOrigin)
+		/// - [ScopedKeysNotSupported](Error::ScopedKeysNotSupported)
+		/// - [ScopedKeyUnavailable](Error::ScopedKeyUnavailable)
+		/// - [NoPermissionsGranted](Error::NoPermissionsGranted)
+		/// - [InvalidExpiry](Error::InvalidExpiry)
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::register_scoped_key())]
+		pub fn register_scoped_key(
+			origin: OriginFor<T>,
+			key: T::AccountId,
+			permissions: BTreeSet<ScopedPermission>,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+			ensure!(
+				matches!(
+					AccountRoles::<T>::get(&account_id),
+					Some(AccountRole::LiquidityProvider | AccountRole::Broker)
+				),
+				Error::<T>::ScopedKeysNotSupported
+			);
+			ensure!(!permissions.is_empty(), Error::<T>::NoPermissionsGranted);
+			ensure!(
+				expires_at.map_or(true, |expires_at| expires_at >
+					frame_system::Pallet::<T>::block_number()),
+				Error::<T>::InvalidExpiry
+			);
+
+			match ScopedKeyAccount::<T>::get(&key) {
+				Some(owner) => ensure!(owner == account_id, Error::<T>::ScopedKeyUnavailable),
+				None => {
+					ensure!(
+						key != account_id &&
+							AccountRoles::<T>::get(&key)
+								.map_or(true, |role| role == AccountRole::Unregistered),
+						Error::<T>::ScopedKeyUnavailable
+					);
+					// The key needs to exist on chain in order to sign extrinsics, but it never
+					// holds funds of its own.
+					frame_system::Pallet::<T>::inc_sufficients(&key);
+					ScopedKeyAccount::<T>::insert(&key, &account_id);
+				},
+			}
+			ScopedKeys::<T>::insert(
+				&account_id,
+				&key,
+				ScopedKey { permissions: permissions.clone(), expires_at },
+			);
+
+			Self::deposit_event(Event::ScopedKeyRegistered {
+				account_id,
+				key,
+				permissions,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Remove a scoped key from the calling account.
+		///
+		/// The dispatch origin of this function must be signed.
+		///
+		/// ## Events
+		///
+		/// - [ScopedKeyDeregistered](Event::ScopedKeyDeregistered)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::error::BadOrigin)
+		/// - [UnknownScopedKey](Error::UnknownScopedKey)
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::deregister_scoped_key())]
+		pub fn deregister_scoped_key(origin: OriginFor<T>, key: T::AccountId) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+			ensure!(
+				ScopedKeys::<T>::take(&account_id, &key).is_some(),
+				Error::<T>::UnknownScopedKey
+			);
+			Self::remove_scoped_key(&key);
+
+			Self::deposit_event(Event::ScopedKeyDeregistered { account_id, key });
+			Ok(())
+		}
+
+		/// Dispatch a call on behalf of the account the signing scoped key is registered for.
+		///
+		/// The call is dispatched with the account as its signed origin, provided the key has not
+		/// expired and has been granted the permission the call requires.
+		///
+		/// ## Events
+		///
+		/// - [ScopedCallDispatched](Event::ScopedCallDispatched)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::error::BadOrigin)
+		/// - [UnknownScopedKey](Error::UnknownScopedKey)
+		/// - [ScopedKeyExpired](Error::ScopedKeyExpired)
+		/// - [CallNotPermitted](Error::CallNotPermitted)
+		#[pallet::call_index(3)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				T::WeightInfo::call_as_account().saturating_add(dispatch_info.weight),
+				dispatch_info.class,
+			)
+		})]
+		pub fn call_as_account(
+			origin: OriginFor<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let key = ensure_signed(origin)?;
+			let account_id =
+				ScopedKeyAccount::<T>::get(&key).ok_or(Error::<T>::UnknownScopedKey)?;
+			let scoped_key =
+				ScopedKeys::<T>::get(&account_id, &key).ok_or(Error::<T>::UnknownScopedKey)?;
+			ensure!(
+				!scoped_key.is_expired(&frame_system::Pallet::<T>::block_number()),
+				Error::<T>::ScopedKeyExpired
+			);
+			ensure!(
+				T::ScopedCallFilter::required_permission(&call)
+					.is_some_and(|permission| scoped_key.permissions.contains(&permission)),
+				Error::<T>::CallNotPermitted
+			);
+
+			call.dispatch(RawOrigin::Signed(account_id.clone()).into())
+				.map_err(|e| e.error)?;
+
+			Self::deposit_event(Event::ScopedCallDispatched { account_id, key });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account a scoped key can currently act for, if the key is registered and has not
+	/// expired.
+	pub fn scoped_key_account(key: &T::AccountId) -> Option<T::AccountId> {
+		ScopedKeyAccount::<T>::get(key).filter(|account_id| {
+			ScopedKeys::<T>::get(account_id, key).is_some_and(|scoped_key| {
+				!scoped_key.is_expired(&frame_system::Pallet::<T>::block_number())
+			})
+		})
+	}
+
+	fn remove_scoped_key(key: &T::AccountId) {
+		ScopedKeyAccount::<T>::remove(key);
+		frame_system::Pallet::<T>::dec_sufficients(key);
 	}
 }
 
@@ -219,6 +497,9 @@ impl<T: Config> AccountRoleRegistry<T> for Pallet<T> {
 impl<T: Config> OnKilledAccount<T::AccountId> for Pallet<T> {
 	fn on_killed_account(who: &T::AccountId) {
 		AccountRoles::<T>::remove(who);
+		for (key, _) in ScopedKeys::<T>::drain_prefix(who) {
+			Self::remove_scoped_key(&key);
+		}
 		let _ = VanityNames::<T>::try_mutate(|vanity_names| vanity_names.remove(who).ok_or(()));
 	}
 }
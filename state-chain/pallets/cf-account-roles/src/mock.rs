@@ -1,6 +1,6 @@
 #![cfg(test)]
 
-use crate::{self as pallet_cf_account_roles, Config};
+use crate::{self as pallet_cf_account_roles, Config, ScopedCallFilter, ScopedPermission};
 use cf_traits::mocks::ensure_origin_mock::NeverFailingOriginCheck;
 
 use frame_support::{
@@ -48,9 +48,27 @@ impl frame_system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<5>;
 }
 
+/// Remarks are treated as orders and remarks with events as withdrawals. Any other call is out of
+/// scope.
+pub struct MockScopedCallFilter;
+
+impl ScopedCallFilter<RuntimeCall> for MockScopedCallFilter {
+	fn required_permission(call: &RuntimeCall) -> Option<ScopedPermission> {
+		match call {
+			RuntimeCall::System(frame_system::Call::remark { .. }) =>
+				Some(ScopedPermission::Orders),
+			RuntimeCall::System(frame_system::Call::remark_with_event { .. }) =>
+				Some(ScopedPermission::Withdrawals),
+			_ => None,
+		}
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type EnsureGovernance = NeverFailingOriginCheck<Self>;
+	type RuntimeCall = RuntimeCall;
+	type ScopedCallFilter = MockScopedCallFilter;
 	type WeightInfo = ();
 }
 
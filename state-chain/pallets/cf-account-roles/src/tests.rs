@@ -162,3 +162,204 @@ fn test_setting_vanity_names_() {
 		);
 	});
 }
+
+mod scoped_keys {
+	use super::*;
+	use sp_runtime::traits::{BlakeTwo256, Hash};
+
+	const KEY: u64 = 100;
+	const OTHER_KEY: u64 = 101;
+
+	fn remark() -> Box<RuntimeCall> {
+		Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }))
+	}
+
+	fn remark_with_event() -> Box<RuntimeCall> {
+		Box::new(RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] }))
+	}
+
+	fn setup_accounts() {
+		<Provider<Test> as HandleLifetime<u64>>::created(&ALICE).unwrap();
+		<Provider<Test> as HandleLifetime<u64>>::created(&BOB).unwrap();
+		<Provider<Test> as HandleLifetime<u64>>::created(&CHARLIE).unwrap();
+		AccountRolesPallet::register_as_liquidity_provider(&ALICE).unwrap();
+		AccountRolesPallet::register_as_broker(&BOB).unwrap();
+		AccountRolesPallet::register_as_validator(&CHARLIE).unwrap();
+	}
+
+	fn register_key(account_id: u64, key: u64, permissions: &[ScopedPermission]) -> DispatchResult {
+		AccountRolesPallet::register_scoped_key(
+			RuntimeOrigin::signed(account_id),
+			key,
+			permissions.iter().copied().collect(),
+			None,
+		)
+	}
+
+	#[test]
+	fn only_liquidity_providers_and_brokers_can_register_scoped_keys() {
+		new_test_ext().execute_with(|| {
+			setup_accounts();
+
+			assert_ok!(register_key(ALICE, KEY, &[ScopedPermission::Orders]));
+			assert_ok!(register_key(BOB, OTHER_KEY, &[ScopedPermission::DepositChannels]));
+			assert_noop!(
+				register_key(CHARLIE, 102, &[ScopedPermission::Orders]),
+				Error::<Test>::ScopedKeysNotSupported
+			);
+
+			assert_eq!(ScopedKeyAccount::<Test>::get(KEY), Some(ALICE));
+			assert_eq!(ScopedKeyAccount::<Test>::get(OTHER_KEY), Some(BOB));
+			// The key exists on chain so that it can sign extrinsics.
+			assert!(frame_system::Pallet::<Test>::account_exists(&KEY));
+			System::assert_last_event(RuntimeEvent::MockAccountRoles(Event::ScopedKeyRegistered {
+				account_id: BOB,
+				key: OTHER_KEY,
+				permissions: BTreeSet::from([ScopedPermission::DepositChannels]),
+				expires_at: None,
+			}));
+		});
+	}
+
+	#[test]
+	fn scoped_key_registration_is_validated() {
+		new_test_ext().execute_with(|| {
+			setup_accounts();
+
+			assert_noop!(register_key(ALICE, KEY, &[]), Error::<Test>::NoPermissionsGranted);
+			assert_noop!(
+				AccountRolesPallet::register_scoped_key(
+					RuntimeOrigin::signed(ALICE),
+					KEY,
+					BTreeSet::from([ScopedPermission::Orders]),
+					Some(System::block_number()),
+				),
+				Error::<Test>::InvalidExpiry
+			);
+			// Registered accounts can't be used as scoped keys.
+			for key in [ALICE, BOB, CHARLIE] {
+				assert_noop!(
+					register_key(ALICE, key, &[ScopedPermission::Orders]),
+					Error::<Test>::ScopedKeyUnavailable
+				);
+			}
+
+			// Keys can't be shared between accounts, but can be updated by their owner.
+			assert_ok!(register_key(ALICE, KEY, &[ScopedPermission::Orders]));
+			assert_noop!(
+				register_key(BOB, KEY, &[ScopedPermission::Orders]),
+				Error::<Test>::ScopedKeyUnavailable
+			);
+			assert_ok!(register_key(
+				ALICE,
+				KEY,
+				&[ScopedPermission::Orders, ScopedPermission::Withdrawals]
+			));
+			assert_eq!(
+				ScopedKeys::<Test>::get(ALICE, KEY).unwrap().permissions,
+				BTreeSet::from([ScopedPermission::Orders, ScopedPermission::Withdrawals])
+			);
+			assert_eq!(frame_system::Pallet::<Test>::sufficients(&KEY), 1);
+		});
+	}
+
+	#[test]
+	fn scoped_keys_can_only_submit_permitted_calls() {
+		new_test_ext().execute_with(|| {
+			setup_accounts();
+			assert_ok!(register_key(ALICE, KEY, &[ScopedPermission::Orders]));
+
+			assert_ok!(AccountRolesPallet::call_as_account(RuntimeOrigin::signed(KEY), remark()));
+			System::assert_last_event(RuntimeEvent::MockAccountRoles(
+				Event::ScopedCallDispatched { account_id: ALICE, key: KEY },
+			));
+
+			// Calls requiring other permissions, or that are out of scope entirely, are rejected.
+			assert_noop!(
+				AccountRolesPallet::call_as_account(
+					RuntimeOrigin::signed(KEY),
+					remark_with_event()
+				),
+				Error::<Test>::CallNotPermitted
+			);
+			assert_noop!(
+				AccountRolesPallet::call_as_account(
+					RuntimeOrigin::signed(KEY),
+					Box::new(RuntimeCall::MockAccountRoles(Call::deregister_scoped_key {
+						key: KEY
+					}))
+				),
+				Error::<Test>::CallNotPermitted
+			);
+			assert_noop!(
+				AccountRolesPallet::call_as_account(RuntimeOrigin::signed(OTHER_KEY), remark()),
+				Error::<Test>::UnknownScopedKey
+			);
+
+			// With the right permission, the call is dispatched on behalf of the account.
+			assert_ok!(register_key(ALICE, KEY, &[ScopedPermission::Withdrawals]));
+			assert_ok!(AccountRolesPallet::call_as_account(
+				RuntimeOrigin::signed(KEY),
+				remark_with_event()
+			));
+			System::assert_has_event(RuntimeEvent::System(frame_system::Event::Remarked {
+				sender: ALICE,
+				hash: BlakeTwo256::hash(&[]),
+			}));
+		});
+	}
+
+	#[test]
+	fn scoped_keys_expire() {
+		new_test_ext().execute_with(|| {
+			setup_accounts();
+			let expires_at = System::block_number() + 10;
+			assert_ok!(AccountRolesPallet::register_scoped_key(
+				RuntimeOrigin::signed(ALICE),
+				KEY,
+				BTreeSet::from([ScopedPermission::Orders]),
+				Some(expires_at),
+			));
+
+			System::set_block_number(expires_at - 1);
+			assert_eq!(AccountRolesPallet::scoped_key_account(&KEY), Some(ALICE));
+			assert_ok!(AccountRolesPallet::call_as_account(RuntimeOrigin::signed(KEY), remark()));
+
+			System::set_block_number(expires_at);
+			assert_eq!(AccountRolesPallet::scoped_key_account(&KEY), None);
+			assert_noop!(
+				AccountRolesPallet::call_as_account(RuntimeOrigin::signed(KEY), remark()),
+				Error::<Test>::ScopedKeyExpired
+			);
+		});
+	}
+
+	#[test]
+	fn scoped_keys_are_removed_with_their_account() {
+		new_test_ext().execute_with(|| {
+			setup_accounts();
+			assert_ok!(register_key(ALICE, KEY, &[ScopedPermission::Orders]));
+			assert_ok!(register_key(ALICE, OTHER_KEY, &[ScopedPermission::Orders]));
+
+			assert_noop!(
+				AccountRolesPallet::deregister_scoped_key(RuntimeOrigin::signed(BOB), KEY),
+				Error::<Test>::UnknownScopedKey
+			);
+			assert_ok!(AccountRolesPallet::deregister_scoped_key(
+				RuntimeOrigin::signed(ALICE),
+				KEY
+			));
+			assert_eq!(ScopedKeyAccount::<Test>::get(KEY), None);
+			assert!(!frame_system::Pallet::<Test>::account_exists(&KEY));
+			assert_noop!(
+				AccountRolesPallet::call_as_account(RuntimeOrigin::signed(KEY), remark()),
+				Error::<Test>::UnknownScopedKey
+			);
+
+			AccountRolesPallet::on_killed_account(&ALICE);
+			assert_eq!(ScopedKeys::<Test>::iter_prefix(ALICE).count(), 0);
+			assert_eq!(ScopedKeyAccount::<Test>::get(OTHER_KEY), None);
+			assert!(!frame_system::Pallet::<Test>::account_exists(&OTHER_KEY));
+		});
+	}
+}
@@ -33,6 +33,9 @@ use core::marker::PhantomData;
 /// Weight functions needed for pallet_cf_account_roles.
 pub trait WeightInfo {
 	fn set_vanity_name() -> Weight;
+	fn register_scoped_key() -> Weight;
+	fn deregister_scoped_key() -> Weight;
+	fn call_as_account() -> Weight;
 }
 
 /// Weights for pallet_cf_account_roles using the Substrate node and recommended hardware.
@@ -49,6 +52,50 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AccountRoles::AccountRoles` (r:2 w:1)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AccountRoles::ScopedKeyAccount` (r:1 w:1)
+	/// Proof: `AccountRoles::ScopedKeyAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AccountRoles::ScopedKeys` (r:0 w:1)
+	/// Proof: `AccountRoles::ScopedKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register_scoped_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6552`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 6552)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccountRoles::ScopedKeys` (r:1 w:1)
+	/// Proof: `AccountRoles::ScopedKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AccountRoles::ScopedKeyAccount` (r:0 w:1)
+	/// Proof: `AccountRoles::ScopedKeyAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn deregister_scoped_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `398`
+		//  Estimated: `3863`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3863)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AccountRoles::ScopedKeyAccount` (r:1 w:0)
+	/// Proof: `AccountRoles::ScopedKeyAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AccountRoles::ScopedKeys` (r:1 w:0)
+	/// Proof: `AccountRoles::ScopedKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn call_as_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `356`
+		//  Estimated: `3821`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3821)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -64,4 +111,48 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AccountRoles::AccountRoles` (r:2 w:1)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AccountRoles::ScopedKeyAccount` (r:1 w:1)
+	/// Proof: `AccountRoles::ScopedKeyAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AccountRoles::ScopedKeys` (r:0 w:1)
+	/// Proof: `AccountRoles::ScopedKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn register_scoped_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `6552`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 6552)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AccountRoles::ScopedKeys` (r:1 w:1)
+	/// Proof: `AccountRoles::ScopedKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AccountRoles::ScopedKeyAccount` (r:0 w:1)
+	/// Proof: `AccountRoles::ScopedKeyAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn deregister_scoped_key() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `398`
+		//  Estimated: `3863`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3863)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `AccountRoles::ScopedKeyAccount` (r:1 w:0)
+	/// Proof: `AccountRoles::ScopedKeyAccount` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AccountRoles::ScopedKeys` (r:1 w:0)
+	/// Proof: `AccountRoles::ScopedKeys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn call_as_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `356`
+		//  Estimated: `3821`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 3821)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
 }
@@ -41,6 +41,8 @@ const INITIATED_AT: u32 = 100;
 // Generates a new signature ready call.
 fn generate_on_signature_ready_call<T: pallet::Config<I>, I>() -> pallet::Call<T, I> {
 	let threshold_request_id = 1;
+	let broadcast_id = 1;
+	PendingBroadcasts::<T, I>::append(broadcast_id);
 	T::ThresholdSigner::insert_signature(
 		threshold_request_id,
 		ThresholdSignatureFor::<T, I>::benchmark_value(),
@@ -49,7 +51,7 @@ fn generate_on_signature_ready_call<T: pallet::Config<I>, I>() -> pallet::Call<T
 		threshold_request_id,
 		threshold_signature_payload: PayloadFor::<T, I>::benchmark_value(),
 		api_call: Box::new(ApiCallFor::<T, I>::benchmark_value()),
-		broadcast_id: 1,
+		broadcast_id,
 		initiated_at: INITIATED_AT.into(),
 		should_broadcast: true,
 	}
@@ -26,7 +26,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::DispatchResultWithPostInfo,
 	pallet_prelude::{ensure, DispatchResult, RuntimeDebug},
-	sp_runtime::traits::{One, Saturating},
+	sp_runtime::traits::{Bounded, One, Saturating},
 	traits::{Defensive, Get, OriginTrait, StorageVersion, UnfilteredDispatchable},
 	Twox64Concat,
 };
@@ -272,6 +272,13 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Signed calls that have been replaced by a version paying a higher fee. They can still be
+	/// included on the target chain instead of their replacement, so they remain witnessable
+	/// until the broadcast succeeds.
+	#[pallet::storage]
+	pub type ReplacedCalls<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, BroadcastId, Vec<ApiCallFor<T, I>>, ValueQuery>;
+
 	/// Stores metadata related to a transaction.
 	#[pallet::storage]
 	pub type TransactionMetadata<T: Config<I>, I: 'static = ()> =
@@ -334,6 +341,24 @@ pub mod pallet {
 		TransactionFeeDeficitRefused { beneficiary: SignerIdFor<T, I> },
 		/// A Call has been re-threshold-signed, and its signature data is inserted into storage.
 		CallResigned { broadcast_id: BroadcastId },
+		/// A transaction has not been included for too long, and a replacement paying a higher
+		/// fee is being threshold-signed.
+		TransactionFeeBumpRequested {
+			broadcast_id: BroadcastId,
+			replaced_transaction_out_id: TransactionOutIdFor<T, I>,
+		},
+		/// The last transaction attempted for an aborted broadcast.
+		AbortedBroadcastInspected {
+			broadcast_id: BroadcastId,
+			transaction_out_id: TransactionOutIdFor<T, I>,
+			transaction_payload: TransactionFor<T, I>,
+		},
+		/// An aborted broadcast is being re-signed with the current key and broadcast again.
+		AbortedBroadcastResigned { broadcast_id: BroadcastId },
+		/// An aborted broadcast is being rebuilt with fresh fee parameters and broadcast again.
+		AbortedBroadcastRebuilt { broadcast_id: BroadcastId },
+		/// An aborted broadcast has been cancelled and its funds returned to the vault.
+		AbortedBroadcastCancelled { broadcast_id: BroadcastId },
 	}
 
 	#[pallet::error]
@@ -344,6 +369,8 @@ pub mod pallet {
 		InvalidBroadcastId,
 		/// A threshold signature was expected but not available.
 		ThresholdSignatureUnavailable,
+		/// The broadcast has not been aborted.
+		BroadcastNotAborted,
 	}
 
 	#[pallet::hooks]
@@ -453,6 +480,16 @@ pub mod pallet {
 				})?
 				.expect("signature can not be unavailable");
 
+			// The broadcast may have succeeded or been aborted while the call was being signed,
+			// for example if a fee bump raced with the success of the transaction it replaces.
+			if should_broadcast && !PendingBroadcasts::<T, I>::get().contains(&broadcast_id) {
+				log::warn!(
+					"Broadcast {} is no longer pending, the signed call is discarded.",
+					broadcast_id
+				);
+				return Ok(().into())
+			}
+
 			let signed_api_call = api_call.signed(&signature);
 
 			ThresholdSignatureData::<T, I>::insert(
@@ -464,7 +501,11 @@ pub mod pallet {
 			if should_broadcast {
 				let transaction_out_id = signed_api_call.transaction_out_id();
 
-				T::BroadcastReadyProvider::on_broadcast_ready(&signed_api_call);
+				// A call that was re-signed without changing its transaction (for example when
+				// an aborted broadcast is retried) has already been made ready.
+				if !TransactionOutIdToBroadcastId::<T, I>::contains_key(&transaction_out_id) {
+					T::BroadcastReadyProvider::on_broadcast_ready(&signed_api_call);
+				}
 
 				// The Engine uses this.
 				TransactionOutIdToBroadcastId::<T, I>::insert(
@@ -528,11 +569,19 @@ pub mod pallet {
 
 			Self::remove_pending_broadcast(&broadcast_id);
 
+			// The witnessed transaction may be a version that was replaced to pay a higher fee.
+			let confirmed_replaced_call = ReplacedCalls::<T, I>::get(broadcast_id)
+				.into_iter()
+				.find(|call| call.transaction_out_id() == tx_out_id);
+
 			if let Some(expected_tx_metadata) = TransactionMetadata::<T, I>::take(broadcast_id) {
 				if tx_metadata.verify_metadata(&expected_tx_metadata) {
-					if let Some(broadcast_data) = AwaitingBroadcast::<T, I>::get(broadcast_id) {
-						let to_refund =
-							broadcast_data.transaction_payload.return_fee_refund(tx_fee);
+					if let Some(transaction_payload) = match &confirmed_replaced_call {
+						Some(call) => Some(T::TransactionBuilder::build_transaction(call)),
+						None => AwaitingBroadcast::<T, I>::get(broadcast_id)
+							.map(|broadcast_data| broadcast_data.transaction_payload),
+					} {
+						let to_refund = transaction_payload.return_fee_refund(tx_fee);
 
 						TransactionFeeDeficit::<T, I>::mutate(signer_id.clone(), |fee_deficit| {
 							*fee_deficit = fee_deficit.saturating_add(to_refund);
@@ -564,6 +613,15 @@ pub mod pallet {
 				);
 			}
 
+			if let Some(confirmed_call) = confirmed_replaced_call {
+				if let Some((replacement, _)) = ThresholdSignatureData::<T, I>::get(broadcast_id) {
+					T::BroadcastReadyProvider::on_replaced_broadcast_confirmed(
+						&confirmed_call,
+						&replacement,
+					);
+				}
+			}
+
 			if let Some(callback) = RequestSuccessCallbacks::<T, I>::get(broadcast_id) {
 				Self::deposit_event(Event::<T, I>::BroadcastCallbackExecuted {
 					broadcast_id,
@@ -633,6 +691,144 @@ pub mod pallet {
 			Self::handle_broadcast_failure(broadcast_id, reporter.into())?;
 			Ok(().into())
 		}
+
+		/// Emits the last transaction that was attempted for an aborted broadcast, so that
+		/// governance can decide how to recover it.
+		///
+		/// ## Events
+		///
+		/// - [AbortedBroadcastInspected](Event::AbortedBroadcastInspected)
+		///
+		/// ## Errors
+		///
+		/// - [BroadcastNotAborted](Error::BroadcastNotAborted)
+		/// - [InvalidBroadcastId](Error::InvalidBroadcastId)
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::DbWeight::get().reads(2))]
+		pub fn inspect_aborted_broadcast(
+			origin: OriginFor<T>,
+			broadcast_id: BroadcastId,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			ensure!(
+				AbortedBroadcasts::<T, I>::get().contains(&broadcast_id),
+				Error::<T, I>::BroadcastNotAborted
+			);
+			let BroadcastData { transaction_out_id, transaction_payload, .. } =
+				AwaitingBroadcast::<T, I>::get(broadcast_id)
+					.ok_or(Error::<T, I>::InvalidBroadcastId)?;
+
+			Self::deposit_event(Event::<T, I>::AbortedBroadcastInspected {
+				broadcast_id,
+				transaction_out_id,
+				transaction_payload,
+			});
+			Ok(())
+		}
+
+		/// Re-signs the call of an aborted broadcast with the current epoch key and broadcasts it
+		/// again.
+		///
+		/// ## Events
+		///
+		/// - [AbortedBroadcastResigned](Event::AbortedBroadcastResigned)
+		///
+		/// ## Errors
+		///
+		/// - [BroadcastNotAborted](Error::BroadcastNotAborted)
+		/// - [ThresholdSignatureUnavailable](Error::ThresholdSignatureUnavailable)
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::start_next_broadcast_attempt())]
+		pub fn resign_aborted_broadcast(
+			origin: OriginFor<T>,
+			broadcast_id: BroadcastId,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			let api_call = Self::take_aborted_broadcast(broadcast_id)?;
+			PendingBroadcasts::<T, I>::append(broadcast_id);
+
+			Self::threshold_sign(api_call, broadcast_id, true);
+
+			Self::deposit_event(Event::<T, I>::AbortedBroadcastResigned { broadcast_id });
+			Ok(())
+		}
+
+		/// Rebuilds an aborted broadcast with fresh fee parameters and broadcasts it again. If the
+		/// fee is part of the signed call, a replacement paying a higher fee is signed. Otherwise
+		/// the transaction is rebuilt from the existing signed call.
+		///
+		/// ## Events
+		///
+		/// - [AbortedBroadcastRebuilt](Event::AbortedBroadcastRebuilt)
+		///
+		/// ## Errors
+		///
+		/// - [BroadcastNotAborted](Error::BroadcastNotAborted)
+		/// - [ThresholdSignatureUnavailable](Error::ThresholdSignatureUnavailable)
+		/// - [InvalidBroadcastId](Error::InvalidBroadcastId)
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::start_next_broadcast_attempt())]
+		pub fn rebuild_aborted_broadcast(
+			origin: OriginFor<T>,
+			broadcast_id: BroadcastId,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			let mut broadcast_data = AwaitingBroadcast::<T, I>::get(broadcast_id)
+				.ok_or(Error::<T, I>::InvalidBroadcastId)?;
+			let api_call = Self::take_aborted_broadcast(broadcast_id)?;
+			PendingBroadcasts::<T, I>::append(broadcast_id);
+
+			// The broadcast was aborted, so regardless of how long it's been pending, its fee
+			// should be bumped if possible.
+			if let Some(replacement) = T::TransactionBuilder::replace_by_fee(
+				&api_call,
+				ChainBlockNumberFor::<T, I>::max_value(),
+			) {
+				T::BroadcastReadyProvider::on_broadcast_replaced(&api_call);
+				ReplacedCalls::<T, I>::append(broadcast_id, api_call);
+				Self::threshold_sign(replacement, broadcast_id, true);
+			} else {
+				broadcast_data.transaction_payload =
+					T::TransactionBuilder::build_transaction(&api_call);
+				broadcast_data.nominee = None;
+				Self::start_broadcast_attempt(broadcast_data);
+			}
+
+			Self::deposit_event(Event::<T, I>::AbortedBroadcastRebuilt { broadcast_id });
+			Ok(())
+		}
+
+		/// Cancels an aborted broadcast, returning the funds it would have spent to the vault and
+		/// cleaning up its storage. Governance must make sure that none of the broadcast's
+		/// transactions can still be included on the target chain.
+		///
+		/// ## Events
+		///
+		/// - [AbortedBroadcastCancelled](Event::AbortedBroadcastCancelled)
+		///
+		/// ## Errors
+		///
+		/// - [BroadcastNotAborted](Error::BroadcastNotAborted)
+		/// - [ThresholdSignatureUnavailable](Error::ThresholdSignatureUnavailable)
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::transaction_succeeded())]
+		pub fn cancel_aborted_broadcast(
+			origin: OriginFor<T>,
+			broadcast_id: BroadcastId,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			let api_call = Self::take_aborted_broadcast(broadcast_id)?;
+
+			T::BroadcastReadyProvider::on_broadcast_cancelled(&api_call);
+			Self::clean_up_broadcast_storage(broadcast_id);
+
+			Self::deposit_event(Event::<T, I>::AbortedBroadcastCancelled { broadcast_id });
+			Ok(())
+		}
 	}
 }
 
@@ -645,6 +841,23 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		if let Some((api_call, _)) = ThresholdSignatureData::<T, I>::take(broadcast_id) {
 			TransactionOutIdToBroadcastId::<T, I>::remove(api_call.transaction_out_id());
 		}
+		for replaced_call in ReplacedCalls::<T, I>::take(broadcast_id) {
+			TransactionOutIdToBroadcastId::<T, I>::remove(replaced_call.transaction_out_id());
+		}
+	}
+
+	/// Removes the broadcast from the aborted broadcasts and returns its latest signed call.
+	fn take_aborted_broadcast(broadcast_id: BroadcastId) -> Result<ApiCallFor<T, I>, Error<T, I>> {
+		AbortedBroadcasts::<T, I>::try_mutate(|aborted_broadcasts| {
+			let index = aborted_broadcasts
+				.iter()
+				.position(|id| *id == broadcast_id)
+				.ok_or(Error::<T, I>::BroadcastNotAborted)?;
+			let (api_call, _signature) = ThresholdSignatureData::<T, I>::get(broadcast_id)
+				.ok_or(Error::<T, I>::ThresholdSignatureUnavailable)?;
+			aborted_broadcasts.remove(index);
+			Ok(api_call)
+		})
 	}
 
 	pub fn remove_pending_broadcast(broadcast_id: &BroadcastId) {
@@ -759,6 +972,33 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						"Signature is invalid -> rescheduled threshold signature for broadcast id {}.",
 						broadcast_id
 					);
+				} else if let Some(replacement) =
+					TransactionOutIdToBroadcastId::<T, I>::get(&broadcast_data.transaction_out_id)
+						.and_then(|(_, initiated_at)| {
+							T::TransactionBuilder::replace_by_fee(
+								&api_call,
+								T::ChainTracking::get_block_height().saturating_sub(initiated_at),
+							)
+						}) {
+					T::BroadcastReadyProvider::on_broadcast_replaced(&api_call);
+					// The current version stays mapped to this broadcast, so that it is still
+					// witnessed if it's included before its replacement.
+					ReplacedCalls::<T, I>::append(broadcast_id, api_call);
+					// The previous attempts most likely failed because the fee was too low, so
+					// they shouldn't count towards aborting the broadcast.
+					FailedBroadcasters::<T, I>::remove(broadcast_id);
+
+					Self::deposit_event(Event::<T, I>::TransactionFeeBumpRequested {
+						broadcast_id,
+						replaced_transaction_out_id: broadcast_data.transaction_out_id,
+					});
+
+					Self::threshold_sign(replacement, broadcast_id, true);
+
+					log::info!(
+						"Transaction is pending for too long -> requested a replacement with a higher fee for broadcast id {}.",
+						broadcast_id
+					);
 				} else {
 					Self::start_broadcast_attempt(broadcast_data);
 				}
@@ -107,9 +107,17 @@ impl UnfilteredDispatchable for MockCallback {
 	}
 }
 
+parameter_types! {
+	pub static ReadyTransactionOutIds: Vec<[u8; 4]> = vec![];
+}
+
 pub struct MockBroadcastReadyProvider;
 impl OnBroadcastReady<MockEthereum> for MockBroadcastReadyProvider {
 	type ApiCall = MockApiCall<MockEthereumChainCrypto>;
+
+	fn on_broadcast_ready(api_call: &Self::ApiCall) {
+		ReadyTransactionOutIds::mutate(|ready| ready.push(api_call.tx_out_id));
+	}
 }
 
 pub struct MockRetryPolicy;
@@ -3,9 +3,9 @@
 use crate::{
 	mock::*, AbortedBroadcasts, AwaitingBroadcast, BroadcastData, BroadcastId, Config,
 	DelayedBroadcastRetryQueue, Error, Event as BroadcastEvent, FailedBroadcasters, Instance1,
-	PalletOffence, PendingBroadcasts, RequestFailureCallbacks, RequestSuccessCallbacks,
-	ThresholdSignatureData, Timeouts, TransactionFeeDeficit, TransactionMetadata,
-	TransactionOutIdToBroadcastId,
+	PalletOffence, PendingBroadcasts, ReplacedCalls, RequestFailureCallbacks,
+	RequestSuccessCallbacks, ThresholdSignatureData, Timeouts, TransactionFeeDeficit,
+	TransactionMetadata, TransactionOutIdToBroadcastId,
 };
 use cf_chains::{
 	evm::SchnorrVerificationComponents,
@@ -1279,3 +1279,218 @@ fn broadcast_is_retried_without_initial_nominee() {
 			assert_broadcast_storage_cleaned_up(broadcast_id);
 		});
 }
+
+#[test]
+fn pending_transaction_is_replaced_and_either_version_can_succeed() {
+	type MockBuilder = MockTransactionBuilder<MockEthereum, MockApiCall<MockEthereumChainCrypto>>;
+	let mut expiry = 0;
+	let (original_tx_out_id, original_call) = api_call(1);
+	let (replacement_tx_out_id, replacement_call) = api_call(2);
+	new_test_ext()
+		.execute_with(|| {
+			let broadcast_id = initiate_and_sign_broadcast(&original_call, TxType::Normal);
+			MockBuilder::set_replacement(replacement_call);
+			expiry = System::block_number() + BROADCAST_EXPIRY_BLOCKS;
+			broadcast_id
+		})
+		// The broadcast times out and is retried in the next block, with a replacement.
+		.then_execute_at_block(expiry, |broadcast_id| broadcast_id)
+		.then_execute_at_next_block(|broadcast_id| {
+			System::assert_has_event(RuntimeEvent::Broadcaster(
+				crate::Event::<Test, Instance1>::TransactionFeeBumpRequested {
+					broadcast_id,
+					replaced_transaction_out_id: original_tx_out_id,
+				},
+			));
+			assert!(FailedBroadcasters::<Test, Instance1>::get(broadcast_id).is_empty());
+
+			EthMockThresholdSigner::execute_signature_result_against_last_request(Ok(
+				ETH_DUMMY_SIG,
+			));
+			assert_transaction_broadcast_request_event(broadcast_id, replacement_tx_out_id);
+
+			// Both versions are witnessed.
+			for tx_out_id in [original_tx_out_id, replacement_tx_out_id] {
+				assert_eq!(
+					TransactionOutIdToBroadcastId::<Test, Instance1>::get(tx_out_id)
+						.map(|(id, _)| id),
+					Some(broadcast_id)
+				);
+			}
+
+			// The original is included instead of its replacement.
+			let expected_refund = AwaitingBroadcast::<Test, Instance1>::get(broadcast_id)
+				.unwrap()
+				.transaction_payload
+				.return_fee_refund(ETH_TX_FEE);
+			witness_broadcast(original_tx_out_id);
+
+			System::assert_last_event(RuntimeEvent::Broadcaster(
+				crate::Event::<Test, Instance1>::BroadcastSuccess {
+					broadcast_id,
+					transaction_out_id: original_tx_out_id,
+					transaction_ref: 0,
+				},
+			));
+			assert_eq!(
+				TransactionFeeDeficit::<Test, Instance1>::get(u64::default()),
+				expected_refund
+			);
+			assert!(TransactionOutIdToBroadcastId::<Test, Instance1>::get(replacement_tx_out_id)
+				.is_none());
+			assert!(ReplacedCalls::<Test, Instance1>::get(broadcast_id).is_empty());
+			assert_broadcast_storage_cleaned_up(broadcast_id);
+		});
+}
+
+fn abort_broadcast(broadcast_id: BroadcastId) {
+	let nominee = ready_to_abort_broadcast(broadcast_id);
+	assert_ok!(Broadcaster::transaction_failed(RawOrigin::Signed(nominee).into(), broadcast_id));
+	assert!(AbortedBroadcasts::<Test, Instance1>::get().contains(&broadcast_id));
+}
+
+#[test]
+fn aborted_broadcast_can_be_inspected_and_resigned() {
+	new_test_ext().execute_with(|| {
+		let (tx_out_id, api_call) = api_call(1);
+		let broadcast_id = initiate_and_sign_broadcast(&api_call, TxType::Normal);
+
+		assert_noop!(
+			Broadcaster::resign_aborted_broadcast(RuntimeOrigin::root(), broadcast_id),
+			Error::<Test, Instance1>::BroadcastNotAborted
+		);
+		abort_broadcast(broadcast_id);
+
+		assert_ok!(Broadcaster::inspect_aborted_broadcast(RuntimeOrigin::root(), broadcast_id));
+		System::assert_last_event(RuntimeEvent::Broadcaster(
+			crate::Event::<Test, Instance1>::AbortedBroadcastInspected {
+				broadcast_id,
+				transaction_out_id: tx_out_id,
+				transaction_payload: Default::default(),
+			},
+		));
+
+		assert_ok!(Broadcaster::resign_aborted_broadcast(RuntimeOrigin::root(), broadcast_id));
+		System::assert_last_event(RuntimeEvent::Broadcaster(
+			crate::Event::<Test, Instance1>::AbortedBroadcastResigned { broadcast_id },
+		));
+		assert!(!AbortedBroadcasts::<Test, Instance1>::get().contains(&broadcast_id));
+		assert!(PendingBroadcasts::<Test, Instance1>::get().contains(&broadcast_id));
+
+		EthMockThresholdSigner::execute_signature_result_against_last_request(Ok(ETH_DUMMY_SIG));
+		assert_transaction_broadcast_request_event(broadcast_id, tx_out_id);
+
+		witness_broadcast(tx_out_id);
+		assert_broadcast_storage_cleaned_up(broadcast_id);
+	});
+}
+
+#[test]
+fn aborted_broadcast_can_be_rebuilt() {
+	type MockBuilder = MockTransactionBuilder<MockEthereum, MockApiCall<MockEthereumChainCrypto>>;
+	new_test_ext().execute_with(|| {
+		let (tx_out_id, api_call) = api_call(1);
+		let broadcast_id = initiate_and_sign_broadcast(&api_call, TxType::Normal);
+		abort_broadcast(broadcast_id);
+
+		// Without a replacement, the same call is broadcast with a freshly built transaction.
+		assert_ok!(Broadcaster::rebuild_aborted_broadcast(RuntimeOrigin::root(), broadcast_id));
+		System::assert_has_event(RuntimeEvent::Broadcaster(
+			crate::Event::<Test, Instance1>::TransactionBroadcastRequest {
+				broadcast_id,
+				nominee: MockNominator::get_last_nominee().unwrap(),
+				transaction_payload: Default::default(),
+				transaction_out_id: tx_out_id,
+			},
+		));
+		System::assert_last_event(RuntimeEvent::Broadcaster(
+			crate::Event::<Test, Instance1>::AbortedBroadcastRebuilt { broadcast_id },
+		));
+		assert!(PendingBroadcasts::<Test, Instance1>::get().contains(&broadcast_id));
+
+		// If the builder can replace the call, the replacement is signed and broadcast.
+		abort_broadcast(broadcast_id);
+		let (replacement_tx_out_id, replacement_call) = api_call(2);
+		MockBuilder::set_replacement(replacement_call);
+		assert_ok!(Broadcaster::rebuild_aborted_broadcast(RuntimeOrigin::root(), broadcast_id));
+		assert_eq!(ReplacedCalls::<Test, Instance1>::get(broadcast_id).len(), 1);
+
+		EthMockThresholdSigner::execute_signature_result_against_last_request(Ok(ETH_DUMMY_SIG));
+		assert_transaction_broadcast_request_event(broadcast_id, replacement_tx_out_id);
+
+		witness_broadcast(replacement_tx_out_id);
+		assert!(TransactionOutIdToBroadcastId::<Test, Instance1>::get(tx_out_id).is_none());
+		assert_broadcast_storage_cleaned_up(broadcast_id);
+	});
+}
+
+#[test]
+fn aborted_broadcast_can_be_cancelled() {
+	new_test_ext().execute_with(|| {
+		let (tx_out_id, api_call) = api_call(1);
+		let broadcast_id = initiate_and_sign_broadcast(&api_call, TxType::Normal);
+		abort_broadcast(broadcast_id);
+
+		assert_ok!(Broadcaster::cancel_aborted_broadcast(RuntimeOrigin::root(), broadcast_id));
+		System::assert_last_event(RuntimeEvent::Broadcaster(
+			crate::Event::<Test, Instance1>::AbortedBroadcastCancelled { broadcast_id },
+		));
+		assert!(!AbortedBroadcasts::<Test, Instance1>::get().contains(&broadcast_id));
+		assert!(AwaitingBroadcast::<Test, Instance1>::get(broadcast_id).is_none());
+		assert!(TransactionOutIdToBroadcastId::<Test, Instance1>::get(tx_out_id).is_none());
+		assert_broadcast_storage_cleaned_up(broadcast_id);
+
+		assert_noop!(
+			Broadcaster::cancel_aborted_broadcast(RuntimeOrigin::root(), broadcast_id),
+			Error::<Test, Instance1>::BroadcastNotAborted
+		);
+	});
+}
+
+#[test]
+fn replacement_signed_after_success_is_not_broadcast() {
+	type MockBuilder = MockTransactionBuilder<MockEthereum, MockApiCall<MockEthereumChainCrypto>>;
+	let mut expiry = 0;
+	let (original_tx_out_id, original_call) = api_call(1);
+	let (replacement_tx_out_id, replacement_call) = api_call(2);
+	new_test_ext()
+		.execute_with(|| {
+			let broadcast_id = initiate_and_sign_broadcast(&original_call, TxType::Normal);
+			MockBuilder::set_replacement(replacement_call);
+			expiry = System::block_number() + BROADCAST_EXPIRY_BLOCKS;
+			broadcast_id
+		})
+		// The broadcast times out and a replacement is requested in the next block.
+		.then_execute_at_block(expiry, |broadcast_id| broadcast_id)
+		.then_execute_at_next_block(|broadcast_id| {
+			System::assert_has_event(RuntimeEvent::Broadcaster(
+				crate::Event::<Test, Instance1>::TransactionFeeBumpRequested {
+					broadcast_id,
+					replaced_transaction_out_id: original_tx_out_id,
+				},
+			));
+
+			// The original is included before the replacement is signed.
+			witness_broadcast(original_tx_out_id);
+			assert_broadcast_storage_cleaned_up(broadcast_id);
+
+			EthMockThresholdSigner::execute_signature_result_against_last_request(Ok(
+				ETH_DUMMY_SIG,
+			));
+
+			// The replacement is neither made ready nor broadcast.
+			assert_eq!(ReadyTransactionOutIds::get(), vec![original_tx_out_id]);
+			assert!(TransactionOutIdToBroadcastId::<Test, Instance1>::get(replacement_tx_out_id)
+				.is_none());
+			assert!(!System::events().iter().any(|record| matches!(
+				record.event,
+				RuntimeEvent::Broadcaster(
+					crate::Event::<Test, Instance1>::TransactionBroadcastRequest {
+						transaction_out_id,
+						..
+					}
+				) if transaction_out_id == replacement_tx_out_id
+			)));
+			assert_broadcast_storage_cleaned_up(broadcast_id);
+		});
+}
@@ -96,6 +96,7 @@ impl pallet_cf_flip::Config for Test {
 	type OnAccountFunded = MockOnAccountFunded;
 	type WeightInfo = ();
 	type WaivedFees = WaivedFeesMock;
+	type FeePayer = ();
 }
 
 pub const EMISSION_RATE: u128 = 10;
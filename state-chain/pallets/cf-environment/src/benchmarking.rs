@@ -36,5 +36,44 @@ mod benchmarks {
 		assert_eq!(ConsolidationParameters::<T>::get(), INITIAL_CONSOLIDATION_PARAMETERS);
 	}
 
+	#[benchmark]
+	fn set_sol_durable_nonce() {
+		let origin = T::EnsureGovernance::try_successful_origin().unwrap();
+		let call = Call::<T>::set_sol_durable_nonce {
+			nonce_account: SolAddress([1u8; 32]),
+			durable_nonce: SolHash([2u8; 32]),
+		};
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		assert_eq!(
+			SolanaDurableNonce::<T>::get(),
+			Some((SolAddress([1u8; 32]), SolHash([2u8; 32])))
+		);
+	}
+
+	#[benchmark]
+	fn witness_sol_durable_nonce() {
+		SolanaDurableNonce::<T>::put((SolAddress([1u8; 32]), SolHash([2u8; 32])));
+		let origin = T::EnsureWitnessed::try_successful_origin().unwrap();
+		let call = Call::<T>::witness_sol_durable_nonce {
+			nonce_account: SolAddress([1u8; 32]),
+			durable_nonce: SolHash([3u8; 32]),
+		};
+
+		#[block]
+		{
+			assert_ok!(call.dispatch_bypass_filter(origin));
+		}
+
+		assert_eq!(
+			SolanaDurableNonce::<T>::get(),
+			Some((SolAddress([1u8; 32]), SolHash([3u8; 32])))
+		);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
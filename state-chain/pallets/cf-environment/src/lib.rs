@@ -12,11 +12,11 @@ use cf_chains::{
 	},
 	dot::{Polkadot, PolkadotAccountId, PolkadotHash, PolkadotIndex},
 	eth::Address as EvmAddress,
-	sol::SolAddress,
+	sol::{SolAddress, SolHash},
 	Chain,
 };
 use cf_primitives::{
-	chains::assets::{arb::Asset as ArbAsset, eth::Asset as EthAsset},
+	chains::assets::{arb::Asset as ArbAsset, eth::Asset as EthAsset, sol::Asset as SolAsset},
 	NetworkEnvironment, SemVer,
 };
 use cf_traits::{
@@ -36,7 +36,7 @@ pub mod weights;
 pub use weights::WeightInfo;
 pub mod migrations;
 
-pub const PALLET_VERSION: StorageVersion = StorageVersion::new(11);
+pub const PALLET_VERSION: StorageVersion = StorageVersion::new(13);
 
 const INITIAL_CONSOLIDATION_PARAMETERS: utxo_selection::ConsolidationParameters =
 	utxo_selection::ConsolidationParameters {
@@ -63,7 +63,7 @@ pub enum SafeModeUpdate<T: Config> {
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use cf_chains::{btc::Utxo, Arbitrum};
+	use cf_chains::{btc::Utxo, Arbitrum, Assethub};
 	use cf_primitives::TxId;
 	use cf_traits::VaultKeyWitnessedHandler;
 	use frame_support::DefaultNoBound;
@@ -80,6 +80,8 @@ pub mod pallet {
 		type BitcoinVaultKeyWitnessedHandler: VaultKeyWitnessedHandler<Bitcoin>;
 		/// On new key witnessed handler for Arbitrum
 		type ArbitrumVaultKeyWitnessedHandler: VaultKeyWitnessedHandler<Arbitrum>;
+		/// On new key witnessed handler for Polkadot Asset Hub
+		type AssethubVaultKeyWitnessedHandler: VaultKeyWitnessedHandler<Assethub>;
 
 		/// For getting the current active AggKey. Used for rotating Utxos from previous vault.
 		type BitcoinKeyProvider: KeyProvider<<Bitcoin as Chain>::ChainCrypto>;
@@ -103,6 +105,8 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// Eth is not an Erc20 token, so its address can't be updated.
 		EthAddressNotUpdateable,
+		/// The witnessed durable nonce belongs to an account other than the Solana nonce account.
+		UnexpectedSolanaNonceAccount,
 	}
 
 	#[pallet::pallet]
@@ -205,6 +209,36 @@ pub mod pallet {
 	#[pallet::getter(fn sol_vault_address)]
 	pub type SolanaVaultAddress<T> = StorageValue<_, SolAddress, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn supported_sol_assets)]
+	/// Map of supported SPL token assets for SOL, to their mint addresses
+	pub type SolanaSupportedAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, SolAsset, SolAddress>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sol_durable_nonce)]
+	/// The durable nonce account used by Solana transactions, and its current nonce
+	pub type SolanaDurableNonce<T> = StorageValue<_, (SolAddress, SolHash), OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn sol_genesis_hash)]
+	/// The genesis hash of the Solana cluster the engines are expected to connect to
+	pub type SolanaGenesisHash<T> = StorageValue<_, SolHash, OptionQuery>;
+
+	// ASSETHUB CHAIN RELATED ENVIRONMENT ITEMS
+	#[pallet::storage]
+	#[pallet::getter(fn assethub_genesis_hash)]
+	pub type AssethubGenesisHash<T> = StorageValue<_, PolkadotHash, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn assethub_vault_account)]
+	/// The Polkadot Asset Hub Vault Anonymous Account
+	pub type AssethubVaultAccountId<T> = StorageValue<_, PolkadotAccountId, OptionQuery>;
+
+	#[pallet::storage]
+	/// Current Nonce of the current Polkadot Asset Hub Proxy Account
+	pub type AssethubProxyAccountNonce<T> = StorageValue<_, PolkadotIndex, ValueQuery>;
+
 	// OTHER ENVIRONMENT ITEMS
 	#[pallet::storage]
 	#[pallet::getter(fn safe_mode)]
@@ -245,6 +279,10 @@ pub mod pallet {
 		ArbitrumInitialized,
 		/// Some unspendable Utxos are discarded from storage.
 		StaleUtxosDiscarded { utxos: Vec<Utxo> },
+		/// Polkadot Asset Hub Vault Account is successfully set
+		AssethubVaultAccountSet { assethub_vault_account_id: PolkadotAccountId },
+		/// The Solana durable nonce account or its nonce was updated
+		SolanaDurableNonceUpdated { nonce_account: SolAddress, durable_nonce: SolHash },
 	}
 
 	#[pallet::call]
@@ -393,6 +431,101 @@ pub mod pallet {
 
 			Ok(dispatch_result)
 		}
+
+		/// Manually initiates Polkadot Asset Hub vault key rotation completion steps so Epoch
+		/// rotation can be continued and sets the Asset Hub Pure Proxy Vault in environment
+		/// pallet. The pure proxy vault is created on Asset Hub by the current Polkadot aggkey,
+		/// analogously to the Polkadot vault.
+		///
+		/// ## Events
+		///
+		/// - [AssethubVaultAccountSet](Event::AssethubVaultAccountSet)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		#[pallet::call_index(6)]
+		// This weight is not strictly correct but since it's a governance call, weight is
+		// irrelevant.
+		#[pallet::weight(Weight::zero())]
+		pub fn witness_assethub_vault_creation(
+			origin: OriginFor<T>,
+			hub_pure_proxy_vault_key: PolkadotAccountId,
+			tx_id: TxId,
+		) -> DispatchResultWithPostInfo {
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			use cf_traits::VaultKeyWitnessedHandler;
+
+			AssethubVaultAccountId::<T>::put(hub_pure_proxy_vault_key);
+			Self::deposit_event(Event::<T>::AssethubVaultAccountSet {
+				assethub_vault_account_id: hub_pure_proxy_vault_key,
+			});
+
+			// Witness the agg_key rotation manually in the vaults pallet for Asset Hub
+			let dispatch_result =
+				T::AssethubVaultKeyWitnessedHandler::on_first_key_activated(tx_id.block_number)?;
+
+			Ok(dispatch_result)
+		}
+
+		/// Sets the durable nonce account used by Solana transactions, along with its current
+		/// nonce. From then on, the engines track the account and witness any change to its nonce.
+		///
+		/// ## Events
+		///
+		/// - [SolanaDurableNonceUpdated](Event::SolanaDurableNonceUpdated)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_sol_durable_nonce())]
+		pub fn set_sol_durable_nonce(
+			origin: OriginFor<T>,
+			nonce_account: SolAddress,
+			durable_nonce: SolHash,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			Self::update_sol_durable_nonce(nonce_account, durable_nonce);
+
+			Ok(())
+		}
+
+		/// Records the nonce of the Solana durable nonce account, as witnessed after it has been
+		/// advanced by one of our transactions.
+		///
+		/// ## Events
+		///
+		/// - [SolanaDurableNonceUpdated](Event::SolanaDurableNonceUpdated)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		/// - [UnexpectedSolanaNonceAccount](Error::UnexpectedSolanaNonceAccount)
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::witness_sol_durable_nonce())]
+		pub fn witness_sol_durable_nonce(
+			origin: OriginFor<T>,
+			nonce_account: SolAddress,
+			durable_nonce: SolHash,
+		) -> DispatchResult {
+			T::EnsureWitnessed::ensure_origin(origin)?;
+
+			let (current_nonce_account, current_durable_nonce) =
+				SolanaDurableNonce::<T>::get().ok_or(Error::<T>::UnexpectedSolanaNonceAccount)?;
+			ensure!(
+				nonce_account == current_nonce_account,
+				Error::<T>::UnexpectedSolanaNonceAccount
+			);
+
+			if durable_nonce != current_durable_nonce {
+				Self::update_sol_durable_nonce(nonce_account, durable_nonce);
+			}
+
+			Ok(())
+		}
 	}
 
 	#[pallet::genesis_config]
@@ -415,6 +548,11 @@ pub mod pallet {
 		pub arbitrum_chain_id: u64,
 		pub network_environment: NetworkEnvironment,
 		pub sol_vault_address: SolAddress,
+		pub sol_usdc_address: SolAddress,
+		pub sol_genesis_hash: Option<SolHash>,
+		pub sol_durable_nonce: Option<(SolAddress, SolHash)>,
+		pub assethub_genesis_hash: PolkadotHash,
+		pub assethub_vault_account_id: Option<PolkadotAccountId>,
 		pub _config: PhantomData<T>,
 	}
 
@@ -446,6 +584,13 @@ pub mod pallet {
 			ArbitrumAddressCheckerAddress::<T>::set(self.arb_address_checker_address);
 
 			SolanaVaultAddress::<T>::set(self.sol_vault_address);
+			SolanaSupportedAssets::<T>::insert(SolAsset::SolUsdc, self.sol_usdc_address);
+			SolanaGenesisHash::<T>::set(self.sol_genesis_hash);
+			SolanaDurableNonce::<T>::set(self.sol_durable_nonce);
+
+			AssethubGenesisHash::<T>::set(self.assethub_genesis_hash);
+			AssethubVaultAccountId::<T>::set(self.assethub_vault_account_id);
+			AssethubProxyAccountNonce::<T>::set(0);
 
 			ChainflipNetworkEnvironment::<T>::set(self.network_environment);
 
@@ -486,6 +631,24 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	pub fn next_assethub_proxy_account_nonce(reset_nonce: bool) -> PolkadotIndex {
+		AssethubProxyAccountNonce::<T>::mutate(|nonce| {
+			let current_nonce = *nonce;
+
+			if reset_nonce {
+				*nonce = 0;
+			} else {
+				*nonce += 1;
+			}
+			current_nonce
+		})
+	}
+
+	fn update_sol_durable_nonce(nonce_account: SolAddress, durable_nonce: SolHash) {
+		SolanaDurableNonce::<T>::put((nonce_account, durable_nonce));
+		Self::deposit_event(Event::<T>::SolanaDurableNonceUpdated { nonce_account, durable_nonce });
+	}
+
 	pub fn add_bitcoin_utxo_to_list(
 		amount: BtcAmount,
 		utxo_id: UtxoId,
@@ -502,6 +665,21 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Whether the utxo with the given id is in the list of available utxos.
+	pub fn bitcoin_utxo_available(utxo_id: &UtxoId) -> bool {
+		BitcoinAvailableUtxos::<T>::get().iter().any(|utxo| utxo.id == *utxo_id)
+	}
+
+	/// Removes the utxo with the given id from the list of available utxos, if it's still there.
+	pub fn take_bitcoin_utxo(utxo_id: &UtxoId) -> Option<Utxo> {
+		BitcoinAvailableUtxos::<T>::mutate(|available_utxos| {
+			available_utxos
+				.iter()
+				.position(|utxo| utxo.id == *utxo_id)
+				.map(|index| available_utxos.remove(index))
+		})
+	}
+
 	// Calculate the selection of utxos, return them and remove them from the list. The fee required
 	// to spend the input utxos are accounted for while selection. The fee required to include
 	// outputs and the minimum constant tx fee is incorporated by adding to the output amount. The
@@ -4,7 +4,7 @@ use crate::{self as pallet_cf_environment, Decode, Encode, TypeInfo};
 use cf_chains::{
 	btc::{BitcoinCrypto, BitcoinFeeInfo},
 	dot::{api::CreatePolkadotVault, PolkadotCrypto},
-	eth, ApiCall, Arbitrum, Bitcoin, Chain, ChainCrypto, Polkadot,
+	eth, ApiCall, Arbitrum, Assethub, Bitcoin, Chain, ChainCrypto, Polkadot,
 };
 use cf_primitives::{BroadcastId, SemVer, ThresholdSignatureRequestId};
 use cf_traits::{
@@ -160,6 +160,15 @@ impl VaultKeyWitnessedHandler<Arbitrum> for MockArbitrumVaultKeyWitnessedHandler
 	}
 }
 
+pub struct MockAssethubVaultKeyWitnessedHandler;
+impl VaultKeyWitnessedHandler<Assethub> for MockAssethubVaultKeyWitnessedHandler {
+	fn on_first_key_activated(
+		_block_number: <Assethub as Chain>::ChainBlockNumber,
+	) -> frame_support::pallet_prelude::DispatchResultWithPostInfo {
+		Ok(().into())
+	}
+}
+
 parameter_types! {
 	pub CurrentReleaseVersion: SemVer = SemVer {
 		major: env!("CARGO_PKG_VERSION_MAJOR").parse::<u8>().unwrap(),
@@ -185,6 +194,7 @@ impl pallet_cf_environment::Config for Test {
 	type PolkadotVaultKeyWitnessedHandler = MockPolkadotVaultKeyWitnessedHandler;
 	type BitcoinVaultKeyWitnessedHandler = MockBitcoinVaultKeyWitnessedHandler;
 	type ArbitrumVaultKeyWitnessedHandler = MockArbitrumVaultKeyWitnessedHandler;
+	type AssethubVaultKeyWitnessedHandler = MockAssethubVaultKeyWitnessedHandler;
 	type BitcoinFeeInfo = MockBitcoinFeeInfo;
 	type BitcoinKeyProvider = MockBitcoinKeyProvider;
 	type RuntimeSafeMode = MockRuntimeSafeMode;
@@ -203,6 +213,7 @@ pub const ARB_VAULT_ADDRESS: eth::Address = H160([5u8; 20]);
 pub const ARBUSDC_TOKEN_ADDRESS: eth::Address = H160([6u8; 20]);
 pub const ARB_ADDRESS_CHECKER_ADDRESS: eth::Address = H160([7u8; 20]);
 pub const ARB_CHAIN_ID: u64 = 2;
+pub const ASSETHUB_GENESIS_HASH: H256 = H256([8u8; 32]);
 
 cf_test_utilities::impl_test_helpers! {
 	Test,
@@ -224,6 +235,8 @@ cf_test_utilities::impl_test_helpers! {
 			eth_usdt_address: [0x2; 20].into(),
 			polkadot_genesis_hash: H256([0u8; 32]),
 			polkadot_vault_account_id: None,
+			assethub_genesis_hash: ASSETHUB_GENESIS_HASH,
+			assethub_vault_account_id: None,
 			network_environment: Default::default(),
 			..Default::default()
 		},
@@ -1,14 +1,18 @@
 #![cfg(test)]
 
-use cf_chains::btc::{
-	api::UtxoSelectionType, deposit_address::DepositAddress, utxo_selection, AggKey,
-	BitcoinFeeInfo, BtcAmount, Utxo, CHANGE_ADDRESS_SALT,
+use cf_chains::{
+	btc::{
+		api::UtxoSelectionType, deposit_address::DepositAddress, utxo_selection, AggKey,
+		BitcoinFeeInfo, BtcAmount, Utxo, CHANGE_ADDRESS_SALT,
+	},
+	sol::{SolAddress, SolHash},
 };
 use cf_traits::SafeMode;
-use frame_support::{assert_ok, traits::OriginTrait};
+use frame_support::{assert_noop, assert_ok, traits::OriginTrait};
 
 use crate::{
-	mock::*, BitcoinAvailableUtxos, ConsolidationParameters, RuntimeSafeMode, SafeModeUpdate,
+	mock::*, AssethubVaultAccountId, BitcoinAvailableUtxos, ConsolidationParameters,
+	RuntimeSafeMode, SafeModeUpdate, SolanaDurableNonce,
 };
 
 fn utxo(amount: BtcAmount, salt: u32, pub_key: Option<[u8; 32]>) -> Utxo {
@@ -39,6 +43,26 @@ fn genesis_config() {
 		assert_eq!(ARB_KEY_MANAGER_ADDRESS, Environment::arb_key_manager_address());
 		assert_eq!(ETH_CHAIN_ID, Environment::ethereum_chain_id());
 		assert_eq!(ARB_CHAIN_ID, Environment::arbitrum_chain_id());
+		assert_eq!(ASSETHUB_GENESIS_HASH, Environment::assethub_genesis_hash());
+	});
+}
+
+#[test]
+fn witness_assethub_vault_creation() {
+	new_test_ext().execute_with(|| {
+		const VAULT: cf_chains::dot::PolkadotAccountId =
+			cf_chains::dot::PolkadotAccountId::from_aliased([9u8; 32]);
+
+		assert_eq!(AssethubVaultAccountId::<Test>::get(), None);
+		assert_ok!(Environment::witness_assethub_vault_creation(
+			OriginTrait::root(),
+			VAULT,
+			cf_primitives::TxId { block_number: 1, extrinsic_index: 2 },
+		));
+		assert_eq!(AssethubVaultAccountId::<Test>::get(), Some(VAULT));
+		System::assert_last_event(RuntimeEvent::Environment(
+			crate::Event::<Test>::AssethubVaultAccountSet { assethub_vault_account_id: VAULT },
+		));
 	});
 }
 
@@ -154,6 +178,55 @@ fn update_safe_mode() {
 	});
 }
 
+#[test]
+fn witnessing_the_sol_durable_nonce() {
+	new_test_ext().execute_with(|| {
+		const NONCE_ACCOUNT: SolAddress = SolAddress([1u8; 32]);
+		const OTHER_ACCOUNT: SolAddress = SolAddress([2u8; 32]);
+
+		// Nothing can be witnessed until the nonce account is set
+		assert_noop!(
+			Environment::witness_sol_durable_nonce(
+				OriginTrait::root(),
+				NONCE_ACCOUNT,
+				SolHash([3u8; 32])
+			),
+			crate::Error::<Test>::UnexpectedSolanaNonceAccount
+		);
+
+		assert_ok!(Environment::set_sol_durable_nonce(
+			OriginTrait::root(),
+			NONCE_ACCOUNT,
+			SolHash([3u8; 32])
+		));
+		assert_eq!(SolanaDurableNonce::<Test>::get(), Some((NONCE_ACCOUNT, SolHash([3u8; 32]))));
+
+		// The nonce of the nonce account is updated once it has been advanced
+		assert_ok!(Environment::witness_sol_durable_nonce(
+			OriginTrait::root(),
+			NONCE_ACCOUNT,
+			SolHash([4u8; 32])
+		));
+		assert_eq!(SolanaDurableNonce::<Test>::get(), Some((NONCE_ACCOUNT, SolHash([4u8; 32]))));
+		System::assert_last_event(RuntimeEvent::Environment(
+			crate::Event::<Test>::SolanaDurableNonceUpdated {
+				nonce_account: NONCE_ACCOUNT,
+				durable_nonce: SolHash([4u8; 32]),
+			},
+		));
+
+		// Nonces of other accounts are rejected
+		assert_noop!(
+			Environment::witness_sol_durable_nonce(
+				OriginTrait::root(),
+				OTHER_ACCOUNT,
+				SolHash([5u8; 32])
+			),
+			crate::Error::<Test>::UnexpectedSolanaNonceAccount
+		);
+	});
+}
+
 #[test]
 fn can_discard_stale_utxos() {
 	let epoch_1 = [0xFE; 32];
@@ -34,6 +34,8 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn update_safe_mode() -> Weight;
 	fn update_consolidation_parameters() -> Weight;
+	fn set_sol_durable_nonce() -> Weight;
+	fn witness_sol_durable_nonce() -> Weight;
 }
 
 /// Weights for pallet_cf_environment using the Substrate node and recommended hardware.
@@ -59,6 +61,27 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 		Weight::from_parts(71_000_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Environment::SolanaDurableNonce` (r:0 w:1)
+	/// Proof: `Environment::SolanaDurableNonce` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_sol_durable_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(70_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Environment::SolanaDurableNonce` (r:1 w:1)
+	/// Proof: `Environment::SolanaDurableNonce` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn witness_sol_durable_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `108`
+		//  Estimated: `1593`
+		// Minimum execution time: 66_000_000 picoseconds.
+		Weight::from_parts(80_000_000, 1593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -83,4 +106,25 @@ impl WeightInfo for () {
 		Weight::from_parts(71_000_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Environment::SolanaDurableNonce` (r:0 w:1)
+	/// Proof: `Environment::SolanaDurableNonce` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_sol_durable_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 58_000_000 picoseconds.
+		Weight::from_parts(70_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Environment::SolanaDurableNonce` (r:1 w:1)
+	/// Proof: `Environment::SolanaDurableNonce` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn witness_sol_durable_nonce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `108`
+		//  Estimated: `1593`
+		// Minimum execution time: 66_000_000 picoseconds.
+		Weight::from_parts(80_000_000, 1593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
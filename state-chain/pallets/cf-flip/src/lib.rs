@@ -40,7 +40,7 @@ pub use pallet::*;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use cf_traits::{Chainflip, OnAccountFunded, WaivedFees};
+	use cf_traits::{Chainflip, FeePayer, OnAccountFunded, WaivedFees};
 
 	/// A 4-byte identifier for different reserves.
 	pub type ReserveId = [u8; 4];
@@ -77,6 +77,9 @@ pub mod pallet {
 			AccountId = Self::AccountId,
 			RuntimeCall = <Self as frame_system::Config>::RuntimeCall,
 		>;
+
+		/// Determines which account is charged for an extrinsic.
+		type FeePayer: FeePayer<Self::AccountId, <Self as frame_system::Config>::RuntimeCall>;
 	}
 
 	#[pallet::pallet]
@@ -3,8 +3,11 @@
 //! The Chainflip network is permissioned and as such the main reasons for fees are (a) to encourage
 //! 'good' behaviour and (b) to ensure that only funded actors can submit extrinsics to the network.
 
-use crate::{imbalances::Surplus, Config as FlipConfig, Pallet as Flip};
-use cf_traits::WaivedFees;
+use crate::{
+	imbalances::{ImbalanceSource, InternalSource, Surplus},
+	Config as FlipConfig, Pallet as Flip,
+};
+use cf_traits::{FeePayer, WaivedFees};
 use frame_support::{
 	pallet_prelude::InvalidTransaction,
 	sp_runtime::traits::{DispatchInfoOf, Zero},
@@ -18,7 +21,8 @@ use sp_std::marker::PhantomData;
 ///
 /// Fees are burned.
 /// Tips are ignored.
-/// Any excess fees are refunded to the caller.
+/// Fees are charged to the account determined by [FlipConfig::FeePayer], usually the caller.
+/// Any excess fees are refunded to the account that paid them.
 pub struct FlipTransactionPayment<T>(PhantomData<T>);
 
 impl<T: TxConfig + FlipConfig + Config> OnChargeTransaction<T> for FlipTransactionPayment<T> {
@@ -35,7 +39,7 @@ impl<T: TxConfig + FlipConfig + Config> OnChargeTransaction<T> for FlipTransacti
 		if T::WaivedFees::should_waive_fees(call, who) {
 			return Ok(None)
 		}
-		if let Some(surplus) = Flip::<T>::try_debit(who, fee) {
+		if let Some(surplus) = Flip::<T>::try_debit(&T::FeePayer::fee_payer(call, who), fee) {
 			if surplus.peek().is_zero() {
 				Ok(None)
 			} else {
@@ -47,7 +51,7 @@ impl<T: TxConfig + FlipConfig + Config> OnChargeTransaction<T> for FlipTransacti
 	}
 
 	fn correct_and_deposit_fee(
-		who: &T::AccountId,
+		_who: &T::AccountId,
 		_dispatch_info: &sp_runtime::traits::DispatchInfoOf<
 			<T as frame_system::Config>::RuntimeCall,
 		>,
@@ -59,9 +63,13 @@ impl<T: TxConfig + FlipConfig + Config> OnChargeTransaction<T> for FlipTransacti
 		escrow: Self::LiquidityInfo,
 	) -> Result<(), frame_support::unsigned::TransactionValidityError> {
 		if let Some(surplus) = escrow {
-			// It's possible the account was deleted during extrinsic execution. If this is the
-			// case, we shouldn't refund anything, we can just burn all fees in escrow.
-			let to_burn = if frame_system::Pallet::<T>::account_exists(who) {
+			// It's possible the paying account was deleted during extrinsic execution. If this is
+			// the case, we shouldn't refund anything, we can just burn all fees in escrow.
+			let to_burn = if matches!(
+				&surplus.source,
+				ImbalanceSource::Internal(InternalSource::Account(payer))
+					if frame_system::Pallet::<T>::account_exists(payer)
+			) {
 				corrected_fee
 			} else {
 				surplus.peek()
@@ -2,7 +2,7 @@
 
 use super::*;
 
-use cf_traits::Chainflip;
+use cf_traits::{AccountRoleRegistry, Chainflip};
 use frame_benchmarking::v2::*;
 use frame_support::{
 	assert_ok,
@@ -175,5 +175,48 @@ mod benchmarks {
 		assert!(BoundExecutorAddress::<T>::contains_key(&caller));
 	}
 
+	#[benchmark]
+	fn set_operator_commission() {
+		let caller =
+			T::AccountRoleRegistry::whitelisted_caller_with_role(AccountRole::Validator).unwrap();
+
+		#[extrinsic_call]
+		set_operator_commission(RawOrigin::Signed(caller.clone()), Percent::from_percent(10));
+
+		assert_eq!(OperatorCommission::<T>::get(&caller), Some(Percent::from_percent(10)));
+	}
+
+	#[benchmark]
+	fn delegate() {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, 0);
+		OperatorCommission::<T>::insert(&operator, Percent::from_percent(10));
+		fund_with_minimum::<T>(&caller);
+		let amount = MinimumFunding::<T>::get();
+
+		#[extrinsic_call]
+		delegate(RawOrigin::Signed(caller.clone()), operator.clone(), amount);
+
+		assert_eq!(Delegations::<T>::get(&operator, &caller), amount);
+	}
+
+	#[benchmark]
+	fn undelegate() {
+		let caller: T::AccountId = whitelisted_caller();
+		let operator: T::AccountId = account("operator", 0, 0);
+		OperatorCommission::<T>::insert(&operator, Percent::from_percent(10));
+		fund_with_minimum::<T>(&caller);
+		assert_ok!(Call::<T>::delegate {
+			operator: operator.clone(),
+			amount: MinimumFunding::<T>::get()
+		}
+		.dispatch_bypass_filter(RawOrigin::Signed(caller.clone()).into()));
+
+		#[extrinsic_call]
+		undelegate(RawOrigin::Signed(caller.clone()), RedemptionAmount::Max);
+
+		assert!(!DelegatedOperator::<T>::contains_key(&caller));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
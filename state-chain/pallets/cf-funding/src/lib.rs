@@ -16,9 +16,10 @@ pub use weights::WeightInfo;
 mod tests;
 
 use cf_chains::{eth::Address as EthereumAddress, RegisterRedemption};
+use cf_primitives::{AccountRole, EpochIndex};
 use cf_traits::{
-	impl_pallet_safe_mode, AccountInfo, AccountRoleRegistry, Broadcaster, Chainflip, FeePayment,
-	Funding,
+	impl_pallet_safe_mode, AccountInfo, AccountRoleRegistry, Broadcaster, Chainflip, DelegationApi,
+	EpochInfo, FeePayment, Funding,
 };
 use codec::{Decode, Encode};
 use frame_support::{
@@ -26,7 +27,7 @@ use frame_support::{
 	ensure,
 	sp_runtime::{
 		traits::{CheckedSub, UniqueSaturatedInto, Zero},
-		Saturating,
+		Percent, Perquintill, Saturating,
 	},
 	traits::{EnsureOrigin, HandleLifetime, IsType, OnKilledAccount, StorageVersion, UnixTime},
 };
@@ -37,6 +38,7 @@ use sp_std::{
 	cmp::{max, min},
 	collections::btree_map::BTreeMap,
 	prelude::*,
+	vec,
 };
 #[derive(Encode, Decode, PartialEq, Debug, TypeInfo)]
 pub enum Pending {
@@ -44,6 +46,10 @@ pub enum Pending {
 }
 pub const PALLET_VERSION: StorageVersion = StorageVersion::new(4);
 
+/// The maximum number of accounts that can delegate to a single operator. Rewards and slashes are
+/// shared with every delegator, so this bounds the work done each time an operator is rewarded.
+pub const MAX_DELEGATORS_PER_OPERATOR: u32 = 100;
+
 #[derive(Encode, Decode, PartialEq, Debug, TypeInfo)]
 pub struct PendingRedemptionInfo<FlipBalance> {
 	pub total: FlipBalance,
@@ -51,6 +57,13 @@ pub struct PendingRedemptionInfo<FlipBalance> {
 	pub redeem_address: EthereumAddress,
 }
 
+/// The number of accounts delegating to an operator, and the total amount they have delegated.
+#[derive(Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub struct DelegationTotals<FlipBalance> {
+	pub delegators: u32,
+	pub amount: FlipBalance,
+}
+
 impl_pallet_safe_mode!(PalletSafeMode; redeem_enabled);
 
 #[frame_support::pallet]
@@ -176,6 +189,40 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type RedemptionTax<T: Config> = StorageValue<_, T::Amount, ValueQuery>;
 
+	/// Validator operators that accept delegations, and the commission they take from their
+	/// delegators' share of rewards.
+	#[pallet::storage]
+	pub type OperatorCommission<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId<T>, Percent, OptionQuery>;
+
+	/// FLIP delegated to an operator, keyed by operator and then by delegator.
+	#[pallet::storage]
+	pub type Delegations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountId<T>,
+		Blake2_128Concat,
+		AccountId<T>,
+		FlipBalance<T>,
+		ValueQuery,
+	>;
+
+	/// Running totals of the delegations to each operator.
+	#[pallet::storage]
+	pub type OperatorDelegationTotals<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId<T>, DelegationTotals<FlipBalance<T>>, ValueQuery>;
+
+	/// The operator that each delegator has delegated to.
+	#[pallet::storage]
+	pub type DelegatedOperator<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId<T>, AccountId<T>, OptionQuery>;
+
+	/// Undelegated FLIP that remains locked until the given epoch has expired, because it backed an
+	/// authority in that epoch.
+	#[pallet::storage]
+	pub type PendingUndelegations<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountId<T>, (FlipBalance<T>, EpochIndex), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -231,6 +278,21 @@ pub mod pallet {
 
 		/// An account has been bound to an executor address.
 		BoundExecutorAddress { account_id: AccountId<T>, address: EthereumAddress },
+
+		/// An operator has set the commission it takes from its delegators' rewards.
+		OperatorCommissionSet { operator: AccountId<T>, commission: Percent },
+
+		/// FLIP has been delegated to an operator.
+		Delegated { delegator: AccountId<T>, operator: AccountId<T>, amount: FlipBalance<T> },
+
+		/// FLIP has been undelegated from an operator. If the operator is still bonded, the funds
+		/// remain locked until the epoch given by `locked_until_epoch` has expired.
+		Undelegated {
+			delegator: AccountId<T>,
+			operator: AccountId<T>,
+			amount: FlipBalance<T>,
+			locked_until_epoch: Option<EpochIndex>,
+		},
 	}
 
 	#[pallet::error]
@@ -273,6 +335,31 @@ pub mod pallet {
 
 		/// The account cannot be reaped before it is unregstered.
 		AccountMustBeUnregistered,
+
+		/// The account is not an operator that accepts delegations.
+		NotAnOperator,
+
+		/// Validator accounts cannot delegate their funds to another operator.
+		ValidatorCannotDelegate,
+
+		/// The account has already delegated to a different operator.
+		AlreadyDelegatedToAnotherOperator,
+
+		/// The account has not delegated any funds.
+		NotDelegating,
+
+		/// The account does not have enough undelegated funds available.
+		InsufficientUndelegatedFunds,
+
+		/// Delegated funds, and recently undelegated funds that are still locked, cannot be
+		/// redeemed.
+		DelegatedFundsLocked,
+
+		/// An account's delegation must be at least the minimum funding amount.
+		BelowMinimumDelegation,
+
+		/// The operator has reached the maximum number of delegators.
+		TooManyDelegators,
 	}
 
 	#[pallet::call]
@@ -380,10 +467,10 @@ pub mod pallet {
 				.saturating_sub(T::Flip::balance(&account_id));
 
 			// The available funds are the total balance minus whichever is larger from:
-			// - The bond.
+			// - The bond, plus any funds locked by delegation.
 			// - The total restricted funds that need to remain in the account after the redemption.
 			let liquid_balance = T::Flip::balance(&account_id).saturating_sub(max(
-				T::Flip::bond(&account_id),
+				T::Flip::bond(&account_id).saturating_add(Self::locked_by_delegation(&account_id)),
 				restricted_balances.values().copied().sum::<FlipBalance<T>>().saturating_sub(
 					restricted_deficit +
 						restricted_balances.get(&address).copied().unwrap_or_default(),
@@ -429,6 +516,10 @@ pub mod pallet {
 						restricted_deficit,
 				Error::<T>::InsufficientUnrestrictedFunds
 			);
+			ensure!(
+				remaining_balance >= Self::locked_by_delegation(&account_id),
+				Error::<T>::DelegatedFundsLocked
+			);
 
 			// Update the account balance.
 			if redeem_amount > Zero::zero() {
@@ -706,6 +797,158 @@ pub mod pallet {
 			});
 			Ok(().into())
 		}
+
+		/// Sets the commission that an operator takes from its delegators' share of rewards.
+		/// Setting a commission allows other accounts to delegate to the operator.
+		///
+		/// ## Events
+		///
+		/// - [OperatorCommissionSet](Event::OperatorCommissionSet)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_operator_commission())]
+		pub fn set_operator_commission(
+			origin: OriginFor<T>,
+			commission: Percent,
+		) -> DispatchResultWithPostInfo {
+			let operator = T::AccountRoleRegistry::ensure_validator(origin)?;
+			OperatorCommission::<T>::insert(&operator, commission);
+			Self::deposit_event(Event::OperatorCommissionSet { operator, commission });
+			Ok(().into())
+		}
+
+		/// Delegates some of the caller's funds to an operator. Delegated funds count towards the
+		/// operator's auction bid, earn a share of its rewards and share in its slashes. They
+		/// cannot be redeemed until they are undelegated.
+		///
+		/// An account can only delegate to one operator at a time.
+		///
+		/// ## Events
+		///
+		/// - [Delegated](Event::Delegated)
+		///
+		/// ## Errors
+		///
+		/// - [NotAnOperator](Error::NotAnOperator)
+		/// - [ValidatorCannotDelegate](Error::ValidatorCannotDelegate)
+		/// - [AlreadyDelegatedToAnotherOperator](Error::AlreadyDelegatedToAnotherOperator)
+		/// - [InsufficientUndelegatedFunds](Error::InsufficientUndelegatedFunds)
+		/// - [BelowMinimumDelegation](Error::BelowMinimumDelegation)
+		/// - [TooManyDelegators](Error::TooManyDelegators)
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::delegate())]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			operator: AccountId<T>,
+			amount: FlipBalance<T>,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+
+			ensure!(OperatorCommission::<T>::contains_key(&operator), Error::<T>::NotAnOperator);
+			ensure!(
+				!T::AccountRoleRegistry::has_account_role(&delegator, AccountRole::Validator),
+				Error::<T>::ValidatorCannotDelegate
+			);
+			if let Some(current_operator) = DelegatedOperator::<T>::get(&delegator) {
+				ensure!(
+					current_operator == operator,
+					Error::<T>::AlreadyDelegatedToAnotherOperator
+				);
+			}
+			ensure!(
+				amount <=
+					T::Flip::liquid_funds(&delegator)
+						.saturating_sub(Self::locked_by_delegation(&delegator)),
+				Error::<T>::InsufficientUndelegatedFunds
+			);
+			let delegated = Self::delegated_by(&delegator).saturating_add(amount);
+			ensure!(
+				!amount.is_zero() && delegated >= MinimumFunding::<T>::get(),
+				Error::<T>::BelowMinimumDelegation
+			);
+			ensure!(
+				Delegations::<T>::contains_key(&operator, &delegator) ||
+					OperatorDelegationTotals::<T>::get(&operator).delegators <
+						MAX_DELEGATORS_PER_OPERATOR,
+				Error::<T>::TooManyDelegators
+			);
+
+			Self::set_delegation(&operator, &delegator, delegated);
+
+			Self::deposit_event(Event::Delegated { delegator, operator, amount });
+			Ok(().into())
+		}
+
+		/// Undelegates some or all of the caller's delegated funds.
+		///
+		/// Undelegation follows the same rules as redemption: it is not possible while the
+		/// operator is bidding in an auction. If the operator is an authority in an epoch that
+		/// has not yet expired, the undelegated funds remain locked until that epoch expires, like
+		/// the operator's bond.
+		///
+		/// ## Events
+		///
+		/// - [Undelegated](Event::Undelegated)
+		///
+		/// ## Errors
+		///
+		/// - [NotDelegating](Error::NotDelegating)
+		/// - [InsufficientUndelegatedFunds](Error::InsufficientUndelegatedFunds)
+		/// - [BelowMinimumDelegation](Error::BelowMinimumDelegation)
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::undelegate())]
+		pub fn undelegate(
+			origin: OriginFor<T>,
+			amount: RedemptionAmount<FlipBalance<T>>,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+
+			let operator =
+				DelegatedOperator::<T>::get(&delegator).ok_or(Error::<T>::NotDelegating)?;
+
+			// Not allowed to undelegate if the operator is an active bidder in the auction phase.
+			T::RedemptionChecker::ensure_can_redeem(&operator)?;
+
+			let delegated = Self::delegated_by(&delegator);
+			let amount = match amount {
+				RedemptionAmount::Max => delegated,
+				RedemptionAmount::Exact(amount) => {
+					ensure!(amount <= delegated, Error::<T>::InsufficientUndelegatedFunds);
+					amount
+				},
+			};
+
+			let remaining = delegated.saturating_sub(amount);
+			ensure!(
+				remaining.is_zero() || remaining >= MinimumFunding::<T>::get(),
+				Error::<T>::BelowMinimumDelegation
+			);
+			Self::set_delegation(&operator, &delegator, remaining);
+
+			let locked_until_epoch = Self::last_bonded_epoch(&operator).map(|bonded_epoch| {
+				let still_locked = Self::pending_undelegation(&delegator);
+				let epoch = match PendingUndelegations::<T>::get(&delegator) {
+					Some((_, epoch)) if !still_locked.is_zero() => epoch.max(bonded_epoch),
+					_ => bonded_epoch,
+				};
+				PendingUndelegations::<T>::insert(
+					&delegator,
+					(still_locked.saturating_add(amount), epoch),
+				);
+				epoch
+			});
+
+			Self::deposit_event(Event::Undelegated {
+				delegator,
+				operator,
+				amount,
+				locked_until_epoch,
+			});
+			Ok(().into())
+		}
 	}
 
 	#[pallet::genesis_config]
@@ -755,6 +998,135 @@ impl<T: Config> Pallet<T> {
 
 		T::Flip::credit_funds(account_id, amount)
 	}
+
+	/// The funds in an account that cannot be redeemed because they are delegated, or because they
+	/// were undelegated from an authority whose bond has not been released yet.
+	pub fn locked_by_delegation(account_id: &AccountId<T>) -> T::Amount {
+		Self::delegated_by(account_id).saturating_add(Self::pending_undelegation(account_id))
+	}
+
+	/// Undelegated funds that are still locked because the epoch they backed has not expired.
+	fn pending_undelegation(account_id: &AccountId<T>) -> T::Amount {
+		match PendingUndelegations::<T>::get(account_id) {
+			Some((amount, epoch)) if epoch > T::EpochInfo::last_expired_epoch() => amount,
+			_ => Zero::zero(),
+		}
+	}
+
+	/// The latest epoch that has not expired yet in which the operator is an authority, and is
+	/// therefore still bonded.
+	fn last_bonded_epoch(operator: &AccountId<T>) -> Option<EpochIndex> {
+		let operator = <T::ValidatorId as IsType<T::AccountId>>::from_ref(operator);
+		(T::EpochInfo::last_expired_epoch().saturating_add(1)..=T::EpochInfo::epoch_index())
+			.rev()
+			.find(|epoch| T::EpochInfo::authorities_at_epoch(*epoch).contains(operator))
+	}
+
+	/// Sets the amount that a delegator has delegated to an operator, keeping the operator's
+	/// totals in sync. Setting the amount to zero removes the delegation.
+	fn set_delegation(operator: &AccountId<T>, delegator: &AccountId<T>, amount: T::Amount) {
+		let previous = Delegations::<T>::try_get(operator, delegator).ok();
+		OperatorDelegationTotals::<T>::mutate(operator, |totals| {
+			totals.amount = totals
+				.amount
+				.saturating_sub(previous.unwrap_or_default())
+				.saturating_add(amount);
+			match (previous.is_some(), amount.is_zero()) {
+				(false, false) => totals.delegators.saturating_inc(),
+				(true, true) => totals.delegators.saturating_dec(),
+				_ => {},
+			}
+		});
+		if amount.is_zero() {
+			Delegations::<T>::remove(operator, delegator);
+			DelegatedOperator::<T>::remove(delegator);
+		} else {
+			Delegations::<T>::insert(operator, delegator, amount);
+			DelegatedOperator::<T>::insert(delegator, operator);
+		}
+	}
+
+	/// Splits an amount between an operator and its delegators, pro-rata to their stake. The
+	/// commission is deducted from each delegator's share and goes to the operator, along with
+	/// any rounding remainder.
+	fn split_by_stake(
+		operator: &AccountId<T>,
+		amount: T::Amount,
+		commission: Percent,
+	) -> Vec<(AccountId<T>, T::Amount)> {
+		let delegations = Delegations::<T>::iter_prefix(operator)
+			.map(|(delegator, delegated)| {
+				let delegated = min(delegated, T::Flip::balance(&delegator));
+				(delegator, delegated)
+			})
+			.filter(|(_, delegated)| !delegated.is_zero())
+			.collect::<Vec<_>>();
+		let total_stake = delegations
+			.iter()
+			.map(|(_, delegated)| *delegated)
+			.fold(T::Flip::balance(operator), |total, delegated| total.saturating_add(delegated));
+
+		if total_stake.is_zero() {
+			return vec![(operator.clone(), amount)]
+		}
+
+		let mut operator_share = amount;
+		let mut shares = Vec::with_capacity(delegations.len() + 1);
+		for (delegator, delegated) in delegations {
+			let gross_share = Perquintill::from_rational(delegated, total_stake) * amount;
+			let share = gross_share.saturating_sub(commission * gross_share);
+			operator_share.saturating_reduce(share);
+			shares.push((delegator, share));
+		}
+		shares.insert(0, (operator.clone(), operator_share));
+		shares
+	}
+}
+
+impl<T: Config> DelegationApi for Pallet<T> {
+	type AccountId = AccountId<T>;
+	type Amount = T::Amount;
+
+	fn delegated_to(operator: &Self::AccountId) -> Self::Amount {
+		OperatorDelegationTotals::<T>::get(operator).amount
+	}
+
+	fn delegated_by(delegator: &Self::AccountId) -> Self::Amount {
+		// Slashes and fees reduce the delegator's balance, and with it the delegated amount.
+		DelegatedOperator::<T>::get(delegator)
+			.map(|operator| {
+				min(Delegations::<T>::get(&operator, delegator), T::Flip::balance(delegator))
+			})
+			.unwrap_or_default()
+	}
+
+	fn reward_shares(
+		operator: &Self::AccountId,
+		reward: Self::Amount,
+	) -> Vec<(Self::AccountId, Self::Amount)> {
+		Self::split_by_stake(
+			operator,
+			reward,
+			OperatorCommission::<T>::get(operator).unwrap_or_default(),
+		)
+	}
+
+	fn slash_shares(
+		operator: &Self::AccountId,
+		slash: Self::Amount,
+	) -> Vec<(Self::AccountId, Self::Amount)> {
+		Self::split_by_stake(operator, slash, Percent::zero())
+	}
+
+	fn reconcile_delegation(delegator: &Self::AccountId) {
+		if let Some(operator) = DelegatedOperator::<T>::get(delegator) {
+			let delegated = Delegations::<T>::get(&operator, delegator);
+			let balance = T::Flip::balance(delegator);
+			if balance < delegated {
+				Self::set_delegation(&operator, delegator, balance);
+			}
+		}
+	}
 }
 
 /// Ensure we clean up account specific items that definitely won't be required once the account
@@ -764,5 +1136,10 @@ impl<T: Config> OnKilledAccount<T::AccountId> for Pallet<T> {
 		RestrictedBalances::<T>::remove(account_id);
 		BoundExecutorAddress::<T>::remove(account_id);
 		BoundRedeemAddress::<T>::remove(account_id);
+		if let Some(operator) = DelegatedOperator::<T>::get(account_id) {
+			Self::set_delegation(&operator, account_id, Zero::zero());
+		}
+		PendingUndelegations::<T>::remove(account_id);
+		OperatorCommission::<T>::remove(account_id);
 	}
 }
@@ -77,6 +77,7 @@ impl pallet_cf_flip::Config for Test {
 	type OnAccountFunded = MockOnAccountFunded;
 	type WeightInfo = ();
 	type WaivedFees = WaivedFeesMock;
+	type FeePayer = ();
 }
 
 cf_traits::impl_mock_ensure_witnessed_for_origin!(RuntimeOrigin);
@@ -1755,3 +1755,344 @@ fn account_references_must_be_zero_for_full_redeem() {
 		);
 	});
 }
+
+mod delegation {
+	use super::*;
+	use crate::{
+		DelegatedOperator, DelegationTotals, Delegations, OperatorDelegationTotals,
+		PendingUndelegations, MAX_DELEGATORS_PER_OPERATOR,
+	};
+	use cf_traits::DelegationApi;
+	use sp_runtime::{AccountId32, Percent};
+
+	const OPERATOR_FUNDS: FlipBalance = 100;
+	const DELEGATOR_FUNDS: FlipBalance = 400;
+	const DELEGATED: FlipBalance = 300;
+
+	fn setup_operator_and_delegator() {
+		for (account_id, amount) in [(ALICE, OPERATOR_FUNDS), (BOB, DELEGATOR_FUNDS)] {
+			assert_ok!(Funding::funded(
+				RuntimeOrigin::root(),
+				account_id,
+				amount,
+				ETH_ZERO_ADDRESS,
+				TX_HASH
+			));
+		}
+		assert_ok!(<MockAccountRoleRegistry as AccountRoleRegistry<Test>>::register_as_validator(
+			&ALICE
+		));
+		assert_ok!(Funding::set_operator_commission(
+			RuntimeOrigin::signed(ALICE),
+			Percent::from_percent(10)
+		));
+	}
+
+	#[test]
+	fn can_delegate_to_operator() {
+		new_test_ext().execute_with(|| {
+			setup_operator_and_delegator();
+
+			// Only operators can receive delegations.
+			assert_noop!(
+				Funding::delegate(RuntimeOrigin::signed(BOB), CHARLIE, DELEGATED),
+				Error::<Test>::NotAnOperator
+			);
+			// Validators can't delegate their own funds.
+			assert_noop!(
+				Funding::delegate(RuntimeOrigin::signed(CHARLIE), ALICE, MIN_FUNDING),
+				Error::<Test>::ValidatorCannotDelegate
+			);
+			assert_noop!(
+				Funding::delegate(RuntimeOrigin::signed(BOB), ALICE, DELEGATOR_FUNDS + 1),
+				Error::<Test>::InsufficientUndelegatedFunds
+			);
+
+			assert_ok!(Funding::delegate(RuntimeOrigin::signed(BOB), ALICE, DELEGATED));
+			assert_eq!(Delegations::<Test>::get(ALICE, BOB), DELEGATED);
+			assert_eq!(DelegatedOperator::<Test>::get(BOB), Some(ALICE));
+			assert_eq!(<Funding as DelegationApi>::delegated_to(&ALICE), DELEGATED);
+			assert_eq!(<Funding as DelegationApi>::delegated_by(&BOB), DELEGATED);
+
+			// Can't delegate more than the remaining undelegated funds.
+			assert_noop!(
+				Funding::delegate(
+					RuntimeOrigin::signed(BOB),
+					ALICE,
+					DELEGATOR_FUNDS - DELEGATED + 1
+				),
+				Error::<Test>::InsufficientUndelegatedFunds
+			);
+
+			// Can only delegate to one operator at a time.
+			assert_ok!(Funding::set_operator_commission(
+				RuntimeOrigin::signed(CHARLIE),
+				Percent::from_percent(5)
+			));
+			assert_noop!(
+				Funding::delegate(RuntimeOrigin::signed(BOB), CHARLIE, MIN_FUNDING),
+				Error::<Test>::AlreadyDelegatedToAnotherOperator
+			);
+
+			assert_event_sequence!(
+				Test,
+				RuntimeEvent::System(frame_system::Event::NewAccount { account: ALICE }),
+				RuntimeEvent::Funding(crate::Event::Funded { account_id: ALICE, .. }),
+				RuntimeEvent::System(frame_system::Event::NewAccount { account: BOB }),
+				RuntimeEvent::Funding(crate::Event::Funded { account_id: BOB, .. }),
+				RuntimeEvent::Funding(crate::Event::OperatorCommissionSet {
+					operator: ALICE,
+					commission: Percent::from_percent(10),
+				}),
+				RuntimeEvent::Funding(crate::Event::Delegated {
+					delegator: BOB,
+					operator: ALICE,
+					amount: DELEGATED,
+				}),
+				RuntimeEvent::Funding(crate::Event::OperatorCommissionSet {
+					operator: CHARLIE,
+					commission: Percent::from_percent(5),
+				}),
+			);
+		});
+	}
+
+	#[test]
+	fn delegated_funds_cannot_be_redeemed() {
+		new_test_ext().execute_with(|| {
+			setup_operator_and_delegator();
+			assert_ok!(Funding::delegate(RuntimeOrigin::signed(BOB), ALICE, DELEGATED));
+
+			assert_noop!(
+				Funding::redeem(
+					RuntimeOrigin::signed(BOB),
+					(DELEGATOR_FUNDS - DELEGATED + 1).into(),
+					ETH_DUMMY_ADDR,
+					Default::default()
+				),
+				Error::<Test>::DelegatedFundsLocked
+			);
+
+			assert_ok!(Funding::redeem(
+				RuntimeOrigin::signed(BOB),
+				RedemptionAmount::Max,
+				ETH_DUMMY_ADDR,
+				Default::default()
+			));
+			assert_eq!(Flip::total_balance_of(&BOB), DELEGATED);
+		});
+	}
+
+	#[test]
+	fn undelegation_follows_redemption_rules() {
+		new_test_ext().execute_with(|| {
+			setup_operator_and_delegator();
+			assert_ok!(Funding::delegate(RuntimeOrigin::signed(BOB), ALICE, DELEGATED));
+			MockEpochInfo::set_epoch(1);
+			MockEpochInfo::set_authorities(vec![ALICE]);
+
+			// Can't undelegate while the operator is bidding in the auction phase.
+			CanRedeem::set(false);
+			assert_noop!(
+				Funding::undelegate(RuntimeOrigin::signed(BOB), RedemptionAmount::Max),
+				BIDDING_ERR
+			);
+			CanRedeem::set(true);
+
+			assert_noop!(
+				Funding::undelegate(RuntimeOrigin::signed(BOB), (DELEGATED + 1).into()),
+				Error::<Test>::InsufficientUndelegatedFunds
+			);
+			assert_ok!(Funding::undelegate(RuntimeOrigin::signed(BOB), RedemptionAmount::Max));
+			assert!(!DelegatedOperator::<Test>::contains_key(BOB));
+			assert_eq!(<Funding as DelegationApi>::delegated_to(&ALICE), 0);
+			assert_eq!(PendingUndelegations::<Test>::get(BOB), Some((DELEGATED, 1)));
+			assert_noop!(
+				Funding::undelegate(RuntimeOrigin::signed(BOB), RedemptionAmount::Max),
+				Error::<Test>::NotDelegating
+			);
+
+			// The operator is an authority, so the funds stay locked until its bond is released,
+			// once the epoch has expired.
+			for epoch in [1, 2] {
+				MockEpochInfo::set_epoch(epoch);
+				assert_noop!(
+					Funding::redeem(
+						RuntimeOrigin::signed(BOB),
+						(DELEGATOR_FUNDS - DELEGATED + 1).into(),
+						ETH_DUMMY_ADDR,
+						Default::default()
+					),
+					Error::<Test>::DelegatedFundsLocked
+				);
+			}
+
+			MockEpochInfo::set_last_expired_epoch(1);
+			assert_ok!(Funding::redeem(
+				RuntimeOrigin::signed(BOB),
+				RedemptionAmount::Max,
+				ETH_DUMMY_ADDR,
+				Default::default()
+			));
+			assert_eq!(Flip::total_balance_of(&BOB), 0);
+
+			assert_event_sequence!(
+				Test,
+				RuntimeEvent::System(frame_system::Event::NewAccount { account: ALICE }),
+				RuntimeEvent::Funding(crate::Event::Funded { account_id: ALICE, .. }),
+				RuntimeEvent::System(frame_system::Event::NewAccount { account: BOB }),
+				RuntimeEvent::Funding(crate::Event::Funded { account_id: BOB, .. }),
+				RuntimeEvent::Funding(crate::Event::OperatorCommissionSet { .. }),
+				RuntimeEvent::Funding(crate::Event::Delegated { .. }),
+				RuntimeEvent::Funding(crate::Event::Undelegated {
+					delegator: BOB,
+					operator: ALICE,
+					amount: DELEGATED,
+					locked_until_epoch: Some(1),
+				}),
+				_,
+			);
+		});
+	}
+
+	#[test]
+	fn undelegated_funds_stay_locked_while_the_operator_is_bonded() {
+		new_test_ext().execute_with(|| {
+			setup_operator_and_delegator();
+			assert_ok!(Funding::delegate(RuntimeOrigin::signed(BOB), ALICE, DELEGATED));
+
+			// The operator is no longer an authority, but its previous epoch has not expired.
+			MockEpochInfo::set_epoch(2);
+			MockEpochInfo::set_last_expired_epoch(0);
+			MockEpochInfo::set_past_authorities(vec![ALICE]);
+			MockEpochInfo::set_authorities(vec![]);
+
+			assert_ok!(Funding::undelegate(RuntimeOrigin::signed(BOB), MIN_FUNDING.into()));
+			assert_eq!(PendingUndelegations::<Test>::get(BOB), Some((MIN_FUNDING, 1)));
+
+			// Undelegating again while the operator is an authority in the current epoch extends
+			// the lock to all the pending funds.
+			MockEpochInfo::set_authorities(vec![ALICE]);
+			assert_ok!(Funding::undelegate(RuntimeOrigin::signed(BOB), MIN_FUNDING.into()));
+			assert_eq!(PendingUndelegations::<Test>::get(BOB), Some((2 * MIN_FUNDING, 2)));
+			assert_eq!(Funding::locked_by_delegation(&BOB), DELEGATED);
+
+			MockEpochInfo::set_last_expired_epoch(1);
+			assert_eq!(Funding::locked_by_delegation(&BOB), DELEGATED);
+
+			MockEpochInfo::set_last_expired_epoch(2);
+			assert_eq!(Funding::locked_by_delegation(&BOB), DELEGATED - 2 * MIN_FUNDING);
+
+			// Once the operator's bond has been released, undelegated funds are not locked.
+			MockEpochInfo::set_epoch(3);
+			MockEpochInfo::set_authorities(vec![]);
+			assert_ok!(Funding::undelegate(RuntimeOrigin::signed(BOB), MIN_FUNDING.into()));
+			assert_eq!(Funding::locked_by_delegation(&BOB), DELEGATED - 3 * MIN_FUNDING);
+		});
+	}
+
+	#[test]
+	fn rewards_and_slashes_are_shared_pro_rata() {
+		new_test_ext().execute_with(|| {
+			setup_operator_and_delegator();
+			assert_ok!(Funding::delegate(RuntimeOrigin::signed(BOB), ALICE, DELEGATED));
+
+			// BOB provides 3/4 of the stake, less the operator's 10% commission on rewards.
+			assert_eq!(
+				<Funding as DelegationApi>::reward_shares(&ALICE, 1_000),
+				vec![(ALICE, 325), (BOB, 675)]
+			);
+			assert_eq!(
+				<Funding as DelegationApi>::slash_shares(&ALICE, 100),
+				vec![(ALICE, 25), (BOB, 75)]
+			);
+
+			// Slashing the delegator reduces the delegated amount.
+			FlipSlasher::<Test>::slash_balance(&BOB, DELEGATOR_FUNDS - DELEGATED + 50);
+			assert_eq!(<Funding as DelegationApi>::delegated_by(&BOB), DELEGATED - 50);
+			<Funding as DelegationApi>::reconcile_delegation(&BOB);
+			assert_eq!(<Funding as DelegationApi>::delegated_to(&ALICE), DELEGATED - 50);
+			assert_eq!(Delegations::<Test>::get(ALICE, BOB), DELEGATED - 50);
+		});
+	}
+
+	#[test]
+	fn delegations_must_meet_the_minimum() {
+		new_test_ext().execute_with(|| {
+			setup_operator_and_delegator();
+
+			for amount in [0, MIN_FUNDING - 1] {
+				assert_noop!(
+					Funding::delegate(RuntimeOrigin::signed(BOB), ALICE, amount),
+					Error::<Test>::BelowMinimumDelegation
+				);
+			}
+			assert_ok!(Funding::delegate(RuntimeOrigin::signed(BOB), ALICE, DELEGATED));
+			// Topping up an existing delegation only needs to be non-zero.
+			assert_ok!(Funding::delegate(RuntimeOrigin::signed(BOB), ALICE, 1));
+
+			// Undelegating must leave either nothing or at least the minimum.
+			assert_noop!(
+				Funding::undelegate(RuntimeOrigin::signed(BOB), DELEGATED.into()),
+				Error::<Test>::BelowMinimumDelegation
+			);
+			assert_ok!(Funding::undelegate(
+				RuntimeOrigin::signed(BOB),
+				(DELEGATED + 1 - MIN_FUNDING).into()
+			));
+			assert_eq!(<Funding as DelegationApi>::delegated_to(&ALICE), MIN_FUNDING);
+		});
+	}
+
+	#[test]
+	fn delegators_per_operator_are_bounded() {
+		new_test_ext().execute_with(|| {
+			setup_operator_and_delegator();
+
+			let delegators = (0..MAX_DELEGATORS_PER_OPERATOR)
+				.map(|i| AccountId32::new([i as u8; 32]))
+				.collect::<Vec<_>>();
+			for delegator in &delegators {
+				assert_ok!(Funding::funded(
+					RuntimeOrigin::root(),
+					delegator.clone(),
+					MIN_FUNDING,
+					ETH_ZERO_ADDRESS,
+					TX_HASH
+				));
+				assert_ok!(Funding::delegate(
+					RuntimeOrigin::signed(delegator.clone()),
+					ALICE,
+					MIN_FUNDING
+				));
+			}
+			assert_eq!(
+				OperatorDelegationTotals::<Test>::get(ALICE),
+				DelegationTotals {
+					delegators: MAX_DELEGATORS_PER_OPERATOR,
+					amount: MIN_FUNDING * MAX_DELEGATORS_PER_OPERATOR as FlipBalance,
+				}
+			);
+
+			assert_noop!(
+				Funding::delegate(RuntimeOrigin::signed(BOB), ALICE, DELEGATED),
+				Error::<Test>::TooManyDelegators
+			);
+
+			// Removing a delegation frees up a slot, and keeps the totals in sync.
+			assert_ok!(Funding::undelegate(
+				RuntimeOrigin::signed(delegators[0].clone()),
+				RedemptionAmount::Max
+			));
+			assert_ok!(Funding::delegate(RuntimeOrigin::signed(BOB), ALICE, DELEGATED));
+			assert_eq!(
+				OperatorDelegationTotals::<Test>::get(ALICE),
+				DelegationTotals {
+					delegators: MAX_DELEGATORS_PER_OPERATOR,
+					amount: MIN_FUNDING * (MAX_DELEGATORS_PER_OPERATOR - 1) as FlipBalance +
+						DELEGATED,
+				}
+			);
+		});
+	}
+}
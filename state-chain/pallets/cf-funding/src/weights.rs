@@ -42,6 +42,9 @@ pub trait WeightInfo {
 	fn bind_redeem_address() -> Weight;
 	fn update_restricted_addresses(a: u32, b: u32, c: u32, ) -> Weight;
 	fn bind_executor_address() -> Weight;
+	fn set_operator_commission() -> Weight;
+	fn delegate() -> Weight;
+	fn undelegate() -> Weight;
 }
 
 /// Weights for pallet_cf_funding using the Substrate node and recommended hardware.
@@ -336,6 +339,81 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OperatorCommission` (r:0 w:1)
+	/// Proof: `Funding::OperatorCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_operator_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `239`
+		//  Estimated: `3498`
+		// Minimum execution time: 14_893_000 picoseconds.
+		Weight::from_parts(15_320_000, 3498)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::OperatorCommission` (r:1 w:0)
+	/// Proof: `Funding::OperatorCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DelegatedOperator` (r:1 w:1)
+	/// Proof: `Funding::DelegatedOperator` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Account` (r:1 w:0)
+	/// Proof: `Flip::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingUndelegations` (r:1 w:0)
+	/// Proof: `Funding::PendingUndelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::CurrentEpoch` (r:1 w:0)
+	/// Proof: `Validator::CurrentEpoch` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::Delegations` (r:1 w:1)
+	/// Proof: `Funding::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::MinimumFunding` (r:1 w:0)
+	/// Proof: `Funding::MinimumFunding` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::OperatorDelegationTotals` (r:1 w:1)
+	/// Proof: `Funding::OperatorDelegationTotals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `4077`
+		// Minimum execution time: 36_417_000 picoseconds.
+		Weight::from_parts(37_205_000, 4077)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Funding::DelegatedOperator` (r:1 w:1)
+	/// Proof: `Funding::DelegatedOperator` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Environment::RuntimeSafeMode` (r:1 w:0)
+	/// Proof: `Environment::RuntimeSafeMode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::CurrentRotationPhase` (r:1 w:0)
+	/// Proof: `Validator::CurrentRotationPhase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::CurrentEpochStartedAt` (r:1 w:0)
+	/// Proof: `Validator::CurrentEpochStartedAt` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::RedemptionPeriodAsPercentage` (r:1 w:0)
+	/// Proof: `Validator::RedemptionPeriodAsPercentage` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::BlocksPerEpoch` (r:1 w:0)
+	/// Proof: `Validator::BlocksPerEpoch` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::Delegations` (r:1 w:1)
+	/// Proof: `Funding::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Account` (r:1 w:0)
+	/// Proof: `Flip::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Validator::CurrentAuthorities` (r:1 w:0)
+	/// Proof: `Validator::CurrentAuthorities` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::CurrentEpoch` (r:1 w:0)
+	/// Proof: `Validator::CurrentEpoch` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::PendingUndelegations` (r:1 w:1)
+	/// Proof: `Funding::PendingUndelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::MinimumFunding` (r:1 w:0)
+	/// Proof: `Funding::MinimumFunding` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::OperatorDelegationTotals` (r:1 w:1)
+	/// Proof: `Funding::OperatorDelegationTotals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1174`
+		//  Estimated: `4639`
+		// Minimum execution time: 48_062_000 picoseconds.
+		Weight::from_parts(49_118_000, 4639)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -628,4 +706,79 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::OperatorCommission` (r:0 w:1)
+	/// Proof: `Funding::OperatorCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_operator_commission() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `239`
+		//  Estimated: `3498`
+		// Minimum execution time: 14_893_000 picoseconds.
+		Weight::from_parts(15_320_000, 3498)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Funding::OperatorCommission` (r:1 w:0)
+	/// Proof: `Funding::OperatorCommission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: Some(33), added: 2508, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::DelegatedOperator` (r:1 w:1)
+	/// Proof: `Funding::DelegatedOperator` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Account` (r:1 w:0)
+	/// Proof: `Flip::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Funding::PendingUndelegations` (r:1 w:0)
+	/// Proof: `Funding::PendingUndelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::CurrentEpoch` (r:1 w:0)
+	/// Proof: `Validator::CurrentEpoch` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::Delegations` (r:1 w:1)
+	/// Proof: `Funding::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::MinimumFunding` (r:1 w:0)
+	/// Proof: `Funding::MinimumFunding` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::OperatorDelegationTotals` (r:1 w:1)
+	/// Proof: `Funding::OperatorDelegationTotals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn delegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `4077`
+		// Minimum execution time: 36_417_000 picoseconds.
+		Weight::from_parts(37_205_000, 4077)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Funding::DelegatedOperator` (r:1 w:1)
+	/// Proof: `Funding::DelegatedOperator` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Environment::RuntimeSafeMode` (r:1 w:0)
+	/// Proof: `Environment::RuntimeSafeMode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::CurrentRotationPhase` (r:1 w:0)
+	/// Proof: `Validator::CurrentRotationPhase` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::CurrentEpochStartedAt` (r:1 w:0)
+	/// Proof: `Validator::CurrentEpochStartedAt` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::RedemptionPeriodAsPercentage` (r:1 w:0)
+	/// Proof: `Validator::RedemptionPeriodAsPercentage` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::BlocksPerEpoch` (r:1 w:0)
+	/// Proof: `Validator::BlocksPerEpoch` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::Delegations` (r:1 w:1)
+	/// Proof: `Funding::Delegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Flip::Account` (r:1 w:0)
+	/// Proof: `Flip::Account` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Validator::CurrentAuthorities` (r:1 w:0)
+	/// Proof: `Validator::CurrentAuthorities` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Validator::CurrentEpoch` (r:1 w:0)
+	/// Proof: `Validator::CurrentEpoch` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::PendingUndelegations` (r:1 w:1)
+	/// Proof: `Funding::PendingUndelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::MinimumFunding` (r:1 w:0)
+	/// Proof: `Funding::MinimumFunding` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Funding::OperatorDelegationTotals` (r:1 w:1)
+	/// Proof: `Funding::OperatorDelegationTotals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn undelegate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1174`
+		//  Estimated: `4639`
+		// Minimum execution time: 48_062_000 picoseconds.
+		Weight::from_parts(49_118_000, 4639)
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
@@ -165,5 +165,66 @@ mod benchmarks {
 		dispatch_whitelisted_call(RawOrigin::Signed(caller.clone()), 1);
 	}
 
+	#[benchmark]
+	fn set_enactment_delay() {
+		let class = ProposalClass { pallet_index: 0, call_index: 0 };
+		let call = Call::<T>::set_enactment_delay { class, delay: Some(100) };
+
+		#[block]
+		{
+			assert_ok!(
+				call.dispatch_bypass_filter(T::EnsureGovernance::try_successful_origin().unwrap())
+			);
+		}
+
+		assert_eq!(EnactmentDelays::<T>::get(class), Some(100));
+	}
+
+	#[benchmark]
+	fn cancel_time_locked_proposal() {
+		let caller: T::AccountId = whitelisted_caller();
+		<Members<T>>::put(BTreeSet::from([caller.clone()]));
+		let call: <T as Config>::RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		TimeLockedProposals::<T>::insert(
+			1,
+			TimeLockedProposal {
+				call: call.encode(),
+				execution: ExecutionMode::Automatic,
+				enactment_time: 100,
+				cancellation_approvals: Default::default(),
+			},
+		);
+		PendingEnactments::<T>::put(vec![PendingEnactment { proposal_id: 1, enactment_time: 100 }]);
+
+		#[extrinsic_call]
+		cancel_time_locked_proposal(RawOrigin::Signed(caller), 1);
+
+		assert!(!TimeLockedProposals::<T>::contains_key(1));
+	}
+
+	#[benchmark]
+	fn release_time_locked_proposals(b: Linear<0, 100>) {
+		let call: <T as Config>::RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+		for proposal_id in 0..b {
+			TimeLockedProposals::<T>::insert(
+				proposal_id,
+				TimeLockedProposal {
+					call: call.encode(),
+					execution: ExecutionMode::Automatic,
+					enactment_time: 0,
+					cancellation_approvals: Default::default(),
+				},
+			);
+			PendingEnactments::<T>::append(PendingEnactment { proposal_id, enactment_time: 0 });
+		}
+
+		#[block]
+		{
+			Pallet::<T>::release_time_locked_proposals();
+		}
+
+		assert_eq!(ExecutionPipeline::<T>::decode_len().unwrap_or_default(), b as usize);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
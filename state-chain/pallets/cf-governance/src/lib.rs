@@ -12,7 +12,7 @@ use frame_support::{
 	pallet_prelude::{DispatchResultWithPostInfo, Weight},
 	sp_runtime::{DispatchError, Percent, TransactionOutcome},
 	storage::with_transaction,
-	traits::{EnsureOrigin, Get, StorageVersion, UnfilteredDispatchable, UnixTime},
+	traits::{EnsureOrigin, Get, IsSubType, StorageVersion, UnfilteredDispatchable, UnixTime},
 };
 pub use pallet::*;
 use sp_std::{boxed::Box, ops::Add, vec::Vec};
@@ -74,6 +74,44 @@ pub mod pallet {
 		pub expiry_time: Timestamp,
 	}
 
+	/// Proposals are grouped into classes by the call they propose, identified by the call's
+	/// pallet index and call index.
+	#[derive(Encode, Decode, TypeInfo, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+	pub struct ProposalClass {
+		pub pallet_index: u8,
+		pub call_index: u8,
+	}
+
+	impl ProposalClass {
+		/// The class of an encoded call.
+		pub fn of(call: &[u8]) -> Option<Self> {
+			match call {
+				[pallet_index, call_index, ..] =>
+					Some(Self { pallet_index: *pallet_index, call_index: *call_index }),
+				_ => None,
+			}
+		}
+	}
+
+	#[derive(Encode, Decode, TypeInfo, Clone, Copy, RuntimeDebug, PartialEq, Eq)]
+	pub struct PendingEnactment {
+		pub proposal_id: ProposalId,
+		pub enactment_time: Timestamp,
+	}
+
+	/// An approved proposal that is waiting for its enactment delay to pass.
+	#[derive(Encode, Decode, TypeInfo, Clone, RuntimeDebug, PartialEq, Eq)]
+	pub struct TimeLockedProposal<AccountId> {
+		/// Encoded representation of a extrinsic.
+		pub call: OpaqueCall,
+		/// How the proposal is executed once the time lock expires.
+		pub execution: ExecutionMode,
+		/// The proposal can be executed from this time onwards.
+		pub enactment_time: Timestamp,
+		/// Accounts who have voted to cancel the proposal.
+		pub cancellation_approvals: BTreeSet<AccountId>,
+	}
+
 	/// Proposal struct
 	#[derive(Encode, Decode, TypeInfo, Clone, RuntimeDebug, PartialEq, Eq)]
 	pub struct Proposal<AccountId> {
@@ -86,8 +124,8 @@ pub mod pallet {
 	}
 
 	type AccountId<T> = <T as frame_system::Config>::AccountId;
-	type OpaqueCall = Vec<u8>;
-	type Timestamp = u64;
+	pub type OpaqueCall = Vec<u8>;
+	pub type Timestamp = u64;
 
 	#[pallet::config]
 	#[pallet::disable_frame_system_supertrait_check]
@@ -103,6 +141,8 @@ pub mod pallet {
 			+ UnfilteredDispatchable<RuntimeOrigin = <Self as Config>::RuntimeOrigin>
 			+ From<frame_system::Call<Self>>
 			+ From<Call<Self>>
+			+ IsSubType<Call<Self>>
+			+ IsSubType<frame_system::Call<Self>>
 			+ GetDispatchInfo;
 		/// UnixTime implementation for TimeSource
 		type TimeSource: UnixTime;
@@ -116,6 +156,10 @@ pub mod pallet {
 		type CompatibleCfeVersions: CompatibleCfeVersions;
 		/// For getting current authorities' CFE versions.
 		type AuthoritiesCfeVersions: AuthoritiesCfeVersions;
+		/// The minimum time in seconds that an approved runtime upgrade proposal is time-locked
+		/// for, regardless of the enactment delay configured for its class.
+		#[pallet::constant]
+		type RuntimeUpgradeMinimumEnactmentDelay: Get<Timestamp>;
 	}
 
 	#[pallet::pallet]
@@ -170,6 +214,20 @@ pub mod pallet {
 	#[pallet::getter(fn members)]
 	pub(super) type Members<T> = StorageValue<_, BTreeSet<AccountId<T>>, ValueQuery>;
 
+	/// Time in seconds that approved proposals of a given class are time-locked for before they
+	/// are executed.
+	#[pallet::storage]
+	pub type EnactmentDelays<T> = StorageMap<_, Twox64Concat, ProposalClass, Timestamp>;
+
+	/// Approved proposals that are time-locked.
+	#[pallet::storage]
+	pub type TimeLockedProposals<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProposalId, TimeLockedProposal<T::AccountId>>;
+
+	/// The enactment times of the time-locked proposals.
+	#[pallet::storage]
+	pub(super) type PendingEnactments<T> = StorageValue<_, Vec<PendingEnactment>, ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		/// on_initialize hook - check the ActiveProposals
@@ -177,8 +235,9 @@ pub mod pallet {
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
 			// Check expiry and expire the proposals if needed
 			let active_proposal_weight = Self::check_expiry();
+			let time_lock_weight = Self::release_time_locked_proposals();
 			let execution_weight = Self::execute_pending_proposals();
-			active_proposal_weight + execution_weight
+			active_proposal_weight + time_lock_weight + execution_weight
 		}
 	}
 
@@ -203,6 +262,14 @@ pub mod pallet {
 		GovKeyCallHashWhitelisted { call_hash: GovCallHash },
 		/// Failed GovKey call
 		GovKeyCallExecutionFailed { call_hash: GovCallHash, error: DispatchError },
+		/// An approved proposal is time-locked until the enactment time.
+		ProposalTimeLocked { proposal_id: ProposalId, enactment_time: Timestamp },
+		/// A member voted to cancel a time-locked proposal.
+		CancellationApproved { proposal_id: ProposalId, member: AccountId<T> },
+		/// A time-locked proposal was cancelled before it was executed.
+		ProposalCancelled { proposal_id: ProposalId },
+		/// The enactment delay of a class of proposals has been updated.
+		EnactmentDelayUpdated { class: ProposalClass, delay: Option<Timestamp> },
 	}
 
 	#[pallet::error]
@@ -223,6 +290,8 @@ pub mod pallet {
 		CallHashNotWhitelisted,
 		/// Insufficient number of CFEs are at the target version to receive the runtime upgrade.
 		NotEnoughAuthoritiesCfesAtTargetVersion,
+		/// The enactment delay for runtime upgrades can't be lower than the configured minimum.
+		EnactmentDelayBelowMinimum,
 	}
 
 	#[pallet::call]
@@ -396,6 +465,10 @@ pub mod pallet {
 		///
 		/// Submit a call to be executed if the gov key has already committed to it.
 		///
+		/// GovKey calls are not time-locked, not even runtime upgrades: this is the emergency path
+		/// that bypasses the Governance Members, for example to replace them if their keys are
+		/// compromised or to roll back a faulty runtime.
+		///
 		/// ## Events
 		///
 		/// - GovKeyCallDispatched
@@ -453,6 +526,91 @@ pub mod pallet {
 				Err(Error::<T>::ProposalNotFound.into())
 			}
 		}
+
+		/// Sets the time in seconds that approved proposals of a given class are time-locked for
+		/// before they are executed. Setting the delay to `None` removes the time lock. Proposals
+		/// that are already time-locked are not affected.
+		///
+		/// **Can only be called via the Governance Origin**
+		///
+		/// ## Events
+		///
+		/// - [EnactmentDelayUpdated](Event::EnactmentDelayUpdated)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_support::error::BadOrigin)
+		/// - [EnactmentDelayBelowMinimum](Error::EnactmentDelayBelowMinimum)
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::set_enactment_delay())]
+		pub fn set_enactment_delay(
+			origin: OriginFor<T>,
+			class: ProposalClass,
+			delay: Option<Timestamp>,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+			if class == Self::runtime_upgrade_class() {
+				ensure!(
+					delay.unwrap_or_default() >= T::RuntimeUpgradeMinimumEnactmentDelay::get(),
+					Error::<T>::EnactmentDelayBelowMinimum
+				);
+			}
+			EnactmentDelays::<T>::set(class, delay);
+			Self::deposit_event(Event::EnactmentDelayUpdated { class, delay });
+			Ok(())
+		}
+
+		/// Votes to cancel a time-locked proposal. The proposal is cancelled once a majority of
+		/// the governance members have voted to cancel it.
+		///
+		/// ## Events
+		///
+		/// - [CancellationApproved](Event::CancellationApproved)
+		/// - [ProposalCancelled](Event::ProposalCancelled)
+		///
+		/// ## Errors
+		///
+		/// - [NotMember](Error::NotMember)
+		/// - [ProposalNotFound](Error::ProposalNotFound)
+		/// - [AlreadyApproved](Error::AlreadyApproved)
+		#[pallet::call_index(9)]
+		#[pallet::weight((T::WeightInfo::cancel_time_locked_proposal(), DispatchClass::Operational))]
+		pub fn cancel_time_locked_proposal(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+		) -> DispatchResultWithPostInfo {
+			let account_id = ensure_governance_member!(origin);
+			let majority =
+				Members::<T>::decode_non_dedup_len().ok_or(Error::<T>::DecodeMembersLenFailed)? / 2;
+
+			let cancelled =
+				TimeLockedProposals::<T>::try_mutate_exists(proposal_id, |maybe_proposal| {
+					let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
+					if !proposal.cancellation_approvals.insert(account_id.clone()) {
+						return Err(Error::<T>::AlreadyApproved)
+					}
+					Self::deposit_event(Event::CancellationApproved {
+						proposal_id,
+						member: account_id,
+					});
+					if proposal.cancellation_approvals.len() > majority {
+						*maybe_proposal = None;
+						Ok(true)
+					} else {
+						Ok(false)
+					}
+				})?;
+
+			if cancelled {
+				PendingEnactments::<T>::mutate(|pending| {
+					pending.retain(|enactment| enactment.proposal_id != proposal_id)
+				});
+				Self::deposit_event(Event::ProposalCancelled { proposal_id });
+			}
+
+			// Governance members don't pay transaction fees
+			Ok(Pays::No.into())
+		}
 	}
 
 	/// Genesis definition
@@ -535,10 +693,28 @@ impl<T: Config> Pallet<T> {
 		if proposal.approved.len() >
 			(Members::<T>::decode_non_dedup_len().ok_or(Error::<T>::DecodeMembersLenFailed)? / 2)
 		{
-			if proposal.execution == ExecutionMode::Manual {
-				PreAuthorisedGovCalls::<T>::insert(approved_id, proposal.call);
+			let enactment_delay = Self::enactment_delay(&proposal.call);
+			if enactment_delay > 0 {
+				let enactment_time = T::TimeSource::now().as_secs() + enactment_delay;
+				TimeLockedProposals::<T>::insert(
+					approved_id,
+					TimeLockedProposal {
+						call: proposal.call,
+						execution: proposal.execution,
+						enactment_time,
+						cancellation_approvals: Default::default(),
+					},
+				);
+				PendingEnactments::<T>::append(PendingEnactment {
+					proposal_id: approved_id,
+					enactment_time,
+				});
+				Self::deposit_event(Event::ProposalTimeLocked {
+					proposal_id: approved_id,
+					enactment_time,
+				});
 			} else {
-				ExecutionPipeline::<T>::append((proposal.call, approved_id));
+				Self::enact_proposal(approved_id, proposal.call, proposal.execution);
 			}
 			Proposals::<T>::remove(approved_id);
 			ActiveProposals::<T>::mutate(|proposals| {
@@ -571,6 +747,94 @@ impl<T: Config> Pallet<T> {
 		Self::expire_proposals(expired) + T::WeightInfo::on_initialize(num_proposals as u32)
 	}
 
+	/// The time in seconds that an approved proposal for the given call is time-locked for. Calls
+	/// that wrap other calls are time-locked for at least as long as the calls they wrap.
+	pub fn enactment_delay(call: &[u8]) -> Timestamp {
+		match <T as Config>::RuntimeCall::decode(&mut &call[..]) {
+			Ok(call) => Self::call_enactment_delay(&call),
+			Err(_) =>
+				ProposalClass::of(call).and_then(EnactmentDelays::<T>::get).unwrap_or_default(),
+		}
+	}
+
+	fn call_enactment_delay(call: &<T as Config>::RuntimeCall) -> Timestamp {
+		let delay = ProposalClass::of(&call.encode())
+			.and_then(EnactmentDelays::<T>::get)
+			.unwrap_or_default();
+		let delay = if Self::is_runtime_upgrade(call) {
+			delay.max(T::RuntimeUpgradeMinimumEnactmentDelay::get())
+		} else {
+			delay
+		};
+		match IsSubType::<Call<T>>::is_sub_type(call) {
+			Some(Call::call_as_sudo { call: inner } | Call::submit_govkey_call { call: inner }) =>
+				delay.max(Self::call_enactment_delay(inner)),
+			_ => delay,
+		}
+	}
+
+	fn is_runtime_upgrade(call: &<T as Config>::RuntimeCall) -> bool {
+		matches!(
+			IsSubType::<Call<T>>::is_sub_type(call),
+			Some(Call::chainflip_runtime_upgrade { .. })
+		) || matches!(
+			IsSubType::<frame_system::Call<T>>::is_sub_type(call),
+			Some(
+				frame_system::Call::set_code { .. } |
+					frame_system::Call::set_code_without_checks { .. }
+			)
+		)
+	}
+
+	/// The proposal class of `chainflip_runtime_upgrade`.
+	pub fn runtime_upgrade_class() -> ProposalClass {
+		ProposalClass::of(
+			&<T as Config>::RuntimeCall::from(Call::<T>::chainflip_runtime_upgrade {
+				cfe_version_restriction: None,
+				code: Default::default(),
+			})
+			.encode(),
+		)
+		.expect("Encoded calls start with the pallet and call index.")
+	}
+
+	/// All time-locked proposals, ordered by their proposal id.
+	pub fn time_locked_proposals() -> Vec<(ProposalId, TimeLockedProposal<T::AccountId>)> {
+		let mut proposals = TimeLockedProposals::<T>::iter().collect::<Vec<_>>();
+		proposals.sort_by_key(|(proposal_id, _)| *proposal_id);
+		proposals
+	}
+
+	fn enact_proposal(proposal_id: ProposalId, call: OpaqueCall, execution: ExecutionMode) {
+		if execution == ExecutionMode::Manual {
+			PreAuthorisedGovCalls::<T>::insert(proposal_id, call);
+		} else {
+			ExecutionPipeline::<T>::append((call, proposal_id));
+		}
+	}
+
+	fn release_time_locked_proposals() -> Weight {
+		let pending_enactments = PendingEnactments::<T>::get();
+		if pending_enactments.is_empty() {
+			return T::WeightInfo::release_time_locked_proposals(0)
+		}
+		let now = T::TimeSource::now().as_secs();
+		let (due, pending): (Vec<PendingEnactment>, Vec<PendingEnactment>) = pending_enactments
+			.into_iter()
+			.partition(|enactment| enactment.enactment_time <= now);
+		if !due.is_empty() {
+			PendingEnactments::<T>::set(pending);
+			for PendingEnactment { proposal_id, .. } in &due {
+				if let Some(TimeLockedProposal { call, execution, .. }) =
+					TimeLockedProposals::<T>::take(proposal_id)
+				{
+					Self::enact_proposal(*proposal_id, call, execution);
+				}
+			}
+		}
+		T::WeightInfo::release_time_locked_proposals(due.len() as u32)
+	}
+
 	fn execute_pending_proposals() -> Weight {
 		let mut execution_weight = Weight::zero();
 		for (call, id) in ExecutionPipeline::<T>::take() {
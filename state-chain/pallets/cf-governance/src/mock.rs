@@ -101,6 +101,7 @@ cf_traits::impl_mock_ensure_witnessed_for_origin!(RuntimeOrigin);
 
 parameter_types! {
 	pub static PercentCfeAtTargetVersion: Percent = Percent::from_percent(100);
+	pub const RuntimeUpgradeMinimumEnactmentDelay: u64 = 100;
 }
 
 pub struct MockAuthoritiesCfeVersions;
@@ -127,6 +128,7 @@ impl pallet_cf_governance::Config for Test {
 	type RuntimeUpgrade = RuntimeUpgradeMock;
 	type AuthoritiesCfeVersions = MockAuthoritiesCfeVersions;
 	type CompatibleCfeVersions = MockCompatibleCfeVersions;
+	type RuntimeUpgradeMinimumEnactmentDelay = RuntimeUpgradeMinimumEnactmentDelay;
 }
 
 pub const ALICE: <Test as frame_system::Config>::AccountId = 123u64;
@@ -1,11 +1,13 @@
 use crate::{
-	mock::*, ActiveProposals, Error, ExecutionMode, ExecutionPipeline, ExpiryTime, Members,
-	PreAuthorisedGovCalls, ProposalIdCounter,
+	mock::*, ActiveProposals, EnactmentDelays, Error, ExecutionMode, ExecutionPipeline, ExpiryTime,
+	Members, PendingEnactments, PreAuthorisedGovCalls, ProposalClass, ProposalId,
+	ProposalIdCounter, TimeLockedProposals,
 };
 use cf_primitives::SemVer;
 use cf_test_utilities::last_event;
 use cf_traits::mocks::time_source;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use codec::Encode;
+use frame_support::{assert_err, assert_noop, assert_ok, traits::UnixTime};
 use sp_runtime::Percent;
 use sp_std::collections::btree_set::BTreeSet;
 use std::time::Duration;
@@ -357,3 +359,265 @@ fn replacing_governance_members() {
 		assert_eq!(System::sufficients(&MAX), 0);
 	});
 }
+
+mod time_lock {
+	use super::*;
+
+	const ENACTMENT_DELAY: u64 = 20;
+
+	fn mock_extrinsic_class() -> ProposalClass {
+		ProposalClass::of(&mock_extrinsic().encode()).unwrap()
+	}
+
+	fn propose_and_approve(call: Box<RuntimeCall>, execution: ExecutionMode) {
+		assert_ok!(Governance::propose_governance_extrinsic(
+			RuntimeOrigin::signed(ALICE),
+			call,
+			execution,
+		));
+		assert_ok!(Governance::approve(RuntimeOrigin::signed(BOB), 1));
+	}
+
+	#[test]
+	fn approved_proposal_is_executed_after_enactment_delay() {
+		new_test_ext()
+			.execute_with(|| {
+				time_source::Mock::reset_to(Duration::from_secs(10));
+				assert_ok!(Governance::set_enactment_delay(
+					crate::RawOrigin::GovernanceApproval.into(),
+					mock_extrinsic_class(),
+					Some(ENACTMENT_DELAY),
+				));
+				propose_and_approve(mock_extrinsic(), ExecutionMode::Automatic);
+				assert_eq!(
+					last_event::<Test>(),
+					RuntimeEvent::Governance(crate::Event::ProposalTimeLocked {
+						proposal_id: 1,
+						enactment_time: 10 + ENACTMENT_DELAY,
+					}),
+				);
+				assert!(TimeLockedProposals::<Test>::contains_key(1));
+				assert_eq!(Governance::time_locked_proposals().len(), 1);
+				assert_eq!(ActiveProposals::<Test>::get().len(), 0);
+			})
+			.then_execute_at_next_block(|_| {
+				// Not executed before the enactment time.
+				assert!(TimeLockedProposals::<Test>::contains_key(1));
+				assert_eq!(Members::<Test>::get(), BTreeSet::from_iter([ALICE, BOB, CHARLES]));
+				time_source::Mock::reset_to(Duration::from_secs(10 + ENACTMENT_DELAY));
+			})
+			.then_execute_at_next_block(|_| {
+				assert_eq!(
+					last_event::<Test>(),
+					RuntimeEvent::Governance(crate::Event::Executed(1)),
+				);
+				assert_eq!(Members::<Test>::get(), BTreeSet::from_iter([EVE, PETER, MAX]));
+				assert!(!TimeLockedProposals::<Test>::contains_key(1));
+				assert!(PendingEnactments::<Test>::get().is_empty());
+			});
+	}
+
+	#[test]
+	fn manual_proposal_is_pre_authorised_after_enactment_delay() {
+		new_test_ext()
+			.execute_with(|| {
+				assert_ok!(Governance::set_enactment_delay(
+					crate::RawOrigin::GovernanceApproval.into(),
+					mock_extrinsic_class(),
+					Some(ENACTMENT_DELAY),
+				));
+				propose_and_approve(mock_extrinsic(), ExecutionMode::Manual);
+				assert!(!PreAuthorisedGovCalls::<Test>::contains_key(1));
+				assert_noop!(
+					Governance::dispatch_whitelisted_call(RuntimeOrigin::signed(CHARLES), 1),
+					Error::<Test>::ProposalNotFound
+				);
+				time_source::Mock::tick(Duration::from_secs(ENACTMENT_DELAY));
+			})
+			.then_execute_at_next_block(|_| {
+				assert!(PreAuthorisedGovCalls::<Test>::contains_key(1));
+				assert_ok!(Governance::dispatch_whitelisted_call(
+					RuntimeOrigin::signed(CHARLES),
+					1
+				));
+			});
+	}
+
+	#[test]
+	fn time_locked_proposal_can_be_cancelled_by_majority() {
+		new_test_ext()
+			.execute_with(|| {
+				assert_ok!(Governance::set_enactment_delay(
+					crate::RawOrigin::GovernanceApproval.into(),
+					mock_extrinsic_class(),
+					Some(ENACTMENT_DELAY),
+				));
+				propose_and_approve(mock_extrinsic(), ExecutionMode::Automatic);
+
+				assert_noop!(
+					Governance::cancel_time_locked_proposal(RuntimeOrigin::signed(EVE), 1),
+					Error::<Test>::NotMember
+				);
+				assert_noop!(
+					Governance::cancel_time_locked_proposal(RuntimeOrigin::signed(ALICE), 2),
+					Error::<Test>::ProposalNotFound
+				);
+				assert_ok!(Governance::cancel_time_locked_proposal(
+					RuntimeOrigin::signed(ALICE),
+					1
+				));
+				assert_noop!(
+					Governance::cancel_time_locked_proposal(RuntimeOrigin::signed(ALICE), 1),
+					Error::<Test>::AlreadyApproved
+				);
+				assert!(TimeLockedProposals::<Test>::contains_key(1));
+
+				assert_ok!(Governance::cancel_time_locked_proposal(RuntimeOrigin::signed(BOB), 1));
+				assert_eq!(
+					last_event::<Test>(),
+					RuntimeEvent::Governance(crate::Event::ProposalCancelled { proposal_id: 1 }),
+				);
+				assert!(!TimeLockedProposals::<Test>::contains_key(1));
+				assert!(PendingEnactments::<Test>::get().is_empty());
+				time_source::Mock::tick(Duration::from_secs(ENACTMENT_DELAY));
+			})
+			.then_execute_at_next_block(|_| {
+				assert_eq!(ExecutionPipeline::<Test>::get().len(), 0);
+				assert_eq!(Members::<Test>::get(), BTreeSet::from_iter([ALICE, BOB, CHARLES]));
+			});
+	}
+
+	#[test]
+	fn enactment_delay_can_be_removed() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Governance::set_enactment_delay(
+				crate::RawOrigin::GovernanceApproval.into(),
+				mock_extrinsic_class(),
+				Some(ENACTMENT_DELAY),
+			));
+			assert_ok!(Governance::set_enactment_delay(
+				crate::RawOrigin::GovernanceApproval.into(),
+				mock_extrinsic_class(),
+				None,
+			));
+			assert!(!EnactmentDelays::<Test>::contains_key(mock_extrinsic_class()));
+
+			propose_and_approve(mock_extrinsic(), ExecutionMode::Automatic);
+			assert!(!TimeLockedProposals::<Test>::contains_key(1));
+			assert_eq!(ExecutionPipeline::<Test>::get().len(), 1);
+		});
+	}
+
+	#[test]
+	fn runtime_upgrades_are_time_locked_for_at_least_the_minimum_delay() {
+		new_test_ext().execute_with(|| {
+			let runtime_upgrade_class = Governance::runtime_upgrade_class();
+			assert_noop!(
+				Governance::set_enactment_delay(
+					crate::RawOrigin::GovernanceApproval.into(),
+					runtime_upgrade_class,
+					Some(RuntimeUpgradeMinimumEnactmentDelay::get() - 1),
+				),
+				Error::<Test>::EnactmentDelayBelowMinimum
+			);
+			assert_noop!(
+				Governance::set_enactment_delay(
+					crate::RawOrigin::GovernanceApproval.into(),
+					runtime_upgrade_class,
+					None,
+				),
+				Error::<Test>::EnactmentDelayBelowMinimum
+			);
+
+			propose_and_approve(
+				Box::new(RuntimeCall::Governance(
+					pallet_cf_governance::Call::<Test>::chainflip_runtime_upgrade {
+						cfe_version_restriction: None,
+						code: DUMMY_WASM_BLOB,
+					},
+				)),
+				ExecutionMode::Automatic,
+			);
+			assert_eq!(
+				TimeLockedProposals::<Test>::get(1).unwrap().enactment_time,
+				time_source::Mock::now().as_secs() + RuntimeUpgradeMinimumEnactmentDelay::get(),
+			);
+		});
+	}
+
+	#[test]
+	fn govkey_calls_are_not_time_locked() {
+		RuntimeUpgradeMock::upgrade_success(true);
+		UpgradeConditionMock::set(true);
+		new_test_ext().execute_with(|| {
+			let runtime_upgrade: Box<RuntimeCall> = Box::new(
+				pallet_cf_governance::Call::<Test>::chainflip_runtime_upgrade {
+					cfe_version_restriction: None,
+					code: DUMMY_WASM_BLOB,
+				}
+				.into(),
+			);
+			let (call_hash, _) =
+				Governance::compute_gov_key_call_hash::<_>(runtime_upgrade.clone());
+			assert_ok!(Governance::set_whitelisted_call_hash(RuntimeOrigin::root(), call_hash));
+
+			assert_ok!(Governance::submit_govkey_call(
+				RuntimeOrigin::signed(ALICE),
+				runtime_upgrade
+			));
+			assert_eq!(
+				last_event::<Test>(),
+				RuntimeEvent::Governance(crate::Event::GovKeyCallExecuted { call_hash }),
+			);
+			assert_eq!(TimeLockedProposals::<Test>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn wrapped_calls_cannot_bypass_the_enactment_delay() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Governance::set_enactment_delay(
+				crate::RawOrigin::GovernanceApproval.into(),
+				mock_extrinsic_class(),
+				Some(ENACTMENT_DELAY),
+			));
+
+			let call_as_sudo = |call: RuntimeCall| {
+				RuntimeCall::Governance(pallet_cf_governance::Call::<Test>::call_as_sudo {
+					call: Box::new(call),
+				})
+			};
+			let runtime_upgrade = RuntimeCall::Governance(
+				pallet_cf_governance::Call::<Test>::chainflip_runtime_upgrade {
+					cfe_version_restriction: None,
+					code: DUMMY_WASM_BLOB,
+				},
+			);
+			let set_code =
+				RuntimeCall::System(frame_system::Call::set_code { code: DUMMY_WASM_BLOB });
+
+			for (proposal_id, (call, delay)) in [
+				(call_as_sudo(*mock_extrinsic()), ENACTMENT_DELAY),
+				(call_as_sudo(runtime_upgrade), RuntimeUpgradeMinimumEnactmentDelay::get()),
+				(set_code.clone(), RuntimeUpgradeMinimumEnactmentDelay::get()),
+				(call_as_sudo(call_as_sudo(set_code)), RuntimeUpgradeMinimumEnactmentDelay::get()),
+			]
+			.into_iter()
+			.enumerate()
+			{
+				let proposal_id = proposal_id as ProposalId + 1;
+				assert_ok!(Governance::propose_governance_extrinsic(
+					RuntimeOrigin::signed(ALICE),
+					Box::new(call),
+					ExecutionMode::Automatic,
+				));
+				assert_ok!(Governance::approve(RuntimeOrigin::signed(BOB), proposal_id));
+				assert_eq!(
+					TimeLockedProposals::<Test>::get(proposal_id).unwrap().enactment_time,
+					time_source::Mock::now().as_secs() + delay,
+				);
+			}
+			assert_eq!(ExecutionPipeline::<Test>::get().len(), 0);
+		});
+	}
+}
@@ -42,6 +42,9 @@ pub trait WeightInfo {
 	fn set_whitelisted_call_hash() -> Weight;
 	fn submit_govkey_call() -> Weight;
 	fn dispatch_whitelisted_call() -> Weight;
+	fn set_enactment_delay() -> Weight;
+	fn cancel_time_locked_proposal() -> Weight;
+	fn release_time_locked_proposals(b: u32, ) -> Weight;
 }
 
 /// Weights for pallet_cf_governance using the Substrate node and recommended hardware.
@@ -244,6 +247,54 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Governance::EnactmentDelays` (r:0 w:1)
+	/// Proof: `Governance::EnactmentDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_enactment_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Governance::Members` (r:1 w:0)
+	/// Proof: `Governance::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Governance::TimeLockedProposals` (r:1 w:1)
+	/// Proof: `Governance::TimeLockedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Governance::PendingEnactments` (r:1 w:1)
+	/// Proof: `Governance::PendingEnactments` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn cancel_time_locked_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224`
+		//  Estimated: `3689`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 3689)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Governance::PendingEnactments` (r:1 w:1)
+	/// Proof: `Governance::PendingEnactments` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Governance::TimeLockedProposals` (r:100 w:100)
+	/// Proof: `Governance::TimeLockedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Governance::ExecutionPipeline` (r:1 w:1)
+	/// Proof: `Governance::ExecutionPipeline` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[0, 100]`.
+	fn release_time_locked_proposals(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `57 + b * (78 ±0)`
+		//  Estimated: `1542 + b * (2554 ±0)`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 1542)
+			// Standard Error: 151_113
+			.saturating_add(Weight::from_parts(9_874_211, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2554).saturating_mul(b.into()))
+	}
 }
 
 // For backwards compatibility and tests
@@ -445,4 +496,52 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Governance::EnactmentDelays` (r:0 w:1)
+	/// Proof: `Governance::EnactmentDelays` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_enactment_delay() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Governance::Members` (r:1 w:0)
+	/// Proof: `Governance::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Governance::TimeLockedProposals` (r:1 w:1)
+	/// Proof: `Governance::TimeLockedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Governance::PendingEnactments` (r:1 w:1)
+	/// Proof: `Governance::PendingEnactments` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn cancel_time_locked_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224`
+		//  Estimated: `3689`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 3689)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Governance::PendingEnactments` (r:1 w:1)
+	/// Proof: `Governance::PendingEnactments` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Governance::TimeLockedProposals` (r:100 w:100)
+	/// Proof: `Governance::TimeLockedProposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Governance::ExecutionPipeline` (r:1 w:1)
+	/// Proof: `Governance::ExecutionPipeline` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[0, 100]`.
+	fn release_time_locked_proposals(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `57 + b * (78 ±0)`
+		//  Estimated: `1542 + b * (2554 ±0)`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(6_000_000, 1542)
+			// Standard Error: 151_113
+			.saturating_add(Weight::from_parts(9_874_211, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(b.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 2554).saturating_mul(b.into()))
+	}
 }
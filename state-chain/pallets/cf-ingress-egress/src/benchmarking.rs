@@ -145,6 +145,15 @@ mod benchmarks {
 		assert_eq!(FailedForeignChainCalls::<T, I>::get(epoch).len(), 1);
 	}
 
+	#[benchmark]
+	fn ccm_delivered() {
+		let origin = T::EnsureWitnessed::try_successful_origin().unwrap();
+		#[block]
+		{
+			assert_ok!(Pallet::<T, I>::ccm_delivered(origin, (ForeignChain::Ethereum, 1)));
+		}
+	}
+
 	#[benchmark]
 	fn ccm_broadcast_failed() {
 		#[block]
@@ -463,6 +472,9 @@ mod benchmarks {
 		new_test_ext().execute_with(|| {
 			_ccm_broadcast_failed::<Test, ()>(true);
 		});
+		new_test_ext().execute_with(|| {
+			_ccm_delivered::<Test, ()>(true);
+		});
 		new_test_ext().execute_with(|| {
 			_vault_transfer_failed::<Test, ()>(true);
 		});
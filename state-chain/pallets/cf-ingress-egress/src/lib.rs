@@ -26,14 +26,16 @@ use cf_chains::{
 	address::{
 		AddressConverter, AddressDerivationApi, AddressDerivationError, IntoForeignChainAddress,
 	},
+	btc::vault_swap_encoding::VaultSwapParameters,
 	AllBatch, AllBatchError, CcmCfParameters, CcmChannelMetadata, CcmDepositMetadata, CcmMessage,
-	Chain, ChannelLifecycleHooks, ChannelRefundParameters, ConsolidateCall, DepositChannel,
-	ExecutexSwapAndCall, FetchAssetParams, ForeignChainAddress, SwapOrigin, TransferAssetParams,
+	Chain, ChannelLifecycleHooks, ChannelRefundParameters, ConsolidateCall, DcaParameters,
+	DepositChannel, ExecutexSwapAndCall, FetchAssetParams, ForeignChainAddress, SwapOrigin,
+	SwapRefundParameters, TransferAssetParams,
 };
 use cf_primitives::{
-	Asset, BasisPoints, Beneficiaries, BoostPoolTier, BroadcastId, ChannelId, EgressCounter,
-	EgressId, EpochIndex, ForeignChain, PrewitnessedDepositId, SwapId, ThresholdSignatureRequestId,
-	SECONDS_PER_BLOCK,
+	Asset, BasisPoints, Beneficiaries, Beneficiary, BoostPoolTier, BroadcastId, ChannelId,
+	EgressCounter, EgressId, EpochIndex, ForeignChain, PrewitnessedDepositId, SwapId,
+	ThresholdSignatureRequestId, TransactionHash, SECONDS_PER_BLOCK,
 };
 use cf_runtime_utilities::log_or_panic;
 use cf_traits::{
@@ -45,12 +47,13 @@ use cf_traits::{
 };
 use frame_support::{
 	pallet_prelude::*,
-	sp_runtime::{traits::Zero, DispatchError, Permill, Saturating},
+	sp_runtime::{traits::Zero, DispatchError, Permill, Saturating, TransactionOutcome},
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
 use sp_runtime::traits::UniqueSaturatedInto;
 use sp_std::{
+	boxed::Box,
 	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
 	marker::PhantomData,
 	vec,
@@ -141,7 +144,7 @@ impl<C: Chain> CrossChainMessage<C> {
 	}
 }
 
-pub const PALLET_VERSION: StorageVersion = StorageVersion::new(10);
+pub const PALLET_VERSION: StorageVersion = StorageVersion::new(11);
 
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
 #[scale_info(skip_type_params(I))]
@@ -235,6 +238,23 @@ pub mod pallet {
 		pub deposit_details: C::DepositDetails,
 	}
 
+	/// A deposit made directly into the vault, together with the swap parameters that were
+	/// attached to it. The parameters are `None` if the deposit carried a malformed payload.
+	#[derive(
+		CloneNoBound, RuntimeDebugNoBound, PartialEqNoBound, EqNoBound, Encode, Decode, TypeInfo,
+	)]
+	#[scale_info(skip_type_params(T, I))]
+	pub struct VaultDepositWitness<T: Config<I>, I: 'static> {
+		pub deposit_address: TargetChainAccount<T, I>,
+		pub asset: TargetChainAsset<T, I>,
+		pub amount: TargetChainAmount<T, I>,
+		pub deposit_details: <T::TargetChain as Chain>::DepositDetails,
+		/// The address that sent the deposit, which is refunded if the swap can't be scheduled.
+		pub refund_address: TargetChainAccount<T, I>,
+		pub tx_hash: TransactionHash,
+		pub swap_parameters: Option<VaultSwapParameters<T::AccountId>>,
+	}
+
 	#[derive(CloneNoBound, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
 	#[scale_info(skip_type_params(T, I))]
 	pub struct DepositChannelDetails<T: Config<I>, I: 'static> {
@@ -272,6 +292,7 @@ pub mod pallet {
 			destination_address: ForeignChainAddress,
 			broker_fees: Beneficiaries<AccountId>,
 			refund_params: Option<ChannelRefundParameters>,
+			dca_params: Option<DcaParameters>,
 		},
 		LiquidityProvision {
 			lp_account: AccountId,
@@ -293,6 +314,7 @@ pub mod pallet {
 		CcmTransfer { principal_swap_id: Option<SwapId>, gas_swap_id: Option<SwapId> },
 		NoAction,
 		BoostersCredited { prewitnessed_deposit_id: PrewitnessedDepositId },
+		Refund { egress_id: EgressId, reason: DispatchError },
 	}
 
 	/// Tracks funds that are owned by the vault and available for egress.
@@ -616,6 +638,11 @@ pub mod pallet {
 		CcmBroadcastFailed {
 			broadcast_id: BroadcastId,
 		},
+		/// The broadcast of a CCM has succeeded, so the message has been delivered. On chains
+		/// without smart contracts, the message is attached to the transfer instead.
+		CcmDelivered {
+			egress_id: EgressId,
+		},
 		/// A failed CCM call has been re-threshold-signed for the current epoch.
 		FailedForeignChainCallResigned {
 			broadcast_id: BroadcastId,
@@ -677,6 +704,23 @@ pub mod pallet {
 		BoostPoolCreated {
 			boost_pool: BoostPoolId<T::TargetChain>,
 		},
+		VaultDepositFinalised {
+			deposit_address: TargetChainAccount<T, I>,
+			asset: TargetChainAsset<T, I>,
+			amount: TargetChainAmount<T, I>,
+			block_height: TargetChainBlockNumber<T, I>,
+			deposit_details: <T::TargetChain as Chain>::DepositDetails,
+			ingress_fee: TargetChainAmount<T, I>,
+			action: DepositAction<T::AccountId>,
+			tx_hash: TransactionHash,
+		},
+		/// The refund of a vault deposit could not be scheduled. The funds remain in the vault.
+		VaultDepositRefundIgnored {
+			tx_hash: TransactionHash,
+			asset: TargetChainAsset<T, I>,
+			amount: TargetChainAmount<T, I>,
+			reason: DispatchError,
+		},
 	}
 
 	#[derive(CloneNoBound, PartialEqNoBound, EqNoBound)]
@@ -712,6 +756,10 @@ pub mod pallet {
 		DepositChannelCreationDisabled,
 		/// The specified boost pool does not exist.
 		BoostPoolDoesNotExist,
+		/// The swap parameters attached to a vault deposit could not be decoded.
+		InvalidVaultSwapParameters,
+		/// Polkadot Asset Hub's Vault Account does not exist in storage.
+		MissingAssethubVault,
 	}
 
 	#[pallet::hooks]
@@ -1128,6 +1176,43 @@ pub mod pallet {
 			})?;
 			Ok(())
 		}
+
+		/// Called when funds have been deposited directly into the vault with swap parameters
+		/// attached. The deposit is swapped, or refunded to the sender if the parameters are
+		/// invalid.
+		///
+		/// Requires `EnsureWitnessed` origin.
+		///
+		/// ## Events
+		///
+		/// - [on_success](Event::VaultDepositFinalised)
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::process_single_deposit())]
+		pub fn vault_swap_request(
+			origin: OriginFor<T>,
+			block_height: TargetChainBlockNumber<T, I>,
+			deposit: Box<VaultDepositWitness<T, I>>,
+		) -> DispatchResult {
+			T::EnsureWitnessed::ensure_origin(origin)?;
+
+			Self::process_vault_deposit(*deposit, block_height)
+		}
+
+		/// Callback for when the broadcast of a CCM has succeeded.
+		/// Requires `EnsureWitnessed` origin, which is the origin success callbacks are dispatched
+		/// with.
+		///
+		/// ## Events
+		///
+		/// - [on_success](Event::CcmDelivered)
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::ccm_delivered())]
+		pub fn ccm_delivered(origin: OriginFor<T>, egress_id: EgressId) -> DispatchResult {
+			T::EnsureWitnessed::ensure_origin(origin)?;
+
+			Self::deposit_event(Event::<T, I>::CcmDelivered { egress_id });
+			Ok(())
+		}
 	}
 }
 
@@ -1212,6 +1297,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					fetch_params.push(FetchAssetParams {
 						deposit_fetch_id: deposit_fetch_id.expect("Checked in extract_if"),
 						asset,
+						amount,
 					});
 					addresses.push(deposit_address.clone());
 					DepositBalances::<T, I>::mutate(asset, |tracker| {
@@ -1281,7 +1367,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				Ok(api_call) => {
 					let broadcast_id = T::Broadcaster::threshold_sign_and_broadcast_with_callback(
 						api_call,
-						None,
+						Some(Call::ccm_delivered { egress_id: ccm.egress_id }.into()),
 						|broadcast_id| Some(Call::ccm_broadcast_failed { broadcast_id }.into()),
 					);
 					Self::deposit_event(Event::<T, I>::CcmBroadcastRequested {
@@ -1496,6 +1582,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				destination_asset,
 				broker_fees,
 				refund_params,
+				dca_params,
 			} => DepositAction::Swap {
 				swap_id: T::SwapDepositHandler::schedule_swap_from_channel(
 					<<T::TargetChain as Chain>::ChainAccount as IntoForeignChainAddress<
@@ -1508,6 +1595,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					destination_address,
 					broker_fees,
 					refund_params,
+					dca_params,
 					channel_id,
 				),
 			},
@@ -1709,6 +1797,118 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Completes a deposit made directly into the vault by scheduling its swap, or refunding it if
+	/// the swap can't be scheduled.
+	#[transactional]
+	fn process_vault_deposit(
+		VaultDepositWitness {
+			deposit_address,
+			asset,
+			amount: deposit_amount,
+			deposit_details,
+			refund_address,
+			tx_hash,
+			swap_parameters,
+		}: VaultDepositWitness<T, I>,
+		block_height: TargetChainBlockNumber<T, I>,
+	) -> DispatchResult {
+		if deposit_amount < MinimumDeposit::<T, I>::get(asset) {
+			Self::deposit_event(Event::<T, I>::DepositIgnored {
+				deposit_address,
+				asset,
+				amount: deposit_amount,
+				deposit_details,
+				reason: DepositIgnoredReason::BelowMinimumDeposit,
+			});
+			return Ok(())
+		}
+
+		// The funds are already in the vault, so no fetch is required.
+		T::DepositHandler::on_vault_deposit_made(
+			deposit_details.clone(),
+			deposit_amount,
+			&deposit_address,
+		);
+
+		let AmountAndFeesWithheld { amount_after_fees, fees_withheld } =
+			Self::withhold_ingress_or_egress_fee(IngressOrEgress::Ingress, asset, deposit_amount);
+
+		DepositBalances::<T, I>::mutate(asset, |deposits| {
+			deposits.register_deposit(amount_after_fees)
+		});
+
+		if amount_after_fees.is_zero() {
+			Self::deposit_event(Event::<T, I>::DepositIgnored {
+				deposit_address,
+				asset,
+				amount: deposit_amount,
+				deposit_details,
+				reason: DepositIgnoredReason::NotEnoughToPayFees,
+			});
+			return Ok(())
+		}
+
+		let swap_result = swap_parameters
+			.ok_or_else(|| Error::<T, I>::InvalidVaultSwapParameters.into())
+			.and_then(|parameters| {
+				T::SwapDepositHandler::schedule_vault_swap(
+					asset.into(),
+					parameters.output_asset,
+					amount_after_fees.into(),
+					parameters.output_address,
+					Beneficiary {
+						account: parameters.broker_id,
+						bps: parameters.broker_commission,
+					},
+					SwapRefundParameters {
+						// Vault swaps are not retried: they are refunded if the minimum output
+						// can't be met when they are first executed.
+						refund_block: frame_system::Pallet::<T>::block_number()
+							.unique_saturated_into(),
+						refund_address:
+							<T::TargetChain as Chain>::ChainAccount::into_foreign_chain_address(
+								refund_address.clone(),
+							),
+						min_output: parameters.min_output_amount,
+					},
+					tx_hash,
+				)
+			});
+
+		let action = match swap_result {
+			Ok(swap_id) => DepositAction::Swap { swap_id },
+			// The deposit is recorded even if the refund fails, so that the funds in the vault
+			// remain accounted for.
+			Err(reason) =>
+				match Self::schedule_egress(asset, amount_after_fees, refund_address, None) {
+					Ok(ScheduledEgressDetails { egress_id, .. }) =>
+						DepositAction::Refund { egress_id, reason },
+					Err(err) => {
+						Self::deposit_event(Event::<T, I>::VaultDepositRefundIgnored {
+							tx_hash,
+							asset,
+							amount: amount_after_fees,
+							reason: err.into(),
+						});
+						DepositAction::NoAction
+					},
+				},
+		};
+
+		Self::deposit_event(Event::<T, I>::VaultDepositFinalised {
+			deposit_address,
+			asset,
+			amount: deposit_amount,
+			block_height,
+			deposit_details,
+			ingress_fee: fees_withheld,
+			action,
+			tx_hash,
+		});
+
+		Ok(())
+	}
+
 	fn expiry_and_recycle_block_height(
 	) -> (TargetChainBlockNumber<T, I>, TargetChainBlockNumber<T, I>, TargetChainBlockNumber<T, I>)
 	{
@@ -1770,8 +1970,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							Error::<T, I>::MissingBitcoinVault,
 						AddressDerivationError::BitcoinChannelIdTooLarge =>
 							Error::<T, I>::BitcoinChannelIdTooLarge,
-						AddressDerivationError::SolanaDerivationError { .. } =>
-							Error::<T, I>::SolanaAddressDerivationError,
+						AddressDerivationError::SolanaDerivationError { .. } |
+						AddressDerivationError::MissingSolanaTokenMint => Error::<T, I>::SolanaAddressDerivationError,
+						AddressDerivationError::MissingAssethubVault =>
+							Error::<T, I>::MissingAssethubVault,
 					})?,
 				next_channel_id,
 			)
@@ -1854,6 +2056,27 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			fees_withheld,
 		}
 	}
+
+	/// The fee a deposit would currently be charged for being boosted by pools with fees of up to
+	/// `max_boost_fee_bps`, or `None` if there aren't enough funds available to boost it.
+	pub fn boost_fee_estimate(
+		asset: TargetChainAsset<T, I>,
+		amount: TargetChainAmount<T, I>,
+		max_boost_fee_bps: BasisPoints,
+	) -> Option<TargetChainAmount<T, I>> {
+		frame_support::storage::with_transaction_unchecked(|| {
+			TransactionOutcome::Rollback(
+				Self::try_boosting(
+					asset,
+					amount,
+					max_boost_fee_bps,
+					PrewitnessedDepositIdCounter::<T, I>::get().saturating_add(1),
+				)
+				.ok()
+				.map(|BoostOutput { total_fee, .. }| total_fee),
+			)
+		})
+	}
 }
 
 impl<T: Config<I>, I: 'static> EgressApi<T::TargetChain> for Pallet<T, I> {
@@ -1978,6 +2201,7 @@ impl<T: Config<I>, I: 'static> DepositApi<T::TargetChain> for Pallet<T, I> {
 		channel_metadata: Option<CcmChannelMetadata>,
 		boost_fee: BasisPoints,
 		refund_params: Option<ChannelRefundParameters>,
+		dca_params: Option<DcaParameters>,
 	) -> Result<
 		(ChannelId, ForeignChainAddress, <T::TargetChain as Chain>::ChainBlockNumber, Self::Amount),
 		DispatchError,
@@ -2004,6 +2228,7 @@ impl<T: Config<I>, I: 'static> DepositApi<T::TargetChain> for Pallet<T, I> {
 					destination_address,
 					broker_fees,
 					refund_params,
+					dca_params,
 				},
 			},
 			boost_fee,
@@ -0,0 +1,180 @@
+use crate::*;
+use frame_support::traits::OnRuntimeUpgrade;
+
+pub(super) mod old {
+
+	use super::*;
+
+	#[derive(Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	pub enum ChannelAction<AccountId> {
+		Swap {
+			destination_asset: Asset,
+			destination_address: ForeignChainAddress,
+			broker_fees: Beneficiaries<AccountId>,
+			refund_params: Option<ChannelRefundParameters>,
+		},
+		LiquidityProvision {
+			lp_account: AccountId,
+		},
+		CcmTransfer {
+			destination_asset: Asset,
+			destination_address: ForeignChainAddress,
+			channel_metadata: CcmChannelMetadata,
+			refund_params: Option<ChannelRefundParameters>,
+		},
+	}
+
+	#[derive(CloneNoBound, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+	#[scale_info(skip_type_params(T, I))]
+	pub struct DepositChannelDetails<T: Config<I>, I: 'static> {
+		pub deposit_channel: DepositChannel<T::TargetChain>,
+		/// The block number at which the deposit channel was opened, expressed as a block number
+		/// on the external Chain.
+		pub opened_at: TargetChainBlockNumber<T, I>,
+		/// The last block on the target chain that the witnessing will witness it in. If funds are
+		/// sent after this block, they will not be witnessed.
+		pub expires_at: TargetChainBlockNumber<T, I>,
+
+		/// The action to be taken when the DepositChannel is deposited to.
+		pub action: ChannelAction<T::AccountId>,
+		/// The boost fee
+		pub boost_fee: BasisPoints,
+		/// Boost status, indicating whether there is pending boost on the channel
+		pub boost_status: BoostStatus<TargetChainAmount<T, I>>,
+	}
+
+	#[frame_support::storage_alias]
+	pub type DepositChannelLookup<T: Config<I>, I: 'static> = StorageMap<
+		Pallet<T, I>,
+		Twox64Concat,
+		TargetChainAccount<T, I>,
+		DepositChannelDetails<T, I>,
+		OptionQuery,
+	>;
+}
+
+pub struct Migration<T: Config<I>, I: 'static>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for Migration<T, I> {
+	fn on_runtime_upgrade() -> Weight {
+		DepositChannelLookup::<T, I>::translate(|_, details: old::DepositChannelDetails<T, I>| {
+			Some(DepositChannelDetails {
+				deposit_channel: details.deposit_channel,
+				opened_at: details.opened_at,
+				expires_at: details.expires_at,
+				action: match details.action {
+					old::ChannelAction::Swap {
+						destination_asset,
+						destination_address,
+						broker_fees,
+						refund_params,
+					} => ChannelAction::Swap {
+						destination_asset,
+						destination_address,
+						broker_fees,
+						refund_params,
+						dca_params: None,
+					},
+					old::ChannelAction::LiquidityProvision { lp_account } =>
+						ChannelAction::LiquidityProvision { lp_account },
+					old::ChannelAction::CcmTransfer {
+						destination_asset,
+						destination_address,
+						channel_metadata,
+						refund_params,
+					} => ChannelAction::CcmTransfer {
+						destination_asset,
+						destination_address,
+						channel_metadata,
+						refund_params,
+					},
+				},
+				boost_fee: details.boost_fee,
+				boost_status: details.boost_status,
+			})
+		});
+
+		Weight::zero()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		Ok(vec![])
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), DispatchError> {
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+
+	use crate::mock_btc::{new_test_ext, Test};
+	use cf_chains::{
+		btc::{deposit_address::DepositAddress, ScriptPubkey},
+		Bitcoin,
+	};
+
+	fn mock_deposit_channel() -> DepositChannel<Bitcoin> {
+		DepositChannel {
+			channel_id: 123,
+			address: ScriptPubkey::Taproot([0u8; 32]),
+			asset: <Bitcoin as Chain>::ChainAsset::Btc,
+			state: DepositAddress::new([1u8; 32], 123),
+		}
+	}
+
+	#[test]
+	fn test_migration() {
+		new_test_ext().execute_with(|| {
+			let input_address = ScriptPubkey::Taproot([0u8; 32]);
+			let output_address = ForeignChainAddress::Eth([0u8; 20].into());
+			let refund_params = ChannelRefundParameters {
+				retry_duration: 10,
+				refund_address: ForeignChainAddress::Btc(ScriptPubkey::Taproot([2u8; 32])),
+				min_price: Default::default(),
+			};
+
+			old::DepositChannelLookup::<Test, ()>::insert(
+				input_address.clone(),
+				old::DepositChannelDetails::<Test, _> {
+					deposit_channel: mock_deposit_channel(),
+					opened_at: Default::default(),
+					expires_at: Default::default(),
+					boost_status: BoostStatus::NotBoosted,
+					action: old::ChannelAction::Swap {
+						destination_asset: Asset::Flip,
+						destination_address: output_address.clone(),
+						broker_fees: Default::default(),
+						refund_params: Some(refund_params.clone()),
+					},
+					boost_fee: 0,
+				},
+			);
+
+			Migration::<Test, ()>::on_runtime_upgrade();
+
+			assert_eq!(
+				DepositChannelLookup::<Test, ()>::get(input_address),
+				Some(DepositChannelDetails::<Test, _> {
+					deposit_channel: mock_deposit_channel(),
+					opened_at: Default::default(),
+					expires_at: Default::default(),
+					boost_status: BoostStatus::NotBoosted,
+					action: ChannelAction::Swap {
+						destination_asset: Asset::Flip,
+						destination_address: output_address,
+						broker_fees: Default::default(),
+						refund_params: Some(refund_params),
+						dca_params: None,
+					},
+					boost_fee: 0,
+				})
+			);
+		});
+	}
+}
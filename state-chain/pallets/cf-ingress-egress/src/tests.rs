@@ -2,21 +2,23 @@ mod boost;
 
 use crate::{
 	mock_eth::*, BoostStatus, Call as PalletCall, ChannelAction, ChannelIdCounter,
-	ChannelOpeningFee, CrossChainMessage, DepositAction, DepositChannelLookup, DepositChannelPool,
-	DepositIgnoredReason, DepositWitness, DisabledEgressAssets, EgressDustLimit,
-	Event as PalletEvent, FailedForeignChainCall, FailedForeignChainCalls, FetchOrTransfer,
-	MinimumDeposit, Pallet, PalletConfigUpdate, PalletSafeMode, PrewitnessedDepositIdCounter,
-	ScheduledEgressCcm, ScheduledEgressFetchOrTransfer, TargetChainAccount,
-	WithheldTransactionFees, MAX_SWAP_RETRY_DURATION_BLOCKS,
+	ChannelOpeningFee, CrossChainMessage, DepositAction, DepositBalances, DepositChannelLookup,
+	DepositChannelPool, DepositIgnoredReason, DepositWitness, DisabledEgressAssets,
+	EgressDustLimit, Event as PalletEvent, FailedForeignChainCall, FailedForeignChainCalls,
+	FetchOrTransfer, MinimumDeposit, Pallet, PalletConfigUpdate, PalletSafeMode,
+	PrewitnessedDepositIdCounter, ScheduledEgressCcm, ScheduledEgressFetchOrTransfer,
+	TargetChainAccount, VaultDepositWitness, WithheldTransactionFees,
+	MAX_SWAP_RETRY_DURATION_BLOCKS,
 };
 use cf_chains::{
-	address::{AddressConverter, IntoForeignChainAddress},
+	address::{AddressConverter, EncodedAddress, IntoForeignChainAddress},
+	btc::vault_swap_encoding::VaultSwapParameters,
 	evm::{DepositDetails, EvmFetchId},
 	mocks::MockEthereum,
 	CcmChannelMetadata, ChannelRefundParameters, DepositChannel, ExecutexSwapAndCall, SwapOrigin,
 	TransferAssetParams,
 };
-use cf_primitives::{chains::assets::eth, ChannelId, ForeignChain};
+use cf_primitives::{chains::assets::eth, Asset, ChannelId, ForeignChain};
 use cf_test_utilities::assert_has_event;
 use cf_traits::{
 	mocks::{
@@ -481,6 +483,7 @@ fn test_refund_parameter_validation() {
 					refund_address: ForeignChainAddress::Eth(Default::default()),
 					min_price: Default::default(),
 				}),
+				None,
 			),
 			DispatchError::Other("Retry duration too long")
 		);
@@ -516,6 +519,7 @@ fn can_process_ccm_deposit() {
 			Some(channel_metadata),
 			0,
 			None,
+			None,
 		)
 		.unwrap();
 
@@ -614,6 +618,12 @@ fn can_egress_ccm() {
 
 		// Storage should be cleared
 		assert_eq!(ScheduledEgressCcm::<Test, ()>::decode_len(), Some(0));
+
+		// Delivery is reported once the broadcast succeeds
+		MockEgressBroadcaster::dispatch_all_success_callbacks();
+		System::assert_last_event(RuntimeEvent::IngressEgress(
+			crate::Event::<Test, ()>::CcmDelivered { egress_id },
+		));
 	});
 }
 
@@ -1731,3 +1741,120 @@ fn safe_mode_prevents_deposit_channel_creation() {
 		);
 	});
 }
+
+#[test]
+fn vault_swaps_are_scheduled_or_refunded() {
+	new_test_ext().execute_with(|| {
+		const VAULT_ADDRESS: EthereumAddress = H160([0xcf; 20]);
+		let vault_deposit = |swap_parameters| VaultDepositWitness::<Test, ()> {
+			deposit_address: VAULT_ADDRESS,
+			asset: ETH_ETH,
+			amount: DEFAULT_DEPOSIT_AMOUNT,
+			deposit_details: Default::default(),
+			refund_address: ALICE_ETH_ADDRESS,
+			tx_hash: [0xab; 32],
+			swap_parameters,
+		};
+
+		assert_ok!(IngressEgress::vault_swap_request(
+			RuntimeOrigin::root(),
+			Default::default(),
+			Box::new(vault_deposit(Some(VaultSwapParameters {
+				output_asset: Asset::Flip,
+				output_address: EncodedAddress::Eth([0xbb; 20]),
+				min_output_amount: 0,
+				broker_id: BROKER,
+				broker_commission: 10,
+			}))),
+		));
+		System::assert_last_event(RuntimeEvent::IngressEgress(
+			crate::Event::<Test, ()>::VaultDepositFinalised {
+				deposit_address: VAULT_ADDRESS,
+				asset: ETH_ETH,
+				amount: DEFAULT_DEPOSIT_AMOUNT,
+				block_height: Default::default(),
+				deposit_details: Default::default(),
+				ingress_fee: 0,
+				action: DepositAction::Swap { swap_id: 1 },
+				tx_hash: [0xab; 32],
+			},
+		));
+		// The funds are already in the vault, so nothing is fetched.
+		assert!(ScheduledEgressFetchOrTransfer::<Test, ()>::get().is_empty());
+
+		// A deposit with a malformed payload is refunded to the sender.
+		assert_ok!(IngressEgress::vault_swap_request(
+			RuntimeOrigin::root(),
+			Default::default(),
+			Box::new(vault_deposit(None)),
+		));
+		System::assert_last_event(RuntimeEvent::IngressEgress(
+			crate::Event::<Test, ()>::VaultDepositFinalised {
+				deposit_address: VAULT_ADDRESS,
+				asset: ETH_ETH,
+				amount: DEFAULT_DEPOSIT_AMOUNT,
+				block_height: Default::default(),
+				deposit_details: Default::default(),
+				ingress_fee: 0,
+				action: DepositAction::Refund {
+					egress_id: (ForeignChain::Ethereum, 1),
+					reason: crate::Error::<Test, ()>::InvalidVaultSwapParameters.into(),
+				},
+				tx_hash: [0xab; 32],
+			},
+		));
+		assert_eq!(
+			ScheduledEgressFetchOrTransfer::<Test, ()>::get(),
+			vec![FetchOrTransfer::<Ethereum>::Transfer {
+				asset: ETH_ETH,
+				amount: DEFAULT_DEPOSIT_AMOUNT,
+				destination_address: ALICE_ETH_ADDRESS,
+				egress_id: (ForeignChain::Ethereum, 1),
+			}]
+		);
+	});
+}
+
+#[test]
+fn vault_deposits_are_recorded_if_their_refund_fails() {
+	new_test_ext().execute_with(|| {
+		const VAULT_ADDRESS: EthereumAddress = H160([0xcf; 20]);
+		EgressDustLimit::<Test, ()>::set(ETH_ETH, DEFAULT_DEPOSIT_AMOUNT + 1);
+
+		assert_ok!(IngressEgress::vault_swap_request(
+			RuntimeOrigin::root(),
+			Default::default(),
+			Box::new(VaultDepositWitness::<Test, ()> {
+				deposit_address: VAULT_ADDRESS,
+				asset: ETH_ETH,
+				amount: DEFAULT_DEPOSIT_AMOUNT,
+				deposit_details: Default::default(),
+				refund_address: ALICE_ETH_ADDRESS,
+				tx_hash: [0xab; 32],
+				swap_parameters: None,
+			}),
+		));
+		System::assert_has_event(RuntimeEvent::IngressEgress(
+			crate::Event::<Test, ()>::VaultDepositRefundIgnored {
+				tx_hash: [0xab; 32],
+				asset: ETH_ETH,
+				amount: DEFAULT_DEPOSIT_AMOUNT,
+				reason: crate::Error::<Test, ()>::BelowEgressDustLimit.into(),
+			},
+		));
+		System::assert_last_event(RuntimeEvent::IngressEgress(
+			crate::Event::<Test, ()>::VaultDepositFinalised {
+				deposit_address: VAULT_ADDRESS,
+				asset: ETH_ETH,
+				amount: DEFAULT_DEPOSIT_AMOUNT,
+				block_height: Default::default(),
+				deposit_details: Default::default(),
+				ingress_fee: 0,
+				action: DepositAction::NoAction,
+				tx_hash: [0xab; 32],
+			},
+		));
+		assert!(ScheduledEgressFetchOrTransfer::<Test, ()>::get().is_empty());
+		assert_eq!(DepositBalances::<Test, ()>::get(ETH_ETH).total(), DEFAULT_DEPOSIT_AMOUNT);
+	});
+}
@@ -282,5 +282,86 @@ mod benchmarks {
 		}
 	}
 
+	#[benchmark]
+	fn set_twap_observation_capacity() {
+		let call =
+			Call::<T>::set_twap_observation_capacity { capacity: MAX_TWAP_OBSERVATION_CAPACITY };
+
+		#[block]
+		{
+			assert_ok!(
+				call.dispatch_bypass_filter(T::EnsureGovernance::try_successful_origin().unwrap())
+			);
+		}
+
+		assert_eq!(TwapObservationCapacity::<T>::get(), MAX_TWAP_OBSERVATION_CAPACITY);
+	}
+
+	#[benchmark]
+	fn schedule_conditional_limit_order_update() {
+		let caller = new_lp_account::<T>();
+		assert_ok!(Pallet::<T>::new_pool(
+			T::EnsureGovernance::try_successful_origin().unwrap(),
+			Asset::Eth,
+			Asset::Usdc,
+			0,
+			price_at_tick(0).unwrap()
+		));
+		#[extrinsic_call]
+		schedule_conditional_limit_order_update(
+			RawOrigin::Signed(caller.clone()),
+			Box::new(Call::<T>::set_limit_order {
+				base_asset: Asset::Eth,
+				quote_asset: Asset::Usdc,
+				side: Side::Sell,
+				id: 0,
+				option_tick: Some(0),
+				sell_amount: 100,
+			}),
+			TriggerCondition::TickAtOrAbove(100),
+		);
+
+		assert!(!ConditionalLimitOrderUpdates::<T>::get(
+			AssetPair::try_new::<T>(Asset::Eth, Asset::Usdc).unwrap()
+		)
+		.is_empty());
+	}
+
+	#[benchmark]
+	fn cancel_conditional_limit_order_updates() {
+		let caller = new_lp_account::<T>();
+		assert_ok!(Pallet::<T>::new_pool(
+			T::EnsureGovernance::try_successful_origin().unwrap(),
+			Asset::Eth,
+			Asset::Usdc,
+			0,
+			price_at_tick(0).unwrap()
+		));
+		assert_ok!(Pallet::<T>::schedule_conditional_limit_order_update(
+			RawOrigin::Signed(caller.clone()).into(),
+			Box::new(Call::<T>::set_limit_order {
+				base_asset: Asset::Eth,
+				quote_asset: Asset::Usdc,
+				side: Side::Sell,
+				id: 0,
+				option_tick: Some(0),
+				sell_amount: 0,
+			}),
+			TriggerCondition::TickAtOrBelow(-100),
+		));
+
+		#[extrinsic_call]
+		cancel_conditional_limit_order_updates(
+			RawOrigin::Signed(caller.clone()),
+			Asset::Eth,
+			Asset::Usdc,
+			0,
+		);
+
+		assert!(!ConditionalLimitOrderUpdates::<T>::contains_key(
+			AssetPair::try_new::<T>(Asset::Eth, Asset::Usdc).unwrap()
+		));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
@@ -22,10 +22,10 @@ use frame_support::{
 	transactional,
 };
 
-use frame_system::pallet_prelude::OriginFor;
+use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use serde::{Deserialize, Serialize};
 use sp_arithmetic::traits::{UniqueSaturatedInto, Zero};
-use sp_std::{boxed::Box, collections::btree_set::BTreeSet, vec::Vec};
+use sp_std::{boxed::Box, collections::btree_set::BTreeSet, vec, vec::Vec};
 
 pub use pallet::*;
 
@@ -49,12 +49,17 @@ pub struct AssetPair {
 	assets: PoolPairsMap<Asset>,
 }
 impl AssetPair {
+	/// Pairs with the stable asset always use it as the quote asset. Pairs of two other assets use
+	/// the lower of the two assets as the base asset, so that each pair has a single canonical
+	/// representation.
 	pub fn new(base_asset: Asset, quote_asset: Asset) -> Option<Self> {
 		Some(AssetPair {
 			assets: match (base_asset, quote_asset) {
-				(STABLE_ASSET, STABLE_ASSET) => None,
+				(STABLE_ASSET, _) => None,
 				(_unstable_asset, STABLE_ASSET) =>
 					Some(PoolPairsMap { base: base_asset, quote: quote_asset }),
+				(base_asset, quote_asset) if base_asset < quote_asset =>
+					Some(PoolPairsMap { base: base_asset, quote: quote_asset }),
 				_ => None,
 			}?,
 		})
@@ -123,6 +128,57 @@ impl<T> AskBidMap<T> {
 
 pub const PALLET_VERSION: StorageVersion = StorageVersion::new(4);
 
+/// The number of observations kept for each pool unless configured otherwise. At one observation
+/// per block this covers an hour.
+pub const DEFAULT_TWAP_OBSERVATION_CAPACITY: u32 = 600;
+
+/// The maximum number of observations that can be kept for each pool.
+pub const MAX_TWAP_OBSERVATION_CAPACITY: u32 = 14_400;
+
+/// The maximum number of old observations dropped from a pool each time a new one is recorded.
+/// Dropping more than one lets pools shrink gradually after the capacity is reduced.
+pub const MAX_TWAP_OBSERVATIONS_DROPPED_PER_RECORD: u32 = 2;
+
+/// The maximum number of conditional limit order updates an LP can have pending in each pool.
+pub const MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_LP: usize = 32;
+
+/// The maximum number of conditional limit order updates that can be pending in each pool.
+pub const MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_POOL: usize = 256;
+
+/// The maximum number of triggered conditional limit order updates dispatched after each batch
+/// of swaps. Any others remain pending until a later batch.
+pub const MAX_TRIGGERED_LIMIT_ORDER_UPDATES_PER_BATCH: usize = 32;
+
+/// The maximum number of order updates that can be applied in a single batch.
+pub const MAX_BATCHED_ORDER_UPDATES: u32 = 64;
+
+/// The cumulative tick of a pool at some block: the sum, over every block since the pool was
+/// created, of the pool's tick at the start of that block.
+#[derive(Copy, Clone, Debug, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq)]
+pub struct Observation<BlockNumber> {
+	pub block_number: BlockNumber,
+	pub tick_cumulative: i64,
+}
+
+/// The sequence numbers of the oldest observation kept for a pool and of the next observation
+/// to be recorded. Each observation is stored in the slot given by its sequence number modulo
+/// `MAX_TWAP_OBSERVATION_CAPACITY`, so the observations of a pool form a ring buffer.
+#[derive(Copy, Clone, Debug, Default, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq)]
+pub struct ObservationIndices {
+	pub oldest: u64,
+	pub next: u64,
+}
+
+impl ObservationIndices {
+	fn len(&self) -> u64 {
+		self.next.saturating_sub(self.oldest)
+	}
+
+	fn slot(sequence: u64) -> u32 {
+		(sequence % MAX_TWAP_OBSERVATION_CAPACITY as u64) as u32
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use cf_amm::{
@@ -145,6 +201,48 @@ pub mod pallet {
 		pub call: Call<T>,
 	}
 
+	/// A condition on the price of a pool, as the tick of the price of its base asset in its quote
+	/// asset. For an LP holding the base asset, a stop-loss is triggered when the tick is at or
+	/// below a threshold, and a take-profit when it is at or above one.
+	#[derive(
+		Copy,
+		Clone,
+		Debug,
+		Encode,
+		Decode,
+		TypeInfo,
+		MaxEncodedLen,
+		PartialEq,
+		Eq,
+		Deserialize,
+		Serialize,
+	)]
+	#[serde(rename_all = "snake_case")]
+	pub enum TriggerCondition {
+		TickAtOrAbove(Tick),
+		TickAtOrBelow(Tick),
+	}
+
+	impl TriggerCondition {
+		pub fn is_met(&self, tick: Tick) -> bool {
+			match self {
+				TriggerCondition::TickAtOrAbove(trigger_tick) => tick >= *trigger_tick,
+				TriggerCondition::TickAtOrBelow(trigger_tick) => tick <= *trigger_tick,
+			}
+		}
+	}
+
+	/// A limit order update that is dispatched once the price of the pool meets the trigger
+	/// condition.
+	#[derive(Clone, Debug, Encode, Decode, TypeInfo)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ConditionalLimitOrderUpdate<T: Config> {
+		pub lp: T::AccountId,
+		pub id: OrderId,
+		pub trigger: TriggerCondition,
+		pub call: Call<T>,
+	}
+
 	#[derive(Clone, Debug, Encode, Decode, TypeInfo)]
 	#[scale_info(skip_type_params(T))]
 	pub struct Pool<T: Config> {
@@ -297,6 +395,35 @@ pub mod pallet {
 	pub(super) type MaximumPriceImpact<T: Config> =
 		StorageMap<_, Twox64Concat, AssetPair, u32, OptionQuery>;
 
+	/// Limit order updates waiting for the price of their pool to meet their trigger condition.
+	/// These are evaluated after every batch of swaps.
+	#[pallet::storage]
+	pub(super) type ConditionalLimitOrderUpdates<T: Config> =
+		StorageMap<_, Twox64Concat, AssetPair, Vec<ConditionalLimitOrderUpdate<T>>, ValueQuery>;
+
+	/// Observations of the cumulative tick of each pool, keyed by slot. A new observation is
+	/// recorded the first time a pool is changed in a block.
+	#[pallet::storage]
+	pub(super) type PoolObservations<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		AssetPair,
+		Twox64Concat,
+		u32,
+		Observation<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The range of observations currently kept for each pool.
+	#[pallet::storage]
+	pub(super) type PoolObservationIndices<T: Config> =
+		StorageMap<_, Twox64Concat, AssetPair, ObservationIndices, ValueQuery>;
+
+	/// The maximum number of observations kept for each pool. Older observations are dropped.
+	#[pallet::storage]
+	pub(super) type TwapObservationCapacity<T: Config> =
+		StorageValue<_, u32, ValueQuery, ConstU32<DEFAULT_TWAP_OBSERVATION_CAPACITY>>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub flip_buy_interval: BlockNumberFor<T>,
@@ -414,6 +541,18 @@ pub mod pallet {
 		UnsupportedCall,
 		/// The update can't be scheduled because it has expired (dispatch_at is in the past).
 		LimitOrderUpdateExpired,
+		/// The TWAP window must end after it starts, and no later than the current block.
+		InvalidTwapWindow,
+		/// The TWAP window starts before the oldest observation of the pool.
+		InsufficientTwapObservations,
+		/// The number of observations kept must be non-zero and at most
+		/// `MAX_TWAP_OBSERVATION_CAPACITY`.
+		InvalidTwapObservationCapacity,
+		/// The LP, or the pool as a whole, already has the maximum number of conditional limit
+		/// order updates pending.
+		TooManyConditionalLimitOrderUpdates,
+		/// The LP has no conditional limit order updates pending for the order.
+		ConditionalLimitOrderUpdateDoesNotExist,
 	}
 
 	#[pallet::event]
@@ -489,6 +628,36 @@ pub mod pallet {
 			asset_pair: AssetPair,
 			limit: Option<u32>,
 		},
+		/// The number of observations kept for each pool has been set.
+		TwapObservationCapacitySet {
+			capacity: u32,
+		},
+		/// A limit order update was scheduled to be dispatched once the pool price meets the
+		/// trigger condition.
+		ConditionalLimitOrderUpdateScheduled {
+			lp: T::AccountId,
+			base_asset: Asset,
+			quote_asset: Asset,
+			order_id: OrderId,
+			trigger: TriggerCondition,
+		},
+		/// The pool price met the trigger condition of a limit order update, which is dispatched
+		/// next.
+		ConditionalLimitOrderUpdateTriggered {
+			lp: T::AccountId,
+			base_asset: Asset,
+			quote_asset: Asset,
+			order_id: OrderId,
+			trigger: TriggerCondition,
+			tick: Tick,
+		},
+		/// All pending conditional limit order updates of an order were cancelled.
+		ConditionalLimitOrderUpdatesCancelled {
+			lp: T::AccountId,
+			base_asset: Asset,
+			quote_asset: Asset,
+			order_id: OrderId,
+		},
 	}
 
 	#[pallet::call]
@@ -540,37 +709,7 @@ pub mod pallet {
 			initial_price: Price,
 		) -> DispatchResult {
 			T::EnsureGovernance::ensure_origin(origin)?;
-
-			let asset_pair = AssetPair::try_new::<T>(base_asset, quote_asset)?;
-			Pools::<T>::try_mutate(asset_pair, |maybe_pool| {
-				ensure!(maybe_pool.is_none(), Error::<T>::PoolAlreadyExists);
-
-				*maybe_pool = Some(Pool {
-					range_orders_cache: Default::default(),
-					limit_orders_cache: Default::default(),
-					pool_state: PoolState::new(fee_hundredth_pips, initial_price).map_err(|e| {
-						match e {
-							NewError::LimitOrders(limit_orders::NewError::InvalidFeeAmount) =>
-								Error::<T>::InvalidFeeAmount,
-							NewError::RangeOrders(range_orders::NewError::InvalidFeeAmount) =>
-								Error::<T>::InvalidFeeAmount,
-							NewError::RangeOrders(range_orders::NewError::InvalidInitialPrice) =>
-								Error::<T>::InvalidInitialPrice,
-						}
-					})?,
-				});
-
-				Ok::<_, Error<T>>(())
-			})?;
-
-			Self::deposit_event(Event::<T>::NewPoolCreated {
-				base_asset,
-				quote_asset,
-				fee_hundredth_pips,
-				initial_price,
-			});
-
-			Ok(())
+			Self::create_pool(base_asset, quote_asset, fee_hundredth_pips, initial_price)
 		}
 
 		/// Optionally move the order to a different range and then increase or decrease its amount
@@ -1002,6 +1141,198 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Sets the maximum number of observations kept for each pool, which limits how far back
+		/// time-weighted average prices can be queried. Requires Governance.
+		///
+		/// Pools that hold more observations drop the oldest ones gradually, up to
+		/// `MAX_TWAP_OBSERVATIONS_DROPPED_PER_RECORD` each time a new observation is recorded.
+		///
+		/// ## Events
+		///
+		/// - [On success](Event::TwapObservationCapacitySet)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::BadOrigin)
+		/// - [InvalidTwapObservationCapacity](pallet_cf_pools::Error::InvalidTwapObservationCapacity)
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_twap_observation_capacity())]
+		pub fn set_twap_observation_capacity(
+			origin: OriginFor<T>,
+			capacity: u32,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+			ensure!(
+				(1..=MAX_TWAP_OBSERVATION_CAPACITY).contains(&capacity),
+				Error::<T>::InvalidTwapObservationCapacity
+			);
+
+			TwapObservationCapacity::<T>::set(capacity);
+			Self::deposit_event(Event::<T>::TwapObservationCapacitySet { capacity });
+
+			Ok(())
+		}
+
+		/// Schedules a limit order update to be executed once the pool price meets the trigger
+		/// condition, e.g. to place a stop-loss or take-profit order, or to cancel an order by
+		/// setting its amount to zero.
+		///
+		/// The update is defined by the passed call, which can be either the `set_limit_order` or
+		/// the `update_limit_order` extrinsic. Trigger conditions are evaluated against the pool
+		/// price after every batch of swaps. If the condition is already met, the call is
+		/// dispatched immediately.
+		///
+		/// ## Events
+		///
+		/// - [On success](Event::ConditionalLimitOrderUpdateScheduled)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::BadOrigin)
+		/// - [UnsupportedCall](pallet_cf_pools::Error::UnsupportedCall)
+		/// - [PoolDoesNotExist](pallet_cf_pools::Error::PoolDoesNotExist)
+		/// - [TooManyConditionalLimitOrderUpdates](pallet_cf_pools::Error::TooManyConditionalLimitOrderUpdates)
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::schedule_conditional_limit_order_update()
+			.saturating_add(call.get_dispatch_info().weight))]
+		pub fn schedule_conditional_limit_order_update(
+			origin: OriginFor<T>,
+			call: Box<Call<T>>,
+			trigger: TriggerCondition,
+		) -> DispatchResultWithPostInfo {
+			let lp = T::AccountRoleRegistry::ensure_liquidity_provider(origin)?;
+
+			let (base_asset, quote_asset, id) = match *call {
+				Call::set_limit_order { base_asset, quote_asset, id, .. } |
+				Call::update_limit_order { base_asset, quote_asset, id, .. } => (base_asset, quote_asset, id),
+				_ => Err(Error::<T>::UnsupportedCall)?,
+			};
+			let asset_pair = AssetPair::try_new::<T>(base_asset, quote_asset)?;
+			let mut pool = Pools::<T>::get(asset_pair).ok_or(Error::<T>::PoolDoesNotExist)?;
+
+			if trigger.is_met(Self::reference_tick(&mut pool.pool_state)) {
+				return (*call).dispatch_bypass_filter(OriginTrait::signed(lp))
+			}
+
+			ConditionalLimitOrderUpdates::<T>::try_mutate(asset_pair, |updates| {
+				ensure!(
+					updates.len() < MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_POOL,
+					Error::<T>::TooManyConditionalLimitOrderUpdates
+				);
+				ensure!(
+					updates.iter().filter(|update| update.lp == lp).count() <
+						MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_LP,
+					Error::<T>::TooManyConditionalLimitOrderUpdates
+				);
+				updates.push(ConditionalLimitOrderUpdate {
+					lp: lp.clone(),
+					id,
+					trigger,
+					call: *call,
+				});
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::<T>::ConditionalLimitOrderUpdateScheduled {
+				lp,
+				base_asset,
+				quote_asset,
+				order_id: id,
+				trigger,
+			});
+
+			Ok(().into())
+		}
+
+		/// Cancels all pending conditional limit order updates of the given order.
+		///
+		/// ## Events
+		///
+		/// - [On success](Event::ConditionalLimitOrderUpdatesCancelled)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::BadOrigin)
+		/// - [ConditionalLimitOrderUpdateDoesNotExist](pallet_cf_pools::Error::ConditionalLimitOrderUpdateDoesNotExist)
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::cancel_conditional_limit_order_updates())]
+		pub fn cancel_conditional_limit_order_updates(
+			origin: OriginFor<T>,
+			base_asset: Asset,
+			quote_asset: Asset,
+			id: OrderId,
+		) -> DispatchResult {
+			let lp = T::AccountRoleRegistry::ensure_liquidity_provider(origin)?;
+			let asset_pair = AssetPair::try_new::<T>(base_asset, quote_asset)?;
+
+			ConditionalLimitOrderUpdates::<T>::try_mutate_exists(asset_pair, |maybe_updates| {
+				let updates = maybe_updates
+					.as_mut()
+					.ok_or(Error::<T>::ConditionalLimitOrderUpdateDoesNotExist)?;
+				let pending_count = updates.len();
+				updates.retain(|update| !(update.lp == lp && update.id == id));
+				ensure!(
+					updates.len() < pending_count,
+					Error::<T>::ConditionalLimitOrderUpdateDoesNotExist
+				);
+				if updates.is_empty() {
+					*maybe_updates = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+
+			Self::deposit_event(Event::<T>::ConditionalLimitOrderUpdatesCancelled {
+				lp,
+				base_asset,
+				quote_asset,
+				order_id: id,
+			});
+
+			Ok(())
+		}
+
+		/// Atomically applies a batch of limit and range order updates, which may be in different
+		/// pools. Each update is defined by a call to one of the `set_limit_order`,
+		/// `update_limit_order`, `set_range_order` or `update_range_order` extrinsics, and the
+		/// updates are applied in order. If any of them fails, none of them are applied.
+		///
+		/// ## Events
+		///
+		/// - The events of each of the batched calls.
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::BadOrigin)
+		/// - [UnsupportedCall](pallet_cf_pools::Error::UnsupportedCall)
+		/// - Any error of the batched calls.
+		#[pallet::call_index(13)]
+		#[pallet::weight(calls.iter().fold(Weight::zero(), |total, call| {
+			total.saturating_add(call.get_dispatch_info().weight)
+		}))]
+		pub fn batch_order_updates(
+			origin: OriginFor<T>,
+			calls: BoundedVec<Call<T>, ConstU32<MAX_BATCHED_ORDER_UPDATES>>,
+		) -> DispatchResultWithPostInfo {
+			let lp = T::AccountRoleRegistry::ensure_liquidity_provider(origin)?;
+
+			ensure!(
+				calls.iter().all(|call| matches!(
+					call,
+					Call::set_limit_order { .. } |
+						Call::update_limit_order { .. } |
+						Call::set_range_order { .. } |
+						Call::update_range_order { .. }
+				)),
+				Error::<T>::UnsupportedCall
+			);
+
+			for call in calls {
+				call.dispatch_bypass_filter(OriginTrait::signed(lp.clone()))?;
+			}
+
+			Ok(().into())
+		}
 	}
 }
 
@@ -1017,6 +1348,23 @@ impl<T: Config> SwappingApi for Pallet<T> {
 		NetworkFeeTaken { remaining_amount: remaining, network_fee: fee }
 	}
 
+	fn network_fee() -> Permill {
+		T::NetworkFee::get()
+	}
+
+	fn on_swap_batch_executed() -> Weight {
+		Self::trigger_conditional_limit_order_updates()
+	}
+
+	fn pools() -> Vec<(Asset, Asset)> {
+		Pools::<T>::iter_keys()
+			.map(|asset_pair| {
+				let assets = asset_pair.assets();
+				(assets.base, assets.quote)
+			})
+			.collect()
+	}
+
 	#[transactional]
 	fn swap_single_leg(
 		from: any::Asset,
@@ -1223,6 +1571,13 @@ pub struct PoolPriceV2 {
 	pub range_order: SqrtPriceQ64F96,
 }
 
+/// The time-weighted average price of a pool over a window of blocks.
+#[derive(Serialize, Deserialize, Clone, Encode, Decode, TypeInfo, PartialEq, Eq, Debug)]
+pub struct PoolTwap {
+	pub tick: Tick,
+	pub price: Price,
+}
+
 #[derive(PartialEq, Eq)]
 enum NoOpStatus {
 	Allow,
@@ -1230,6 +1585,55 @@ enum NoOpStatus {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Creates a new pool for the asset pair, with no liquidity.
+	pub fn create_pool(
+		base_asset: any::Asset,
+		quote_asset: any::Asset,
+		fee_hundredth_pips: u32,
+		initial_price: Price,
+	) -> DispatchResult {
+		let asset_pair = AssetPair::try_new::<T>(base_asset, quote_asset)?;
+		Pools::<T>::try_mutate(asset_pair, |maybe_pool| {
+			ensure!(maybe_pool.is_none(), Error::<T>::PoolAlreadyExists);
+
+			*maybe_pool = Some(Pool {
+				range_orders_cache: Default::default(),
+				limit_orders_cache: Default::default(),
+				pool_state: PoolState::new(fee_hundredth_pips, initial_price).map_err(
+					|e| match e {
+						NewError::LimitOrders(limit_orders::NewError::InvalidFeeAmount) =>
+							Error::<T>::InvalidFeeAmount,
+						NewError::RangeOrders(range_orders::NewError::InvalidFeeAmount) =>
+							Error::<T>::InvalidFeeAmount,
+						NewError::RangeOrders(range_orders::NewError::InvalidInitialPrice) =>
+							Error::<T>::InvalidInitialPrice,
+					},
+				)?,
+			});
+
+			Ok::<_, Error<T>>(())
+		})?;
+
+		PoolObservations::<T>::insert(
+			asset_pair,
+			ObservationIndices::slot(0),
+			Observation {
+				block_number: frame_system::Pallet::<T>::block_number(),
+				tick_cumulative: 0,
+			},
+		);
+		PoolObservationIndices::<T>::insert(asset_pair, ObservationIndices { oldest: 0, next: 1 });
+
+		Self::deposit_event(Event::<T>::NewPoolCreated {
+			base_asset,
+			quote_asset,
+			fee_hundredth_pips,
+			initial_price,
+		});
+
+		Ok(())
+	}
+
 	fn inner_sweep(lp: &T::AccountId) -> DispatchResult {
 		// Collect to avoid undefined behaviour (See StorsgeMap::iter_keys documentation)
 		for asset_pair in Pools::<T>::iter_keys().collect::<Vec<_>>() {
@@ -1624,10 +2028,199 @@ impl<T: Config> Pallet<T> {
 	) -> Result<R, E> {
 		Pools::<T>::try_mutate(asset_pair, |maybe_pool| {
 			let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolDoesNotExist)?;
+			Self::record_observation(&asset_pair, pool);
 			f(&asset_pair, pool)
 		})
 	}
 
+	/// Dispatches the conditional limit order updates whose trigger condition is met by the
+	/// current price of their pool, up to `MAX_TRIGGERED_LIMIT_ORDER_UPDATES_PER_BATCH`. Returns
+	/// the weight consumed.
+	fn trigger_conditional_limit_order_updates() -> Weight {
+		let mut weight_used = Weight::zero();
+		let mut remaining_dispatches = MAX_TRIGGERED_LIMIT_ORDER_UPDATES_PER_BATCH;
+
+		for asset_pair in ConditionalLimitOrderUpdates::<T>::iter_keys().collect::<Vec<_>>() {
+			weight_used.saturating_accrue(T::DbWeight::get().reads(2));
+			let Some(mut pool) = Pools::<T>::get(asset_pair) else { continue };
+			let tick = Self::reference_tick(&mut pool.pool_state);
+
+			let mut triggered = Vec::new();
+			let pending = ConditionalLimitOrderUpdates::<T>::get(asset_pair)
+				.into_iter()
+				.filter_map(|update| {
+					if remaining_dispatches > 0 && update.trigger.is_met(tick) {
+						remaining_dispatches -= 1;
+						triggered.push(update);
+						None
+					} else {
+						Some(update)
+					}
+				})
+				.collect::<Vec<_>>();
+			if triggered.is_empty() {
+				continue
+			}
+			weight_used.saturating_accrue(T::DbWeight::get().writes(1));
+			if pending.is_empty() {
+				ConditionalLimitOrderUpdates::<T>::remove(asset_pair);
+			} else {
+				ConditionalLimitOrderUpdates::<T>::insert(asset_pair, pending);
+			}
+
+			let PoolPairsMap { base: base_asset, quote: quote_asset } = asset_pair.assets();
+			for ConditionalLimitOrderUpdate { lp, id, trigger, call } in triggered {
+				Self::deposit_event(Event::<T>::ConditionalLimitOrderUpdateTriggered {
+					lp: lp.clone(),
+					base_asset,
+					quote_asset,
+					order_id: id,
+					trigger,
+					tick,
+				});
+				weight_used.saturating_accrue(call.get_dispatch_info().weight);
+				let origin = OriginTrait::signed(lp.clone());
+				let _result = with_storage_layer(move || call.dispatch_bypass_filter(origin))
+					.map(|_| {
+						Self::deposit_event(Event::<T>::ScheduledLimitOrderUpdateDispatchSuccess {
+							lp: lp.clone(),
+							order_id: id,
+						});
+					})
+					.map_err(|err| {
+						Self::deposit_event(Event::<T>::ScheduledLimitOrderUpdateDispatchFailure {
+							lp: lp.clone(),
+							order_id: id,
+							error: err.error,
+						});
+					});
+			}
+
+			if remaining_dispatches == 0 {
+				break
+			}
+		}
+
+		weight_used
+	}
+
+	/// The tick used as the price of a pool, both for its time-weighted average price and for
+	/// trigger conditions: the mid tick between the best sell and buy prices, or the range order
+	/// price if the pool has no liquidity.
+	fn reference_tick(pool_state: &mut PoolState<(T::AccountId, OrderId)>) -> Tick {
+		match (pool_state.current_price(Side::Sell), pool_state.current_price(Side::Buy)) {
+			(Some((_, _, sell_tick)), Some((_, _, buy_tick))) =>
+				((sell_tick as i64 + buy_tick as i64).div_euclid(2)) as Tick,
+			(Some((_, _, tick)), None) | (None, Some((_, _, tick))) => tick,
+			(None, None) => common::tick_at_sqrt_price(pool_state.current_range_order_pool_price()),
+		}
+	}
+
+	fn blocks_between(from: BlockNumberFor<T>, to: BlockNumberFor<T>) -> i64 {
+		UniqueSaturatedInto::<u64>::unique_saturated_into(to.saturating_sub(from)) as i64
+	}
+
+	/// Records the cumulative tick of the pool before its first change in the current block.
+	fn record_observation(asset_pair: &AssetPair, pool: &mut Pool<T>) {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		let tick = Self::reference_tick(&mut pool.pool_state);
+
+		let mut indices = PoolObservationIndices::<T>::get(asset_pair);
+		let latest =
+			indices.next.checked_sub(1).filter(|latest| *latest >= indices.oldest).and_then(
+				|latest| PoolObservations::<T>::get(asset_pair, ObservationIndices::slot(latest)),
+			);
+		let tick_cumulative = match latest {
+			Some(last) if last.block_number == current_block => return,
+			Some(last) => last.tick_cumulative.saturating_add(
+				(tick as i64)
+					.saturating_mul(Self::blocks_between(last.block_number, current_block)),
+			),
+			None => 0,
+		};
+
+		// Make room for the new observation first, since it may reuse the oldest one's slot.
+		let capacity = TwapObservationCapacity::<T>::get() as u64;
+		for _ in 0..MAX_TWAP_OBSERVATIONS_DROPPED_PER_RECORD {
+			if indices.len() < capacity {
+				break
+			}
+			PoolObservations::<T>::remove(asset_pair, ObservationIndices::slot(indices.oldest));
+			indices.oldest.saturating_inc();
+		}
+
+		PoolObservations::<T>::insert(
+			asset_pair,
+			ObservationIndices::slot(indices.next),
+			Observation { block_number: current_block, tick_cumulative },
+		);
+		indices.next.saturating_inc();
+		PoolObservationIndices::<T>::insert(asset_pair, indices);
+	}
+
+	/// The cumulative tick at `block`, interpolated from the observations of the pool. Between
+	/// two observations the pool's tick is constant, and after the last one it is `current_tick`.
+	fn tick_cumulative_at(
+		asset_pair: &AssetPair,
+		indices: &ObservationIndices,
+		current_tick: Tick,
+		block: BlockNumberFor<T>,
+	) -> Option<i64> {
+		let observation =
+			|sequence| PoolObservations::<T>::get(asset_pair, ObservationIndices::slot(sequence));
+
+		// Binary search for the first observation after `block`.
+		let (mut low, mut high) = (indices.oldest, indices.next);
+		while low < high {
+			let mid = low + (high - low) / 2;
+			if observation(mid)?.block_number <= block {
+				low = mid + 1;
+			} else {
+				high = mid;
+			}
+		}
+
+		let previous = observation(low.checked_sub(1).filter(|i| *i >= indices.oldest)?)?;
+		let tick = match (low < indices.next).then(|| observation(low)).flatten() {
+			Some(next) =>
+				(next.tick_cumulative - previous.tick_cumulative) /
+					Self::blocks_between(previous.block_number, next.block_number),
+			None => current_tick as i64,
+		};
+		Some(previous.tick_cumulative.saturating_add(
+			tick.saturating_mul(Self::blocks_between(previous.block_number, block)),
+		))
+	}
+
+	/// The time-weighted average price of the pool over the blocks from `from_block` (inclusive)
+	/// to `to_block` (exclusive). Each block is weighted by the pool's price at its start, so
+	/// the price can't be moved by swaps within the block the average is read in.
+	pub fn pool_twap(
+		base_asset: Asset,
+		quote_asset: Asset,
+		from_block: BlockNumberFor<T>,
+		to_block: BlockNumberFor<T>,
+	) -> Result<PoolTwap, DispatchError> {
+		let asset_pair = AssetPair::try_new::<T>(base_asset, quote_asset)?;
+		let mut pool = Pools::<T>::get(asset_pair).ok_or(Error::<T>::PoolDoesNotExist)?;
+		ensure!(
+			from_block < to_block && to_block <= frame_system::Pallet::<T>::block_number(),
+			Error::<T>::InvalidTwapWindow
+		);
+
+		let current_tick = Self::reference_tick(&mut pool.pool_state);
+		let indices = PoolObservationIndices::<T>::get(asset_pair);
+		let tick_cumulative_at = |block| {
+			Self::tick_cumulative_at(&asset_pair, &indices, current_tick, block)
+				.ok_or(Error::<T>::InsufficientTwapObservations)
+		};
+
+		let tick = (tick_cumulative_at(to_block)? - tick_cumulative_at(from_block)?)
+			.div_euclid(Self::blocks_between(from_block, to_block)) as Tick;
+
+		Ok(PoolTwap { tick, price: common::price_at_tick(tick).ok_or(Error::<T>::InvalidTick)? })
+	}
+
 	fn try_mutate_order<R, F: FnOnce(&AssetPair, &mut Pool<T>) -> Result<R, DispatchError>>(
 		lp: &T::AccountId,
 		base_asset: any::Asset,
@@ -2118,6 +2711,9 @@ pub mod utilities {
 			Asset::Usdc => Some(USD_ESTIMATION_CAP),
 			Asset::Usdt => Some(USD_ESTIMATION_CAP),
 			Asset::ArbUsdc => Some(USD_ESTIMATION_CAP),
+			Asset::SolUsdc => Some(USD_ESTIMATION_CAP),
+			Asset::HubUsdt => Some(USD_ESTIMATION_CAP),
+			Asset::HubUsdc => Some(USD_ESTIMATION_CAP),
 			_ => None,
 		}
 	}
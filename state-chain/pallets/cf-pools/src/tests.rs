@@ -1,15 +1,25 @@
 use crate::{
 	self as pallet_cf_pools, mock::*, utilities, AskBidMap, AssetAmounts, AssetPair,
-	CollectedNetworkFee, Error, Event, FlipBuyInterval, LimitOrder, PoolInfo, PoolOrders,
-	PoolPairsMap, Pools, RangeOrder, RangeOrderSize, ScheduledLimitOrderUpdates, STABLE_ASSET,
+	CollectedNetworkFee, ConditionalLimitOrderUpdates, Error, Event, FlipBuyInterval, LimitOrder,
+	Observation, ObservationIndices, OrderId, PoolInfo, PoolObservationIndices, PoolObservations,
+	PoolOrders, PoolPairsMap, PoolTwap, Pools, RangeOrder, RangeOrderSize,
+	ScheduledLimitOrderUpdates, TriggerCondition, TwapObservationCapacity,
+	MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_LP, MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_POOL,
+	MAX_TRIGGERED_LIMIT_ORDER_UPDATES_PER_BATCH, MAX_TWAP_OBSERVATION_CAPACITY, STABLE_ASSET,
+};
+use cf_amm::{
+	common::{price_at_tick, tick_at_price, Price, Side, Tick, PRICE_FRACTIONAL_BITS},
+	PoolState,
 };
-use cf_amm::common::{price_at_tick, tick_at_price, Price, Side, Tick, PRICE_FRACTIONAL_BITS};
 use cf_chains::Ethereum;
 use cf_primitives::{chains::assets::any::Asset, AssetAmount, SwapOutput};
 use cf_test_utilities::{assert_events_match, assert_has_event, last_event};
 use cf_traits::{
-	mocks::swap_queue_api::{MockSwap, MockSwapQueueApi},
-	AssetConverter, SwapType, SwappingApi,
+	mocks::{
+		account_role_registry::MockAccountRoleRegistry,
+		swap_queue_api::{MockSwap, MockSwapQueueApi},
+	},
+	AccountRoleRegistry, AssetConverter, SwapType, SwappingApi,
 };
 use frame_support::{assert_noop, assert_ok, traits::Hooks};
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -66,6 +76,39 @@ fn can_create_new_trading_pool() {
 	});
 }
 
+#[test]
+fn can_create_pool_without_stable_asset() {
+	new_test_ext().execute_with(|| {
+		let default_price = price_at_tick(0).unwrap();
+
+		// Pairs of two unstable assets use the lower asset as the base asset:
+		assert!(Asset::Eth < Asset::Btc);
+		assert_noop!(
+			LiquidityPools::new_pool(
+				RuntimeOrigin::root(),
+				Asset::Btc,
+				Asset::Eth,
+				500_000u32,
+				default_price
+			),
+			Error::<Test>::PoolDoesNotExist
+		);
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			Asset::Btc,
+			500_000u32,
+			default_price,
+		));
+
+		assert_eq!(
+			AssetPair::from_swap(Asset::Btc, Asset::Eth),
+			Some((AssetPair::new(Asset::Eth, Asset::Btc).unwrap(), Side::Buy))
+		);
+		assert!(<LiquidityPools as SwappingApi>::pools().contains(&(Asset::Eth, Asset::Btc)));
+	});
+}
+
 #[test]
 fn test_buy_back_flip_no_funds_available() {
 	new_test_ext().execute_with(|| {
@@ -1348,3 +1391,497 @@ fn can_accept_additional_limit_orders() {
 		)
 	});
 }
+
+fn observations(asset_pair: AssetPair) -> Vec<Observation<BlockNumberFor<Test>>> {
+	let indices = PoolObservationIndices::<Test>::get(asset_pair);
+	(indices.oldest..indices.next)
+		.map(|sequence| {
+			PoolObservations::<Test>::get(asset_pair, ObservationIndices::slot(sequence)).unwrap()
+		})
+		.collect()
+}
+
+#[test]
+fn twap_is_interpolated_between_observations() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		let asset_pair = AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap();
+		assert_eq!(
+			observations(asset_pair),
+			vec![Observation { block_number: 1, tick_cumulative: 0 }]
+		);
+
+		// The pool was at tick 100 from block 1 to block 11, and has been at tick 0 since.
+		PoolObservations::<Test>::insert(
+			asset_pair,
+			ObservationIndices::slot(1),
+			Observation { block_number: 11, tick_cumulative: 1_000 },
+		);
+		PoolObservationIndices::<Test>::insert(
+			asset_pair,
+			ObservationIndices { oldest: 0, next: 2 },
+		);
+		System::set_block_number(21);
+
+		let twap = |from_block, to_block| {
+			LiquidityPools::pool_twap(Asset::Eth, STABLE_ASSET, from_block, to_block)
+		};
+		assert_eq!(
+			twap(1, 11).unwrap(),
+			PoolTwap { tick: 100, price: price_at_tick(100).unwrap() }
+		);
+		assert_eq!(twap(11, 21).unwrap().tick, 0);
+		assert_eq!(twap(6, 16).unwrap().tick, 50);
+		assert_eq!(twap(10, 12).unwrap().tick, 50);
+
+		assert_noop!(twap(0, 11), Error::<Test>::InsufficientTwapObservations);
+		assert_noop!(twap(11, 11), Error::<Test>::InvalidTwapWindow);
+		assert_noop!(twap(11, 22), Error::<Test>::InvalidTwapWindow);
+		assert_noop!(
+			LiquidityPools::pool_twap(Asset::Flip, STABLE_ASSET, 1, 11),
+			Error::<Test>::PoolDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn observations_are_recorded_once_per_block_up_to_capacity() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		let asset_pair = AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap();
+		let set_limit_order = |id, tick| {
+			assert_ok!(LiquidityPools::set_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				Asset::Eth,
+				STABLE_ASSET,
+				Side::Buy,
+				id,
+				Some(tick),
+				1_000,
+			));
+		};
+
+		// The pool stays at its initial tick until it is first changed:
+		System::set_block_number(3);
+		set_limit_order(0, 100);
+		set_limit_order(1, 200);
+		assert_eq!(
+			observations(asset_pair),
+			vec![
+				Observation { block_number: 1, tick_cumulative: 0 },
+				Observation { block_number: 3, tick_cumulative: 0 },
+			]
+		);
+
+		assert_noop!(
+			LiquidityPools::set_twap_observation_capacity(RuntimeOrigin::root(), 0),
+			Error::<Test>::InvalidTwapObservationCapacity
+		);
+		assert_noop!(
+			LiquidityPools::set_twap_observation_capacity(
+				RuntimeOrigin::root(),
+				MAX_TWAP_OBSERVATION_CAPACITY + 1
+			),
+			Error::<Test>::InvalidTwapObservationCapacity
+		);
+		assert_ok!(LiquidityPools::set_twap_observation_capacity(RuntimeOrigin::root(), 2));
+		System::assert_last_event(RuntimeEvent::LiquidityPools(
+			Event::<Test>::TwapObservationCapacitySet { capacity: 2 },
+		));
+		assert_eq!(TwapObservationCapacity::<Test>::get(), 2);
+
+		// The oldest observation is dropped once the capacity is exceeded:
+		System::set_block_number(5);
+		let current_tick = LiquidityPools::pool_twap(Asset::Eth, STABLE_ASSET, 3, 5).unwrap().tick;
+		set_limit_order(2, 300);
+		assert_eq!(
+			observations(asset_pair),
+			vec![
+				Observation { block_number: 3, tick_cumulative: 0 },
+				Observation { block_number: 5, tick_cumulative: 2 * current_tick as i64 },
+			]
+		);
+		assert_noop!(
+			LiquidityPools::pool_twap(Asset::Eth, STABLE_ASSET, 1, 5),
+			Error::<Test>::InsufficientTwapObservations
+		);
+	});
+}
+
+#[test]
+fn observations_are_dropped_gradually_after_capacity_is_reduced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		let asset_pair = AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap();
+		let record_observation_at = |block, id| {
+			System::set_block_number(block);
+			assert_ok!(LiquidityPools::set_limit_order(
+				RuntimeOrigin::signed(ALICE),
+				Asset::Eth,
+				STABLE_ASSET,
+				Side::Buy,
+				id,
+				Some(0),
+				1_000,
+			));
+		};
+
+		for (id, block) in (2..=5).enumerate() {
+			record_observation_at(block, id as OrderId);
+		}
+		assert_eq!(PoolObservationIndices::<Test>::get(asset_pair).len(), 5);
+
+		assert_ok!(LiquidityPools::set_twap_observation_capacity(RuntimeOrigin::root(), 1));
+		for (block, expected_len) in [(6, 4), (7, 3), (8, 2), (9, 1), (10, 1)] {
+			record_observation_at(block, 0);
+			assert_eq!(PoolObservationIndices::<Test>::get(asset_pair).len(), expected_len);
+			assert_eq!(observations(asset_pair).last().unwrap().block_number, block);
+		}
+		// Slots of dropped observations are cleared:
+		assert_eq!(PoolObservations::<Test>::iter_prefix(asset_pair).count(), 1);
+	});
+}
+
+fn conditional_sell_order(
+	id: OrderId,
+	sell_amount: AssetAmount,
+) -> Box<pallet_cf_pools::Call<Test>> {
+	Box::new(pallet_cf_pools::Call::<Test>::set_limit_order {
+		base_asset: Asset::Eth,
+		quote_asset: STABLE_ASSET,
+		side: Side::Sell,
+		id,
+		option_tick: Some(0),
+		sell_amount,
+	})
+}
+
+#[test]
+fn conditional_limit_order_update_is_dispatched_once_triggered() {
+	new_test_ext().execute_with(|| {
+		const TRIGGER: TriggerCondition = TriggerCondition::TickAtOrAbove(100);
+
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		let asset_pair = AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap();
+
+		assert_ok!(LiquidityPools::schedule_conditional_limit_order_update(
+			RuntimeOrigin::signed(ALICE),
+			conditional_sell_order(0, 1_000),
+			TRIGGER,
+		));
+		System::assert_last_event(RuntimeEvent::LiquidityPools(
+			Event::<Test>::ConditionalLimitOrderUpdateScheduled {
+				lp: ALICE,
+				base_asset: Asset::Eth,
+				quote_asset: STABLE_ASSET,
+				order_id: 0,
+				trigger: TRIGGER,
+			},
+		));
+
+		// The pool price doesn't meet the trigger condition yet:
+		<LiquidityPools as SwappingApi>::on_swap_batch_executed();
+		assert_eq!(ConditionalLimitOrderUpdates::<Test>::get(asset_pair).len(), 1);
+		assert_eq!(AliceDebitedEth::get(), 0);
+
+		// The price moves past the trigger tick:
+		Pools::<Test>::mutate(asset_pair, |pool| {
+			pool.as_mut().unwrap().pool_state =
+				PoolState::new(0, price_at_tick(150).unwrap()).unwrap();
+		});
+		<LiquidityPools as SwappingApi>::on_swap_batch_executed();
+
+		assert_has_event::<Test>(RuntimeEvent::LiquidityPools(
+			Event::<Test>::ConditionalLimitOrderUpdateTriggered {
+				lp: ALICE,
+				base_asset: Asset::Eth,
+				quote_asset: STABLE_ASSET,
+				order_id: 0,
+				trigger: TRIGGER,
+				tick: 150,
+			},
+		));
+		System::assert_last_event(RuntimeEvent::LiquidityPools(
+			Event::<Test>::ScheduledLimitOrderUpdateDispatchSuccess { lp: ALICE, order_id: 0 },
+		));
+		assert_eq!(AliceDebitedEth::get(), 1_000);
+		assert!(!ConditionalLimitOrderUpdates::<Test>::contains_key(asset_pair));
+	});
+}
+
+#[test]
+fn can_schedule_and_cancel_conditional_limit_order_updates() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		let asset_pair = AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap();
+
+		assert_noop!(
+			LiquidityPools::schedule_conditional_limit_order_update(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(pallet_cf_pools::Call::<Test>::update_buy_interval {
+					new_buy_interval: 1
+				}),
+				TriggerCondition::TickAtOrAbove(100),
+			),
+			Error::<Test>::UnsupportedCall
+		);
+
+		// Conditions that are already met are dispatched immediately:
+		assert_ok!(LiquidityPools::schedule_conditional_limit_order_update(
+			RuntimeOrigin::signed(ALICE),
+			conditional_sell_order(0, 1_000),
+			TriggerCondition::TickAtOrBelow(0),
+		));
+		assert_eq!(AliceDebitedEth::get(), 1_000);
+		assert!(!ConditionalLimitOrderUpdates::<Test>::contains_key(asset_pair));
+
+		// A stop-loss and a take-profit that both cancel the order:
+		for trigger in [TriggerCondition::TickAtOrBelow(-100), TriggerCondition::TickAtOrAbove(100)]
+		{
+			assert_ok!(LiquidityPools::schedule_conditional_limit_order_update(
+				RuntimeOrigin::signed(ALICE),
+				conditional_sell_order(0, 0),
+				trigger,
+			));
+		}
+		assert_eq!(ConditionalLimitOrderUpdates::<Test>::get(asset_pair).len(), 2);
+
+		assert_noop!(
+			LiquidityPools::cancel_conditional_limit_order_updates(
+				RuntimeOrigin::signed(BOB),
+				Asset::Eth,
+				STABLE_ASSET,
+				0
+			),
+			Error::<Test>::ConditionalLimitOrderUpdateDoesNotExist
+		);
+		assert_ok!(LiquidityPools::cancel_conditional_limit_order_updates(
+			RuntimeOrigin::signed(ALICE),
+			Asset::Eth,
+			STABLE_ASSET,
+			0
+		));
+		System::assert_last_event(RuntimeEvent::LiquidityPools(
+			Event::<Test>::ConditionalLimitOrderUpdatesCancelled {
+				lp: ALICE,
+				base_asset: Asset::Eth,
+				quote_asset: STABLE_ASSET,
+				order_id: 0,
+			},
+		));
+		assert!(!ConditionalLimitOrderUpdates::<Test>::contains_key(asset_pair));
+
+		// The number of pending updates per LP is limited:
+		for id in 0..MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_LP as OrderId {
+			assert_ok!(LiquidityPools::schedule_conditional_limit_order_update(
+				RuntimeOrigin::signed(ALICE),
+				conditional_sell_order(id, 0),
+				TriggerCondition::TickAtOrAbove(100),
+			));
+		}
+		assert_noop!(
+			LiquidityPools::schedule_conditional_limit_order_update(
+				RuntimeOrigin::signed(ALICE),
+				conditional_sell_order(0, 0),
+				TriggerCondition::TickAtOrAbove(100),
+			),
+			Error::<Test>::TooManyConditionalLimitOrderUpdates
+		);
+		assert_ok!(LiquidityPools::schedule_conditional_limit_order_update(
+			RuntimeOrigin::signed(BOB),
+			conditional_sell_order(0, 0),
+			TriggerCondition::TickAtOrAbove(100),
+		));
+	});
+}
+
+#[test]
+fn conditional_limit_order_updates_are_bounded() {
+	new_test_ext().execute_with(|| {
+		const TRIGGER: TriggerCondition = TriggerCondition::TickAtOrAbove(100);
+		const LP_COUNT: u64 =
+			(MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_POOL / MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_LP)
+				as u64;
+
+		assert_ok!(LiquidityPools::new_pool(
+			RuntimeOrigin::root(),
+			Asset::Eth,
+			STABLE_ASSET,
+			Default::default(),
+			price_at_tick(0).unwrap(),
+		));
+		let asset_pair = AssetPair::new(Asset::Eth, STABLE_ASSET).unwrap();
+
+		// Fill the pool up to its limit, spread across several LPs:
+		let lps = (1_000..1_000 + LP_COUNT).collect::<Vec<_>>();
+		for lp in &lps {
+			assert_ok!(<MockAccountRoleRegistry as AccountRoleRegistry<Test>>::register_as_liquidity_provider(lp));
+			for id in 0..MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_LP as OrderId {
+				assert_ok!(LiquidityPools::schedule_conditional_limit_order_update(
+					RuntimeOrigin::signed(*lp),
+					conditional_sell_order(id, 0),
+					TRIGGER,
+				));
+			}
+		}
+		assert_eq!(
+			ConditionalLimitOrderUpdates::<Test>::get(asset_pair).len(),
+			MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_POOL
+		);
+
+		// Even an LP with no pending updates can't schedule more:
+		assert_noop!(
+			LiquidityPools::schedule_conditional_limit_order_update(
+				RuntimeOrigin::signed(ALICE),
+				conditional_sell_order(0, 0),
+				TRIGGER,
+			),
+			Error::<Test>::TooManyConditionalLimitOrderUpdates
+		);
+
+		// Once the price moves past the trigger, only a limited number of updates is dispatched
+		// after each batch of swaps:
+		Pools::<Test>::mutate(asset_pair, |pool| {
+			pool.as_mut().unwrap().pool_state =
+				PoolState::new(0, price_at_tick(150).unwrap()).unwrap();
+		});
+		let mut remaining = MAX_CONDITIONAL_LIMIT_ORDER_UPDATES_PER_POOL;
+		while remaining > 0 {
+			let weight = <LiquidityPools as SwappingApi>::on_swap_batch_executed();
+			assert!(!weight.is_zero());
+			remaining -= MAX_TRIGGERED_LIMIT_ORDER_UPDATES_PER_BATCH;
+			assert_eq!(ConditionalLimitOrderUpdates::<Test>::get(asset_pair).len(), remaining);
+		}
+		assert!(!ConditionalLimitOrderUpdates::<Test>::contains_key(asset_pair));
+	});
+}
+
+#[test]
+fn can_batch_order_updates() {
+	new_test_ext().execute_with(|| {
+		for asset in [Asset::Eth, Asset::Flip] {
+			assert_ok!(LiquidityPools::new_pool(
+				RuntimeOrigin::root(),
+				asset,
+				STABLE_ASSET,
+				Default::default(),
+				price_at_tick(0).unwrap(),
+			));
+		}
+
+		let set_limit_order =
+			|base_asset, id, option_tick| pallet_cf_pools::Call::<Test>::set_limit_order {
+				base_asset,
+				quote_asset: STABLE_ASSET,
+				side: Side::Sell,
+				id,
+				option_tick,
+				sell_amount: 1_000,
+			};
+
+		assert_noop!(
+			LiquidityPools::batch_order_updates(
+				RuntimeOrigin::signed(ALICE),
+				bounded_vec![
+					set_limit_order(Asset::Eth, 0, Some(100)),
+					pallet_cf_pools::Call::<Test>::update_buy_interval { new_buy_interval: 1 },
+				],
+			),
+			Error::<Test>::UnsupportedCall
+		);
+
+		// If any update fails, none of them are applied:
+		assert_noop!(
+			LiquidityPools::batch_order_updates(
+				RuntimeOrigin::signed(ALICE),
+				bounded_vec![
+					set_limit_order(Asset::Eth, 0, Some(100)),
+					set_limit_order(Asset::Flip, 0, None),
+				],
+			),
+			Error::<Test>::UnspecifiedOrderPrice
+		);
+
+		assert_ok!(LiquidityPools::batch_order_updates(
+			RuntimeOrigin::signed(ALICE),
+			bounded_vec![
+				set_limit_order(Asset::Eth, 0, Some(100)),
+				set_limit_order(Asset::Flip, 0, Some(-100)),
+				pallet_cf_pools::Call::<Test>::set_range_order {
+					base_asset: Asset::Eth,
+					quote_asset: STABLE_ASSET,
+					id: 1,
+					option_tick_range: Some(-100..100),
+					size: RangeOrderSize::Liquidity { liquidity: 1_000_000 },
+				},
+			],
+		));
+
+		assert_events_match!(
+			Test,
+			RuntimeEvent::LiquidityPools(Event::LimitOrderUpdated {
+				base_asset: Asset::Eth,
+				id: 0,
+				tick: 100,
+				sell_amount_total: 1_000,
+				..
+			}) => (),
+			RuntimeEvent::LiquidityPools(Event::LimitOrderUpdated {
+				base_asset: Asset::Flip,
+				id: 0,
+				tick: -100,
+				sell_amount_total: 1_000,
+				..
+			}) => (),
+			RuntimeEvent::LiquidityPools(Event::RangeOrderUpdated {
+				base_asset: Asset::Eth,
+				id: 1,
+				liquidity_total: 1_000_000,
+				..
+			}) => ()
+		);
+		for asset in [Asset::Eth, Asset::Flip] {
+			assert_eq!(
+				LiquidityPools::pool_orders(asset, STABLE_ASSET, Some(ALICE))
+					.unwrap()
+					.limit_orders
+					.asks
+					.len(),
+				1
+			);
+		}
+	});
+}
@@ -41,6 +41,9 @@ pub trait WeightInfo {
 	fn set_pool_fees() -> Weight;
 	fn schedule_limit_order_update() -> Weight;
 	fn set_maximum_price_impact(n: u32, ) -> Weight;
+	fn set_twap_observation_capacity() -> Weight;
+	fn schedule_conditional_limit_order_update() -> Weight;
+	fn cancel_conditional_limit_order_updates() -> Weight;
 }
 
 /// Weights for pallet_cf_pools using the Substrate node and recommended hardware.
@@ -193,6 +196,44 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `LiquidityPools::TwapObservationCapacity` (r:0 w:1)
+	/// Proof: `LiquidityPools::TwapObservationCapacity` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_twap_observation_capacity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::Pools` (r:1 w:0)
+	/// Proof: `LiquidityPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::ConditionalLimitOrderUpdates` (r:1 w:1)
+	/// Proof: `LiquidityPools::ConditionalLimitOrderUpdates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn schedule_conditional_limit_order_update() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1195`
+		//  Estimated: `4660`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 4660)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::ConditionalLimitOrderUpdates` (r:1 w:1)
+	/// Proof: `LiquidityPools::ConditionalLimitOrderUpdates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_conditional_limit_order_updates() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `700`
+		//  Estimated: `4165`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 4165)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -344,4 +385,42 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `LiquidityPools::TwapObservationCapacity` (r:0 w:1)
+	/// Proof: `LiquidityPools::TwapObservationCapacity` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_twap_observation_capacity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::Pools` (r:1 w:0)
+	/// Proof: `LiquidityPools::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::ConditionalLimitOrderUpdates` (r:1 w:1)
+	/// Proof: `LiquidityPools::ConditionalLimitOrderUpdates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn schedule_conditional_limit_order_update() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1195`
+		//  Estimated: `4660`
+		// Minimum execution time: 28_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 4660)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `AccountRoles::AccountRoles` (r:1 w:0)
+	/// Proof: `AccountRoles::AccountRoles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `LiquidityPools::ConditionalLimitOrderUpdates` (r:1 w:1)
+	/// Proof: `LiquidityPools::ConditionalLimitOrderUpdates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_conditional_limit_order_updates() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `700`
+		//  Estimated: `4165`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 4165)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
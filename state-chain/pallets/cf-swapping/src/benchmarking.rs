@@ -76,6 +76,7 @@ mod benchmarks {
 			boost_fee: 0,
 			channel_metadata: None,
 			refund_parameters: None,
+			dca_parameters: None,
 			affiliate_fees,
 		};
 
@@ -3,12 +3,13 @@
 use cf_amm::common::Side;
 use cf_chains::{
 	address::{AddressConverter, ForeignChainAddress},
-	CcmChannelMetadata, CcmDepositMetadata, ChannelRefundParameters, SwapOrigin,
+	CcmChannelMetadata, CcmDepositMetadata, ChannelRefundParameters, DcaParameters, SwapOrigin,
 	SwapRefundParameters,
 };
 use cf_primitives::{
 	AccountRole, Affiliates, Asset, AssetAmount, Beneficiaries, Beneficiary, ChannelId,
-	ForeignChain, SwapId, SwapLeg, TransactionHash, BASIS_POINTS_PER_MILLION, STABLE_ASSET,
+	ForeignChain, SwapId, SwapLeg, SwapOutput, TransactionHash, BASIS_POINTS_PER_MILLION,
+	STABLE_ASSET,
 };
 use cf_runtime_utilities::log_or_panic;
 use cf_traits::{
@@ -19,8 +20,9 @@ use frame_support::{
 	pallet_prelude::*,
 	sp_runtime::{
 		traits::{Get, Saturating},
-		DispatchError, Permill,
+		DispatchError, Permill, TransactionOutcome,
 	},
+	storage::with_transaction,
 	transactional,
 };
 use frame_system::pallet_prelude::*;
@@ -36,6 +38,8 @@ mod tests;
 mod benchmarking;
 
 pub mod migrations;
+mod routing;
+pub use routing::{SwapRoute, MAX_SWAP_ROUTE_HOPS};
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -46,10 +50,15 @@ pub const SWAP_DELAY_BLOCKS: u32 = 2;
 /// Number of blocks to wait before trying a previously failed swap again
 pub const SWAP_RETRY_DELAY_BLOCKS: u32 = 5;
 
+/// Maximum number of blocks between the execution of the first and the last chunk of a DCA swap.
+pub const MAX_DCA_DURATION_BLOCKS: u32 = 14_400;
+
 struct SwapState {
 	swap: Swap,
 	stable_amount: Option<AssetAmount>,
 	final_output: Option<AssetAmount>,
+	/// Set if the swap takes a route other than the default route through the stable asset.
+	route: Option<SwapRoute>,
 }
 
 impl SwapState {
@@ -57,6 +66,7 @@ impl SwapState {
 		Self {
 			stable_amount: if swap.from == STABLE_ASSET { Some(swap.input_amount) } else { None },
 			final_output: if swap.from == swap.to { Some(swap.input_amount) } else { None },
+			route: None,
 			swap,
 		}
 	}
@@ -141,6 +151,43 @@ pub struct SwapLegInfo {
 	pub amount: AssetAmount,
 	pub source_asset: Option<Asset>,
 	pub source_amount: Option<AssetAmount>,
+	pub dca_info: Option<DcaInfo>,
+}
+
+/// Progress of a DCA swap, as reported alongside its scheduled chunk.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct DcaInfo {
+	pub remaining_chunks: u32,
+	pub chunk_interval: u32,
+	pub remaining_input_amount: AssetAmount,
+	pub accumulated_output_amount: AssetAmount,
+}
+
+/// State of a swap whose input is split into chunks that are executed one after another (DCA).
+/// All chunks share the swap id of the original swap.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub struct DcaState {
+	/// Input amount of the chunks that have not been scheduled yet.
+	pub remaining_input_amount: AssetAmount,
+	/// Number of chunks that have not been scheduled yet.
+	pub remaining_chunks: u32,
+	/// Number of blocks to wait after a chunk is executed before executing the next one.
+	pub chunk_interval: u32,
+	/// Sum of the outputs of all chunks executed so far.
+	pub accumulated_output_amount: AssetAmount,
+	/// Used to derive the refund parameters of each chunk at the time it is scheduled.
+	pub refund_params: Option<ChannelRefundParameters>,
+}
+
+impl From<DcaState> for DcaInfo {
+	fn from(state: DcaState) -> Self {
+		Self {
+			remaining_chunks: state.remaining_chunks,
+			chunk_interval: state.chunk_interval,
+			remaining_input_amount: state.remaining_input_amount,
+			accumulated_output_amount: state.accumulated_output_amount,
+		}
+	}
 }
 
 impl Swap {
@@ -171,6 +218,7 @@ pub(crate) struct CcmSwapOutput {
 
 enum BatchExecutionError {
 	SwapLegFailed { asset: Asset, direction: SwapLeg, amount: AssetAmount },
+	RouteHopFailed { from: Asset, to: Asset, amount: AssetAmount },
 	PriceLimitHit { successful_swaps: Vec<Swap>, failed_swaps: Vec<Swap> },
 	DispatchError { error: DispatchError },
 }
@@ -217,6 +265,7 @@ pub struct CcmSwapAmounts {
 pub enum CcmFailReason {
 	UnsupportedForTargetChain,
 	InsufficientDepositAmount,
+	MessageTooLong,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
@@ -326,6 +375,10 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type FlipToBurn<T: Config> = StorageValue<_, AssetAmount, ValueQuery>;
 
+	/// State of DCA swaps that have chunks left to execute.
+	#[pallet::storage]
+	pub type DcaStates<T: Config> = StorageMap<_, Twox64Concat, SwapId, DcaState>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -406,6 +459,19 @@ pub mod pallet {
 			direction: SwapLeg,
 			amount: AssetAmount,
 		},
+		/// A hop of swaps taking a route other than the default one through the stable asset
+		/// failed.
+		BatchSwapHopFailed {
+			from: Asset,
+			to: Asset,
+			amount: AssetAmount,
+		},
+		/// A swap is executed along a route other than the default one through the stable asset,
+		/// because it is expected to give a better output.
+		SwapRouted {
+			swap_id: SwapId,
+			route: SwapRoute,
+		},
 		CcmEgressScheduled {
 			ccm_id: u64,
 			egress_id: EgressId,
@@ -451,6 +517,28 @@ pub mod pallet {
 			swap_id: SwapId,
 			fee_amount: AssetAmount,
 		},
+		/// A chunk of a DCA swap has been scheduled.
+		DcaChunkScheduled {
+			swap_id: SwapId,
+			chunk_input: AssetAmount,
+			remaining_chunks: u32,
+			execute_at: BlockNumberFor<T>,
+		},
+		/// A chunk of a DCA swap has been executed.
+		DcaChunkExecuted {
+			swap_id: SwapId,
+			chunk_input: AssetAmount,
+			chunk_output: AssetAmount,
+			accumulated_output_amount: AssetAmount,
+			remaining_chunks: u32,
+		},
+		/// A chunk of a DCA swap could not meet its minimum output before the refund block. The
+		/// output accumulated so far is egressed and the unexecuted input is refunded.
+		DcaSwapAborted {
+			swap_id: SwapId,
+			accumulated_output_amount: AssetAmount,
+			unexecuted_input_amount: AssetAmount,
+		},
 	}
 	#[pallet::error]
 	pub enum Error<T> {
@@ -477,6 +565,17 @@ pub mod pallet {
 		EarnedFeesNotWithdrawn,
 		/// The provided list of broker contains an account which is not registered as Broker
 		AffiliateAccountIsNotABroker,
+		/// DCA requires at least one chunk, an interval of at least one block, and must complete
+		/// within the maximum DCA duration.
+		InvalidDcaParameters,
+		/// DCA is not supported for CCM swaps.
+		DcaUnsupportedForCcm,
+		/// The broker of a vault swap is not registered as a Broker.
+		BrokerNotRegistered,
+		/// There is not enough liquidity to estimate the swap.
+		InsufficientLiquidity,
+		/// The CCM message is longer than the destination chain allows.
+		CcmMessageTooLong,
 	}
 
 	#[pallet::hooks]
@@ -508,6 +607,11 @@ pub mod pallet {
 				match Self::execute_batch(swaps_to_execute.clone()) {
 					Ok(successful_swaps) => {
 						Self::process_swap_outcomes(&successful_swaps);
+						// Hooks can't return weight from on_finalize, so it is registered here.
+						frame_system::Pallet::<T>::register_extra_weight_unchecked(
+							T::SwappingApi::on_swap_batch_executed(),
+							DispatchClass::Mandatory,
+						);
 						// Nothing to do here, all swaps are processed for block
 						return
 					},
@@ -529,6 +633,14 @@ pub mod pallet {
 									});
 									(vec![], swaps_to_execute)
 								},
+								BatchExecutionError::RouteHopFailed { from, to, amount } => {
+									Self::deposit_event(Event::<T>::BatchSwapHopFailed {
+										from,
+										to,
+										amount,
+									});
+									(vec![], swaps_to_execute)
+								},
 								BatchExecutionError::DispatchError { error } => {
 									// This should only happen when the transaction nested too deep,
 									// which should not happen in practice (max nesting is 255):
@@ -550,10 +662,14 @@ pub mod pallet {
 									if BlockNumberFor::<T>::from(params.refund_block) <
 										retry_block =>
 								{
-									// Reached refund block, schedule refund:
+									// Reached refund block, schedule refund. For DCA swaps this
+									// includes the input of chunks that were not scheduled yet:
+									let refund_amount = swap
+										.input_amount
+										.saturating_add(Self::abort_dca_swap(&swap));
 									match T::EgressHandler::schedule_egress(
 										swap.from,
-										swap.input_amount,
+										refund_amount,
 										params.refund_address,
 										None,
 									) {
@@ -576,7 +692,7 @@ pub mod pallet {
 											Self::deposit_event(Event::<T>::RefundEgressIgnored {
 												swap_id: swap.swap_id,
 												asset: swap.from,
-												amount: swap.input_amount,
+												amount: refund_amount,
 												reason: err.into(),
 											});
 										},
@@ -634,7 +750,8 @@ pub mod pallet {
 				channel_metadata,
 				boost_fee,
 				Default::default(),
-				// This extrinsic is for backwards compatibility and does not support FoK
+				// This extrinsic is for backwards compatibility and does not support FoK or DCA
+				None,
 				None,
 			)
 		}
@@ -823,6 +940,9 @@ pub mod pallet {
 
 		/// Request a swap deposit address.
 		///
+		/// `dca_parameters` was added in transaction version 13 of the runtime, so clients built
+		/// against earlier versions need to be updated to encode it.
+		///
 		/// ## Events
 		///
 		/// - [SwapDepositAddressReady](Event::SwapDepositAddressReady)
@@ -838,6 +958,7 @@ pub mod pallet {
 			boost_fee: BasisPoints,
 			affiliate_fees: Affiliates<T::AccountId>,
 			refund_parameters: Option<ChannelRefundParameters>,
+			dca_parameters: Option<DcaParameters>,
 		) -> DispatchResult {
 			let broker = T::AccountRoleRegistry::ensure_broker(origin)?;
 			let (beneficiaries, total_bps) = {
@@ -872,9 +993,24 @@ pub mod pallet {
 			let destination_address_internal =
 				Self::validate_destination_address(&destination_address, destination_asset)?;
 
-			if channel_metadata.is_some() {
+			if let Some(metadata) = &channel_metadata {
 				let destination_chain: ForeignChain = destination_asset.into();
 				ensure!(destination_chain.ccm_support(), Error::<T>::CcmUnsupportedForTargetChain);
+				ensure!(
+					metadata.message.len() <= destination_chain.ccm_max_message_length() as usize,
+					Error::<T>::CcmMessageTooLong
+				);
+				ensure!(dca_parameters.is_none(), Error::<T>::DcaUnsupportedForCcm);
+			}
+
+			if let Some(DcaParameters { number_of_chunks, chunk_interval }) = &dca_parameters {
+				ensure!(
+					*number_of_chunks > 0 &&
+						*chunk_interval > 0 &&
+						number_of_chunks.saturating_sub(1).saturating_mul(*chunk_interval) <=
+							MAX_DCA_DURATION_BLOCKS,
+					Error::<T>::InvalidDcaParameters
+				);
 			}
 
 			let (channel_id, deposit_address, expiry_height, channel_opening_fee) =
@@ -887,6 +1023,7 @@ pub mod pallet {
 					channel_metadata.clone(),
 					boost_fee,
 					refund_parameters,
+					dca_parameters,
 				)?;
 
 			Self::deposit_event(Event::<T>::SwapDepositAddressReady {
@@ -912,9 +1049,33 @@ pub mod pallet {
 		pub fn get_scheduled_swap_legs(
 			swaps: Vec<Swap>,
 			base_asset: Asset,
+			quote_asset: Asset,
 		) -> Result<Vec<SwapLegInfo>, ()> {
-			let mut swaps: Vec<_> = swaps.into_iter().map(SwapState::new).collect();
+			let (routed_swaps, swaps): (Vec<_>, Vec<_>) = swaps
+				.into_iter()
+				.map(|swap| {
+					let route = Self::better_route(swap.from, swap.to, swap.input_amount);
+					SwapState { route, ..SwapState::new(swap) }
+				})
+				.partition(|swap| swap.route.is_some());
+
+			// Legs of routed swaps are simulated before the pools are changed below.
+			let routed_swap_legs: Vec<_> = routed_swaps
+				.iter()
+				.filter_map(|swap| Self::routed_swap_leg(swap, base_asset, quote_asset))
+				.collect();
+
+			// Swaps on the default route only use pools with the stable asset.
+			if quote_asset != STABLE_ASSET {
+				return Ok(routed_swap_legs)
+			}
 
+			let mut swaps: Vec<_> = swaps
+				.into_iter()
+				.filter(|swap| {
+					swap.input_asset() == base_asset || swap.output_asset() == base_asset
+				})
+				.collect();
 			Self::swap_into_stable_taking_network_fee(&mut swaps)
 				.map_err(|_| log::error!("Failed to simulate swaps"))?;
 
@@ -925,12 +1086,14 @@ pub mod pallet {
 						Some(SwapLegInfo {
 							swap_id: swap.swap_id(),
 							base_asset,
-							// All swaps from `base_asset` have to go through the stable asset:
+							// Swaps from `base_asset` on the default route go through the stable
+							// asset:
 							quote_asset: STABLE_ASSET,
 							side: Side::Sell,
 							amount: swap.input_amount(),
 							source_asset: None,
 							source_amount: None,
+							dca_info: DcaStates::<T>::get(swap.swap_id()).map(Into::into),
 						})
 					} else if swap.output_asset() == base_asset {
 						// In case the swap is "simulated", the amount is just an estimate,
@@ -944,7 +1107,8 @@ pub mod pallet {
 						Some(SwapLegInfo {
 							swap_id: swap.swap_id(),
 							base_asset,
-							// All swaps to `base_asset` have to go through the stable asset:
+							// Swaps to `base_asset` on the default route go through the stable
+							// asset:
 							quote_asset: STABLE_ASSET,
 							side: Side::Buy,
 							// Safe to unwrap as we have swapped everything into the stable asset at
@@ -952,14 +1116,285 @@ pub mod pallet {
 							amount: swap.stable_amount.unwrap(),
 							source_asset,
 							source_amount,
+							dca_info: DcaStates::<T>::get(swap.swap_id()).map(Into::into),
 						})
 					} else {
 						None
 					}
 				})
+				.chain(routed_swap_legs)
 				.collect())
 		}
 
+		/// The leg of a routed swap that goes through the pool of `base_asset` and `quote_asset`,
+		/// if its route uses that pool.
+		fn routed_swap_leg(
+			swap: &SwapState,
+			base_asset: Asset,
+			quote_asset: Asset,
+		) -> Option<SwapLegInfo> {
+			let route = swap.route.as_ref()?;
+			let hop = route.windows(2).position(|hop| {
+				hop == [base_asset, quote_asset] || hop == [quote_asset, base_asset]
+			})?;
+			let amounts = with_transaction(|| {
+				TransactionOutcome::Rollback(Self::swap_along_route(route, swap.input_amount()))
+			})
+			.ok()?
+			.0;
+			let (source_asset, source_amount) = if hop > 0 {
+				(Some(swap.input_asset()), Some(swap.input_amount()))
+			} else {
+				(None, None)
+			};
+
+			Some(SwapLegInfo {
+				swap_id: swap.swap_id(),
+				base_asset,
+				quote_asset,
+				side: if route[hop] == base_asset { Side::Sell } else { Side::Buy },
+				amount: amounts[hop],
+				source_asset,
+				source_amount,
+				dca_info: DcaStates::<T>::get(swap.swap_id()).map(Into::into),
+			})
+		}
+
+		/// Returns a route other than the default route through the stable asset, if there is one
+		/// that is expected to give a strictly higher output for `amount`. Routes are compared by
+		/// simulating the swap on its own against the current state of the pools.
+		pub fn better_route(from: Asset, to: Asset, amount: AssetAmount) -> Option<SwapRoute> {
+			let default_route = routing::default_route(from, to);
+			let other_routes: Vec<_> = routing::find_routes(&T::SwappingApi::pools(), from, to)
+				.into_iter()
+				.filter(|route| *route != default_route)
+				.collect();
+
+			// Avoid simulating swaps in the common case where there are no alternatives:
+			if other_routes.is_empty() {
+				return None
+			}
+
+			let simulated_output = |route: &SwapRoute| {
+				with_transaction(|| {
+					TransactionOutcome::Rollback(Self::swap_along_route(route, amount))
+				})
+				.ok()
+				.and_then(|(amounts, _)| amounts.last().copied())
+			};
+
+			let mut best_output = simulated_output(&default_route);
+			let mut best_route = None;
+			for route in other_routes {
+				if let Some(output) = simulated_output(&route) {
+					if best_output.map_or(true, |best_output| output > best_output) {
+						best_output = Some(output);
+						best_route = Some(route);
+					}
+				}
+			}
+			best_route
+		}
+
+		/// The commission taken by a broker and its affiliates from the input of a channel swap.
+		pub fn broker_fee(total_commission_bps: BasisPoints, amount: AssetAmount) -> AssetAmount {
+			// Permill maxes out at 100% so this is safe.
+			Permill::from_parts(total_commission_bps as u32 * BASIS_POINTS_PER_MILLION) * amount
+		}
+
+		/// Simulates a swap along the route that would be chosen for it, taking the network fee.
+		pub fn simulate_swap(
+			from: Asset,
+			to: Asset,
+			amount: AssetAmount,
+		) -> Result<(SwapRoute, SwapOutput), DispatchError> {
+			let route = Self::better_route(from, to, amount)
+				.unwrap_or_else(|| routing::default_route(from, to));
+			let (amounts, network_fee) = with_transaction(|| {
+				TransactionOutcome::Rollback(Self::swap_along_route(&route, amount))
+			})?;
+
+			// Only an intermediate amount in the stable asset is reported:
+			let intermediary = route
+				.iter()
+				.zip(&amounts)
+				.skip(1)
+				.take(route.len().saturating_sub(2))
+				.find(|(asset, _)| **asset == STABLE_ASSET)
+				.map(|(_, amount)| *amount);
+
+			Ok((
+				route,
+				SwapOutput {
+					intermediary,
+					output: amounts.last().copied().unwrap_or_default(),
+					network_fee,
+				},
+			))
+		}
+
+		/// Estimates the amount of `from` that needs to be swapped to receive `desired_output` of
+		/// `to`, at current pool prices and including the network fee.
+		pub fn estimate_swap_input(
+			from: Asset,
+			to: Asset,
+			desired_output: AssetAmount,
+		) -> Result<AssetAmount, DispatchError> {
+			if from == to || desired_output.is_zero() {
+				return Ok(desired_output)
+			}
+
+			// Get a rough input amount by swapping in reverse, then scale it by the ratio
+			// observed when swapping it forward.
+			let (_, SwapOutput { output: probe_input, .. }) =
+				Self::simulate_swap(to, from, desired_output)?;
+			let (_, SwapOutput { output: probe_output, .. }) =
+				Self::simulate_swap(from, to, probe_input)?;
+
+			ensure!(!probe_output.is_zero(), Error::<T>::InsufficientLiquidity);
+
+			Ok(multiply_by_rational_with_rounding(
+				probe_input,
+				desired_output,
+				probe_output,
+				Rounding::Up,
+			)
+			.unwrap_or(AssetAmount::MAX))
+		}
+
+		/// Swaps `amount` hop by hop along `route`. The network fee is taken in the stable asset
+		/// if the route goes through it, otherwise it is taken from the input amount. Returns the
+		/// amount held at each asset of the route and the network fee.
+		fn swap_along_route(
+			route: &[Asset],
+			amount: AssetAmount,
+		) -> Result<(Vec<AssetAmount>, AssetAmount), DispatchError> {
+			let mut network_fee = Zero::zero();
+			let mut amount = amount;
+
+			if !route.contains(&STABLE_ASSET) {
+				network_fee = T::SwappingApi::network_fee() * amount;
+				amount.saturating_reduce(network_fee);
+			}
+
+			let mut amounts = Vec::with_capacity(route.len());
+			for (index, asset) in route.iter().enumerate() {
+				if index > 0 {
+					amount = T::SwappingApi::swap_single_leg(route[index - 1], *asset, amount)?;
+				}
+				if *asset == STABLE_ASSET {
+					let NetworkFeeTaken { remaining_amount, network_fee: fee } =
+						T::SwappingApi::take_network_fee(amount);
+					amount = remaining_amount;
+					network_fee = fee;
+				}
+				amounts.push(amount);
+			}
+
+			Ok((amounts, network_fee))
+		}
+
+		/// Executes swaps that take a route other than the default one, hop by hop. Swaps that
+		/// share a route are bundled together at every hop.
+		fn execute_routed_swaps(swaps: &mut [SwapState]) -> Result<(), BatchExecutionError> {
+			let route_groups =
+				swaps.iter_mut().fold(BTreeMap::new(), |mut groups: BTreeMap<_, Vec<_>>, swap| {
+					groups.entry(swap.route.clone().unwrap_or_default()).or_default().push(swap);
+					groups
+				});
+
+			// Network fees taken outside of the stable asset, to be swapped into FLIP and burned.
+			let mut fees_to_burn: BTreeMap<Asset, AssetAmount> = BTreeMap::new();
+
+			for (route, mut swaps) in route_groups {
+				let mut amounts: Vec<_> = swaps.iter().map(|swap| swap.input_amount()).collect();
+
+				if !route.contains(&STABLE_ASSET) {
+					for (swap, amount) in swaps.iter().zip(amounts.iter_mut()) {
+						if swap.swap_type() == &SwapType::NetworkFee {
+							continue;
+						}
+						let network_fee = T::SwappingApi::network_fee() * *amount;
+						amount.saturating_reduce(network_fee);
+						fees_to_burn
+							.entry(swap.input_asset())
+							.or_default()
+							.saturating_accrue(network_fee);
+						Self::deposit_event(Event::<T>::NetworkFeeTaken {
+							swap_id: swap.swap_id(),
+							fee_amount: network_fee,
+						});
+					}
+				}
+
+				for (index, asset) in route.iter().enumerate() {
+					if index > 0 {
+						let from = route[index - 1];
+						let bundle_input: AssetAmount = amounts.iter().sum();
+						let bundle_output =
+							T::SwappingApi::swap_single_leg(from, *asset, bundle_input).map_err(
+								|_| BatchExecutionError::RouteHopFailed {
+									from,
+									to: *asset,
+									amount: bundle_input,
+								},
+							)?;
+						for amount in amounts.iter_mut() {
+							*amount = if bundle_input > 0 {
+								multiply_by_rational_with_rounding(
+									*amount,
+									bundle_output,
+									bundle_input,
+									Rounding::Down,
+								)
+								.expect("bundle_input >= amount && bundle_input != 0 ∴ result can't overflow")
+							} else {
+								0
+							};
+						}
+					}
+					if *asset == STABLE_ASSET {
+						for (swap, amount) in swaps.iter_mut().zip(amounts.iter_mut()) {
+							if swap.swap_type() != &SwapType::NetworkFee {
+								let NetworkFeeTaken { remaining_amount, network_fee } =
+									T::SwappingApi::take_network_fee(*amount);
+								*amount = remaining_amount;
+								Self::deposit_event(Event::<T>::NetworkFeeTaken {
+									fee_amount: network_fee,
+									swap_id: swap.swap_id(),
+								});
+							}
+							swap.stable_amount = Some(*amount);
+						}
+					}
+				}
+
+				for (swap, amount) in swaps.iter_mut().zip(amounts) {
+					swap.final_output = Some(amount);
+					Self::deposit_event(Event::<T>::SwapRouted {
+						swap_id: swap.swap_id(),
+						route: route.clone(),
+					});
+				}
+			}
+
+			for (asset, network_fee) in fees_to_burn {
+				if asset == Asset::Flip {
+					FlipToBurn::<T>::mutate(|total| total.saturating_accrue(network_fee));
+				} else if !network_fee.is_zero() {
+					Self::schedule_swap(
+						asset,
+						Asset::Flip,
+						network_fee,
+						None,
+						SwapType::NetworkFee,
+					);
+				}
+			}
+
+			Ok(())
+		}
+
 		fn swap_into_stable_taking_network_fee(
 			swaps: &mut [SwapState],
 		) -> Result<(), BatchExecutionError> {
@@ -1001,7 +1436,14 @@ pub mod pallet {
 
 		#[transactional]
 		fn execute_batch(swaps: Vec<Swap>) -> Result<Vec<SwapState>, BatchExecutionError> {
-			let mut swaps: Vec<_> = swaps.into_iter().map(SwapState::new).collect();
+			// Routes are chosen against the state of the pools before any of the swaps execute.
+			let (mut routed_swaps, mut swaps): (Vec<_>, Vec<_>) = swaps
+				.into_iter()
+				.map(|swap| {
+					let route = Self::better_route(swap.from, swap.to, swap.input_amount);
+					SwapState { route, ..SwapState::new(swap) }
+				})
+				.partition(|swap| swap.route.is_some());
 
 			// Swap into Stable asset first, then take network fees:
 			Self::swap_into_stable_taking_network_fee(&mut swaps)?;
@@ -1009,6 +1451,9 @@ pub mod pallet {
 			// Swap from Stable asset, and complete the swap logic.
 			Self::do_group_and_swap(&mut swaps, SwapLeg::FromStable)?;
 
+			Self::execute_routed_swaps(&mut routed_swaps)?;
+			swaps.extend(routed_swaps);
+
 			// Swaps executed without triggering price impact protection, but we still need to
 			// check that none of the swaps violated their minimum output requirements:
 			let (non_violating, violating): (Vec<_>, Vec<_>) =
@@ -1057,35 +1502,19 @@ pub mod pallet {
 					});
 					// Handle swap completion logic.
 					match &swap.swap_type() {
-						SwapType::Swap(destination_address) =>
-							match T::EgressHandler::schedule_egress(
-								swap.output_asset(),
-								swap_output,
-								destination_address.clone(),
-								None,
-							) {
-								Ok(ScheduledEgressDetails {
-									egress_id,
+						SwapType::Swap(destination_address) => {
+							// DCA swaps are only egressed once all of their chunks are executed.
+							if let Some(egress_amount) =
+								Self::process_dca_chunk_output(&swap.swap, swap_output)
+							{
+								Self::egress_swap_output(
+									swap.swap_id(),
+									swap.output_asset(),
 									egress_amount,
-									fee_withheld,
-								}) => {
-									Self::deposit_event(Event::<T>::SwapEgressScheduled {
-										swap_id: swap.swap_id(),
-										egress_id,
-										asset: swap.output_asset(),
-										amount: egress_amount,
-										fee: fee_withheld,
-									});
-								},
-								Err(err) => {
-									Self::deposit_event(Event::<T>::SwapEgressIgnored {
-										swap_id: swap.swap_id(),
-										asset: swap.output_asset(),
-										amount: swap_output,
-										reason: err.into(),
-									});
-								},
-							},
+									destination_address.clone(),
+								);
+							}
+						},
 						SwapType::CcmPrincipal(ccm_id) => {
 							Self::handle_ccm_swap_result(
 								*ccm_id,
@@ -1129,6 +1558,159 @@ pub mod pallet {
 			}
 		}
 
+		fn egress_swap_output(
+			swap_id: SwapId,
+			asset: Asset,
+			amount: AssetAmount,
+			destination_address: ForeignChainAddress,
+		) {
+			match T::EgressHandler::schedule_egress(asset, amount, destination_address, None) {
+				Ok(ScheduledEgressDetails { egress_id, egress_amount, fee_withheld }) => {
+					Self::deposit_event(Event::<T>::SwapEgressScheduled {
+						swap_id,
+						egress_id,
+						asset,
+						amount: egress_amount,
+						fee: fee_withheld,
+					});
+				},
+				Err(err) => {
+					Self::deposit_event(Event::<T>::SwapEgressIgnored {
+						swap_id,
+						asset,
+						amount,
+						reason: err.into(),
+					});
+				},
+			}
+		}
+
+		/// Records the output of an executed swap. For DCA swaps with chunks remaining, the next
+		/// chunk is scheduled and `None` is returned. Otherwise returns the total amount to be
+		/// egressed.
+		fn process_dca_chunk_output(swap: &Swap, chunk_output: AssetAmount) -> Option<AssetAmount> {
+			let Some(mut dca_state) = DcaStates::<T>::take(swap.swap_id) else {
+				return Some(chunk_output)
+			};
+
+			dca_state.accumulated_output_amount.saturating_accrue(chunk_output);
+
+			Self::deposit_event(Event::<T>::DcaChunkExecuted {
+				swap_id: swap.swap_id,
+				chunk_input: swap.input_amount,
+				chunk_output,
+				accumulated_output_amount: dca_state.accumulated_output_amount,
+				remaining_chunks: dca_state.remaining_chunks,
+			});
+
+			if dca_state.remaining_chunks == 0 {
+				return Some(dca_state.accumulated_output_amount)
+			}
+
+			let execute_at =
+				frame_system::Pallet::<T>::block_number() + dca_state.chunk_interval.into();
+			Self::schedule_dca_chunk(
+				swap.swap_id,
+				swap.from,
+				swap.to,
+				swap.swap_type.clone(),
+				dca_state,
+				execute_at,
+			);
+
+			None
+		}
+
+		/// Takes the next chunk from the DCA state and schedules it for execution at `execute_at`.
+		fn schedule_dca_chunk(
+			swap_id: SwapId,
+			from: Asset,
+			to: Asset,
+			swap_type: SwapType,
+			mut dca_state: DcaState,
+			execute_at: BlockNumberFor<T>,
+		) {
+			// The last chunk takes whatever is left to account for rounding.
+			let chunk_input = dca_state.remaining_input_amount / dca_state.remaining_chunks as u128;
+			dca_state.remaining_input_amount.saturating_reduce(chunk_input);
+			dca_state.remaining_chunks.saturating_reduce(1);
+
+			SwapQueue::<T>::append(
+				execute_at,
+				Swap::new(
+					swap_id,
+					from,
+					to,
+					chunk_input,
+					dca_state
+						.refund_params
+						.clone()
+						.map(|params| Self::swap_refund_params(params, chunk_input)),
+					swap_type,
+				),
+			);
+
+			Self::deposit_event(Event::<T>::DcaChunkScheduled {
+				swap_id,
+				chunk_input,
+				remaining_chunks: dca_state.remaining_chunks,
+				execute_at,
+			});
+
+			DcaStates::<T>::insert(swap_id, dca_state);
+		}
+
+		/// Stops a DCA swap whose chunk is being refunded. The output accumulated by previously
+		/// executed chunks is egressed to the destination, and the input of the chunks that were
+		/// not scheduled yet is returned so that it can be refunded along with the failed chunk.
+		fn abort_dca_swap(swap: &Swap) -> AssetAmount {
+			let Some(dca_state) = DcaStates::<T>::take(swap.swap_id) else { return Zero::zero() };
+
+			Self::deposit_event(Event::<T>::DcaSwapAborted {
+				swap_id: swap.swap_id,
+				accumulated_output_amount: dca_state.accumulated_output_amount,
+				unexecuted_input_amount: swap
+					.input_amount
+					.saturating_add(dca_state.remaining_input_amount),
+			});
+
+			if !dca_state.accumulated_output_amount.is_zero() {
+				if let SwapType::Swap(destination_address) = &swap.swap_type {
+					Self::egress_swap_output(
+						swap.swap_id,
+						swap.to,
+						dca_state.accumulated_output_amount,
+						destination_address.clone(),
+					);
+				}
+			}
+
+			dca_state.remaining_input_amount
+		}
+
+		/// Derives the refund parameters of a swap from the channel's refund parameters, now that
+		/// the swap's input amount is known.
+		fn swap_refund_params(
+			params: ChannelRefundParameters,
+			input_amount: AssetAmount,
+		) -> SwapRefundParameters {
+			SwapRefundParameters {
+				refund_block: {
+					use sp_arithmetic::traits::UniqueSaturatedInto;
+					// In practice block number always fits in u32:
+					let current_block: u32 =
+						frame_system::Pallet::<T>::block_number().unique_saturated_into();
+					current_block.saturating_add(params.retry_duration)
+				},
+				refund_address: params.refund_address,
+				min_output: u128::try_from(cf_amm::common::output_amount_ceil(
+					input_amount.into(),
+					params.min_price,
+				))
+				.unwrap_or(u128::MAX),
+			}
+		}
+
 		pub fn principal_and_gas_amounts(
 			deposit_amount: AssetAmount,
 			channel_metadata: &CcmChannelMetadata,
@@ -1141,6 +1723,10 @@ pub mod pallet {
 			let destination_chain: ForeignChain = destination_asset.into();
 			if !destination_chain.ccm_support() {
 				return Err(CcmFailReason::UnsupportedForTargetChain)
+			} else if channel_metadata.message.len() >
+				destination_chain.ccm_max_message_length() as usize
+			{
+				return Err(CcmFailReason::MessageTooLong)
 			} else if deposit_amount < gas_budget {
 				return Err(CcmFailReason::InsufficientDepositAmount)
 			}
@@ -1319,13 +1905,13 @@ pub mod pallet {
 			destination_address: ForeignChainAddress,
 			broker_commission: Beneficiaries<Self::AccountId>,
 			refund_params: Option<ChannelRefundParameters>,
+			dca_params: Option<DcaParameters>,
 			channel_id: ChannelId,
 		) -> SwapId {
-			// Permill maxes out at 100% so this is safe.
-			let fee: u128 = Permill::from_parts(
-				broker_commission.iter().fold(0, |acc, entry| acc + entry.bps) as u32 *
-					BASIS_POINTS_PER_MILLION,
-			) * amount;
+			let fee = Self::broker_fee(
+				broker_commission.iter().fold(0, |acc, entry| acc + entry.bps),
+				amount,
+			);
 
 			assert!(fee <= amount, "Broker fee cannot be more than the amount");
 
@@ -1339,30 +1925,42 @@ pub mod pallet {
 				deposit_block_height,
 			};
 
-			// Now that we know input amount, we can calculate the minimum output amount:
-			let refund_params = refund_params.map(|params| SwapRefundParameters {
-				refund_block: {
-					use sp_arithmetic::traits::UniqueSaturatedInto;
-					// In practice block number always fits in u32:
-					let current_block: u32 =
-						frame_system::Pallet::<T>::block_number().unique_saturated_into();
-					current_block.saturating_add(params.retry_duration)
+			let swap_type = SwapType::Swap(destination_address.clone());
+
+			// Only split the swap if every chunk gets a non-zero input.
+			let (swap_id, execute_at) = match dca_params.filter(|params| {
+				params.number_of_chunks > 1 && net_amount >= params.number_of_chunks.into()
+			}) {
+				Some(DcaParameters { number_of_chunks, chunk_interval }) => {
+					let (swap_id, swap_amount) =
+						Self::next_swap_id_and_amount(from, to, net_amount, &swap_type);
+					let execute_at =
+						frame_system::Pallet::<T>::block_number() + SWAP_DELAY_BLOCKS.into();
+					Self::schedule_dca_chunk(
+						swap_id,
+						from,
+						to,
+						swap_type,
+						DcaState {
+							remaining_input_amount: swap_amount,
+							remaining_chunks: number_of_chunks,
+							chunk_interval,
+							accumulated_output_amount: Zero::zero(),
+							refund_params,
+						},
+						execute_at,
+					);
+					(swap_id, execute_at)
 				},
-				refund_address: params.refund_address,
-				min_output: u128::try_from(cf_amm::common::output_amount_ceil(
-					net_amount.into(),
-					params.min_price,
-				))
-				.unwrap_or(u128::MAX),
-			});
-
-			let (swap_id, execute_at) = Self::schedule_swap(
-				from,
-				to,
-				net_amount,
-				refund_params,
-				SwapType::Swap(destination_address.clone()),
-			);
+				None => Self::schedule_swap(
+					from,
+					to,
+					net_amount,
+					// Now that we know input amount, we can calculate the minimum output amount:
+					refund_params.map(|params| Self::swap_refund_params(params, net_amount)),
+					swap_type,
+				),
+			};
 
 			for Beneficiary { account, bps } in broker_commission {
 				EarnedBrokerFees::<T>::mutate(&account, from, |earned_fees| {
@@ -1387,6 +1985,57 @@ pub mod pallet {
 
 			swap_id
 		}
+
+		fn schedule_vault_swap(
+			from: Asset,
+			to: Asset,
+			amount: AssetAmount,
+			destination_address: EncodedAddress,
+			broker_commission: Beneficiary<Self::AccountId>,
+			refund_params: SwapRefundParameters,
+			tx_hash: TransactionHash,
+		) -> Result<SwapId, DispatchError> {
+			let destination_address_internal =
+				Self::validate_destination_address(&destination_address, to)?;
+			ensure!(
+				T::AccountRoleRegistry::has_account_role(
+					&broker_commission.account,
+					AccountRole::Broker
+				),
+				Error::<T>::BrokerNotRegistered
+			);
+			ensure!(broker_commission.bps <= 1000, Error::<T>::BrokerCommissionBpsTooHigh);
+
+			let fee = Self::broker_fee(broker_commission.bps, amount);
+			let net_amount = amount.saturating_sub(fee);
+
+			let (swap_id, execute_at) = Self::schedule_swap(
+				from,
+				to,
+				net_amount,
+				Some(refund_params),
+				SwapType::Swap(destination_address_internal.clone()),
+			);
+
+			EarnedBrokerFees::<T>::mutate(&broker_commission.account, from, |earned_fees| {
+				earned_fees.saturating_accrue(fee)
+			});
+
+			Self::deposit_event(Event::<T>::SwapScheduled {
+				swap_id,
+				source_asset: from,
+				deposit_amount: amount,
+				destination_asset: to,
+				destination_address,
+				origin: SwapOrigin::Vault { tx_hash },
+				swap_type: SwapType::Swap(destination_address_internal),
+				broker_commission: Some(fee),
+				broker_fee: Some(fee),
+				execute_at,
+			});
+
+			Ok(swap_id)
+		}
 	}
 
 	impl<T: Config> CcmHandler for Pallet<T> {
@@ -1530,13 +2179,35 @@ impl<T: Config> SwapQueueApi for Pallet<T> {
 		refund_params: Option<SwapRefundParameters>,
 		swap_type: SwapType,
 	) -> (u64, Self::BlockNumber) {
+		let (swap_id, swap_amount) = Self::next_swap_id_and_amount(from, to, amount, &swap_type);
+
+		let execute_at = frame_system::Pallet::<T>::block_number() + SWAP_DELAY_BLOCKS.into();
+
+		SwapQueue::<T>::append(
+			execute_at,
+			Swap::new(swap_id, from, to, swap_amount, refund_params, swap_type),
+		);
+
+		(swap_id, execute_at)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Allocates a new swap id and limits the amount to the maximum swap amount, confiscating the
+	/// excess. Returns the swap id and the amount that can be swapped.
+	fn next_swap_id_and_amount(
+		from: Asset,
+		to: Asset,
+		amount: AssetAmount,
+		swap_type: &SwapType,
+	) -> (SwapId, AssetAmount) {
 		let swap_id = SwapIdCounter::<T>::mutate(|id| {
 			id.saturating_accrue(1);
 			*id
 		});
 
 		// Do not limit the maximum swap amount for network fee swaps.
-		let swap_amount = if swap_type == SwapType::NetworkFee {
+		let swap_amount = if *swap_type == SwapType::NetworkFee {
 			amount
 		} else {
 			let (swap_amount, confiscated_amount) = match MaximumSwapAmount::<T>::get(from) {
@@ -1558,14 +2229,7 @@ impl<T: Config> SwapQueueApi for Pallet<T> {
 			swap_amount
 		};
 
-		let execute_at = frame_system::Pallet::<T>::block_number() + SWAP_DELAY_BLOCKS.into();
-
-		SwapQueue::<T>::append(
-			execute_at,
-			Swap::new(swap_id, from, to, swap_amount, refund_params, swap_type),
-		);
-
-		(swap_id, execute_at)
+		(swap_id, swap_amount)
 	}
 }
 
@@ -2,7 +2,7 @@ use core::cell::Cell;
 
 use crate::{self as pallet_cf_swapping, PalletSafeMode, WeightInfo};
 use cf_chains::AnyChain;
-use cf_primitives::{Asset, AssetAmount};
+use cf_primitives::{Asset, AssetAmount, STABLE_ASSET};
 #[cfg(feature = "runtime-benchmarks")]
 use cf_traits::mocks::fee_payment::MockFeePayment;
 use cf_traits::{
@@ -18,7 +18,7 @@ use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	Percent,
+	Percent, Permill,
 };
 
 type AccountId = u64;
@@ -71,6 +71,10 @@ parameter_types! {
 	pub static NetworkFee: Percent = Percent::from_percent(0);
 	pub static Swaps: Vec<(Asset, Asset, AssetAmount)> = vec![];
 	pub static SwapRate: f64 = 1f64;
+	/// Pools in addition to the pools of every asset with the stable asset.
+	pub static ExtraPools: Vec<(Asset, Asset)> = vec![];
+	/// Rates that override `SwapRate` for swaps from one asset to another.
+	pub static PoolRates: Vec<((Asset, Asset), f64)> = vec![];
 }
 
 thread_local! {
@@ -107,7 +111,23 @@ impl SwappingApi for MockSwappingApi {
 		let mut swaps = Swaps::get();
 		swaps.push((from, to, input_amount));
 		Swaps::set(swaps);
-		Ok((input_amount as f64 * SwapRate::get()) as AssetAmount)
+		let rate = PoolRates::get()
+			.into_iter()
+			.find_map(|(pair, rate)| (pair == (from, to)).then_some(rate))
+			.unwrap_or_else(SwapRate::get);
+		Ok((input_amount as f64 * rate) as AssetAmount)
+	}
+
+	fn network_fee() -> Permill {
+		Permill::from_percent(NetworkFee::get().deconstruct().into())
+	}
+
+	fn pools() -> Vec<(Asset, Asset)> {
+		Asset::all()
+			.filter(|asset| *asset != STABLE_ASSET)
+			.map(|asset| (asset, STABLE_ASSET))
+			.chain(ExtraPools::get())
+			.collect()
 	}
 }
 
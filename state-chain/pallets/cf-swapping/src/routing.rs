@@ -0,0 +1,58 @@
+use cf_primitives::{Asset, STABLE_ASSET};
+use sp_std::{vec, vec::Vec};
+
+/// The maximum number of pools a swap can be routed through.
+pub const MAX_SWAP_ROUTE_HOPS: usize = 3;
+
+/// The assets a swap passes through, starting with the input asset and ending with the output
+/// asset. Every two consecutive assets are swapped through the pool of that pair.
+pub type SwapRoute = Vec<Asset>;
+
+/// The route swaps take unless a better one is found: through the stable asset.
+pub fn default_route(from: Asset, to: Asset) -> SwapRoute {
+	if from == STABLE_ASSET || to == STABLE_ASSET {
+		vec![from, to]
+	} else {
+		vec![from, STABLE_ASSET, to]
+	}
+}
+
+/// All routes from `from` to `to` through the given pools that visit each asset at most once and
+/// take at most `MAX_SWAP_ROUTE_HOPS` hops.
+pub fn find_routes(pools: &[(Asset, Asset)], from: Asset, to: Asset) -> Vec<SwapRoute> {
+	fn extend_route(
+		pools: &[(Asset, Asset)],
+		route: &mut SwapRoute,
+		to: Asset,
+		routes: &mut Vec<SwapRoute>,
+	) {
+		let current = *route.last().expect("routes are never empty");
+		if current == to {
+			routes.push(route.clone());
+			return
+		}
+		if route.len() > MAX_SWAP_ROUTE_HOPS {
+			return
+		}
+		for &(base, quote) in pools {
+			let next = if current == base {
+				quote
+			} else if current == quote {
+				base
+			} else {
+				continue
+			};
+			if !route.contains(&next) {
+				route.push(next);
+				extend_route(pools, route, to, routes);
+				route.pop();
+			}
+		}
+	}
+
+	let mut routes = Vec::new();
+	if from != to {
+		extend_route(pools, &mut vec![from], to, &mut routes);
+	}
+	routes
+}
@@ -1,4 +1,6 @@
+mod dca;
 mod fill_or_kill;
+mod routing;
 
 use super::*;
 use crate::{
@@ -141,6 +143,7 @@ fn insert_swaps(swaps: &[Swap]) {
 						swap.input_amount.into(),
 					)),
 				}),
+				None,
 				1, /* channel id */
 			);
 		} else {
@@ -182,6 +185,7 @@ fn request_swap_success_with_valid_parameters() {
 			0,
 			Default::default(),
 			None,
+			None,
 		));
 	});
 }
@@ -231,6 +235,7 @@ fn expect_earned_fees_to_be_recorded() {
 			ForeignChainAddress::Eth([2; 20].into()),
 			bounded_vec![Beneficiary { account: ALICE, bps: 200 }],
 			None,
+			None,
 			1,
 		);
 		assert_eq!(EarnedBrokerFees::<Test>::get(ALICE, cf_primitives::Asset::Flip), 2);
@@ -243,6 +248,7 @@ fn expect_earned_fees_to_be_recorded() {
 			ForeignChainAddress::Eth([2; 20].into()),
 			bounded_vec![Beneficiary { account: ALICE, bps: 200 }],
 			None,
+			None,
 			1,
 		);
 		assert_eq!(EarnedBrokerFees::<Test>::get(ALICE, cf_primitives::Asset::Flip), 4);
@@ -258,6 +264,7 @@ fn expect_earned_fees_to_be_recorded() {
 				Beneficiary { account: BOB, bps: 200 }
 			],
 			None,
+			None,
 			1,
 		);
 		assert_eq!(EarnedBrokerFees::<Test>::get(ALICE, cf_primitives::Asset::Eth), 2);
@@ -279,6 +286,7 @@ fn cannot_swap_with_incorrect_destination_address_type() {
 			ForeignChainAddress::Eth([2; 20].into()),
 			bounded_vec![Beneficiary { account: ALICE, bps: 2 }],
 			None,
+			None,
 			1,
 		);
 
@@ -301,6 +309,7 @@ fn expect_swap_id_to_be_emitted() {
 				0,
 				Default::default(),
 				None,
+				None,
 			));
 
 			const AMOUNT: AssetAmount = 500;
@@ -314,6 +323,7 @@ fn expect_swap_id_to_be_emitted() {
 				ForeignChainAddress::Eth(Default::default()),
 				bounded_vec![],
 				None,
+				None,
 				1,
 			);
 			// 3. Process swaps -> SwapExecuted, SwapEgressScheduled
@@ -425,6 +435,86 @@ fn can_swap_using_witness_origin() {
 	});
 }
 
+#[test]
+fn can_schedule_vault_swap() {
+	new_test_ext().execute_with(|| {
+		let from = Asset::Btc;
+		let to = Asset::Eth;
+		let amount = 10_000;
+		let refund_params = SwapRefundParameters {
+			refund_block: 1,
+			refund_address: ForeignChainAddress::Btc(ScriptPubkey::P2PKH([1; 20])),
+			min_output: 500,
+		};
+
+		assert_eq!(
+			<Swapping as SwapDepositHandler>::schedule_vault_swap(
+				from,
+				to,
+				amount,
+				EncodedAddress::Eth(Default::default()),
+				Beneficiary { account: ALICE, bps: 100 },
+				refund_params.clone(),
+				[0xab; 32],
+			),
+			Ok(1)
+		);
+
+		System::assert_last_event(RuntimeEvent::Swapping(Event::<Test>::SwapScheduled {
+			swap_id: 1,
+			source_asset: from,
+			deposit_amount: amount,
+			destination_asset: to,
+			destination_address: EncodedAddress::Eth(Default::default()),
+			origin: SwapOrigin::Vault { tx_hash: [0xab; 32] },
+			swap_type: SwapType::Swap(ForeignChainAddress::Eth(Default::default())),
+			broker_commission: Some(100),
+			broker_fee: Some(100),
+			execute_at: 3,
+		}));
+		assert_eq!(EarnedBrokerFees::<Test>::get(ALICE, from), 100);
+		assert_eq!(SwapQueue::<Test>::get(3)[0].refund_params, Some(refund_params.clone()));
+
+		// Invalid parameters are rejected so that the deposit can be refunded.
+		assert_noop!(
+			<Swapping as SwapDepositHandler>::schedule_vault_swap(
+				from,
+				to,
+				amount,
+				EncodedAddress::Dot(Default::default()),
+				Beneficiary { account: ALICE, bps: 100 },
+				refund_params.clone(),
+				Default::default(),
+			),
+			Error::<Test>::IncompatibleAssetAndAddress
+		);
+		assert_noop!(
+			<Swapping as SwapDepositHandler>::schedule_vault_swap(
+				from,
+				to,
+				amount,
+				EncodedAddress::Eth(Default::default()),
+				Beneficiary { account: ALICE + 1, bps: 100 },
+				refund_params.clone(),
+				Default::default(),
+			),
+			Error::<Test>::BrokerNotRegistered
+		);
+		assert_noop!(
+			<Swapping as SwapDepositHandler>::schedule_vault_swap(
+				from,
+				to,
+				amount,
+				EncodedAddress::Eth(Default::default()),
+				Beneficiary { account: ALICE, bps: 1001 },
+				refund_params,
+				Default::default(),
+			),
+			Error::<Test>::BrokerCommissionBpsTooHigh
+		);
+	});
+}
+
 #[test]
 fn reject_invalid_ccm_deposit() {
 	new_test_ext().execute_with(|| {
@@ -460,19 +550,26 @@ fn reject_invalid_ccm_deposit() {
 		assert_failed_ccm(
 			Asset::Eth,
 			1_000_000,
-			Asset::Dot,
-			ForeignChainAddress::Dot(Default::default()),
+			Asset::HubDot,
+			ForeignChainAddress::Hub(Default::default()),
 			ccm.clone(),
 			CcmFailReason::UnsupportedForTargetChain,
 		);
 
+		// Bitcoin only allows messages that fit in an OP_RETURN output.
 		assert_failed_ccm(
 			Asset::Eth,
 			1_000_000,
 			Asset::Btc,
 			ForeignChainAddress::Btc(cf_chains::btc::ScriptPubkey::P2PKH(Default::default())),
-			ccm.clone(),
-			CcmFailReason::UnsupportedForTargetChain,
+			CcmDepositMetadata {
+				channel_metadata: CcmChannelMetadata {
+					message: vec![0x01; 81].try_into().unwrap(),
+					..generate_ccm_channel()
+				},
+				..ccm.clone()
+			},
+			CcmFailReason::MessageTooLong,
 		);
 		assert_failed_ccm(
 			Asset::Eth,
@@ -501,10 +598,27 @@ fn rejects_invalid_swap_deposit() {
 				0,
 				Default::default(),
 				None,
+				None,
 			),
 			Error::<Test>::IncompatibleAssetAndAddress
 		);
 
+		assert_noop!(
+			Swapping::request_swap_deposit_address_with_affiliates(
+				RuntimeOrigin::signed(ALICE),
+				Asset::Eth,
+				Asset::HubDot,
+				EncodedAddress::Hub(Default::default()),
+				0,
+				Some(ccm.clone()),
+				0,
+				Default::default(),
+				None,
+				None,
+			),
+			Error::<Test>::CcmUnsupportedForTargetChain
+		);
+
 		assert_noop!(
 			Swapping::request_swap_deposit_address_with_affiliates(
 				RuntimeOrigin::signed(ALICE),
@@ -512,12 +626,16 @@ fn rejects_invalid_swap_deposit() {
 				Asset::Dot,
 				EncodedAddress::Dot(Default::default()),
 				0,
-				Some(ccm),
+				Some(CcmChannelMetadata {
+					message: vec![0x01; 2_049].try_into().unwrap(),
+					..ccm
+				}),
 				0,
 				Default::default(),
 				None,
+				None,
 			),
-			Error::<Test>::CcmUnsupportedForTargetChain
+			Error::<Test>::CcmMessageTooLong
 		);
 	});
 }
@@ -582,6 +700,7 @@ fn can_process_ccms_via_swap_deposit_address() {
 			0,
 			Default::default(),
 			None,
+			None,
 		));
 		assert_ok!(Swapping::on_ccm_deposit(
 			Asset::Dot,
@@ -1010,6 +1129,7 @@ fn swap_by_deposit_happy_path() {
 			ForeignChainAddress::Eth(Default::default()),
 			bounded_vec![],
 			None,
+			None,
 			1,
 		);
 
@@ -1491,6 +1611,7 @@ fn can_handle_swaps_with_zero_outputs() {
 				eth_address.clone(),
 				bounded_vec![],
 				None,
+				None,
 				0,
 			);
 			Swapping::schedule_swap_from_channel(
@@ -1502,6 +1623,7 @@ fn can_handle_swaps_with_zero_outputs() {
 				eth_address,
 				bounded_vec![],
 				None,
+				None,
 				0,
 			);
 
@@ -1592,7 +1714,8 @@ fn swap_excess_are_confiscated_ccm_via_deposit() {
 			Some(request_ccm),
 			0,
 			Default::default(),
-			None
+			None,
+			None,
 		));
 
 		assert_ok!(Swapping::on_ccm_deposit(
@@ -1748,6 +1871,7 @@ fn swap_excess_are_confiscated_for_swap_via_deposit() {
 			ForeignChainAddress::Eth(Default::default()),
 			bounded_vec![],
 			None,
+			None,
 			0,
 		);
 
@@ -1862,6 +1986,7 @@ fn swap_input_excludes_network_fee() {
 				destination_address.clone(),
 				bounded_vec![],
 				None,
+				None,
 				0,
 			);
 		})
@@ -1971,6 +2096,7 @@ fn swap_with_custom_broker_fee(
 		ForeignChainAddress::Eth([2; 20].into()),
 		bounded_vec![Beneficiary { account: ALICE, bps: broker_fee }],
 		None,
+		None,
 		1,
 	);
 }
@@ -2075,6 +2201,7 @@ fn broker_bps_is_limited() {
 				0,
 				Default::default(),
 				None,
+				None,
 			),
 			Error::<Test>::BrokerCommissionBpsTooHigh
 		);
@@ -2247,6 +2374,7 @@ fn deposit_address_ready_event_contain_correct_boost_fee_value() {
 			BOOST_FEE,
 			Default::default(),
 			None,
+			None,
 		));
 		assert_event_sequence!(
 			Test,
@@ -2280,7 +2408,7 @@ fn test_get_scheduled_swap_legs() {
 		assert_ne!(INIT_AMOUNT, INTERMEDIATE_AMOUNT);
 
 		assert_eq!(
-			Swapping::get_scheduled_swap_legs(swaps, Asset::Flip).unwrap(),
+			Swapping::get_scheduled_swap_legs(swaps, Asset::Flip, Asset::Usdc).unwrap(),
 			vec![
 				SwapLegInfo {
 					swap_id: 1,
@@ -2290,6 +2418,7 @@ fn test_get_scheduled_swap_legs() {
 					amount: INIT_AMOUNT,
 					source_asset: None,
 					source_amount: None,
+					dca_info: None,
 				},
 				SwapLegInfo {
 					swap_id: 2,
@@ -2299,6 +2428,7 @@ fn test_get_scheduled_swap_legs() {
 					amount: INIT_AMOUNT,
 					source_asset: None,
 					source_amount: None,
+					dca_info: None,
 				},
 				SwapLegInfo {
 					swap_id: 4,
@@ -2308,6 +2438,7 @@ fn test_get_scheduled_swap_legs() {
 					amount: INIT_AMOUNT,
 					source_asset: None,
 					source_amount: None,
+					dca_info: None,
 				},
 				SwapLegInfo {
 					swap_id: 5,
@@ -2317,6 +2448,7 @@ fn test_get_scheduled_swap_legs() {
 					amount: INTERMEDIATE_AMOUNT,
 					source_asset: Some(Asset::Eth),
 					source_amount: Some(INIT_AMOUNT),
+					dca_info: None,
 				},
 			]
 		);
@@ -0,0 +1,252 @@
+use super::*;
+
+use cf_amm::common::{Price, PRICE_FRACTIONAL_BITS};
+
+const INPUT_AMOUNT: AssetAmount = 400;
+const NUMBER_OF_CHUNKS: u32 = 4;
+const CHUNK_AMOUNT: AssetAmount = INPUT_AMOUNT / NUMBER_OF_CHUNKS as u128;
+const CHUNK_INTERVAL: u32 = 2;
+// Input of all chunks but the first one.
+const REMAINING_AMOUNT: AssetAmount = INPUT_AMOUNT - CHUNK_AMOUNT;
+
+const SWAPS_ADDED_BLOCK: u64 = 1;
+const FIRST_CHUNK_BLOCK: u64 = SWAPS_ADDED_BLOCK + SWAP_DELAY_BLOCKS as u64;
+const SECOND_CHUNK_BLOCK: u64 = FIRST_CHUNK_BLOCK + CHUNK_INTERVAL as u64;
+
+fn destination_address() -> ForeignChainAddress {
+	ForeignChainAddress::Eth([9; 20].into())
+}
+
+fn schedule_dca_swap(refund_params: Option<ChannelRefundParameters>) {
+	Swapping::schedule_swap_from_channel(
+		ForeignChainAddress::Eth([2; 20].into()),
+		Default::default(),
+		Asset::Eth,
+		Asset::Usdc,
+		INPUT_AMOUNT,
+		destination_address(),
+		bounded_vec![],
+		refund_params,
+		Some(DcaParameters { number_of_chunks: NUMBER_OF_CHUNKS, chunk_interval: CHUNK_INTERVAL }),
+		1,
+	);
+}
+
+#[test]
+fn dca_swap_executes_chunks_and_egresses_once() {
+	const LAST_CHUNK_BLOCK: u64 =
+		FIRST_CHUNK_BLOCK + (CHUNK_INTERVAL * (NUMBER_OF_CHUNKS - 1)) as u64;
+
+	new_test_ext()
+		.execute_with(|| {
+			assert_eq!(System::block_number(), SWAPS_ADDED_BLOCK);
+
+			schedule_dca_swap(None);
+
+			assert_event_sequence!(
+				Test,
+				RuntimeEvent::Swapping(Event::DcaChunkScheduled {
+					swap_id: 1,
+					chunk_input: CHUNK_AMOUNT,
+					remaining_chunks: 3,
+					execute_at: FIRST_CHUNK_BLOCK,
+				}),
+				RuntimeEvent::Swapping(Event::SwapScheduled {
+					swap_id: 1,
+					deposit_amount: INPUT_AMOUNT,
+					execute_at: FIRST_CHUNK_BLOCK,
+					..
+				}),
+			);
+			assert_eq!(SwapQueue::<Test>::get(FIRST_CHUNK_BLOCK).len(), 1);
+		})
+		.then_execute_at_block(FIRST_CHUNK_BLOCK, |_| {})
+		.then_execute_with(|_| {
+			assert_event_sequence!(
+				Test,
+				RuntimeEvent::Swapping(Event::NetworkFeeTaken { swap_id: 1, .. }),
+				RuntimeEvent::Swapping(Event::SwapExecuted {
+					swap_id: 1,
+					swap_input: CHUNK_AMOUNT,
+					..
+				}),
+				RuntimeEvent::Swapping(Event::DcaChunkExecuted {
+					swap_id: 1,
+					chunk_input: CHUNK_AMOUNT,
+					chunk_output: CHUNK_AMOUNT,
+					accumulated_output_amount: CHUNK_AMOUNT,
+					remaining_chunks: 3,
+				}),
+				RuntimeEvent::Swapping(Event::DcaChunkScheduled {
+					swap_id: 1,
+					chunk_input: CHUNK_AMOUNT,
+					remaining_chunks: 2,
+					execute_at: SECOND_CHUNK_BLOCK,
+				}),
+			);
+
+			// Nothing is egressed until all chunks are executed:
+			assert!(MockEgressHandler::<AnyChain>::get_scheduled_egresses().is_empty());
+			assert_eq!(SwapQueue::<Test>::get(SECOND_CHUNK_BLOCK).len(), 1);
+			assert_eq!(
+				Swapping::get_scheduled_swap_legs(
+					SwapQueue::<Test>::get(SECOND_CHUNK_BLOCK),
+					Asset::Eth,
+					Asset::Usdc
+				)
+				.unwrap()[0]
+					.dca_info,
+				Some(DcaInfo {
+					remaining_chunks: 2,
+					chunk_interval: CHUNK_INTERVAL,
+					remaining_input_amount: REMAINING_AMOUNT - CHUNK_AMOUNT,
+					accumulated_output_amount: CHUNK_AMOUNT,
+				})
+			);
+		})
+		.then_process_blocks_until(|_| System::block_number() == LAST_CHUNK_BLOCK)
+		.then_execute_with(|_| {
+			assert_event_sequence!(
+				Test,
+				RuntimeEvent::Swapping(Event::NetworkFeeTaken { swap_id: 1, .. }),
+				RuntimeEvent::Swapping(Event::SwapExecuted { swap_id: 1, .. }),
+				RuntimeEvent::Swapping(Event::DcaChunkExecuted {
+					swap_id: 1,
+					accumulated_output_amount: INPUT_AMOUNT,
+					remaining_chunks: 0,
+					..
+				}),
+				RuntimeEvent::Swapping(Event::SwapEgressScheduled {
+					swap_id: 1,
+					amount: INPUT_AMOUNT,
+					..
+				}),
+			);
+
+			assert_eq!(
+				MockEgressHandler::<AnyChain>::get_scheduled_egresses(),
+				vec![MockEgressParameter::<AnyChain>::Swap {
+					asset: Asset::Usdc,
+					amount: INPUT_AMOUNT,
+					destination_address: destination_address(),
+					fee: 0,
+				}]
+			);
+			assert_swaps_queue_is_empty();
+			assert!(DcaStates::<Test>::get(1).is_none());
+		});
+}
+
+#[test]
+fn dca_swap_refunds_remaining_input_if_min_output_is_not_met() {
+	const REFUND_ADDRESS: ForeignChainAddress = ForeignChainAddress::Eth(H160([10; 20]));
+
+	new_test_ext()
+		.execute_with(|| {
+			schedule_dca_swap(Some(ChannelRefundParameters {
+				// Chunks are refunded as soon as they fail:
+				retry_duration: 0,
+				refund_address: REFUND_ADDRESS,
+				min_price: Price::from(1) << PRICE_FRACTIONAL_BITS,
+			}));
+		})
+		.then_execute_at_block(FIRST_CHUNK_BLOCK, |_| {})
+		.then_execute_with(|_| {
+			assert_event_sequence!(
+				Test,
+				RuntimeEvent::Swapping(Event::NetworkFeeTaken { swap_id: 1, .. }),
+				RuntimeEvent::Swapping(Event::SwapExecuted { swap_id: 1, .. }),
+				RuntimeEvent::Swapping(Event::DcaChunkExecuted { swap_id: 1, .. }),
+				RuntimeEvent::Swapping(Event::DcaChunkScheduled { swap_id: 1, .. }),
+			);
+		})
+		.then_execute_at_block(SECOND_CHUNK_BLOCK, |_| {
+			// The price drops below the minimum price:
+			SwapRate::set(0.5);
+		})
+		.then_execute_with(|_| {
+			assert_event_sequence!(
+				Test,
+				RuntimeEvent::Swapping(Event::DcaSwapAborted {
+					swap_id: 1,
+					accumulated_output_amount: CHUNK_AMOUNT,
+					unexecuted_input_amount: REMAINING_AMOUNT,
+				}),
+				RuntimeEvent::Swapping(Event::SwapEgressScheduled {
+					swap_id: 1,
+					amount: CHUNK_AMOUNT,
+					..
+				}),
+				RuntimeEvent::Swapping(Event::RefundEgressScheduled {
+					swap_id: 1,
+					amount: REMAINING_AMOUNT,
+					..
+				}),
+			);
+
+			assert_eq!(
+				MockEgressHandler::<AnyChain>::get_scheduled_egresses(),
+				vec![
+					MockEgressParameter::<AnyChain>::Swap {
+						asset: Asset::Usdc,
+						amount: CHUNK_AMOUNT,
+						destination_address: destination_address(),
+						fee: 0,
+					},
+					MockEgressParameter::<AnyChain>::Swap {
+						asset: Asset::Eth,
+						amount: REMAINING_AMOUNT,
+						destination_address: REFUND_ADDRESS,
+						fee: 0,
+					},
+				]
+			);
+			assert_swaps_queue_is_empty();
+			assert!(DcaStates::<Test>::get(1).is_none());
+		});
+}
+
+#[test]
+fn rejects_invalid_dca_parameters() {
+	new_test_ext().execute_with(|| {
+		let request_with_dca = |channel_metadata: Option<CcmChannelMetadata>,
+		                        dca_parameters: DcaParameters| {
+			Swapping::request_swap_deposit_address_with_affiliates(
+				RuntimeOrigin::signed(ALICE),
+				Asset::Eth,
+				Asset::Usdc,
+				EncodedAddress::Eth(Default::default()),
+				0,
+				channel_metadata,
+				0,
+				Default::default(),
+				None,
+				Some(dca_parameters),
+			)
+		};
+
+		for invalid_params in [
+			DcaParameters { number_of_chunks: 0, chunk_interval: 1 },
+			DcaParameters { number_of_chunks: 2, chunk_interval: 0 },
+			DcaParameters { number_of_chunks: 2, chunk_interval: MAX_DCA_DURATION_BLOCKS + 1 },
+		] {
+			assert_noop!(
+				request_with_dca(None, invalid_params),
+				Error::<Test>::InvalidDcaParameters
+			);
+		}
+
+		assert_noop!(
+			request_with_dca(
+				Some(generate_ccm_channel()),
+				DcaParameters { number_of_chunks: 2, chunk_interval: 1 }
+			),
+			Error::<Test>::DcaUnsupportedForCcm
+		);
+
+		assert_ok!(request_with_dca(
+			None,
+			DcaParameters { number_of_chunks: 2, chunk_interval: MAX_DCA_DURATION_BLOCKS }
+		));
+	});
+}
@@ -0,0 +1,226 @@
+use super::*;
+
+use crate::routing::{default_route, find_routes};
+
+const INPUT_AMOUNT: AssetAmount = 1000;
+
+const SWAPS_ADDED_BLOCK: u64 = 1;
+const SWAPS_EXECUTED_BLOCK: u64 = SWAPS_ADDED_BLOCK + SWAP_DELAY_BLOCKS as u64;
+
+fn destination_address() -> ForeignChainAddress {
+	ForeignChainAddress::Eth([9; 20].into())
+}
+
+fn schedule_btc_to_eth_swap() {
+	Swapping::schedule_swap(
+		Asset::Btc,
+		Asset::Eth,
+		INPUT_AMOUNT,
+		None,
+		SwapType::Swap(destination_address()),
+	);
+}
+
+#[test]
+fn finds_routes_through_all_pools() {
+	let pools =
+		vec![(Asset::Btc, Asset::Usdc), (Asset::Eth, Asset::Usdc), (Asset::Btc, Asset::Eth)];
+
+	assert_eq!(
+		find_routes(&pools, Asset::Btc, Asset::Eth)
+			.into_iter()
+			.sorted()
+			.collect::<Vec<_>>(),
+		vec![vec![Asset::Btc, Asset::Usdc, Asset::Eth], vec![Asset::Btc, Asset::Eth]]
+			.into_iter()
+			.sorted()
+			.collect::<Vec<_>>()
+	);
+	assert_eq!(
+		find_routes(&pools, Asset::Usdc, Asset::Eth)
+			.into_iter()
+			.sorted()
+			.collect::<Vec<_>>(),
+		vec![vec![Asset::Usdc, Asset::Eth], vec![Asset::Usdc, Asset::Btc, Asset::Eth]]
+			.into_iter()
+			.sorted()
+			.collect::<Vec<_>>()
+	);
+
+	// No routes without a pool, or to the same asset:
+	assert!(find_routes(&pools, Asset::Btc, Asset::Flip).is_empty());
+	assert!(find_routes(&pools, Asset::Btc, Asset::Btc).is_empty());
+
+	assert_eq!(default_route(Asset::Btc, Asset::Eth), vec![Asset::Btc, Asset::Usdc, Asset::Eth]);
+	assert_eq!(default_route(Asset::Usdc, Asset::Eth), vec![Asset::Usdc, Asset::Eth]);
+}
+
+#[test]
+fn routes_are_limited_in_length() {
+	let assets = [Asset::Flip, Asset::Btc, Asset::Dot, Asset::Eth, Asset::Sol];
+	let pools: Vec<_> = assets.windows(2).map(|pair| (pair[0], pair[1])).collect();
+
+	assert_eq!(
+		find_routes(&pools, Asset::Flip, Asset::Eth),
+		vec![vec![Asset::Flip, Asset::Btc, Asset::Dot, Asset::Eth]]
+	);
+	// FLIP to SOL would take one hop more than allowed:
+	assert!(find_routes(&pools, Asset::Flip, Asset::Sol).is_empty());
+}
+
+#[test]
+fn swap_is_routed_through_direct_pool_if_output_is_higher() {
+	const NETWORK_FEE: AssetAmount = INPUT_AMOUNT / 10;
+	const EXPECTED_OUTPUT: AssetAmount = (INPUT_AMOUNT - NETWORK_FEE) * 3 / 2;
+
+	new_test_ext()
+		.execute_with(|| {
+			ExtraPools::set(vec![(Asset::Btc, Asset::Eth)]);
+			PoolRates::set(vec![((Asset::Btc, Asset::Eth), 1.5)]);
+			NetworkFee::set(Percent::from_percent(10));
+
+			schedule_btc_to_eth_swap();
+		})
+		.then_execute_at_block(SWAPS_EXECUTED_BLOCK, |_| {})
+		.then_execute_with(|_| {
+			assert_event_sequence!(
+				Test,
+				RuntimeEvent::Swapping(Event::NetworkFeeTaken {
+					swap_id: 1,
+					fee_amount: NETWORK_FEE
+				}),
+				RuntimeEvent::Swapping(Event::SwapRouted { swap_id: 1, .. }),
+				RuntimeEvent::Swapping(Event::SwapScheduled {
+					swap_type: SwapType::NetworkFee,
+					deposit_amount: NETWORK_FEE,
+					..
+				}),
+				RuntimeEvent::Swapping(Event::SwapExecuted {
+					swap_id: 1,
+					swap_output: EXPECTED_OUTPUT,
+					intermediate_amount: None,
+					..
+				}),
+				RuntimeEvent::Swapping(Event::SwapEgressScheduled {
+					swap_id: 1,
+					amount: EXPECTED_OUTPUT,
+					..
+				}),
+			);
+			System::assert_has_event(RuntimeEvent::Swapping(Event::SwapRouted {
+				swap_id: 1,
+				route: vec![Asset::Btc, Asset::Eth],
+			}));
+
+			// The network fee taken in BTC is swapped into FLIP to be burned:
+			assert_eq!(
+				SwapQueue::<Test>::get(SWAPS_EXECUTED_BLOCK + SWAP_DELAY_BLOCKS as u64)
+					.into_iter()
+					.map(|swap| (swap.from, swap.to, swap.input_amount, swap.swap_type))
+					.collect::<Vec<_>>(),
+				vec![(Asset::Btc, Asset::Flip, NETWORK_FEE, SwapType::NetworkFee)]
+			);
+		});
+}
+
+#[test]
+fn swap_takes_default_route_if_direct_pool_is_worse() {
+	new_test_ext()
+		.execute_with(|| {
+			ExtraPools::set(vec![(Asset::Btc, Asset::Eth)]);
+			PoolRates::set(vec![((Asset::Btc, Asset::Eth), 0.5)]);
+
+			schedule_btc_to_eth_swap();
+		})
+		.then_execute_at_block(SWAPS_EXECUTED_BLOCK, |_| {})
+		.then_execute_with(|_| {
+			assert_event_sequence!(
+				Test,
+				RuntimeEvent::Swapping(Event::NetworkFeeTaken { swap_id: 1, .. }),
+				RuntimeEvent::Swapping(Event::SwapExecuted {
+					swap_id: 1,
+					swap_output: INPUT_AMOUNT,
+					intermediate_amount: Some(INPUT_AMOUNT),
+					..
+				}),
+				RuntimeEvent::Swapping(Event::SwapEgressScheduled {
+					swap_id: 1,
+					amount: INPUT_AMOUNT,
+					..
+				}),
+			);
+		});
+}
+
+#[test]
+fn simulated_swaps_and_swap_legs_include_route() {
+	new_test_ext().execute_with(|| {
+		ExtraPools::set(vec![(Asset::Btc, Asset::Eth)]);
+		PoolRates::set(vec![((Asset::Btc, Asset::Eth), 2.0)]);
+
+		assert_eq!(
+			Swapping::simulate_swap(Asset::Btc, Asset::Eth, INPUT_AMOUNT).unwrap(),
+			(
+				vec![Asset::Btc, Asset::Eth],
+				SwapOutput { intermediary: None, output: 2 * INPUT_AMOUNT, network_fee: 0 }
+			)
+		);
+		assert_eq!(
+			Swapping::simulate_swap(Asset::Flip, Asset::Dot, INPUT_AMOUNT).unwrap(),
+			(
+				vec![Asset::Flip, Asset::Usdc, Asset::Dot],
+				SwapOutput {
+					intermediary: Some(INPUT_AMOUNT),
+					output: INPUT_AMOUNT,
+					network_fee: 0
+				}
+			)
+		);
+
+		let swaps = vec![Swap::new(
+			1,
+			Asset::Btc,
+			Asset::Eth,
+			INPUT_AMOUNT,
+			None,
+			SwapType::Swap(destination_address()),
+		)];
+
+		// The routed swap only uses the BTC/ETH pool:
+		assert!(Swapping::get_scheduled_swap_legs(swaps.clone(), Asset::Btc, Asset::Usdc)
+			.unwrap()
+			.is_empty());
+		assert_eq!(
+			Swapping::get_scheduled_swap_legs(swaps, Asset::Btc, Asset::Eth).unwrap(),
+			vec![SwapLegInfo {
+				swap_id: 1,
+				base_asset: Asset::Btc,
+				quote_asset: Asset::Eth,
+				side: Side::Sell,
+				amount: INPUT_AMOUNT,
+				source_asset: None,
+				source_amount: None,
+				dca_info: None,
+			}]
+		);
+	});
+}
+
+#[test]
+fn estimates_swap_input_for_desired_output() {
+	new_test_ext().execute_with(|| {
+		ExtraPools::set(vec![(Asset::Btc, Asset::Eth)]);
+		PoolRates::set(vec![((Asset::Btc, Asset::Eth), 2.0)]);
+
+		assert_eq!(
+			Swapping::estimate_swap_input(Asset::Btc, Asset::Eth, 2 * INPUT_AMOUNT).unwrap(),
+			INPUT_AMOUNT
+		);
+		// Same asset and zero amounts don't need a swap:
+		assert_eq!(
+			Swapping::estimate_swap_input(Asset::Eth, Asset::Eth, INPUT_AMOUNT).unwrap(),
+			INPUT_AMOUNT
+		);
+		assert_eq!(Swapping::estimate_swap_input(Asset::Btc, Asset::Eth, 0).unwrap(), 0);
+	});
+}
@@ -4,11 +4,15 @@ use super::*;
 
 use cf_traits::{Chainflip, FeePayment};
 use frame_benchmarking::v2::*;
-use frame_support::sp_runtime::traits::UniqueSaturatedFrom;
+use frame_support::{
+	assert_ok,
+	sp_runtime::traits::UniqueSaturatedFrom,
+	traits::{EnsureOrigin, UnfilteredDispatchable},
+};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_std::collections::btree_set::BTreeSet;
 
-fn generate_proposal() -> Proposal {
+fn generate_proposal<T: Config>() -> Proposal<T> {
 	Proposal::SetGovernanceKey(ForeignChain::Ethereum, vec![1; 32])
 }
 
@@ -20,7 +24,7 @@ mod benchmarks {
 	fn on_initialize_resolve_votes(a: Linear<10, 1_000>) {
 		// a: Number of backers
 
-		let proposal = generate_proposal();
+		let proposal = generate_proposal::<T>();
 		Proposals::<T>::insert(BlockNumberFor::<T>::from(1u32), proposal.clone());
 		let backers = (0..a).map(|i| account("doogle", i, 0)).collect::<BTreeSet<_>>();
 		for account in &backers {
@@ -60,7 +64,7 @@ mod benchmarks {
 		T::FeePayment::mint_to_account(&caller, T::ProposalFee::get());
 
 		#[extrinsic_call]
-		submit_proposal(RawOrigin::Signed(whitelisted_caller()), generate_proposal());
+		submit_proposal(RawOrigin::Signed(whitelisted_caller()), generate_proposal::<T>());
 
 		assert!(Proposals::<T>::contains_key(
 			<frame_system::Pallet<T>>::block_number() + T::VotingPeriod::get()
@@ -70,7 +74,7 @@ mod benchmarks {
 	#[benchmark]
 	fn back_proposal(a: Linear<1, 1_000>) {
 		let caller: T::AccountId = whitelisted_caller();
-		let proposal = generate_proposal();
+		let proposal = generate_proposal::<T>();
 		let backers = (0..a)
 			.map(|i| account::<T::AccountId>("signers", i, 0))
 			.collect::<BTreeSet<_>>();
@@ -86,5 +90,43 @@ mod benchmarks {
 		assert!(Backers::<T>::get(proposal).contains(&caller));
 	}
 
+	#[benchmark]
+	fn update_parameter_change_voting_settings() {
+		let settings = VotingSettings {
+			voting_period: 10u32.into(),
+			approval_threshold: DEFAULT_PARAMETER_CHANGE_APPROVAL_THRESHOLD,
+			enactment_delay: 10u32.into(),
+		};
+		let call =
+			Call::<T>::update_parameter_change_voting_settings { settings: settings.clone() };
+
+		#[block]
+		{
+			assert_ok!(
+				call.dispatch_bypass_filter(T::EnsureGovernance::try_successful_origin().unwrap())
+			);
+		}
+
+		assert_eq!(ParameterChangeVotingSettings::<T>::get(), Some(settings));
+	}
+
+	#[benchmark]
+	fn veto_parameter_change() {
+		let parameter_change = T::ParameterChange::benchmark_value();
+		let proposal = Proposal::<T>::ChangeParameter(parameter_change.clone());
+		Proposals::<T>::insert(BlockNumberFor::<T>::from(1u32), proposal.clone());
+		Backers::<T>::insert(proposal.clone(), BTreeSet::from([whitelisted_caller()]));
+		let call = Call::<T>::veto_parameter_change { parameter_change };
+
+		#[block]
+		{
+			assert_ok!(
+				call.dispatch_bypass_filter(T::EnsureGovernance::try_successful_origin().unwrap())
+			);
+		}
+
+		assert!(!Backers::<T>::contains_key(proposal));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test,);
 }
@@ -1,8 +1,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-use cf_chains::{eth::Address, ForeignChain};
+use cf_chains::{benchmarking_value::BenchmarkValue, eth::Address, ForeignChain};
 use cf_traits::{BroadcastAnyChainGovKey, Chainflip, CommKeyBroadcaster, FeePayment, FundingInfo};
 use codec::{Decode, Encode};
-use frame_support::{pallet_prelude::*, traits::StorageVersion, RuntimeDebugNoBound};
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::{traits::Zero, Percent},
+	traits::StorageVersion,
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use sp_std::{cmp::PartialEq, vec, vec::Vec};
 
 pub use pallet::*;
@@ -17,13 +22,37 @@ mod tests;
 pub mod weights;
 pub use weights::WeightInfo;
 
-#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebugNoBound)]
+#[derive(
+	CloneNoBound, PartialEqNoBound, EqNoBound, Encode, Decode, TypeInfo, RuntimeDebugNoBound,
+)]
 #[scale_info(skip_type_params(T))]
-pub enum Proposal {
+pub enum Proposal<T: Config> {
 	SetGovernanceKey(ForeignChain, Vec<u8>),
 	SetCommunityKey(Address),
+	ChangeParameter(T::ParameterChange),
 }
 
+/// A whitelisted parameter change that tokenholders can propose. The runtime defines which
+/// parameters can be changed, and how each change is applied.
+pub trait ParameterChange: Member + Parameter + BenchmarkValue {
+	/// Applies the parameter change.
+	fn enact(self) -> DispatchResult;
+}
+
+/// Voting rules for parameter change proposals.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct VotingSettings<BlockNumber> {
+	/// The number of blocks a proposal is open for backing.
+	pub voting_period: BlockNumber,
+	/// The proportion of all on-chain funds that must back a proposal for it to pass.
+	pub approval_threshold: Percent,
+	/// The number of blocks between a proposal passing and being enacted.
+	pub enactment_delay: BlockNumber,
+}
+
+/// The default approval threshold for parameter change proposals.
+pub const DEFAULT_PARAMETER_CHANGE_APPROVAL_THRESHOLD: Percent = Percent::from_percent(50);
+
 pub const PALLET_VERSION: StorageVersion = StorageVersion::new(1);
 
 #[frame_support::pallet]
@@ -59,19 +88,21 @@ pub mod pallet {
 		/// Delay in blocks after a successfully backed proposal gets executed.
 		#[pallet::constant]
 		type EnactmentDelay: Get<BlockNumberFor<Self>>;
+		/// The parameter changes that tokenholders can propose.
+		type ParameterChange: ParameterChange;
 	}
 
 	/// All unresolved proposals that are open for backing, indexed by the block at which the vote
 	/// will be resolved.
 	#[pallet::storage]
 	#[pallet::getter(fn proposals)]
-	pub type Proposals<T: Config> = StorageMap<_, Twox64Concat, BlockNumberFor<T>, Proposal>;
+	pub type Proposals<T: Config> = StorageMap<_, Twox64Concat, BlockNumberFor<T>, Proposal<T>>;
 
 	/// The accounts currently backing each proposal.
 	#[pallet::storage]
 	#[pallet::getter(fn backers)]
 	pub type Backers<T: Config> =
-		StorageMap<_, Twox64Concat, Proposal, BTreeSet<T::AccountId>, ValueQuery>;
+		StorageMap<_, Twox64Concat, Proposal<T>, BTreeSet<T::AccountId>, ValueQuery>;
 
 	/// The Government key proposal currently awaiting enactment, if any. Indexed by the block
 	/// number we will attempt to enact this update.
@@ -89,21 +120,39 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type GovKeys<T> = StorageMap<_, Twox64Concat, ForeignChain, Vec<u8>>;
 
+	/// Voting rules for parameter change proposals. If unset, the voting period and enactment
+	/// delay default to those of the key proposals.
+	#[pallet::storage]
+	pub type ParameterChangeVotingSettings<T> =
+		StorageValue<_, VotingSettings<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Parameter changes that have passed and are awaiting enactment, indexed by the block number
+	/// at which they will be enacted.
+	#[pallet::storage]
+	pub type ParameterChangesAwaitingEnactment<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<T::ParameterChange>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A proposal has been submitted.
-		ProposalSubmitted { proposal: Proposal },
+		ProposalSubmitted { proposal: Proposal<T> },
 		/// A proposal has passed.
-		ProposalPassed { proposal: Proposal },
+		ProposalPassed { proposal: Proposal<T> },
 		/// A proposal was rejected.
-		ProposalRejected { proposal: Proposal },
+		ProposalRejected { proposal: Proposal<T> },
 		/// A proposal was enacted.
-		ProposalEnacted { proposal: Proposal },
+		ProposalEnacted { proposal: Proposal<T> },
 		/// Update of GOV key has failed.
 		GovKeyUpdatedHasFailed { chain: ForeignChain, key: Vec<u8> },
 		/// Update of GOV key was successful.
 		GovKeyUpdatedWasSuccessful { chain: ForeignChain, key: Vec<u8> },
+		/// A parameter change passed, but could not be applied.
+		ParameterChangeFailed { parameter_change: T::ParameterChange, error: DispatchError },
+		/// A parameter change proposal was vetoed by governance.
+		ParameterChangeVetoed { parameter_change: T::ParameterChange },
+		/// The voting rules for parameter change proposals have been updated.
+		ParameterChangeVotingSettingsUpdated { settings: VotingSettings<BlockNumberFor<T>> },
 	}
 
 	#[pallet::error]
@@ -114,6 +163,10 @@ pub mod pallet {
 		ProposalDoesntExist,
 		/// The proposed governance key is incompatible with the proposed chain.
 		IncompatibleGovkey,
+		/// Another proposal is already due to be resolved in the same block.
+		ResolutionBlockOccupied,
+		/// The voting period must be greater than zero.
+		InvalidVotingSettings,
 	}
 
 	#[pallet::hooks]
@@ -154,6 +207,18 @@ pub mod pallet {
 					weight.saturating_accrue(T::WeightInfo::on_initialize_execute_proposal());
 				}
 			}
+			for parameter_change in ParameterChangesAwaitingEnactment::<T>::take(current_block) {
+				match parameter_change.clone().enact() {
+					Ok(()) => Self::deposit_event(Event::<T>::ProposalEnacted {
+						proposal: Proposal::ChangeParameter(parameter_change),
+					}),
+					Err(error) => Self::deposit_event(Event::<T>::ParameterChangeFailed {
+						parameter_change,
+						error,
+					}),
+				}
+				weight.saturating_accrue(T::WeightInfo::on_initialize_execute_proposal());
+			}
 			if let Some((enactment_block, new_key)) = CommKeyUpdateAwaitingEnactment::<T>::get() {
 				if enactment_block == current_block {
 					T::CommKeyBroadcaster::broadcast(new_key);
@@ -185,7 +250,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::submit_proposal())]
 		pub fn submit_proposal(
 			origin: OriginFor<T>,
-			proposal: Proposal,
+			proposal: Proposal<T>,
 		) -> DispatchResultWithPostInfo {
 			let proposer = ensure_signed(origin)?;
 			if let Proposal::SetGovernanceKey(chain, ref key) = proposal {
@@ -194,11 +259,18 @@ pub mod pallet {
 					Error::<T>::IncompatibleGovkey
 				);
 			}
-			T::FeePayment::try_burn_fee(&proposer, T::ProposalFee::get())?;
-			Proposals::<T>::insert(
-				<frame_system::Pallet<T>>::block_number() + T::VotingPeriod::get(),
-				proposal.clone(),
+			let voting_period = match proposal {
+				Proposal::ChangeParameter(_) =>
+					Self::parameter_change_voting_settings().voting_period,
+				_ => T::VotingPeriod::get(),
+			};
+			let resolution_block = <frame_system::Pallet<T>>::block_number() + voting_period;
+			ensure!(
+				!Proposals::<T>::contains_key(resolution_block),
+				Error::<T>::ResolutionBlockOccupied
 			);
+			T::FeePayment::try_burn_fee(&proposer, T::ProposalFee::get())?;
+			Proposals::<T>::insert(resolution_block, proposal.clone());
 			Backers::<T>::insert(proposal.clone(), BTreeSet::from([proposer]));
 			Self::deposit_event(Event::<T>::ProposalSubmitted { proposal });
 			Ok(().into())
@@ -215,7 +287,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::back_proposal(Backers::<T>::decode_non_dedup_len(proposal).unwrap_or_default() as u32))]
 		pub fn back_proposal(
 			origin: OriginFor<T>,
-			proposal: Proposal,
+			proposal: Proposal<T>,
 		) -> DispatchResultWithPostInfo {
 			let backer = ensure_signed(origin)?;
 			Backers::<T>::try_mutate_exists(proposal, |maybe_backers| match maybe_backers {
@@ -229,33 +301,140 @@ pub mod pallet {
 			})?;
 			Ok(().into())
 		}
+
+		/// Updates the voting rules for parameter change proposals. Proposals that are already
+		/// open for backing keep their original voting period.
+		///
+		/// Requires Governance.
+		///
+		/// ## Events
+		///
+		/// - [ParameterChangeVotingSettingsUpdated](Event::ParameterChangeVotingSettingsUpdated)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::BadOrigin)
+		/// - [InvalidVotingSettings](Error::InvalidVotingSettings)
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::update_parameter_change_voting_settings())]
+		pub fn update_parameter_change_voting_settings(
+			origin: OriginFor<T>,
+			settings: VotingSettings<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+			ensure!(!settings.voting_period.is_zero(), Error::<T>::InvalidVotingSettings);
+			ParameterChangeVotingSettings::<T>::put(settings.clone());
+			Self::deposit_event(Event::<T>::ParameterChangeVotingSettingsUpdated { settings });
+			Ok(())
+		}
+
+		/// Vetoes a parameter change, either while it is open for backing or while it is awaiting
+		/// enactment.
+		///
+		/// Requires Governance.
+		///
+		/// ## Events
+		///
+		/// - [ParameterChangeVetoed](Event::ParameterChangeVetoed)
+		///
+		/// ## Errors
+		///
+		/// - [BadOrigin](frame_system::BadOrigin)
+		/// - [ProposalDoesntExist](Error::ProposalDoesntExist)
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::veto_parameter_change())]
+		pub fn veto_parameter_change(
+			origin: OriginFor<T>,
+			parameter_change: T::ParameterChange,
+		) -> DispatchResult {
+			T::EnsureGovernance::ensure_origin(origin)?;
+
+			let proposal = Proposal::<T>::ChangeParameter(parameter_change.clone());
+			let mut vetoed = false;
+
+			if Backers::<T>::contains_key(&proposal) {
+				Backers::<T>::remove(&proposal);
+				let resolution_blocks = Proposals::<T>::iter()
+					.filter_map(|(block, pending)| (pending == proposal).then_some(block))
+					.collect::<Vec<_>>();
+				for block in resolution_blocks {
+					Proposals::<T>::remove(block);
+				}
+				vetoed = true;
+			}
+
+			let enactment_blocks = ParameterChangesAwaitingEnactment::<T>::iter()
+				.filter_map(|(block, changes)| changes.contains(&parameter_change).then_some(block))
+				.collect::<Vec<_>>();
+			for block in enactment_blocks {
+				ParameterChangesAwaitingEnactment::<T>::mutate_exists(block, |maybe_changes| {
+					if let Some(changes) = maybe_changes {
+						changes.retain(|change| *change != parameter_change);
+						if changes.is_empty() {
+							*maybe_changes = None;
+						}
+					}
+				});
+				vetoed = true;
+			}
+
+			ensure!(vetoed, Error::<T>::ProposalDoesntExist);
+			Self::deposit_event(Event::<T>::ParameterChangeVetoed { parameter_change });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
-		pub fn resolve_vote(proposal: Proposal) -> usize {
+		/// The current voting rules for parameter change proposals.
+		pub fn parameter_change_voting_settings() -> VotingSettings<BlockNumberFor<T>> {
+			ParameterChangeVotingSettings::<T>::get().unwrap_or_else(|| VotingSettings {
+				voting_period: T::VotingPeriod::get(),
+				approval_threshold: DEFAULT_PARAMETER_CHANGE_APPROVAL_THRESHOLD,
+				enactment_delay: T::EnactmentDelay::get(),
+			})
+		}
+
+		pub fn resolve_vote(proposal: Proposal<T>) -> usize {
 			let backers = Backers::<T>::take(&proposal);
-			Self::deposit_event(
-				if backers.iter().map(T::FundingInfo::total_balance_of).sum::<T::Amount>() >
-					(T::FundingInfo::total_onchain_funds() / 3u32.into()) * 2u32.into()
-				{
-					let enactment_block =
-						<frame_system::Pallet<T>>::block_number() + T::EnactmentDelay::get();
-					match proposal.clone() {
-						Proposal::SetGovernanceKey(chain, key) => {
-							GovKeyUpdateAwaitingEnactment::<T>::put::<(
-								BlockNumberFor<T>,
-								(cf_chains::ForeignChain, Vec<u8>),
-							)>((enactment_block, (chain, key)));
-						},
-						Proposal::SetCommunityKey(key) => {
-							CommKeyUpdateAwaitingEnactment::<T>::put((enactment_block, key));
-						},
-					}
-					Event::<T>::ProposalPassed { proposal }
-				} else {
-					Event::<T>::ProposalRejected { proposal }
+			let backed_funds =
+				backers.iter().map(T::FundingInfo::total_balance_of).sum::<T::Amount>();
+			let total_funds = T::FundingInfo::total_onchain_funds();
+			let (passed, enactment_delay) = match proposal {
+				Proposal::ChangeParameter(_) => {
+					let settings = Self::parameter_change_voting_settings();
+					(
+						backed_funds > settings.approval_threshold * total_funds,
+						settings.enactment_delay,
+					)
 				},
-			);
+				_ => (
+					backed_funds > (total_funds / 3u32.into()) * 2u32.into(),
+					T::EnactmentDelay::get(),
+				),
+			};
+			Self::deposit_event(if passed {
+				let enactment_block = <frame_system::Pallet<T>>::block_number() + enactment_delay;
+				match proposal.clone() {
+					Proposal::SetGovernanceKey(chain, key) => {
+						GovKeyUpdateAwaitingEnactment::<T>::put::<(
+							BlockNumberFor<T>,
+							(cf_chains::ForeignChain, Vec<u8>),
+						)>((enactment_block, (chain, key)));
+					},
+					Proposal::SetCommunityKey(key) => {
+						CommKeyUpdateAwaitingEnactment::<T>::put((enactment_block, key));
+					},
+					Proposal::ChangeParameter(parameter_change) => {
+						ParameterChangesAwaitingEnactment::<T>::append(
+							enactment_block,
+							parameter_change,
+						);
+					},
+				}
+				Event::<T>::ProposalPassed { proposal }
+			} else {
+				Event::<T>::ProposalRejected { proposal }
+			});
 			backers.len()
 		}
 	}
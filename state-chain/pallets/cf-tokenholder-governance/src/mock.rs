@@ -6,8 +6,11 @@ use cf_traits::{
 	CommKeyBroadcaster, WaivedFees,
 };
 use codec::{Decode, Encode};
-use frame_support::{derive_impl, parameter_types, traits::HandleLifetime};
+use frame_support::{
+	derive_impl, pallet_prelude::DispatchResult, parameter_types, traits::HandleLifetime,
+};
 use frame_system as system;
+use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::traits::{BlakeTwo256, IdentityLookup};
 
@@ -135,6 +138,38 @@ impl CommKeyBroadcaster for MockBroadcaster {
 	}
 }
 
+/// A parameter change that records its value when enacted. The value `FAILING_PARAMETER` fails.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct MockParameterChange(pub u32);
+
+pub const FAILING_PARAMETER: u32 = u32::MAX;
+
+impl MockParameterChange {
+	pub fn enacted() -> Vec<u32> {
+		EnactedParameters::get().unwrap_or_default()
+	}
+}
+
+#[frame_support::storage_alias]
+type EnactedParameters = StorageValue<Mock, Vec<u32>>;
+
+impl pallet_cf_tokenholder_governance::ParameterChange for MockParameterChange {
+	fn enact(self) -> DispatchResult {
+		if self.0 == FAILING_PARAMETER {
+			return Err(sp_runtime::DispatchError::Other("Mock parameter change failed"))
+		}
+		EnactedParameters::append(self.0);
+		Ok(())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl cf_chains::benchmarking_value::BenchmarkValue for MockParameterChange {
+	fn benchmark_value() -> Self {
+		Self(1)
+	}
+}
+
 impl pallet_cf_tokenholder_governance::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type FeePayment = MockFeePayment<Self>;
@@ -144,6 +179,7 @@ impl pallet_cf_tokenholder_governance::Config for Test {
 	type VotingPeriod = VotingPeriod;
 	type EnactmentDelay = EnactmentDelay;
 	type ProposalFee = ProposalFee;
+	type ParameterChange = MockParameterChange;
 }
 
 // Accounts
@@ -14,7 +14,7 @@ fn awaiting_gov_key() -> Vec<u8> {
 	awaiting_key
 }
 
-fn submit_and_pass_proposal(proposal: Proposal) {
+fn submit_and_pass_proposal(proposal: Proposal<Test>) {
 	assert_ok!(TokenholderGovernance::submit_proposal(
 		RuntimeOrigin::signed(ALICE),
 		proposal.clone()
@@ -190,7 +190,7 @@ fn replace_proposal_during_enactment_period() {
 	new_test_ext().execute_with(|| {
 		let proposed_key_1 = vec![1; 32];
 		let proposed_key_2 = vec![2; 32];
-		fn create_and_back_proposal(proposal: Proposal) {
+		fn create_and_back_proposal(proposal: Proposal<Test>) {
 			assert_ok!(TokenholderGovernance::submit_proposal(
 				RuntimeOrigin::signed(ALICE),
 				proposal.clone()
@@ -255,3 +255,187 @@ fn govkey_broadcast_to_correct_chain() {
 		);
 	});
 }
+
+mod parameter_changes {
+	use super::*;
+
+	const PARAMETER: u32 = 42;
+
+	fn settings() -> VotingSettings<u64> {
+		VotingSettings {
+			voting_period: 5,
+			approval_threshold: Percent::from_percent(50),
+			enactment_delay: 7,
+		}
+	}
+
+	fn submit_and_back(parameter: u32) -> Proposal<Test> {
+		let proposal = Proposal::ChangeParameter(MockParameterChange(parameter));
+		assert_ok!(TokenholderGovernance::submit_proposal(
+			RuntimeOrigin::signed(ALICE),
+			proposal.clone()
+		));
+		assert_ok!(TokenholderGovernance::back_proposal(
+			RuntimeOrigin::signed(BOB),
+			proposal.clone()
+		));
+		proposal
+	}
+
+	#[test]
+	fn parameter_change_passes_with_configured_threshold_and_delay() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(TokenholderGovernance::update_parameter_change_voting_settings(
+				RuntimeOrigin::root(),
+				settings()
+			));
+			go_to_block(1);
+			let proposal = submit_and_back(PARAMETER);
+
+			// ALICE and BOB hold more than half, but less than the two thirds needed for keys.
+			go_to_block(1 + settings().voting_period);
+			assert_eq!(
+				last_event::<Test>(),
+				RuntimeEvent::TokenholderGovernance(Event::ProposalPassed {
+					proposal: proposal.clone()
+				}),
+			);
+
+			let enactment_block = 1 + settings().voting_period + settings().enactment_delay;
+			go_to_block(enactment_block - 1);
+			assert!(MockParameterChange::enacted().is_empty());
+
+			go_to_block(enactment_block);
+			assert_eq!(MockParameterChange::enacted(), vec![PARAMETER]);
+			assert!(!ParameterChangesAwaitingEnactment::<Test>::contains_key(enactment_block));
+			assert_eq!(
+				last_event::<Test>(),
+				RuntimeEvent::TokenholderGovernance(Event::ProposalEnacted { proposal }),
+			);
+		});
+	}
+
+	#[test]
+	fn parameter_change_below_threshold_is_rejected() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(TokenholderGovernance::update_parameter_change_voting_settings(
+				RuntimeOrigin::root(),
+				VotingSettings { approval_threshold: Percent::from_percent(70), ..settings() }
+			));
+			go_to_block(1);
+			let proposal = submit_and_back(PARAMETER);
+
+			go_to_block(1 + settings().voting_period);
+			assert_eq!(
+				last_event::<Test>(),
+				RuntimeEvent::TokenholderGovernance(Event::ProposalRejected { proposal }),
+			);
+			assert_eq!(ParameterChangesAwaitingEnactment::<Test>::iter().count(), 0);
+		});
+	}
+
+	#[test]
+	fn failed_parameter_change_emits_event() {
+		new_test_ext().execute_with(|| {
+			ParameterChangesAwaitingEnactment::<Test>::append(
+				1,
+				MockParameterChange(FAILING_PARAMETER),
+			);
+			go_to_block(1);
+			assert!(MockParameterChange::enacted().is_empty());
+			assert!(matches!(
+				last_event::<Test>(),
+				RuntimeEvent::TokenholderGovernance(Event::ParameterChangeFailed {
+					parameter_change: MockParameterChange(FAILING_PARAMETER),
+					..
+				})
+			));
+		});
+	}
+
+	#[test]
+	fn governance_can_veto_parameter_change() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(TokenholderGovernance::update_parameter_change_voting_settings(
+				RuntimeOrigin::root(),
+				settings()
+			));
+
+			// Veto while the proposal is open for backing.
+			go_to_block(1);
+			let proposal = submit_and_back(PARAMETER);
+			assert_ok!(TokenholderGovernance::veto_parameter_change(
+				RuntimeOrigin::root(),
+				MockParameterChange(PARAMETER)
+			));
+			assert!(!Backers::<Test>::contains_key(&proposal));
+			assert!(!Proposals::<Test>::contains_key(1 + settings().voting_period));
+			assert_eq!(
+				last_event::<Test>(),
+				RuntimeEvent::TokenholderGovernance(Event::ParameterChangeVetoed {
+					parameter_change: MockParameterChange(PARAMETER)
+				}),
+			);
+
+			// Veto while the proposal is awaiting enactment.
+			go_to_block(2);
+			submit_and_back(PARAMETER);
+			go_to_block(2 + settings().voting_period);
+			assert_ok!(TokenholderGovernance::veto_parameter_change(
+				RuntimeOrigin::root(),
+				MockParameterChange(PARAMETER)
+			));
+			go_to_block(2 + settings().voting_period + settings().enactment_delay);
+			assert!(MockParameterChange::enacted().is_empty());
+
+			assert_noop!(
+				TokenholderGovernance::veto_parameter_change(
+					RuntimeOrigin::root(),
+					MockParameterChange(PARAMETER)
+				),
+				Error::<Test>::ProposalDoesntExist
+			);
+		});
+	}
+
+	#[test]
+	fn governance_can_update_voting_settings() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(
+				TokenholderGovernance::parameter_change_voting_settings(),
+				VotingSettings {
+					voting_period: VotingPeriod::get(),
+					approval_threshold: DEFAULT_PARAMETER_CHANGE_APPROVAL_THRESHOLD,
+					enactment_delay: EnactmentDelay::get(),
+				}
+			);
+			assert_noop!(
+				TokenholderGovernance::update_parameter_change_voting_settings(
+					RuntimeOrigin::root(),
+					VotingSettings { voting_period: 0, ..settings() }
+				),
+				Error::<Test>::InvalidVotingSettings
+			);
+			assert_ok!(TokenholderGovernance::update_parameter_change_voting_settings(
+				RuntimeOrigin::root(),
+				settings()
+			));
+			assert_eq!(TokenholderGovernance::parameter_change_voting_settings(), settings());
+		});
+	}
+
+	#[test]
+	fn cannot_resolve_two_proposals_in_the_same_block() {
+		new_test_ext().execute_with(|| {
+			go_to_block(1);
+			submit_and_back(PARAMETER);
+			assert_noop!(
+				TokenholderGovernance::submit_proposal(
+					RuntimeOrigin::signed(ALICE),
+					Proposal::SetCommunityKey(sp_core::H160::from_slice(&[1; 20]))
+				),
+				Error::<Test>::ResolutionBlockOccupied
+			);
+		});
+	}
+}
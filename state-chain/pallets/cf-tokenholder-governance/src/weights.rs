@@ -36,6 +36,8 @@ pub trait WeightInfo {
 	fn on_initialize_execute_proposal() -> Weight;
 	fn submit_proposal() -> Weight;
 	fn back_proposal(a: u32, ) -> Weight;
+	fn update_parameter_change_voting_settings() -> Weight;
+	fn veto_parameter_change() -> Weight;
 }
 
 /// Weights for pallet_cf_tokenholder_governance using the Substrate node and recommended hardware.
@@ -154,6 +156,31 @@ impl<T: frame_system::Config> WeightInfo for PalletWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(a.into()))
 	}
+	/// Storage: `TokenholderGovernance::ParameterChangeVotingSettings` (r:0 w:1)
+	/// Proof: `TokenholderGovernance::ParameterChangeVotingSettings` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	fn update_parameter_change_voting_settings() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenholderGovernance::Backers` (r:1 w:1)
+	/// Proof: `TokenholderGovernance::Backers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TokenholderGovernance::Proposals` (r:2 w:1)
+	/// Proof: `TokenholderGovernance::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TokenholderGovernance::ParameterChangesAwaitingEnactment` (r:1 w:0)
+	/// Proof: `TokenholderGovernance::ParameterChangesAwaitingEnactment` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn veto_parameter_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `241`
+		//  Estimated: `6181`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 6181)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -271,4 +298,29 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(a.into()))
 	}
+	/// Storage: `TokenholderGovernance::ParameterChangeVotingSettings` (r:0 w:1)
+	/// Proof: `TokenholderGovernance::ParameterChangeVotingSettings` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	fn update_parameter_change_voting_settings() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TokenholderGovernance::Backers` (r:1 w:1)
+	/// Proof: `TokenholderGovernance::Backers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TokenholderGovernance::Proposals` (r:2 w:1)
+	/// Proof: `TokenholderGovernance::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `TokenholderGovernance::ParameterChangesAwaitingEnactment` (r:1 w:0)
+	/// Proof: `TokenholderGovernance::ParameterChangesAwaitingEnactment` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn veto_parameter_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `241`
+		//  Estimated: `6181`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 6181)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
@@ -23,7 +23,7 @@ use cf_primitives::{
 };
 use cf_traits::{
 	impl_pallet_safe_mode, offence_reporting::OffenceReporter, AccountInfo, AsyncResult,
-	AuthoritiesCfeVersions, Bid, Bonding, CfePeerRegistration, Chainflip, EpochInfo,
+	AuthoritiesCfeVersions, Bid, Bonding, CfePeerRegistration, Chainflip, DelegationApi, EpochInfo,
 	EpochTransitionHandler, ExecutionCondition, FundingInfo, HistoricalEpoch, KeyRotator,
 	MissedAuthorshipSlots, OnAccountFunded, QualifyNode, RedemptionCheck, ReputationResetter,
 	SetSafeMode,
@@ -150,6 +150,9 @@ pub mod pallet {
 		/// Updates the bond of an authority.
 		type Bonder: Bonding<ValidatorId = ValidatorIdOf<Self>, Amount = Self::Amount>;
 
+		/// Provides the FLIP delegated to operators, which counts towards their auction bids.
+		type Delegation: DelegationApi<AccountId = Self::AccountId, Amount = Self::Amount>;
+
 		/// This is used to reset the validator's reputation
 		type ReputationResetter: ReputationResetter<ValidatorId = ValidatorIdOf<Self>>;
 
@@ -1032,7 +1035,7 @@ impl<T: Config> Pallet<T> {
 			if EpochHistory::<T>::number_of_active_epochs_for_authority(authority) == 0 {
 				T::ReputationResetter::reset_reputation(authority);
 			}
-			T::Bonder::update_bond(authority, EpochHistory::<T>::active_bond(authority));
+			Self::update_bond(authority);
 		}
 		T::EpochTransitionHandler::on_expired_epoch(epoch);
 		T::ValidatorWeightInfo::expire_epoch(num_expired_authorities)
@@ -1058,7 +1061,7 @@ impl<T: Config> Pallet<T> {
 		new_authorities.iter().enumerate().for_each(|(index, account_id)| {
 			AuthorityIndex::<T>::insert(new_epoch, account_id, index as AuthorityCount);
 			EpochHistory::<T>::activate_epoch(account_id, new_epoch);
-			T::Bonder::update_bond(account_id, EpochHistory::<T>::active_bond(account_id));
+			Self::update_bond(account_id);
 		});
 
 		CurrentEpochStartedAt::<T>::set(frame_system::Pallet::<T>::current_block_number());
@@ -1301,11 +1304,24 @@ impl<T: Config> Pallet<T> {
 			.into_iter()
 			.map(|bidder_id| Bid {
 				bidder_id: <ValidatorIdOf<T> as IsType<T::AccountId>>::from_ref(&bidder_id).clone(),
-				amount: T::FundingInfo::balance(&bidder_id),
+				// Funds delegated to another operator back that operator's bid instead.
+				amount: T::FundingInfo::balance(&bidder_id)
+					.saturating_sub(T::Delegation::delegated_by(&bidder_id))
+					.saturating_add(T::Delegation::delegated_to(&bidder_id)),
 			})
 			.collect()
 	}
 
+	/// Funds delegated to an authority count towards its bid but are not bonded, otherwise the
+	/// bond could exceed the authority's own balance and it could never be slashed.
+	fn update_bond(authority: &ValidatorIdOf<T>) {
+		T::Bonder::update_bond(
+			authority,
+			EpochHistory::<T>::active_bond(authority)
+				.saturating_sub(T::Delegation::delegated_to(authority.into_ref())),
+		);
+	}
+
 	pub fn get_qualified_bidders<Q: QualifyNode<ValidatorIdOf<T>>>(
 	) -> Vec<Bid<ValidatorIdOf<T>, T::Amount>> {
 		Self::get_active_bids()
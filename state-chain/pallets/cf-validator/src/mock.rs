@@ -7,8 +7,9 @@ use cf_primitives::FlipBalance;
 use cf_traits::{
 	impl_mock_chainflip, impl_mock_runtime_safe_mode,
 	mocks::{
-		cfe_interface_mock::MockCfeInterface, key_rotator::MockKeyRotatorA,
-		qualify_node::QualifyAll, reputation_resetter::MockReputationResetter,
+		cfe_interface_mock::MockCfeInterface, delegation::MockDelegation,
+		key_rotator::MockKeyRotatorA, qualify_node::QualifyAll,
+		reputation_resetter::MockReputationResetter,
 	},
 	AccountRoleRegistry,
 };
@@ -172,6 +173,7 @@ impl Config for Test {
 	type MissedAuthorshipSlots = MockMissedAuthorshipSlots;
 	type OffenceReporter = MockOffenceReporter;
 	type Bonder = MockBonder;
+	type Delegation = MockDelegation<Self>;
 	type ReputationResetter = MockReputationResetter<Self>;
 	type KeygenQualification = QualifyAll<ValidatorId>;
 	type SafeMode = MockRuntimeSafeMode;
@@ -5,7 +5,10 @@ use core::ops::Range;
 use crate::{mock::*, Error, *};
 use cf_test_utilities::{assert_event_sequence, last_event};
 use cf_traits::{
-	mocks::{key_rotator::MockKeyRotatorA, reputation_resetter::MockReputationResetter},
+	mocks::{
+		delegation::MockDelegation, key_rotator::MockKeyRotatorA,
+		reputation_resetter::MockReputationResetter,
+	},
 	AccountRoleRegistry, SafeMode, SetSafeMode,
 };
 use cf_utilities::success_threshold_from_share_count;
@@ -654,6 +657,26 @@ mod bond_expiry {
 			assert_eq!(EpochHistory::<Test>::active_bond(&3), 99);
 		});
 	}
+
+	#[test]
+	fn delegated_funds_are_not_bonded() {
+		new_test_ext().execute_with(|| {
+			const BOND: u128 = 100;
+			const DELEGATED: u128 = 60;
+			MockDelegation::<Test>::set_delegated_to(&1, DELEGATED);
+
+			ValidatorPallet::transition_to_next_epoch(vec![1, 2], BOND);
+
+			// Only the operator's own share of the bond is locked.
+			assert_eq!(MockBonder::get_bond(&1), BOND - DELEGATED);
+			assert_eq!(MockBonder::get_bond(&2), BOND);
+
+			// Without delegations, the operator's own funds back the full bond.
+			MockDelegation::<Test>::set_delegated_to(&1, 0);
+			ValidatorPallet::transition_to_next_epoch(vec![1, 2], BOND);
+			assert_eq!(MockBonder::get_bond(&1), BOND);
+		});
+	}
 }
 
 #[test]
@@ -1257,6 +1280,25 @@ fn test_start_and_stop_bidding() {
 	});
 }
 
+#[test]
+fn delegated_funds_count_towards_the_operator_bid() {
+	new_test_ext().execute_with(|| {
+		const OWN_FUNDS: u128 = 100;
+		const DELEGATED: u128 = 60;
+
+		add_bids(vec![Bid { bidder_id: ALICE, amount: OWN_FUNDS }]);
+		add_bids(vec![Bid { bidder_id: BOB, amount: OWN_FUNDS }]);
+
+		MockDelegation::<Test>::set_delegated_to(&ALICE, DELEGATED);
+		// Funds that BOB has delegated elsewhere no longer back BOB's own bid.
+		MockDelegation::<Test>::set_delegated_by(&BOB, DELEGATED);
+
+		let bids = ValidatorPallet::get_active_bids();
+		assert!(bids.contains(&Bid { bidder_id: ALICE, amount: OWN_FUNDS + DELEGATED }));
+		assert!(bids.contains(&Bid { bidder_id: BOB, amount: OWN_FUNDS - DELEGATED }));
+	});
+}
+
 #[test]
 fn can_determine_is_auction_phase() {
 	new_test_ext().execute_with(|| {
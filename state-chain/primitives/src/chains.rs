@@ -92,7 +92,8 @@ chains! {
 	Polkadot = 2,
 	Bitcoin = 3,
 	Arbitrum = 4,
-	Solana = 5
+	Solana = 5,
+	Assethub = 6
 }
 
 /// Can be any Chain.
@@ -119,15 +120,31 @@ impl ForeignChain {
 			ForeignChain::Bitcoin => assets::any::Asset::Btc,
 			ForeignChain::Arbitrum => assets::any::Asset::ArbEth,
 			ForeignChain::Solana => assets::any::Asset::Sol,
+			ForeignChain::Assethub => assets::any::Asset::HubDot,
 		}
 	}
 	pub const fn ccm_support(self) -> bool {
 		match self {
 			ForeignChain::Ethereum => true,
-			ForeignChain::Polkadot => false,
-			ForeignChain::Bitcoin => false,
+			ForeignChain::Polkadot => true,
+			ForeignChain::Bitcoin => true,
 			ForeignChain::Arbitrum => true,
 			ForeignChain::Solana => true,
+			ForeignChain::Assethub => false,
+		}
+	}
+	/// The maximum length in bytes of a CCM message that can be delivered to this chain.
+	///
+	/// Bitcoin messages are delivered in an OP_RETURN output, which is limited to 80 bytes by the
+	/// standard relay policy. Polkadot messages are delivered as a `system.remark_with_event`.
+	pub const fn ccm_max_message_length(self) -> u32 {
+		match self {
+			ForeignChain::Ethereum => 10_000,
+			ForeignChain::Polkadot => 2_048,
+			ForeignChain::Bitcoin => 80,
+			ForeignChain::Arbitrum => 10_000,
+			ForeignChain::Solana => 10_000,
+			ForeignChain::Assethub => 0,
 		}
 	}
 }
@@ -139,6 +156,7 @@ fn chain_as_u32() {
 	assert_eq!(ForeignChain::Bitcoin as u32, 3);
 	assert_eq!(ForeignChain::Arbitrum as u32, 4);
 	assert_eq!(ForeignChain::Solana as u32, 5);
+	assert_eq!(ForeignChain::Assethub as u32, 6);
 }
 
 #[test]
@@ -148,7 +166,8 @@ fn chain_id_to_chain() {
 	assert_eq!(ForeignChain::try_from(3), Ok(ForeignChain::Bitcoin));
 	assert_eq!(ForeignChain::try_from(4), Ok(ForeignChain::Arbitrum));
 	assert_eq!(ForeignChain::try_from(5), Ok(ForeignChain::Solana));
-	assert!(ForeignChain::try_from(6).is_err());
+	assert_eq!(ForeignChain::try_from(6), Ok(ForeignChain::Assethub));
+	assert!(ForeignChain::try_from(7).is_err());
 }
 
 #[test]
@@ -158,6 +177,7 @@ fn test_chains() {
 	assert_eq!(Bitcoin.as_ref(), &ForeignChain::Bitcoin);
 	assert_eq!(Arbitrum.as_ref(), &ForeignChain::Arbitrum);
 	assert_eq!(Solana.as_ref(), &ForeignChain::Solana);
+	assert_eq!(Assethub.as_ref(), &ForeignChain::Assethub);
 }
 
 #[test]
@@ -167,6 +187,7 @@ fn test_get_chain_identifier() {
 	assert_eq!(Bitcoin::get(), ForeignChain::Bitcoin);
 	assert_eq!(Arbitrum::get(), ForeignChain::Arbitrum);
 	assert_eq!(Solana::get(), ForeignChain::Solana);
+	assert_eq!(Assethub::get(), ForeignChain::Assethub);
 }
 
 #[test]
@@ -191,4 +212,8 @@ fn test_chain_to_and_from_str() {
 		ForeignChain::from_str(ForeignChain::Solana.to_string().as_str()).unwrap(),
 		ForeignChain::Solana
 	);
+	assert_eq!(
+		ForeignChain::from_str(ForeignChain::Assethub.to_string().as_str()).unwrap(),
+		ForeignChain::Assethub
+	);
 }
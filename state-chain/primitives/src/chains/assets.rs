@@ -659,6 +659,46 @@ assets!(
 				gas: true,
 				index: 9,
 			},
+			Asset {
+				variant: SolUsdc,
+				member: usdc,
+				string: "USDC" (aliases: ["Usdc", "usdc"]),
+				json: "USDC",
+				gas: false,
+				index: 10,
+			},
+		],
+	},
+	Chain {
+		variant: Assethub,
+		member_and_module: hub,
+		string: "Assethub" (aliases: ["ASSETHUB", "assethub"]),
+		json: "Assethub",
+		assets: [
+			Asset {
+				variant: HubDot,
+				member: dot,
+				string: "DOT" (aliases: ["Dot", "dot"]),
+				json: "DOT",
+				gas: true,
+				index: 11,
+			},
+			Asset {
+				variant: HubUsdt,
+				member: usdt,
+				string: "USDT" (aliases: ["Usdt", "usdt"]),
+				json: "USDT",
+				gas: false,
+				index: 12,
+			},
+			Asset {
+				variant: HubUsdc,
+				member: usdc,
+				string: "USDC" (aliases: ["Usdc", "usdc"]),
+				json: "USDC",
+				gas: false,
+				index: 13,
+			},
 		],
 	}
 );
@@ -693,6 +733,10 @@ mod test_assets {
 		assert_eq!(any::Asset::try_from(7).unwrap(), any::Asset::ArbUsdc);
 		assert_eq!(any::Asset::try_from(8).unwrap(), any::Asset::Usdt);
 		assert_eq!(any::Asset::try_from(9).unwrap(), any::Asset::Sol);
+		assert_eq!(any::Asset::try_from(10).unwrap(), any::Asset::SolUsdc);
+		assert_eq!(any::Asset::try_from(11).unwrap(), any::Asset::HubDot);
+		assert_eq!(any::Asset::try_from(12).unwrap(), any::Asset::HubUsdt);
+		assert_eq!(any::Asset::try_from(13).unwrap(), any::Asset::HubUsdc);
 	}
 
 	#[test]
@@ -706,6 +750,10 @@ mod test_assets {
 		assert_conversion!(arb, ArbEth);
 		assert_conversion!(arb, ArbUsdc);
 		assert_conversion!(sol, Sol);
+		assert_conversion!(sol, SolUsdc);
+		assert_conversion!(hub, HubDot);
+		assert_conversion!(hub, HubUsdt);
+		assert_conversion!(hub, HubUsdc);
 
 		assert_incompatible!(eth, Dot);
 		assert_incompatible!(dot, Eth);
@@ -713,6 +761,11 @@ mod test_assets {
 		assert_incompatible!(dot, Usdc);
 		assert_incompatible!(btc, Usdc);
 		assert_incompatible!(btc, Usdt);
+		assert_incompatible!(sol, Usdc);
+		assert_incompatible!(arb, SolUsdc);
+		assert_incompatible!(dot, HubDot);
+		assert_incompatible!(hub, Dot);
+		assert_incompatible!(hub, Usdt);
 	}
 
 	#[test]
@@ -728,12 +781,17 @@ mod test_assets {
 		assert_eq!(assert_ok!(any::Asset::from_str("Ethereum-Eth")), any::Asset::Eth);
 		assert_eq!(assert_ok!(any::Asset::from_str("Arbitrum-Eth")), any::Asset::ArbEth);
 		assert_eq!(assert_ok!(any::Asset::from_str("Solana-Sol")), any::Asset::Sol);
+		assert_eq!(assert_ok!(any::Asset::from_str("Solana-USDC")), any::Asset::SolUsdc);
+		assert_eq!(assert_ok!(any::Asset::from_str("Assethub-DOT")), any::Asset::HubDot);
+		assert_eq!(assert_ok!(any::Asset::from_str("Assethub-USDT")), any::Asset::HubUsdt);
+		assert_eq!(assert_ok!(any::Asset::from_str("Assethub-USDC")), any::Asset::HubUsdc);
 
 		assert_err!(any::Asset::from_str("Ethereum-BTC"));
 		assert_err!(any::Asset::from_str("Polkadot-USDC"));
 		assert_err!(any::Asset::from_str("Arbitrum-Btc"));
 		assert_err!(any::Asset::from_str("Terra-ETH"));
 		assert_err!(any::Asset::from_str("Solana-BTC"));
+		assert_err!(any::Asset::from_str("Assethub-FLIP"));
 
 		// Serialization
 
@@ -757,6 +815,14 @@ mod test_assets {
 			assert_ok!(serde_json::to_string(&any::Asset::Sol)),
 			"{\"chain\":\"Solana\",\"asset\":\"SOL\"}"
 		);
+		assert_eq!(
+			assert_ok!(serde_json::to_string(&any::Asset::SolUsdc)),
+			"{\"chain\":\"Solana\",\"asset\":\"USDC\"}"
+		);
+		assert_eq!(
+			assert_ok!(serde_json::to_string(&any::Asset::HubUsdt)),
+			"{\"chain\":\"Assethub\",\"asset\":\"USDT\"}"
+		);
 
 		// Explicit Chain Deserialization
 
@@ -790,6 +856,18 @@ mod test_assets {
 			)),
 			any::Asset::Sol
 		);
+		assert_eq!(
+			assert_ok!(serde_json::from_str::<any::Asset>(
+				"{\"chain\":\"Solana\",\"asset\":\"USDC\"}"
+			)),
+			any::Asset::SolUsdc
+		);
+		assert_eq!(
+			assert_ok!(serde_json::from_str::<any::Asset>(
+				"{\"chain\":\"Assethub\",\"asset\":\"DOT\"}"
+			)),
+			any::Asset::HubDot
+		);
 
 		assert_err!(serde_json::from_str::<any::Asset>(
 			"{\"chain\":\"Ethereum\",\"asset\":\"Eth\"}"
@@ -896,7 +974,8 @@ mod test_assets {
 			btc(Btc),
 			dot(Dot),
 			arb(ArbEth, ArbUsdc),
-			sol(Sol)
+			sol(Sol, SolUsdc),
+			hub(HubDot, HubUsdt, HubUsdc)
 		);
 
 		assert_ok!(any::AssetMap::try_from_iter(any::AssetMap::from_fn(|_asset| 1u32).iter()));
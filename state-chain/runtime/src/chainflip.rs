@@ -4,18 +4,19 @@ pub mod backup_node_rewards;
 pub mod cons_key_rotator;
 pub mod decompose_recompose;
 pub mod epoch_transition;
-pub mod evm_vault_activator;
 mod missed_authorship_slots;
+pub mod multi_vault_activator;
 mod offences;
 mod signer_nomination;
 
 use crate::{
 	impl_transaction_builder_for_evm_chain, AccountId, AccountRoles, ArbitrumChainTracking,
-	ArbitrumIngressEgress, Authorship, BitcoinChainTracking, BitcoinIngressEgress,
-	BitcoinThresholdSigner, BlockNumber, Emissions, Environment, EthereumBroadcaster,
-	EthereumChainTracking, EthereumIngressEgress, Flip, FlipBalance, Hash, PolkadotBroadcaster,
-	PolkadotChainTracking, PolkadotIngressEgress, PolkadotThresholdSigner, Runtime, RuntimeCall,
-	SolanaIngressEgress, System, Validator, YEAR,
+	ArbitrumIngressEgress, AssethubChainTracking, AssethubIngressEgress, Authorship,
+	BitcoinChainTracking, BitcoinIngressEgress, BitcoinThresholdSigner, BlockNumber, Emissions,
+	Environment, EthereumBroadcaster, EthereumChainTracking, EthereumIngressEgress, Flip,
+	FlipBalance, Funding, Hash, LiquidityPools, PolkadotBroadcaster, PolkadotChainTracking,
+	PolkadotIngressEgress, PolkadotThresholdSigner, Runtime, RuntimeCall, RuntimeOrigin,
+	SolanaChainTracking, SolanaIngressEgress, SolanaThresholdSigner, System, Validator, YEAR,
 };
 use backup_node_rewards::calculate_backup_rewards;
 use cf_chains::{
@@ -27,7 +28,8 @@ use cf_chains::{
 	assets::any::ForeignChainAndAsset,
 	btc::{
 		api::{BitcoinApi, SelectedUtxosAndChangeAmount, UtxoSelectionType},
-		Bitcoin, BitcoinCrypto, BitcoinFeeInfo, BitcoinTransactionData, UtxoId,
+		deposit_address::DepositAddress,
+		Bitcoin, BitcoinCrypto, BitcoinFeeInfo, BitcoinTransactionData, CHANGE_ADDRESS_SALT,
 	},
 	dot::{
 		api::PolkadotApi, Polkadot, PolkadotAccountId, PolkadotCrypto, PolkadotReplayProtection,
@@ -43,24 +45,29 @@ use cf_chains::{
 		api::{EvmChainId, EvmEnvironmentProvider, EvmReplayProtection},
 		EvmCrypto, Transaction,
 	},
-	sol::api::SolanaApi,
+	hub::{api::AssethubApi, Assethub},
+	sol::{
+		api::{SolanaApi, SolanaEnvironment},
+		SolAddress, SolHash, SolTransaction, SolanaCrypto,
+	},
 	AnyChain, ApiCall, Arbitrum, CcmChannelMetadata, CcmDepositMetadata, Chain, ChainCrypto,
-	ChainEnvironment, ChainState, ChannelRefundParameters, DepositChannel, ForeignChain,
-	ReplayProtectionProvider, SetCommKeyWithAggKey, SetGovKeyWithAggKey, Solana,
+	ChainEnvironment, ChainState, ChannelRefundParameters, DcaParameters, DepositChannel,
+	ForeignChain, ReplayProtectionProvider, SetCommKeyWithAggKey, SetGovKeyWithAggKey, Solana,
 	TransactionBuilder,
 };
 use cf_primitives::{chains::assets, AccountRole, Asset, BasisPoints, Beneficiaries, ChannelId};
 use cf_traits::{
 	AccountInfo, AccountRoleRegistry, BackupRewardsNotifier, BlockEmissions,
-	BroadcastAnyChainGovKey, Broadcaster, Chainflip, CommKeyBroadcaster, DepositApi, EgressApi,
-	EpochInfo, Heartbeat, IngressEgressFeeApi, Issuance, KeyProvider, OnBroadcastReady, OnDeposit,
-	QualifyNode, RewardsDistribution, RuntimeUpgrade, ScheduledEgressDetails,
+	BroadcastAnyChainGovKey, Broadcaster, Chainflip, CommKeyBroadcaster, DelegationApi, DepositApi,
+	EgressApi, EpochInfo, EpochKey, GetBitcoinFeeInfo, Heartbeat, HistoricalEpoch,
+	IngressEgressFeeApi, Issuance, KeyProvider, OnBroadcastReady, OnDeposit, QualifyNode,
+	RewardsDistribution, RuntimeUpgrade, ScheduledEgressDetails, Slashing,
 };
 use codec::{Decode, Encode};
 use eth::Address as EvmAddress;
 use frame_support::{
 	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
-	pallet_prelude::DispatchError,
+	pallet_prelude::{DispatchError, DispatchResult},
 	sp_runtime::{
 		traits::{BlockNumberProvider, One, UniqueSaturatedFrom, UniqueSaturatedInto},
 		FixedPointNumber, FixedU64,
@@ -69,6 +76,7 @@ use frame_support::{
 };
 pub use missed_authorship_slots::MissedAuraSlots;
 pub use offences::*;
+use pallet_cf_account_roles::ScopedPermission;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 pub use signer_nomination::RandomSignerNomination;
@@ -88,6 +96,13 @@ impl Chainflip for Runtime {
 	type FundingInfo = Flip;
 }
 
+/// Mints a reward for an operator and shares it with the operator's delegators.
+fn settle_operator_reward(operator: &AccountId, reward: FlipBalance) {
+	for (account_id, share) in Funding::reward_shares(operator, reward) {
+		Flip::settle(&account_id, pallet_cf_flip::FlipIssuance::<Runtime>::mint(share).into());
+	}
+}
+
 struct BackupNodeEmissions;
 
 impl RewardsDistribution for BackupNodeEmissions {
@@ -114,7 +129,7 @@ impl RewardsDistribution for BackupNodeEmissions {
 			Emissions::current_authority_emission_per_block(),
 			Self::Balance::unique_saturated_from(Validator::current_authority_count()),
 		) {
-			Flip::settle(&validator_id, Self::Issuance::mint(reward).into());
+			settle_operator_reward(&validator_id, reward);
 			<Emissions as BackupRewardsNotifier>::emit_event(&validator_id, reward);
 		}
 	}
@@ -147,14 +162,92 @@ impl cf_traits::WaivedFees for WaivedFees {
 	}
 }
 
+/// Determines which calls scoped keys of liquidity providers and brokers can submit.
+pub struct ScopedCallFilter;
+
+impl pallet_cf_account_roles::ScopedCallFilter<RuntimeCall> for ScopedCallFilter {
+	fn required_permission(call: &RuntimeCall) -> Option<ScopedPermission> {
+		match call {
+			RuntimeCall::LiquidityPools(
+				pallet_cf_pools::Call::update_range_order { .. } |
+				pallet_cf_pools::Call::set_range_order { .. } |
+				pallet_cf_pools::Call::update_limit_order { .. } |
+				pallet_cf_pools::Call::set_limit_order { .. } |
+				pallet_cf_pools::Call::schedule_limit_order_update { .. } |
+				pallet_cf_pools::Call::schedule_conditional_limit_order_update { .. } |
+				pallet_cf_pools::Call::cancel_conditional_limit_order_updates { .. } |
+				pallet_cf_pools::Call::batch_order_updates { .. },
+			) |
+			RuntimeCall::EthereumIngressEgress(
+				pallet_cf_ingress_egress::Call::add_boost_funds { .. } |
+				pallet_cf_ingress_egress::Call::stop_boosting { .. },
+			) |
+			RuntimeCall::PolkadotIngressEgress(
+				pallet_cf_ingress_egress::Call::add_boost_funds { .. } |
+				pallet_cf_ingress_egress::Call::stop_boosting { .. },
+			) |
+			RuntimeCall::BitcoinIngressEgress(
+				pallet_cf_ingress_egress::Call::add_boost_funds { .. } |
+				pallet_cf_ingress_egress::Call::stop_boosting { .. },
+			) |
+			RuntimeCall::ArbitrumIngressEgress(
+				pallet_cf_ingress_egress::Call::add_boost_funds { .. } |
+				pallet_cf_ingress_egress::Call::stop_boosting { .. },
+			) |
+			RuntimeCall::SolanaIngressEgress(
+				pallet_cf_ingress_egress::Call::add_boost_funds { .. } |
+				pallet_cf_ingress_egress::Call::stop_boosting { .. },
+			) |
+			RuntimeCall::AssethubIngressEgress(
+				pallet_cf_ingress_egress::Call::add_boost_funds { .. } |
+				pallet_cf_ingress_egress::Call::stop_boosting { .. },
+			) => Some(ScopedPermission::Orders),
+			RuntimeCall::LiquidityProvider(
+				pallet_cf_lp::Call::request_liquidity_deposit_address { .. },
+			) |
+			RuntimeCall::Swapping(
+				pallet_cf_swapping::Call::request_swap_deposit_address { .. } |
+				pallet_cf_swapping::Call::request_swap_deposit_address_with_affiliates { .. },
+			) => Some(ScopedPermission::DepositChannels),
+			RuntimeCall::LiquidityProvider(
+				pallet_cf_lp::Call::withdraw_asset { .. } |
+				pallet_cf_lp::Call::transfer_asset { .. },
+			) |
+			RuntimeCall::Swapping(pallet_cf_swapping::Call::withdraw { .. }) =>
+				Some(ScopedPermission::Withdrawals),
+			RuntimeCall::LiquidityProvider(
+				pallet_cf_lp::Call::register_liquidity_refund_address { .. },
+			) => Some(ScopedPermission::AccountManagement),
+			_ => None,
+		}
+	}
+}
+
+/// Calls submitted with a scoped key are paid for by the account the key is registered for.
+pub struct ScopedKeyFeePayer;
+
+impl cf_traits::FeePayer<AccountId, RuntimeCall> for ScopedKeyFeePayer {
+	fn fee_payer(call: &RuntimeCall, signer: &AccountId) -> AccountId {
+		match call {
+			RuntimeCall::AccountRoles(pallet_cf_account_roles::Call::call_as_account {
+				..
+			}) => AccountRoles::scoped_key_account(signer).unwrap_or_else(|| signer.clone()),
+			_ => signer.clone(),
+		}
+	}
+}
+
 /// We are willing to pay at most 2x the base fee. This is approximately the theoretical
 /// limit of the rate of increase of the base fee over 6 blocks (12.5% per block).
 const ETHEREUM_BASE_FEE_MULTIPLIER: FixedU64 = FixedU64::from_rational(2, 1);
 /// Arbitrum has smaller variability so we are willing to pay at most 1.5x the base fee.
 const ARBITRUM_BASE_FEE_MULTIPLIER: FixedU64 = FixedU64::from_rational(3, 2);
 // We arbitrarily set the MAX_GAS_LIMIT
-const ETHEREUM_MAX_GAS_LIMIT: u128 = 10_000_000;
-const ARBITRUM_MAX_GAS_LIMIT: u128 = 25_000_000;
+pub const ETHEREUM_MAX_GAS_LIMIT: u128 = 10_000_000;
+pub const ARBITRUM_MAX_GAS_LIMIT: u128 = 25_000_000;
+/// Bitcoin transactions that are still not included after this many blocks (about an hour) are
+/// replaced with a version that pays the current fee rate.
+const BITCOIN_FEE_BUMP_THRESHOLD: <Bitcoin as Chain>::ChainBlockNumber = 6;
 
 pub trait EvmPriorityFee<C: Chain> {
 	fn get_priority_fee(_tracked_data: &C::TrackedData) -> Option<U256> {
@@ -278,6 +371,27 @@ impl TransactionBuilder<Polkadot, PolkadotApi<DotEnvironment>> for DotTransactio
 	}
 }
 
+pub struct HubTransactionBuilder;
+impl TransactionBuilder<Assethub, AssethubApi<HubEnvironment>> for HubTransactionBuilder {
+	fn build_transaction(
+		signed_call: &AssethubApi<HubEnvironment>,
+	) -> <Assethub as Chain>::Transaction {
+		PolkadotTransactionData { encoded_extrinsic: signed_call.chain_encoded() }
+	}
+
+	fn refresh_unsigned_data(_unsigned_tx: &mut <Assethub as Chain>::Transaction) {
+		// Asset Hub transactions are paid in DOT with a zero tip, so there is nothing to refresh.
+	}
+
+	fn requires_signature_refresh(
+		call: &AssethubApi<HubEnvironment>,
+		payload: &<<Assethub as Chain>::ChainCrypto as ChainCrypto>::Payload,
+	) -> bool {
+		// As for Polkadot, only a runtime version update can invalidate the payload.
+		&call.threshold_signature_payload() != payload
+	}
+}
+
 pub struct BtcTransactionBuilder;
 impl TransactionBuilder<Bitcoin, BitcoinApi<BtcEnvironment>> for BtcTransactionBuilder {
 	fn build_transaction(
@@ -304,26 +418,48 @@ impl TransactionBuilder<Bitcoin, BitcoinApi<BtcEnvironment>> for BtcTransactionB
 		// to check anything here and just rebroadcast.
 		false
 	}
+
+	fn replace_by_fee(
+		call: &BitcoinApi<BtcEnvironment>,
+		blocks_pending: <Bitcoin as Chain>::ChainBlockNumber,
+	) -> Option<BitcoinApi<BtcEnvironment>> {
+		if blocks_pending < BITCOIN_FEE_BUMP_THRESHOLD {
+			return None
+		}
+		match call {
+			BitcoinApi::BatchTransfer(batch_transfer) => {
+				let replacement =
+					batch_transfer.replace_by_fee(&BitcoinFeeGetter::bitcoin_fee_info())?;
+				// The change utxo of the current version is replaced by that of the replacement
+				// once it's signed. If it has already been spent by a subsequent transaction, the
+				// current version can't be replaced without invalidating that transaction too.
+				Environment::bitcoin_utxo_available(&batch_transfer.change_utxo_id()?)
+					.then(|| replacement.into())
+			},
+			_ => unreachable!(),
+		}
+	}
 }
 
 pub struct SolanaTransactionBuilder;
 impl TransactionBuilder<Solana, SolanaApi<SolEnvironment>> for SolanaTransactionBuilder {
 	fn build_transaction(
-		_signed_call: &SolanaApi<SolEnvironment>,
+		signed_call: &SolanaApi<SolEnvironment>,
 	) -> <Solana as Chain>::Transaction {
-		todo!()
+		SolTransaction { serialized_transaction: signed_call.chain_encoded() }
 	}
 	fn refresh_unsigned_data(_tx: &mut <Solana as Chain>::Transaction) {
-		todo!()
+		// The compute unit price is part of the signed message, so there is nothing to refresh.
 	}
 	fn calculate_gas_limit(_call: &SolanaApi<SolEnvironment>) -> Option<U256> {
-		todo!()
+		None
 	}
 	fn requires_signature_refresh(
 		_call: &SolanaApi<SolEnvironment>,
 		_payload: &<<Solana as Chain>::ChainCrypto as ChainCrypto>::Payload,
 	) -> bool {
-		todo!()
+		// Transactions use a durable nonce rather than a recent blockhash, so they don't expire.
+		false
 	}
 }
 
@@ -337,13 +473,48 @@ impl RewardsDistribution for BlockAuthorRewardDistribution {
 		let reward_amount = Emissions::current_authority_emission_per_block();
 		if reward_amount != 0 {
 			if let Some(current_block_author) = Authorship::author() {
-				Flip::settle(&current_block_author, Self::Issuance::mint(reward_amount).into());
+				settle_operator_reward(&current_block_author, reward_amount);
 			} else {
 				log::warn!("No block author for block {}.", System::current_block_number());
 			}
 		}
 	}
 }
+/// Slashes validators, sharing each slash between the operator and its delegators.
+pub struct DelegatedStakeSlasher;
+
+impl Slashing for DelegatedStakeSlasher {
+	type AccountId = AccountId;
+	type BlockNumber = BlockNumber;
+	type Balance = FlipBalance;
+
+	fn slash(validator_id: &Self::AccountId, blocks_offline: Self::BlockNumber) {
+		Self::slash_balance(
+			validator_id,
+			Self::calculate_slash_amount(validator_id, blocks_offline),
+		);
+	}
+
+	fn slash_balance(account_id: &Self::AccountId, slash_amount: FlipBalance) {
+		for (account_id, share) in Funding::slash_shares(account_id, slash_amount) {
+			pallet_cf_flip::FlipSlasher::<Runtime>::slash_balance(&account_id, share);
+			Funding::reconcile_delegation(&account_id);
+		}
+	}
+
+	/// Delegated funds are not bonded, so the slash is calculated from the operator's full bond
+	/// and shared pro-rata between the operator and its delegators.
+	fn calculate_slash_amount(
+		account_id: &Self::AccountId,
+		blocks: Self::BlockNumber,
+	) -> Self::Balance {
+		(pallet_cf_flip::SlashingRate::<Runtime>::get() *
+			pallet_cf_validator::EpochHistory::<Runtime>::active_bond(account_id) /
+			FlipBalance::from(<Runtime as pallet_cf_flip::Config>::BlocksPerDay::get()))
+		.saturating_mul(blocks.into())
+	}
+}
+
 pub struct RuntimeUpgradeManager;
 
 impl RuntimeUpgrade for RuntimeUpgradeManager {
@@ -459,6 +630,34 @@ impl ChainEnvironment<cf_chains::dot::api::VaultAccount, PolkadotAccountId> for
 	}
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub struct HubEnvironment;
+
+impl ReplayProtectionProvider<Assethub> for HubEnvironment {
+	fn replay_protection(reset_nonce: ResetProxyAccountNonce) -> PolkadotReplayProtection {
+		PolkadotReplayProtection {
+			genesis_hash: Environment::assethub_genesis_hash(),
+			// The Asset Hub vault is controlled by the Polkadot aggregate key.
+			signer: <PolkadotThresholdSigner as KeyProvider<PolkadotCrypto>>::active_epoch_key()
+				.map(|epoch_key| epoch_key.key)
+				.defensive_unwrap_or_default(),
+			nonce: Environment::next_assethub_proxy_account_nonce(reset_nonce),
+		}
+	}
+}
+
+impl Get<RuntimeVersion> for HubEnvironment {
+	fn get() -> RuntimeVersion {
+		AssethubChainTracking::chain_state().unwrap().tracked_data.runtime_version
+	}
+}
+
+impl ChainEnvironment<cf_chains::dot::api::VaultAccount, PolkadotAccountId> for HubEnvironment {
+	fn lookup(_: cf_chains::dot::api::VaultAccount) -> Option<PolkadotAccountId> {
+		Environment::assethub_vault_account()
+	}
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub struct BtcEnvironment;
 
@@ -482,6 +681,31 @@ impl ChainEnvironment<(), cf_chains::btc::AggKey> for BtcEnvironment {
 #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub struct SolEnvironment;
 
+impl SolanaEnvironment for SolEnvironment {
+	fn vault_address() -> SolAddress {
+		Environment::sol_vault_address()
+	}
+
+	fn token_mint(asset: assets::sol::Asset) -> Option<SolAddress> {
+		Environment::supported_sol_assets(asset)
+	}
+
+	fn current_agg_key() -> Option<SolAddress> {
+		<SolanaThresholdSigner as KeyProvider<SolanaCrypto>>::active_epoch_key()
+			.map(|epoch_key| epoch_key.key)
+	}
+
+	fn durable_nonce() -> Option<(SolAddress, SolHash)> {
+		Environment::sol_durable_nonce()
+	}
+
+	fn compute_price() -> u64 {
+		SolanaChainTracking::chain_state()
+			.map(|state| state.tracked_data.priority_fee.try_into().unwrap_or(u64::MAX))
+			.unwrap_or_default()
+	}
+}
+
 pub struct TokenholderGovernanceBroadcaster;
 
 impl TokenholderGovernanceBroadcaster {
@@ -523,6 +747,7 @@ impl BroadcastAnyChainGovKey for TokenholderGovernanceBroadcaster {
 			ForeignChain::Bitcoin => Err(()),
 			ForeignChain::Arbitrum => Err(()),
 			ForeignChain::Solana => todo!(),
+			ForeignChain::Assethub => Err(()),
 		}
 	}
 
@@ -535,6 +760,7 @@ impl BroadcastAnyChainGovKey for TokenholderGovernanceBroadcaster {
 			ForeignChain::Bitcoin => false,
 			ForeignChain::Arbitrum => false,
 			ForeignChain::Solana => todo!(),
+			ForeignChain::Assethub => false,
 		}
 	}
 }
@@ -547,6 +773,41 @@ impl CommKeyBroadcaster for TokenholderGovernanceBroadcaster {
 	}
 }
 
+/// The parameters that tokenholders are allowed to change through on-chain proposals.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum TokenholderParameterChange {
+	ValidatorConfig(pallet_cf_validator::PalletConfigUpdate),
+	CurrentAuthorityEmissionInflation(u32),
+	BackupNodeEmissionInflation(u32),
+	PoolFees { base_asset: Asset, quote_asset: Asset, fee_hundredth_pips: u32 },
+}
+
+impl pallet_cf_tokenholder_governance::ParameterChange for TokenholderParameterChange {
+	fn enact(self) -> DispatchResult {
+		let origin: RuntimeOrigin = pallet_cf_governance::RawOrigin::GovernanceApproval.into();
+		match self {
+			Self::ValidatorConfig(update) => Validator::update_pallet_config(origin, update),
+			Self::CurrentAuthorityEmissionInflation(inflation) =>
+				Emissions::update_current_authority_emission_inflation(origin, inflation)
+					.map(|_| ())
+					.map_err(|e| e.error),
+			Self::BackupNodeEmissionInflation(inflation) =>
+				Emissions::update_backup_node_emission_inflation(origin, inflation)
+					.map(|_| ())
+					.map_err(|e| e.error),
+			Self::PoolFees { base_asset, quote_asset, fee_hundredth_pips } =>
+				LiquidityPools::set_pool_fees(origin, base_asset, quote_asset, fee_hundredth_pips),
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl cf_chains::benchmarking_value::BenchmarkValue for TokenholderParameterChange {
+	fn benchmark_value() -> Self {
+		Self::CurrentAuthorityEmissionInflation(Default::default())
+	}
+}
+
 #[macro_export]
 macro_rules! impl_deposit_api_for_anychain {
 	( $t: ident, $(($chain: ident, $pallet: ident)),+ ) => {
@@ -580,6 +841,7 @@ macro_rules! impl_deposit_api_for_anychain {
 				channel_metadata: Option<CcmChannelMetadata>,
 				boost_fee: BasisPoints,
 				refund_parameters: Option<ChannelRefundParameters>,
+				dca_parameters: Option<DcaParameters>,
 			) -> Result<(ChannelId, ForeignChainAddress, <AnyChain as cf_chains::Chain>::ChainBlockNumber, FlipBalance), DispatchError> {
 				match source_asset.into() {
 					$(
@@ -592,6 +854,7 @@ macro_rules! impl_deposit_api_for_anychain {
 							channel_metadata,
 							boost_fee,
 							refund_parameters,
+							dca_parameters,
 						).map(|(channel, address, block_number, channel_opening_fee)| (channel, address, block_number.into(), channel_opening_fee)),
 					)+
 				}
@@ -638,7 +901,8 @@ impl_deposit_api_for_anychain!(
 	(Polkadot, PolkadotIngressEgress),
 	(Bitcoin, BitcoinIngressEgress),
 	(Arbitrum, ArbitrumIngressEgress),
-	(Solana, SolanaIngressEgress)
+	(Solana, SolanaIngressEgress),
+	(Assethub, AssethubIngressEgress)
 );
 
 impl_egress_api_for_anychain!(
@@ -647,7 +911,8 @@ impl_egress_api_for_anychain!(
 	(Polkadot, PolkadotIngressEgress),
 	(Bitcoin, BitcoinIngressEgress),
 	(Arbitrum, ArbitrumIngressEgress),
-	(Solana, SolanaIngressEgress)
+	(Solana, SolanaIngressEgress),
+	(Assethub, AssethubIngressEgress)
 );
 
 pub struct DepositHandler;
@@ -661,9 +926,36 @@ impl OnDeposit<Bitcoin> for DepositHandler {
 	) {
 		Environment::add_bitcoin_utxo_to_list(amount, utxo_id, channel.state.clone())
 	}
+
+	fn on_vault_deposit_made(
+		utxo_id: <Bitcoin as Chain>::DepositDetails,
+		amount: <Bitcoin as Chain>::ChainAmount,
+		deposit_address: &<Bitcoin as Chain>::ChainAccount,
+	) {
+		// The vault address is the change address of the current (or, during a rotation, the
+		// previous) aggregate key, so the deposit can be spent like a change utxo.
+		let vault_pubkey_x =
+			<BitcoinThresholdSigner as KeyProvider<BitcoinCrypto>>::active_epoch_key().and_then(
+				|EpochKey { key, .. }| {
+					core::iter::once(key.current).chain(key.previous).find(|pubkey_x| {
+						DepositAddress::new(*pubkey_x, CHANGE_ADDRESS_SALT).script_pubkey() ==
+							*deposit_address
+					})
+				},
+			);
+		match vault_pubkey_x {
+			Some(pubkey_x) => Environment::add_bitcoin_change_utxo(amount, utxo_id, pubkey_x),
+			None => log::error!(
+				"Bitcoin vault deposit {:?} was made to an unknown vault address {:?}.",
+				utxo_id,
+				deposit_address
+			),
+		}
+	}
 }
 impl OnDeposit<Arbitrum> for DepositHandler {}
 impl OnDeposit<Solana> for DepositHandler {}
+impl OnDeposit<Assethub> for DepositHandler {}
 
 pub struct ChainAddressConverter;
 
@@ -692,16 +984,78 @@ impl OnBroadcastReady<Bitcoin> for BroadcastReadyProvider {
 	fn on_broadcast_ready(api_call: &Self::ApiCall) {
 		match api_call {
 			BitcoinApi::BatchTransfer(batch_transfer) => {
-				let tx_id = batch_transfer.bitcoin_transaction.txid();
-				let outputs = batch_transfer.bitcoin_transaction.outputs.clone();
-				let output_len = outputs.len();
-				let vout = output_len - 1;
-				let change_output = outputs.get(vout).unwrap();
-				Environment::add_bitcoin_change_utxo(
-					change_output.amount,
-					UtxoId { tx_id, vout: vout as u32 },
-					batch_transfer.change_utxo_key,
-				);
+				// There is no change output if the change would have been below the dust limit.
+				if let Some(change_utxo_id) = batch_transfer.change_utxo_id() {
+					Environment::add_bitcoin_change_utxo(
+						batch_transfer.bitcoin_transaction.outputs[change_utxo_id.vout as usize]
+							.amount,
+						change_utxo_id,
+						batch_transfer.change_utxo_key,
+					);
+				}
+			},
+			_ => unreachable!(),
+		}
+	}
+
+	fn on_replaced_broadcast_confirmed(confirmed: &Self::ApiCall, replacement: &Self::ApiCall) {
+		match (confirmed, replacement) {
+			(BitcoinApi::BatchTransfer(confirmed), BitcoinApi::BatchTransfer(replacement)) => {
+				// If the replacement was never signed, the confirmed call is still the latest one
+				// and its change utxo was already set aside when the replacement was requested.
+				if let Some(change_utxo_id) =
+					replacement.change_utxo_id().filter(|_| replacement != confirmed)
+				{
+					if Environment::take_bitcoin_utxo(&change_utxo_id).is_none() {
+						log::error!(
+							"Change utxo {:?} of a replaced bitcoin transaction has already been spent.",
+							change_utxo_id
+						);
+					}
+				}
+				if let Some(change_utxo_id) = confirmed.change_utxo_id() {
+					Environment::add_bitcoin_change_utxo(
+						confirmed.bitcoin_transaction.outputs[change_utxo_id.vout as usize].amount,
+						change_utxo_id,
+						confirmed.change_utxo_key,
+					);
+				}
+			},
+			_ => unreachable!(),
+		}
+	}
+
+	fn on_broadcast_replaced(replaced: &Self::ApiCall) {
+		match replaced {
+			BitcoinApi::BatchTransfer(batch_transfer) => {
+				// The replacement adds its own change utxo once it's signed. Until then, the
+				// change of the replaced version must not be spent.
+				if let Some(change_utxo_id) = batch_transfer.change_utxo_id() {
+					Environment::take_bitcoin_utxo(&change_utxo_id);
+				}
+			},
+			_ => unreachable!(),
+		}
+	}
+
+	fn on_broadcast_cancelled(api_call: &Self::ApiCall) {
+		match api_call {
+			BitcoinApi::BatchTransfer(batch_transfer) => {
+				if let Some(change_utxo_id) = batch_transfer.change_utxo_id() {
+					if Environment::take_bitcoin_utxo(&change_utxo_id).is_none() {
+						log::error!(
+							"Change utxo {:?} of a cancelled bitcoin transaction has already been spent.",
+							change_utxo_id
+						);
+					}
+				}
+				for utxo in batch_transfer.bitcoin_transaction.inputs() {
+					Environment::add_bitcoin_utxo_to_list(
+						utxo.amount,
+						utxo.id.clone(),
+						utxo.deposit_address.clone(),
+					);
+				}
 			},
 			_ => unreachable!(),
 		}
@@ -714,9 +1068,12 @@ impl OnBroadcastReady<Arbitrum> for BroadcastReadyProvider {
 impl OnBroadcastReady<Solana> for BroadcastReadyProvider {
 	type ApiCall = SolanaApi<SolEnvironment>;
 }
+impl OnBroadcastReady<Assethub> for BroadcastReadyProvider {
+	type ApiCall = AssethubApi<HubEnvironment>;
+}
 
 pub struct BitcoinFeeGetter;
-impl cf_traits::GetBitcoinFeeInfo for BitcoinFeeGetter {
+impl GetBitcoinFeeInfo for BitcoinFeeGetter {
 	fn bitcoin_fee_info() -> BitcoinFeeInfo {
 		BitcoinChainTracking::chain_state().unwrap().tracked_data.btc_fee_info
 	}
@@ -805,5 +1162,6 @@ impl_ingress_egress_fee_api_for_anychain!(
 	(Polkadot, PolkadotIngressEgress),
 	(Bitcoin, BitcoinIngressEgress),
 	(Arbitrum, ArbitrumIngressEgress),
-	(Solana, SolanaIngressEgress)
+	(Solana, SolanaIngressEgress),
+	(Assethub, AssethubIngressEgress)
 );
@@ -2,5 +2,6 @@ pub mod arb;
 pub mod btc;
 pub mod dot;
 pub mod eth;
+pub mod hub;
 mod sol;
 pub struct AddressDerivation;
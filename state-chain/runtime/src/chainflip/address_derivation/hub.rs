@@ -0,0 +1,82 @@
+use cf_chains::{
+	address::{AddressDerivationApi, AddressDerivationError},
+	hub::Assethub,
+	Chain,
+};
+use cf_primitives::ChannelId;
+
+use crate::Environment;
+
+use super::{dot::derive_sub_account, AddressDerivation};
+
+/// Asset Hub uses the same `utility` pallet as the relay chain, so deposit channels are derived
+/// from the Asset Hub vault exactly as they are on Polkadot.
+impl AddressDerivationApi<Assethub> for AddressDerivation {
+	fn generate_address(
+		_source_asset: <Assethub as Chain>::ChainAsset,
+		channel_id: ChannelId,
+	) -> Result<<Assethub as Chain>::ChainAccount, AddressDerivationError> {
+		Ok(derive_sub_account(
+			Environment::assethub_vault_account()
+				.ok_or(AddressDerivationError::MissingAssethubVault)?,
+			channel_id,
+		))
+	}
+
+	fn generate_address_and_state(
+		source_asset: <Assethub as Chain>::ChainAsset,
+		channel_id: ChannelId,
+	) -> Result<
+		(<Assethub as Chain>::ChainAccount, <Assethub as Chain>::DepositChannelState),
+		AddressDerivationError,
+	> {
+		Ok((
+			<Self as AddressDerivationApi<Assethub>>::generate_address(source_asset, channel_id)?,
+			Default::default(),
+		))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Runtime;
+	use cf_chains::{dot::PolkadotAccountId, Polkadot};
+	use cf_primitives::chains::assets::{dot, hub};
+	use pallet_cf_environment::{AssethubVaultAccountId, PolkadotVaultAccountId};
+
+	#[test]
+	fn derives_same_addresses_as_polkadot_for_the_same_vault() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let vault = PolkadotAccountId::from_aliased([7u8; 32]);
+			PolkadotVaultAccountId::<Runtime>::put(vault);
+			AssethubVaultAccountId::<Runtime>::put(vault);
+
+			assert_eq!(
+				<AddressDerivation as AddressDerivationApi<Assethub>>::generate_address(
+					hub::Asset::HubUsdt,
+					0x0001_0002
+				)
+				.unwrap(),
+				<AddressDerivation as AddressDerivationApi<Polkadot>>::generate_address(
+					dot::Asset::Dot,
+					0x0001_0002
+				)
+				.unwrap(),
+			);
+		});
+	}
+
+	#[test]
+	fn fails_without_vault() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			assert_eq!(
+				<AddressDerivation as AddressDerivationApi<Assethub>>::generate_address(
+					hub::Asset::HubDot,
+					1
+				),
+				Err(AddressDerivationError::MissingAssethubVault),
+			);
+		});
+	}
+}
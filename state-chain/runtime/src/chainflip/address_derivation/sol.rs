@@ -1,5 +1,8 @@
 use cf_chains::{
-	address::AddressDerivationApi, assets::sol::Asset, sol::DerivedAddressBuilder, Solana,
+	address::{AddressDerivationApi, AddressDerivationError},
+	assets::sol::Asset,
+	sol::{derive_associated_token_account, DerivedAddressBuilder},
+	Solana,
 };
 
 use crate::Environment;
@@ -32,12 +35,17 @@ impl AddressDerivationApi<Solana> for AddressDerivation {
 		cf_chains::address::AddressDerivationError,
 	> {
 		let vault_address = Environment::sol_vault_address();
+		let (pda, _bump) = DerivedAddressBuilder::from_address(vault_address)?
+			.chain_seed(channel_id.to_le_bytes())?
+			.finish()?;
 		match source_asset {
-			Asset::Sol => {
-				let (pda, _bump) = DerivedAddressBuilder::from_address(vault_address)?
-					.chain_seed(channel_id.to_le_bytes())?
-					.finish()?;
-				Ok((pda, ()))
+			Asset::Sol => Ok((pda, ())),
+			// SPL tokens are deposited into the channel PDA's associated token account.
+			Asset::SolUsdc => {
+				let mint = Environment::supported_sol_assets(Asset::SolUsdc)
+					.ok_or(AddressDerivationError::MissingSolanaTokenMint)?;
+				let (ata, _bump) = derive_associated_token_account(pda, mint)?;
+				Ok((ata, ()))
 			},
 		}
 	}
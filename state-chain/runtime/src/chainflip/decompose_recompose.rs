@@ -1,5 +1,6 @@
 use crate::{
-	ArbitrumInstance, BitcoinInstance, EthereumInstance, PolkadotInstance, Runtime, RuntimeCall,
+	ArbitrumInstance, AssethubInstance, BitcoinInstance, EthereumInstance, PolkadotInstance,
+	Runtime, RuntimeCall,
 };
 use cf_chains::{arb::ArbitrumTrackedData, btc::BitcoinFeeInfo};
 use codec::{Decode, Encode};
@@ -37,6 +38,15 @@ impl WitnessDataExtraction for RuntimeCall {
 				let fee_info = mem::take(&mut new_chain_state.tracked_data.median_tip);
 				Some(fee_info.encode())
 			},
+			RuntimeCall::AssethubChainTracking(pallet_cf_chain_tracking::Call::<
+				Runtime,
+				AssethubInstance,
+			>::update_chain_state {
+				ref mut new_chain_state,
+			}) => {
+				let fee_info = mem::take(&mut new_chain_state.tracked_data.median_tip);
+				Some(fee_info.encode())
+			},
 			// In Arbitrum the amount of gas for calls keep changing accross time. In order to get
 			// that value (gas multiplier) we need to use a call to the NodeInterface which
 			// simulates a transaction to get that value. However, that estimation can't be done at
@@ -92,6 +102,16 @@ impl WitnessDataExtraction for RuntimeCall {
 					new_chain_state.tracked_data.median_tip = median;
 				};
 			},
+			RuntimeCall::AssethubChainTracking(pallet_cf_chain_tracking::Call::<
+				Runtime,
+				AssethubInstance,
+			>::update_chain_state {
+				new_chain_state,
+			}) => {
+				if let Some(median) = decode_and_select(data, select_median) {
+					new_chain_state.tracked_data.median_tip = median;
+				};
+			},
 			RuntimeCall::ArbitrumChainTracking(pallet_cf_chain_tracking::Call::<
 				Runtime,
 				ArbitrumInstance,
@@ -184,7 +204,7 @@ mod tests {
 		btc::{BitcoinFeeInfo, BitcoinTrackedData},
 		dot::PolkadotTrackedData,
 		eth::EthereumTrackedData,
-		Bitcoin, Chain, ChainState, Ethereum, Polkadot,
+		Assethub, Bitcoin, Chain, ChainState, Ethereum, Polkadot,
 	};
 	use cf_primitives::{AccountRole, ForeignChain};
 	use cf_traits::EpochInfo;
@@ -247,6 +267,19 @@ mod tests {
 						tracked_data: Default::default(),
 					},
 				}),
+			ForeignChain::Assethub =>
+				RuntimeCall::AssethubChainTracking(pallet_cf_chain_tracking::Call::<
+					Runtime,
+					AssethubInstance,
+				>::update_chain_state {
+					new_chain_state: ChainState {
+						block_height: BLOCK_HEIGHT as u32,
+						tracked_data: PolkadotTrackedData {
+							median_tip: fee.into(),
+							runtime_version: Default::default(),
+						},
+					},
+				}),
 		}
 	}
 
@@ -255,6 +288,7 @@ mod tests {
 		test_medians::<Ethereum>();
 		test_medians::<Bitcoin>();
 		test_medians::<Polkadot>();
+		test_medians::<Assethub>();
 		// we dont test medians for Arbitrum since there is no priority fee in arbitrum
 	}
 
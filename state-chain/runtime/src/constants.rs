@@ -59,6 +59,10 @@ pub mod common {
 	pub const DAYS: BlockNumber = HOURS * 24;
 	pub const YEAR: BlockNumber = DAYS * 365;
 
+	/// The minimum time in seconds that an approved runtime upgrade is time-locked for, giving
+	/// governance members a window to cancel it.
+	pub const RUNTIME_UPGRADE_MINIMUM_ENACTMENT_DELAY_SECONDS: u64 = 60 * 60;
+
 	/// Percent of the epoch we are allowed to redeem
 	pub const REDEMPTION_PERIOD_AS_PERCENTAGE: u8 = 50;
 
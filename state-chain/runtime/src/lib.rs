@@ -20,9 +20,10 @@ use crate::{
 	},
 	runtime_apis::{
 		runtime_decl_for_custom_runtime_api::CustomRuntimeApiV1, AuctionState, BoostPoolDepth,
-		BoostPoolDetails, BrokerInfo, DispatchErrorWithMessage, EventFilter,
-		FailingWitnessValidators, LiquidityProviderInfo, RuntimeApiPenalty,
-		SimulateSwapAdditionalOrder, SimulatedSwapInformation, ValidatorInfo,
+		BoostPoolDetails, BrokerInfo, CcmDepositCheck, CcmGasBudgetEstimate,
+		DispatchErrorWithMessage, EventFilter, FailingWitnessValidators, LiquidityProviderInfo,
+		RuntimeApiPenalty, SimulateSwapAdditionalOrder, SimulatedSwapInformation, SwapQuote,
+		ValidatorInfo,
 	},
 };
 use cf_amm::{
@@ -30,8 +31,8 @@ use cf_amm::{
 	range_orders::Liquidity,
 };
 pub use cf_chains::instances::{
-	ArbitrumInstance, BitcoinInstance, EthereumInstance, EvmInstance, PolkadotInstance,
-	SolanaInstance,
+	ArbitrumInstance, AssethubInstance, BitcoinInstance, EthereumInstance, EvmInstance,
+	PolkadotInstance, SolanaInstance,
 };
 use cf_chains::{
 	arb::api::ArbitrumApi,
@@ -40,23 +41,24 @@ use cf_chains::{
 	dot::{self, PolkadotAccountId, PolkadotCrypto},
 	eth::{self, api::EthereumApi, Address as EthereumAddress, Ethereum},
 	evm::EvmCrypto,
+	hub::api::AssethubApi,
 	sol::SolanaCrypto,
-	Arbitrum, Bitcoin, CcmChannelMetadata, DefaultRetryPolicy, ForeignChain, Polkadot, Solana,
-	TransactionBuilder,
+	Arbitrum, Assethub, Bitcoin, CcmChannelMetadata, DefaultRetryPolicy, ForeignChain, Polkadot,
+	Solana, TransactionBuilder,
 };
-use cf_primitives::{BroadcastId, EpochIndex, NetworkEnvironment};
+use cf_primitives::{BasisPoints, BroadcastId, EpochIndex, NetworkEnvironment};
 use cf_traits::{AdjustedFeeEstimationApi, AssetConverter, LpBalanceApi};
 use codec::{alloc::string::ToString, Encode};
 use core::ops::Range;
 use frame_support::instances::*;
 pub use frame_system::Call as SystemCall;
-use pallet_cf_governance::GovCallHash;
+use pallet_cf_governance::{GovCallHash, ProposalId, TimeLockedProposal};
 use pallet_cf_ingress_egress::{
 	ChannelAction, DepositWitness, IngressOrEgress, OwedAmount, TargetChainAsset,
 };
 use pallet_cf_pools::{
 	AskBidMap, AssetPair, OrderId, PoolLiquidity, PoolOrderbook, PoolPriceV1, PoolPriceV2,
-	UnidirectionalPoolDepth,
+	PoolTwap, UnidirectionalPoolDepth,
 };
 use pallet_cf_reputation::ExclusionList;
 use pallet_cf_swapping::{CcmSwapAmounts, SwapLegInfo};
@@ -103,7 +105,7 @@ pub use sp_runtime::BuildStorage;
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	ApplyExtrinsicResult, DispatchError, MultiSignature,
 };
 pub use sp_runtime::{Perbill, Permill};
 use sp_std::prelude::*;
@@ -123,14 +125,15 @@ pub use cf_traits::{
 pub use pallet_cf_validator::SetSizeParameters;
 
 use chainflip::{
-	epoch_transition::ChainflipEpochTransitions, evm_vault_activator::EvmVaultActivator,
+	epoch_transition::ChainflipEpochTransitions, multi_vault_activator::MultiVaultActivator,
 	BroadcastReadyProvider, BtcEnvironment, ChainAddressConverter, ChainflipHeartbeat,
-	DotEnvironment, EvmEnvironment, SolEnvironment, TokenholderGovernanceBroadcaster,
+	DelegatedStakeSlasher, DotEnvironment, EvmEnvironment, HubEnvironment, SolEnvironment,
+	TokenholderGovernanceBroadcaster,
 };
 use safe_mode::{RuntimeSafeMode, WitnesserCallPermission};
 
 use constants::common::*;
-use pallet_cf_flip::{Bonder, FlipSlasher};
+use pallet_cf_flip::Bonder;
 pub use pallet_transaction_payment::ChargeTransactionPayment;
 
 // Make the WASM binary available.
@@ -186,7 +189,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_version: 150,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 12,
+	transaction_version: 13,
 	state_version: 1,
 };
 
@@ -222,6 +225,7 @@ impl pallet_cf_validator::Config for Runtime {
 	);
 	type OffenceReporter = Reputation;
 	type Bonder = Bonder<Runtime>;
+	type Delegation = Funding;
 	type SafeMode = RuntimeSafeMode;
 	type ReputationResetter = Reputation;
 	type CfePeerRegistration = CfeInterface;
@@ -240,6 +244,7 @@ impl pallet_cf_environment::Config for Runtime {
 	type PolkadotVaultKeyWitnessedHandler = PolkadotVault;
 	type BitcoinVaultKeyWitnessedHandler = BitcoinVault;
 	type ArbitrumVaultKeyWitnessedHandler = ArbitrumVault;
+	type AssethubVaultKeyWitnessedHandler = AssethubVault;
 	type BitcoinFeeInfo = chainflip::BitcoinFeeGetter;
 	type BitcoinKeyProvider = BitcoinThresholdSigner;
 	type RuntimeSafeMode = RuntimeSafeMode;
@@ -315,6 +320,17 @@ impl pallet_cf_vaults::Config<Instance5> for Runtime {
 	type CfeMultisigRequest = CfeInterface;
 }
 
+impl pallet_cf_vaults::Config<Instance6> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Chain = Assethub;
+	type SetAggKeyWithAggKey = AssethubApi<HubEnvironment>;
+	type Broadcaster = AssethubBroadcaster;
+	type WeightInfo = pallet_cf_vaults::weights::PalletWeight<Runtime>;
+	type ChainTracking = AssethubChainTracking;
+	type SafeMode = RuntimeSafeMode;
+	type CfeMultisigRequest = CfeInterface;
+}
+
 use chainflip::address_derivation::AddressDerivation;
 
 impl pallet_cf_ingress_egress::Config<Instance1> for Runtime {
@@ -422,6 +438,27 @@ impl pallet_cf_ingress_egress::Config<Instance5> for Runtime {
 	type SafeMode = RuntimeSafeMode;
 }
 
+impl pallet_cf_ingress_egress::Config<Instance6> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type TargetChain = Assethub;
+	type AddressDerivation = AddressDerivation;
+	type AddressConverter = ChainAddressConverter;
+	type LpBalance = LiquidityProvider;
+	type SwapDepositHandler = Swapping;
+	type ChainApiCall = AssethubApi<HubEnvironment>;
+	type Broadcaster = AssethubBroadcaster;
+	type WeightInfo = pallet_cf_ingress_egress::weights::PalletWeight<Runtime>;
+	type DepositHandler = chainflip::DepositHandler;
+	type ChainTracking = AssethubChainTracking;
+	type CcmHandler = Swapping;
+	type NetworkEnvironment = Environment;
+	type AssetConverter = LiquidityPools;
+	type FeePayment = Flip;
+	type SwapQueueApi = Swapping;
+	type SafeMode = RuntimeSafeMode;
+}
+
 parameter_types! {
 	pub const NetworkFee: Permill = Permill::from_perthousand(1);
 }
@@ -450,6 +487,8 @@ impl pallet_cf_lp::Config for Runtime {
 impl pallet_cf_account_roles::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type EnsureGovernance = pallet_cf_governance::EnsureGovernance;
+	type RuntimeCall = RuntimeCall;
+	type ScopedCallFilter = chainflip::ScopedCallFilter;
 	type WeightInfo = ();
 }
 
@@ -612,6 +651,7 @@ impl pallet_cf_flip::Config for Runtime {
 	type OnAccountFunded = pallet_cf_validator::UpdateBackupMapping<Self>;
 	type WeightInfo = pallet_cf_flip::weights::PalletWeight<Runtime>;
 	type WaivedFees = chainflip::WaivedFees;
+	type FeePayer = chainflip::ScopedKeyFeePayer;
 }
 
 impl pallet_cf_witnesser::Config for Runtime {
@@ -650,6 +690,7 @@ impl pallet_cf_tokenholder_governance::Config for Runtime {
 	type CommKeyBroadcaster = TokenholderGovernanceBroadcaster;
 	type ProposalFee = ConstU128<{ 1_000 * FLIPPERINOS_PER_FLIP }>;
 	type EnactmentDelay = ConstU32<{ 7 * DAYS }>;
+	type ParameterChange = chainflip::TokenholderParameterChange;
 }
 
 impl pallet_cf_governance::Config for Runtime {
@@ -662,6 +703,8 @@ impl pallet_cf_governance::Config for Runtime {
 	type RuntimeUpgrade = chainflip::RuntimeUpgradeManager;
 	type CompatibleCfeVersions = Environment;
 	type AuthoritiesCfeVersions = Validator;
+	type RuntimeUpgradeMinimumEnactmentDelay =
+		ConstU64<RUNTIME_UPGRADE_MINIMUM_ENACTMENT_DELAY_SECONDS>;
 }
 
 impl pallet_cf_emissions::Config for Runtime {
@@ -709,7 +752,7 @@ impl pallet_cf_reputation::Config for Runtime {
 	type Heartbeat = ChainflipHeartbeat;
 	type HeartbeatBlockInterval = ConstU32<HEARTBEAT_BLOCK_INTERVAL>;
 	type ReputationPointFloorAndCeiling = ReputationPointFloorAndCeiling;
-	type Slasher = FlipSlasher<Self>;
+	type Slasher = DelegatedStakeSlasher;
 	type WeightInfo = pallet_cf_reputation::weights::PalletWeight<Runtime>;
 	type MaximumAccruableReputation = MaximumAccruableReputation;
 	type SafeMode = RuntimeSafeMode;
@@ -722,11 +765,11 @@ impl pallet_cf_threshold_signature::Config<Instance16> for Runtime {
 	type ThresholdCallable = RuntimeCall;
 	type ThresholdSignerNomination = chainflip::RandomSignerNomination;
 	type TargetChainCrypto = EvmCrypto;
-	type VaultActivator = EvmVaultActivator<EthereumVault, ArbitrumVault>;
+	type VaultActivator = MultiVaultActivator<EthereumVault, ArbitrumVault>;
 	type OffenceReporter = Reputation;
 	type CeremonyRetryDelay = ConstU32<1>;
 	type SafeMode = RuntimeSafeMode;
-	type Slasher = FlipSlasher<Self>;
+	type Slasher = DelegatedStakeSlasher;
 	type CfeMultisigRequest = CfeInterface;
 	type Weights = pallet_cf_threshold_signature::weights::PalletWeight<Self>;
 }
@@ -738,11 +781,11 @@ impl pallet_cf_threshold_signature::Config<Instance2> for Runtime {
 	type ThresholdCallable = RuntimeCall;
 	type ThresholdSignerNomination = chainflip::RandomSignerNomination;
 	type TargetChainCrypto = PolkadotCrypto;
-	type VaultActivator = PolkadotVault;
+	type VaultActivator = MultiVaultActivator<PolkadotVault, AssethubVault>;
 	type OffenceReporter = Reputation;
 	type CeremonyRetryDelay = ConstU32<1>;
 	type SafeMode = RuntimeSafeMode;
-	type Slasher = FlipSlasher<Self>;
+	type Slasher = DelegatedStakeSlasher;
 	type CfeMultisigRequest = CfeInterface;
 	type Weights = pallet_cf_threshold_signature::weights::PalletWeight<Self>;
 }
@@ -758,7 +801,7 @@ impl pallet_cf_threshold_signature::Config<Instance3> for Runtime {
 	type OffenceReporter = Reputation;
 	type CeremonyRetryDelay = ConstU32<1>;
 	type SafeMode = RuntimeSafeMode;
-	type Slasher = FlipSlasher<Self>;
+	type Slasher = DelegatedStakeSlasher;
 	type CfeMultisigRequest = CfeInterface;
 	type Weights = pallet_cf_threshold_signature::weights::PalletWeight<Self>;
 }
@@ -774,7 +817,7 @@ impl pallet_cf_threshold_signature::Config<Instance5> for Runtime {
 	type OffenceReporter = Reputation;
 	type CeremonyRetryDelay = ConstU32<1>;
 	type SafeMode = RuntimeSafeMode;
-	type Slasher = FlipSlasher<Self>;
+	type Slasher = DelegatedStakeSlasher;
 	type CfeMultisigRequest = CfeInterface;
 	type Weights = pallet_cf_threshold_signature::weights::PalletWeight<Self>;
 }
@@ -899,6 +942,30 @@ impl pallet_cf_broadcast::Config<Instance5> for Runtime {
 	type CfeBroadcastRequest = CfeInterface;
 }
 
+impl pallet_cf_broadcast::Config<Instance6> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeOrigin = RuntimeOrigin;
+	type BroadcastCallable = RuntimeCall;
+	type Offence = chainflip::Offence;
+	type TargetChain = Assethub;
+	type ApiCall = AssethubApi<HubEnvironment>;
+	type ThresholdSigner = PolkadotThresholdSigner;
+	type TransactionBuilder = chainflip::HubTransactionBuilder;
+	type BroadcastSignerNomination = chainflip::RandomSignerNomination;
+	type OffenceReporter = Reputation;
+	type EnsureThresholdSigned =
+		pallet_cf_threshold_signature::EnsureThresholdSigned<Self, PolkadotInstance>;
+	type BroadcastReadyProvider = BroadcastReadyProvider;
+	type BroadcastTimeout = ConstU32<{ 10 * MINUTES }>;
+	type WeightInfo = pallet_cf_broadcast::weights::PalletWeight<Runtime>;
+	type SafeMode = RuntimeSafeMode;
+	type SafeModeBlockMargin = ConstU32<10>;
+	type ChainTracking = AssethubChainTracking;
+	type RetryPolicy = DefaultRetryPolicy;
+	type CfeBroadcastRequest = CfeInterface;
+}
+
 impl pallet_cf_chain_tracking::Config<Instance1> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type TargetChain = Ethereum;
@@ -929,6 +996,12 @@ impl pallet_cf_chain_tracking::Config<Instance5> for Runtime {
 	type WeightInfo = pallet_cf_chain_tracking::weights::PalletWeight<Runtime>;
 }
 
+impl pallet_cf_chain_tracking::Config<Instance6> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type TargetChain = Assethub;
+	type WeightInfo = pallet_cf_chain_tracking::weights::PalletWeight<Runtime>;
+}
+
 construct_runtime!(
 	pub struct Runtime
 	{
@@ -989,6 +1062,11 @@ construct_runtime!(
 		SolanaThresholdSigner: pallet_cf_threshold_signature::<Instance5>,
 		SolanaBroadcaster: pallet_cf_broadcast::<Instance5>,
 		SolanaIngressEgress: pallet_cf_ingress_egress::<Instance5>,
+
+		AssethubChainTracking: pallet_cf_chain_tracking::<Instance6>,
+		AssethubVault: pallet_cf_vaults::<Instance6>,
+		AssethubBroadcaster: pallet_cf_broadcast::<Instance6>,
+		AssethubIngressEgress: pallet_cf_ingress_egress::<Instance6>,
 	}
 );
 
@@ -1069,12 +1147,14 @@ pub type PalletExecutionOrder = (
 	BitcoinChainTracking,
 	ArbitrumChainTracking,
 	SolanaChainTracking,
+	AssethubChainTracking,
 	// Vaults
 	EthereumVault,
 	PolkadotVault,
 	BitcoinVault,
 	ArbitrumVault,
 	SolanaVault,
+	AssethubVault,
 	// Threshold Signers
 	EvmThresholdSigner,
 	PolkadotThresholdSigner,
@@ -1086,6 +1166,7 @@ pub type PalletExecutionOrder = (
 	BitcoinBroadcaster,
 	ArbitrumBroadcaster,
 	SolanaBroadcaster,
+	AssethubBroadcaster,
 	// Swapping and Liquidity Provision
 	Swapping,
 	LiquidityProvider,
@@ -1095,6 +1176,7 @@ pub type PalletExecutionOrder = (
 	BitcoinIngressEgress,
 	ArbitrumIngressEgress,
 	SolanaIngressEgress,
+	AssethubIngressEgress,
 	// Liquidity Pools
 	LiquidityPools,
 );
@@ -1127,11 +1209,13 @@ type PalletMigrations = (
 	pallet_cf_chain_tracking::migrations::PalletMigration<Runtime, BitcoinInstance>,
 	pallet_cf_chain_tracking::migrations::PalletMigration<Runtime, ArbitrumInstance>,
 	// pallet_cf_chain_tracking::migrations::PalletMigration<Runtime, SolanaInstance>,
+	// pallet_cf_chain_tracking::migrations::PalletMigration<Runtime, AssethubInstance>,
 	pallet_cf_vaults::migrations::PalletMigration<Runtime, EthereumInstance>,
 	pallet_cf_vaults::migrations::PalletMigration<Runtime, PolkadotInstance>,
 	pallet_cf_vaults::migrations::PalletMigration<Runtime, BitcoinInstance>,
 	pallet_cf_vaults::migrations::PalletMigration<Runtime, ArbitrumInstance>,
 	// pallet_cf_vaults::migrations::PalletMigration<Runtime, SolanaInstance>,
+	// pallet_cf_vaults::migrations::PalletMigration<Runtime, AssethubInstance>,
 	pallet_cf_threshold_signature::migrations::PalletMigration<Runtime, EvmInstance>,
 	pallet_cf_threshold_signature::migrations::PalletMigration<Runtime, PolkadotInstance>,
 	pallet_cf_threshold_signature::migrations::PalletMigration<Runtime, BitcoinInstance>,
@@ -1141,6 +1225,7 @@ type PalletMigrations = (
 	pallet_cf_broadcast::migrations::PalletMigration<Runtime, BitcoinInstance>,
 	pallet_cf_broadcast::migrations::PalletMigration<Runtime, ArbitrumInstance>,
 	// pallet_cf_broadcast::migrations::PalletMigration<Runtime, SolanaInstance>,
+	// pallet_cf_broadcast::migrations::PalletMigration<Runtime, AssethubInstance>,
 	pallet_cf_swapping::migrations::PalletMigration<Runtime>,
 	pallet_cf_lp::migrations::PalletMigration<Runtime>,
 	pallet_cf_ingress_egress::migrations::PalletMigration<Runtime, EthereumInstance>,
@@ -1148,6 +1233,7 @@ type PalletMigrations = (
 	pallet_cf_ingress_egress::migrations::PalletMigration<Runtime, BitcoinInstance>,
 	pallet_cf_ingress_egress::migrations::PalletMigration<Runtime, ArbitrumInstance>,
 	// pallet_cf_ingress_egress::migrations::PalletMigration<Runtime, SolanaInstance>,
+	// pallet_cf_ingress_egress::migrations::PalletMigration<Runtime, AssethubInstance>,
 	pallet_cf_pools::migrations::PalletMigration<Runtime>,
 	pallet_cf_cfe_interface::migrations::PalletMigration<Runtime>,
 	// TODO: After this migration is run, remember to un-comment the
@@ -1158,6 +1244,20 @@ type PalletMigrations = (
 		10,
 		11,
 	>,
+	// TODO: After this migration is run, remember to un-comment the
+	// Assethub-specific pallet migrations.
+	VersionedMigration<
+		pallet_cf_environment::Pallet<Runtime>,
+		migrations::assethub_integration::AssethubIntegration,
+		11,
+		12,
+	>,
+	VersionedMigration<
+		pallet_cf_environment::Pallet<Runtime>,
+		migrations::solana_usdc::SolanaUsdc,
+		12,
+		13,
+	>,
 );
 
 // TODO: After this  release, remember to un-comment the
@@ -1198,6 +1298,149 @@ mod benches {
 	);
 }
 
+/// Withholds the ingress or egress fee from an amount of any asset, returning the remaining amount
+/// and the fee.
+fn remove_fees(
+	ingress_or_egress: IngressOrEgress,
+	asset: Asset,
+	amount: AssetAmount,
+) -> (AssetAmount, AssetAmount) {
+	use pallet_cf_ingress_egress::AmountAndFeesWithheld;
+
+	match asset.into() {
+		ForeignChainAndAsset::Ethereum(asset) => {
+			let AmountAndFeesWithheld {
+				amount_after_fees,
+				fees_withheld,
+			} = pallet_cf_ingress_egress::Pallet::<Runtime, EthereumInstance>::withhold_ingress_or_egress_fee(ingress_or_egress, asset, amount.unique_saturated_into());
+
+			(amount_after_fees, fees_withheld)
+		},
+		ForeignChainAndAsset::Polkadot(asset) => {
+			let AmountAndFeesWithheld {
+				amount_after_fees,
+				fees_withheld,
+			} = pallet_cf_ingress_egress::Pallet::<Runtime, PolkadotInstance>::withhold_ingress_or_egress_fee(ingress_or_egress, asset, amount.unique_saturated_into());
+
+			(amount_after_fees, fees_withheld)
+		},
+		ForeignChainAndAsset::Bitcoin(asset) => {
+			let AmountAndFeesWithheld {
+				amount_after_fees,
+				fees_withheld,
+			} = pallet_cf_ingress_egress::Pallet::<Runtime, BitcoinInstance>::withhold_ingress_or_egress_fee(ingress_or_egress, asset, amount.unique_saturated_into());
+
+			(amount_after_fees.into(), fees_withheld.into())
+		},
+		ForeignChainAndAsset::Arbitrum(asset) => {
+			let AmountAndFeesWithheld {
+				amount_after_fees,
+				fees_withheld,
+			} = pallet_cf_ingress_egress::Pallet::<Runtime, ArbitrumInstance>::withhold_ingress_or_egress_fee(ingress_or_egress, asset, amount.unique_saturated_into());
+
+			(amount_after_fees, fees_withheld)
+		},
+		ForeignChainAndAsset::Solana(asset) => {
+			let AmountAndFeesWithheld {
+				amount_after_fees,
+				fees_withheld,
+			} = pallet_cf_ingress_egress::Pallet::<Runtime, SolanaInstance>::withhold_ingress_or_egress_fee(ingress_or_egress, asset, amount.unique_saturated_into());
+
+			(amount_after_fees, fees_withheld)
+		},
+		ForeignChainAndAsset::Assethub(asset) => {
+			let AmountAndFeesWithheld {
+				amount_after_fees,
+				fees_withheld,
+			} = pallet_cf_ingress_egress::Pallet::<Runtime, AssethubInstance>::withhold_ingress_or_egress_fee(ingress_or_egress, asset, amount.unique_saturated_into());
+
+			(amount_after_fees, fees_withheld)
+		},
+	}
+}
+
+/// The fee a deposit of any asset would be charged for being boosted by pools with fees of up to
+/// `max_boost_fee`, or `None` if it would not be boosted.
+fn boost_fee_estimate(
+	asset: Asset,
+	amount: AssetAmount,
+	max_boost_fee: BasisPoints,
+) -> Option<AssetAmount> {
+	if max_boost_fee == 0 {
+		return None
+	}
+
+	match asset.into() {
+		ForeignChainAndAsset::Ethereum(asset) =>
+			pallet_cf_ingress_egress::Pallet::<Runtime, EthereumInstance>::boost_fee_estimate(asset, amount.unique_saturated_into(), max_boost_fee),
+		ForeignChainAndAsset::Polkadot(asset) =>
+			pallet_cf_ingress_egress::Pallet::<Runtime, PolkadotInstance>::boost_fee_estimate(asset, amount.unique_saturated_into(), max_boost_fee),
+		ForeignChainAndAsset::Bitcoin(asset) =>
+			pallet_cf_ingress_egress::Pallet::<Runtime, BitcoinInstance>::boost_fee_estimate(asset, amount.unique_saturated_into(), max_boost_fee).map(Into::into),
+		ForeignChainAndAsset::Arbitrum(asset) =>
+			pallet_cf_ingress_egress::Pallet::<Runtime, ArbitrumInstance>::boost_fee_estimate(asset, amount.unique_saturated_into(), max_boost_fee),
+		ForeignChainAndAsset::Solana(asset) =>
+			pallet_cf_ingress_egress::Pallet::<Runtime, SolanaInstance>::boost_fee_estimate(asset, amount.unique_saturated_into(), max_boost_fee),
+		ForeignChainAndAsset::Assethub(asset) =>
+			pallet_cf_ingress_egress::Pallet::<Runtime, AssethubInstance>::boost_fee_estimate(asset, amount.unique_saturated_into(), max_boost_fee),
+	}
+}
+
+/// The gas budget, in the gas asset of `chain`, that a CCM needs for its receiver to be able to use
+/// `gas_limit` gas, or `None` if the chain does not support CCM.
+fn ccm_gas_budget_estimate(
+	chain: ForeignChain,
+	gas_limit: u128,
+	message_length: u32,
+) -> Option<AssetAmount> {
+	use cf_chains::FeeEstimationApi;
+
+	match chain {
+		ForeignChain::Ethereum => EthereumChainTracking::chain_state()?
+			.tracked_data
+			.estimate_ccm_gas_budget(gas_limit, message_length),
+		ForeignChain::Polkadot => PolkadotChainTracking::chain_state()?
+			.tracked_data
+			.estimate_ccm_gas_budget(gas_limit, message_length),
+		ForeignChain::Bitcoin => BitcoinChainTracking::chain_state()?
+			.tracked_data
+			.estimate_ccm_gas_budget(gas_limit, message_length)
+			.map(Into::into),
+		ForeignChain::Arbitrum => ArbitrumChainTracking::chain_state()?
+			.tracked_data
+			.estimate_ccm_gas_budget(gas_limit, message_length),
+		ForeignChain::Solana => SolanaChainTracking::chain_state()?
+			.tracked_data
+			.estimate_ccm_gas_budget(gas_limit, message_length)
+			.map(Into::into),
+		ForeignChain::Assethub => AssethubChainTracking::chain_state()?
+			.tracked_data
+			.estimate_ccm_gas_budget(gas_limit, message_length),
+	}
+}
+
+/// The part of a CCM's gas budget, in the gas asset of `chain`, that the receiver can use. The gas
+/// limit of CCM calls on EVM chains is capped, so any budget beyond what the capped gas limit costs
+/// at the current gas price is never used.
+fn ccm_usable_gas_budget(chain: ForeignChain, gas_budget: AssetAmount) -> AssetAmount {
+	match chain {
+		ForeignChain::Ethereum => EthereumChainTracking::chain_state().map(|chain_state| {
+			chain_state
+				.tracked_data
+				.max_fee_per_gas(One::one())
+				.saturating_mul(chainflip::ETHEREUM_MAX_GAS_LIMIT)
+		}),
+		ForeignChain::Arbitrum => ArbitrumChainTracking::chain_state().map(|chain_state| {
+			chain_state
+				.tracked_data
+				.max_fee_per_gas(One::one())
+				.saturating_mul(chainflip::ARBITRUM_MAX_GAS_LIMIT)
+		}),
+		_ => None,
+	}
+	.map_or(gas_budget, |max_gas_budget| gas_budget.min(max_gas_budget))
+}
+
 impl_runtime_apis! {
 	// START custom runtime APIs
 	impl runtime_apis::CustomRuntimeApi<Block> for Runtime {
@@ -1353,6 +1596,10 @@ impl_runtime_apis! {
 			LiquidityPools::pool_price(base_asset, quote_asset).map_err(Into::into)
 		}
 
+		fn cf_pool_twap(base_asset: Asset, quote_asset: Asset, from_block: BlockNumber, to_block: BlockNumber) -> Result<PoolTwap, DispatchErrorWithMessage> {
+			LiquidityPools::pool_twap(base_asset, quote_asset, from_block, to_block).map_err(Into::into)
+		}
+
 		/// Simulates a swap and return the intermediate (if any) and final output.
 		///
 		/// If no swap rate can be calculated, returns None. This can happen if the pools are not
@@ -1391,56 +1638,9 @@ impl_runtime_apis! {
 				}
 			}
 
-			fn remove_fees(ingress_or_egress: IngressOrEgress, asset: Asset, amount: AssetAmount) -> (AssetAmount, AssetAmount) {
-				use pallet_cf_ingress_egress::AmountAndFeesWithheld;
-
-				match asset.into() {
-					ForeignChainAndAsset::Ethereum(asset) => {
-						let AmountAndFeesWithheld {
-							amount_after_fees,
-							fees_withheld,
-						} = pallet_cf_ingress_egress::Pallet::<Runtime, EthereumInstance>::withhold_ingress_or_egress_fee(ingress_or_egress, asset, amount.unique_saturated_into());
-
-						(amount_after_fees, fees_withheld)
-					},
-					ForeignChainAndAsset::Polkadot(asset) => {
-						let AmountAndFeesWithheld {
-							amount_after_fees,
-							fees_withheld,
-						} = pallet_cf_ingress_egress::Pallet::<Runtime, PolkadotInstance>::withhold_ingress_or_egress_fee(ingress_or_egress, asset, amount.unique_saturated_into());
-
-						(amount_after_fees, fees_withheld)
-					},
-					ForeignChainAndAsset::Bitcoin(asset) => {
-						let AmountAndFeesWithheld {
-							amount_after_fees,
-							fees_withheld,
-						} = pallet_cf_ingress_egress::Pallet::<Runtime, BitcoinInstance>::withhold_ingress_or_egress_fee(ingress_or_egress, asset, amount.unique_saturated_into());
-
-						(amount_after_fees.into(), fees_withheld.into())
-					},
-					ForeignChainAndAsset::Arbitrum(asset) => {
-						let AmountAndFeesWithheld {
-							amount_after_fees,
-							fees_withheld,
-						} = pallet_cf_ingress_egress::Pallet::<Runtime, ArbitrumInstance>::withhold_ingress_or_egress_fee(ingress_or_egress, asset, amount.unique_saturated_into());
-
-						(amount_after_fees, fees_withheld)
-					},
-					ForeignChainAndAsset::Solana(asset) => {
-						let AmountAndFeesWithheld {
-							amount_after_fees,
-							fees_withheld,
-						} = pallet_cf_ingress_egress::Pallet::<Runtime, SolanaInstance>::withhold_ingress_or_egress_fee(ingress_or_egress, asset, amount.unique_saturated_into());
-
-						(amount_after_fees, fees_withheld)
-					},
-				}
-			}
-
 			let (amount_to_swap, ingress_fee) = remove_fees(IngressOrEgress::Ingress, from, amount);
 
-			let swap_output = LiquidityPools::swap_with_network_fee(
+			let (route, swap_output) = Swapping::simulate_swap(
 				from,
 				to,
 				amount_to_swap,
@@ -1454,9 +1654,101 @@ impl_runtime_apis! {
 				network_fee: swap_output.network_fee,
 				ingress_fee,
 				egress_fee,
+				route,
 			})
 		}
 
+		fn cf_swap_quote(
+			from: Asset,
+			to: Asset,
+			deposit_amount: AssetAmount,
+			broker_commission: BasisPoints,
+			boost_fee: BasisPoints,
+		) -> Result<SwapQuote, DispatchErrorWithMessage> {
+			// The fees are taken in the same order as when a deposit is made to a swap channel:
+			let boost_fee = boost_fee_estimate(from, deposit_amount, boost_fee);
+			let (amount_after_ingress_fee, ingress_fee) = remove_fees(
+				IngressOrEgress::Ingress,
+				from,
+				deposit_amount.saturating_sub(boost_fee.unwrap_or_default()),
+			);
+			let broker_fee = Swapping::broker_fee(broker_commission, amount_after_ingress_fee);
+			let swap_input = amount_after_ingress_fee.saturating_sub(broker_fee);
+
+			let (route, swap_output) = Swapping::simulate_swap(from, to, swap_input)?;
+
+			let (output, egress_fee) = remove_fees(IngressOrEgress::Egress, to, swap_output.output);
+
+			Ok(SwapQuote {
+				boost_fee,
+				ingress_fee,
+				broker_fee,
+				swap_input,
+				intermediary: swap_output.intermediary,
+				network_fee: swap_output.network_fee,
+				swap_output: swap_output.output,
+				egress_fee,
+				output,
+				route,
+			})
+		}
+
+		fn cf_ccm_gas_budget_estimate(
+			source_asset: Asset,
+			destination_chain: ForeignChain,
+			message_length: u32,
+			gas_limit: u128,
+		) -> Result<CcmGasBudgetEstimate, DispatchErrorWithMessage> {
+			let gas_budget = ccm_gas_budget_estimate(destination_chain, gas_limit, message_length)
+				.ok_or(DispatchError::from(
+					pallet_cf_swapping::Error::<Runtime>::CcmUnsupportedForTargetChain,
+				))?;
+			let gas_asset = destination_chain.gas_asset();
+
+			Ok(CcmGasBudgetEstimate {
+				gas_asset,
+				gas_budget,
+				source_asset_gas_budget: Swapping::estimate_swap_input(
+					source_asset,
+					gas_asset,
+					gas_budget,
+				)?,
+			})
+		}
+
+		fn cf_ccm_deposit_check(
+			source_asset: Asset,
+			destination_asset: Asset,
+			deposit_amount: AssetAmount,
+			channel_metadata: CcmChannelMetadata,
+		) -> Result<CcmDepositCheck, DispatchErrorWithMessage> {
+			// The CCM is checked against the amount that is left after the ingress fee.
+			let (amount_after_ingress_fee, _) =
+				remove_fees(IngressOrEgress::Ingress, source_asset, deposit_amount);
+
+			match Swapping::principal_and_gas_amounts(
+				amount_after_ingress_fee,
+				&channel_metadata,
+				source_asset,
+				destination_asset,
+			) {
+				Ok(CcmSwapAmounts { principal_swap_amount, gas_budget, other_gas_asset }) =>
+					Ok(CcmDepositCheck::Accepted {
+						principal_swap_amount,
+						gas_budget,
+						estimated_destination_gas_budget: ccm_usable_gas_budget(
+							destination_asset.into(),
+							match other_gas_asset {
+								Some(gas_asset) =>
+									Swapping::simulate_swap(source_asset, gas_asset, gas_budget)?.1.output,
+								None => gas_budget,
+							},
+						),
+					}),
+				Err(reason) => Ok(CcmDepositCheck::Rejected(reason)),
+			}
+		}
+
 		fn cf_pool_info(base_asset: Asset, quote_asset: Asset) -> Result<PoolInfo, DispatchErrorWithMessage> {
 			LiquidityPools::pool_info(base_asset, quote_asset).map_err(Into::into)
 		}
@@ -1518,6 +1810,7 @@ impl_runtime_apis! {
 				ForeignChainAndAsset::Bitcoin(asset) => MinimumDeposit::<Runtime, BitcoinInstance>::get(asset).into(),
 				ForeignChainAndAsset::Arbitrum(asset) => MinimumDeposit::<Runtime, ArbitrumInstance>::get(asset),
 				ForeignChainAndAsset::Solana(asset) => MinimumDeposit::<Runtime, SolanaInstance>::get(asset),
+				ForeignChainAndAsset::Assethub(asset) => MinimumDeposit::<Runtime, AssethubInstance>::get(asset),
 			}
 		}
 
@@ -1530,6 +1823,7 @@ impl_runtime_apis! {
 				ForeignChainAndAsset::Bitcoin(asset) => EgressDustLimit::<Runtime, BitcoinInstance>::get(asset),
 				ForeignChainAndAsset::Arbitrum(asset) => EgressDustLimit::<Runtime, ArbitrumInstance>::get(asset),
 				ForeignChainAndAsset::Solana(asset) => EgressDustLimit::<Runtime, SolanaInstance>::get(asset),
+				ForeignChainAndAsset::Assethub(asset) => EgressDustLimit::<Runtime, AssethubInstance>::get(asset),
 			}
 		}
 
@@ -1551,6 +1845,12 @@ impl_runtime_apis! {
 				},
 				ForeignChainAndAsset::Solana(asset) => Some(pallet_cf_chain_tracking::Pallet::<Runtime, SolanaInstance>::
 				estimate_ingress_fee(asset)),
+				ForeignChainAndAsset::Assethub(asset) => {
+					pallet_cf_pools::Pallet::<Runtime>::calculate_input_for_gas_output::<Assethub>(
+						asset,
+						pallet_cf_chain_tracking::Pallet::<Runtime, AssethubInstance>::estimate_ingress_fee(asset)
+					)
+				},
 			}
 		}
 
@@ -1572,6 +1872,12 @@ impl_runtime_apis! {
 				},
 				ForeignChainAndAsset::Solana(asset) => Some(pallet_cf_chain_tracking::Pallet::<Runtime, SolanaInstance>::
 				estimate_egress_fee(asset)),
+				ForeignChainAndAsset::Assethub(asset) => {
+					pallet_cf_pools::Pallet::<Runtime>::calculate_input_for_gas_output::<Assethub>(
+						asset,
+						pallet_cf_chain_tracking::Pallet::<Runtime, AssethubInstance>::estimate_egress_fee(asset)
+					)
+				},
 			}
 		}
 
@@ -1582,6 +1888,7 @@ impl_runtime_apis! {
 				ForeignChain::Polkadot => pallet_cf_ingress_egress::Pallet::<Runtime, PolkadotInstance>::witness_safety_margin().map(Into::into),
 				ForeignChain::Arbitrum => pallet_cf_ingress_egress::Pallet::<Runtime, ArbitrumInstance>::witness_safety_margin(),
 				ForeignChain::Solana => pallet_cf_ingress_egress::Pallet::<Runtime, SolanaInstance>::witness_safety_margin(),
+				ForeignChain::Assethub => pallet_cf_ingress_egress::Pallet::<Runtime, AssethubInstance>::witness_safety_margin().map(Into::into),
 			}
 		}
 
@@ -1715,6 +2022,11 @@ impl_runtime_apis! {
 							}) => {
 								all_prewitnessed_swaps.extend(filter_deposit_swaps::<Polkadot, PolkadotInstance>(from, to, deposit_witnesses));
 							},
+							RuntimeCall::AssethubIngressEgress(pallet_cf_ingress_egress::Call::process_deposits {
+								deposit_witnesses, ..
+							}) => {
+								all_prewitnessed_swaps.extend(filter_deposit_swaps::<Assethub, AssethubInstance>(from, to, deposit_witnesses));
+							},
 							RuntimeCall::Swapping(pallet_cf_swapping::Call::ccm_deposit {
 								source_asset, deposit_amount, destination_asset, deposit_metadata, ..
 							}) => {
@@ -1735,7 +2047,7 @@ impl_runtime_apis! {
 			all_prewitnessed_swaps
 		}
 
-		fn cf_scheduled_swaps(base_asset: Asset, _quote_asset: Asset) -> Vec<(SwapLegInfo, BlockNumber)> {
+		fn cf_scheduled_swaps(base_asset: Asset, quote_asset: Asset) -> Vec<(SwapLegInfo, BlockNumber)> {
 
 			let current_block = System::block_number();
 
@@ -1744,8 +2056,7 @@ impl_runtime_apis! {
 				// In case `block` has already passed, the swaps will be re-tried at the next block:
 				let execute_at = core::cmp::max(block, current_block.saturating_add(1));
 
-				let swaps: Vec<_> = swaps_for_block.iter().filter(|swap| swap.from == base_asset || swap.to == base_asset).cloned().collect();
-				Swapping::get_scheduled_swap_legs(swaps, base_asset).unwrap().into_iter().map(move |swap| (swap, execute_at))
+				Swapping::get_scheduled_swap_legs(swaps_for_block, base_asset, quote_asset).unwrap().into_iter().map(move |swap| (swap, execute_at))
 			}).collect()
 		}
 
@@ -1794,6 +2105,7 @@ impl_runtime_apis! {
 				ForeignChain::Bitcoin => pallet_cf_ingress_egress::Pallet::<Runtime, BitcoinInstance>::channel_opening_fee(),
 				ForeignChain::Arbitrum => pallet_cf_ingress_egress::Pallet::<Runtime, ArbitrumInstance>::channel_opening_fee(),
 				ForeignChain::Solana => pallet_cf_ingress_egress::Pallet::<Runtime, SolanaInstance>::channel_opening_fee(),
+				ForeignChain::Assethub => pallet_cf_ingress_egress::Pallet::<Runtime, AssethubInstance>::channel_opening_fee(),
 			}
 		}
 
@@ -1835,6 +2147,7 @@ impl_runtime_apis! {
 					ForeignChain::Bitcoin => boost_pools_depth::<BitcoinInstance>(),
 					ForeignChain::Arbitrum => boost_pools_depth::<ArbitrumInstance>(),
 					ForeignChain::Solana => boost_pools_depth::<SolanaInstance>(),
+					ForeignChain::Assethub => boost_pools_depth::<AssethubInstance>(),
 				}
 			}).collect()
 
@@ -1871,9 +2184,28 @@ impl_runtime_apis! {
 				ForeignChain::Bitcoin => boost_pools_details::<BitcoinInstance>(asset.try_into().unwrap()),
 				ForeignChain::Arbitrum => boost_pools_details::<ArbitrumInstance>(asset.try_into().unwrap()),
 				ForeignChain::Solana => boost_pools_details::<SolanaInstance>(asset.try_into().unwrap()),
+				ForeignChain::Assethub => boost_pools_details::<AssethubInstance>(asset.try_into().unwrap()),
 			}
 
 		}
+
+		fn cf_aborted_broadcasts() -> Vec<(ForeignChain, BroadcastId)> {
+			ForeignChain::iter().flat_map(|chain| {
+				match chain {
+					ForeignChain::Ethereum => EthereumBroadcaster::aborted_broadcasts(),
+					ForeignChain::Polkadot => PolkadotBroadcaster::aborted_broadcasts(),
+					ForeignChain::Bitcoin => BitcoinBroadcaster::aborted_broadcasts(),
+					ForeignChain::Arbitrum => ArbitrumBroadcaster::aborted_broadcasts(),
+					ForeignChain::Solana => SolanaBroadcaster::aborted_broadcasts(),
+					ForeignChain::Assethub => AssethubBroadcaster::aborted_broadcasts(),
+				}.into_iter().map(move |broadcast_id| (chain, broadcast_id))
+			}).collect()
+		}
+
+		fn cf_time_locked_governance_proposals(
+		) -> Vec<(ProposalId, TimeLockedProposal<AccountId>)> {
+			Governance::time_locked_proposals()
+		}
 	}
 
 	impl monitoring_apis::MonitoringRuntimeApi<Block> for Runtime {
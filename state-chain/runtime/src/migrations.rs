@@ -3,9 +3,11 @@ use crate::System;
 use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
 use sp_std::marker::PhantomData;
 
+pub mod assethub_integration;
 pub mod housekeeping;
 pub mod reap_old_accounts;
 pub mod solana_integration;
+pub mod solana_usdc;
 
 #[cfg(feature = "try-runtime")]
 use sp_std::{vec, vec::Vec};
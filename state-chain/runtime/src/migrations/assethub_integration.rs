@@ -0,0 +1,186 @@
+use crate::{safe_mode, Runtime};
+use cf_chains::{
+	dot::PolkadotHash,
+	instances::{
+		ArbitrumInstance, AssethubInstance, BitcoinInstance, EthereumInstance, PolkadotInstance,
+		SolanaInstance,
+	},
+};
+use cf_traits::SafeMode;
+use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::DispatchError;
+#[cfg(feature = "try-runtime")]
+use sp_std::{vec, vec::Vec};
+
+pub mod old {
+	use super::*;
+	use cf_chains::instances::{BitcoinCryptoInstance, EvmInstance, PolkadotCryptoInstance};
+	use frame_support::pallet_prelude::*;
+
+	#[derive(
+		Encode, Decode, MaxEncodedLen, TypeInfo, Default, Copy, Clone, PartialEq, Eq, RuntimeDebug,
+	)]
+	pub struct WitnesserCallPermission {
+		pub governance: bool,
+		pub funding: bool,
+		pub swapping: bool,
+		pub ethereum_broadcast: bool,
+		pub ethereum_chain_tracking: bool,
+		pub ethereum_ingress_egress: bool,
+		pub ethereum_vault: bool,
+		pub polkadot_broadcast: bool,
+		pub polkadot_chain_tracking: bool,
+		pub polkadot_ingress_egress: bool,
+		pub polkadot_vault: bool,
+		pub bitcoin_broadcast: bool,
+		pub bitcoin_chain_tracking: bool,
+		pub bitcoin_ingress_egress: bool,
+		pub bitcoin_vault: bool,
+		pub arbitrum_broadcast: bool,
+		pub arbitrum_chain_tracking: bool,
+		pub arbitrum_ingress_egress: bool,
+		pub arbitrum_vault: bool,
+		pub solana_broadcast: bool,
+		pub solana_chain_tracking: bool,
+		pub solana_ingress_egress: bool,
+		pub solana_vault: bool,
+	}
+
+	#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, RuntimeDebug, PartialEq, Eq)]
+	pub struct RuntimeSafeMode {
+		pub emissions: pallet_cf_emissions::PalletSafeMode,
+		pub funding: pallet_cf_funding::PalletSafeMode,
+		pub swapping: pallet_cf_swapping::PalletSafeMode,
+		pub liquidity_provider: pallet_cf_lp::PalletSafeMode,
+		pub validator: pallet_cf_validator::PalletSafeMode,
+		pub pools: pallet_cf_pools::PalletSafeMode,
+		pub reputation: pallet_cf_reputation::PalletSafeMode,
+		pub threshold_signature_evm: pallet_cf_threshold_signature::PalletSafeMode<EvmInstance>,
+		pub threshold_signature_bitcoin:
+			pallet_cf_threshold_signature::PalletSafeMode<BitcoinCryptoInstance>,
+		pub threshold_signature_polkadot:
+			pallet_cf_threshold_signature::PalletSafeMode<PolkadotCryptoInstance>,
+		pub broadcast_ethereum: pallet_cf_broadcast::PalletSafeMode<EthereumInstance>,
+		pub broadcast_bitcoin: pallet_cf_broadcast::PalletSafeMode<BitcoinInstance>,
+		pub broadcast_polkadot: pallet_cf_broadcast::PalletSafeMode<PolkadotInstance>,
+		pub broadcast_arbitrum: pallet_cf_broadcast::PalletSafeMode<ArbitrumInstance>,
+		pub ingress_egress_ethereum: pallet_cf_ingress_egress::PalletSafeMode<EthereumInstance>,
+		pub ingress_egress_bitcoin: pallet_cf_ingress_egress::PalletSafeMode<BitcoinInstance>,
+		pub ingress_egress_polkadot: pallet_cf_ingress_egress::PalletSafeMode<PolkadotInstance>,
+		pub ingress_egress_arbitrum: pallet_cf_ingress_egress::PalletSafeMode<ArbitrumInstance>,
+		pub witnesser: pallet_cf_witnesser::PalletSafeMode<WitnesserCallPermission>,
+	}
+}
+
+fn translate_witnesser_permission(
+	old: old::WitnesserCallPermission,
+) -> safe_mode::WitnesserCallPermission {
+	safe_mode::WitnesserCallPermission {
+		governance: old.governance,
+		funding: old.funding,
+		swapping: old.swapping,
+		ethereum_broadcast: old.ethereum_broadcast,
+		ethereum_chain_tracking: old.ethereum_chain_tracking,
+		ethereum_ingress_egress: old.ethereum_ingress_egress,
+		ethereum_vault: old.ethereum_vault,
+		polkadot_broadcast: old.polkadot_broadcast,
+		polkadot_chain_tracking: old.polkadot_chain_tracking,
+		polkadot_ingress_egress: old.polkadot_ingress_egress,
+		polkadot_vault: old.polkadot_vault,
+		bitcoin_broadcast: old.bitcoin_broadcast,
+		bitcoin_chain_tracking: old.bitcoin_chain_tracking,
+		bitcoin_ingress_egress: old.bitcoin_ingress_egress,
+		bitcoin_vault: old.bitcoin_vault,
+		arbitrum_broadcast: old.arbitrum_broadcast,
+		arbitrum_chain_tracking: old.arbitrum_chain_tracking,
+		arbitrum_ingress_egress: old.arbitrum_ingress_egress,
+		arbitrum_vault: old.arbitrum_vault,
+		solana_broadcast: old.solana_broadcast,
+		solana_chain_tracking: old.solana_chain_tracking,
+		solana_ingress_egress: old.solana_ingress_egress,
+		solana_vault: old.solana_vault,
+		// Asset Hub witnessing follows the Polkadot permissions.
+		assethub_broadcast: old.polkadot_broadcast,
+		assethub_chain_tracking: old.polkadot_chain_tracking,
+		assethub_ingress_egress: old.polkadot_ingress_egress,
+		assethub_vault: old.polkadot_vault,
+	}
+}
+
+pub struct AssethubIntegration;
+
+impl OnRuntimeUpgrade for AssethubIntegration {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		use frame_support::assert_ok;
+
+		// The Solana integration leaves the safe mode untouched, so it is translated from its
+		// layout before both integrations.
+		assert_ok!(pallet_cf_environment::RuntimeSafeMode::<Runtime>::translate(
+			|maybe_old: Option<old::RuntimeSafeMode>| {
+				maybe_old.map(|old| safe_mode::RuntimeSafeMode {
+					emissions: old.emissions,
+					funding: old.funding,
+					swapping: old.swapping,
+					liquidity_provider: old.liquidity_provider,
+					validator: old.validator,
+					pools: old.pools,
+					reputation: old.reputation,
+					threshold_signature_evm: old.threshold_signature_evm,
+					threshold_signature_bitcoin: old.threshold_signature_bitcoin,
+					threshold_signature_polkadot: old.threshold_signature_polkadot,
+					threshold_signature_solana: <pallet_cf_threshold_signature::PalletSafeMode<
+						SolanaInstance,
+					> as SafeMode>::CODE_GREEN,
+					broadcast_ethereum: old.broadcast_ethereum,
+					broadcast_bitcoin: old.broadcast_bitcoin,
+					broadcast_polkadot: old.broadcast_polkadot,
+					broadcast_arbitrum: old.broadcast_arbitrum,
+					broadcast_solana: <pallet_cf_broadcast::PalletSafeMode<SolanaInstance> as SafeMode>::CODE_GREEN,
+					broadcast_assethub: <pallet_cf_broadcast::PalletSafeMode<AssethubInstance> as SafeMode>::CODE_GREEN,
+					witnesser: match old.witnesser {
+						pallet_cf_witnesser::PalletSafeMode::CodeGreen =>
+							pallet_cf_witnesser::PalletSafeMode::CodeGreen,
+						pallet_cf_witnesser::PalletSafeMode::CodeRed =>
+							pallet_cf_witnesser::PalletSafeMode::CodeRed,
+						pallet_cf_witnesser::PalletSafeMode::CodeAmber(permission) =>
+							pallet_cf_witnesser::PalletSafeMode::CodeAmber(
+								translate_witnesser_permission(permission),
+							),
+					},
+					ingress_egress_ethereum: old.ingress_egress_ethereum,
+					ingress_egress_bitcoin: old.ingress_egress_bitcoin,
+					ingress_egress_polkadot: old.ingress_egress_polkadot,
+					ingress_egress_arbitrum: old.ingress_egress_arbitrum,
+					// Set safe mode on for ingress-egress to disable boost features.
+					ingress_egress_solana: <pallet_cf_ingress_egress::PalletSafeMode<SolanaInstance> as SafeMode>::CODE_RED,
+					ingress_egress_assethub: <pallet_cf_ingress_egress::PalletSafeMode<AssethubInstance> as SafeMode>::CODE_RED,
+				})
+			},
+		));
+
+		// Only the Polkadot Asset Hub is public. The Asset Hubs of the test networks are parachains
+		// of their own relay chains, so their genesis hashes are left unset here.
+		if cf_runtime_upgrade_utilities::genesis_hashes::genesis_hash::<Runtime>() ==
+			cf_runtime_upgrade_utilities::genesis_hashes::BERGHAIN
+		{
+			pallet_cf_environment::AssethubGenesisHash::<Runtime>::put(PolkadotHash::from(
+				hex_literal::hex!(
+					"68d56f15f85d3136970ec16946040bc1752654e906147f7e43e9d539d7c3de2f"
+				),
+			));
+		}
+
+		Weight::zero()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		Ok(vec![])
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), DispatchError> {
+		Ok(())
+	}
+}
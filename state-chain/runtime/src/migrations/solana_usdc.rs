@@ -0,0 +1,66 @@
+use crate::Runtime;
+use cf_chains::sol::SolAddress;
+use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::DispatchError;
+#[cfg(feature = "try-runtime")]
+use sp_std::{vec, vec::Vec};
+
+/// The fee of the Solana USDC pool, in hundredths of a pip.
+const SOL_USDC_POOL_FEE_HUNDREDTH_PIPS: u32 = 20;
+
+pub struct SolanaUsdc;
+
+impl OnRuntimeUpgrade for SolanaUsdc {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		let usdc_address: SolAddress =
+			match cf_runtime_upgrade_utilities::genesis_hashes::genesis_hash::<Runtime>() {
+				// EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v
+				cf_runtime_upgrade_utilities::genesis_hashes::BERGHAIN => hex_literal::hex!(
+					"c6fa7af3bedbad3a3d65f36aabc97431b1bbe4c2d2f6e0e47ca60203452f5d61"
+				)
+				.into(),
+				// 4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU
+				cf_runtime_upgrade_utilities::genesis_hashes::PERSEVERANCE |
+				cf_runtime_upgrade_utilities::genesis_hashes::SISYPHOS => hex_literal::hex!(
+					"3b442cb3912157f13a933d0134282d032b5ffecd01a2dbf1b7790608df002ea7"
+				)
+				.into(),
+				_ => {
+					// Assume testnet
+					// 24PNhTaNtomHhoy3fTRaMhAFCRj4uHqhZEEoWrKDbR5p
+					hex_literal::hex!(
+						"0fb9ba52b1f09445f1e3a7508d59f0797923acf744fbe2da303fb06da859ee87"
+					)
+					.into()
+				},
+			};
+
+		pallet_cf_environment::SolanaSupportedAssets::<Runtime>::insert(
+			cf_primitives::chains::assets::sol::Asset::SolUsdc,
+			usdc_address,
+		);
+
+		// Both USDC tokens have 6 decimals, so the pool starts at a price of one.
+		if let Err(e) = pallet_cf_pools::Pallet::<Runtime>::create_pool(
+			cf_primitives::Asset::SolUsdc,
+			cf_primitives::Asset::Usdc,
+			SOL_USDC_POOL_FEE_HUNDREDTH_PIPS,
+			cf_amm::common::price_at_tick(0).expect("Tick 0 is a valid tick"),
+		) {
+			log::error!("Failed to create the Solana USDC pool: {:?}", e);
+		}
+
+		Weight::zero()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+		Ok(vec![])
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), DispatchError> {
+		Ok(())
+	}
+}
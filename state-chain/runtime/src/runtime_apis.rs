@@ -4,23 +4,24 @@ use cf_amm::{
 	range_orders::Liquidity,
 };
 use cf_chains::{
-	assets::any::AssetMap, eth::Address as EthereumAddress, Chain, ForeignChainAddress,
+	assets::any::AssetMap, eth::Address as EthereumAddress, CcmChannelMetadata, Chain,
+	ForeignChainAddress,
 };
 use cf_primitives::{
-	AccountRole, Asset, AssetAmount, BlockNumber, BroadcastId, EpochIndex, FlipBalance,
-	ForeignChain, NetworkEnvironment, PrewitnessedDepositId, SemVer,
+	AccountRole, Asset, AssetAmount, BasisPoints, BlockNumber, BroadcastId, EpochIndex,
+	FlipBalance, ForeignChain, NetworkEnvironment, PrewitnessedDepositId, SemVer,
 };
 use codec::{Decode, Encode};
 use core::ops::Range;
 use frame_support::sp_runtime::AccountId32;
 use frame_system::EventRecord;
-use pallet_cf_governance::GovCallHash;
+use pallet_cf_governance::{GovCallHash, ProposalId, TimeLockedProposal};
 pub use pallet_cf_ingress_egress::OwedAmount;
 use pallet_cf_pools::{
 	AskBidMap, PoolInfo, PoolLiquidity, PoolOrderbook, PoolOrders, PoolPriceV1, PoolPriceV2,
-	UnidirectionalPoolDepth,
+	PoolTwap, UnidirectionalPoolDepth,
 };
-use pallet_cf_swapping::SwapLegInfo;
+use pallet_cf_swapping::{CcmFailReason, SwapLegInfo};
 use pallet_cf_witnesser::CallHash;
 use scale_info::{prelude::string::String, TypeInfo};
 use serde::{Deserialize, Serialize};
@@ -136,6 +137,59 @@ pub struct SimulatedSwapInformation {
 	pub network_fee: AssetAmount,
 	pub ingress_fee: AssetAmount,
 	pub egress_fee: AssetAmount,
+	/// The assets the swap passes through, from the input to the output asset.
+	pub route: Vec<Asset>,
+}
+
+/// The amounts a deposit to a swap channel goes through until the output is egressed, with every
+/// fee taken along the way, in the order they are taken.
+#[derive(Encode, Decode, TypeInfo)]
+pub struct SwapQuote {
+	/// Taken from the deposit amount if it is boosted. In the source asset.
+	pub boost_fee: Option<AssetAmount>,
+	/// In the source asset.
+	pub ingress_fee: AssetAmount,
+	/// Commission of the broker and its affiliates. In the source asset.
+	pub broker_fee: AssetAmount,
+	/// The amount of the source asset that is swapped.
+	pub swap_input: AssetAmount,
+	pub intermediary: Option<AssetAmount>,
+	/// Taken in the stable asset, unless the swap is routed around it.
+	pub network_fee: AssetAmount,
+	/// The amount of the destination asset the swap outputs.
+	pub swap_output: AssetAmount,
+	/// In the destination asset.
+	pub egress_fee: AssetAmount,
+	/// The amount expected to be egressed.
+	pub output: AssetAmount,
+	/// The assets the swap passes through, from the input to the output asset.
+	pub route: Vec<Asset>,
+}
+
+/// The gas budget a CCM needs for its receiver to be able to use a given amount of gas.
+#[derive(Encode, Decode, TypeInfo)]
+pub struct CcmGasBudgetEstimate {
+	/// The gas asset of the destination chain.
+	pub gas_asset: Asset,
+	/// In the destination chain's gas asset.
+	pub gas_budget: AssetAmount,
+	/// The amount of the source asset that needs to be set aside as gas budget.
+	pub source_asset_gas_budget: AssetAmount,
+}
+
+/// Whether a CCM deposit would be accepted, and if so how it would be split.
+#[derive(Encode, Decode, TypeInfo)]
+pub enum CcmDepositCheck {
+	Accepted {
+		/// In the source asset, after the ingress fee.
+		principal_swap_amount: AssetAmount,
+		/// In the source asset.
+		gas_budget: AssetAmount,
+		/// The gas budget is expected to be worth this much of the destination chain's gas
+		/// asset, capped at what the destination chain's maximum CCM gas limit costs.
+		estimated_destination_gas_budget: AssetAmount,
+	},
+	Rejected(CcmFailReason),
 }
 
 #[derive(Debug, Decode, Encode, TypeInfo)]
@@ -201,12 +255,37 @@ decl_runtime_apis!(
 			base_asset: Asset,
 			quote_asset: Asset,
 		) -> Result<PoolPriceV2, DispatchErrorWithMessage>;
+		fn cf_pool_twap(
+			base_asset: Asset,
+			quote_asset: Asset,
+			from_block: BlockNumber,
+			to_block: BlockNumber,
+		) -> Result<PoolTwap, DispatchErrorWithMessage>;
 		fn cf_pool_simulate_swap(
 			from: Asset,
 			to: Asset,
 			amount: AssetAmount,
 			additional_limit_orders: Option<Vec<SimulateSwapAdditionalOrder>>,
 		) -> Result<SimulatedSwapInformation, DispatchErrorWithMessage>;
+		fn cf_swap_quote(
+			from: Asset,
+			to: Asset,
+			deposit_amount: AssetAmount,
+			broker_commission: BasisPoints,
+			boost_fee: BasisPoints,
+		) -> Result<SwapQuote, DispatchErrorWithMessage>;
+		fn cf_ccm_gas_budget_estimate(
+			source_asset: Asset,
+			destination_chain: ForeignChain,
+			message_length: u32,
+			gas_limit: u128,
+		) -> Result<CcmGasBudgetEstimate, DispatchErrorWithMessage>;
+		fn cf_ccm_deposit_check(
+			source_asset: Asset,
+			destination_asset: Asset,
+			deposit_amount: AssetAmount,
+			channel_metadata: CcmChannelMetadata,
+		) -> Result<CcmDepositCheck, DispatchErrorWithMessage>;
 		fn cf_pool_info(
 			base_asset: Asset,
 			quote_asset: Asset,
@@ -279,5 +358,8 @@ decl_runtime_apis!(
 		fn cf_get_events(filter: EventFilter) -> Vec<EventRecord<RuntimeEvent, Hash>>;
 		fn cf_boost_pools_depth() -> Vec<BoostPoolDepth>;
 		fn cf_boost_pool_details(asset: Asset) -> BTreeMap<u16, BoostPoolDetails>;
+		fn cf_aborted_broadcasts() -> Vec<(ForeignChain, BroadcastId)>;
+		fn cf_time_locked_governance_proposals(
+		) -> Vec<(ProposalId, TimeLockedProposal<AccountId32>)>;
 	}
 );
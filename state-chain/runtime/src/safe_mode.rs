@@ -25,12 +25,14 @@ impl_runtime_safe_mode! {
 	broadcast_polkadot: pallet_cf_broadcast::PalletSafeMode<Instance2>,
 	broadcast_arbitrum: pallet_cf_broadcast::PalletSafeMode<Instance4>,
 	broadcast_solana: pallet_cf_broadcast::PalletSafeMode<Instance5>,
+	broadcast_assethub: pallet_cf_broadcast::PalletSafeMode<Instance6>,
 	witnesser: pallet_cf_witnesser::PalletSafeMode<WitnesserCallPermission>,
 	ingress_egress_ethereum: pallet_cf_ingress_egress::PalletSafeMode<Instance1>,
 	ingress_egress_bitcoin: pallet_cf_ingress_egress::PalletSafeMode<Instance3>,
 	ingress_egress_polkadot: pallet_cf_ingress_egress::PalletSafeMode<Instance2>,
 	ingress_egress_arbitrum: pallet_cf_ingress_egress::PalletSafeMode<Instance4>,
 	ingress_egress_solana: pallet_cf_ingress_egress::PalletSafeMode<Instance5>,
+	ingress_egress_assethub: pallet_cf_ingress_egress::PalletSafeMode<Instance6>,
 }
 
 /// Contains permissions for different Runtime calls.
@@ -83,6 +85,12 @@ pub struct WitnesserCallPermission {
 	pub solana_chain_tracking: bool,
 	pub solana_ingress_egress: bool,
 	pub solana_vault: bool,
+
+	// Assethub pallets
+	pub assethub_broadcast: bool,
+	pub assethub_chain_tracking: bool,
+	pub assethub_ingress_egress: bool,
+	pub assethub_vault: bool,
 }
 
 impl WitnesserCallPermission {
@@ -111,6 +119,10 @@ impl WitnesserCallPermission {
 			solana_chain_tracking: true,
 			solana_ingress_egress: true,
 			solana_vault: true,
+			assethub_broadcast: true,
+			assethub_chain_tracking: true,
+			assethub_ingress_egress: true,
+			assethub_vault: true,
 		}
 	}
 }
@@ -145,7 +157,15 @@ impl CallDispatchFilter<RuntimeCall> for WitnesserCallPermission {
 			RuntimeCall::SolanaBroadcaster(..) => self.solana_broadcast,
 			RuntimeCall::SolanaChainTracking(..) => self.solana_chain_tracking,
 			RuntimeCall::SolanaIngressEgress(..) => self.solana_ingress_egress,
-			RuntimeCall::SolanaVault(..) => self.solana_vault,
+			RuntimeCall::SolanaVault(..) |
+			RuntimeCall::Environment(pallet_cf_environment::Call::witness_sol_durable_nonce {
+				..
+			}) => self.solana_vault,
+
+			RuntimeCall::AssethubBroadcaster(..) => self.assethub_broadcast,
+			RuntimeCall::AssethubChainTracking(..) => self.assethub_chain_tracking,
+			RuntimeCall::AssethubIngressEgress(..) => self.assethub_ingress_egress,
+			RuntimeCall::AssethubVault(..) => self.assethub_vault,
 
 			_ => {
 				cf_runtime_utilities::log_or_panic!(
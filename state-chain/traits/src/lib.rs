@@ -16,7 +16,7 @@ pub use async_result::AsyncResult;
 
 use cf_chains::{
 	address::ForeignChainAddress, ApiCall, CcmChannelMetadata, CcmDepositMetadata, Chain,
-	ChainCrypto, ChannelRefundParameters, DepositChannel, Ethereum, SwapOrigin,
+	ChainCrypto, ChannelRefundParameters, DcaParameters, DepositChannel, Ethereum, SwapOrigin,
 };
 use cf_primitives::{
 	AccountRole, Asset, AssetAmount, AuthorityCount, BasisPoints, Beneficiaries, BroadcastId,
@@ -287,6 +287,36 @@ pub trait AccountInfo {
 	fn liquid_funds(account_id: &Self::AccountId) -> Self::Amount;
 }
 
+/// FLIP delegated by tokenholders to validator operators.
+pub trait DelegationApi {
+	type AccountId;
+	type Amount;
+
+	/// The total amount of FLIP that other accounts have delegated to an operator.
+	fn delegated_to(operator: &Self::AccountId) -> Self::Amount;
+
+	/// The amount of an account's own FLIP that it has delegated to an operator.
+	fn delegated_by(delegator: &Self::AccountId) -> Self::Amount;
+
+	/// Splits a reward earned by an operator between the operator and its delegators, pro-rata to
+	/// their stake. The operator's commission is deducted from the delegators' shares.
+	fn reward_shares(
+		operator: &Self::AccountId,
+		reward: Self::Amount,
+	) -> Vec<(Self::AccountId, Self::Amount)>;
+
+	/// Splits a slash incurred by an operator between the operator and its delegators, pro-rata to
+	/// their stake.
+	fn slash_shares(
+		operator: &Self::AccountId,
+		slash: Self::Amount,
+	) -> Vec<(Self::AccountId, Self::Amount)>;
+
+	/// Reduces a delegation that is no longer backed by the delegator's balance, eg. after the
+	/// delegator has been slashed.
+	fn reconcile_delegation(delegator: &Self::AccountId);
+}
+
 /// Trait for managing token issuance.
 pub trait Issuance {
 	type AccountId;
@@ -568,6 +598,18 @@ pub trait WaivedFees {
 	fn should_waive_fees(call: &Self::RuntimeCall, caller: &Self::AccountId) -> bool;
 }
 
+/// Determines which account pays the fees for an extrinsic.
+pub trait FeePayer<AccountId, RuntimeCall> {
+	fn fee_payer(call: &RuntimeCall, signer: &AccountId) -> AccountId;
+}
+
+/// The signer pays.
+impl<AccountId: Clone, RuntimeCall> FeePayer<AccountId, RuntimeCall> for () {
+	fn fee_payer(_call: &RuntimeCall, signer: &AccountId) -> AccountId {
+		signer.clone()
+	}
+}
+
 /// Qualify what is considered as a potential authority for the network
 pub trait QualifyNode<Id: Ord> {
 	/// Is the node qualified to be an authority and meet our expectations of one
@@ -698,6 +740,7 @@ pub trait DepositApi<C: Chain> {
 		channel_metadata: Option<CcmChannelMetadata>,
 		boost_fee: BasisPoints,
 		refund_params: Option<ChannelRefundParameters>,
+		dca_params: Option<DcaParameters>,
 	) -> Result<(ChannelId, ForeignChainAddress, C::ChainBlockNumber, Self::Amount), DispatchError>;
 }
 
@@ -856,6 +899,14 @@ pub trait OnDeposit<C: Chain> {
 		_channel: &DepositChannel<C>,
 	) {
 	}
+
+	/// Called for a deposit made directly into the vault rather than to a deposit channel.
+	fn on_vault_deposit_made(
+		_deposit_details: C::DepositDetails,
+		_amount: C::ChainAmount,
+		_deposit_address: &C::ChainAccount,
+	) {
+	}
 }
 
 pub trait NetworkEnvironmentProvider {
@@ -901,6 +952,18 @@ pub trait OnBroadcastReady<C: Chain> {
 	type ApiCall: ApiCall<C::ChainCrypto>;
 
 	fn on_broadcast_ready(_api_call: &Self::ApiCall) {}
+
+	/// Called when a call that had been replaced, e.g. to pay a higher fee, was included on the
+	/// target chain instead of its replacement.
+	fn on_replaced_broadcast_confirmed(_confirmed: &Self::ApiCall, _replacement: &Self::ApiCall) {}
+
+	/// Called when a call is about to be replaced, e.g. to pay a higher fee, before the replacement
+	/// is signed.
+	fn on_broadcast_replaced(_replaced: &Self::ApiCall) {}
+
+	/// Called when a broadcast that was made ready is cancelled, so that anything set aside for
+	/// it can be released.
+	fn on_broadcast_cancelled(_api_call: &Self::ApiCall) {}
 }
 
 pub trait GetBitcoinFeeInfo {
@@ -1,12 +1,17 @@
 use cf_chains::{
-	address::ForeignChainAddress, assets::any::AssetMap, ChannelRefundParameters,
-	SwapRefundParameters,
+	address::{EncodedAddress, ForeignChainAddress},
+	assets::any::AssetMap,
+	ChannelRefundParameters, DcaParameters, SwapRefundParameters,
+};
+use cf_primitives::{
+	Asset, AssetAmount, Beneficiaries, Beneficiary, ChannelId, SwapId, TransactionHash,
 };
-use cf_primitives::{Asset, AssetAmount, Beneficiaries, ChannelId, SwapId};
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::pallet_prelude::{DispatchError, DispatchResult};
+use frame_support::pallet_prelude::{DispatchError, DispatchResult, Weight};
 use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
+use sp_runtime::Permill;
+use sp_std::vec::Vec;
 
 pub trait SwapDepositHandler {
 	type AccountId;
@@ -20,8 +25,22 @@ pub trait SwapDepositHandler {
 		destination_address: ForeignChainAddress,
 		broker_commission: Beneficiaries<Self::AccountId>,
 		refund_params: Option<ChannelRefundParameters>,
+		dca_params: Option<DcaParameters>,
 		channel_id: ChannelId,
 	) -> SwapId;
+
+	/// Schedules a swap for a deposit made directly into the vault, with the swap parameters
+	/// supplied by the depositor rather than by a deposit channel. Fails if the parameters are
+	/// invalid, in which case the deposit should be refunded.
+	fn schedule_vault_swap(
+		from: Asset,
+		to: Asset,
+		amount: AssetAmount,
+		destination_address: EncodedAddress,
+		broker_commission: Beneficiary<Self::AccountId>,
+		refund_params: SwapRefundParameters,
+		tx_hash: TransactionHash,
+	) -> Result<SwapId, DispatchError>;
 }
 
 pub trait LpDepositHandler {
@@ -105,12 +124,24 @@ pub trait SwappingApi {
 	/// and return the (remaining value, network fee taken)
 	fn take_network_fee(input_amount: AssetAmount) -> NetworkFeeTaken;
 
-	/// Process a single leg of a swap, into or from Stable asset. No network fee is taken.
+	/// The percentage of the swapped amount that is taken as the network fee.
+	fn network_fee() -> Permill;
+
+	/// Process a single leg of a swap through the pool of `from` and `to`. No network fee is
+	/// taken.
 	fn swap_single_leg(
 		from: Asset,
 		to: Asset,
 		input_amount: AssetAmount,
 	) -> Result<AssetAmount, DispatchError>;
+
+	/// The (base, quote) asset pairs of all existing pools.
+	fn pools() -> Vec<(Asset, Asset)>;
+
+	/// Called after each batch of swaps has been executed. Returns the weight consumed.
+	fn on_swap_batch_executed() -> Weight {
+		Weight::zero()
+	}
 }
 
 pub trait SwapQueueApi {
@@ -14,6 +14,7 @@ pub mod ccm_handler;
 pub mod ceremony_id_provider;
 pub mod cfe_interface_mock;
 pub mod chain_tracking;
+pub mod delegation;
 pub mod deposit_handler;
 pub mod egress_handler;
 pub mod ensure_origin_mock;
@@ -0,0 +1,52 @@
+use crate::{Chainflip, DelegationApi};
+use sp_std::{marker::PhantomData, vec, vec::Vec};
+
+use super::{MockPallet, MockPalletStorage};
+
+pub struct MockDelegation<T>(PhantomData<T>);
+
+impl<T> MockPallet for MockDelegation<T> {
+	const PREFIX: &'static [u8] = b"MockDelegation";
+}
+
+const DELEGATED_TO: &[u8] = b"DELEGATED_TO";
+const DELEGATED_BY: &[u8] = b"DELEGATED_BY";
+
+impl<T: Chainflip> MockDelegation<T> {
+	pub fn set_delegated_to(operator: &T::AccountId, amount: T::Amount) {
+		<Self as MockPalletStorage>::put_storage(DELEGATED_TO, operator, amount);
+	}
+
+	pub fn set_delegated_by(delegator: &T::AccountId, amount: T::Amount) {
+		<Self as MockPalletStorage>::put_storage(DELEGATED_BY, delegator, amount);
+	}
+}
+
+impl<T: Chainflip> DelegationApi for MockDelegation<T> {
+	type AccountId = T::AccountId;
+	type Amount = T::Amount;
+
+	fn delegated_to(operator: &Self::AccountId) -> Self::Amount {
+		<Self as MockPalletStorage>::get_storage(DELEGATED_TO, operator).unwrap_or_default()
+	}
+
+	fn delegated_by(delegator: &Self::AccountId) -> Self::Amount {
+		<Self as MockPalletStorage>::get_storage(DELEGATED_BY, delegator).unwrap_or_default()
+	}
+
+	fn reward_shares(
+		operator: &Self::AccountId,
+		reward: Self::Amount,
+	) -> Vec<(Self::AccountId, Self::Amount)> {
+		vec![(operator.clone(), reward)]
+	}
+
+	fn slash_shares(
+		operator: &Self::AccountId,
+		slash: Self::Amount,
+	) -> Vec<(Self::AccountId, Self::Amount)> {
+		vec![(operator.clone(), slash)]
+	}
+
+	fn reconcile_delegation(_delegator: &Self::AccountId) {}
+}
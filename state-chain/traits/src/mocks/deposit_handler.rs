@@ -2,7 +2,7 @@ use super::{MockPallet, MockPalletStorage};
 use crate::{Chainflip, DepositApi};
 use cf_chains::{
 	address::ForeignChainAddress, dot::PolkadotAccountId, CcmChannelMetadata, Chain,
-	ChannelRefundParameters, ForeignChain,
+	ChannelRefundParameters, DcaParameters, ForeignChain,
 };
 use cf_primitives::{chains::assets::any, BasisPoints, Beneficiaries, ChannelId};
 use codec::{Decode, Encode};
@@ -66,6 +66,9 @@ impl<C: Chain, T: Chainflip> MockDepositHandler<C, T> {
 				),
 				ForeignChain::Bitcoin => todo!("Bitcoin address"),
 				ForeignChain::Solana => todo!("Solana address"),
+				ForeignChain::Assethub => ForeignChainAddress::Hub(
+					PolkadotAccountId::from_aliased([channel_id as u8; 32]),
+				),
 			},
 			Default::default(),
 		)
@@ -124,6 +127,7 @@ impl<C: Chain, T: Chainflip> DepositApi<C> for MockDepositHandler<C, T> {
 		channel_metadata: Option<CcmChannelMetadata>,
 		boost_fee: BasisPoints,
 		_refund_params: Option<ChannelRefundParameters>,
+		_dca_params: Option<DcaParameters>,
 	) -> Result<
 		(cf_primitives::ChannelId, ForeignChainAddress, C::ChainBlockNumber, Self::Amount),
 		sp_runtime::DispatchError,
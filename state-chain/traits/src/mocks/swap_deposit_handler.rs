@@ -1,6 +1,12 @@
 use crate::{EgressApi, SwapDepositHandler};
-use cf_chains::{Chain, ChannelRefundParameters, ForeignChainAddress};
-use cf_primitives::{Asset, AssetAmount, Beneficiaries, ChannelId, SwapId};
+use cf_chains::{
+	address::EncodedAddress, Chain, ChannelRefundParameters, DcaParameters, ForeignChainAddress,
+	SwapRefundParameters,
+};
+use cf_primitives::{
+	Asset, AssetAmount, Beneficiaries, Beneficiary, ChannelId, SwapId, TransactionHash,
+};
+use sp_runtime::DispatchError;
 
 /// Simple mock that applies 1:1 swap ratio to all pairs.
 pub struct MockSwapDepositHandler<T>(sp_std::marker::PhantomData<T>);
@@ -20,6 +26,7 @@ where
 		destination_address: ForeignChainAddress,
 		_broker_commission: Beneficiaries<Self::AccountId>,
 		_refund_params: Option<ChannelRefundParameters>,
+		_dca_params: Option<DcaParameters>,
 		_channel_id: ChannelId,
 	) -> SwapId {
 		let _ = E::schedule_egress(
@@ -30,4 +37,16 @@ where
 		);
 		1
 	}
+
+	fn schedule_vault_swap(
+		_from: Asset,
+		_to: Asset,
+		_amount: AssetAmount,
+		_destination_address: EncodedAddress,
+		_broker_commission: Beneficiary<Self::AccountId>,
+		_refund_params: SwapRefundParameters,
+		_tx_hash: TransactionHash,
+	) -> Result<SwapId, DispatchError> {
+		Ok(1)
+	}
 }
@@ -422,6 +422,18 @@ build_gauge_vec!(
 	"Gauge keeping track of the number of ceremonies currently running",
 	["chain", "type"]
 );
+build_gauge_vec!(
+	QUEUED_CEREMONIES,
+	"cfe_queued_ceremonies",
+	"Gauge keeping track of the number of authorised ceremonies waiting for a free slot to start",
+	["scheme", "type"]
+);
+build_counter_vec!(
+	REJECTED_CEREMONIES,
+	"cfe_rejected_ceremonies",
+	"Count the ceremony requests rejected because too many ceremonies were already queued",
+	["scheme", "type"]
+);
 build_counter_vec!(
 	RPC_RETRIER_REQUESTS,
 	"cfe_rpc_requests",
@@ -434,6 +446,18 @@ build_counter_vec!(
 	"Count all the rpc calls made by the retrier, it counts every single call even if it is the same made multiple times",
 	["client","rpc_method"]
 );
+build_counter_vec!(
+	RPC_QUORUM_DISAGREEMENTS,
+	"cfe_rpc_quorum_disagreements",
+	"Count the responses from an rpc endpoint that disagreed with the value agreed by the quorum of endpoints",
+	["client", "rpc_method", "endpoint"]
+);
+build_gauge_vec!(
+	RPC_ENDPOINT_PENALTY,
+	"cfe_rpc_endpoint_penalty",
+	"Gauge keeping track of the penalty of each rpc endpoint, increased each time the endpoint disagrees with the quorum",
+	["client", "endpoint"]
+);
 build_counter_vec!(
 	P2P_MONITOR_EVENT,
 	"cfe_p2p_monitor_event",